@@ -61,6 +61,11 @@ fn performance_benchmark() {
             to_call: 50,
             my_stack: 1000,
             opponent_stack: 1000,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
         };
         
         let _ = api.get_optimal_strategy(state);
@@ -120,6 +125,11 @@ fn api_demo() {
         to_call: 15,
         my_stack: 1000,
         opponent_stack: 1000,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     let result = api.get_optimal_strategy(premium_hand.clone());
@@ -140,6 +150,11 @@ fn api_demo() {
         to_call: 80,
         my_stack: 600,
         opponent_stack: 800,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     let result = api.get_optimal_strategy(marginal_hand.clone());
@@ -46,6 +46,7 @@ fn test_preflop_pocket_aces() {
         sample_count: 5000,  // 빠른 데모를 위해 샘플 수 감소
         max_depth: 8,
         use_opponent_model: true,
+        ..EVConfig::default()
     };
     
 // EV 계산 실행
@@ -84,6 +85,7 @@ fn test_flop_top_pair() {
         sample_count: 3000,
         max_depth: 6,
         use_opponent_model: true,
+        ..EVConfig::default()
     };
     
     let calculator = EVCalculator::new(config);
@@ -116,6 +118,7 @@ fn test_turn_draw() {
         sample_count: 2000,
         max_depth: 4,
         use_opponent_model: true,
+        ..EVConfig::default()
     };
     
     let calculator = EVCalculator::new(config);
@@ -149,6 +152,7 @@ fn test_river_bluff_catcher() {
         sample_count: 1500,
         max_depth: 3,
         use_opponent_model: true,
+        ..EVConfig::default()
     };
     
     let calculator = EVCalculator::new(config);
@@ -0,0 +1,25 @@
+// 웹 API DTO JSON 스키마 내보내기
+// 사용법: cargo run --example export_schemas --features schema -- [출력 디렉토리]
+use nice_hand_core::api::schema::export_all;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() -> std::io::Result<()> {
+    let out_dir = PathBuf::from(std::env::args().nth(1).unwrap_or_else(|| "schemas".to_string()));
+    fs::create_dir_all(&out_dir)?;
+
+    println!("📐 JSON 스키마 내보내기 → {}", out_dir.display());
+
+    let mut schemas: Vec<_> = export_all().into_iter().collect();
+    schemas.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (name, schema) in &schemas {
+        let path = out_dir.join(format!("{}.schema.json", name));
+        let json = serde_json::to_string_pretty(schema).expect("스키마 직렬화 실패");
+        fs::write(&path, json)?;
+        println!("  ✅ {}", path.display());
+    }
+
+    println!("✨ {}개 스키마 생성 완료", schemas.len());
+    Ok(())
+}
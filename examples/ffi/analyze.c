@@ -0,0 +1,45 @@
+/*
+ * C에서 nice-hand-core 분석 호출 예제 (feature = "ffi")
+ * 빌드와 실행: scripts/check_ffi.sh
+ */
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "nice_hand_core.h"
+
+int main(void) {
+    printf("nice-hand-core %s\n", nhc_version());
+
+    /* AA (As Ah) / 보드 2s 3h 3d */
+    const uint8_t hole[2] = {0, 13};
+    const uint8_t board[3] = {2, 15, 28};
+    double strength = nhc_hand_strength(hole, 2, board, 3);
+    if (isnan(strength) || strength < 0.0 || strength > 1.0) {
+        fprintf(stderr, "잘못된 핸드 스트렝스: %f\n", strength);
+        return 1;
+    }
+    printf("hand strength: %.3f\n", strength);
+
+    const char *request =
+        "{\"game_state\": {\"hole_cards\": [0, 13], \"board\": [], \"street\": 0, \"pot\": 30,"
+        " \"stacks\": [1000, 1000], \"alive_players\": [0, 1], \"street_investments\": [10, 20],"
+        " \"to_call\": 10, \"player_to_act\": 0, \"hero_position\": 0, \"betting_history\": []},"
+        " \"options\": {\"depth\": \"quick\", \"max_calculation_time_ms\": null,"
+        " \"include_insights\": false, \"include_range_analysis\": false,"
+        " \"include_equity_calculation\": false, \"opponent_modeling\": \"Tight\"}}";
+    char *response = nhc_analyze_json(request);
+    if (response == NULL || strstr(response, "\"error\"") != NULL) {
+        fprintf(stderr, "분석 실패: %s\n", response ? response : "(null)");
+        nhc_free_string(response);
+        return 1;
+    }
+    printf("analysis: %.80s...\n", response);
+    nhc_free_string(response);
+
+    /* 널 요청은 크래시 대신 에러 JSON */
+    char *error = nhc_analyze_json(NULL);
+    int ok = error != NULL && strstr(error, "\"error\"") != NULL;
+    nhc_free_string(error);
+    return ok ? 0 : 1;
+}
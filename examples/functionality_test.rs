@@ -17,6 +17,9 @@ fn create_simple_scenario() -> WebGameState {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     }
 }
 
@@ -78,6 +81,9 @@ fn test_basic_functionality() {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     };
     
     match analyze_comprehensive(&flop_scenario, "quick", false) {
@@ -106,6 +112,9 @@ fn test_edge_cases() {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     };
     
     println!("\n🔧 Test: Invalid game state (too many board cards)");
@@ -127,6 +136,9 @@ fn test_edge_cases() {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     };
     
     println!("\n🔧 Test: Empty stacks scenario");
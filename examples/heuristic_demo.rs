@@ -21,6 +21,11 @@ fn main() {
         to_call: 100,
         my_stack: 1000,
         opponent_stack: 1000,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     demonstrate_strategy(&api, premium_state, "포켓 에이스 프리플롭에서 레이즈에 직면");
@@ -37,6 +42,11 @@ fn main() {
         to_call: 150,
         my_stack: 800,
         opponent_stack: 800,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     demonstrate_strategy(&api, marginal_state, "KQ 오프수트에서 큰 프리플롭 레이즈에 직면");
@@ -53,6 +63,11 @@ fn main() {
         to_call: 0, // 우리에게 체크
         my_stack: 700,
         opponent_stack: 700,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     demonstrate_strategy(&api, postflop_state, "플롭에서 킹 키커를 가진 에이스 탑 페어");
@@ -69,6 +84,11 @@ fn main() {
         to_call: 200,
         my_stack: 600,
         opponent_stack: 600,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     demonstrate_strategy(&api, flush_draw_state, "연결된 보드에서 베팅에 직면한 플러시 드로우");
@@ -85,6 +105,11 @@ fn main() {
         to_call: 0,
         my_stack: 750,
         opponent_stack: 750,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     demonstrate_strategy(&api, bluff_state, "높은 연결 보드에서의 완전한 에어");
@@ -101,6 +126,11 @@ fn main() {
         to_call: 180, // 우리 스택의 거의 절반
         my_stack: 400,
         opponent_stack: 800,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     demonstrate_strategy(&api, short_stack_state, "포켓 7s 숏 스택에서 큰 레이즈에 직면");
@@ -119,6 +149,11 @@ fn main() {
             to_call: (i % 200) as u32,
             my_stack: 1000,
             opponent_stack: 1000,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
         }
     }).collect();
     
@@ -183,9 +218,9 @@ fn get_action_emoji(action: &str) -> &'static str {
         "fold" => "🛑",
         "check" => "✋",
         "call" => "📞",
-        "bet_small" => "💰",
-        "bet_large" => "💎",
+        "bet_allin" => "💎",
         "raise" => "🚀",
+        bet if bet.starts_with("bet_") => "💰",
         _ => "❓",
     }
 }
@@ -19,6 +19,11 @@ fn main() {
         to_call: 100,
         my_stack: 1000,
         opponent_stack: 1000,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     let response = api.get_optimal_strategy(premium_state);
@@ -39,6 +44,11 @@ fn main() {
         to_call: 150,
         my_stack: 800,
         opponent_stack: 800,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     let response2 = api.get_optimal_strategy(marginal_state);
@@ -59,6 +69,11 @@ fn main() {
         to_call: 0, // 우리에게 체크
         my_stack: 700,
         opponent_stack: 700,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     let response3 = api.get_optimal_strategy(postflop_state);
@@ -81,6 +96,11 @@ fn main() {
             to_call: i % 200,
             my_stack: 1000,
             opponent_stack: 1000,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
         }
     }).collect();
     
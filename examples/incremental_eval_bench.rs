@@ -0,0 +1,72 @@
+// 증분 핸드 평가 벤치마크
+//
+// 턴 열거 작업 (홀카드 2장 + 턴 보드 4장을 고정하고 남은 리버 46장을 모두 평가)을
+// `evaluate_7cards`로 처음부터 계산할 때와 `PartialEval::extend`로 이어서 계산할 때 비교합니다.
+//
+// 실행: cargo run --release --example incremental_eval_bench
+
+use nice_hand_core::hand_eval::{evaluate_7cards, PartialEval};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SPOTS: usize = 20_000;
+const ROUNDS: usize = 5;
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut deck: Vec<u8> = (0..52).collect();
+    let spots: Vec<([u8; 6], Vec<u8>)> = (0..SPOTS)
+        .map(|_| {
+            deck.shuffle(&mut rng);
+            let fixed = [deck[0], deck[1], deck[2], deck[3], deck[4], deck[5]];
+            (fixed, deck[6..].to_vec())
+        })
+        .collect();
+    let evaluations = spots.iter().map(|(_, rivers)| rivers.len()).sum::<usize>();
+
+    println!("🃏 턴 열거: 상황 {}개 x 리버 46장 = 평가 {}회, {}회 중 최솟값", SPOTS, evaluations, ROUNDS);
+
+    let from_scratch = best_of(|| {
+        let mut checksum = 0u64;
+        for (fixed, rivers) in &spots {
+            for &river in rivers {
+                let [a, b, c, d, e, f] = *fixed;
+                checksum += evaluate_7cards(black_box([a, b, c, d, e, f, river])) as u64;
+            }
+        }
+        checksum
+    });
+    let incremental = best_of(|| {
+        let mut checksum = 0u64;
+        for (fixed, rivers) in &spots {
+            let partial = PartialEval::from_cards(black_box(fixed));
+            for &river in rivers {
+                checksum += partial.extend(&[black_box(river)]) as u64;
+            }
+        }
+        checksum
+    });
+
+    let per_eval = |elapsed: Duration| elapsed.as_nanos() as f64 / evaluations as f64;
+    println!("  evaluate_7cards:     {:>8.2?} ({:.1} ns/평가)", from_scratch, per_eval(from_scratch));
+    println!("  PartialEval::extend: {:>8.2?} ({:.1} ns/평가)", incremental, per_eval(incremental));
+    println!(
+        "  속도 향상: {:.2}배",
+        from_scratch.as_secs_f64() / incremental.as_secs_f64().max(1e-9)
+    );
+}
+
+/// `ROUNDS`번 실행한 시간 중 최솟값
+fn best_of(mut run: impl FnMut() -> u64) -> Duration {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(run());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
@@ -0,0 +1,70 @@
+// 루트 글롭 재내보내기 제거 후의 import 방식
+//
+// 이전에는 `solver`, `game`, `api`의 모든 pub 항목이 크레이트 루트로 올라왔지만, 이제 루트에는 하위 모듈과
+// 골라 둔 핵심 타입만 있습니다. 자주 쓰는 타입은 `prelude`에서, 나머지는 모듈 경로로 가져옵니다.
+
+// 1. 자주 쓰는 타입은 프렐루드 한 줄로
+use nice_hand_core::prelude::*;
+
+// 2. 루트에서 사라진 항목은 모듈 경로로
+//    이전: use nice_hand_core::hand_strength;
+use nice_hand_core::card_abstraction::hand_strength;
+
+// 3. 이름이 겹치던 타입은 모듈로 구분
+//    - 분석 API의 상대 모델 수준 `OpponentModel` -> `analysis::OpponentModelKind` (이전 이름은 한 릴리스 동안 deprecated 별칭)
+//    - 플레이어별 통계 모델은 그대로 `tournament::OpponentModel`
+//    - 간단 API의 게임 상태는 `web_api_simple::WebGameState`, 루트/프렐루드의 `WebGameState`는 `web_api` 쪽
+use nice_hand_core::analysis::{AnalysisOptions, OpponentModelKind};
+use nice_hand_core::tournament::OpponentModel;
+use nice_hand_core::web_api_simple;
+
+fn main() {
+    // 짧은 모듈 경로와 루트의 핵심 타입은 그대로 사용 가능
+    let mut trainer = nice_hand_core::Trainer::<nice_hand_core::holdem::State>::new();
+    trainer.run(vec![HoldemState::new()], 1);
+    println!("학습된 정보 세트: {}", trainer.nodes.len());
+
+    println!("AA 프리플랍 스트렝스: {:.2}", hand_strength([0, 13], &[]));
+
+    let options = AnalysisOptions {
+        opponent_modeling: OpponentModelKind::Aggressive,
+        ..AnalysisOptions::default()
+    };
+    println!("분석 상대 모델: {:?}", options.opponent_modeling);
+
+    let villain = OpponentModel::new(1);
+    println!("토너먼트 상대 {} VPIP: {:.2}", villain.player_id, villain.vpip);
+
+    let full_state = WebGameState {
+        hole_cards: [0, 13],
+        board: vec![],
+        street: 0,
+        pot: 150,
+        stacks: vec![1000, 1000],
+        alive_players: vec![0, 1],
+        street_investments: vec![50, 100],
+        to_call: 100,
+        player_to_act: 0,
+        hero_position: 0,
+        betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
+    };
+    let simple_state = web_api_simple::WebGameState {
+        hole_cards: full_state.hole_cards,
+        board: full_state.board.clone(),
+        street: full_state.street,
+        pot: full_state.pot,
+        to_call: full_state.to_call,
+        my_stack: full_state.stacks[0],
+        opponent_stack: full_state.stacks[1],
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
+    };
+    let strategy = QuickPokerAPI::new().get_optimal_strategy(simple_state);
+    println!("추천 액션: {}", strategy.recommended_action);
+}
@@ -44,6 +44,7 @@ fn main() {
         level_duration_minutes: 20,
         starting_stack: 10000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
 
     // Initialize tournament
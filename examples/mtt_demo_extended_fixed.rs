@@ -33,6 +33,7 @@ fn create_large_tournament() -> MTTManager {
         level_duration_minutes: 20,
         starting_stack: 10000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     // 180명 플레이어, 테이블당 9명, 상금 풀 $18,000로 MTT 생성
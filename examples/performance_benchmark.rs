@@ -18,6 +18,9 @@ fn create_preflop_scenario() -> WebGameState {
         player_to_act: 3,
         hero_position: 3,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     }
 }
 
@@ -34,6 +37,9 @@ fn create_flop_scenario() -> WebGameState {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     }
 }
 
@@ -33,6 +33,11 @@ fn current_capabilities_demo() {
         to_call: 50,
         my_stack: 1000,
         opponent_stack: 1000,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     let result = api.get_optimal_strategy(state.clone());
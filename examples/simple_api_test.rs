@@ -17,6 +17,9 @@ fn main() {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     };
 
     println!("Created web state with hole cards: {:?}", web_state.hole_cards);
@@ -17,8 +17,19 @@ fn main() {
         stack: [1000; 6],
         alive: [true, true, false, false, false, false], // 2명의 플레이어만
         invested: [15, 30, 0, 0, 0, 0], // 블라인드 게시
+        antes: [0; 6],
         to_call: 30,
         actions_taken: 0,
+        button: 0,
+        rake: None,
+        variant: holdem::HoldemVariant::Standard,
+        flop_sampler: None,
+        betting: holdem::BettingRules::NoLimit,
+        raises_this_street: 0,
+        big_blind: 30,
+        last_raise: 0,
+        straddle: 0,
+        opponent_deal: None,
     };
     
     println!("50회 반복으로 홀덤 훈련 (무한 재귀 테스트)...");
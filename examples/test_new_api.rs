@@ -39,6 +39,9 @@ fn test_comprehensive_analysis() {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     };
 
     match analyze_comprehensive(&web_state, "standard", true) {
@@ -83,6 +86,9 @@ fn test_quick_ev_calculation() {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     };
 
     match calculate_quick_ev(&web_state, Some(1000)) {
@@ -116,6 +122,9 @@ fn test_game_state_validation() {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     };
 
     match validate_game_state(&valid_state) {
@@ -136,6 +145,9 @@ fn test_game_state_validation() {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     };
 
     match validate_game_state(&invalid_state) {
@@ -161,6 +173,9 @@ fn test_action_recommendation() {
         player_to_act: 0,
         hero_position: 0,
         betting_history: vec![],
+        seat_status: vec![],
+        posted: vec![],
+        straddle: None,
     };
 
     // Test different risk tolerances
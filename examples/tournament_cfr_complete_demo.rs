@@ -3,7 +3,7 @@
 
 use nice_hand_core::{
     TournamentHoldem, TournamentHoldemState, TournamentCFRTrainer,
-    game::tournament::{TournamentState, TournamentStructure, BlindLevel},
+    game::tournament::{AnteType, TournamentState, TournamentStructure, BlindLevel},
     game::holdem::State as HoldemState,
     solver::cfr_core::Game,
 };
@@ -47,6 +47,7 @@ fn demo_tournament_setup() {
         level_duration_minutes: 15,
         starting_stack: 1500,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let tournament_state = TournamentState::new(structure, 100, 10000);
@@ -73,6 +74,7 @@ fn demo_icm_bubble_strategy() {
         level_duration_minutes: 15,
         starting_stack: 1500,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let mut tournament_state = TournamentState::new(structure, 100, 10000);
@@ -120,6 +122,7 @@ fn demo_tournament_game_states() {
         level_duration_minutes: 15,
         starting_stack: 1500,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let tournament_state = TournamentState::new(structure, 2, 1000);
@@ -168,6 +171,7 @@ fn demo_cfr_integration() {
         level_duration_minutes: 15,
         starting_stack: 1000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let tournament_state = TournamentState::new(structure, 2, 500);
@@ -220,6 +224,7 @@ fn demo_realtime_decisions() {
         level_duration_minutes: 15,
         starting_stack: 1500,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let mut tournament_state = TournamentState::new(structure, 20, 5000);
@@ -31,6 +31,7 @@ fn demo_tournament_cfr_integration() {
         level_duration_minutes: 20,
         starting_stack: 10000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let tournament_state = TournamentState::new(structure, 100, 200000);
@@ -94,6 +95,7 @@ fn demo_adaptive_tournament_strategy() {
             level_duration_minutes: 20,
             starting_stack: 10000,
             ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
         };
         
         let mut tournament_state = TournamentState::new(structure, 180, 500000);
@@ -106,7 +108,7 @@ fn demo_adaptive_tournament_strategy() {
                 players_remaining, payout_spots, bubble_strategy.bubble_factor);
         
         for (i, &stack) in stacks.iter().enumerate() {
-            let avg_stack = tournament_state.total_chips() / tournament_state.players_remaining;
+            let avg_stack = tournament_state.total_chips() / tournament_state.players_remaining as u64;
             let stack_ratio = stack as f64 / avg_stack as f64;
             
             // Create tournament strategy
@@ -137,6 +139,7 @@ fn demo_realtime_tournament_decisions() {
         level_duration_minutes: 20,
         starting_stack: 10000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let mut tournament_state = TournamentState::new(structure, 27, 50000);
@@ -175,15 +178,10 @@ fn demo_realtime_tournament_decisions() {
         let bubble_strategy = BubbleStrategy::new(10, 9);
         let should_be_aggressive = bubble_strategy.should_make_aggressive_play(stack_ratio, icm_pressure.abs());
         
-        // Simulate available actions for this context
-        let available_actions = vec![
-            TournamentAction::Fold,
-            TournamentAction::Call,
-            TournamentAction::Raise(900),
-            TournamentAction::AllIn,
-        ];
+        // Heads-up hand at 150/300 with the player's stack (raises are sized from its pot)
+        let hand = holdem::State::new_hand([150, 300], [player_stacks[player_idx]; 6], 2);
         
-        let recommended_action = evaluator.select_opponent_action(player_idx as u32, &context, &available_actions);
+        let recommended_action = evaluator.select_opponent_action(player_idx as u32, &hand, &context, None, &mut rand::thread_rng());
         
         println!("         Stack ratio: {:.1}x average", stack_ratio);
         println!("         ICM pressure: {:.4}", icm_pressure);
@@ -196,13 +194,8 @@ fn demo_realtime_tournament_decisions() {
     
     println!("\n   🎯 Performance Metrics:");
     
-    // Create available actions for benchmark
-    let available_actions = vec![
-        TournamentAction::Fold,
-        TournamentAction::Call,
-        TournamentAction::Raise(500),
-        TournamentAction::AllIn,
-    ];
+    // Create a hand for benchmark
+    let hand = holdem::State::new_hand([150, 300], [15000; 6], 2);
     
     // Benchmark decision speed
     let start = Instant::now();
@@ -216,7 +209,7 @@ fn demo_realtime_tournament_decisions() {
             num_opponents: 4,
         };
         
-        let _action = evaluator.select_opponent_action(1, &context, &available_actions);
+        let _action = evaluator.select_opponent_action(1, &hand, &context, None, &mut rand::thread_rng());
     }
     let decision_time = start.elapsed();
     
@@ -1,4 +1,5 @@
 // 토너먼트 기능 종합 데모
+use nice_hand_core::game::holdem;
 use nice_hand_core::game::tournament::*;
 use std::time::Instant;
 
@@ -146,6 +147,7 @@ fn demo_mtt_management() {
         level_duration_minutes: 20,
         starting_stack: 10000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let mut mtt = MTTManager::new(54, 9, structure, 100000);
@@ -260,6 +262,7 @@ fn demo_tournament_evaluation() {
         level_duration_minutes: 20,
         starting_stack: 10000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let tournament_state = TournamentState::new(structure, 180, 500000);
@@ -301,15 +304,11 @@ fn demo_tournament_evaluation() {
         }),
     ];
     
-    let available_actions = vec![
-        TournamentAction::Fold,
-        TournamentAction::Call,
-        TournamentAction::Raise(800),
-        TournamentAction::AllIn,
-    ];
+    // 200/400 헤즈업 핸드 (레이즈 크기는 이 핸드의 팟으로 계산)
+    let hand = holdem::State::new_hand([200, 400], [final_stacks[1]; 6], 2);
     
     for (scenario, context) in contexts {
-        let selected_action = evaluator.select_opponent_action(1, &context, &available_actions);
+        let selected_action = evaluator.select_opponent_action(1, &hand, &context, None, &mut rand::thread_rng());
         println!("         {}: {:?}", scenario, selected_action);
     }
     
@@ -435,6 +434,7 @@ fn test_mtt_management() {
         level_duration_minutes: 15,
         starting_stack: 5000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let mut mtt = MTTManager::new(27, 9, structure, 10000);
@@ -531,6 +531,7 @@ fn test_tournament_evaluator() {
         level_duration_minutes: 20,
         starting_stack: 10000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let tournament_state = TournamentState::new(structure, 100, 50000);
@@ -555,13 +556,9 @@ fn test_tournament_evaluator() {
         num_opponents: 2,
     };
     
-    let available_actions = vec![
-        TournamentAction::Fold,
-        TournamentAction::Call,
-        TournamentAction::Raise(100),
-    ];
+    let hand = holdem::State::new_hand([25, 50], [player_stacks[1]; 6], 2);
     
-    let selected_action = evaluator.select_opponent_action(1, &context, &available_actions);
+    let selected_action = evaluator.select_opponent_action(1, &hand, &context, None, &mut rand::thread_rng());
     println!("   🤖 선택된 상대방 액션: {:?}", selected_action);
     
     // ICM 압박 계산 테스트
@@ -586,6 +583,7 @@ fn demo_cfr_integration() {
         level_duration_minutes: 20,
         starting_stack: 10000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let tournament_state = TournamentState::new(structure, 100, 200000);
@@ -675,6 +673,7 @@ fn demo_performance_comparison() {
         level_duration_minutes: 20,
         starting_stack: 10000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let start = Instant::now();
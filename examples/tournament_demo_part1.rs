@@ -116,6 +116,7 @@ fn test_tournament_structure() {
         level_duration_minutes: 20,
         starting_stack: 1500,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     // 토너먼트 상태 생성
@@ -22,6 +22,7 @@ fn tournament_strategy_demo() {
         level_duration_minutes: 20,
         starting_stack: 10000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let mut tournament_state = TournamentState::new(structure, 100, 200000);
@@ -88,6 +88,7 @@ pub fn test_mtt_management() {
         level_duration_minutes: 15,
         starting_stack: 5000,
         ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
     };
     
     let mut mtt = MTTManager::new(27, 9, structure, 10000);
@@ -0,0 +1,45 @@
+// QuickPokerAPI 휴리스틱 파라미터 튜닝 예제
+// 생성된 스팟 모음에서 힐 클라이밍을 실행하고 HeuristicParams::tuned() 후보를 출력한 뒤
+// 학습된 CFR 정책과의 헤즈업 대결로 검증합니다
+
+use nice_hand_core::api::tuning::{
+    arena_bb_per_100, evaluate, generate_spots, optimize, ArenaConfig, HeuristicParams, TuningConfig,
+};
+use std::time::Instant;
+
+fn main() {
+    println!("🎯 휴리스틱 파라미터 튜닝");
+    println!("========================");
+
+    let train = generate_spots(600, 2024);
+    let held_out = generate_spots(400, 0xbeef);
+
+    let classic = HeuristicParams::classic();
+    println!("classic 후회 (학습/검증): {:.4} / {:.4}", evaluate(&classic, &train), evaluate(&classic, &held_out));
+
+    let config = TuningConfig {
+        iterations: 1500,
+        ..TuningConfig::default()
+    };
+    let result = optimize(&classic, &train, &config);
+
+    println!("tuned 후회 (학습/검증): {:.4} / {:.4}", result.objective, evaluate(&result.params, &held_out));
+    println!("\n찾은 파라미터:");
+    println!("{:#?}", result.params);
+
+    let shipped = HeuristicParams::tuned();
+    println!("\n현재 tuned() 후회 (검증): {:.4}", evaluate(&shipped, &held_out));
+
+    // 학습된 CFR 정책과의 대결 (100핸드당 빅블라인드, 휴리스틱 기준)
+    let arena = ArenaConfig::default();
+    let start = Instant::now();
+    let opponent = arena.train_opponent(ARENA_DEALS, ARENA_ITERATIONS);
+    println!("\n상대 CFR 정책 학습: 노드 {}개, {:.1}초", opponent.nodes.len(), start.elapsed().as_secs_f64());
+    for (name, params) in [("classic", &classic), ("찾은 값", &result.params), ("현재 tuned()", &shipped)] {
+        println!("{} 대결 ({}핸드): {:+.1} bb/100", name, arena.hands, arena_bb_per_100(params, &opponent, &arena));
+    }
+}
+
+/// 상대 정책 학습에 쓰는 딜 수와 반복 수
+const ARENA_DEALS: usize = 200;
+const ARENA_ITERATIONS: usize = 20;
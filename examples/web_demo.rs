@@ -24,6 +24,11 @@ fn main() {
         to_call: 50,
         my_stack: 1000,
         opponent_stack: 1000,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     let start_time = std::time::Instant::now();
@@ -48,6 +53,11 @@ fn main() {
         to_call: 75,
         my_stack: 925,
         opponent_stack: 875,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     let start_time = std::time::Instant::now();
@@ -72,6 +82,11 @@ fn main() {
         to_call: 150,
         my_stack: 750,
         opponent_stack: 700,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     let start_time = std::time::Instant::now();
@@ -96,6 +111,11 @@ fn main() {
         to_call: 25,
         my_stack: 975,
         opponent_stack: 950,
+        num_opponents: 1,
+        hero_position: 0,
+        players_behind: 0,
+        opponent_stacks: vec![],
+        dead_money: 0,
     };
     
     let perf_start = std::time::Instant::now();
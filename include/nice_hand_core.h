@@ -0,0 +1,47 @@
+#ifndef NICE_HAND_CORE_H
+#define NICE_HAND_CORE_H
+
+/* cbindgen으로 생성된 파일입니다. 직접 수정하지 마세요. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ * 포커 상황 분석
+ *
+ * `request_json`은 `AnalysisRequest` JSON이고, 반환값은 `PokerAnalysisResponse` JSON
+ * 또는 `{"error": "..."}`입니다. 반환된 문자열은 `nhc_free_string`으로 해제해야 합니다.
+ *
+ * # Safety
+ * `request_json`은 널이거나 널 종료된 유효한 C 문자열을 가리켜야 합니다.
+ */
+char *nhc_analyze_json(const char *request_json);
+
+/*
+ * `nhc_analyze_json`이 반환한 문자열 해제 (널이면 아무것도 하지 않음)
+ *
+ * # Safety
+ * `s`는 널이거나 이 라이브러리가 반환하고 아직 해제하지 않은 문자열이어야 합니다.
+ */
+void nhc_free_string(char *s);
+
+/*
+ * 핸드 스트렝스 (0.0-1.0, `calculate_hand_strength`와 같음)
+ *
+ * `cards`는 홀카드 `n_hole`장, `board`는 보드 `n_board`장(0-5)입니다.
+ * 홀카드가 2장이 아니거나, 포인터가 널이거나, 계산 중 패닉이 나면 NaN을 반환합니다.
+ * 보드가 없으면 `board`는 널이어도 됩니다.
+ *
+ * # Safety
+ * `cards`는 `n_hole`바이트, `board`는 `n_board`바이트를 읽을 수 있어야 합니다.
+ */
+double nhc_hand_strength(const uint8_t *cards, uint8_t n_hole, const uint8_t *board, uint8_t n_board);
+
+/*
+ * 라이브러리 버전 (정적 문자열, 해제하지 마세요)
+ */
+const char *nhc_version(void);
+
+#endif  /* NICE_HAND_CORE_H */
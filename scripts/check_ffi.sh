@@ -0,0 +1,17 @@
+#!/usr/bin/env sh
+# C ABI 확인
+# ffi 기능으로 공유 라이브러리를 빌드하고 examples/ffi/analyze.c를 헤더와 함께 컴파일해 실행합니다.
+# 필요: C 컴파일러 (CC, 기본 cc)
+set -eu
+
+cd "$(dirname "$0")/.."
+
+cargo build --lib --features ffi
+cargo test --lib --features ffi ffi::
+
+out="target/debug/ffi_example"
+"${CC:-cc}" -Wall -Wextra -Werror -Iinclude examples/ffi/analyze.c \
+    -Ltarget/debug -lnice_hand_core -lm -o "$out"
+LD_LIBRARY_PATH="target/debug${LD_LIBRARY_PATH:+:$LD_LIBRARY_PATH}" \
+    DYLD_LIBRARY_PATH="target/debug${DYLD_LIBRARY_PATH:+:$DYLD_LIBRARY_PATH}" \
+    "$out"
@@ -0,0 +1,30 @@
+#!/usr/bin/env sh
+# 최소 구성(서버리스용) 확인
+# 학습 코드 없이 라이브러리가 빌드되는지, 학습 모듈이 실제로 빠졌는지 확인하고 무상태 API 테스트를 돌립니다.
+set -eu
+
+cd "$(dirname "$0")/.."
+
+cargo build --lib --no-default-features --features minimal
+
+# 학습 전용 모듈을 참조하는 코드는 최소 구성에서 컴파일되지 않아야 함
+probe="target/minimal_probe"
+mkdir -p "$probe/src"
+cat > "$probe/Cargo.toml" <<TOML
+[package]
+name = "minimal_probe"
+version = "0.0.0"
+edition = "2021"
+
+[dependencies]
+nice-hand-core = { path = "../..", default-features = false, features = ["minimal"] }
+TOML
+echo 'fn main() { let _: Option<nice_hand_core::solver::mccfr::MCCFRTrainer<nice_hand_core::HoldemState>> = None; }' > "$probe/src/main.rs"
+if cargo build --quiet --manifest-path "$probe/Cargo.toml" 2>/dev/null; then
+    echo "최소 구성에 MCCFR 모듈이 포함되어 있습니다" >&2
+    exit 1
+fi
+echo 'fn main() { let _ = nice_hand_core::api::stateless::StatelessOptions::default(); }' > "$probe/src/main.rs"
+cargo build --quiet --manifest-path "$probe/Cargo.toml"
+
+cargo test --lib --no-default-features --features minimal stateless::
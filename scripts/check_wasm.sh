@@ -0,0 +1,10 @@
+#!/usr/bin/env sh
+# wasm32 빌드 확인
+# 네이티브 전용 의존성(getrandom, rayon, 파일 I/O)이 wasm 빌드에 섞이지 않는지 검사합니다.
+# 필요: rustup target add wasm32-unknown-unknown
+set -eu
+
+cd "$(dirname "$0")/.."
+
+cargo check --lib --target wasm32-unknown-unknown
+cargo check --lib --target wasm32-unknown-unknown --features wasm
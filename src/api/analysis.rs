@@ -1,33 +1,103 @@
 // 포커 분석 API 모듈
 // 게임 상태 검증, EV 계산, 고급 분석 기능 제공
 
-use crate::game::holdem::{Act, State as HoldemState};
-use crate::solver::ev_calculator::{ActionEV, EVCalculator, EVConfig};
-use crate::api::web_api::WebGameState;
+/// 블로커(카드 제거) 효과와 블러프 후보 점수
+pub mod blockers;
+/// 결정 난이도 점수와 다가올 결정 지점 순위
+pub mod complexity;
+/// 런아웃별 에퀴티 분포 히스토그램
+pub mod equity_histogram;
+/// 상대 모델과 GTO 베이스라인 비교 익스플로잇 리포트
+pub mod exploit_report;
+/// 머신러닝용 특징 벡터 추출
+pub mod features;
+/// 좌석 에퀴티(포지션 가치) 시뮬레이션
+pub mod seat_equity;
+
+use crate::api::coaching::{self, ActionAdvice, CoachingInputs, Explanation, Locale, PositionSpot, PositionalAdvice};
+use crate::game::card_abstraction::{showdown_split, PercentileMode};
+use crate::game::hand_eval::classify;
+use crate::game::holdem::{Act, RaiseBounds, RakeModel, State as HoldemState};
+use crate::game::opponent_deal::{estimate_opponent_deal, PreflopLine};
+use crate::game::rng::entropy_rng;
+use crate::solver::budget::ComputeBudget;
+use crate::solver::cfr_core::{Game, GameState};
+use crate::game::value_unit::{ValueContext, ValueUnit};
+use crate::solver::ev_calculator::{
+    ActionEV, EVCalculator, EVConfig, EVDebugInfo, SizingCurve, DEFAULT_SUBTREE_CACHE_SAMPLES,
+};
+use crate::api::web_api::{SeatStatus, StrategyTable, WebGameState};
+use crate::interop::pio::parse_range;
+pub use equity_histogram::{equity_distribution, CardEquity, EquityHistogram};
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
-use std::time::Instant;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// 분석 요청 설정
 #[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AnalysisRequest {
     pub game_state: WebGameState,
     pub options: AnalysisOptions,
+    /// 핸드 시작부터의 액션 히스토리 (반사실 분석용, 선택)
+    #[serde(default)]
+    pub action_history: Vec<Act>,
 }
 
 /// 분석 옵션
 #[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AnalysisOptions {
     /// 분석 깊이 ("quick", "standard", "deep")
     pub depth: String,
-    /// 최대 계산 시간 (밀리초)
+    /// 최대 계산 시간 (밀리초, 메인 EV/반사실 분석/베팅 크기 곡선 전체)
     pub max_calculation_time_ms: Option<u64>,
+    /// 분석 전체에서 쓸 수 있는 최대 시뮬레이션 샘플 수 (없으면 깊이 설정대로)
+    #[serde(default)]
+    pub max_samples: Option<u64>,
     /// 포함할 분석 요소들
     pub include_insights: bool,
     pub include_range_analysis: bool,
     pub include_equity_calculation: bool,
     /// 상대방 모델링 수준
-    pub opponent_modeling: OpponentModel,
+    pub opponent_modeling: OpponentModelKind,
+    /// 액션 히스토리의 각 히어로 결정 지점에 대한 반사실 분석 포함 여부
+    #[serde(default)]
+    pub counterfactuals: bool,
+    /// 시뮬레이션 상대방 액션 추적 등 디버그 정보 포함 여부
+    #[serde(default)]
+    pub debug: bool,
+    /// 캐시 게임 레이크 (없으면 레이크 없는 팟으로 계산)
+    #[serde(default)]
+    pub rake: Option<RakeModel>,
+    /// EV 출력 단위 (기본: 칩)
+    #[serde(default)]
+    pub value_unit: ValueUnit,
+    /// 단위 변환 정보 (BigBlinds는 빅블라인드, 상금 단위는 ICM 정보 필요)
+    #[serde(default)]
+    pub value_context: ValueContext,
+    /// 다음 카드별 에퀴티 분포 계산 설정 (없으면 계산하지 않음)
+    #[serde(default)]
+    pub equity_histogram: Option<EquityHistogramOptions>,
+    /// 최소 레이즈부터 올인까지의 EV-베팅 크기 곡선 포함 여부
+    #[serde(default)]
+    pub include_sizing_curve: bool,
+}
+
+/// 에퀴티 분포 옵션
+#[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EquityHistogramOptions {
+    /// 상대 레인지 (PIO 형식, 예: "AA,KQs:0.5")
+    pub villain_range: String,
+    /// 히스토그램 구간 수
+    #[serde(default = "default_histogram_buckets")]
+    pub buckets: usize,
+}
+
+fn default_histogram_buckets() -> usize {
+    10
 }
 
 impl Default for AnalysisOptions {
@@ -35,17 +105,30 @@ impl Default for AnalysisOptions {
         Self {
             depth: "standard".to_string(),
             max_calculation_time_ms: None,
+            max_samples: None,
             include_insights: true,
             include_range_analysis: false,
             include_equity_calculation: false,
-            opponent_modeling: OpponentModel::Tight,
+            opponent_modeling: OpponentModelKind::Tight,
+            counterfactuals: false,
+            debug: false,
+            rake: None,
+            value_unit: ValueUnit::Chips,
+            value_context: ValueContext::default(),
+            equity_histogram: None,
+            include_sizing_curve: false,
         }
     }
 }
 
 /// 상대방 모델링 타입
+///
+/// JSON 표현: 변형 이름 문자열 (`"Random"`, `"Tight"`, ...)
+///
+/// 플레이어별 통계를 쌓는 `tournament::OpponentModel`과 다른 타입입니다.
 #[derive(Debug, Deserialize, Clone)]
-pub enum OpponentModel {
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum OpponentModelKind {
     /// 완전 랜덤 상대
     Random,
     /// 기본 TAG 스타일
@@ -56,22 +139,72 @@ pub enum OpponentModel {
     Custom,
 }
 
+/// 이전 이름 (`tournament::OpponentModel`과 이름이 겹쳐 변경됨)
+#[deprecated(since = "0.1.0", note = "`OpponentModelKind`를 사용하세요")]
+pub type OpponentModel = OpponentModelKind;
+
 /// 포괄적인 분석 응답
 #[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PokerAnalysisResponse {
     /// 기본 EV 분석
     pub ev_analysis: EVAnalysisResponse,
     /// 추가 인사이트
     pub insights: Option<AnalysisInsights>,
+    /// 과거 결정 지점별 반사실 분석 (요청 시에만)
+    pub counterfactuals: Option<Vec<CounterfactualPoint>>,
+    /// EV 시뮬레이션 디버그 정보 (요청 시에만 직렬화)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debug: Option<EVDebugInfo>,
+    /// 다음 카드별 에퀴티 분포 (요청 시에만 직렬화)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equity_histogram: Option<EquityHistogram>,
+    /// 액션할 플레이어의 레이즈 가능 범위 (레이즈할 수 없으면 None)
+    pub raise_bounds: Option<RaiseBounds>,
+    /// EV-베팅 크기 곡선 (요청 시에만 직렬화)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sizing_curve: Option<SizingCurve>,
     /// 메타데이터
     pub metadata: AnalysisMetadata,
 }
 
+/// EV-베팅 크기 곡선의 점 수
+pub const SIZING_CURVE_POINTS: usize = 20;
+
+/// 반사실 분석에서 검토할 최대 결정 지점 수 (응답 지연 제한)
+pub const MAX_COUNTERFACTUAL_POINTS: usize = 6;
+
+/// 과거 히어로 결정 지점의 반사실 분석 결과
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CounterfactualPoint {
+    /// 결정이 일어난 스트리트
+    pub street: u8,
+    /// 실제로 선택한 액션
+    pub taken: Act,
+    /// 실제 액션의 EV (`EVAnalysisResponse::unit` 단위)
+    pub taken_ev: f64,
+    /// 해당 시점의 최고 EV 액션
+    pub best: Act,
+    /// 최고 EV 액션의 EV
+    pub best_ev: f64,
+}
+
+impl CounterfactualPoint {
+    /// 실제 선택으로 잃은 EV (0 이상)
+    pub fn ev_loss(&self) -> f64 {
+        (self.best_ev - self.taken_ev).max(0.0)
+    }
+}
+
 /// EV 분석 결과
 #[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct EVAnalysisResponse {
     /// 각 액션별 EV 및 신뢰도 정보
     pub action_evs: Vec<ActionEV>,
+    /// EV 값들의 단위 (반사실 분석 EV도 같은 단위)
+    pub unit: ValueUnit,
     /// 사용된 분석 타입 ("quick" 또는 "detailed")
     pub analysis_type: String,
     /// 변환 과정이나 결과에 대한 추가 정보
@@ -80,21 +213,31 @@ pub struct EVAnalysisResponse {
 
 /// 분석 인사이트
 #[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AnalysisInsights {
     /// 추천 액션 (가장 높은 EV)
     pub recommended_action: Act,
     /// 각 액션의 상대적 강도 (0-100)
     pub action_strength: HashMap<String, f32>,
-    /// 포지션별 조언
+    /// 포지션별 조언 (`coaching::render_positional`로 렌더링한 문장)
     pub positional_advice: Option<String>,
     /// 리스크 평가
     pub risk_assessment: RiskLevel,
     /// 핸드 스트렝스 점수
     pub hand_strength: f64,
+    /// 추천 액션의 구조화된 설명 (`coaching::render`로 렌더링)
+    pub explanation: Explanation,
+    /// 찹 가능성 안내 (`coaching::render_chop`, 현재 보드에서 무작위 상대 핸드와 비기는
+    /// 비율이 `CHOP_NOTE_THRESHOLD` 이상일 때만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chop_note: Option<String>,
 }
 
 /// 리스크 레벨
+///
+/// JSON 표현: 변형 이름 문자열 (`"Low"`, `"Medium"`, `"High"`, `"Extreme"`)
 #[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum RiskLevel {
     Low,
     Medium, 
@@ -104,12 +247,17 @@ pub enum RiskLevel {
 
 /// 분석 메타데이터
 #[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AnalysisMetadata {
     pub calculation_time_ms: u64,
     pub analysis_depth: String,
     pub confidence_level: f32,
     pub limitations: Vec<String>,
     pub game_state_valid: bool,
+    /// 시간/샘플 예산이 끝나 일부 계산을 목표보다 적은 샘플로 마쳤는지
+    pub truncated: bool,
+    /// 분석 전체에서 쓴 시뮬레이션 샘플 수
+    pub samples_used: u64,
 }
 
 /// 상태 검증 에러
@@ -145,6 +293,8 @@ pub enum AnalysisError {
     CalculationTimeout,
     InsufficientData,
     InternalError { message: String },
+    /// 요청한 EV 단위로 변환할 정보가 부족함
+    UnsupportedValueUnit { unit: ValueUnit, reason: String },
 }
 
 impl std::fmt::Display for AnalysisError {
@@ -154,6 +304,9 @@ impl std::fmt::Display for AnalysisError {
             Self::CalculationTimeout => write!(f, "계산 시간이 초과되었습니다"),
             Self::InsufficientData => write!(f, "분석에 필요한 데이터가 부족합니다"),
             Self::InternalError { message } => write!(f, "내부 오류: {}", message),
+            Self::UnsupportedValueUnit { unit, reason } => {
+                write!(f, "{:?} 단위로 EV를 계산할 수 없습니다: {}", unit, reason)
+            }
         }
     }
 }
@@ -168,6 +321,9 @@ pub struct HoldemStateBuilder {
     pot: Option<i32>,
     to_act: Option<usize>,
     hole_cards: Option<Vec<[u8; 2]>>,
+    seat_status: Vec<SeatStatus>,
+    posted: Vec<u32>,
+    straddle: u32,
 }
 
 impl HoldemStateBuilder {
@@ -179,6 +335,9 @@ impl HoldemStateBuilder {
             pot: None,
             to_act: None,
             hole_cards: None,
+            seat_status: Vec::new(),
+            posted: Vec::new(),
+            straddle: 0,
         }
     }
     
@@ -194,12 +353,18 @@ impl HoldemStateBuilder {
         
         // 보드 카드 검증
         builder = builder.validate_board(&web_state.board)?;
+
+        // 히어로 홀 카드 검증
+        builder = builder.validate_hole_cards(web_state.hole_cards, &web_state.board)?;
         
         // 팟 검증
         builder = builder.validate_pot(web_state.pot)?;
         
         // 포지션 검증
         builder = builder.validate_position(web_state.player_to_act, web_state.stacks.len())?;
+
+        // 좌석 상태와 이미 낸 칩 검증
+        builder = builder.validate_seats(web_state)?;
         
         // 홀 카드 설정
         builder = builder.set_hole_cards_from_web(web_state);
@@ -241,6 +406,20 @@ impl HoldemStateBuilder {
         Ok(self)
     }
     
+    fn validate_hole_cards(self, hole_cards: [u8; 2], board: &[u8]) -> Result<Self, ValidationError> {
+        for &card in &hole_cards {
+            if card >= 52 {
+                return Err(ValidationError::InvalidCard(card));
+            }
+        }
+
+        if hole_cards[0] == hole_cards[1] || board.iter().any(|c| hole_cards.contains(c)) {
+            return Err(ValidationError::InconsistentState("홀 카드가 중복되었습니다".to_string()));
+        }
+
+        Ok(self)
+    }
+
     fn validate_pot(mut self, pot: u32) -> Result<Self, ValidationError> {
         self.pot = Some(pot as i32);
         Ok(self)
@@ -254,6 +433,29 @@ impl HoldemStateBuilder {
         Ok(self)
     }
     
+    fn validate_seats(mut self, web_state: &WebGameState) -> Result<Self, ValidationError> {
+        let player_count = web_state.stacks.len();
+        if !web_state.seat_status.is_empty() && web_state.seat_status.len() != player_count {
+            return Err(ValidationError::InconsistentState("좌석 상태 수가 스택 수와 다릅니다".to_string()));
+        }
+        if !web_state.posted.is_empty() && web_state.posted.len() != player_count {
+            return Err(ValidationError::InconsistentState("이미 낸 칩의 좌석 수가 스택 수와 다릅니다".to_string()));
+        }
+
+        let status = |seat: usize| web_state.seat_status.get(seat).copied().unwrap_or_default();
+        if status(web_state.player_to_act) != SeatStatus::Live {
+            return Err(ValidationError::InconsistentState("액션할 플레이어가 폴드했거나 올인했습니다".to_string()));
+        }
+        if (0..player_count).filter(|&seat| status(seat) != SeatStatus::Folded).count() < 2 {
+            return Err(ValidationError::InconsistentState("살아 있는 플레이어가 2명 미만입니다".to_string()));
+        }
+
+        self.seat_status = web_state.seat_status.clone();
+        self.posted = web_state.posted.clone();
+        self.straddle = web_state.straddle.unwrap_or(0);
+        Ok(self)
+    }
+
     fn set_hole_cards_from_web(mut self, web_state: &WebGameState) -> Self {
         // 현재는 hero의 홀 카드만 알고 있고, 나머지는 기본값 사용
         let mut hole_cards = Vec::new();
@@ -303,8 +505,44 @@ impl HoldemStateBuilder {
             num_players,
         );
         
-        // 상태 설정
-        state.pot = pot as u32;
+        // 이미 낸 칩이 주어지면 기본 블라인드 대신 사용 (프리플랍만)
+        if street == 0 && !self.posted.is_empty() {
+            for (seat, &posted) in self.posted.iter().enumerate().take(6) {
+                let stack = stacks_array[seat];
+                state.stack[seat] = stack - posted.min(stack);
+                state.invested[seat] = posted.min(stack);
+                state.antes[seat] = 0;
+            }
+            state.to_call = state.invested.iter().copied().max().unwrap_or(0);
+        }
+
+        // 스트래들은 핸드 내내 전략 키에 남고, 프리플랍에서는 최소 레이즈 증가분이 스트래들 금액
+        state.straddle = self.straddle;
+        if street == 0 {
+            state.last_raise = state.last_raise.max(self.straddle);
+        }
+
+        // 폴드한 좌석은 죽은 좌석으로, 낸 칩은 데드 머니(`antes`)로 팟에 남김
+        for (seat, &status) in self.seat_status.iter().enumerate().take(6) {
+            match status {
+                SeatStatus::Live => {}
+                SeatStatus::Folded => {
+                    state.alive[seat] = false;
+                    state.antes[seat] += state.invested[seat];
+                    state.invested[seat] = 0;
+                }
+                SeatStatus::AllIn => {
+                    state.invested[seat] += state.stack[seat];
+                    state.stack[seat] = 0;
+                }
+            }
+        }
+        let live_investment = (0..6).filter(|&seat| state.alive[seat]).map(|seat| state.invested[seat]).max();
+        state.to_call = state.to_call.max(live_investment.unwrap_or(0));
+
+        // 상태 설정 (팟은 적어도 이미 들어간 칩 전부)
+        let committed: u32 = state.invested.iter().chain(&state.antes).sum();
+        state.pot = (pot as u32).max(committed);
         state.board = board;
         state.to_act = to_act;
         state.street = street;
@@ -324,10 +562,26 @@ impl HoldemStateBuilder {
 pub fn analyze_poker_state(request: AnalysisRequest) -> AnalysisResult {
     let start_time = Instant::now();
     let mut limitations = Vec::new();
+    // 모든 하위 계산이 나눠 쓰는 예산 (요청 시각 기준 마감)
+    let budget = ComputeBudget::new(
+        request.options.max_calculation_time_ms.map(|ms| start_time + Duration::from_millis(ms)),
+        request.options.max_samples,
+        None,
+    );
+    // 메인 EV 계산 뒤에 예산을 나눠 쓸 추가 계산 수
+    let mut extra_calculations = request.options.counterfactuals as usize + request.options.include_sizing_curve as usize;
     
     // 1. 상태 변환 및 검증
+    // 히어로 외 홀카드는 모르므로 시뮬레이션마다 히스토리의 프리플랍 라인에서 추정한 레인지로 딜링
     let internal_state = match HoldemStateBuilder::from_web_state(&request.game_state) {
-        Ok(state) => state,
+        Ok(state) => {
+            let lines = PreflopLine::replay(&history_root(&request.game_state, &state), &request.action_history);
+            HoldemState {
+                rake: request.options.rake,
+                opponent_deal: Some(Arc::new(estimate_opponent_deal(&lines, request.game_state.hero_position))),
+                ..state
+            }
+        }
         Err(e) => return Err(AnalysisError::InvalidGameState { 
             reason: e.to_string() 
         }),
@@ -339,19 +593,33 @@ pub fn analyze_poker_state(request: AnalysisRequest) -> AnalysisResult {
             sample_count: 1000,
             max_depth: 5,
             use_opponent_model: false,
+            ..EVConfig::default()
         },
         "standard" => EVConfig::default(),
         "deep" => EVConfig {
             sample_count: 50000,
             max_depth: 15,
             use_opponent_model: true,
+            subtree_cache: Some(DEFAULT_SUBTREE_CACHE_SAMPLES),
+            ..EVConfig::default()
         },
         _ => EVConfig::default(),
     };
     
-    // 3. EV 계산 수행
-    let calculator = EVCalculator::new(ev_config);
-    let action_evs = calculator.calculate_action_evs(&internal_state);
+    // 3. EV 계산 수행 (단위 문맥이 부족하면 에러)
+    let calculator = EVCalculator::try_new(EVConfig {
+        debug_trace: request.options.debug,
+        value_unit: request.options.value_unit,
+        value_context: request.options.value_context.clone(),
+        ..ev_config
+    })
+    .map_err(|e| AnalysisError::UnsupportedValueUnit {
+        unit: request.options.value_unit,
+        reason: e.to_string(),
+    })?;
+    let calculation =
+        calculator.calculate_action_evs_within(&internal_state, &budget.child(1.0 / (1 + extra_calculations) as f64));
+    let action_evs = calculation.action_evs;
     
     if action_evs.is_empty() {
         limitations.push("유효한 액션이 없습니다".to_string());
@@ -363,12 +631,66 @@ pub fn analyze_poker_state(request: AnalysisRequest) -> AnalysisResult {
     } else {
         None
     };
+
+    // 5. 반사실 분석 (히스토리가 주어진 경우)
+    let counterfactuals = if request.options.counterfactuals {
+        let share = 1.0 / extra_calculations as f64;
+        extra_calculations -= 1;
+        Some(analyze_counterfactuals(
+            &request.game_state,
+            &internal_state,
+            &request.action_history,
+            &request.options,
+            &budget.child(share),
+            &mut limitations,
+        ))
+    } else {
+        None
+    };
+
+    // 6. 에퀴티 분포 (플랍/턴에서만)
+    let equity_histogram = request.options.equity_histogram.as_ref().and_then(|options| {
+        let board = &internal_state.board;
+        if !(3..=4).contains(&board.len()) {
+            limitations.push("에퀴티 분포는 플랍과 턴에서만 계산됩니다".to_string());
+            return None;
+        }
+        let range = parse_range(&options.villain_range);
+        let hole = internal_state.hole[request.game_state.hero_position];
+        Some(equity_distribution(hole, board, &range, options.buckets))
+    });
+
+    // 7. 베팅 슬라이더용 레이즈 범위 (요청의 스트리트 투자액 기준)
+    let raise_bounds = StrategyTable::web_to_observed_state(&request.game_state)
+        .to_state()
+        .raise_bounds();
+
+    // 8. EV-베팅 크기 곡선 (히어로 차례이고 레이즈할 수 있을 때만)
+    let sizing_curve = if request.options.include_sizing_curve {
+        let curve = calculator.sizing_curve_within(
+            &internal_state,
+            request.game_state.hero_position,
+            SIZING_CURVE_POINTS,
+            &budget.child(1.0 / extra_calculations as f64),
+        );
+        if curve.is_none() {
+            limitations.push("히어로가 레이즈할 수 없는 상황이라 베팅 크기 곡선을 계산하지 않았습니다".to_string());
+        }
+        curve
+    } else {
+        None
+    };
     
-    // 5. 응답 구성
+    // 9. 응답 구성
     let calculation_time = start_time.elapsed().as_millis() as u64;
+    let truncated = budget.exhausted().is_some();
+    if truncated {
+        limitations.push("계산 예산이 끝나 일부 EV는 목표보다 적은 샘플로 계산되었습니다".to_string());
+    }
     
     let ev_analysis = EVAnalysisResponse {
         action_evs,
+        unit: request.options.value_unit,
         analysis_type: request.options.depth.clone(),
         notes: Some("상태 변환이 완전히 구현되지 않아 일부 정보가 기본값으로 설정됩니다".to_string()),
     };
@@ -379,70 +701,283 @@ pub fn analyze_poker_state(request: AnalysisRequest) -> AnalysisResult {
         confidence_level: if limitations.is_empty() { 0.8 } else { 0.6 },
         limitations,
         game_state_valid: true,
+        truncated,
+        samples_used: budget.samples_used(),
     };
     
     Ok(PokerAnalysisResponse {
         ev_analysis,
         insights,
+        counterfactuals,
+        debug: calculation.debug,
+        equity_histogram,
+        raise_bounds,
+        sizing_curve,
         metadata,
     })
 }
 
+/// 액션 히스토리를 재생할 핸드 시작 상태 (빌더와 같은 기본 블라인드, 현재 상태의 홀카드와 레이크)
+fn history_root(web_state: &WebGameState, current_state: &HoldemState) -> HoldemState {
+    let blinds = [10, 20];
+    let mut stacks = [0u32; 6];
+    for (i, &stack) in web_state.stacks.iter().enumerate().take(6) {
+        stacks[i] = stack;
+    }
+    let mut state = HoldemState::new_hand(blinds, stacks, web_state.stacks.len());
+    state.hole = current_state.hole;
+    state.rake = current_state.rake;
+    state
+}
+
+/// 반사실 분석 - 핸드를 처음부터 재구성하며 히어로의 각 결정을 재평가
+///
+/// 히스토리를 프리플랍 시작 상태부터 재생하고, 히어로 차례마다
+/// 실제 액션의 EV와 최고 EV 액션을 비교합니다. 보드 카드는 요청의
+/// 보드를 그대로 사용하며, 알려지지 않은 카드는 랜덤으로 채웁니다.
+/// 결정 지점마다 `budget`의 남은 몫을 남은 지점 수로 나눠 씁니다.
+fn analyze_counterfactuals(
+    web_state: &WebGameState,
+    current_state: &HoldemState,
+    history: &[Act],
+    options: &AnalysisOptions,
+    budget: &ComputeBudget,
+    limitations: &mut Vec<String>,
+) -> Vec<CounterfactualPoint> {
+    let mut points = Vec::new();
+    let hero = web_state.hero_position;
+    let mut state = history_root(web_state, current_state);
+
+    // 반사실 지점은 빠른 설정으로 계산 (단위 문맥은 메인 분석에서 이미 검증됨)
+    let calculator = EVCalculator::new(EVConfig {
+        sample_count: 1000,
+        max_depth: 5,
+        use_opponent_model: false,
+        value_unit: options.value_unit,
+        value_context: options.value_context.clone(),
+        ..EVConfig::default()
+    });
+    let mut rng = entropy_rng();
+
+    for &taken in history {
+        // 스트리트가 넘어가면 실제 보드 카드로 딜링
+        while state.is_chance_node() {
+            let dealt = state.board.len();
+            let mut next = HoldemState::apply_chance(&state, &mut rng);
+            for i in dealt..next.board.len() {
+                if let Some(&card) = web_state.board.get(i) {
+                    next.board[i] = card;
+                }
+            }
+            state = next;
+        }
+
+        if state.is_terminal() || HoldemState::current_player(&state).is_none() {
+            limitations.push("액션 히스토리가 핸드 종료 이후까지 이어집니다".to_string());
+            break;
+        }
+
+        let legal_actions = HoldemState::legal_actions(&state);
+        if !legal_actions.contains(&taken) {
+            limitations.push(format!(
+                "재구성한 상태에서 {:?} 액션이 불가능하여 반사실 분석을 중단했습니다",
+                taken
+            ));
+            break;
+        }
+
+        if state.to_act == hero {
+            if points.len() >= MAX_COUNTERFACTUAL_POINTS {
+                limitations.push(format!(
+                    "반사실 분석은 최대 {}개 결정 지점까지만 수행됩니다",
+                    MAX_COUNTERFACTUAL_POINTS
+                ));
+                break;
+            }
+
+            let share = 1.0 / (MAX_COUNTERFACTUAL_POINTS - points.len()) as f64;
+            let action_evs = calculator.calculate_action_evs_within(&state, &budget.child(share)).action_evs;
+            let taken_ev = action_evs
+                .iter()
+                .find(|a| a.action == taken)
+                .map(|a| a.ev)
+                .unwrap_or(0.0);
+            // calculate_action_evs는 EV 내림차순으로 정렬됨
+            let (best, best_ev) = action_evs
+                .first()
+                .map(|a| (a.action, a.ev))
+                .unwrap_or((taken, taken_ev));
+
+            points.push(CounterfactualPoint {
+                street: state.street,
+                taken,
+                taken_ev,
+                best,
+                best_ev,
+            });
+        }
+
+        state = HoldemState::next_state(&state, taken);
+    }
+
+    points
+}
+
+/// 액션 강도 정규화의 최소 EV 폭 (액션 표준오차 최댓값의 배수)
+///
+/// EV 차이가 추정 오차보다 작을 때 잡음만으로 0과 100이 갈리지 않도록, 정규화 폭을
+/// 이 값 이상으로 두어 강도가 50 주변으로 모이게 합니다.
+const STRENGTH_SPREAD_FLOOR_SE: f64 = 2.0;
+/// 두 액션의 EV 신뢰구간(95%)에 쓰는 z값
+const CONFIDENCE_Z: f64 = 1.96;
+/// 스택 대비 이 비율을 넘게 거는 액션은 콜 오프로 취급
+const CALL_OFF_FRACTION: f64 = 0.7;
+/// 스택 대비 이 비율을 넘게 거는 액션은 큰 금액으로 취급
+const LARGE_COMMIT_FRACTION: f64 = 0.3;
+/// 이 값보다 핸드 스트렝스가 낮으면 애매한 핸드로 취급
+const MARGINAL_HAND_STRENGTH: f64 = 0.6;
+/// 뒤에 이 인원 이상 남으면 얼리 포지션으로 취급
+const EARLY_POSITION_PLAYERS_BEHIND: usize = 3;
+/// 무작위 상대 핸드와 비기는 비율이 이 값 이상이면 인사이트에 찹 가능성을 알림
+pub const CHOP_NOTE_THRESHOLD: f64 = 0.1;
+
 /// 인사이트 생성
-fn generate_insights(action_evs: &[ActionEV], state: &HoldemState, _options: &AnalysisOptions) -> AnalysisInsights {
+fn generate_insights(action_evs: &[ActionEV], state: &HoldemState, options: &AnalysisOptions) -> AnalysisInsights {
     // 최고 EV 액션 찾기
     let best_action = action_evs.iter()
         .max_by(|a, b| a.ev.partial_cmp(&b.ev).unwrap_or(std::cmp::Ordering::Equal))
         .map(|a| a.action)
         .unwrap_or(Act::Fold);
-    
-    // 액션 강도 계산
-    let mut action_strength = HashMap::new();
-    let max_ev = action_evs.iter().map(|a| a.ev).fold(f64::NEG_INFINITY, f64::max);
-    let min_ev = action_evs.iter().map(|a| a.ev).fold(f64::INFINITY, f64::min);
-    
-    for action_ev in action_evs {
-        let normalized = if max_ev == min_ev {
-            50.0 // 모든 액션이 같은 EV면 중간값
-        } else {
-            ((action_ev.ev - min_ev) / (max_ev - min_ev) * 100.0).max(0.0).min(100.0)
-        };
-        action_strength.insert(format!("{:?}", action_ev.action), normalized as f32);
-    }
-    
+
     // 핸드 스트렝스 계산 (현재 플레이어 기준)
-    let current_player = state.to_act;
-    let hole_cards = state.hole[current_player];
+    let hero = state.to_act;
+    let hole_cards = state.hole[hero];
     let hand_strength = crate::game::card_abstraction::hand_strength(hole_cards, &state.board);
-    
-    // 리스크 평가
-    let risk_assessment = if hand_strength > 0.8 {
-        RiskLevel::Low
-    } else if hand_strength > 0.6 {
-        RiskLevel::Medium
-    } else if hand_strength > 0.3 {
-        RiskLevel::High
-    } else {
-        RiskLevel::Extreme
+
+    let to_call = state.to_call.saturating_sub(state.invested[hero]);
+    let best_ev = |aggressive: bool| {
+        action_evs
+            .iter()
+            .filter(|a| matches!(a.action, Act::Raise(_)) == aggressive && a.ev.is_finite())
+            .map(|a| a.ev)
+            .reduce(f64::max)
     };
-    
-    // 포지션별 조언
-    let positional_advice = match current_player {
-        0..=1 => Some("얼리 포지션: 보수적인 플레이를 권장합니다".to_string()),
-        2..=3 => Some("미들 포지션: 표준적인 전략을 사용하세요".to_string()),
-        4..=5 => Some("레이트 포지션: 더 공격적으로 플레이할 수 있습니다".to_string()),
-        _ => None,
+    let inputs = CoachingInputs {
+        hand_strength,
+        required_equity: required_equity(state),
+        players_behind: state.players_behind(hero),
+        spr: state.stack[hero] as f64 / state.pot.max(1) as f64,
+        fold_equity: best_ev(true).zip(best_ev(false)).map(|(raise, passive)| raise - passive),
+        icm_pressure: coaching::icm_pressure(&options.value_context),
+        made_hand: (!state.board.is_empty())
+            .then(|| classify(&[&hole_cards[..], &state.board].concat())),
     };
-    
+
     AnalysisInsights {
         recommended_action: best_action,
-        action_strength,
-        positional_advice,
-        risk_assessment,
+        action_strength: action_strengths(action_evs),
+        positional_advice: positional_advice(state, hand_strength)
+            .map(|advice| coaching::render_positional(&advice, Locale::default())),
+        risk_assessment: assess_risk(action_evs, state),
         hand_strength,
+        explanation: coaching::explain(&inputs, ActionAdvice::from_act(best_action, to_call > 0)),
+        chop_note: showdown_split(hole_cards, &state.board, PercentileMode::Exact)
+            .map(|(_, tie, _)| tie)
+            .filter(|&tie| tie >= CHOP_NOTE_THRESHOLD)
+            .map(|tie| coaching::render_chop(tie, Locale::default())),
     }
 }
 
+/// 콜에 필요한 에퀴티 (폴드한 좌석의 데드 머니 포함 팟 기준, 콜할 금액이 없으면 None)
+fn required_equity(state: &HoldemState) -> Option<f64> {
+    let to_call = state.to_call.saturating_sub(state.invested[state.to_act]);
+    (to_call > 0).then(|| to_call as f64 / (state.pot as f64 + to_call as f64))
+}
+
+/// 액션별 상대 강도 (0-100)
+///
+/// 액션 집합의 최소-최대 EV로 정규화하므로 모든 EV가 음수여도 최고 EV 액션이 높게 나옵니다.
+/// 정규화 폭은 `STRENGTH_SPREAD_FLOOR_SE` × 최대 표준오차 이상이며, 폭이 넓어지면 강도는
+/// 50을 중심으로 줄어듭니다. 모든 EV가 같으면 50, 유한하지 않은 EV는 0입니다.
+fn action_strengths(action_evs: &[ActionEV]) -> HashMap<String, f32> {
+    let finite = || action_evs.iter().filter(|a| a.ev.is_finite());
+    let max_ev = finite().map(|a| a.ev).fold(f64::NEG_INFINITY, f64::max);
+    let min_ev = finite().map(|a| a.ev).fold(f64::INFINITY, f64::min);
+    let noise = finite().map(|a| a.std_error).filter(|e| e.is_finite()).fold(0.0, f64::max);
+    let spread = (max_ev - min_ev).max(STRENGTH_SPREAD_FLOOR_SE * noise);
+    let mid = (max_ev + min_ev) / 2.0;
+
+    action_evs
+        .iter()
+        .map(|action_ev| {
+            let strength = if !action_ev.ev.is_finite() {
+                0.0
+            } else if spread <= f64::EPSILON {
+                50.0
+            } else {
+                (50.0 + 100.0 * (action_ev.ev - mid) / spread).clamp(0.0, 100.0)
+            };
+            (format!("{:?}", action_ev.action), strength as f32)
+        })
+        .collect()
+}
+
+/// 최고 EV 액션의 리스크 평가
+///
+/// 최고 액션이 거는 칩의 스택 대비 비율과, 두 번째 액션과의 EV 신뢰구간이 겹치는지(표준오차 기준
+/// 으로 어느 쪽이 나은지 확신할 수 없는지)를 함께 봅니다. 스택의 70% 넘게 걸면서 신뢰구간이
+/// 겹치면 `Extreme`, 둘 중 하나만 심하면 `High`입니다.
+fn assess_risk(action_evs: &[ActionEV], state: &HoldemState) -> RiskLevel {
+    let mut ranked: Vec<&ActionEV> = action_evs.iter().filter(|a| a.ev.is_finite()).collect();
+    ranked.sort_by(|a, b| b.ev.total_cmp(&a.ev));
+    let Some(best) = ranked.first() else {
+        return RiskLevel::High;
+    };
+
+    let hero = state.to_act;
+    let stack = state.stack[hero];
+    let committed = stack.saturating_sub(HoldemState::next_state(state, best.action).stack[hero]);
+    let fraction = committed as f64 / stack.max(1) as f64;
+
+    let overlapping = ranked
+        .get(1)
+        .is_some_and(|second| best.ev - second.ev <= CONFIDENCE_Z * (best.std_error + second.std_error));
+
+    match (fraction > CALL_OFF_FRACTION, fraction > LARGE_COMMIT_FRACTION, overlapping) {
+        (true, _, true) => RiskLevel::Extreme,
+        (true, _, false) | (false, true, true) => RiskLevel::High,
+        (false, true, false) | (false, false, true) => RiskLevel::Medium,
+        (false, false, false) => RiskLevel::Low,
+    }
+}
+
+/// 재구성한 상태의 좌석, 뒤에 남은 인원, 스트리트로 고른 포지션 조언
+fn positional_advice(state: &HoldemState, hand_strength: f64) -> Option<PositionalAdvice> {
+    let hero = state.to_act;
+    if !state.alive[hero] {
+        return None;
+    }
+
+    let behind = state.players_behind(hero);
+    let checked_to = state.street > 0 && state.to_call <= state.invested[hero];
+    let marginal = hand_strength < MARGINAL_HAND_STRENGTH;
+
+    let spot = match state.seat_label(hero) {
+        Some("BTN") if checked_to => PositionSpot::ButtonCheckedTo,
+        Some("BTN") => PositionSpot::Button,
+        _ if behind == 0 && checked_to => PositionSpot::LastToActCheckedTo,
+        _ if behind >= EARLY_POSITION_PLAYERS_BEHIND && marginal => PositionSpot::EarlyMarginal,
+        _ if behind >= EARLY_POSITION_PLAYERS_BEHIND => PositionSpot::EarlyStrong,
+        Some(blind) if state.street == 0 => PositionSpot::Blind(blind.to_string()),
+        _ => PositionSpot::Standard,
+    };
+    Some(PositionalAdvice {
+        street: state.street,
+        players_behind: behind,
+        spot,
+    })
+}
+
 /// 온디맨드 EV 분석 (기존 함수 유지)
 pub fn get_on_demand_ev_analysis(
     web_state: &WebGameState,
@@ -457,6 +992,7 @@ pub fn get_on_demand_ev_analysis(
             include_equity_calculation: false,
             ..Default::default()
         },
+        action_history: Vec::new(),
     };
     
     match analyze_poker_state(analysis_request) {
@@ -464,3 +1000,476 @@ pub fn get_on_demand_ev_analysis(
         Err(e) => Err(e.to_string()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heads_up_state(hole_cards: [u8; 2]) -> WebGameState {
+        WebGameState {
+            hole_cards,
+            board: vec![],
+            street: 0,
+            pot: 30,
+            stacks: vec![1000, 1000],
+            alive_players: vec![0, 1],
+            street_investments: vec![10, 20],
+            to_call: 20,
+            player_to_act: 0,
+            hero_position: 0,
+            betting_history: vec![],
+            seat_status: vec![],
+            posted: vec![],
+            straddle: None,
+        }
+    }
+
+    #[test]
+    fn test_sizing_curve_behind_flag() {
+        let request = |include_sizing_curve| AnalysisRequest {
+            game_state: heads_up_state([0, 13]),
+            options: AnalysisOptions {
+                depth: "quick".to_string(),
+                include_insights: false,
+                include_sizing_curve,
+                ..Default::default()
+            },
+            action_history: vec![],
+        };
+
+        assert!(analyze_poker_state(request(false)).unwrap().sizing_curve.is_none());
+        let curve = analyze_poker_state(request(true)).unwrap().sizing_curve.unwrap();
+        assert_eq!(curve.points.len(), SIZING_CURVE_POINTS);
+        assert!(curve.points.contains(&curve.best));
+    }
+
+    #[test]
+    fn test_counterfactual_open_fold_aces() {
+        let request = AnalysisRequest {
+            game_state: heads_up_state([0, 13]), // AA
+            options: AnalysisOptions {
+                depth: "quick".to_string(),
+                include_insights: false,
+                counterfactuals: true,
+                ..Default::default()
+            },
+            action_history: vec![Act::Fold],
+        };
+
+        let response = analyze_poker_state(request).unwrap();
+        let points = response.counterfactuals.unwrap();
+
+        assert_eq!(points.len(), 1);
+        let point = &points[0];
+        assert_eq!(point.street, 0);
+        assert_eq!(point.taken, Act::Fold);
+        assert_ne!(point.best, Act::Fold);
+        assert!(
+            point.ev_loss() > 10.0,
+            "AA 오픈 폴드는 큰 EV 손실이어야 함: {:?}",
+            point
+        );
+    }
+
+    #[test]
+    fn test_nested_analysis_shares_one_budget() {
+        let request = |max_calculation_time_ms, max_samples| AnalysisRequest {
+            game_state: heads_up_state([0, 13]),
+            options: AnalysisOptions {
+                depth: "deep".to_string(),
+                max_calculation_time_ms,
+                max_samples,
+                include_insights: false,
+                counterfactuals: true,
+                include_sizing_curve: true,
+                ..Default::default()
+            },
+            action_history: vec![Act::Fold],
+        };
+
+        // 메인 EV, 반사실 분석, 베팅 크기 곡선이 100ms 하나를 나눠 씀
+        let start = Instant::now();
+        let response = analyze_poker_state(request(Some(100), None)).unwrap();
+        let elapsed = start.elapsed();
+        assert!(elapsed < Duration::from_millis(1_000), "{:?}", elapsed);
+        assert!(response.metadata.truncated);
+        assert!(response.sizing_curve.is_some());
+        assert_eq!(response.counterfactuals.unwrap().len(), 1);
+
+        let response = analyze_poker_state(request(None, Some(2_000))).unwrap();
+        assert!(response.metadata.truncated);
+        assert!(response.metadata.samples_used <= 2_000, "{}", response.metadata.samples_used);
+        assert!(response.metadata.samples_used > 1_000, "{}", response.metadata.samples_used);
+        assert!(response.ev_analysis.action_evs.iter().all(|a| a.ev.is_finite()));
+    }
+
+    #[test]
+    fn test_counterfactuals_disabled_by_default() {
+        let request = AnalysisRequest {
+            game_state: heads_up_state([0, 13]),
+            options: AnalysisOptions {
+                depth: "quick".to_string(),
+                ..Default::default()
+            },
+            action_history: vec![Act::Fold],
+        };
+
+        let response = analyze_poker_state(request).unwrap();
+        assert!(response.counterfactuals.is_none());
+        assert!(response.debug.is_none());
+    }
+
+    #[test]
+    fn test_rake_option_deserializes() {
+        let options: AnalysisOptions = serde_json::from_str(
+            r#"{
+                "depth": "quick",
+                "include_insights": false,
+                "include_range_analysis": false,
+                "include_equity_calculation": false,
+                "opponent_modeling": "Tight",
+                "rake": {"percentage": 0.05, "cap": 3, "no_flop_no_drop": true}
+            }"#,
+        )
+        .unwrap();
+        let rake = options.rake.expect("레이크 옵션이 있어야 함");
+        assert_eq!(rake.cap, 3);
+        assert_eq!(rake.rake(100, true), 3);
+
+        let request = AnalysisRequest {
+            game_state: heads_up_state([0, 13]),
+            options,
+            action_history: Vec::new(),
+        };
+        assert!(analyze_poker_state(request).is_ok());
+    }
+
+    #[test]
+    fn test_debug_info_only_when_requested() {
+        let request = AnalysisRequest {
+            game_state: heads_up_state([0, 13]),
+            options: AnalysisOptions {
+                depth: "quick".to_string(),
+                debug: true,
+                ..Default::default()
+            },
+            action_history: Vec::new(),
+        };
+
+        let response = analyze_poker_state(request).unwrap();
+        let debug = response.debug.as_ref().expect("디버그 정보가 있어야 함");
+        assert!(debug.simulations > 0);
+        assert!(!debug.samples.is_empty());
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json.get("debug").is_some());
+    }
+
+    #[test]
+    fn test_equity_histogram_opt_in() {
+        let options: AnalysisOptions = serde_json::from_str(
+            r#"{
+                "depth": "quick",
+                "include_insights": false,
+                "include_range_analysis": false,
+                "include_equity_calculation": false,
+                "opponent_modeling": "Tight",
+                "equity_histogram": {"villain_range": "AQs,JTs"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(options.equity_histogram.as_ref().unwrap().buckets, 10);
+
+        // 턴 보드 Kh 8h 3c 2d, 히어로 3s3d
+        let mut game_state = heads_up_state([2, 28]);
+        game_state.board = vec![25, 20, 41, 27];
+        game_state.street = 2;
+        let request = AnalysisRequest {
+            game_state,
+            options: options.clone(),
+            action_history: Vec::new(),
+        };
+        let response = analyze_poker_state(request).unwrap();
+        let histogram = response.equity_histogram.expect("에퀴티 분포가 있어야 함");
+        assert_eq!(histogram.runouts, 46);
+        assert!(histogram.worst_cards[0].card.ends_with('h'));
+
+        // 프리플랍에서는 계산하지 않고 제한 사항으로 알림
+        let request = AnalysisRequest {
+            game_state: heads_up_state([2, 28]),
+            options,
+            action_history: Vec::new(),
+        };
+        let response = analyze_poker_state(request).unwrap();
+        assert!(response.equity_histogram.is_none());
+        assert!(!response.metadata.limitations.is_empty());
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json.get("equity_histogram").is_none());
+
+        // 기본값은 계산하지 않음
+        let response = analyze_poker_state(AnalysisRequest {
+            game_state: heads_up_state([2, 28]),
+            options: AnalysisOptions::default(),
+            action_history: Vec::new(),
+        })
+        .unwrap();
+        assert!(response.equity_histogram.is_none());
+    }
+
+    #[test]
+    fn test_insights_note_chop_when_board_plays() {
+        let request = |board: Vec<u8>| {
+            let mut game_state = heads_up_state([1, 15]); // 2s 3h
+            game_state.street = if board.is_empty() { 0 } else { 3 };
+            game_state.board = board;
+            AnalysisRequest {
+                game_state,
+                options: AnalysisOptions {
+                    depth: "quick".to_string(),
+                    include_insights: true,
+                    ..Default::default()
+                },
+                action_history: vec![],
+            }
+        };
+
+        // Ts Jh Qd Kc As: 브로드웨이 스트레이트 보드라 어떤 상대 핸드와도 비김
+        let response = analyze_poker_state(request(vec![9, 23, 37, 51, 0])).unwrap();
+        let note = response.insights.unwrap().chop_note.expect("찹 안내가 있어야 함");
+        assert_eq!(note, coaching::render_chop(1.0, Locale::default()));
+        assert!(note.contains("100%"), "{}", note);
+
+        let preflop = analyze_poker_state(request(vec![])).unwrap();
+        assert!(preflop.insights.unwrap().chop_note.is_none());
+    }
+
+    fn action_ev(action: Act, ev: f64, std_error: f64) -> ActionEV {
+        ActionEV {
+            action,
+            ev,
+            confidence: 0.6,
+            std_error,
+            unit: ValueUnit::Chips,
+        }
+    }
+
+    #[test]
+    fn test_strengths_with_all_negative_evs() {
+        let evs = [
+            action_ev(Act::Call, -5.0, 0.5),
+            action_ev(Act::Fold, -20.0, 0.0),
+            action_ev(Act::Raise(0), -80.0, 1.0),
+        ];
+        let strength = action_strengths(&evs);
+        assert_eq!(strength["Call"], 100.0);
+        assert_eq!(strength["Raise(0)"], 0.0);
+        assert!(strength["Fold"] > 0.0 && strength["Fold"] < 100.0);
+
+        // 표준오차보다 작은 EV 차이는 0/100으로 벌리지 않음
+        let close = action_strengths(&[action_ev(Act::Call, -10.0, 1.0), action_ev(Act::Fold, -10.1, 0.0)]);
+        assert!(close.values().all(|&s| (45.0..=55.0).contains(&s)), "{:?}", close);
+        assert!(close["Call"] > close["Fold"]);
+
+        let broken = action_strengths(&[action_ev(Act::Call, f64::NAN, 0.0), action_ev(Act::Fold, -3.0, 0.0)]);
+        assert_eq!((broken["Call"], broken["Fold"]), (0.0, 50.0));
+    }
+
+    #[test]
+    fn test_full_stack_call_with_thin_edge_is_risky() {
+        // 헤즈업 버튼(SB)이 상대 올인에 남은 스택 전부로 콜해야 하는 상황
+        let mut state = HoldemState::new_hand([10, 20], [1000; 6], 2);
+        state.invested[1] = 1000;
+        state.stack[1] = 0;
+        state.to_call = 1000;
+        state.pot = 1010;
+        assert!(HoldemState::legal_actions(&state).contains(&Act::Call));
+
+        let thin = [action_ev(Act::Call, 3.0, 4.0), action_ev(Act::Fold, 0.0, 0.0)];
+        let risk = assess_risk(&thin, &state);
+        assert!(matches!(risk, RiskLevel::Extreme), "{:?}", risk);
+
+        // 폴드가 확실히 나으면 거는 칩이 없어 낮은 리스크
+        let clear_fold = [action_ev(Act::Fold, 0.0, 0.0), action_ev(Act::Call, -300.0, 4.0)];
+        assert!(matches!(assess_risk(&clear_fold, &state), RiskLevel::Low));
+    }
+
+    #[test]
+    fn test_positional_advice_uses_rebuilt_seats() {
+        use crate::game::card_abstraction::hand_class::make_card;
+
+        // 헤즈업에서 히어로(0번)는 버튼
+        let state = HoldemStateBuilder::from_web_state(&heads_up_state([make_card(12, 0), make_card(12, 1)])).unwrap();
+        let render = |state: &HoldemState, strength: f64| {
+            coaching::render_positional(&positional_advice(state, strength).unwrap(), Locale::Korean)
+        };
+        let advice = render(&state, 0.9);
+        assert!(advice.contains("버튼"), "{}", advice);
+
+        // 6인 UTG(뒤에 5명)의 애매한 핸드는 조심
+        let mut web_state = heads_up_state([make_card(5, 3), make_card(0, 2)]);
+        web_state.stacks = vec![1000; 6];
+        let utg = HoldemStateBuilder::from_web_state(&web_state).unwrap();
+        let advice = render(&utg, 0.2);
+        assert!(advice.contains("뒤에 5명") && advice.contains("보수적"), "{}", advice);
+
+        // 플랍에서 버튼이 체크를 받으면 공격적으로
+        web_state.board = vec![make_card(11, 0), make_card(6, 1), make_card(1, 2)];
+        web_state.player_to_act = 3;
+        let mut button = HoldemStateBuilder::from_web_state(&web_state).unwrap();
+        button.invested = [0; 6];
+        button.to_call = 0;
+        let advice = render(&button, 0.2);
+        assert!(advice.contains("버튼") && advice.contains("공격적"), "{}", advice);
+    }
+
+    #[test]
+    fn test_analyze_poker_state_adversarial_inputs() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let extremes = [0, 1, u32::MAX];
+
+        for i in 0..300 {
+            let pick = |rng: &mut StdRng| {
+                if i % 3 == 0 {
+                    extremes[rng.gen_range(0..extremes.len())]
+                } else {
+                    rng.gen_range(0..5000)
+                }
+            };
+            // 대부분 검증을 통과하도록 카드/인원은 유효 범위 근처에서 생성
+            let players = rng.gen_range(1..8);
+            let board_len = [0, 3, 4, 5, 2, 6][rng.gen_range(0..6)];
+            let game_state = WebGameState {
+                hole_cards: [rng.gen_range(0..60), rng.gen_range(0..60)],
+                board: (0..board_len).map(|_| rng.gen_range(0..56)).collect(),
+                street: rng.gen_range(0..6),
+                pot: pick(&mut rng),
+                stacks: (0..players).map(|_| pick(&mut rng)).collect(),
+                alive_players: (0..rng.gen_range(0..8)).map(|_| rng.gen_range(0..10)).collect(),
+                street_investments: (0..rng.gen_range(0..8)).map(|_| pick(&mut rng)).collect(),
+                to_call: pick(&mut rng),
+                player_to_act: rng.gen_range(0..7),
+                hero_position: rng.gen_range(0..7),
+                betting_history: vec![],
+                seat_status: vec![],
+                posted: vec![],
+                straddle: None,
+            };
+            let request = AnalysisRequest {
+                game_state: game_state.clone(),
+                options: AnalysisOptions {
+                    depth: "quick".to_string(),
+                    counterfactuals: rng.gen(),
+                    ..Default::default()
+                },
+                action_history: (0..rng.gen_range(0..4))
+                    .map(|_| match rng.gen_range(0..3) {
+                        0 => Act::Fold,
+                        1 => Act::Call,
+                        _ => Act::Raise(rng.gen()),
+                    })
+                    .collect(),
+            };
+
+            let result = catch_unwind(AssertUnwindSafe(|| analyze_poker_state(request)));
+            assert!(result.is_ok(), "패닉 발생: {:?}", game_state);
+        }
+    }
+
+
+    #[test]
+    fn test_folded_seats_widen_first_in_opening() {
+        use crate::game::card_abstraction::hand_class::HandClass;
+
+        // 같은 핸드와 스택: UTG(뒤에 5명) vs 4명이 폴드한 스몰 블라인드(뒤에 빅 블라인드만)
+        let first_in = |hole: [u8; 2], folded_to_small_blind: bool| {
+            let mut web_state = heads_up_state(hole);
+            web_state.stacks = vec![1000; 6];
+            if folded_to_small_blind {
+                web_state.player_to_act = 4;
+                web_state.hero_position = 4;
+                web_state.seat_status = [vec![SeatStatus::Folded; 4], vec![SeatStatus::Live; 2]].concat();
+                web_state.posted = vec![0, 0, 0, 0, 10, 20];
+            }
+            web_state
+        };
+
+        let mut opens = [0, 0];
+        for text in ["Q9o", "A5o", "J9o", "K5s", "98o", "J7s"] {
+            let hole = text.parse::<HandClass>().unwrap().combos(&[])[0];
+            for (scenario, opened) in opens.iter_mut().enumerate() {
+                let web_state = first_in(hole, scenario == 1);
+                let state = HoldemStateBuilder::from_web_state(&web_state).unwrap();
+                assert_eq!(state.players_behind(state.to_act), if scenario == 1 { 1 } else { 5 });
+
+                let request = AnalysisRequest {
+                    game_state: web_state,
+                    options: AnalysisOptions { depth: "quick".to_string(), ..Default::default() },
+                    action_history: vec![],
+                };
+                let insights = analyze_poker_state(request).unwrap().insights.unwrap();
+                *opened += (insights.recommended_action != Act::Fold) as usize;
+            }
+        }
+        assert!(opens[1] > opens[0], "UTG {} vs 스몰 블라인드 {}", opens[0], opens[1]);
+    }
+
+    #[test]
+    fn test_folded_seat_chips_stay_as_dead_money() {
+        use crate::game::card_abstraction::hand_class::make_card;
+
+        // 2번 좌석이 60으로 오픈하고 스몰 블라인드의 180 3벳에 폴드, 빅 블라인드(히어로) 차례
+        let mut web_state = heads_up_state([make_card(12, 0), make_card(11, 1)]);
+        web_state.stacks = vec![1000; 6];
+        web_state.pot = 200; // 데드 머니를 빠뜨린 팟
+        web_state.player_to_act = 5;
+        web_state.hero_position = 5;
+        web_state.seat_status = [vec![SeatStatus::Folded; 4], vec![SeatStatus::Live; 2]].concat();
+        web_state.posted = vec![0, 0, 60, 0, 180, 20];
+
+        let state = HoldemStateBuilder::from_web_state(&web_state).unwrap();
+        assert!(!state.alive[2] && state.alive[4] && state.alive[5]);
+        assert_eq!((state.invested[2], state.antes[2]), (0, 60));
+        assert_eq!((state.invested[4], state.stack[4]), (180, 820));
+        assert_eq!((state.pot, state.to_call), (260, 180));
+        assert_eq!(state.players_behind(5), 0);
+
+        // 콜 160으로 팟 260 + 160을 노림
+        let required = required_equity(&state).unwrap();
+        assert!((required - 160.0 / 420.0).abs() < 1e-9, "{}", required);
+
+        // 폴드한 히어로나 상태 수가 맞지 않는 요청은 거부
+        let mut folded_hero = web_state.clone();
+        folded_hero.seat_status[5] = SeatStatus::Folded;
+        assert!(HoldemStateBuilder::from_web_state(&folded_hero).is_err());
+        web_state.posted.pop();
+        assert!(HoldemStateBuilder::from_web_state(&web_state).is_err());
+    }
+
+    #[test]
+    fn test_straddle_flag_changes_min_raise_and_info_key() {
+        use crate::game::card_abstraction::hand_class::make_card;
+
+        // UTG(0번 좌석)가 40 스트래들, UTG+1(히어로) 차례
+        let mut web_state = heads_up_state([make_card(12, 0), make_card(11, 1)]);
+        web_state.stacks = vec![1000; 6];
+        web_state.pot = 70;
+        web_state.player_to_act = 1;
+        web_state.hero_position = 1;
+        web_state.posted = vec![40, 0, 0, 0, 10, 20];
+        let plain = HoldemStateBuilder::from_web_state(&web_state).unwrap();
+
+        let json = serde_json::to_value(&web_state).unwrap();
+        let mut json = json.as_object().unwrap().clone();
+        json.insert("straddle".to_string(), serde_json::json!(40));
+        let web_state: WebGameState = serde_json::from_value(serde_json::Value::Object(json)).unwrap();
+        let straddled = HoldemStateBuilder::from_web_state(&web_state).unwrap();
+
+        assert_eq!((straddled.straddle, straddled.to_call, straddled.pot), (40, 40, 70));
+        assert_eq!(straddled.min_raise_size(), 80);
+        assert_eq!(plain.min_raise_size(), 60);
+        assert_ne!(HoldemState::info_key(&plain, 1), HoldemState::info_key(&straddled, 1));
+    }
+}
@@ -0,0 +1,205 @@
+// 블로커(카드 제거) 분석
+// 히어로 홀카드가 현재 보드에서 상대 레인지의 핸드 카테고리별 조합을 얼마나 지우는지
+// 무작위 홀카드 기준선과 비교하고, 블러프 후보로서의 점수를 계산합니다.
+
+use crate::game::hand_eval::best_hand_rank;
+use crate::interop::pio::HandRangeWeights;
+use serde::{Deserialize, Serialize};
+
+/// 현재 보드 기준 상대 핸드 카테고리
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum VillainCategory {
+    /// 풀하우스 이상
+    FullHouseOrBetter,
+    Flush,
+    Straight,
+    /// 셋/트립스
+    Trips,
+    TwoPair,
+    /// 원페어 (블러프에 콜할 수 있는 핸드)
+    BluffCatcher,
+    /// 하이카드 (베팅에 폴드하는 핸드)
+    Air,
+}
+
+impl VillainCategory {
+    /// 강한 순서의 모든 카테고리
+    pub const ALL: [Self; 7] = [
+        Self::FullHouseOrBetter,
+        Self::Flush,
+        Self::Straight,
+        Self::Trips,
+        Self::TwoPair,
+        Self::BluffCatcher,
+        Self::Air,
+    ];
+
+    /// 핸드 랭킹 값(`v7()`과 같은 범위)의 카테고리
+    pub fn from_rank(rank: u32) -> Self {
+        match rank {
+            0..=3824 => Self::FullHouseOrBetter,
+            3825..=5108 => Self::Flush,
+            5109..=5863 => Self::Straight,
+            5864..=8919 => Self::Trips,
+            8920..=21293 => Self::TwoPair,
+            21294..=32487 => Self::BluffCatcher,
+            _ => Self::Air,
+        }
+    }
+
+    /// 블러프에 밸류로 대응하는 카테고리인지 (투페어 이상)
+    pub fn is_value(self) -> bool {
+        !matches!(self, Self::BluffCatcher | Self::Air)
+    }
+}
+
+/// 카테고리 하나의 카드 제거 효과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CategoryBlockers {
+    pub category: VillainCategory,
+    /// 보드와 겹치는 조합을 뺀 상대 레인지의 가중 조합 수
+    pub combos: f64,
+    /// 히어로 홀카드가 지우는 가중 조합 수
+    pub removed: f64,
+    /// 무작위 홀카드가 평균적으로 지우는 가중 조합 수
+    pub baseline_removed: f64,
+}
+
+/// 블로커 분석 결과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BlockerReport {
+    /// `VillainCategory::ALL` 순서의 카테고리별 결과 (입력이 잘못되면 비어 있음)
+    pub categories: Vec<CategoryBlockers>,
+    /// 밸류 조합 제거 비율에서 기준선 비율을 뺀 값 (양수면 평균보다 밸류를 많이 막음)
+    pub value_blocking: f64,
+    /// 폴드할 조합(`Air`) 제거 비율에서 기준선 비율을 뺀 값
+    pub fold_blocking: f64,
+    /// 블러프 후보 점수 (`value_blocking - fold_blocking`, 밸류를 막고 폴드를 남길수록 높음)
+    pub bluff_score: f64,
+}
+
+/// 히어로 홀카드의 블로커 효과 분석
+///
+/// 상대 레인지에서 보드와 겹치는 조합을 뺀 뒤 각 조합을 현재 보드로 평가해 카테고리를 나누고,
+/// 히어로 카드가 지우는 가중 조합 수를 같은 보드의 무작위 홀카드가 지우는 기대값과 비교합니다.
+/// 보드가 3-5장이 아니거나 카드가 겹치면 빈 결과를 반환합니다.
+pub fn blocker_report(hero: [u8; 2], villain_range: &HandRangeWeights, board: &[u8]) -> BlockerReport {
+    let mut report = BlockerReport {
+        categories: Vec::new(),
+        value_blocking: 0.0,
+        fold_blocking: 0.0,
+        bluff_score: 0.0,
+    };
+
+    let mut known: Vec<u8> = hero.iter().chain(board).copied().collect();
+    known.sort_unstable();
+    known.dedup();
+    let valid = (3..=5).contains(&board.len()) && known.len() == board.len() + 2 && known.iter().all(|&c| c < 52);
+    if !valid {
+        return report;
+    }
+
+    let mut combos = [0.0; 7];
+    let mut removed = [0.0; 7];
+    for (combo, weight) in villain_range.remove_conflicts(board) {
+        let cards: Vec<u8> = combo.iter().chain(board).copied().collect();
+        let Some(rank) = best_hand_rank(&cards) else {
+            continue;
+        };
+        let index = VillainCategory::ALL
+            .iter()
+            .position(|&category| category == VillainCategory::from_rank(rank))
+            .unwrap_or(6);
+        combos[index] += weight;
+        if combo.iter().any(|card| hero.contains(card)) {
+            removed[index] += weight;
+        }
+    }
+
+    // 남은 덱에서 뽑은 무작위 홀카드가 특정 조합과 카드를 공유할 확률 (모든 조합에 같음)
+    let deck = (52 - board.len()) as f64;
+    let overlap = 1.0 - (deck - 2.0) * (deck - 3.0) / (deck * (deck - 1.0));
+
+    report.categories = VillainCategory::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, &category)| CategoryBlockers {
+            category,
+            combos: combos[i],
+            removed: removed[i],
+            baseline_removed: combos[i] * overlap,
+        })
+        .collect();
+
+    let relative = |filter: fn(VillainCategory) -> bool| {
+        let (total, hit) = report
+            .categories
+            .iter()
+            .filter(|c| filter(c.category))
+            .fold((0.0, 0.0), |(total, hit), c| (total + c.combos, hit + c.removed));
+        if total > 0.0 {
+            hit / total - overlap
+        } else {
+            0.0
+        }
+    };
+    report.value_blocking = relative(VillainCategory::is_value);
+    report.fold_blocking = relative(|category| category == VillainCategory::Air);
+    report.bluff_score = report.value_blocking - report.fold_blocking;
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::card_abstraction::hand_class::make_card;
+    use crate::interop::pio::parse_range;
+
+    // 강도 순 랭크: 0=2, ..., 8=T, 9=J, 10=Q, 11=K, 12=A / 수트: 0=s, 1=h, 2=d, 3=c
+    fn card(rank: u8, suit: u8) -> u8 {
+        make_card(rank, suit)
+    }
+
+    fn category(report: &BlockerReport, category: VillainCategory) -> &CategoryBlockers {
+        report.categories.iter().find(|c| c.category == category).unwrap()
+    }
+
+    #[test]
+    fn test_nut_flush_blocker_is_better_bluff() {
+        // Kh 8h 3h
+        let board = [card(11, 1), card(6, 1), card(1, 1)];
+        let range = parse_range("AQs,AJs,KQs,QJs,JTs,T9s,87s,76s,65s,54s,A5s,A4s,KJo,QJo,88,33,AK");
+        let ace_of_hearts = blocker_report([card(12, 1), card(8, 3)], &range, &board); // Ah Tc
+        let blanks = blocker_report([card(2, 3), card(0, 2)], &range, &board); // 4c 2d
+
+        let flush = category(&ace_of_hearts, VillainCategory::Flush);
+        assert!(flush.removed > 0.0);
+        assert!(flush.removed > flush.baseline_removed);
+        assert_eq!(category(&blanks, VillainCategory::Flush).removed, 0.0);
+
+        assert!(ace_of_hearts.value_blocking > blanks.value_blocking);
+        assert!(
+            ace_of_hearts.bluff_score > blanks.bluff_score,
+            "{} vs {}",
+            ace_of_hearts.bluff_score,
+            blanks.bluff_score
+        );
+    }
+
+    #[test]
+    fn test_invalid_input_gives_empty_report() {
+        let range = parse_range("AA,KK");
+        let board = [card(11, 1), card(6, 1), card(1, 1)];
+        assert!(blocker_report([board[0], card(0, 0)], &range, &board).categories.is_empty());
+        assert!(blocker_report([card(12, 0), card(12, 1)], &range, &board[..2]).categories.is_empty());
+
+        // 카테고리별 조합 수 합은 보드와 겹치지 않는 레인지 전체
+        let report = blocker_report([card(0, 0), card(0, 1)], &range, &board);
+        let total: f64 = report.categories.iter().map(|c| c.combos).sum();
+        assert_eq!(total, range.remove_conflicts(&board).len() as f64);
+        assert_eq!(total, 9.0); // AA 6 + KK 3
+    }
+}
@@ -0,0 +1,399 @@
+// 결정 난이도 점수와 다가올 결정 지점 순위
+// 클라이언트가 몇 초 안에 필요할 스팟의 전략을 미리 계산할 수 있도록, 결정 지점 하나의 난이도를
+// 0-100 점수(요소별 분해 포함)로 매기고, 히어로의 다음 결정 지점들을 도달 확률 x 예상 난이도 순으로
+// 정렬합니다. 좌석별로 다음 차례까지 남은 액션 수(time-to-act)도 함께 제공합니다.
+
+use crate::game::holdem::{Act, State};
+use crate::game::poker_math::minimum_defense_frequency;
+use crate::solver::cfr_core::Game;
+use crate::solver::ev_calculator::{EVCalculator, EVConfig};
+use serde::{Deserialize, Serialize};
+
+/// 빠른 평가의 액션당 몬테카를로 샘플 수 (정확히 열거할 수 없는 상태만)
+const QUICK_SAMPLES: usize = 400;
+/// 빠른 평가 시드 (같은 상태는 항상 같은 점수)
+const QUICK_SEED: u64 = 0xC0DE;
+/// 상위 두 액션의 EV 차이가 걸린 칩의 이 비율 이상이면 근접도 0
+const CLOSE_EV_STAKE_FRACTION: f64 = 0.5;
+/// 상대가 베팅을 마주하지 않았을 때 빠른 정책의 베팅/레이즈 빈도
+const QUICK_BET_FREQUENCY: f64 = 0.3;
+/// 상대가 베팅을 마주했을 때 계속하는 빈도 중 레이즈 비율
+const QUICK_RAISE_SHARE: f64 = 0.2;
+
+/// 유효 스택 대 팟 비율(SPR) 구간
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SprBand {
+    /// 1 미만 - 사실상 올인이 정해진 팟
+    Committed,
+    /// 1-3 - 한 번의 큰 베팅으로 스택이 걸림
+    Low,
+    /// 3-7 - 커밋 여부를 스트리트마다 다시 정해야 하는 가장 어려운 구간
+    Medium,
+    /// 7 이상 - 딥 스택
+    Deep,
+}
+
+impl SprBand {
+    /// SPR 값의 구간
+    pub fn of(spr: f64) -> Self {
+        match spr {
+            spr if spr < 1.0 => SprBand::Committed,
+            spr if spr < 3.0 => SprBand::Low,
+            spr if spr < 7.0 => SprBand::Medium,
+            _ => SprBand::Deep,
+        }
+    }
+
+    /// 구간의 난이도 (0-100)
+    fn difficulty(self) -> f64 {
+        match self {
+            SprBand::Committed => 10.0,
+            SprBand::Low => 60.0,
+            SprBand::Medium => 100.0,
+            SprBand::Deep => 70.0,
+        }
+    }
+}
+
+/// 난이도 요소별 점수 (각 0-100)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ComplexityComponents {
+    /// 합법 액션 수 (1개면 0, 3개면 100)
+    pub action_count: f64,
+    /// 빠른 평가에서 상위 두 액션 EV의 근접도 (가까울수록 높음)
+    pub ev_closeness: f64,
+    /// SPR 구간 난이도
+    pub spr: f64,
+    /// 스트리트 난이도 (플랍이 가장 높음)
+    pub street: f64,
+    /// 콜이 스택 커밋 경계(스택의 약 절반)에 가까운 정도
+    pub commitment: f64,
+}
+
+/// 결정 지점 하나의 난이도
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DecisionComplexity {
+    /// 난이도 점수 (0-100)
+    pub score: f64,
+    pub components: ComplexityComponents,
+    /// 합법 액션 수
+    pub legal_actions: usize,
+    /// 빠른 평가의 상위 두 액션 EV 차이 (칩, 액션이 하나 이하면 None)
+    pub ev_gap: Option<f64>,
+    /// 유효 스택 / 팟
+    pub spr: f64,
+    pub spr_band: SprBand,
+    /// 콜이 스택 커밋 경계 근처인지 (스택의 25-75%를 넣게 됨)
+    pub near_commitment: bool,
+}
+
+impl DecisionComplexity {
+    /// 결정할 것이 없는 지점 (히어로 차례가 아니거나 액션이 하나뿐)
+    fn trivial(legal_actions: usize, spr: f64) -> Self {
+        Self {
+            score: 0.0,
+            components: ComplexityComponents {
+                action_count: 0.0,
+                ev_closeness: 0.0,
+                spr: 0.0,
+                street: 0.0,
+                commitment: 0.0,
+            },
+            legal_actions,
+            ev_gap: None,
+            spr,
+            spr_band: SprBand::of(spr),
+            near_commitment: false,
+        }
+    }
+}
+
+/// `hero`가 `state`에서 내릴 결정의 난이도
+///
+/// EV 근접도가 주 요소이고 나머지 요소(액션 수, SPR, 스트리트, 커밋 경계)의 평균이 점수를 0.5-1배로
+/// 조절합니다. 어느 액션이 나은지 분명하면 구조가 복잡해도 쉬운 결정입니다. 근접도는 상위 두 액션의
+/// EV 차이를 그 결정에 걸린 칩(액션 중 가장 많이 넣는 금액)과 비교합니다. 빠른 평가는 홀카드를 모두
+/// 아는 리버면 정확히 열거하고, 아니면 짧은 몬테카를로로 계산합니다 (상대는 균등 랜덤).
+/// `hero`의 차례가 아니거나 액션이 하나 이하면 0점입니다.
+pub fn score(state: &State, hero: usize) -> DecisionComplexity {
+    let hero = hero.min(5);
+    let actions = State::legal_actions(state);
+    let spr = effective_spr(state, hero);
+    if State::current_player(state) != Some(hero) || actions.len() < 2 {
+        return DecisionComplexity::trivial(actions.len(), spr);
+    }
+
+    let mut evs = quick_action_evs(state);
+    evs.sort_by(|a, b| b.total_cmp(a));
+    let ev_gap = evs[0] - evs[1];
+    let stake = actions
+        .iter()
+        .map(|&action| state.stack[hero].saturating_sub(State::next_state(state, action).stack[hero]))
+        .max()
+        .unwrap_or(0)
+        .max(1) as f64;
+    let ev_closeness = 100.0 * (1.0 - ev_gap / (CLOSE_EV_STAKE_FRACTION * stake)).clamp(0.0, 1.0);
+
+    let call = state.to_call.saturating_sub(state.invested[hero]).min(state.stack[hero]);
+    let committed_after_call = (state.invested[hero] + call) as f64 / (state.stack[hero] + state.invested[hero]).max(1) as f64;
+    let all_in_call = call > 0 && call >= state.stack[hero];
+    let commitment = if all_in_call {
+        0.0
+    } else {
+        100.0 * (1.0 - (committed_after_call - 0.5).abs() / 0.25).clamp(0.0, 1.0)
+    };
+
+    let components = ComplexityComponents {
+        action_count: 100.0 * (actions.len() - 1).min(2) as f64 / 2.0,
+        ev_closeness,
+        spr: SprBand::of(spr).difficulty(),
+        street: match state.street {
+            0 => 25.0,
+            1 => 100.0,
+            2 => 75.0,
+            _ => 60.0,
+        },
+        commitment,
+    };
+    let context = (components.action_count + components.spr + components.street + components.commitment) / 4.0;
+    DecisionComplexity {
+        score: (ev_closeness * (0.5 + 0.5 * context / 100.0)).clamp(0.0, 100.0),
+        components,
+        legal_actions: actions.len(),
+        ev_gap: Some(ev_gap),
+        spr,
+        spr_band: SprBand::of(spr),
+        near_commitment: commitment > 0.0,
+    }
+}
+
+/// 다가올 결정 지점 식별 정보
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct StateDigest {
+    /// 현재 상태부터 이 결정 지점까지의 액션
+    pub path: Vec<Act>,
+    /// 히어로 관점 정보 집합 키 (학습 전략 조회 키)
+    pub info_key: u64,
+    pub street: u8,
+    pub pot: u32,
+    pub to_call: u32,
+    /// 이 지점에 오기까지 다른 좌석이 하는 액션 수 (히어로의 time-to-act)
+    pub opponent_actions: usize,
+}
+
+/// 현재 차례인 히어로의 다음 결정 지점들을 (도달 확률 x 난이도) 내림차순으로
+///
+/// 현재 상태에서 `lookahead_depth`개 액션 안에 닿는 히어로 차례(현재 지점 제외)를 모두 찾습니다.
+/// 도달 확률은 히어로가 그 라인을 고른다는 조건에서 상대 액션 확률의 곱이며, 상대는 팟 오즈를
+/// 따르는 빠른 정책(베팅을 마주하면 최소 방어 빈도만큼 계속)으로 봅니다. 다음 스트리트 카드가
+/// 필요한 지점은 카드를 알 수 없으므로 찾지 않습니다.
+pub fn rank_upcoming_spots(state: &State, lookahead_depth: usize) -> Vec<(StateDigest, f64)> {
+    let Some(hero) = State::current_player(state) else {
+        return Vec::new();
+    };
+    let mut spots = Vec::new();
+    let mut path = Vec::new();
+    collect_hero_spots(state, hero, lookahead_depth, 1.0, 0, &mut path, &mut spots);
+    let mut ranked: Vec<(StateDigest, f64)> = spots
+        .into_iter()
+        .map(|(next, digest, reach)| {
+            let expected = reach * score(&next, hero).score;
+            (digest, expected)
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+}
+
+/// 좌석별로 다음 차례까지 남은 액션 수 (이번 베팅 라운드에 차례가 없으면 None)
+///
+/// 아무도 레이즈하지 않고 모두 체크/콜한다고 보고 세며, 지금 차례인 좌석은 0입니다.
+pub fn seats_time_to_act(state: &State) -> [Option<usize>; 6] {
+    let mut turns = [None; 6];
+    let mut current = state.clone();
+    for step in 0..6 {
+        let Some(player) = State::current_player(&current) else {
+            break;
+        };
+        if turns[player.min(5)].is_some() {
+            break;
+        }
+        turns[player.min(5)] = Some(step);
+        current = State::next_state(&current, Act::Call);
+    }
+    turns
+}
+
+/// 히어로 차례를 찾으며 `depth`개 액션까지 트리를 내려감
+fn collect_hero_spots(
+    state: &State,
+    hero: usize,
+    depth: usize,
+    reach: f64,
+    opponent_actions: usize,
+    path: &mut Vec<Act>,
+    spots: &mut Vec<(State, StateDigest, f64)>,
+) {
+    let Some(player) = State::current_player(state) else {
+        return;
+    };
+    if player == hero && !path.is_empty() {
+        spots.push((
+            state.clone(),
+            StateDigest {
+                path: path.clone(),
+                info_key: State::info_key(state, hero),
+                street: state.street,
+                pot: state.pot,
+                to_call: state.to_call,
+                opponent_actions,
+            },
+            reach,
+        ));
+        return;
+    }
+    if depth == 0 {
+        return;
+    }
+
+    let actions = State::legal_actions(state);
+    let probabilities = if player == hero {
+        vec![1.0; actions.len()]
+    } else {
+        quick_opponent_policy(state, &actions)
+    };
+    for (&action, probability) in actions.iter().zip(probabilities) {
+        if probability <= 0.0 {
+            continue;
+        }
+        path.push(action);
+        let next = State::next_state(state, action);
+        let opponent_actions = opponent_actions + (player != hero) as usize;
+        collect_hero_spots(&next, hero, depth - 1, reach * probability, opponent_actions, path, spots);
+        path.pop();
+    }
+}
+
+/// 상대의 빠른 정책 (`actions` 순서 확률)
+///
+/// 공짜로 체크할 수 있으면 폴드하지 않고 `QUICK_BET_FREQUENCY`만큼 베팅하며, 베팅을 마주하면
+/// 최소 방어 빈도만큼 계속(그중 `QUICK_RAISE_SHARE`는 레이즈)합니다.
+fn quick_opponent_policy(state: &State, actions: &[Act]) -> Vec<f64> {
+    let player = state.to_act.min(5);
+    let call = state.to_call.saturating_sub(state.invested[player]);
+    let can_raise = actions.iter().any(|action| matches!(action, Act::Raise(_)));
+    let (fold, raise) = if call == 0 {
+        (0.0, if can_raise { QUICK_BET_FREQUENCY } else { 0.0 })
+    } else {
+        let defend = minimum_defense_frequency(state.pot.saturating_sub(call) as f64, call as f64);
+        (1.0 - defend, if can_raise { defend * QUICK_RAISE_SHARE } else { 0.0 })
+    };
+    actions
+        .iter()
+        .map(|action| match action {
+            Act::Fold => fold,
+            Act::Call => 1.0 - fold - raise,
+            Act::Raise(_) => raise,
+        })
+        .collect()
+}
+
+/// 액션별 빠른 EV (칩, 정확히 열거할 수 있으면 열거)
+fn quick_action_evs(state: &State) -> Vec<f64> {
+    let calculator = EVCalculator::new(EVConfig {
+        sample_count: QUICK_SAMPLES,
+        max_depth: 8,
+        use_opponent_model: false,
+        seed: Some(QUICK_SEED),
+        ..EVConfig::default()
+    });
+    match calculator.exact_action_evs(state) {
+        Some(exact) => exact.into_iter().map(|(_, ev)| ev).collect(),
+        None => calculator.calculate_action_evs(state).into_iter().map(|a| a.ev).collect(),
+    }
+}
+
+/// `hero`와 가장 깊은 살아 있는 상대 중 작은 스택 / 팟
+fn effective_spr(state: &State, hero: usize) -> f64 {
+    let opponent_stack = (0..6)
+        .filter(|&seat| seat != hero && state.alive[seat])
+        .map(|seat| state.stack[seat])
+        .max()
+        .unwrap_or(0);
+    let effective = state.stack[hero].min(opponent_stack) as f64;
+    effective / state.pot.max(1) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::card_abstraction::hand_class::make_card;
+
+    /// 헤즈업 리버: 히어로(0번) 체크 뒤 1번 좌석이 `bet` 베팅, 히어로 차례
+    fn river_facing_bet(hero_hole: [u8; 2], stacks: [u32; 2], pot: u32, bet: u32) -> State {
+        let mut state = State::new_hand([10, 20], [1000; 6], 2);
+        state.hole[0] = hero_hole;
+        state.hole[1] = [make_card(12, 0), make_card(3, 1)]; // A5o
+        state.board = vec![make_card(12, 2), make_card(8, 3), make_card(6, 1), make_card(1, 0), make_card(0, 3)];
+        state.street = 3;
+        state.antes = [0; 6];
+        state.stack = [stacks[0], stacks[1] - bet, 0, 0, 0, 0];
+        state.invested = [0, bet, 0, 0, 0, 0];
+        state.pot = pot + bet;
+        state.to_call = bet;
+        state.last_raise = bet;
+        state.raises_this_street = 1;
+        state.actions_taken = 2;
+        state.to_act = 0;
+        state
+    }
+
+    #[test]
+    fn test_call_for_crumbs_is_trivial() {
+        // 스택 5칩 남은 히어로가 팟 1000에서 5칩 베팅을 콜할지 (에이스 투페어)
+        let state = river_facing_bet([make_card(12, 1), make_card(8, 0)], [5, 1000], 1000, 5);
+        let complexity = score(&state, 0);
+        assert_eq!(complexity.legal_actions, 2);
+        assert_eq!(complexity.spr_band, SprBand::Committed);
+        assert!(!complexity.near_commitment);
+        assert!(complexity.score < 5.0, "{:?}", complexity);
+
+        // 히어로 차례가 아니면 0점
+        assert_eq!(score(&state, 1).score, 0.0);
+    }
+
+    #[test]
+    fn test_river_check_raise_with_close_evs_is_hard() {
+        // 에이스 투페어로 팟 베팅을 받음: 콜과 체크레이즈의 EV 차이가 걸린 칩에 비해 작음
+        let state = river_facing_bet([make_card(12, 1), make_card(8, 0)], [1500, 1500], 400, 400);
+        let complexity = score(&state, 0);
+        assert_eq!(complexity.legal_actions, 3);
+        assert_eq!(complexity.spr_band, SprBand::Low);
+        assert!(complexity.components.ev_closeness > 70.0, "{:?}", complexity);
+        assert!(complexity.score > 55.0, "{:?}", complexity);
+
+        // 같은 핸드라도 레이즈가 분명히 나은 얕은 팟은 쉬움
+        let clear = score(&river_facing_bet([make_card(12, 1), make_card(8, 0)], [800, 1000], 400, 200), 0);
+        assert!(clear.score < complexity.score / 2.0, "{:?}", clear);
+    }
+
+    #[test]
+    fn test_upcoming_spots_cover_reachable_hero_decisions() {
+        // 헤즈업 프리플랍 SB(0번) 차례: 히어로 액션 뒤 BB 응답, 다시 히어로 차례
+        let state = State::new_hand([10, 20], [1000; 6], 2);
+        let ranked = rank_upcoming_spots(&state, 2);
+        let mut paths: Vec<Vec<Act>> = ranked.iter().map(|(digest, _)| digest.path.clone()).collect();
+        paths.sort_by_key(|path| format!("{:?}", path));
+        // 림프 뒤 BB 레이즈, 레이즈 뒤 BB 리레이즈만 2액션 안에 히어로 차례가 다시 옴
+        assert_eq!(paths, vec![vec![Act::Call, Act::Raise(0)], vec![Act::Raise(0), Act::Raise(0)]]);
+        assert!(ranked.iter().all(|(digest, value)| digest.opponent_actions == 1 && (0.0..=100.0).contains(value)));
+        assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(rank_upcoming_spots(&state, 1).is_empty());
+
+        assert_eq!(seats_time_to_act(&state)[..2], [Some(0), Some(1)]);
+    }
+}
@@ -0,0 +1,200 @@
+// 에퀴티 분포 히스토그램
+// 남은 런아웃마다 히어로 핸드의 상대 레인지 대비 에퀴티를 계산해 구간별로 집계합니다.
+
+use crate::game::hand_eval::{card_to_string, PartialEval};
+use crate::interop::pio::HandRangeWeights;
+use serde::{Deserialize, Serialize};
+
+/// 히스토그램과 함께 보고하는 최악의 카드 수
+pub const WORST_CARD_COUNT: usize = 10;
+
+/// 카드 한 장이 나왔을 때의 평균 에퀴티
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CardEquity {
+    /// 카드 이름 (예: "Ah")
+    pub card: String,
+    /// 카드 번호 (0-51)
+    pub card_index: u8,
+    /// 이 카드를 포함한 런아웃들의 평균 에퀴티
+    pub equity: f64,
+}
+
+/// 런아웃별 에퀴티 분포
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EquityHistogram {
+    /// 구간 경계 (0.0-1.0, 구간 수 + 1개)
+    pub bucket_edges: Vec<f64>,
+    /// 구간별 런아웃 수 (마지막 구간은 1.0 포함)
+    pub counts: Vec<usize>,
+    /// 전체 런아웃 평균 에퀴티 (현재 에퀴티와 같음)
+    pub mean: f64,
+    /// 평가한 런아웃 수 (턴이면 리버 카드 수, 플랍이면 턴-리버 조합 수)
+    pub runouts: usize,
+    /// 평균 에퀴티가 가장 낮은 카드들 (낮은 순)
+    pub worst_cards: Vec<CardEquity>,
+}
+
+/// 다음 카드(플랍에서는 턴-리버 조합)마다 히어로의 레인지 대비 에퀴티 분포 계산
+///
+/// 각 런아웃에서 보드를 완성한 뒤 히어로, 보드, 런아웃과 겹치지 않는 상대 조합들을
+/// 레인지 가중치로 평균한 쇼다운 에퀴티(무승부는 절반)를 구간에 넣습니다.
+/// 보드가 3장이나 4장이 아니거나 겹치지 않는 상대 조합이 없으면 빈 히스토그램을 반환합니다.
+pub fn equity_distribution(
+    hole: [u8; 2],
+    board: &[u8],
+    villain_range: &HandRangeWeights,
+    buckets: usize,
+) -> EquityHistogram {
+    let buckets = buckets.max(1);
+    let bucket_edges: Vec<f64> = (0..=buckets).map(|i| i as f64 / buckets as f64).collect();
+    let mut histogram = EquityHistogram {
+        bucket_edges,
+        counts: vec![0; buckets],
+        mean: 0.0,
+        runouts: 0,
+        worst_cards: Vec::new(),
+    };
+
+    let mut dead: Vec<u8> = hole.iter().chain(board).copied().collect();
+    dead.sort_unstable();
+    dead.dedup();
+    let valid = (3..=4).contains(&board.len()) && dead.len() == board.len() + 2 && dead.iter().all(|&c| c < 52);
+    if !valid {
+        return histogram;
+    }
+
+    let villain_combos = villain_range.remove_conflicts(&dead);
+    let deck: Vec<u8> = (0..52).filter(|card| !dead.contains(card)).collect();
+
+    let runouts: Vec<Vec<u8>> = if board.len() == 4 {
+        deck.iter().map(|&card| vec![card]).collect()
+    } else {
+        (0..deck.len())
+            .flat_map(|i| ((i + 1)..deck.len()).map(move |j| (i, j)))
+            .map(|(i, j)| vec![deck[i], deck[j]])
+            .collect()
+    };
+
+    let mut card_totals = [(0.0, 0usize); 52];
+    let mut total = 0.0;
+    for runout in &runouts {
+        let mut full_board = [0u8; 5];
+        full_board[..board.len()].copy_from_slice(board);
+        full_board[board.len()..].copy_from_slice(runout);
+        let Some(equity) = equity_vs_range(hole, full_board, &villain_combos) else {
+            continue;
+        };
+
+        let bucket = ((equity * buckets as f64) as usize).min(buckets - 1);
+        histogram.counts[bucket] += 1;
+        histogram.runouts += 1;
+        total += equity;
+        for &card in runout {
+            card_totals[card as usize].0 += equity;
+            card_totals[card as usize].1 += 1;
+        }
+    }
+    if histogram.runouts == 0 {
+        return histogram;
+    }
+    histogram.mean = total / histogram.runouts as f64;
+
+    let mut card_equities: Vec<CardEquity> = (0..52u8)
+        .filter(|&card| card_totals[card as usize].1 > 0)
+        .map(|card| {
+            let (sum, count) = card_totals[card as usize];
+            CardEquity {
+                card: card_to_string(card),
+                card_index: card,
+                equity: sum / count as f64,
+            }
+        })
+        .collect();
+    card_equities.sort_by(|a, b| a.equity.total_cmp(&b.equity).then(a.card_index.cmp(&b.card_index)));
+    card_equities.truncate(WORST_CARD_COUNT);
+    histogram.worst_cards = card_equities;
+    histogram
+}
+
+/// 완성된 보드에서 상대 조합들에 대한 가중 쇼다운 에퀴티 (유효한 조합이 없으면 None)
+fn equity_vs_range(hole: [u8; 2], board: [u8; 5], villain_combos: &[([u8; 2], f64)]) -> Option<f64> {
+    let board_eval = PartialEval::from_cards(&board);
+    let hero_rank = board_eval.extend(&hole);
+
+    let mut won = 0.0;
+    let mut weight_total = 0.0;
+    for &(combo, weight) in villain_combos {
+        if board.contains(&combo[0]) || board.contains(&combo[1]) {
+            continue;
+        }
+        let villain_rank = board_eval.extend(&combo);
+        // 랭크가 낮을수록 강한 핸드
+        won += weight * match hero_rank.cmp(&villain_rank) {
+            std::cmp::Ordering::Less => 1.0,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Greater => 0.0,
+        };
+        weight_total += weight;
+    }
+    (weight_total > 0.0).then(|| won / weight_total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::card_abstraction::hand_class::make_card;
+    use crate::interop::pio::parse_range;
+
+    // 강도 순 랭크: 0=2, ..., 12=A / 수트: 0=s, 1=h, 2=d, 3=c
+    const HEARTS: u8 = 1;
+
+    #[test]
+    fn test_flush_cards_form_low_equity_mass() {
+        // 턴 보드 Kh 8h 3c 2d, 히어로 3s3d 셋 vs 수티드 브로드웨이 (스트레이트 불가능)
+        let board = [make_card(11, HEARTS), make_card(6, HEARTS), make_card(1, 3), make_card(0, 2)];
+        let hole = [make_card(1, 0), make_card(1, 2)];
+        let range = parse_range("AQs,AJs,ATs,QJs,QTs,JTs");
+
+        let histogram = equity_distribution(hole, &board, &range, 20);
+        assert_eq!(histogram.bucket_edges.len(), 21);
+        assert_eq!(histogram.runouts, 46);
+        assert_eq!(histogram.counts.iter().sum::<usize>(), 46);
+
+        // 보드를 페어로 만들지 않는 하트 9장은 상대 플러시를 완성 (3h/2h는 히어로 풀하우스 이상)
+        // - 블로커가 없으면 수티드 조합 중 하트 조합에만 지므로 0.75
+        // - 나머지 37장은 모두 승리
+        assert_eq!(histogram.counts[19], 37);
+        let low_mass: usize = histogram.counts[..19].iter().sum();
+        assert_eq!(low_mass, 9, "{:?}", histogram.counts);
+        assert_eq!(histogram.counts[15], 5);
+        assert!(histogram.mean > 0.95 && histogram.mean < 0.97, "{}", histogram.mean);
+
+        assert_eq!(histogram.worst_cards.len(), WORST_CARD_COUNT);
+        let names: Vec<&str> = histogram.worst_cards.iter().map(|card| card.card.as_str()).collect();
+        assert_eq!(&names[..5], ["4h", "5h", "6h", "7h", "9h"]);
+        for card in &histogram.worst_cards[..9] {
+            assert!(card.card.ends_with('h'), "{:?}", card);
+            assert_eq!(card.card_index / 13, HEARTS);
+            assert!(card.equity < 0.9);
+        }
+        assert_eq!(histogram.worst_cards[9].equity, 1.0);
+    }
+
+    #[test]
+    fn test_flop_uses_runout_pairs() {
+        let board = [make_card(11, HEARTS), make_card(6, HEARTS), make_card(1, 3)];
+        let hole = [make_card(1, 0), make_card(1, 2)];
+        let range = parse_range("AQs,JTs");
+
+        let histogram = equity_distribution(hole, &board, &range, 10);
+        assert_eq!(histogram.runouts, 47 * 46 / 2);
+        assert!(histogram.worst_cards[0].card.ends_with('h'), "{:?}", histogram.worst_cards);
+
+        // 프리플랍 보드, 겹치는 카드, 빈 레인지는 빈 히스토그램
+        assert_eq!(equity_distribution(hole, &[], &range, 10).runouts, 0);
+        assert_eq!(equity_distribution(hole, &[hole[0], 20, 30], &range, 10).runouts, 0);
+        assert_eq!(equity_distribution(hole, &board, &parse_range(""), 10).runouts, 0);
+    }
+}
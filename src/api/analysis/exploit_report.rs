@@ -0,0 +1,567 @@
+// 상대 익스플로잇 리포트
+// 특정 플레이어의 관찰 통계(상대 모델)를 학습된 GTO 베이스라인 전략과 표준 스팟별로 비교해
+// 기준치를 넘는 편차(과도한 폴드, 과도한 콜, 3벳 부족 등)를 찾고,
+// 그 편차를 공략하는 카운터 조정의 100핸드당 기대 이득을 EV 계산기로 추정합니다.
+
+use super::seat_equity::position_name;
+use crate::game::card_abstraction::hand_class::make_card;
+use crate::game::holdem::{Act, State};
+use crate::game::observation::ObservedState;
+use crate::game::tournament::OpponentModel;
+use crate::game::value_unit::{ValueContext, ValueUnit};
+use crate::solver::cfr_core::{Game, GameState, Trainer};
+use crate::solver::ev_calculator::{EVCalculator, EVConfig, OpponentActionModel};
+use crate::solver::sanity::{action_class, aggregate_frequencies};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+/// 표준 스팟 종류 (상대가 결정하는 상황)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SpotKind {
+    /// 앞에서 모두 폴드한 뒤 첫 오픈 결정
+    Open,
+    /// 히어로의 오픈을 마주한 결정 (3벳 여부)
+    FacingOpen,
+    /// 자신의 오픈이 3벳을 맞은 결정
+    FacingThreeBet,
+    /// 플랍에서 C벳을 마주한 결정
+    FacingCbet,
+}
+
+impl SpotKind {
+    /// 폴드/콜/레이즈가 이 스팟에서 가리키는 플레이 이름
+    fn action_names(self) -> [&'static str; 3] {
+        match self {
+            SpotKind::Open => ["오픈 폴드", "림프", "오픈 레이즈"],
+            SpotKind::FacingOpen => ["오픈에 폴드", "오픈에 플랫 콜", "3벳"],
+            SpotKind::FacingThreeBet => ["3벳에 폴드", "3벳에 콜", "4벳"],
+            SpotKind::FacingCbet => ["C벳에 폴드", "C벳에 콜", "C벳에 레이즈"],
+        }
+    }
+}
+
+/// 베이스라인 대비 편차 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum LeakDirection {
+    OverFolds,
+    UnderFolds,
+    OverCalls,
+    UnderCalls,
+    OverRaises,
+    UnderRaises,
+}
+
+impl LeakDirection {
+    /// 액션 종류(0=폴드, 1=콜, 2=레이즈)와 편차 부호로 방향 결정
+    fn from_deviation(action_class: usize, deviation: f64) -> Self {
+        match (action_class, deviation > 0.0) {
+            (0, true) => LeakDirection::OverFolds,
+            (0, false) => LeakDirection::UnderFolds,
+            (1, true) => LeakDirection::OverCalls,
+            (1, false) => LeakDirection::UnderCalls,
+            (_, true) => LeakDirection::OverRaises,
+            (_, false) => LeakDirection::UnderRaises,
+        }
+    }
+
+    fn is_over(self) -> bool {
+        matches!(
+            self,
+            LeakDirection::OverFolds | LeakDirection::OverCalls | LeakDirection::OverRaises
+        )
+    }
+
+    /// 공략 방향: Some(true)면 스팟으로 이어지는 공격을 늘리고, Some(false)면 줄임
+    ///
+    /// 콜이 적은 것만으로는 폴드와 레이즈 중 어느 쪽이 늘었는지 알 수 없어 None입니다.
+    fn exploit_with_aggression(self) -> Option<bool> {
+        match self {
+            LeakDirection::OverFolds | LeakDirection::UnderRaises => Some(true),
+            LeakDirection::UnderFolds | LeakDirection::OverCalls | LeakDirection::OverRaises => Some(false),
+            LeakDirection::UnderCalls => None,
+        }
+    }
+}
+
+/// 리포트 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExploitConfig {
+    /// 편차로 보고할 빈도 차이의 최소 절댓값 (0-1)
+    pub threshold: f64,
+    /// 이 표본 수 미만의 모델은 신뢰할 수 없는 것으로 표시
+    pub min_sample_size: u32,
+    /// 카운터 조정 EV 계산의 액션별 시뮬레이션 샘플 수
+    pub ev_samples: usize,
+}
+
+impl Default for ExploitConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.1,
+            min_sample_size: 200,
+            ev_samples: 500,
+        }
+    }
+}
+
+/// 히어로가 상대 스팟으로 이어지는 결정을 내리는 상태
+#[derive(Debug, Clone)]
+pub struct LeadIn {
+    /// 히어로 좌석
+    pub hero: usize,
+    /// 히어로가 결정하는 상태 (`to_act == hero`, 히어로 홀카드는 카운터 조정을 평가할 경계 핸드)
+    pub state: State,
+    /// 상대 스팟으로 이어지는 히어로의 공격 액션
+    pub action: Act,
+}
+
+/// 비교할 스팟
+#[derive(Debug, Clone)]
+pub struct SpotSpec {
+    /// 표시 이름 (예: "BTN 오픈 vs 3벳")
+    pub name: String,
+    pub kind: SpotKind,
+    /// 결정하는 상대 좌석
+    pub villain: usize,
+    /// 상대가 결정하는 상태 (`to_act == villain`)
+    pub state: State,
+    /// 이 스팟으로 이어지는 히어로의 결정 (없으면 카운터 조정 EV를 추정하지 않음)
+    pub lead_in: Option<LeadIn>,
+    /// 빅블라인드 크기 (EV 단위 변환)
+    pub big_blind: u32,
+    /// 히어로가 이 스팟을 만나는 100핸드당 횟수
+    pub opportunities_per_100: f64,
+}
+
+/// 편차를 공략하는 카운터 조정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CounterAdjustment {
+    /// 조정 설명 (예: "3벳 레인지 확장")
+    pub description: String,
+    /// 경계 핸드에서 새로 선택할 히어로 액션
+    pub action: Act,
+    /// 경계 핸드 한 번에서 기존 액션 대비 EV 이득 (빅블라인드)
+    pub ev_gain_bb: f64,
+    /// 100핸드당 기대 이득 (빅블라인드)
+    pub ev_bb_per_100: f64,
+}
+
+/// 베이스라인에서 벗어난 상대 성향 하나
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExploitFinding {
+    /// 스팟 이름
+    pub spot: String,
+    pub kind: SpotKind,
+    pub direction: LeakDirection,
+    /// 요약 (예: "3벳에 폴드 과다 (90% vs 베이스라인 50%)")
+    pub description: String,
+    /// 상대 모델이 예측한 빈도
+    pub model_frequency: f64,
+    /// 베이스라인 전략의 빈도
+    pub baseline_frequency: f64,
+    /// 모델 빈도 - 베이스라인 빈도
+    pub deviation: f64,
+    /// 베이스라인 노드가 있는 상대 홀카드 조합의 비율
+    pub baseline_coverage: f64,
+    /// 카운터 조정 (스팟으로 이어지는 히어로 결정이 없거나 방향이 모호하면 None)
+    pub counter: Option<CounterAdjustment>,
+}
+
+/// 익스플로잇 리포트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExploitReport {
+    pub player_id: u32,
+    /// 모델의 관찰 핸드 수
+    pub sample_size: u32,
+    /// 표본이 `min_sample_size` 이상인지
+    pub reliable: bool,
+    /// 카운터 조정의 100핸드당 이득이 큰 순서의 편차 (카운터 조정이 없는 항목은 마지막)
+    pub findings: Vec<ExploitFinding>,
+    /// 베이스라인 노드가 없어 비교하지 못한 스팟 이름
+    pub uncovered_spots: Vec<String>,
+}
+
+/// 기본 설정으로 익스플로잇 리포트 생성
+pub fn generate(model: &OpponentModel, baseline: &Trainer<State>, scenarios: &[SpotSpec]) -> ExploitReport {
+    generate_with_config(model, baseline, scenarios, &ExploitConfig::default())
+}
+
+/// 상대 모델을 베이스라인 전략과 스팟별로 비교해 익스플로잇 리포트 생성
+///
+/// 베이스라인 빈도는 상대의 모든 홀카드 조합에서 학습된 평균 전략을 평균한 값이며,
+/// 노드가 하나도 없는 스팟은 `uncovered_spots`로 보고됩니다. 카운터 조정 EV는
+/// 스팟의 상대 좌석이 모델 빈도로 행동하고 다른 좌석은 폴드하는 시뮬레이션으로 계산하며,
+/// 히어로가 편차 크기만큼 경계 핸드의 액션을 바꾼다고 가정해 100핸드당 이득으로 환산합니다.
+pub fn generate_with_config(
+    model: &OpponentModel,
+    baseline: &Trainer<State>,
+    scenarios: &[SpotSpec],
+    config: &ExploitConfig,
+) -> ExploitReport {
+    let mut findings = Vec::new();
+    let mut uncovered_spots = Vec::new();
+
+    for spot in scenarios {
+        let Some((baseline_frequencies, coverage)) = baseline_frequencies(baseline, spot) else {
+            uncovered_spots.push(spot.name.clone());
+            continue;
+        };
+        let frequencies = model_frequencies(model, spot.kind);
+
+        for action_class in 0..3 {
+            let deviation = frequencies[action_class] - baseline_frequencies[action_class];
+            if deviation.abs() <= config.threshold {
+                continue;
+            }
+            let direction = LeakDirection::from_deviation(action_class, deviation);
+            let action_name = spot.kind.action_names()[action_class];
+            findings.push(ExploitFinding {
+                spot: spot.name.clone(),
+                kind: spot.kind,
+                direction,
+                description: format!(
+                    "{} {} ({:.0}% vs 베이스라인 {:.0}%)",
+                    action_name,
+                    if direction.is_over() { "과다" } else { "부족" },
+                    frequencies[action_class] * 100.0,
+                    baseline_frequencies[action_class] * 100.0
+                ),
+                model_frequency: frequencies[action_class],
+                baseline_frequency: baseline_frequencies[action_class],
+                deviation,
+                baseline_coverage: coverage,
+                counter: counter_adjustment(spot, direction, deviation, frequencies, config),
+            });
+        }
+    }
+
+    findings.sort_by(|a, b| {
+        let value = |finding: &ExploitFinding| finding.counter.as_ref().map_or(f64::NEG_INFINITY, |c| c.ev_bb_per_100);
+        value(b)
+            .partial_cmp(&value(a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(b.deviation.abs().partial_cmp(&a.deviation.abs()).unwrap_or(std::cmp::Ordering::Equal))
+    });
+
+    ExploitReport {
+        player_id: model.player_id,
+        sample_size: model.sample_size,
+        reliable: model.sample_size >= config.min_sample_size,
+        findings,
+        uncovered_spots,
+    }
+}
+
+/// 표준 스팟 목록: 포지션별 오픈, 버튼 오픈에 대한 빅블라인드 대응, 3벳 대응, C벳 대응
+///
+/// 히어로와 상대는 버튼과 빅블라인드이며, 나머지 좌석은 폴드한 상태로 만듭니다.
+/// 히어로의 경계 핸드는 Q5o, C벳 스팟의 플랍은 K♠7♦2♣로 고정입니다.
+/// 각 스팟은 한 바퀴(참여 인원 수의 핸드)에 한 번 만난다고 가정합니다.
+pub fn standard_spots(blinds: [u32; 2], stacks: [u32; 6], player_count: usize) -> Vec<SpotSpec> {
+    let root = {
+        let mut root = State::new_hand(blinds, stacks, player_count);
+        root.hole = [[0; 2]; 6];
+        root
+    };
+    let button = root.button;
+    let big_blind_seat = if player_count == 2 { 1 } else { player_count - 1 };
+    let opportunities_per_100 = 100.0 / player_count as f64;
+    let spot = |name: String, kind: SpotKind, villain: usize, state: State, lead_in: Option<LeadIn>| SpotSpec {
+        name,
+        kind,
+        villain,
+        state,
+        lead_in,
+        big_blind: blinds[1],
+        opportunities_per_100,
+    };
+    let with_hero_hand = |mut state: State, hero: usize| {
+        state.hole[hero] = [make_card(10, 1), make_card(3, 3)]; // Qh 5c
+        state
+    };
+
+    let mut spots = Vec::new();
+
+    // 포지션별 오픈 (빅블라인드 제외)
+    let mut first_in = root.clone();
+    while first_in.to_act != big_blind_seat && !first_in.is_terminal() {
+        let seat = first_in.to_act;
+        let name = format!("{} 오픈", position_name(seat, button, player_count));
+        spots.push(spot(name, SpotKind::Open, seat, first_in.clone(), None));
+        first_in = State::next_state(&first_in, Act::Fold);
+    }
+
+    // 버튼 오픈 vs 빅블라인드 (버튼까지 폴드, 스몰블라인드도 폴드)
+    let button_open = fold_until(root.clone(), button);
+    let bb_facing_open = fold_until(State::next_state(&button_open, Act::Raise(0)), big_blind_seat);
+
+    // 히어로 버튼 오픈에 상대 빅블라인드 대응
+    spots.push(spot(
+        "BB vs BTN 오픈".to_string(),
+        SpotKind::FacingOpen,
+        big_blind_seat,
+        bb_facing_open.clone(),
+        Some(LeadIn {
+            hero: button,
+            state: with_hero_hand(button_open.clone(), button),
+            action: Act::Raise(0),
+        }),
+    ));
+
+    // 상대 버튼 오픈에 히어로 빅블라인드 3벳
+    let facing_three_bet = State::next_state(&bb_facing_open, Act::Raise(0));
+    spots.push(spot(
+        "BTN 오픈 vs 3벳".to_string(),
+        SpotKind::FacingThreeBet,
+        button,
+        facing_three_bet,
+        Some(LeadIn {
+            hero: big_blind_seat,
+            state: with_hero_hand(bb_facing_open.clone(), big_blind_seat),
+            action: Act::Raise(0),
+        }),
+    ));
+
+    // 히어로 버튼 오픈 후 빅블라인드 콜, 플랍 체크 후 히어로 C벳
+    let mut flop = State::apply_chance(&State::next_state(&bb_facing_open, Act::Call), &mut StdRng::seed_from_u64(0));
+    flop.board = vec![make_card(11, 0), make_card(5, 2), make_card(0, 3)];
+    let hero_cbet = State::next_state(&flop, Act::Call);
+    spots.push(spot(
+        "BB vs 플랍 C벳".to_string(),
+        SpotKind::FacingCbet,
+        big_blind_seat,
+        State::next_state(&hero_cbet, Act::Raise(0)),
+        Some(LeadIn {
+            hero: button,
+            state: with_hero_hand(hero_cbet, button),
+            action: Act::Raise(0),
+        }),
+    ));
+
+    spots
+}
+
+/// `seat` 차례가 될 때까지 앞 좌석을 폴드
+fn fold_until(mut state: State, seat: usize) -> State {
+    while state.to_act != seat && !state.is_terminal() && !state.is_chance_node() {
+        state = State::next_state(&state, Act::Fold);
+    }
+    state
+}
+
+/// 모델이 예측한 스팟의 [폴드, 콜, 레이즈] 빈도
+///
+/// 오픈과 오픈 대응은 VPIP/PFR/3벳 통계로, 3벳과 C벳 대응은 폴드 통계로 정하고
+/// 계속하는 빈도는 공격성(aggression)에 따라 콜과 레이즈로 나눕니다.
+fn model_frequencies(model: &OpponentModel, kind: SpotKind) -> [f64; 3] {
+    let vpip = model.vpip.clamp(0.0, 1.0);
+    let continuing = |fold: f64| {
+        let fold = fold.clamp(0.0, 1.0);
+        let aggression = model.aggression.max(0.0);
+        let raise_share = aggression / (aggression + 3.0);
+        [fold, (1.0 - fold) * (1.0 - raise_share), (1.0 - fold) * raise_share]
+    };
+
+    match kind {
+        SpotKind::Open => {
+            let pfr = model.pfr.clamp(0.0, vpip);
+            [1.0 - vpip, vpip - pfr, pfr]
+        }
+        SpotKind::FacingOpen => {
+            let three_bet = model.three_bet.clamp(0.0, vpip);
+            [1.0 - vpip, vpip - three_bet, three_bet]
+        }
+        SpotKind::FacingThreeBet => continuing(model.fold_to_three_bet),
+        SpotKind::FacingCbet => continuing(model.fold_to_cbet),
+    }
+}
+
+/// 베이스라인 전략의 스팟 [폴드, 콜, 레이즈] 빈도와 노드가 있는 홀카드 조합 비율
+fn baseline_frequencies(baseline: &Trainer<State>, spot: &SpotSpec) -> Option<([f64; 3], f64)> {
+    aggregate_frequencies(baseline, &spot.state, spot.villain)
+}
+
+/// 편차를 공략하는 히어로 조정과 그 EV 이득
+fn counter_adjustment(
+    spot: &SpotSpec,
+    direction: LeakDirection,
+    deviation: f64,
+    frequencies: [f64; 3],
+    config: &ExploitConfig,
+) -> Option<CounterAdjustment> {
+    let lead_in = spot.lead_in.as_ref()?;
+    let more_aggression = direction.exploit_with_aggression()?;
+
+    // 경계 핸드의 기존 액션: 공짜 체크가 가능하면 체크, 아니면 폴드
+    let passive = if lead_in.state.to_call <= lead_in.state.invested[lead_in.hero] {
+        Act::Call
+    } else {
+        Act::Fold
+    };
+    let (action, replaced) = if more_aggression {
+        (lead_in.action, passive)
+    } else {
+        (passive, lead_in.action)
+    };
+
+    let calculator = EVCalculator::new(EVConfig {
+        sample_count: config.ev_samples.max(1),
+        value_unit: ValueUnit::BigBlinds,
+        value_context: ValueContext {
+            big_blind: Some(spot.big_blind.max(1)),
+            ..ValueContext::default()
+        },
+        ..EVConfig::default()
+    })
+    .with_opponent_model(SpotOpponent {
+        villain: spot.villain,
+        frequencies,
+    });
+    // 상대 홀카드는 가리고 히어로 관점에서 계산
+    let evs = calculator.calculate_action_evs(&lead_in.state.observe(lead_in.hero).to_state());
+    let ev_of = |target: Act| evs.iter().find(|ev| ev.action == target).map(|ev| ev.ev);
+    let ev_gain_bb = ev_of(action)? - ev_of(replaced)?;
+
+    let description = match (spot.kind, more_aggression) {
+        (SpotKind::Open, _) => return None,
+        (SpotKind::FacingOpen, true) => "오픈 레인지 확장",
+        (SpotKind::FacingOpen, false) => "오픈 레인지 축소",
+        (SpotKind::FacingThreeBet, true) => "3벳 레인지 확장",
+        (SpotKind::FacingThreeBet, false) => "3벳 블러프 축소",
+        (SpotKind::FacingCbet, true) => "C벳 빈도 증가",
+        (SpotKind::FacingCbet, false) => "C벳 블러프 축소",
+    };
+
+    Some(CounterAdjustment {
+        description: description.to_string(),
+        action,
+        ev_gain_bb,
+        ev_bb_per_100: ev_gain_bb * deviation.abs() * spot.opportunities_per_100,
+    })
+}
+
+/// 스팟의 상대 좌석은 모델 빈도로, 나머지 좌석은 폴드로 행동하는 시뮬레이션 상대
+struct SpotOpponent {
+    villain: usize,
+    frequencies: [f64; 3],
+}
+
+impl OpponentActionModel for SpotOpponent {
+    fn action_probabilities(&self, observed: &ObservedState, actions: &[Act]) -> Vec<f64> {
+        let free_check = observed.to_call <= observed.invested[observed.player.min(5)];
+        let mut frequencies = if observed.player == self.villain {
+            self.frequencies
+        } else {
+            [1.0, 0.0, 0.0]
+        };
+        // 공짜 체크가 가능하면 폴드하지 않음
+        if free_check {
+            frequencies = [0.0, frequencies[0] + frequencies[1], frequencies[2]];
+        }
+        actions.iter().map(|action| frequencies[action_class(action)]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::cfr_core::Node;
+
+    /// 스팟의 모든 상대 홀카드 조합에 [폴드, 콜, 레이즈] 평균 전략을 가진 노드 삽입
+    fn seed_baseline(trainer: &mut Trainer<State>, spot: &SpotSpec, strategy: [f64; 3]) {
+        let deck = spot.state.variant.deck();
+        let mut state = spot.state.clone();
+        for (i, &first) in deck.iter().enumerate() {
+            for &second in &deck[i + 1..] {
+                state.hole[spot.villain] = [first, second];
+                let key = State::info_key(&state, spot.villain);
+                trainer.nodes.entry(key).or_insert_with(|| {
+                    let mut node = Node::new(3, vec![1.0; 3]);
+                    for (action, probability) in strategy.iter().enumerate() {
+                        node.update_strategy(action, *probability);
+                    }
+                    node
+                });
+            }
+        }
+    }
+
+    fn heads_up_spots() -> Vec<SpotSpec> {
+        standard_spots([50, 100], [10_000; 6], 2)
+    }
+
+    #[test]
+    fn test_over_folding_to_three_bets_is_exploited() {
+        let spots = heads_up_spots();
+        let facing_three_bet = spots.iter().find(|s| s.kind == SpotKind::FacingThreeBet).unwrap();
+        assert_eq!(State::legal_actions(&facing_three_bet.state).len(), 3);
+
+        let mut baseline = Trainer::new();
+        seed_baseline(&mut baseline, facing_three_bet, [0.5, 0.35, 0.15]);
+
+        let mut model = OpponentModel::new(7);
+        model.fold_to_three_bet = 0.9;
+        model.sample_size = 500;
+
+        let report = generate(&model, &baseline, &spots);
+        assert!(report.reliable);
+        assert_eq!(report.uncovered_spots.len(), spots.len() - 1);
+
+        let finding = &report.findings[0];
+        assert_eq!(finding.kind, SpotKind::FacingThreeBet);
+        assert_eq!(finding.direction, LeakDirection::OverFolds);
+        assert!((finding.model_frequency - 0.9).abs() < 1e-9);
+        assert!((finding.baseline_frequency - 0.5).abs() < 1e-9);
+        assert_eq!(finding.baseline_coverage, 1.0);
+
+        let counter = finding.counter.as_ref().expect("3벳 확장 카운터");
+        assert_eq!(counter.description, "3벳 레인지 확장");
+        assert_eq!(counter.action, Act::Raise(0));
+        assert!(counter.ev_gain_bb > 0.0, "{:?}", counter);
+        assert!(counter.ev_bb_per_100 > 0.0, "{:?}", counter);
+    }
+
+    #[test]
+    fn test_standard_spots_and_thresholds() {
+        let spots = standard_spots([50, 100], [10_000; 6], 6);
+        let opens: Vec<&str> = spots
+            .iter()
+            .filter(|s| s.kind == SpotKind::Open)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(opens, ["UTG 오픈", "HJ 오픈", "CO 오픈", "BTN 오픈", "SB 오픈"]);
+        for spot in &spots {
+            assert_eq!(spot.state.to_act, spot.villain, "{}", spot.name);
+            if let Some(lead_in) = &spot.lead_in {
+                assert_eq!(lead_in.state.to_act, lead_in.hero, "{}", spot.name);
+            }
+        }
+
+        // 베이스라인과 같은 빈도의 모델은 편차가 없음
+        let model = OpponentModel::new(1);
+        let cbet = spots.iter().find(|s| s.kind == SpotKind::FacingCbet).unwrap();
+        let mut baseline = Trainer::new();
+        seed_baseline(&mut baseline, cbet, model_frequencies(&model, SpotKind::FacingCbet));
+        let report = generate(&model, &baseline, &spots);
+        assert!(report.findings.is_empty(), "{:?}", report.findings);
+        assert!(!report.reliable);
+
+        // 기준치를 낮추면 작은 편차도 보고
+        let mut loose = model.clone();
+        loose.fold_to_cbet = 0.4;
+        let strict = ExploitConfig {
+            threshold: 0.01,
+            ev_samples: 50,
+            ..ExploitConfig::default()
+        };
+        assert!(generate(&loose, &baseline, &spots).findings.is_empty());
+        let report = generate_with_config(&loose, &baseline, &spots, &strict);
+        assert!(report.findings.iter().any(|f| f.direction == LeakDirection::UnderFolds));
+    }
+}
@@ -0,0 +1,217 @@
+// 머신러닝 학습용 핸드 특징 추출
+// 홀덤 상태 하나를 고정 순서의 숫자 벡터로 변환하고 CSV/NDJSON으로 일괄 내보냅니다.
+// 특정 ML 프레임워크에 의존하지 않습니다.
+
+use crate::game::card_abstraction::hand_class::HandClass;
+use crate::game::card_abstraction::{board_texture, hand_strength, has_flush_draw, straight_draw, StraightDraw};
+use crate::game::hand_eval::classify;
+use crate::game::holdem::State;
+use serde::{Deserialize, Serialize};
+
+/// 특징 이름 (벡터 순서와 같음)
+///
+/// | 이름 | 의미 |
+/// |---|---|
+/// | `hand_class` | 169 핸드 클래스 인덱스 (AA=0, ..., 22=168) |
+/// | `hand_strength` | `card_abstraction::hand_strength` 값 (0.0-1.0) |
+/// | `flush_draw` | 플러시 드로우 여부 (0/1) |
+/// | `open_ended_draw` | 양방향(또는 더블 거터샷) 스트레이트 드로우 여부 (0/1) |
+/// | `gutshot` | 거터샷 여부 (0/1) |
+/// | `board_max_suit` | 보드에서 가장 많은 수트의 장수 |
+/// | `board_paired` | 페어 보드 여부 (0/1) |
+/// | `board_connectedness` | 연속 5랭크 구간 안의 최대 보드 카드 수 |
+/// | `board_high_rank` | 가장 높은 보드 카드 랭크 (0=2, ..., 12=A, 보드 없으면 0) |
+/// | `pot_odds` | 콜 금액 / (팟 + 콜 금액), 콜할 금액이 없으면 0 |
+/// | `spr` | 유효 스택 / 팟 |
+/// | `seat` | 히어로 좌석 번호 |
+/// | `position` | 버튼 기준 상대 포지션 (0=버튼, 1=SB, 2=BB, ...) |
+/// | `opponents` | 폴드하지 않은 상대 수 |
+/// | `street` | 0=프리플랍, 1=플랍, 2=턴, 3=리버 |
+/// | `invested_fraction` | 현재 스트리트 투자금과 앤티가 (스택 + 그 금액)에서 차지하는 비율 |
+/// | `made_hand` | 홀카드 + 보드의 족보 순번 (`HandCategory::index`, 0=하이카드, 1=원페어, ..., 8=스트레이트 플러시) |
+pub const FEATURE_NAMES: [&str; 17] = [
+    "hand_class",
+    "hand_strength",
+    "flush_draw",
+    "open_ended_draw",
+    "gutshot",
+    "board_max_suit",
+    "board_paired",
+    "board_connectedness",
+    "board_high_rank",
+    "pot_odds",
+    "spr",
+    "seat",
+    "position",
+    "opponents",
+    "street",
+    "invested_fraction",
+    "made_hand",
+];
+
+/// 한 결정 지점의 특징 벡터 (`FEATURE_NAMES` 순서)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureVector {
+    pub values: Vec<f64>,
+}
+
+impl FeatureVector {
+    /// 특징 이름 (`values`와 같은 순서)
+    pub fn names() -> &'static [&'static str] {
+        &FEATURE_NAMES
+    }
+
+    /// 이름으로 특징 값 조회
+    pub fn get(&self, name: &str) -> Option<f64> {
+        FEATURE_NAMES
+            .iter()
+            .position(|&feature| feature == name)
+            .and_then(|index| self.values.get(index).copied())
+    }
+}
+
+/// `hero` 관점의 특징 벡터 추출
+///
+/// 히어로 본인의 홀카드와 공개 정보만 사용합니다.
+pub fn extract(state: &State, hero: usize) -> FeatureVector {
+    let hero = hero.min(5);
+    let hole = state.hole[hero];
+    let board = &state.board;
+    let flag = |value: bool| if value { 1.0 } else { 0.0 };
+
+    let draw = straight_draw(hole, board);
+    let cards: Vec<u8> = hole.iter().chain(board).copied().collect();
+    let texture = board_texture(board);
+
+    let call = state
+        .to_call
+        .saturating_sub(state.invested[hero])
+        .min(state.stack[hero]) as f64;
+    let pot = state.pot as f64;
+    let pot_odds = if call > 0.0 { call / (pot + call) } else { 0.0 };
+
+    let opponent_stack = (0..6)
+        .filter(|&seat| seat != hero && state.alive[seat])
+        .map(|seat| state.stack[seat])
+        .max()
+        .unwrap_or(0);
+    let effective_stack = state.stack[hero].min(opponent_stack) as f64;
+    let spr = if pot > 0.0 { effective_stack / pot } else { 0.0 };
+
+    let committed = (state.invested[hero] + state.antes[hero]) as f64;
+    let invested_fraction = if committed > 0.0 {
+        committed / (state.stack[hero] as f64 + committed)
+    } else {
+        0.0
+    };
+
+    let values = vec![
+        HandClass::from_cards(hole).to_index() as f64,
+        hand_strength(hole, board),
+        flag(has_flush_draw(hole, board)),
+        flag(draw == StraightDraw::OpenEnded),
+        flag(draw == StraightDraw::Gutshot),
+        texture.max_suit_count as f64,
+        flag(texture.paired),
+        texture.connectedness as f64,
+        texture.high_rank as f64,
+        pot_odds,
+        spr,
+        hero as f64,
+        ((hero + 6 - state.button % 6) % 6) as f64,
+        (0..6).filter(|&seat| seat != hero && state.alive[seat]).count() as f64,
+        state.street as f64,
+        invested_fraction,
+        classify(&cards).index() as f64,
+    ];
+    FeatureVector { values }
+}
+
+/// (상태, 히어로 좌석) 묶음을 헤더가 있는 CSV로 내보내기
+pub fn to_csv(samples: &[(State, usize)]) -> String {
+    let mut csv = FEATURE_NAMES.join(",");
+    csv.push('\n');
+    for (state, hero) in samples {
+        let row: Vec<String> = extract(state, *hero).values.iter().map(|value| value.to_string()).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// (상태, 히어로 좌석) 묶음을 한 줄에 하나씩 `{"이름": 값, ...}` JSON 객체로 내보내기
+pub fn to_ndjson(samples: &[(State, usize)]) -> String {
+    let mut ndjson = String::new();
+    for (state, hero) in samples {
+        let features = extract(state, *hero);
+        let object: serde_json::Map<String, serde_json::Value> = FEATURE_NAMES
+            .iter()
+            .zip(&features.values)
+            .map(|(name, &value)| (name.to_string(), serde_json::Value::from(value)))
+            .collect();
+        ndjson.push_str(&serde_json::Value::Object(object).to_string());
+        ndjson.push('\n');
+    }
+    ndjson
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::card_abstraction::hand_class::make_card;
+
+    /// 6맥스 플랍: 히어로(BTN, 3번 좌석) AhKh, 보드 Qh7h2c, 상대 BB만 남음
+    fn flop_state() -> State {
+        let mut state = State::new_hand([50, 100], [1000; 6], 6);
+        state.hole[3] = [make_card(12, 1), make_card(11, 1)];
+        state.board = vec![make_card(10, 1), make_card(5, 1), make_card(0, 3)];
+        state.street = 1;
+        state.alive = [false, false, false, true, false, true];
+        state.pot = 250;
+        state.stack = [1000, 1000, 1000, 900, 950, 750];
+        state.invested = [0, 0, 0, 0, 0, 150];
+        state.to_call = 150;
+        state
+    }
+
+    #[test]
+    fn test_extract_flush_draw_spot() {
+        let features = extract(&flop_state(), 3);
+        assert_eq!(features.values.len(), FeatureVector::names().len());
+
+        assert_eq!(features.get("hand_class"), Some(1.0)); // AKs
+        assert_eq!(features.get("flush_draw"), Some(1.0));
+        assert_eq!(features.get("open_ended_draw"), Some(0.0));
+        assert_eq!(features.get("gutshot"), Some(0.0));
+        assert_eq!(features.get("board_max_suit"), Some(2.0));
+        assert_eq!(features.get("board_high_rank"), Some(10.0));
+        assert_eq!(features.get("pot_odds"), Some(150.0 / 400.0));
+        assert_eq!(features.get("spr"), Some(750.0 / 250.0));
+        assert_eq!(features.get("position"), Some(0.0)); // 버튼
+        assert_eq!(features.get("opponents"), Some(1.0));
+        assert_eq!(features.get("street"), Some(1.0));
+        assert_eq!(features.get("invested_fraction"), Some(0.0));
+        assert_eq!(features.get("made_hand"), Some(0.0)); // 에이스 하이
+        assert_eq!(features.get("unknown"), None);
+    }
+
+    #[test]
+    fn test_batch_export() {
+        let preflop = State::new_hand([50, 100], [1000; 6], 6);
+        let samples = vec![(flop_state(), 3), (preflop, 5)];
+
+        let csv = to_csv(&samples);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], FEATURE_NAMES.join(","));
+        assert!(lines[1..].iter().all(|line| line.split(',').count() == FEATURE_NAMES.len()));
+
+        let ndjson = to_ndjson(&samples);
+        let rows: Vec<serde_json::Value> = ndjson.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0]["flush_draw"], 1.0);
+        // BB는 블라인드 100을 투자
+        assert_eq!(rows[1]["invested_fraction"], 100.0 / 1000.0);
+        assert_eq!(rows[1]["position"], 2.0);
+    }
+}
@@ -0,0 +1,256 @@
+// 좌석 에퀴티(포지션 가치) 리포트
+// 학습된 전략을 모든 좌석에 앉혀 같은 카드를 좌석별로 돌려가며 시뮬레이션하고,
+// 좌석별 핸드당 평균 획득 칩을 빅블라인드 단위와 신뢰구간으로 보고합니다.
+
+use crate::game::all_in::AllInAdjudicator;
+use crate::game::holdem::State;
+use crate::game::observation::{ActionPolicy, TrainerPolicy};
+use crate::solver::cfr_core::{Game, GameState, Trainer};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+/// 95% 신뢰구간 z 값
+const Z_95: f64 = 1.96;
+
+/// 한 핸드의 최대 액션 수 (홀덤 상태 머신은 액션 수 제한으로 항상 종료됨)
+const MAX_STEPS: usize = 256;
+
+/// 좌석 하나의 에퀴티
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SeatEquity {
+    /// 좌석 번호
+    pub seat: usize,
+    /// 포지션 이름 (UTG, HJ, CO, BTN, SB, BB)
+    pub position: String,
+    /// 핸드당 평균 획득 칩 (빅블라인드)
+    pub mean_bb: f64,
+    /// 평균의 표준 오차 (빅블라인드)
+    pub std_error_bb: f64,
+    /// 95% 신뢰구간 하한
+    pub ci_low_bb: f64,
+    /// 95% 신뢰구간 상한
+    pub ci_high_bb: f64,
+}
+
+/// 좌석 에퀴티 리포트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SeatEquityReport {
+    /// 참여 플레이어 수
+    pub player_count: usize,
+    /// 딜 수 (각 딜은 좌석 수만큼 돌려서 플레이)
+    pub deals: usize,
+    /// 실제 플레이한 핸드 수
+    pub hands_played: usize,
+    /// 좌석별 에퀴티 (참여 좌석만)
+    pub seats: Vec<SeatEquity>,
+    /// 좌석 에퀴티 합 (레이크가 없으면 0에 가까워야 함)
+    pub total_bb: f64,
+}
+
+impl SeatEquityReport {
+    /// 좌석 번호로 에퀴티 조회
+    pub fn seat(&self, seat: usize) -> Option<&SeatEquity> {
+        self.seats.iter().find(|equity| equity.seat == seat)
+    }
+
+    /// 포지션 이름으로 에퀴티 조회
+    pub fn position(&self, position: &str) -> Option<&SeatEquity> {
+        self.seats.iter().find(|equity| equity.position == position)
+    }
+}
+
+/// 학습된 전략으로 좌석별 기대 획득 칩 계산
+///
+/// `stacks`에서 앞쪽부터 연속된 0이 아닌 스택의 수가 참여 인원입니다 (2-6명).
+/// `hands`는 플레이할 핸드 수이며 좌석 수의 배수로 올림됩니다. 한 딜(홀카드 묶음과
+/// 보드)을 좌석마다 한 칸씩 돌려가며 플레이하는 듀플리케이트 방식이라 카드 운의
+/// 분산이 크게 줄어듭니다. 같은 `seed`는 같은 리포트를 만듭니다.
+pub fn compute(trainer: &Trainer<State>, stacks: [u32; 6], blinds: [u32; 2], hands: usize, seed: u64) -> SeatEquityReport {
+    let player_count = stacks.iter().take_while(|&&stack| stack > 0).count();
+    assert!(
+        (2..=6).contains(&player_count),
+        "좌석 에퀴티에는 2-6명의 스택이 필요합니다: {:?}",
+        stacks
+    );
+    assert!(blinds[1] > 0, "빅블라인드는 0보다 커야 합니다");
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut policy = TrainerPolicy::new(trainer, StdRng::seed_from_u64(seed ^ 0x5eed));
+    let deals = hands.div_ceil(player_count).max(1);
+    let big_blind = blinds[1] as f64;
+
+    // 딜 단위 결과 (로테이션 평균) - 딜끼리는 독립 표본
+    let mut sums = [0.0; 6];
+    let mut squares = [0.0; 6];
+    for _ in 0..deals {
+        let mut deck: Vec<u8> = (0..52).collect();
+        deck.shuffle(&mut rng);
+        let (holes, board) = deck.split_at(player_count * 2);
+        let board = &board[..5];
+
+        let mut per_deal = [0.0; 6];
+        for rotation in 0..player_count {
+            let mut root = State::new_hand(blinds, stacks, player_count);
+            for seat in 0..player_count {
+                let hand = (seat + rotation) % player_count;
+                root.hole[seat] = [holes[hand * 2], holes[hand * 2 + 1]];
+            }
+            let result = play_duplicate_hand(&root, board, &mut policy, &mut rng);
+            for seat in 0..player_count {
+                per_deal[seat] += result[seat] / big_blind / player_count as f64;
+            }
+        }
+        for seat in 0..player_count {
+            sums[seat] += per_deal[seat];
+            squares[seat] += per_deal[seat] * per_deal[seat];
+        }
+    }
+
+    let root = State::new_hand(blinds, stacks, player_count);
+    let n = deals as f64;
+    let seats: Vec<SeatEquity> = (0..player_count)
+        .map(|seat| {
+            let mean = sums[seat] / n;
+            let variance = if deals > 1 {
+                ((squares[seat] - n * mean * mean) / (n - 1.0)).max(0.0)
+            } else {
+                0.0
+            };
+            let std_error = (variance / n).sqrt();
+            SeatEquity {
+                seat,
+                position: position_name(seat, root.button, player_count).to_string(),
+                mean_bb: mean,
+                std_error_bb: std_error,
+                ci_low_bb: mean - Z_95 * std_error,
+                ci_high_bb: mean + Z_95 * std_error,
+            }
+        })
+        .collect();
+
+    SeatEquityReport {
+        player_count,
+        deals,
+        hands_played: deals * player_count,
+        total_bb: seats.iter().map(|seat| seat.mean_bb).sum(),
+        seats,
+    }
+}
+
+/// 고정된 보드로 한 핸드를 끝까지 진행하고 좌석별 순이익(칩) 반환
+///
+/// `State::util`은 스트리트별 투자액만 보므로 여기서는 핸드 전체의 스택 변화로 계산합니다.
+/// 리버 전에 끝난 쇼다운은 남은 보드를 같은 딜의 카드로 채워 판정합니다.
+fn play_duplicate_hand(root: &State, board: &[u8], policy: &mut dyn ActionPolicy, rng: &mut StdRng) -> [f64; 6] {
+    let mut state = root.clone();
+    for _ in 0..MAX_STEPS {
+        if state.is_terminal() {
+            break;
+        }
+        if state.is_chance_node() {
+            let dealt = state.board.len();
+            state = State::apply_chance(&state, rng);
+            let len = state.board.len();
+            state.board[dealt..len].copy_from_slice(&board[dealt..len]);
+            continue;
+        }
+        let Some(player) = State::current_player(&state) else {
+            break;
+        };
+        let legal_actions = State::legal_actions(&state);
+        if legal_actions.is_empty() {
+            break;
+        }
+        let action = policy.choose_action(&state.observe(player), &legal_actions);
+        state = State::next_state(&state, action);
+    }
+
+    let alive: Vec<usize> = (0..6).filter(|&seat| state.alive[seat]).collect();
+    let net_pot = state.net_pot() as f64;
+    let mut shares = [0.0; 6];
+    if alive.len() == 1 {
+        shares[alive[0]] = 1.0;
+    } else {
+        let mut showdown = state.clone();
+        showdown.board = board.to_vec();
+        let equity = AllInAdjudicator::deterministic().state_equity(&showdown);
+        for &seat in &alive {
+            shares[seat] = equity.equities[seat];
+        }
+    }
+
+    let mut result = [0.0; 6];
+    for seat in 0..6 {
+        if root.alive[seat] {
+            let contributed = (root.stack[seat] + root.invested[seat] + root.antes[seat] - state.stack[seat]) as f64;
+            result[seat] = shares[seat] * net_pot - contributed;
+        }
+    }
+    result
+}
+
+/// 버튼 기준 포지션 이름
+pub(crate) fn position_name(seat: usize, button: usize, player_count: usize) -> &'static str {
+    let offset = (seat + player_count - button) % player_count;
+    if player_count == 2 {
+        return if offset == 0 { "BTN" } else { "BB" };
+    }
+    match (offset, player_count - offset) {
+        (0, _) => "BTN",
+        (1, _) => "SB",
+        (2, _) => "BB",
+        (_, 1) => "CO",
+        (_, 2) => "HJ",
+        _ => "UTG",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::cfr_core::LimitPolicy;
+
+    #[test]
+    fn test_position_names_follow_button() {
+        // 6맥스 new_hand는 버튼이 3번 좌석
+        let names: Vec<&str> = (0..6).map(|seat| position_name(seat, 3, 6)).collect();
+        assert_eq!(names, ["UTG", "HJ", "CO", "BTN", "SB", "BB"]);
+        assert_eq!(position_name(0, 0, 2), "BTN");
+        assert_eq!(position_name(1, 0, 2), "BB");
+    }
+
+    #[test]
+    fn test_button_outearns_utg_and_seats_sum_to_zero() {
+        // 3BB 숏스택 6맥스 - 전체 트리는 한 반복도 크므로 노드 수를 제한해 일부만 학습
+        let stacks = [300; 6];
+        let blinds = [50, 100];
+        let mut trainer = Trainer::<State>::new();
+        trainer.set_seed(17);
+        trainer.set_node_limit(2_000, LimitPolicy::Abort);
+        let _ = trainer.try_run(vec![State::new_hand(blinds, stacks, 6)], 1);
+
+        let report = compute(&trainer, stacks, blinds, 20_000, 42);
+        assert_eq!(report.player_count, 6);
+        assert_eq!(report.hands_played, 20_004);
+
+        let button = report.position("BTN").unwrap();
+        let utg = report.position("UTG").unwrap();
+        assert!(
+            button.mean_bb > utg.mean_bb,
+            "버튼 {:?}가 UTG {:?}보다 가치가 높아야 함",
+            button,
+            utg
+        );
+        assert!(report.total_bb.abs() < 1e-6, "레이크 없는 좌석 에퀴티 합: {}", report.total_bb);
+        for seat in &report.seats {
+            assert!(seat.ci_low_bb <= seat.mean_bb && seat.mean_bb <= seat.ci_high_bb);
+        }
+
+        // 같은 시드는 같은 리포트
+        assert_eq!(compute(&trainer, stacks, blinds, 60, 42), compute(&trainer, stacks, blinds, 60, 42));
+    }
+}
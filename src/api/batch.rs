@@ -0,0 +1,134 @@
+// 배치 분석 평탄화 모듈
+// 여러 상황의 전략 응답을 고정 stride의 f64 배열 하나로 펼쳐
+// WASM에서 JSON 파싱 없이 Float64Array로 넘길 수 있게 합니다.
+
+use crate::api::web_api::{PokerWebAPI, StrategyResponse, WebGameState};
+use crate::game::value_unit::ValueUnit;
+use serde::{Deserialize, Serialize};
+
+/// 상황 하나가 차지하는 f64 개수
+pub const BATCH_STRIDE: usize = 5;
+
+/// 한 줄 안의 필드 순서
+pub const BATCH_FIELDS: [&str; BATCH_STRIDE] = ["fold", "call", "raise", "ev", "confidence"];
+
+/// 평탄화된 배치 결과의 레이아웃 설명 (`analyze_batch_meta`가 JSON으로 반환)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchLayout {
+    /// 상황 하나가 차지하는 값 개수 (`i`번째 상황은 `[i * stride, (i + 1) * stride)`)
+    pub stride: usize,
+    /// 한 줄 안의 필드 이름 (순서대로)
+    pub fields: Vec<String>,
+    /// `ev` 필드의 단위
+    pub value_unit: ValueUnit,
+    /// `raise`에 합산되는 응답 액션 이름 접두사와 이름
+    pub raise_actions: Vec<String>,
+}
+
+/// 배치 결과 레이아웃
+pub fn batch_layout() -> BatchLayout {
+    BatchLayout {
+        stride: BATCH_STRIDE,
+        fields: BATCH_FIELDS.iter().map(|field| field.to_string()).collect(),
+        value_unit: ValueUnit::Chips,
+        raise_actions: vec!["raise*".to_string(), "all_in".to_string()],
+    }
+}
+
+/// 응답 하나를 `[fold, call, raise, ev, confidence]`로 평탄화
+///
+/// 레이즈 크기별 확률(`raise_*`)과 `all_in`은 `raise` 하나로 합치고, 응답에 없는 액션은 0입니다.
+pub fn flatten_response(response: &StrategyResponse) -> [f64; BATCH_STRIDE] {
+    let probability = |name: &str| response.strategy.get(name).copied().unwrap_or(0.0);
+    let raise: f64 = response
+        .strategy
+        .iter()
+        .filter(|(name, _)| name.starts_with("raise") || name.as_str() == "all_in")
+        .map(|(_, prob)| prob)
+        .sum();
+    [
+        probability("fold"),
+        probability("call"),
+        raise,
+        response.expected_value,
+        response.confidence,
+    ]
+}
+
+/// 여러 상황을 분석해 `states.len() * BATCH_STRIDE` 길이의 배열로 반환
+pub fn analyze_batch_flat(api: &PokerWebAPI, states: &[WebGameState]) -> Vec<f64> {
+    let mut flat = Vec::with_capacity(states.len() * BATCH_STRIDE);
+    for state in states {
+        flat.extend(flatten_response(&api.get_optimal_strategy(state.clone())));
+    }
+    flat
+}
+
+/// JSON 배열(`WebGameState` 목록)을 분석해 평탄화된 배열로 반환
+pub fn analyze_batch_json(api: &PokerWebAPI, states_json: &str) -> Result<Vec<f64>, String> {
+    let states: Vec<WebGameState> =
+        serde_json::from_str(states_json).map_err(|e| format!("상태 목록 파싱 실패: {}", e))?;
+    Ok(analyze_batch_flat(api, &states))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "training")]
+    use crate::api::web_api::{OfflineTrainer, StrategyTable};
+
+    #[cfg(feature = "training")]
+    fn state(hole_cards: [u8; 2], to_call: u32) -> WebGameState {
+        WebGameState {
+            hole_cards,
+            board: vec![],
+            street: 0,
+            pot: 150,
+            stacks: vec![1000, 1000],
+            alive_players: vec![0, 1],
+            street_investments: vec![50, 100],
+            to_call,
+            player_to_act: 0,
+            hero_position: 0,
+            betting_history: vec![],
+            seat_status: vec![],
+            posted: vec![],
+            straddle: None,
+        }
+    }
+
+    #[cfg(feature = "training")]
+    #[test]
+    fn test_batch_matches_single_calls() {
+        let trainer = OfflineTrainer::train_simple_strategy(20);
+        let bytes = StrategyTable::from_trained_cfr(&trainer).to_bytes().unwrap();
+        let api = PokerWebAPI::from_table(StrategyTable::from_bytes(&bytes).unwrap(), None);
+
+        let states = vec![state([0, 13], 100), state([1, 20], 50), state([5, 31], 0)];
+        let json = serde_json::to_string(&states).unwrap();
+        let flat = analyze_batch_json(&api, &json).unwrap();
+        assert_eq!(flat.len(), states.len() * BATCH_STRIDE);
+
+        for (row, state) in flat.chunks(BATCH_STRIDE).zip(&states) {
+            let single = api.get_optimal_strategy(state.clone());
+            let expected = flatten_response(&single);
+            for (value, expected) in row.iter().zip(expected) {
+                assert!((value - expected).abs() < 1e-9, "{:?} vs {:?}", row, expected);
+            }
+            let total: f64 = single.strategy.values().sum();
+            assert!((row[0] + row[1] + row[2] - total).abs() < 1e-9);
+        }
+
+        assert!(analyze_batch_json(&api, "not json").is_err());
+        assert!(analyze_batch_json(&api, "[]").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_layout_describes_stride() {
+        let layout = batch_layout();
+        assert_eq!(layout.stride, BATCH_STRIDE);
+        assert_eq!(layout.fields.len(), layout.stride);
+        let json = serde_json::to_string(&layout).unwrap();
+        assert_eq!(serde_json::from_str::<BatchLayout>(&json).unwrap(), layout);
+    }
+}
@@ -34,6 +34,8 @@ impl StateSignature {
         web_state.stacks.hash(&mut players_hasher);
         web_state.alive_players.hash(&mut players_hasher);
         web_state.street_investments.hash(&mut players_hasher);
+        web_state.seat_status.hash(&mut players_hasher);
+        web_state.posted.hash(&mut players_hasher);
         let players_hash = players_hasher.finish();
         
         // 보드 카드 해시
@@ -289,7 +291,7 @@ pub fn clear_cache() {
 mod tests {
     use super::*;
     use crate::api::web_api::WebGameState;
-    use crate::api::analysis::{AnalysisRequest, AnalysisOptions, OpponentModel};
+    use crate::api::analysis::{AnalysisRequest, AnalysisOptions, OpponentModelKind};
 
     #[test]
     fn test_state_signature() {
@@ -339,8 +341,12 @@ mod tests {
                 include_range_analysis: false,
                 include_equity_calculation: false,
                 max_calculation_time_ms: None,
-                opponent_modeling: OpponentModel::Tight,
+                opponent_modeling: OpponentModelKind::Tight,
+                counterfactuals: false,
+                debug: false,
+                rake: None,
             },
+            action_history: Vec::new(),
         };
         
         // 첫 번째 요청 (캐시 미스)
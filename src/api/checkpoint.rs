@@ -0,0 +1,371 @@
+// 재개 가능한 오프라인 학습
+// 긴 학습 작업이 중간에 죽어도 마지막 체크포인트부터 이어서 학습할 수 있도록
+// 학습기 상태를 주기적으로 디스크에 저장합니다.
+
+use crate::api::web_api::OfflineTrainer;
+use crate::game::holdem;
+use crate::solver::cfr_core::{Game, Trainer, TrainerSnapshot};
+use crate::solver::compat::{CompatibilityFingerprint, MigrationRegistry, FINGERPRINT_METADATA_KEY};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// 체크포인트 파일 형식 버전 (2: 학습기 메타데이터 추가, 3: 호환성 지문 추가)
+pub const CHECKPOINT_FORMAT_VERSION: u32 = 3;
+
+/// 지문 없이 읽을 수 있는 이전 체크포인트 형식 버전
+const LEGACY_CHECKPOINT_FORMAT_VERSION: u32 = 2;
+
+const CHECKPOINT_PREFIX: &str = "checkpoint-";
+const CHECKPOINT_EXTENSION: &str = ".bin";
+
+/// 체크포인트 학습 설정
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointConfig {
+    /// 블라인드 [스몰, 빅]
+    pub blinds: [u32; 2],
+    /// 시작 스택
+    pub stacks: [u32; 6],
+    /// 참여 플레이어 수
+    pub player_count: usize,
+    /// 목표 CFR 반복 수
+    pub iterations: usize,
+    /// 찬스 노드 시드 (재개한 학습이 중단 없는 학습과 같아지도록 항상 고정)
+    pub seed: u64,
+    /// 디스크에 남겨 둘 최근 체크포인트 수 (최소 1)
+    pub keep_last: usize,
+}
+
+impl Default for CheckpointConfig {
+    fn default() -> Self {
+        Self {
+            blinds: [50, 100],
+            stacks: [10_000; 6],
+            player_count: 2,
+            iterations: 1_000,
+            seed: 0,
+            keep_last: 3,
+        }
+    }
+}
+
+impl CheckpointConfig {
+    /// 이 설정으로 학습한 전략의 호환성 지문
+    pub fn fingerprint(&self) -> CompatibilityFingerprint {
+        holdem::State::fingerprint(Some(&self.root()))
+    }
+
+    /// 학습 루트 상태
+    ///
+    /// 재개한 작업도 같은 루트로 학습하도록 홀카드를 `seed`로 딜링합니다.
+    pub fn root(&self) -> holdem::State {
+        let mut root = holdem::State::new_hand(self.blinds, self.stacks, self.player_count);
+        let mut deck: Vec<u8> = (0..52).collect();
+        deck.shuffle(&mut StdRng::seed_from_u64(self.seed));
+        for (seat, hole) in root.hole.iter_mut().enumerate().take(self.player_count.min(6)) {
+            *hole = [deck[seat * 2], deck[seat * 2 + 1]];
+        }
+        root
+    }
+}
+
+/// 체크포인트 파일 내용 (bincode 직렬화)
+#[derive(Serialize, Deserialize)]
+struct CheckpointFile {
+    version: u32,
+    /// 체크포인트를 쓴 빌드의 호환성 지문
+    fingerprint: CompatibilityFingerprint,
+    config: CheckpointConfig,
+    every_n_iterations: usize,
+    /// 완료된 반복 수
+    iteration: usize,
+    trainer: TrainerSnapshot<u64>,
+}
+
+/// 지문이 없는 버전 2 체크포인트 파일
+#[derive(Deserialize)]
+struct LegacyCheckpointFile {
+    config: CheckpointConfig,
+    every_n_iterations: usize,
+    iteration: usize,
+    trainer: TrainerSnapshot<u64>,
+}
+
+impl OfflineTrainer {
+    /// 체크포인트를 남기며 처음부터 학습
+    ///
+    /// `every_n_iterations` 반복마다, 그리고 학습이 끝날 때 `checkpoint_dir`에 체크포인트를 씁니다.
+    /// 파일은 임시 파일에 쓴 뒤 이름을 바꿔 원자적으로 교체되며, 최근 `keep_last`개만 남습니다.
+    /// 디렉터리에 있던 이전 작업의 체크포인트는 시작할 때 삭제됩니다.
+    pub fn train_with_checkpoints(
+        config: &CheckpointConfig,
+        checkpoint_dir: impl AsRef<Path>,
+        every_n_iterations: usize,
+    ) -> io::Result<Trainer<holdem::State>> {
+        let dir = checkpoint_dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        for (_, path) in list_checkpoints(dir)? {
+            std::fs::remove_file(path)?;
+        }
+
+        let mut trainer = Trainer::new();
+        trainer.set_seed(config.seed);
+        run_checkpointed(&mut trainer, config, dir, every_n_iterations.max(1), 0, config.iterations)?;
+        Ok(trainer)
+    }
+
+    /// 가장 최근의 유효한 체크포인트부터 목표 반복 수까지 이어서 학습
+    ///
+    /// 읽을 수 없거나 손상된 체크포인트와 현재 빌드와 호환되지 않는 지문의 체크포인트는
+    /// 경고를 출력하고 건너뜁니다. 유효한 체크포인트가 없으면 `NotFound` 에러를 반환합니다.
+    pub fn resume_from(checkpoint_dir: impl AsRef<Path>) -> io::Result<Trainer<holdem::State>> {
+        let dir = checkpoint_dir.as_ref();
+        let file = latest_valid_checkpoint(dir)?;
+        let mut trainer =
+            Trainer::from_snapshot_verified(file.trainer, &file.config.fingerprint(), &MigrationRegistry::builtin())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        run_checkpointed(
+            &mut trainer,
+            &file.config,
+            dir,
+            file.every_n_iterations.max(1),
+            file.iteration,
+            file.config.iterations,
+        )?;
+        Ok(trainer)
+    }
+}
+
+/// `start`부터 `stop`까지 학습하며 체크포인트 저장
+///
+/// 반복을 나눠 실행해도 시드 난수와 누적 반복 수가 이어지므로 한 번에 학습한 것과 같습니다.
+fn run_checkpointed(
+    trainer: &mut Trainer<holdem::State>,
+    config: &CheckpointConfig,
+    dir: &Path,
+    every_n_iterations: usize,
+    start: usize,
+    stop: usize,
+) -> io::Result<()> {
+    let root = config.root();
+    let mut iteration = start;
+    while iteration < stop {
+        let next_checkpoint = (iteration / every_n_iterations + 1) * every_n_iterations;
+        let step = next_checkpoint.min(stop) - iteration;
+        trainer.run(vec![root.clone()], step);
+        iteration += step;
+
+        if iteration.is_multiple_of(every_n_iterations) || iteration == config.iterations {
+            write_checkpoint(trainer, config, dir, every_n_iterations, iteration)?;
+        }
+    }
+    Ok(())
+}
+
+fn checkpoint_path(dir: &Path, iteration: usize) -> PathBuf {
+    dir.join(format!("{}{:010}{}", CHECKPOINT_PREFIX, iteration, CHECKPOINT_EXTENSION))
+}
+
+/// 체크포인트를 원자적으로 쓰고 오래된 체크포인트 정리
+fn write_checkpoint(
+    trainer: &Trainer<holdem::State>,
+    config: &CheckpointConfig,
+    dir: &Path,
+    every_n_iterations: usize,
+    iteration: usize,
+) -> io::Result<()> {
+    let file = CheckpointFile {
+        version: CHECKPOINT_FORMAT_VERSION,
+        fingerprint: trainer.fingerprint(),
+        config: config.clone(),
+        every_n_iterations,
+        iteration,
+        trainer: trainer.snapshot(),
+    };
+    let bytes = bincode::serialize(&file).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let path = checkpoint_path(dir, iteration);
+    let temp = path.with_extension("tmp");
+    std::fs::write(&temp, bytes)?;
+    std::fs::rename(&temp, &path)?;
+
+    let checkpoints = list_checkpoints(dir)?;
+    let stale = checkpoints.len().saturating_sub(config.keep_last.max(1));
+    for (_, old) in checkpoints.into_iter().take(stale) {
+        std::fs::remove_file(old)?;
+    }
+    Ok(())
+}
+
+/// 디렉터리의 체크포인트 파일들 (반복 수 오름차순)
+fn list_checkpoints(dir: &Path) -> io::Result<Vec<(usize, PathBuf)>> {
+    let mut checkpoints = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let iteration = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(CHECKPOINT_PREFIX))
+            .and_then(|name| name.strip_suffix(CHECKPOINT_EXTENSION))
+            .and_then(|digits| digits.parse::<usize>().ok());
+        if let Some(iteration) = iteration {
+            checkpoints.push((iteration, path));
+        }
+    }
+    checkpoints.sort();
+    Ok(checkpoints)
+}
+
+/// 체크포인트를 읽고 현재 빌드와 호환되는지 검사 (버전 2 파일은 기준 버전 지문으로 간주)
+fn read_checkpoint(path: &Path) -> Result<CheckpointFile, String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    let corrupt = |e: bincode::Error| format!("체크포인트 역직렬화 실패: {}", e);
+    let mut file = match bincode::deserialize::<u32>(&bytes).map_err(corrupt)? {
+        CHECKPOINT_FORMAT_VERSION => bincode::deserialize::<CheckpointFile>(&bytes).map_err(corrupt)?,
+        LEGACY_CHECKPOINT_FORMAT_VERSION => {
+            let (version, legacy): (u32, LegacyCheckpointFile) = bincode::deserialize(&bytes).map_err(corrupt)?;
+            CheckpointFile {
+                version,
+                fingerprint: legacy.config.fingerprint().with_baseline_versions(),
+                config: legacy.config,
+                every_n_iterations: legacy.every_n_iterations,
+                iteration: legacy.iteration,
+                trainer: legacy.trainer,
+            }
+        }
+        version => {
+            return Err(format!(
+                "지원하지 않는 체크포인트 버전: {} (지원: {})",
+                version, CHECKPOINT_FORMAT_VERSION
+            ))
+        }
+    };
+    MigrationRegistry::builtin()
+        .check(&file.fingerprint, &file.config.fingerprint())
+        .map_err(|e| e.to_string())?;
+    // 복원할 때 파일에 기록된 지문으로 검사/마이그레이션하도록 학습기 메타데이터에 맞춰 둠
+    file.trainer
+        .metadata
+        .insert(FINGERPRINT_METADATA_KEY.to_string(), file.fingerprint.to_metadata_value());
+    Ok(file)
+}
+
+fn latest_valid_checkpoint(dir: &Path) -> io::Result<CheckpointFile> {
+    for (_, path) in list_checkpoints(dir)?.into_iter().rev() {
+        match read_checkpoint(&path) {
+            Ok(file) => return Ok(file),
+            Err(e) => eprintln!("⚠️ 손상된 체크포인트를 건너뜁니다 ({}): {}", path.display(), e),
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("유효한 체크포인트가 없습니다: {}", dir.display()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("nice-hand-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn config() -> CheckpointConfig {
+        // 헤즈업 3BB 스택 (빠른 학습)
+        CheckpointConfig {
+            stacks: [300; 6],
+            iterations: 200,
+            seed: 7,
+            keep_last: 2,
+            ..CheckpointConfig::default()
+        }
+    }
+
+    fn sorted_strategies(trainer: &Trainer<holdem::State>) -> Vec<(u64, Vec<f64>)> {
+        let mut strategies: Vec<(u64, Vec<f64>)> =
+            trainer.nodes.iter().map(|(key, node)| (*key, node.average())).collect();
+        strategies.sort_by_key(|(key, _)| *key);
+        strategies
+    }
+
+    #[test]
+    fn test_resumed_training_matches_uninterrupted_run() {
+        let config = config();
+        let dir = test_dir("resume");
+
+        let mut uninterrupted = Trainer::new();
+        uninterrupted.set_seed(config.seed);
+        uninterrupted.run(vec![config.root()], 200);
+
+        // 120 반복 후 중단된 작업: 50, 100 체크포인트만 남음
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut interrupted = Trainer::new();
+        interrupted.set_seed(config.seed);
+        run_checkpointed(&mut interrupted, &config, &dir, 50, 0, 120).unwrap();
+        let on_disk: Vec<usize> = list_checkpoints(&dir).unwrap().into_iter().map(|(i, _)| i).collect();
+        assert_eq!(on_disk, [50, 100]);
+
+        let resumed = OfflineTrainer::resume_from(&dir).unwrap();
+        assert_eq!(resumed.iterations_run(), 200);
+        assert_eq!(sorted_strategies(&resumed), sorted_strategies(&uninterrupted));
+
+        // 최근 2개만 유지
+        let on_disk: Vec<usize> = list_checkpoints(&dir).unwrap().into_iter().map(|(i, _)| i).collect();
+        assert_eq!(on_disk, [150, 200]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_truncated_checkpoint_is_skipped() {
+        let config = CheckpointConfig {
+            iterations: 60,
+            ..config()
+        };
+        let dir = test_dir("truncated");
+        let trained = OfflineTrainer::train_with_checkpoints(&config, &dir, 20).unwrap();
+
+        // 마지막 체크포인트가 쓰다 만 파일이 된 경우
+        let latest = checkpoint_path(&dir, 60);
+        let bytes = std::fs::read(&latest).unwrap();
+        std::fs::write(&latest, &bytes[..bytes.len() / 2]).unwrap();
+
+        let resumed = OfflineTrainer::resume_from(&dir).unwrap();
+        assert_eq!(resumed.iterations_run(), 60);
+        assert_eq!(sorted_strategies(&resumed), sorted_strategies(&trained));
+
+        // 유효한 체크포인트가 하나도 없으면 에러
+        let empty = test_dir("empty");
+        std::fs::create_dir_all(&empty).unwrap();
+        std::fs::write(checkpoint_path(&empty, 10), b"garbage").unwrap();
+        let error = OfflineTrainer::resume_from(&empty).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        std::fs::remove_dir_all(&empty).unwrap();
+    }
+
+    #[test]
+    fn test_checkpoint_with_other_fingerprint_is_rejected() {
+        let config = config();
+        let dir = test_dir("fingerprint");
+        std::fs::create_dir_all(&dir).unwrap();
+        write_checkpoint(&Trainer::new(), &config, &dir, 10, 10).unwrap();
+        let path = checkpoint_path(&dir, 10);
+        assert!(read_checkpoint(&path).is_ok());
+
+        let mut file: CheckpointFile = bincode::deserialize(&std::fs::read(&path).unwrap()).unwrap();
+        file.fingerprint.action_abstraction.pop();
+        std::fs::write(&path, bincode::serialize(&file).unwrap()).unwrap();
+        let error = read_checkpoint(&path).err().unwrap();
+        assert!(error.contains("action_abstraction"), "{}", error);
+        assert_eq!(OfflineTrainer::resume_from(&dir).err().unwrap().kind(), io::ErrorKind::NotFound);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
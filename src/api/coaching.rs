@@ -0,0 +1,497 @@
+// 코칭 설명 모듈
+// 전략 엔진이 이미 계산하는 지표(핸드 강도, 팟 오즈, 포지션, 스택 깊이, 폴드 에퀴티, ICM 압박)와
+// 고른 액션을 구조화된 설명(`Explanation`)으로 만들고, 문장은 로케일별 `render`에서만 만듭니다.
+// 클라이언트는 설명 객체를 직접 그리거나 원하는 언어로 렌더링할 수 있습니다.
+
+use crate::game::hand_eval::HandCategory;
+use crate::game::holdem::Act;
+use crate::game::value_unit::{ValueContext, ValueUnit};
+use serde::{Deserialize, Serialize};
+
+/// 이 값 이상의 핸드 강도는 공격 쪽 요인
+const STRONG_HAND_STRENGTH: f64 = 0.5;
+/// 스택 대 팟 비율이 이보다 작으면 숏스택 (공격 쪽 요인)
+const SHORT_SPR: f64 = 3.0;
+/// 스택 대 팟 비율이 이보다 크면 딥스택 (신중 쪽 요인)
+const DEEP_SPR: f64 = 10.0;
+/// 이보다 작은 ICM 압박은 설명에서 생략
+const MIN_ICM_PRESSURE: f64 = 0.05;
+
+/// 설명 요인 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FactorKind {
+    /// 핸드 강도 (값: 0-1)
+    HandStrength,
+    /// 팟 오즈 (값: 콜에 필요한 에퀴티 0-1)
+    PotOdds,
+    /// 포지션 (값: 뒤에 남은 플레이어 수)
+    Position,
+    /// 스택 깊이 (값: 스택 대 팟 비율)
+    StackDepth,
+    /// 폴드 에퀴티 (값: 공격적인 액션이 수동적인 액션보다 나은 EV)
+    FoldEquity,
+    /// ICM 압박 (값: 0-1, 탈락 손실이 더블업 이득보다 큰 정도)
+    IcmPressure,
+}
+
+impl FactorKind {
+    /// 기본 가중치 (설명에 들어간 요인끼리 합이 1이 되도록 정규화됨)
+    fn base_weight(self) -> f64 {
+        match self {
+            Self::HandStrength => 0.35,
+            Self::PotOdds => 0.25,
+            Self::Position | Self::StackDepth | Self::FoldEquity | Self::IcmPressure => 0.1,
+        }
+    }
+}
+
+/// 요인이 가리키는 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Direction {
+    /// 공격적인 플레이(베팅, 레이즈, 계속 진행) 쪽
+    For,
+    /// 신중한 플레이(체크, 폴드) 쪽
+    Against,
+}
+
+/// 결정에 영향을 준 요인 하나
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Factor {
+    pub kind: FactorKind,
+    pub direction: Direction,
+    /// 설명 안에서의 비중 (모든 요인의 합이 1)
+    pub weight: f64,
+    /// 요인 값 (단위는 `FactorKind` 참고)
+    pub value: f64,
+}
+
+/// 권장 액션
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ActionAdvice {
+    Fold,
+    #[default]
+    Check,
+    Call,
+    Raise,
+    /// 팟 대비 퍼센트 크기의 베팅
+    Bet { pot_percent: u32 },
+    AllIn,
+}
+
+impl ActionAdvice {
+    /// QuickPokerAPI 전략 키에서 변환 ("fold", "check", "call", "raise", "bet_66pct", "bet_allin")
+    pub fn from_action_key(key: &str) -> Option<Self> {
+        match key {
+            "fold" => Some(Self::Fold),
+            "check" => Some(Self::Check),
+            "call" => Some(Self::Call),
+            "raise" => Some(Self::Raise),
+            "bet_allin" => Some(Self::AllIn),
+            _ => {
+                let percent = key.strip_prefix("bet_")?.strip_suffix("pct")?;
+                Some(Self::Bet {
+                    pot_percent: percent.parse().ok()?,
+                })
+            }
+        }
+    }
+
+    /// 홀덤 액션에서 변환 (콜할 금액이 없으면 `Call`은 체크)
+    pub fn from_act(action: Act, facing_bet: bool) -> Self {
+        match action {
+            Act::Fold => Self::Fold,
+            Act::Call if facing_bet => Self::Call,
+            Act::Call => Self::Check,
+            Act::Raise(0) => Self::Raise,
+            Act::Raise(_) => Self::AllIn,
+        }
+    }
+}
+
+/// 결정 설명: 요인 목록과 결론
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Explanation {
+    /// 비중이 큰 순서의 요인들
+    pub factors: Vec<Factor>,
+    pub conclusion: ActionAdvice,
+    /// 히어로의 메이드 핸드 (보드가 깔린 뒤, 핸드 강도 문장에 함께 렌더링)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub made_hand: Option<HandCategory>,
+}
+
+/// 설명을 만드는 데 쓰는 지표 (엔진이 이미 계산한 값)
+#[derive(Debug, Clone, Default)]
+pub struct CoachingInputs {
+    /// 핸드 강도 (0-1)
+    pub hand_strength: f64,
+    /// 콜에 필요한 에퀴티 (콜 금액 / (팟 + 콜 금액), 콜할 금액이 없으면 None)
+    pub required_equity: Option<f64>,
+    /// 히어로 뒤에 남은 플레이어 수
+    pub players_behind: usize,
+    /// 스택 대 팟 비율 (유효 스택 / 팟)
+    pub spr: f64,
+    /// 가장 좋은 공격적인 액션과 수동적인 액션의 EV 차이 (EV 분석이 있을 때)
+    pub fold_equity: Option<f64>,
+    /// ICM 압박 (0-1, 토너먼트 정보가 있을 때, `icm_pressure` 참고)
+    pub icm_pressure: Option<f64>,
+    /// 홀카드 + 보드의 족보 (`hand_eval::classify`, 프리플랍이면 None)
+    pub made_hand: Option<HandCategory>,
+}
+
+/// 지표와 고른 액션으로 설명 생성
+pub fn explain(inputs: &CoachingInputs, conclusion: ActionAdvice) -> Explanation {
+    let direction = |aggressive: bool| if aggressive { Direction::For } else { Direction::Against };
+    let mut factors = vec![(
+        FactorKind::HandStrength,
+        direction(inputs.hand_strength >= STRONG_HAND_STRENGTH),
+        inputs.hand_strength,
+    )];
+    if let Some(required) = inputs.required_equity {
+        factors.push((FactorKind::PotOdds, direction(inputs.hand_strength >= required), required));
+    }
+    factors.push((
+        FactorKind::Position,
+        direction(inputs.players_behind == 0),
+        inputs.players_behind as f64,
+    ));
+    if inputs.spr < SHORT_SPR || inputs.spr > DEEP_SPR {
+        factors.push((FactorKind::StackDepth, direction(inputs.spr < SHORT_SPR), inputs.spr));
+    }
+    if let Some(edge) = inputs.fold_equity {
+        factors.push((FactorKind::FoldEquity, direction(edge > 0.0), edge));
+    }
+    if let Some(pressure) = inputs.icm_pressure.filter(|&p| p >= MIN_ICM_PRESSURE) {
+        factors.push((FactorKind::IcmPressure, Direction::Against, pressure));
+    }
+
+    let total: f64 = factors.iter().map(|(kind, _, _)| kind.base_weight()).sum();
+    let mut factors: Vec<Factor> = factors
+        .into_iter()
+        .map(|(kind, direction, value)| Factor {
+            kind,
+            direction,
+            weight: kind.base_weight() / total,
+            value,
+        })
+        .collect();
+    factors.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+    Explanation {
+        factors,
+        conclusion,
+        made_hand: inputs.made_hand.clone(),
+    }
+}
+
+/// ICM 압박 (0-1)
+///
+/// 히어로가 스택만큼 잃을 때의 상금 에퀴티 손실 대비 같은 양을 딸 때의 이득이 작을수록
+/// 커집니다 (1 - 이득 / 손실). ICM 정보가 없거나 히어로 스택이 0이면 None입니다.
+pub fn icm_pressure(context: &ValueContext) -> Option<f64> {
+    let icm = context.icm.as_ref()?;
+    let stack = *icm.stacks.get(icm.hero)? as f64;
+    if stack <= 0.0 {
+        return None;
+    }
+    let gain = context.chips_to(stack, ValueUnit::PrizeDollars).ok()?;
+    let loss = -context.chips_to(-stack, ValueUnit::PrizeDollars).ok()?;
+    (loss > 0.0).then(|| (1.0 - gain / loss).clamp(0.0, 1.0))
+}
+
+/// 렌더링 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Locale {
+    English,
+    #[default]
+    Korean,
+}
+
+/// 설명을 문장으로 렌더링 (요인 순서대로, 마지막에 결론)
+pub fn render(explanation: &Explanation, locale: Locale) -> String {
+    let mut sentences: Vec<String> = explanation
+        .factors
+        .iter()
+        .map(|factor| render_factor(factor, explanation.made_hand.as_ref(), locale))
+        .collect();
+    sentences.push(render_conclusion(explanation.conclusion, locale));
+    sentences.join(" ")
+}
+
+fn render_factor(factor: &Factor, made_hand: Option<&HandCategory>, locale: Locale) -> String {
+    let value = factor.value;
+    let aggressive = factor.direction == Direction::For;
+    match (factor.kind, locale) {
+        (FactorKind::HandStrength, Locale::English) => {
+            let tier = hand_tier(value, ["Premium", "Good", "Marginal", "Weak"]);
+            match made_hand {
+                Some(hand) => format!("{} hand strength ({:.0}%): {}.", tier, value * 100.0, hand),
+                None => format!("{} hand strength ({:.0}%).", tier, value * 100.0),
+            }
+        }
+        (FactorKind::HandStrength, Locale::Korean) => {
+            let tier = hand_tier(value, ["프리미엄", "좋은", "한계적", "약한"]);
+            match made_hand {
+                Some(hand) => format!(
+                    "{} 핸드 스트렝스 ({:.0}%): {} ({}).",
+                    tier,
+                    value * 100.0,
+                    hand.name(),
+                    hand.short_code()
+                ),
+                None => format!("{} 핸드 스트렝스 ({:.0}%).", tier, value * 100.0),
+            }
+        }
+        (FactorKind::PotOdds, Locale::English) => format!(
+            "{} pot odds: calling needs {:.0}% equity.",
+            if aggressive { "Favorable" } else { "Poor" },
+            value * 100.0
+        ),
+        (FactorKind::PotOdds, Locale::Korean) => format!(
+            "팟 오즈가 {}: 콜에 필요한 에퀴티는 {:.0}%입니다.",
+            if aggressive { "좋습니다" } else { "나쁩니다" },
+            value * 100.0
+        ),
+        (FactorKind::Position, Locale::English) if aggressive => "Last to act with position.".to_string(),
+        (FactorKind::Position, Locale::English) => {
+            format!("{} player(s) left to act reduces aggression.", value as usize)
+        }
+        (FactorKind::Position, Locale::Korean) if aggressive => "포지션을 가진 마지막 행동자입니다.".to_string(),
+        (FactorKind::Position, Locale::Korean) => {
+            format!("뒤에 {}명이 남아 공격성을 줄입니다.", value as usize)
+        }
+        (FactorKind::StackDepth, Locale::English) if aggressive => {
+            format!("Short stacks (SPR {:.1}) favor aggressive play.", value)
+        }
+        (FactorKind::StackDepth, Locale::English) => {
+            format!("Deep stacks (SPR {:.1}) leave room for post-flop play.", value)
+        }
+        (FactorKind::StackDepth, Locale::Korean) if aggressive => {
+            format!("숏스택(SPR {:.1})은 공격적인 플레이에 유리합니다.", value)
+        }
+        (FactorKind::StackDepth, Locale::Korean) => {
+            format!("딥스택(SPR {:.1})이라 포스트플랍 플레이 여지가 있습니다.", value)
+        }
+        (FactorKind::FoldEquity, Locale::English) if aggressive => {
+            format!("Aggression gains {:.2} over passive play.", value)
+        }
+        (FactorKind::FoldEquity, Locale::English) => {
+            format!("Passive play beats aggression by {:.2}.", -value)
+        }
+        (FactorKind::FoldEquity, Locale::Korean) if aggressive => {
+            format!("공격적인 액션이 수동적인 액션보다 {:.2} 낫습니다.", value)
+        }
+        (FactorKind::FoldEquity, Locale::Korean) => {
+            format!("수동적인 액션이 공격적인 액션보다 {:.2} 낫습니다.", -value)
+        }
+        (FactorKind::IcmPressure, Locale::English) => {
+            format!("ICM pressure ({:.0}%) raises the cost of busting.", value * 100.0)
+        }
+        (FactorKind::IcmPressure, Locale::Korean) => {
+            format!("ICM 압박({:.0}%)으로 탈락 비용이 큽니다.", value * 100.0)
+        }
+    }
+}
+
+fn hand_tier(strength: f64, tiers: [&'static str; 4]) -> &'static str {
+    match strength {
+        s if s > 0.8 => tiers[0],
+        s if s > 0.6 => tiers[1],
+        s if s > 0.4 => tiers[2],
+        _ => tiers[3],
+    }
+}
+
+fn render_conclusion(advice: ActionAdvice, locale: Locale) -> String {
+    match (advice, locale) {
+        (ActionAdvice::Fold, Locale::English) => "Folding to minimize losses.".to_string(),
+        (ActionAdvice::Check, Locale::English) => "Checking to control pot size.".to_string(),
+        (ActionAdvice::Call, Locale::English) => "Calling to see the next card.".to_string(),
+        (ActionAdvice::Raise, Locale::English) => "Raising for value/protection.".to_string(),
+        (ActionAdvice::Bet { pot_percent }, Locale::English) => {
+            format!("Betting {}% of the pot to get stacks in by the river.", pot_percent)
+        }
+        (ActionAdvice::AllIn, Locale::English) => "Shoving all-in: stacks are pot-committed.".to_string(),
+        (ActionAdvice::Fold, Locale::Korean) => "손실을 줄이기 위해 폴드합니다.".to_string(),
+        (ActionAdvice::Check, Locale::Korean) => "팟 크기를 조절하기 위해 체크합니다.".to_string(),
+        (ActionAdvice::Call, Locale::Korean) => "다음 카드를 보기 위해 콜합니다.".to_string(),
+        (ActionAdvice::Raise, Locale::Korean) => "밸류/보호를 위해 레이즈합니다.".to_string(),
+        (ActionAdvice::Bet { pot_percent }, Locale::Korean) => {
+            format!("리버까지 스택을 넣기 위해 팟의 {}%를 베팅합니다.", pot_percent)
+        }
+        (ActionAdvice::AllIn, Locale::Korean) => "스택이 팟에 묶여 올인합니다.".to_string(),
+    }
+}
+
+/// 찹(팟 분할) 가능성 문장 (`probability`: 0-1)
+pub fn render_chop(probability: f64, locale: Locale) -> String {
+    match locale {
+        Locale::English => format!("Board plays: {:.0}% chop.", probability * 100.0),
+        Locale::Korean => format!("보드 플레이: {:.0}% 확률로 팟을 나눕니다.", probability * 100.0),
+    }
+}
+
+/// 포지션 조언 상황
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PositionSpot {
+    /// 버튼에서 체크를 받음
+    ButtonCheckedTo,
+    /// 버튼
+    Button,
+    /// 마지막 행동자로 체크를 받음
+    LastToActCheckedTo,
+    /// 얼리 포지션의 애매한 핸드
+    EarlyMarginal,
+    /// 얼리 포지션의 강한 핸드
+    EarlyStrong,
+    /// 프리플랍 블라인드 (좌석 이름)
+    Blind(String),
+    /// 그 밖의 상황
+    Standard,
+}
+
+/// 포지션 조언
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PositionalAdvice {
+    /// 스트리트 (0=프리플랍, 1=플랍, 2=턴, 3=리버)
+    pub street: u8,
+    /// 히어로 뒤에 남은 플레이어 수
+    pub players_behind: usize,
+    pub spot: PositionSpot,
+}
+
+/// 포지션 조언을 문장으로 렌더링
+pub fn render_positional(advice: &PositionalAdvice, locale: Locale) -> String {
+    let behind = advice.players_behind;
+    match locale {
+        Locale::English => {
+            let street = ["Preflop", "Flop", "Turn"].get(advice.street as usize).unwrap_or(&"River");
+            match &advice.spot {
+                PositionSpot::ButtonCheckedTo => format!(
+                    "{} checked to you on the button: nobody is left behind, so consider betting to take the pot",
+                    street
+                ),
+                PositionSpot::Button => format!(
+                    "{} on the button: with position you only worry about the {} player(s) behind and can play wide",
+                    street, behind
+                ),
+                PositionSpot::LastToActCheckedTo => format!(
+                    "{} checked to you as the last to act: you can bet aggressively to take the pot",
+                    street
+                ),
+                PositionSpot::EarlyMarginal => format!(
+                    "{} early position ({} behind): play marginal hands conservatively",
+                    street, behind
+                ),
+                PositionSpot::EarlyStrong => format!(
+                    "{} early position ({} behind): even with a strong hand, respect the ranges behind you",
+                    street, behind
+                ),
+                PositionSpot::Blind(seat) => format!(
+                    "{} {}: the posted blind gives good pot odds, but later streets are played out of position",
+                    street, seat
+                ),
+                PositionSpot::Standard => format!("{} with {} behind: use a standard strategy", street, behind),
+            }
+        }
+        Locale::Korean => {
+            let street = ["프리플랍", "플랍", "턴"].get(advice.street as usize).unwrap_or(&"리버");
+            match &advice.spot {
+                PositionSpot::ButtonCheckedTo => format!(
+                    "{} 버튼에서 체크를 받았습니다: 뒤에 남은 플레이어가 없으니 베팅으로 팟을 가져오는 공격적인 플레이를 고려하세요",
+                    street
+                ),
+                PositionSpot::Button => format!(
+                    "{} 버튼: 포지션 우위가 있어 뒤의 {}명만 신경 쓰며 넓은 범위로 플레이할 수 있습니다",
+                    street, behind
+                ),
+                PositionSpot::LastToActCheckedTo => format!(
+                    "{} 마지막 행동자로 체크를 받았습니다: 공격적으로 베팅해 팟을 가져올 수 있습니다",
+                    street
+                ),
+                PositionSpot::EarlyMarginal => format!(
+                    "{} 얼리 포지션(뒤에 {}명): 애매한 핸드는 보수적으로 플레이하세요",
+                    street, behind
+                ),
+                PositionSpot::EarlyStrong => format!(
+                    "{} 얼리 포지션(뒤에 {}명): 강한 핸드라도 뒤 플레이어들의 레인지를 존중하세요",
+                    street, behind
+                ),
+                PositionSpot::Blind(seat) => format!(
+                    "{} {}: 이미 낸 블라인드로 팟 오즈는 좋지만 이후 스트리트는 포지션 없이 플레이합니다",
+                    street, seat
+                ),
+                PositionSpot::Standard => format!("{} 뒤에 {}명: 표준적인 전략을 사용하세요", street, behind),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_premium_hand_with_great_odds_points_for_aggression() {
+        let inputs = CoachingInputs {
+            hand_strength: 0.92,
+            required_equity: Some(0.1),
+            players_behind: 0,
+            spr: 2.0,
+            fold_equity: Some(15.0),
+            icm_pressure: None,
+            made_hand: None,
+        };
+        let explanation = explain(&inputs, ActionAdvice::Raise);
+        assert_eq!(explanation.factors.len(), 5);
+        assert!(explanation.factors.iter().all(|f| f.direction == Direction::For), "{:?}", explanation);
+        let total: f64 = explanation.factors.iter().map(|f| f.weight).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert_eq!(explanation.factors[0].kind, FactorKind::HandStrength);
+
+        for locale in [Locale::English, Locale::Korean] {
+            let text = render(&explanation, locale);
+            assert!(!text.is_empty());
+            assert!(text.contains("92%"), "{}", text);
+        }
+        assert_ne!(render(&explanation, Locale::English), render(&explanation, Locale::Korean));
+    }
+
+    #[test]
+    fn test_made_hand_is_named_in_hand_strength() {
+        // As Ah 9s 9d Kc
+        let made_hand = crate::game::hand_eval::classify(&[0, 13, 8, 34, 51]);
+        let inputs = CoachingInputs {
+            hand_strength: 0.85,
+            spr: 5.0,
+            made_hand: Some(made_hand.clone()),
+            ..CoachingInputs::default()
+        };
+        let explanation = explain(&inputs, ActionAdvice::Raise);
+        assert_eq!(explanation.made_hand, Some(made_hand));
+
+        let english = render(&explanation, Locale::English);
+        assert!(english.starts_with("Premium hand strength (85%): Two Pair, Aces and Nines, King kicker."), "{}", english);
+        let korean = render(&explanation, Locale::Korean);
+        assert!(korean.starts_with("프리미엄 핸드 스트렝스 (85%): 투페어 (2P-A-9-K)."), "{}", korean);
+    }
+
+    #[test]
+    fn test_action_keys_and_icm_pressure() {
+        assert_eq!(ActionAdvice::from_action_key("bet_66pct"), Some(ActionAdvice::Bet { pot_percent: 66 }));
+        assert_eq!(ActionAdvice::from_action_key("bet_allin"), Some(ActionAdvice::AllIn));
+        assert_eq!(ActionAdvice::from_action_key("limp"), None);
+
+        // 버블에서 중간 스택은 잃을 때 손해가 따는 이득보다 큼
+        let bubble = ValueContext::with_icm(vec![5000, 3000, 1000, 1000], vec![60, 40, 0], 1);
+        let pressure = icm_pressure(&bubble).unwrap();
+        assert!(pressure > MIN_ICM_PRESSURE, "{}", pressure);
+        assert_eq!(icm_pressure(&ValueContext::default()), None);
+    }
+}
@@ -0,0 +1,728 @@
+// 라이브 세션 모듈
+// 진행 중인 핸드를 액션 이벤트로 추적하면서, 상대가 행동할 때마다 추적 중인 상태에서
+// 액션 문맥(스트리트, 스택 비율, 팟 오즈, 포지션, 버블 여부)을 직접 만들어
+// 좌석별 상대 모델을 자동으로 갱신합니다. 세션이 끝나면 모델을 저장소에 넘깁니다.
+// 추적 상태에서 불가능한 이벤트는 최근 이벤트 기록과 함께 `DesyncError`로 보고하고,
+// 외부의 정답 상태(`PartialHandSpec`)로 다시 맞출 수 있습니다.
+
+use crate::api::analysis::seat_equity::position_name;
+use crate::game::concrete_action::{abstract_from, resolve, ActionKind, ConcreteAction};
+use crate::game::holdem::{Act, State};
+use crate::game::opponent_deal::{estimate_opponent_deal, PreflopLine};
+use crate::game::rng::entropy_rng;
+use crate::game::tournament::{
+    ActionContext, OpponentModel, Position, SeatMap, TournamentAction, TournamentState, TournamentStrategy,
+};
+use crate::solver::cfr_core::{Game, GameState};
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::Arc;
+
+/// 이 값보다 버블 팩터가 크면 버블 근처로 취급 (`TournamentStrategy::adjust_strategy`와 같은 기준)
+const NEAR_BUBBLE_FACTOR: f64 = 0.5;
+
+/// 진단용으로 보관하는 기본 최근 이벤트 수
+pub const DEFAULT_EVENT_HISTORY: usize = 32;
+
+/// 세션에 들어온 이벤트 (진단 기록용)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LiveEvent {
+    /// 새 핸드 시작
+    HandStarted,
+    /// 지금까지의 전체 보드
+    Board(Vec<u8>),
+    /// 좌석의 액션 (추상 액션으로 들어온 경우 추적 상태 기준 금액)
+    Action { seat: usize, action: ConcreteAction },
+    /// 외부 상태로 다시 맞춤
+    Resync,
+}
+
+/// 이벤트가 추적 상태와 맞지 않는 이유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DesyncKind {
+    /// 보드 카드가 먼저 들어와야 하는 상태
+    BoardPending,
+    /// 핸드가 이미 끝남
+    HandOver,
+    /// 다른 좌석의 차례 (올인했거나 폴드한 좌석의 액션 포함)
+    OutOfTurn { expected: usize },
+    /// 현재 상태에서 불가능한 액션 (예: 베팅을 마주한 체크)
+    IllegalAction,
+    /// 남은 스택보다 큰 금액
+    AmountExceedsStack { stack: u32 },
+    /// 콜 금액이 추적 중인 콜 금액과 다름
+    CallAmountMismatch { expected: u32 },
+}
+
+impl fmt::Display for DesyncKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DesyncKind::BoardPending => write!(f, "보드 카드를 먼저 반영해야 합니다 (observe_board)"),
+            DesyncKind::HandOver => write!(f, "핸드가 이미 끝났습니다"),
+            DesyncKind::OutOfTurn { expected } => write!(f, "{}번 좌석의 차례입니다", expected),
+            DesyncKind::IllegalAction => write!(f, "현재 상태에서 불가능한 액션입니다"),
+            DesyncKind::AmountExceedsStack { stack } => write!(f, "남은 스택({})보다 큰 금액입니다", stack),
+            DesyncKind::CallAmountMismatch { expected } => write!(f, "콜 금액은 {}이어야 합니다", expected),
+        }
+    }
+}
+
+/// 관찰된 이벤트가 추적 상태와 맞지 않을 때의 진단 정보
+///
+/// 에러가 나도 추적 상태는 바뀌지 않습니다. 외부 정답 상태가 있으면
+/// `LiveSession::resync`로 다시 맞춘 뒤 계속 진행합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesyncError {
+    pub kind: DesyncKind,
+    /// 문제가 된 이벤트
+    pub observed: LiveEvent,
+    /// 추적 상태에서 가능한 액션 (차례인 좌석 기준, 액션할 수 없으면 비어 있음)
+    pub legal_actions: Vec<ConcreteAction>,
+    /// 히어로 관점의 추적 상태 요약
+    pub state_summary: String,
+    /// 문제가 된 이벤트를 포함한 최근 이벤트 (오래된 순)
+    pub recent_events: Vec<LiveEvent>,
+}
+
+impl fmt::Display for DesyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let legal: Vec<String> = self
+            .legal_actions
+            .iter()
+            .map(|action| format!("{:?} {}", action.kind, action.amount))
+            .collect();
+        write!(
+            f,
+            "{:?}: {} (가능한 액션: [{}], 상태: {})",
+            self.observed,
+            self.kind,
+            legal.join(", "),
+            self.state_summary
+        )
+    }
+}
+
+impl std::error::Error for DesyncError {}
+
+/// 핸드 도중 외부에서 받은 정답 상태 (`LiveSession::resync`)
+///
+/// 버튼, 블라인드, 베팅 규칙, 홀카드처럼 주어지지 않은 정보는 추적 중인 핸드에서 가져옵니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartialHandSpec {
+    /// 지금까지의 전체 보드 (0, 3, 4, 5장)
+    pub board: Vec<u8>,
+    /// 현재 팟
+    pub pot: u32,
+    /// 좌석별 남은 스택
+    pub stacks: [u32; 6],
+    /// 좌석별 이번 스트리트 투자액
+    pub invested: [u32; 6],
+    /// 핸드에 남아 있는 좌석 (폴드하지 않음)
+    pub alive: [bool; 6],
+    /// 다음에 액션할 좌석
+    pub to_act: usize,
+    /// 이번 스트리트에서 지금까지 나온 액션 수 (베팅 라운드 종료 판정용)
+    pub actions_taken: usize,
+}
+
+/// 플레이어별 상대 모델 저장소
+///
+/// 세션 시작 시 저장된 모델을 불러오고(`LiveSession::load_models`), 세션이 끝나면
+/// 갱신된 모델을 저장합니다(`LiveSession::end`).
+pub trait OpponentModelStore {
+    /// 저장된 모델 (없으면 None)
+    fn load(&self, player_id: u32) -> Option<OpponentModel>;
+    /// 모델 저장 (같은 플레이어의 기존 모델은 덮어씀)
+    fn save(&mut self, model: &OpponentModel);
+}
+
+/// 메모리 저장소
+impl OpponentModelStore for HashMap<u32, OpponentModel> {
+    fn load(&self, player_id: u32) -> Option<OpponentModel> {
+        self.get(&player_id).cloned()
+    }
+
+    fn save(&mut self, model: &OpponentModel) {
+        self.insert(model.player_id, model.clone());
+    }
+}
+
+/// 직렬화 가능한 상대 모델 요약
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpponentSnapshot {
+    pub player_id: u32,
+    pub vpip: f64,
+    pub pfr: f64,
+    pub aggression: f64,
+    pub tightness: f64,
+    pub three_bet: f64,
+    pub fold_to_three_bet: f64,
+    pub fold_to_cbet: f64,
+    /// 모델에 반영된 액션 수
+    pub sample_size: u32,
+}
+
+impl From<&OpponentModel> for OpponentSnapshot {
+    fn from(model: &OpponentModel) -> Self {
+        Self {
+            player_id: model.player_id,
+            vpip: model.vpip,
+            pfr: model.pfr,
+            aggression: model.aggression,
+            tightness: model.tightness,
+            three_bet: model.three_bet,
+            fold_to_three_bet: model.fold_to_three_bet,
+            fold_to_cbet: model.fold_to_cbet,
+            sample_size: model.sample_size,
+        }
+    }
+}
+
+/// 액션 이벤트로 핸드를 추적하며 상대 모델을 갱신하는 세션
+///
+/// 히어로가 아닌 좌석의 액션마다 `ActionContext`를 만들어 그 좌석 플레이어의
+/// `OpponentModel::update_with_action`을 호출합니다. 모델은 핸드가 바뀌어도 유지됩니다.
+///
+/// 추적 상태의 `opponent_deal`은 좌석별 프리플랍 라인에서 추정한 레인지로 채우므로,
+/// `state()`로 EV를 계산하면 상대 홀카드를 그 레인지에서 다시 딜링합니다.
+pub struct LiveSession {
+    state: State,
+    hero: usize,
+    /// 이번 핸드의 좌석별 프리플랍 라인
+    preflop_lines: [PreflopLine; 6],
+    /// 좌석별 플레이어 id (기본: 좌석 번호)
+    player_ids: Vec<u32>,
+    tournament: Option<TournamentState>,
+    models: HashMap<u32, OpponentModel>,
+    /// 스트리트 전환 샘플링용 (딜링된 카드는 `observe_board`의 카드로 덮어씀)
+    rng: StdRng,
+    /// 진단용 최근 이벤트 (최대 `event_capacity`개)
+    events: VecDeque<LiveEvent>,
+    event_capacity: usize,
+}
+
+impl LiveSession {
+    /// `root` 핸드에서 시작하는 세션 (`hero` 좌석은 모델링하지 않음)
+    pub fn new(root: State, hero: usize) -> Self {
+        Self {
+            state: with_opponent_deal(root, &[PreflopLine::default(); 6], hero),
+            hero,
+            preflop_lines: [PreflopLine::default(); 6],
+            player_ids: (0..6).collect(),
+            tournament: None,
+            models: HashMap::new(),
+            rng: entropy_rng(),
+            events: VecDeque::new(),
+            event_capacity: DEFAULT_EVENT_HISTORY,
+        }
+    }
+
+    /// 진단용으로 보관할 최근 이벤트 수 지정 (최소 1)
+    pub fn with_event_history(mut self, capacity: usize) -> Self {
+        self.event_capacity = capacity.max(1);
+        while self.events.len() > self.event_capacity {
+            self.events.pop_front();
+        }
+        self
+    }
+
+    /// 좌석별 플레이어 id 지정 (좌석이 바뀌어도 같은 플레이어의 모델을 이어서 갱신)
+    pub fn with_player_ids(mut self, player_ids: Vec<u32>) -> Self {
+        for (seat, id) in player_ids.into_iter().enumerate().take(6) {
+            self.player_ids[seat] = id;
+        }
+        self
+    }
+
+    /// 핸드의 좌석 맵으로 좌석별 플레이어 id 지정 (`MTTManager::deal_table_hand`의 `seat_map`)
+    ///
+    /// 테이블 밸런싱으로 테이블이나 좌석이 바뀌어도 관찰이 좌석 번호가 아닌 플레이어를 따라갑니다.
+    /// 맵에 없는 좌석은 기존 id를 유지합니다.
+    pub fn with_seat_map(mut self, seat_map: &SeatMap) -> Self {
+        for (seat, id) in seat_map.players() {
+            self.player_ids[seat] = id;
+        }
+        self
+    }
+
+    /// 토너먼트 문맥 연결 (스택 비율은 토너먼트 평균 스택 기준, 버블 여부 계산)
+    pub fn with_tournament(mut self, tournament: TournamentState) -> Self {
+        self.tournament = Some(tournament);
+        self
+    }
+
+    /// 저장소에서 현재 좌석 플레이어들의 모델을 불러옴 (이미 세션에 있는 모델은 유지)
+    pub fn load_models(&mut self, store: &impl OpponentModelStore) {
+        for seat in (0..6).filter(|&seat| seat != self.hero) {
+            let id = self.player_ids[seat];
+            if let (false, Some(model)) = (self.models.contains_key(&id), store.load(id)) {
+                self.models.insert(id, model);
+            }
+        }
+    }
+
+    /// 추적 중인 현재 상태
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// 최근 이벤트 (오래된 순)
+    pub fn recent_events(&self) -> impl Iterator<Item = &LiveEvent> {
+        self.events.iter()
+    }
+
+    /// 새 핸드 시작 (상대 모델은 유지)
+    pub fn start_hand(&mut self, root: State) {
+        self.preflop_lines = [PreflopLine::default(); 6];
+        self.state = with_opponent_deal(root, &self.preflop_lines, self.hero);
+        self.record(LiveEvent::HandStarted);
+    }
+
+    /// 외부에서 받은 정답 상태로 추적 상태를 다시 구성
+    ///
+    /// 스트리트는 보드 장수로 정하고, 콜 금액은 가장 큰 투자액입니다. 이번 스트리트의 레이즈
+    /// 기록은 알 수 없으므로 최소 레이즈는 빅블라인드 기준으로 돌아갑니다.
+    pub fn resync(&mut self, snapshot: PartialHandSpec) -> Result<(), String> {
+        let street = match snapshot.board.len() {
+            0 => 0,
+            3 => 1,
+            4 => 2,
+            5 => 3,
+            n => return Err(format!("보드 카드 {}장은 유효하지 않습니다", n)),
+        };
+        if snapshot.to_act >= 6 || !snapshot.alive[snapshot.to_act] || snapshot.stacks[snapshot.to_act] == 0 {
+            return Err(format!("{}번 좌석은 액션할 수 없습니다", snapshot.to_act));
+        }
+
+        let mut state = self.state.clone();
+        state.board = snapshot.board;
+        state.street = street;
+        state.pot = snapshot.pot;
+        state.stack = snapshot.stacks;
+        state.invested = snapshot.invested;
+        state.alive = snapshot.alive;
+        state.to_call = snapshot.invested.iter().copied().max().unwrap_or(0);
+        state.to_act = snapshot.to_act;
+        state.actions_taken = snapshot.actions_taken;
+        state.raises_this_street = 0;
+        state.last_raise = 0;
+        self.state = state;
+        self.record(LiveEvent::Resync);
+        Ok(())
+    }
+
+    /// 딜링된 보드 카드 반영
+    ///
+    /// 베팅이 끝나 다음 스트리트로 넘어가야 하는 상태에서만 진행하며, `board`는
+    /// 지금까지의 전체 보드입니다 (새로 필요한 카드가 모자라면 에러).
+    pub fn observe_board(&mut self, board: &[u8]) -> Result<(), String> {
+        self.record(LiveEvent::Board(board.to_vec()));
+        while self.state.is_chance_node() {
+            let mut next = State::apply_chance(&self.state, &mut self.rng);
+            if board.len() < next.board.len() {
+                return Err(format!(
+                    "보드 카드가 {}장 필요하지만 {}장만 주어졌습니다",
+                    next.board.len(),
+                    board.len()
+                ));
+            }
+            let dealt = next.board.len();
+            next.board.copy_from_slice(&board[..dealt]);
+            self.state = next;
+        }
+        Ok(())
+    }
+
+    /// 현재 차례 플레이어의 액션 반영
+    ///
+    /// 상대 좌석의 액션이면 액션 전 상태에서 만든 문맥으로 모델을 갱신하고 그 문맥을 반환합니다.
+    /// 히어로의 액션과 칩을 넣지 않는 체크는 모델에 반영하지 않으므로 None입니다.
+    /// 보드 딜링이 필요한 상태이거나 불가능한 액션이면 추적 상태를 바꾸지 않고 `DesyncError`입니다.
+    pub fn observe_action(&mut self, action: Act) -> Result<Option<ActionContext>, DesyncError> {
+        let seat = State::current_player(&self.state).unwrap_or(self.state.to_act.min(5));
+        let event = LiveEvent::Action {
+            seat,
+            action: resolve(action, &self.state),
+        };
+        self.record(event.clone());
+        self.check_turn(seat, &event)?;
+        if !State::legal_actions(&self.state).contains(&action) {
+            return Err(self.desync(DesyncKind::IllegalAction, event));
+        }
+        Ok(self.apply_action(seat, action))
+    }
+
+    /// 외부 피드가 보고한 좌석의 칩 단위 액션 반영
+    ///
+    /// 차례, 스택, 콜 금액과 액션 종류를 추적 상태와 대조한 뒤 가장 가까운 추상 액션으로
+    /// 매핑해 `observe_action`과 같이 반영합니다.
+    pub fn observe_concrete(
+        &mut self,
+        seat: usize,
+        action: ConcreteAction,
+    ) -> Result<Option<ActionContext>, DesyncError> {
+        let event = LiveEvent::Action { seat, action };
+        self.record(event.clone());
+        self.check_turn(seat, &event)?;
+
+        let stack = self.state.stack[seat];
+        if action.amount > stack {
+            return Err(self.desync(DesyncKind::AmountExceedsStack { stack }, event));
+        }
+        let expected_call = self.state.to_call.saturating_sub(self.state.invested[seat]).min(stack);
+        if action.kind == ActionKind::Call && action.amount != expected_call {
+            return Err(self.desync(DesyncKind::CallAmountMismatch { expected: expected_call }, event));
+        }
+        match abstract_from(action, &self.state) {
+            Some(act) => Ok(self.apply_action(seat, act)),
+            None => Err(self.desync(DesyncKind::IllegalAction, event)),
+        }
+    }
+
+    /// 추적 상태에서 `seat`이 지금 액션할 수 있는지 확인
+    fn check_turn(&self, seat: usize, event: &LiveEvent) -> Result<(), DesyncError> {
+        if self.state.is_chance_node() {
+            return Err(self.desync(DesyncKind::BoardPending, event.clone()));
+        }
+        match State::current_player(&self.state) {
+            None => Err(self.desync(DesyncKind::HandOver, event.clone())),
+            Some(expected) if expected != seat => Err(self.desync(DesyncKind::OutOfTurn { expected }, event.clone())),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// 진단 정보를 담은 에러 생성
+    fn desync(&self, kind: DesyncKind, observed: LiveEvent) -> DesyncError {
+        let legal_actions = if State::current_player(&self.state).is_some() {
+            State::legal_actions(&self.state)
+                .into_iter()
+                .map(|act| resolve(act, &self.state))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        DesyncError {
+            kind,
+            observed,
+            legal_actions,
+            state_summary: self.state.fmt_for_player(self.hero).to_string(),
+            recent_events: self.events.iter().cloned().collect(),
+        }
+    }
+
+    /// 최근 이벤트 기록 (용량을 넘으면 가장 오래된 이벤트를 버림)
+    fn record(&mut self, event: LiveEvent) {
+        if self.events.len() == self.event_capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    /// 검증된 액션을 추적 상태에 적용하고 상대 모델 갱신
+    fn apply_action(&mut self, seat: usize, action: Act) -> Option<ActionContext> {
+        let next = State::next_state(&self.state, action);
+        let committed = self.state.stack[seat].saturating_sub(next.stack[seat]);
+        let observed = match action {
+            _ if seat == self.hero => None,
+            Act::Fold => Some(TournamentAction::Fold),
+            Act::Call if committed == 0 => None,
+            Act::Call => Some(TournamentAction::Call),
+            Act::Raise(_) if next.stack[seat] == 0 => Some(TournamentAction::AllIn),
+            Act::Raise(_) => Some(TournamentAction::Raise(committed)),
+        };
+
+        self.preflop_lines[seat].record(&self.state, action);
+        let next = if self.state.street == 0 && seat != self.hero {
+            with_opponent_deal(next, &self.preflop_lines, self.hero)
+        } else {
+            next
+        };
+
+        let context = observed.map(|tournament_action| {
+            let context = self.action_context(seat);
+            let id = self.player_ids[seat];
+            self.models
+                .entry(id)
+                .or_insert_with(|| OpponentModel::new(id))
+                .update_with_action(&tournament_action, &context);
+            context
+        });
+        self.state = next;
+        context
+    }
+
+    /// 좌석 플레이어의 현재 모델 요약 (관찰 전이면 기본 모델)
+    pub fn opponent_profile(&self, seat: usize) -> OpponentSnapshot {
+        let id = self.player_ids[seat.min(5)];
+        match self.models.get(&id) {
+            Some(model) => model.into(),
+            None => (&OpponentModel::new(id)).into(),
+        }
+    }
+
+    /// 세션 종료: 갱신된 모든 모델을 저장소에 저장
+    pub fn end(self, store: &mut impl OpponentModelStore) {
+        for model in self.models.values() {
+            store.save(model);
+        }
+    }
+
+    /// 현재 상태에서 `seat`이 행동할 때의 문맥
+    ///
+    /// 팟 오즈는 콜 금액 대비 팟 크기(`팟 / 콜`, 3.0이면 3:1)이며 콜할 금액이 없으면 0입니다.
+    fn action_context(&self, seat: usize) -> ActionContext {
+        let state = &self.state;
+        let stack = state.stack[seat];
+        let player_count = (0..6).filter(|&i| state.alive[i] || state.hole[i] != [0, 0]).count();
+
+        let table_average =
+            (0..player_count).map(|i| state.stack[i] as f64).sum::<f64>() / player_count.max(1) as f64;
+        let (average, near_bubble) = match &self.tournament {
+            Some(tournament) if tournament.players_remaining > 0 => (
+                tournament.total_chips() as f64 / tournament.players_remaining as f64,
+                TournamentStrategy::new(tournament, stack).bubble_factor > NEAR_BUBBLE_FACTOR,
+            ),
+            _ => (table_average, false),
+        };
+
+        let call_amount = state.to_call.saturating_sub(state.invested[seat]).min(stack);
+        let pot_odds = if call_amount > 0 {
+            state.pot as f64 / call_amount as f64
+        } else {
+            0.0
+        };
+
+        let position = match position_name(seat, state.button, player_count.max(2)) {
+            "BTN" => Position::Button,
+            "SB" => Position::SmallBlind,
+            "BB" => Position::BigBlind,
+            "CO" => Position::LatePosition,
+            "HJ" => Position::MiddlePosition,
+            _ => Position::EarlyPosition,
+        };
+
+        ActionContext {
+            stack_ratio: if average > 0.0 { stack as f64 / average } else { 0.0 },
+            pot_odds,
+            is_preflop: state.street == 0,
+            near_bubble,
+            position,
+            num_opponents: (0..6).filter(|&i| i != seat && state.alive[i]).count() as u32,
+        }
+    }
+}
+
+/// 좌석별 프리플랍 라인에서 추정한 딜링 모델을 설정한 상태
+fn with_opponent_deal(state: State, lines: &[PreflopLine; 6], hero: usize) -> State {
+    State {
+        opponent_deal: Some(Arc::new(estimate_opponent_deal(lines, hero))),
+        ..state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tournament::{AnteType, BlindLevel, MTTManager, TournamentStructure};
+    use rand::SeedableRng;
+
+    /// 헤즈업 딥스택 핸드: 히어로 0번(버튼), 상대 1번(빅블라인드)
+    fn heads_up_session() -> LiveSession {
+        LiveSession::new(State::new_hand([10, 20], [10_000; 6], 2), 0)
+    }
+
+    #[test]
+    fn test_villain_raises_update_model_with_derived_contexts() {
+        let mut session = heads_up_session();
+        let prior = session.opponent_profile(1);
+        let mut contexts = Vec::new();
+        let mut observe = |session: &mut LiveSession, action: Act| {
+            let street = session.state().street;
+            if let Some(context) = session.observe_action(action).unwrap() {
+                contexts.push((street, context));
+            }
+        };
+
+        // 프리플랍: 히어로 오픈, 상대 3벳, 히어로 콜
+        observe(&mut session, Act::Raise(0));
+        observe(&mut session, Act::Raise(0));
+        observe(&mut session, Act::Call);
+        assert!(session.observe_action(Act::Call).is_err(), "보드 전에는 액션 불가");
+        session.observe_board(&[0, 13, 26]).unwrap();
+
+        // 플랍: 상대 체크, 히어로 베팅, 상대 레이즈, 히어로 콜
+        observe(&mut session, Act::Call);
+        observe(&mut session, Act::Raise(0));
+        observe(&mut session, Act::Raise(0));
+        observe(&mut session, Act::Call);
+        session.observe_board(&[0, 13, 26, 39]).unwrap();
+        assert_eq!(session.state().board, vec![0, 13, 26, 39]);
+
+        // 턴: 상대 베팅
+        observe(&mut session, Act::Raise(0));
+
+        let summary: Vec<(u8, bool, f64)> = contexts
+            .iter()
+            .map(|(street, context)| (*street, context.is_preflop, context.pot_odds))
+            .collect();
+        // 50 오픈에 30 콜(팟 70) = 7:3, 플랍 팟 베팅에 콜(팟 2배) = 2:1, 턴 선제 베팅 = 0
+        assert_eq!(summary, vec![(0, true, 70.0 / 30.0), (1, false, 2.0), (2, false, 0.0)]);
+        for (_, context) in &contexts {
+            assert_eq!(context.position, Position::BigBlind);
+            assert_eq!(context.num_opponents, 1);
+            assert!(!context.near_bubble);
+        }
+
+        let profile = session.opponent_profile(1);
+        assert_eq!(profile.sample_size, 3);
+        assert!(profile.aggression > prior.aggression);
+        assert!(profile.pfr > prior.pfr);
+        // 히어로는 모델링하지 않음
+        assert_eq!(session.opponent_profile(0), OpponentSnapshot::from(&OpponentModel::new(0)));
+    }
+
+    #[test]
+    fn test_models_persist_through_store_with_tournament_context() {
+        let structure = TournamentStructure {
+            levels: vec![BlindLevel {
+                level: 1,
+                small_blind: 10,
+                big_blind: 20,
+                ante: 0,
+            }],
+            level_duration_minutes: 15,
+            starting_stack: 10_000,
+            ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
+        };
+        // 30명 중 3명 입상, 4명 남음 = 버블
+        let mut tournament = TournamentState::new(structure, 30, 30_000);
+        tournament.players_remaining = 4;
+
+        let mut session = heads_up_session().with_player_ids(vec![7, 42]).with_tournament(tournament);
+        session.observe_action(Act::Call).unwrap();
+        let context = session.observe_action(Act::Raise(0)).unwrap().unwrap();
+        assert!(context.near_bubble);
+        // 토너먼트 평균 75,000 대비 9,980칩
+        assert!((context.stack_ratio - 9_980.0 / 75_000.0).abs() < 1e-9, "{}", context.stack_ratio);
+
+        let mut store: HashMap<u32, OpponentModel> = HashMap::new();
+        let profile = session.opponent_profile(1);
+        session.end(&mut store);
+        assert_eq!(store.len(), 1);
+        assert_eq!(OpponentSnapshot::from(&store[&42]), profile);
+
+        // 다음 세션은 같은 플레이어의 모델을 이어서 사용
+        let mut next = heads_up_session().with_player_ids(vec![7, 42]);
+        next.load_models(&store);
+        assert_eq!(next.opponent_profile(1), profile);
+        let json = serde_json::to_string(&profile).unwrap();
+        assert!(json.contains("\"player_id\":42") && json.contains("\"sample_size\":1"), "{}", json);
+    }
+
+    #[test]
+    fn test_observations_follow_player_moved_by_table_balancing() {
+        let structure = TournamentStructure {
+            levels: vec![BlindLevel {
+                level: 1,
+                small_blind: 25,
+                big_blind: 50,
+                ante: 0,
+            }],
+            level_duration_minutes: 15,
+            starting_stack: 1500,
+            ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
+        };
+        let mut mtt = MTTManager::new(12, 6, structure, 10_000);
+        let mut rng = StdRng::seed_from_u64(8);
+        let mut store: HashMap<u32, OpponentModel> = HashMap::new();
+        let villain = 7;
+
+        // 상대 좌석은 모두 림프하고 추적 대상 플레이어 차례에서 레이즈
+        let mut play_hand = |mtt: &mut MTTManager, store: &mut HashMap<u32, OpponentModel>, table_id: u32| {
+            let hand = mtt.deal_table_hand(table_id, &mut rng).unwrap();
+            let villain_seat = hand.seat_map.seat_of(villain).unwrap();
+            let hero = (villain_seat + 1) % hand.seat_map.players().count();
+            let mut session = LiveSession::new(hand.root.clone(), hero).with_seat_map(&hand.seat_map);
+            session.load_models(store);
+            while State::current_player(session.state()) != Some(villain_seat) {
+                session.observe_action(Act::Call).unwrap();
+            }
+            session.observe_action(Act::Raise(0)).unwrap();
+            session.end(store);
+        };
+
+        play_hand(&mut mtt, &mut store, 1);
+        assert_eq!(store[&villain].sample_size, 1);
+
+        // 0번 테이블에서 두 명이 탈락하면 밸런싱으로 1번 테이블 플레이어가 넘어감
+        mtt.settle_hand(0, &[(1, 0), (2, 0)]);
+        let moved_to = mtt.get_tournament_standings().iter().find(|standing| standing.0 == villain).unwrap().2;
+        assert_eq!(moved_to, 0);
+
+        play_hand(&mut mtt, &mut store, 0);
+        let model = &store[&villain];
+        assert_eq!(model.sample_size, 2);
+        assert!(model.pfr > OpponentModel::new(villain).pfr);
+        // 모든 모델이 두 테이블에 앉은 플레이어 id로 쌓임 (좌석 번호 0-2로 쌓인 모델 없음)
+        assert!(store.keys().all(|id| (3..=12).contains(id)), "{:?}", store.keys().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_check_facing_bet_reports_desync_and_resync_recovers() {
+        let mut session = heads_up_session().with_event_history(4);
+        session.observe_action(Act::Call).unwrap();
+        session.observe_action(Act::Call).unwrap();
+        session.observe_board(&[0, 13, 26]).unwrap();
+        // 플랍: 상대(빅블라인드) 베팅 40
+        session.observe_concrete(1, ConcreteAction::new(ActionKind::Bet, 40)).unwrap();
+        let before = session.state().clone();
+
+        // 피드가 히어로의 체크를 보고함 - 베팅을 마주한 체크는 불가능
+        let check = ConcreteAction::new(ActionKind::Check, 0);
+        let err = session.observe_concrete(0, check).unwrap_err();
+        assert_eq!(err.kind, DesyncKind::IllegalAction);
+        assert_eq!(err.observed, LiveEvent::Action { seat: 0, action: check });
+        let kinds: Vec<ActionKind> = err.legal_actions.iter().map(|action| action.kind).collect();
+        assert_eq!(kinds, vec![ActionKind::Fold, ActionKind::Call, ActionKind::Raise]);
+        assert!(err.legal_actions.contains(&ConcreteAction::new(ActionKind::Call, 40)));
+        assert!(err.state_summary.contains("Flop"), "{}", err.state_summary);
+        assert!(err.to_string().contains("Call 40"), "{}", err);
+        // 진단 기록은 문제가 된 이벤트로 끝나고 용량만큼만 보관
+        assert_eq!(err.recent_events.len(), 4);
+        assert_eq!(err.recent_events.last(), Some(&err.observed));
+        assert_eq!(session.state().pot, before.pot, "에러에도 추적 상태는 그대로");
+
+        // 스택보다 큰 금액과 차례가 아닌 좌석도 보고
+        let shove = ConcreteAction::new(ActionKind::AllIn, 20_000);
+        assert!(matches!(
+            session.observe_concrete(0, shove).unwrap_err().kind,
+            DesyncKind::AmountExceedsStack { stack: 9_980 }
+        ));
+        let raise = ConcreteAction::new(ActionKind::Raise, 200);
+        assert_eq!(
+            session.observe_concrete(1, raise).unwrap_err().kind,
+            DesyncKind::OutOfTurn { expected: 0 }
+        );
+
+        // 실제로는 히어로가 콜하고 상대가 턴에서 베팅한 상태로 다시 맞춤
+        session
+            .resync(PartialHandSpec {
+                board: vec![0, 13, 26, 39],
+                pot: 200,
+                stacks: [9_900, 9_840, 0, 0, 0, 0],
+                invested: [0, 60, 0, 0, 0, 0],
+                alive: [true, true, false, false, false, false],
+                to_act: 0,
+                actions_taken: 1,
+            })
+            .unwrap();
+        assert_eq!((session.state().street, session.state().to_call), (2, 60));
+        assert_eq!(session.recent_events().last(), Some(&LiveEvent::Resync));
+        session.observe_concrete(0, ConcreteAction::new(ActionKind::Call, 60)).unwrap();
+        assert!(session.state().is_chance_node());
+    }
+}
@@ -4,12 +4,45 @@
 //! - 빠른 전략 쿼리를 위한 간단한 무상태 API
 //! - 상태 추적 및 배치 처리가 가능한 완전 기능 API
 //! - 고급 분석 및 EV 계산 API
+//! - QuickPokerAPI 휴리스틱 파라미터 튜닝
+//! - 재개 가능한 체크포인트 오프라인 학습
+//! - WASM용 고정 레이아웃 배치 분석
+//! - 라이브 세션의 상대 모델 자동 갱신
+//! - 구조화된 코칭 설명과 로케일별 렌더링
+//! - 크레이트에 포함된 프리플랍 프리셋 전략
+//! - DTO JSON 스키마 내보내기 (`schema` feature)
+//! - 서버리스 배포용 무상태 순수 함수 평가
+//! - 토너먼트 ICM 푸시/폴드 차트
 
 pub mod web_api;
 pub mod web_api_simple;
 pub mod analysis;
+pub mod tuning;
+/// 디스크 체크포인트 (파일 I/O가 있어 wasm32 빌드에서는 제외, `training` 기능 필요)
+#[cfg(all(feature = "training", not(target_arch = "wasm32")))]
+pub mod checkpoint;
+pub mod batch;
+pub mod live_session;
+pub mod coaching;
+pub mod presets;
+pub mod stateless;
+pub mod tournament_charts;
+
+/// JSON 스키마 생성 (feature = "schema")
+#[cfg(feature = "schema")]
+pub mod schema;
 
 // 충돌을 피하기 위해 선택된 타입들을 재수출
-pub use web_api::{OfflineTrainer, PokerWebAPI, StrategyTable};
+pub use web_api::{
+    best_action, canonical_action_order, BucketSelection, PokerWebAPI, SeatStatus, StackBucket, StackBucketConfig, StrategyCache,
+    StrategyCacheConfig, StrategyMap, StrategyPayload, StrategyTable, StrategyTableError,
+};
+#[cfg(feature = "training")]
+pub use web_api::OfflineTrainer;
 pub use analysis::{analyze_poker_state, get_on_demand_ev_analysis, AnalysisRequest, PokerAnalysisResponse};
 pub use web_api_simple::QuickPokerAPI;
+pub use presets::{PresetError, PresetPosition, PresetSpot, PresetStrategies};
+pub use stateless::{StatelessRequest, StatelessResponse, StrategySource};
+pub use tournament_charts::{push_fold_chart, PushFoldChart, PushFoldConfig, PushFoldError, PushFoldPayoff};
+#[cfg(all(feature = "training", not(target_arch = "wasm32")))]
+pub use checkpoint::CheckpointConfig;
@@ -0,0 +1,471 @@
+// 프리플랍 프리셋 전략
+// 학습 없이 바로 쓸 수 있도록 100bb 6맥스의 오픈, 오픈 대응(콜/3벳), 3벳 대응(콜/4벳)
+// 전략을 크레이트에 포함된 팩으로 제공합니다. 팩은 `generate_presets` 바이너리로
+// 오프라인에서 만들고, 로드할 때 형식 버전과 체크섬을 검사합니다.
+
+/// 프리셋 팩 생성 (`training` 기능)
+#[cfg(feature = "training")]
+mod generation;
+
+use crate::api::web_api::{StrategyResponse, StrategyTable, StrategyTableError, WebGameState};
+use crate::game::holdem::{self, Act};
+use crate::game::tournament::AnteType;
+use crate::solver::cfr_core::Game;
+use crate::solver::compat::{CompatibilityFingerprint, IncompatibleArtifact, MigrationRegistry};
+use crate::solver::purification::Purification;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[cfg(feature = "training")]
+pub use generation::{generate_pack, PresetGenerationConfig};
+
+/// 프리셋 팩 파일 형식 버전 (2: 전략 정제 방식 추가, 3: 호환성 지문 추가)
+pub const PRESET_PACK_FORMAT_VERSION: u32 = 3;
+
+/// 지문 없이 읽을 수 있는 이전 팩 형식 버전
+const LEGACY_PRESET_PACK_FORMAT_VERSION: u32 = 2;
+
+/// 프리셋 블라인드 [스몰, 빅] (칩)
+pub const PRESET_BLINDS: [u32; 2] = [50, 100];
+
+/// 프리셋 시작 스택 (빅블라인드 단위)
+pub const PRESET_STACK_BB: u32 = 100;
+
+/// 크레이트에 포함된 기본 프리셋 팩 (`cargo run --release --bin generate_presets`로 재생성)
+static DEFAULT_PACK: &[u8] = include_bytes!("../../assets/presets/preflop_100bb_6max.bin");
+
+/// 6맥스 프리셋 테이블의 포지션 (좌석 번호는 UTG 0부터 빅블라인드 5까지)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PresetPosition {
+    Utg,
+    Hijack,
+    Cutoff,
+    Button,
+    SmallBlind,
+    BigBlind,
+}
+
+impl PresetPosition {
+    /// 액션 순서대로 모든 포지션
+    pub const ALL: [Self; 6] = [
+        Self::Utg,
+        Self::Hijack,
+        Self::Cutoff,
+        Self::Button,
+        Self::SmallBlind,
+        Self::BigBlind,
+    ];
+
+    /// 프리셋 테이블의 좌석 번호 (`holdem::State::new_hand` 6인 배치와 같음)
+    pub fn seat(self) -> usize {
+        self as usize
+    }
+}
+
+/// 프리셋이 다루는 프리플랍 상황 (포지션 쌍으로 구분)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PresetSpot {
+    /// 앞 좌석이 모두 폴드한 뒤 첫 액션 (폴드/림프/오픈 레이즈)
+    Open { hero: PresetPosition },
+    /// `opener`의 오픈 레이즈에 대한 대응 (폴드/콜/3벳)
+    VsOpen { hero: PresetPosition, opener: PresetPosition },
+    /// 히어로의 오픈 레이즈에 `three_bettor`가 3벳한 뒤 (폴드/콜/4벳)
+    VsThreeBet { hero: PresetPosition, three_bettor: PresetPosition },
+}
+
+impl PresetSpot {
+    /// 프리셋 팩이 다루는 모든 상황
+    pub fn covered() -> Vec<Self> {
+        let pairs = || {
+            PresetPosition::ALL
+                .into_iter()
+                .flat_map(|opener| PresetPosition::ALL.into_iter().map(move |defender| (opener, defender)))
+                .filter(|(opener, defender)| opener < defender)
+        };
+        let opens = PresetPosition::ALL[..5].iter().map(|&hero| Self::Open { hero });
+        let vs_opens = pairs().map(|(opener, hero)| Self::VsOpen { hero, opener });
+        let vs_three_bets = pairs().map(|(hero, three_bettor)| Self::VsThreeBet { hero, three_bettor });
+        opens.chain(vs_opens).chain(vs_three_bets).collect()
+    }
+
+    /// 프리셋 팩이 이 상황을 다루는지 여부
+    ///
+    /// 빅블라인드는 오픈할 수 없고, 오픈에 대응하는 좌석은 오프너 뒤에 있어야 합니다.
+    pub fn is_covered(self) -> bool {
+        let (opener, defender) = self.matchup();
+        opener < defender
+    }
+
+    /// 액션할 포지션
+    pub fn hero(self) -> PresetPosition {
+        match self {
+            Self::Open { hero } | Self::VsOpen { hero, .. } | Self::VsThreeBet { hero, .. } => hero,
+        }
+    }
+
+    /// 학습한 헤즈업 대결 (오프너, 방어자)
+    ///
+    /// 오픈은 빅블라인드를 방어자로 두고 뒤에 남은 좌석들 중 가장 강한 핸드를 준 대결에서 학습합니다.
+    fn matchup(self) -> (PresetPosition, PresetPosition) {
+        match self {
+            Self::Open { hero } => (hero, PresetPosition::BigBlind),
+            Self::VsOpen { hero, opener } => (opener, hero),
+            Self::VsThreeBet { hero, three_bettor } => (hero, three_bettor),
+        }
+    }
+
+    /// 히어로가 액션하기 전까지의 레이즈 수
+    fn raises_before(self) -> usize {
+        match self {
+            Self::Open { .. } => 0,
+            Self::VsOpen { .. } => 1,
+            Self::VsThreeBet { .. } => 2,
+        }
+    }
+
+    /// 히어로가 `hole`로 이 상황에서 액션할 차례인 상태
+    fn decision_state(self, hole: [u8; 2]) -> holdem::State {
+        let (opener, defender) = self.matchup();
+        let mut state = matchup_root(opener, defender);
+        state.hole[self.hero().seat()] = hole;
+        for _ in 0..self.raises_before() {
+            state = holdem::State::next_state(&state, Act::Raise(0));
+        }
+        state
+    }
+
+    /// 이 상황을 웹 API 요청 상태로 표현 (`StrategyTable::get_strategy`에 그대로 쓸 수 있음)
+    pub fn web_state(self, hole: [u8; 2]) -> Result<WebGameState, PresetError> {
+        if !self.is_covered() {
+            return Err(PresetError::NotCovered(self));
+        }
+        let state = self.decision_state(hole);
+        let hero = self.hero().seat();
+        let alive: Vec<usize> = (0..6).filter(|&seat| state.alive[seat]).collect();
+        Ok(WebGameState {
+            hole_cards: hole,
+            board: Vec::new(),
+            street: 0,
+            pot: state.pot,
+            stacks: alive.iter().map(|&seat| state.stack[seat]).collect(),
+            alive_players: alive,
+            street_investments: state.invested.to_vec(),
+            to_call: state.to_call,
+            player_to_act: hero,
+            hero_position: hero,
+            betting_history: Vec::new(),
+            seat_status: Vec::new(),
+            posted: Vec::new(),
+            straddle: None,
+        })
+    }
+}
+
+/// 프리셋 로드/조회 에러
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresetError {
+    /// 프리셋 팩이 다루지 않는 상황
+    NotCovered(PresetSpot),
+    /// 팩에 해당 핸드의 전략이 없음
+    MissingHand { spot: PresetSpot, hole: [u8; 2] },
+    /// 지원하지 않는 팩 형식 버전
+    UnsupportedVersion { found: u32, supported: u32 },
+    /// 체크섬 불일치 (손상되거나 변조된 팩)
+    ChecksumMismatch { expected: u64, actual: u64 },
+    /// 현재 빌드와 호환되지 않는 팩 (마이그레이션 경로 없음)
+    Incompatible(IncompatibleArtifact),
+    /// 팩을 해석할 수 없음
+    Corrupt(String),
+}
+
+impl fmt::Display for PresetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotCovered(spot) => write!(f, "프리셋이 다루지 않는 상황입니다: {:?}", spot),
+            Self::MissingHand { spot, hole } => write!(f, "{:?}에 핸드 {:?}의 프리셋 전략이 없습니다", spot, hole),
+            Self::UnsupportedVersion { found, supported } => {
+                write!(f, "지원하지 않는 프리셋 팩 버전: {} (지원: {})", found, supported)
+            }
+            Self::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "프리셋 팩 체크섬 불일치 (기록 {:016x}, 계산 {:016x}) - 손상되었거나 변조된 팩입니다",
+                expected, actual
+            ),
+            Self::Incompatible(error) => write!(f, "프리셋 팩을 쓸 수 없습니다: {}", error),
+            Self::Corrupt(reason) => write!(f, "프리셋 팩을 읽을 수 없습니다: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for PresetError {}
+
+/// 프리셋 팩 파일 내용 (bincode 직렬화)
+#[derive(Serialize, Deserialize)]
+struct PresetPackFile {
+    version: u32,
+    /// `payload`의 FNV-1a 64비트 해시
+    checksum: u64,
+    /// `PresetPayload`를 직렬화한 바이트
+    payload: Vec<u8>,
+}
+
+/// 체크섬으로 보호되는 팩 본문
+#[derive(Serialize, Deserialize)]
+struct PresetPayload {
+    /// 팩을 만든 빌드의 호환성 지문
+    fingerprint: CompatibilityFingerprint,
+    /// 상황별 전략 테이블 (`StrategyTable::to_bytes` 형식)
+    spots: Vec<(PresetSpot, Vec<u8>)>,
+    /// 생성 시 전략에 적용한 정제 방식
+    purification: Purification,
+}
+
+/// 지문이 없는 버전 2 팩 본문
+#[derive(Deserialize)]
+struct LegacyPresetPayload {
+    spots: Vec<(PresetSpot, Vec<u8>)>,
+    purification: Purification,
+}
+
+/// FNV-1a 64비트 해시 (손상/변조 검출용이며 암호학적 서명은 아님)
+fn checksum(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// 프리플랍 프리셋 전략 모음
+///
+/// 상황마다 `StrategyTable` 하나를 가지며, 조회는 상황을 웹 요청 상태로 바꿔
+/// 해당 테이블에서 합니다. 정보 집합 키에는 상대 포지션이 없으므로 포지션 쌍마다
+/// 테이블을 따로 둡니다.
+pub struct PresetStrategies {
+    tables: BTreeMap<PresetSpot, StrategyTable>,
+    purification: Purification,
+    fingerprint: CompatibilityFingerprint,
+}
+
+impl PresetStrategies {
+    /// 크레이트에 포함된 기본 팩 (100bb 6맥스)
+    pub fn load_default() -> Self {
+        Self::from_bytes(DEFAULT_PACK).expect("크레이트에 포함된 프리셋 팩은 항상 유효함")
+    }
+
+    /// 팩 바이트에서 복원 (형식 버전, 체크섬, 호환성 지문 검사)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PresetError> {
+        Self::from_bytes_with_migrations(bytes, &MigrationRegistry::builtin())
+    }
+
+    /// 팩 바이트에서 복원 (지문의 버전 구성 요소가 다르면 `registry`의 마이그레이션 적용)
+    ///
+    /// 지문이 없는 버전 2 팩은 기준 버전으로 만든 것으로 봅니다.
+    pub fn from_bytes_with_migrations(bytes: &[u8], registry: &MigrationRegistry) -> Result<Self, PresetError> {
+        let file: PresetPackFile =
+            bincode::deserialize(bytes).map_err(|e| PresetError::Corrupt(e.to_string()))?;
+        if file.version != PRESET_PACK_FORMAT_VERSION && file.version != LEGACY_PRESET_PACK_FORMAT_VERSION {
+            return Err(PresetError::UnsupportedVersion {
+                found: file.version,
+                supported: PRESET_PACK_FORMAT_VERSION,
+            });
+        }
+        let actual = checksum(&file.payload);
+        if actual != file.checksum {
+            return Err(PresetError::ChecksumMismatch {
+                expected: file.checksum,
+                actual,
+            });
+        }
+
+        let corrupt = |e: bincode::Error| PresetError::Corrupt(e.to_string());
+        let payload: PresetPayload = if file.version == LEGACY_PRESET_PACK_FORMAT_VERSION {
+            let legacy: LegacyPresetPayload = bincode::deserialize(&file.payload).map_err(corrupt)?;
+            PresetPayload {
+                fingerprint: holdem::State::fingerprint(None).with_baseline_versions(),
+                spots: legacy.spots,
+                purification: legacy.purification,
+            }
+        } else {
+            bincode::deserialize(&file.payload).map_err(corrupt)?
+        };
+        let fingerprint = holdem::State::fingerprint(None);
+        registry
+            .check(&payload.fingerprint, &fingerprint)
+            .map_err(PresetError::Incompatible)?;
+
+        // 정제는 다시 적용해도 결과가 같으므로 조회 응답에 정제 방식을 기록하는 용도로 테이블에 지정
+        let purification = payload.purification;
+        let tables = payload
+            .spots
+            .into_iter()
+            .map(|(spot, bytes)| {
+                let table = StrategyTable::from_bytes_with_migrations(&bytes, registry).map_err(|e| match e {
+                    StrategyTableError::Incompatible(error) => PresetError::Incompatible(error),
+                    other => PresetError::Corrupt(other.to_string()),
+                })?;
+                Ok((spot, table.with_purification(purification)))
+            })
+            .collect::<Result<_, PresetError>>()?;
+        Ok(Self {
+            tables,
+            purification,
+            fingerprint,
+        })
+    }
+
+    /// 팩 파일 로드
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes(&bytes).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// 팩 생성 시 전략에 적용한 정제 방식
+    pub fn purification(&self) -> Purification {
+        self.purification
+    }
+
+    /// 팩 전략의 호환성 지문 (마이그레이션했다면 변환 후의 현재 지문)
+    pub fn fingerprint(&self) -> &CompatibilityFingerprint {
+        &self.fingerprint
+    }
+
+    /// 팩에 들어 있는 상황들
+    pub fn spots(&self) -> impl Iterator<Item = PresetSpot> + '_ {
+        self.tables.keys().copied()
+    }
+
+    /// 상황의 전략 테이블
+    pub fn table(&self, spot: PresetSpot) -> Result<&StrategyTable, PresetError> {
+        self.tables.get(&spot).ok_or(PresetError::NotCovered(spot))
+    }
+
+    /// 상황에서 `hole`의 액션 확률 (폴드, 콜, 레이즈 순)
+    pub fn lookup_strategy(&self, spot: PresetSpot, hole: [u8; 2]) -> Result<Vec<f64>, PresetError> {
+        let table = self.table(spot)?;
+        table
+            .lookup_strategy(&spot.web_state(hole)?)
+            .ok_or(PresetError::MissingHand { spot, hole })
+    }
+
+    /// 상황에서 `hole`의 전략 응답 (`StrategyTable::get_strategy`와 같은 형식)
+    pub fn get_strategy(&self, spot: PresetSpot, hole: [u8; 2]) -> Result<StrategyResponse, PresetError> {
+        self.lookup_strategy(spot, hole)?;
+        Ok(self.table(spot)?.get_strategy(&spot.web_state(hole)?))
+    }
+
+    /// 상황의 레이즈 빈도 (1,326개 홀카드 조합 평균, 오픈 상황이면 오픈 레인지 비율)
+    pub fn raise_frequency(&self, spot: PresetSpot) -> Result<f64, PresetError> {
+        let combos = hole_combos();
+        let mut total = 0.0;
+        for &hole in &combos {
+            total += self.lookup_strategy(spot, hole)?.get(2).copied().unwrap_or(0.0);
+        }
+        Ok(total / combos.len() as f64)
+    }
+}
+
+/// 모든 홀카드 조합 (1,326개)
+fn hole_combos() -> Vec<[u8; 2]> {
+    (0..52u8).flat_map(|a| (a + 1..52).map(move |b| [a, b])).collect()
+}
+
+/// 오프너와 방어자만 남은 100bb 6맥스 프리플랍 (블라인드는 폴드했어도 데드 머니로 남음)
+fn matchup_root(opener: PresetPosition, defender: PresetPosition) -> holdem::State {
+    let stacks = [PRESET_STACK_BB * PRESET_BLINDS[1]; 6];
+    let mut state = holdem::State::new_hand_with_rng(
+        PRESET_BLINDS,
+        0,
+        AnteType::PerPlayer,
+        stacks,
+        6,
+        &mut StdRng::seed_from_u64(0),
+    );
+    for seat in 0..6 {
+        state.alive[seat] = seat == opener.seat() || seat == defender.seat();
+        state.hole[seat] = [0, 0];
+    }
+    state.to_act = opener.seat();
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tampered_pack_is_rejected() {
+        let mut bytes = DEFAULT_PACK.to_vec();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0x55;
+        assert!(matches!(
+            PresetStrategies::from_bytes(&bytes),
+            Err(PresetError::ChecksumMismatch { .. })
+        ));
+
+        let mut file: PresetPackFile = bincode::deserialize(DEFAULT_PACK).unwrap();
+        file.version += 1;
+        let bumped = bincode::serialize(&file).unwrap();
+        assert_eq!(
+            PresetStrategies::from_bytes(&bumped).err(),
+            Some(PresetError::UnsupportedVersion {
+                found: PRESET_PACK_FORMAT_VERSION + 1,
+                supported: PRESET_PACK_FORMAT_VERSION,
+            })
+        );
+        assert!(matches!(
+            PresetStrategies::from_bytes(&DEFAULT_PACK[..10]),
+            Err(PresetError::Corrupt(_))
+        ));
+    }
+
+    #[test]
+    fn test_default_pack_open_ranges_widen_by_position() {
+        let presets = PresetStrategies::load_default();
+        assert_eq!(presets.spots().count(), PresetSpot::covered().len());
+
+        let utg = presets.raise_frequency(PresetSpot::Open { hero: PresetPosition::Utg }).unwrap();
+        let button = presets.raise_frequency(PresetSpot::Open { hero: PresetPosition::Button }).unwrap();
+        assert!(utg < button, "UTG 오픈 {:.3}, 버튼 오픈 {:.3}", utg, button);
+
+        // 에이스 페어는 UTG에서도 오픈
+        let aces = presets.get_strategy(PresetSpot::Open { hero: PresetPosition::Utg }, [0, 13]).unwrap();
+        assert_eq!(aces.recommended_action, "raise_small");
+    }
+
+    #[test]
+    fn test_uncovered_spot_is_typed_error() {
+        let presets = PresetStrategies::load_default();
+        let spot = PresetSpot::Open { hero: PresetPosition::BigBlind };
+        assert_eq!(presets.lookup_strategy(spot, [0, 13]), Err(PresetError::NotCovered(spot)));
+
+        // 오프너보다 앞 좌석은 오픈에 대응할 수 없음
+        let spot = PresetSpot::VsOpen {
+            hero: PresetPosition::Utg,
+            opener: PresetPosition::Button,
+        };
+        assert!(!spot.is_covered());
+        assert_eq!(presets.get_strategy(spot, [0, 13]).err(), Some(PresetError::NotCovered(spot)));
+    }
+
+    #[cfg(feature = "training")]
+    #[test]
+    fn test_generated_pack_records_purification() {
+        let config = PresetGenerationConfig {
+            deals_per_matchup: 1,
+            iterations: 1,
+            seed: 1,
+            purification: Purification::Argmax,
+        };
+        let presets = PresetStrategies::from_bytes(&generate_pack(&config).unwrap()).unwrap();
+        assert_eq!(presets.purification(), Purification::Argmax);
+
+        let spot = PresetSpot::Open { hero: PresetPosition::Button };
+        let strategy = presets.lookup_strategy(spot, [0, 13]).unwrap();
+        assert!(strategy.iter().all(|&p| !(1e-3..=1.0 - 1e-3).contains(&p)), "{:?}", strategy);
+        assert_eq!(presets.get_strategy(spot, [0, 13]).unwrap().purification, Purification::Argmax);
+        assert_eq!(PresetStrategies::load_default().purification(), Purification::None);
+    }
+}
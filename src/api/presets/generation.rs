@@ -0,0 +1,242 @@
+// 프리셋 팩 생성 (오프라인 작업, `training` 기능)
+// 포지션 쌍마다 프리플랍 헤즈업 대결을 CFR로 학습해 상황별 전략 테이블을 팩으로 묶습니다.
+
+use super::{
+    checksum, hole_combos, matchup_root, PresetPackFile, PresetPayload, PresetPosition, PresetSpot,
+    PRESET_BLINDS, PRESET_PACK_FORMAT_VERSION,
+};
+use crate::api::web_api::{StackBucket, StrategyPayload, StrategyTable};
+use crate::game::all_in::AllInAdjudicator;
+use crate::game::card_abstraction::{hand_strength, preflop_bucket};
+use crate::game::holdem::{self, Act};
+use crate::solver::cfr_core::{Game, GameState, Trainer};
+use crate::solver::compat::CompatibilityFingerprint;
+use crate::solver::purification::Purification;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashMap;
+
+/// 프리셋 팩 생성 설정
+#[derive(Debug, Clone)]
+pub struct PresetGenerationConfig {
+    /// 대결마다 학습에 쓸 무작위 딜 수 (모든 프리플랍 버킷이 최소 한 번씩 추가로 들어감)
+    pub deals_per_matchup: usize,
+    /// 대결마다 CFR 반복 수
+    pub iterations: usize,
+    /// 딜링 시드
+    pub seed: u64,
+    /// 팩에 담기 전 평균 전략에 적용할 정제 방식
+    pub purification: Purification,
+}
+
+impl Default for PresetGenerationConfig {
+    fn default() -> Self {
+        Self {
+            deals_per_matchup: 400,
+            iterations: 300,
+            seed: 0,
+            purification: Purification::None,
+        }
+    }
+}
+
+/// 프리플랍 베팅만 학습하는 헤즈업 대결
+///
+/// 프리플랍 베팅이 끝나면 (플랍 찬스 노드) 두 핸드의 올인 에퀴티로 팟을 나눠 정산합니다.
+/// 포스트플랍 플레이와 포지션의 에퀴티 실현 차이는 무시합니다.
+#[derive(Clone)]
+struct PreflopMatchup {
+    state: holdem::State,
+    /// 좌석별 쇼다운 팟 지분 (딜마다 한 번 계산)
+    equity: [f64; 6],
+}
+
+impl GameState for PreflopMatchup {
+    fn is_terminal(&self) -> bool {
+        self.state.is_terminal() || self.state.is_chance_node()
+    }
+
+    fn is_chance_node(&self) -> bool {
+        false
+    }
+}
+
+impl Game for PreflopMatchup {
+    type State = PreflopMatchup;
+    type Action = Act;
+    type InfoKey = u64;
+
+    const N_PLAYERS: usize = 6;
+
+    fn current_player(s: &Self::State) -> Option<usize> {
+        if s.is_terminal() {
+            return None;
+        }
+        holdem::State::current_player(&s.state)
+    }
+
+    fn legal_actions(s: &Self::State) -> Vec<Act> {
+        if s.is_terminal() {
+            return Vec::new();
+        }
+        holdem::State::legal_actions(&s.state)
+    }
+
+    fn next_state(s: &Self::State, a: Act) -> Self::State {
+        Self {
+            state: holdem::State::next_state(&s.state, a),
+            equity: s.equity,
+        }
+    }
+
+    fn apply_chance<R: rand::Rng>(s: &Self::State, _r: &mut R) -> Self::State {
+        s.clone()
+    }
+
+    fn util(s: &Self::State, hero: usize) -> f64 {
+        let state = &s.state;
+        let alive = state.alive.iter().filter(|&&alive| alive).count();
+        if alive < 2 || !state.alive[hero] {
+            return holdem::State::util(state, hero);
+        }
+        let contributed = (state.invested[hero] + state.antes[hero]) as f64;
+        s.equity[hero] * state.net_pot() as f64 - contributed
+    }
+
+    fn info_key(s: &Self::State, v: usize) -> u64 {
+        holdem::State::info_key(&s.state, v)
+    }
+
+    fn fingerprint(root: Option<&Self::State>) -> CompatibilityFingerprint {
+        holdem::State::fingerprint(root.map(|matchup| &matchup.state))
+    }
+}
+
+/// 대결의 학습 루트들 (무작위 딜 + 좌석별로 빠진 프리플랍 버킷을 채우는 딜)
+///
+/// `best_of`가 1보다 크면 방어자는 그만큼의 핸드 중 가장 강한 핸드를 받습니다
+/// (오픈 뒤에 남은 좌석 수만큼 누군가 좋은 핸드를 들고 있을 가능성 반영).
+fn matchup_roots(
+    opener: PresetPosition,
+    defender: PresetPosition,
+    best_of: usize,
+    config: &PresetGenerationConfig,
+    rng: &mut StdRng,
+) -> Vec<PreflopMatchup> {
+    let root = matchup_root(opener, defender);
+    let seats = [opener.seat(), defender.seat()];
+    let adjudicator = AllInAdjudicator::deterministic();
+    let deal = |fixed: Option<(usize, [u8; 2])>, rng: &mut StdRng| {
+        let mut deck: Vec<u8> = (0..52).filter(|card| fixed.is_none_or(|(_, hole)| !hole.contains(card))).collect();
+        deck.shuffle(rng);
+        let mut cards = deck.chunks_exact(2).map(|pair| [pair[0], pair[1]]);
+        let mut hands = [[0u8; 2]; 2];
+        for (hand, &seat) in hands.iter_mut().zip(&seats) {
+            *hand = match fixed {
+                Some((fixed_seat, hole)) if fixed_seat == seat => hole,
+                _ if seat == defender.seat() => (0..best_of.max(1))
+                    .filter_map(|_| cards.next())
+                    .max_by(|a, b| hand_strength(*a, &[]).total_cmp(&hand_strength(*b, &[])))
+                    .unwrap_or([0, 1]),
+                _ => cards.next().unwrap_or([0, 1]),
+            };
+        }
+        let mut state = root.clone();
+        let mut equity = [0.0; 6];
+        let shares = adjudicator.equities(&hands, &[]).equities;
+        for ((&seat, hand), share) in seats.iter().zip(hands).zip(shares) {
+            state.hole[seat] = hand;
+            equity[seat] = share;
+        }
+        PreflopMatchup { state, equity }
+    };
+
+    let mut roots: Vec<PreflopMatchup> = (0..config.deals_per_matchup).map(|_| deal(None, rng)).collect();
+    for seat in seats {
+        let mut seen: Vec<u8> = roots.iter().map(|root| preflop_bucket(root.state.hole[seat])).collect();
+        for hole in hole_combos() {
+            let bucket = preflop_bucket(hole);
+            if !seen.contains(&bucket) {
+                seen.push(bucket);
+                roots.push(deal(Some((seat, hole)), rng));
+            }
+        }
+    }
+    roots
+}
+
+/// 상황별 전략을 `StrategyTable` 바이트로 변환
+///
+/// 모든 홀카드 조합에 대해 학습 노드의 평균 전략을 정제해 웹 요청 상태의 정보 집합 키로 옮겨 담습니다.
+fn spot_table(
+    spot: PresetSpot,
+    trainer: &Trainer<PreflopMatchup>,
+    purification: Purification,
+) -> Result<Vec<u8>, String> {
+    let hero = spot.hero().seat();
+    let mut strategies = HashMap::new();
+    let mut visits = HashMap::new();
+    for hole in hole_combos() {
+        let trained_key = holdem::State::info_key(&spot.decision_state(hole), hero);
+        let Some(node) = trainer.nodes.get(&trained_key) else {
+            return Err(format!("{:?}: 핸드 {:?}의 학습 노드가 없습니다", spot, hole));
+        };
+        let web = spot.web_state(hole).map_err(|e| e.to_string())?;
+        let key = holdem::State::info_key(&StrategyTable::web_to_observed_state(&web).to_state(), hero);
+        strategies.insert(key, purification.apply(&node.average()));
+        visits.insert(key, node.visits());
+    }
+    let bucket = StackBucket {
+        stack_bb: None,
+        strategies: StrategyPayload::Raw(strategies).compacted(),
+        visits,
+        flop_subset: None,
+    };
+    StrategyTable::from_buckets(vec![bucket], PRESET_BLINDS[1]).to_bytes()
+}
+
+/// 프리셋 팩 생성 (오프라인 작업, `generate_presets` 바이너리에서 사용)
+///
+/// 오픈은 포지션마다, 오픈/3벳 대응은 포지션 쌍마다 프리플랍 헤즈업 대결을 학습합니다.
+pub fn generate_pack(config: &PresetGenerationConfig) -> Result<Vec<u8>, String> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut spots = Vec::new();
+    let mut train = |opener: PresetPosition, defender: PresetPosition, best_of: usize| {
+        let mut trainer = Trainer::<PreflopMatchup>::new();
+        trainer.set_seed(config.seed);
+        trainer.run(matchup_roots(opener, defender, best_of, config, &mut rng), config.iterations);
+        trainer
+    };
+
+    for spot in PresetSpot::covered() {
+        let trainer = match spot {
+            // 오픈 뒤에 남은 좌석 수만큼의 핸드 중 가장 강한 핸드를 빅블라인드가 받음
+            PresetSpot::Open { hero } => train(hero, PresetPosition::BigBlind, 5 - hero.seat()),
+            PresetSpot::VsOpen { .. } => continue,
+            PresetSpot::VsThreeBet { hero, three_bettor } => {
+                let trainer = train(hero, three_bettor, 1);
+                let vs_open = PresetSpot::VsOpen {
+                    hero: three_bettor,
+                    opener: hero,
+                };
+                spots.push((vs_open, spot_table(vs_open, &trainer, config.purification)?));
+                trainer
+            }
+        };
+        spots.push((spot, spot_table(spot, &trainer, config.purification)?));
+    }
+
+    let payload = bincode::serialize(&PresetPayload {
+        fingerprint: holdem::State::fingerprint(None),
+        spots,
+        purification: config.purification,
+    })
+    .map_err(|e| e.to_string())?;
+    let file = PresetPackFile {
+        version: PRESET_PACK_FORMAT_VERSION,
+        checksum: checksum(&payload),
+        payload,
+    };
+    bincode::serialize(&file).map_err(|e| e.to_string())
+}
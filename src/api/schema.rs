@@ -0,0 +1,164 @@
+// 웹 API DTO의 JSON 스키마 생성 (feature = "schema")
+// 프론트엔드 타입 정의를 Rust 구조체와 동기화하기 위해 사용
+
+use crate::api::analysis::{
+    AnalysisInsights, AnalysisMetadata, AnalysisOptions, AnalysisRequest, CardEquity, CounterfactualPoint,
+    EVAnalysisResponse, EquityHistogram, EquityHistogramOptions, OpponentModelKind, PokerAnalysisResponse, RiskLevel,
+};
+use crate::api::{web_api, web_api_simple};
+use crate::game::all_in::{AllInEquity, RunItNTimesReport};
+use crate::game::concrete_action::ConcreteAction;
+use crate::game::holdem::{Act, RaiseBounds};
+use crate::game::observation::ObservedState;
+use crate::game::value_unit::{ValueContext, ValueUnit};
+use crate::solver::ev_calculator::{ActionEV, SizingCurve};
+use crate::solver::purification::Purification;
+use schemars::{schema_for, JsonSchema};
+use std::collections::HashMap;
+
+/// 단일 타입의 JSON 스키마 생성
+pub fn schema_of<T: JsonSchema>() -> serde_json::Value {
+    serde_json::to_value(schema_for!(T)).expect("스키마는 항상 JSON으로 직렬화 가능")
+}
+
+/// 모든 공개 API DTO의 JSON 스키마를 이름별로 반환
+///
+/// 이름이 겹치는 간단 API 타입은 `Simple` 접두사를 붙입니다.
+pub fn export_all() -> HashMap<String, serde_json::Value> {
+    let mut schemas = HashMap::new();
+    let mut add = |name: &str, schema: serde_json::Value| {
+        schemas.insert(name.to_string(), schema);
+    };
+
+    // 공통 타입
+    add("Act", schema_of::<Act>());
+    add("ConcreteAction", schema_of::<ConcreteAction>());
+    add("ActionEV", schema_of::<ActionEV>());
+    add("ObservedState", schema_of::<ObservedState>());
+    add("RaiseBounds", schema_of::<RaiseBounds>());
+    add("Purification", schema_of::<Purification>());
+    add("ValueUnit", schema_of::<ValueUnit>());
+    add("ValueContext", schema_of::<ValueContext>());
+    add("AllInEquity", schema_of::<AllInEquity>());
+    add("RunItNTimesReport", schema_of::<RunItNTimesReport>());
+
+    // 완전 기능 웹 API
+    add("WebGameState", schema_of::<web_api::WebGameState>());
+    add("Action", schema_of::<web_api::Action>());
+    add("StrategyResponse", schema_of::<web_api::StrategyResponse>());
+
+    // 간단 웹 API
+    add("SimpleWebGameState", schema_of::<web_api_simple::WebGameState>());
+    add("SimpleStrategyResponse", schema_of::<web_api_simple::StrategyResponse>());
+
+    // 분석 API
+    add("AnalysisRequest", schema_of::<AnalysisRequest>());
+    add("AnalysisOptions", schema_of::<AnalysisOptions>());
+    add("OpponentModelKind", schema_of::<OpponentModelKind>());
+    add("PokerAnalysisResponse", schema_of::<PokerAnalysisResponse>());
+    add("EVAnalysisResponse", schema_of::<EVAnalysisResponse>());
+    add("AnalysisInsights", schema_of::<AnalysisInsights>());
+    add("RiskLevel", schema_of::<RiskLevel>());
+    add("AnalysisMetadata", schema_of::<AnalysisMetadata>());
+    add("CounterfactualPoint", schema_of::<CounterfactualPoint>());
+    add("EquityHistogramOptions", schema_of::<EquityHistogramOptions>());
+    add("EquityHistogram", schema_of::<EquityHistogram>());
+    add("CardEquity", schema_of::<CardEquity>());
+    add("SizingCurve", schema_of::<SizingCurve>());
+
+    schemas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::analysis::analyze_poker_state;
+    use jsonschema::JSONSchema;
+
+    fn assert_valid(schema: &serde_json::Value, instance: &serde_json::Value) {
+        let compiled = JSONSchema::compile(schema).expect("스키마 컴파일 실패");
+        let messages: Vec<String> = match compiled.validate(instance) {
+            Ok(()) => return,
+            Err(errors) => errors.map(|e| e.to_string()).collect(),
+        };
+        panic!("스키마 검증 실패: {:?}", messages);
+    }
+
+    #[test]
+    fn test_export_all_covers_dtos() {
+        let schemas = export_all();
+        for name in [
+            "Act",
+            "ConcreteAction",
+            "ActionEV",
+            "ObservedState",
+            "RaiseBounds",
+            "Purification",
+            "ValueUnit",
+            "ValueContext",
+            "AllInEquity",
+            "RunItNTimesReport",
+            "WebGameState",
+            "Action",
+            "StrategyResponse",
+            "SimpleWebGameState",
+            "SimpleStrategyResponse",
+            "AnalysisRequest",
+            "AnalysisOptions",
+            "PokerAnalysisResponse",
+            "RiskLevel",
+            "CounterfactualPoint",
+            "EquityHistogramOptions",
+            "EquityHistogram",
+            "SizingCurve",
+        ] {
+            let schema = schemas.get(name).unwrap_or_else(|| panic!("{} 스키마 없음", name));
+            assert!(JSONSchema::compile(schema).is_ok(), "{} 스키마 컴파일 실패", name);
+        }
+    }
+
+    #[test]
+    fn test_sample_response_validates() {
+        let request = AnalysisRequest {
+            game_state: web_api::WebGameState {
+                hole_cards: [0, 13],
+                board: vec![],
+                street: 0,
+                pot: 30,
+                stacks: vec![1000, 1000],
+                alive_players: vec![0, 1],
+                street_investments: vec![10, 20],
+                to_call: 20,
+                player_to_act: 0,
+                hero_position: 0,
+                betting_history: vec![vec![web_api::Action::Raise(60)]],
+                seat_status: Vec::new(),
+                posted: Vec::new(),
+                straddle: None,
+            },
+            options: AnalysisOptions {
+                depth: "quick".to_string(),
+                ..Default::default()
+            },
+            action_history: vec![Act::Call],
+        };
+
+        let request_json = serde_json::to_value(&request.game_state).unwrap();
+        assert_valid(&schema_of::<web_api::WebGameState>(), &request_json);
+
+        let response = analyze_poker_state(request).unwrap();
+        let response_json = serde_json::to_value(&response).unwrap();
+        assert_valid(&schema_of::<PokerAnalysisResponse>(), &response_json);
+    }
+
+    #[test]
+    fn test_act_representation() {
+        let json = serde_json::to_value(Act::Raise(2)).unwrap();
+        assert_eq!(json, serde_json::json!({"type": "Raise", "size": 2}));
+        assert_eq!(
+            serde_json::to_value(Act::Fold).unwrap(),
+            serde_json::json!({"type": "Fold"})
+        );
+        assert_valid(&schema_of::<Act>(), &json);
+    }
+}
@@ -0,0 +1,298 @@
+// 서버리스 배포용 순수 함수 API
+// 요청 하나에 필요한 모든 것(게임 상태, 전략 출처, 예산)을 담아 `evaluate` 한 번으로 전략과
+// EV를 계산합니다. 전역 상태, 백그라운드 스레드, 파일 I/O가 없고, 캐시는 호출 안에서만 삽니다.
+//
+// - 전략 테이블은 요청의 바이트에서, 프리셋은 크레이트에 포함된 팩에서 호출마다 읽습니다.
+// - EV 시뮬레이션은 요청의 시드로 돌리므로 시간 예산이 끝나지 않는 한 같은 요청은 같은 응답을 냅니다.
+// - 올인 판정의 프리플랍 헤즈업 열거 결과도 전역 캐시 대신 호출마다 새로 만든 캐시에 둡니다.
+// - 응답에는 경과 시간처럼 호출마다 달라지는 값을 넣지 않습니다. 지연 시간은 호출하는 쪽에서 잽니다.
+// - 패닉은 잡아서 `error`로 돌려줍니다.
+//
+// 학습 코드가 필요 없으므로 `--no-default-features --features minimal`로 빌드한 라이브러리에서도 쓸 수 있습니다.
+
+use crate::api::analysis::HoldemStateBuilder;
+use crate::api::presets::{PresetSpot, PresetStrategies};
+use crate::api::web_api::{StrategyResponse, StrategyTable, WebGameState};
+use crate::game::all_in::{AllInConfig, PreflopMemo};
+use crate::solver::ev_calculator::{ActionEV, EVCalculator, EVConfig};
+use serde::{Deserialize, Serialize};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::Duration;
+
+/// 요청 하나로 허용하는 최대 시뮬레이션 수 (메모리와 지연 시간 상한)
+pub const MAX_STATELESS_SAMPLES: usize = 20_000;
+
+/// 요청 하나로 허용하는 최대 시뮬레이션 깊이
+pub const MAX_STATELESS_DEPTH: u8 = 10;
+
+/// 무상태 평가 요청
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatelessRequest {
+    pub game_state: WebGameState,
+    /// 전략 출처 (기본: 없음, EV만 계산)
+    #[serde(default)]
+    pub strategy: StrategySource,
+    #[serde(default)]
+    pub options: StatelessOptions,
+}
+
+/// 전략 출처
+///
+/// JSON 표현 (내부 태그): `{"type": "None"}`, `{"type": "Blob", "bytes": [...]}`,
+/// `{"type": "Preset", "spot": {"Open": {"hero": "Button"}}}`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum StrategySource {
+    /// 전략 없이 EV만 계산
+    #[default]
+    None,
+    /// `StrategyTable::to_bytes` 형식의 전략 테이블
+    Blob { bytes: Vec<u8> },
+    /// 크레이트에 포함된 프리플랍 프리셋의 상황 (히어로 홀카드는 게임 상태에서)
+    Preset { spot: PresetSpot },
+}
+
+/// 계산 예산과 옵션
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatelessOptions {
+    /// 액션마다 돌릴 최대 시뮬레이션 수 (`MAX_STATELESS_SAMPLES`로 제한)
+    pub max_samples: usize,
+    /// 최대 시뮬레이션 깊이 (`MAX_STATELESS_DEPTH`로 제한)
+    pub max_depth: u8,
+    /// EV 계산 시간 예산 (밀리초, 없으면 `max_samples`까지)
+    ///
+    /// 예산이 끝나면 남은 시뮬레이션을 건너뛰므로 결과가 실행 환경의 속도에 따라 달라집니다.
+    pub time_budget_ms: Option<u64>,
+    /// 시뮬레이션 난수 시드
+    pub seed: u64,
+    /// 상대 액션을 상대 모델로 샘플링할지 여부
+    pub use_opponent_model: bool,
+}
+
+impl Default for StatelessOptions {
+    fn default() -> Self {
+        Self {
+            max_samples: 1000,
+            max_depth: 5,
+            time_budget_ms: None,
+            seed: 0,
+            use_opponent_model: false,
+        }
+    }
+}
+
+/// 무상태 평가 응답
+#[derive(Debug, Clone, Serialize)]
+pub struct StatelessResponse {
+    /// 전략 출처에서 조회한 전략 (출처가 없거나 조회에 실패하면 None)
+    pub strategy: Option<StrategyResponse>,
+    /// 액션별 EV (EV 높은 순, 계산하지 못하면 비어 있음)
+    pub action_evs: Vec<ActionEV>,
+    pub metadata: StatelessMetadata,
+    /// 요청을 처리하지 못한 이유 (성공하면 None)
+    pub error: Option<String>,
+}
+
+/// 응답 메타데이터 (같은 요청이면 같은 값, 경과 시간은 넣지 않음)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatelessMetadata {
+    /// 라이브러리 버전
+    pub version: String,
+    /// 사용한 전략 출처 ("none", "blob", "preset")
+    pub strategy_source: String,
+    /// 모든 액션에 걸쳐 실제로 돌린 시뮬레이션 수
+    pub samples_run: usize,
+    /// 시간 예산이 끝나 시뮬레이션을 줄였는지
+    pub budget_exhausted: bool,
+    /// 계산은 했지만 주의할 점
+    pub warnings: Vec<String>,
+}
+
+/// 요청 하나를 평가 (패닉하지 않음)
+pub fn evaluate(request: StatelessRequest) -> StatelessResponse {
+    let mut metadata = StatelessMetadata {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        strategy_source: match request.strategy {
+            StrategySource::None => "none",
+            StrategySource::Blob { .. } => "blob",
+            StrategySource::Preset { .. } => "preset",
+        }
+        .to_string(),
+        ..StatelessMetadata::default()
+    };
+    let failed = |metadata, error: String| StatelessResponse {
+        strategy: None,
+        action_evs: Vec::new(),
+        metadata,
+        error: Some(error),
+    };
+
+    match catch_unwind(AssertUnwindSafe(|| evaluate_unguarded(&request, &mut metadata))) {
+        Ok(Ok((strategy, action_evs))) => StatelessResponse {
+            strategy,
+            action_evs,
+            metadata,
+            error: None,
+        },
+        Ok(Err(error)) => failed(metadata, error),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "알 수 없는 패닉".to_string());
+            failed(metadata, format!("평가 중 패닉: {}", message))
+        }
+    }
+}
+
+fn evaluate_unguarded(
+    request: &StatelessRequest,
+    metadata: &mut StatelessMetadata,
+) -> Result<(Option<StrategyResponse>, Vec<ActionEV>), String> {
+    let state = HoldemStateBuilder::from_web_state(&request.game_state).map_err(|e| e.to_string())?;
+
+    let strategy = match lookup_strategy(&request.strategy, &request.game_state) {
+        Ok(strategy) => strategy,
+        Err(warning) => {
+            metadata.warnings.push(warning);
+            None
+        }
+    };
+
+    let options = &request.options;
+    let calculator = EVCalculator::try_new(EVConfig {
+        sample_count: options.max_samples.clamp(1, MAX_STATELESS_SAMPLES),
+        max_depth: options.max_depth.min(MAX_STATELESS_DEPTH),
+        use_opponent_model: options.use_opponent_model,
+        seed: Some(options.seed),
+        time_budget: options.time_budget_ms.map(Duration::from_millis),
+        all_in: AllInConfig {
+            preflop_memo: PreflopMemo::local(),
+            ..AllInConfig::default()
+        },
+        ..EVConfig::default()
+    })
+    .map_err(|e| e.to_string())?;
+    let calculation = calculator.calculate_action_evs_traced(&state);
+    metadata.samples_run = calculation.samples_run;
+    metadata.budget_exhausted = calculation.budget_exhausted;
+
+    Ok((strategy, calculation.action_evs))
+}
+
+/// 전략 출처에서 전략 조회 (실패하면 경고 문구)
+fn lookup_strategy(source: &StrategySource, game_state: &WebGameState) -> Result<Option<StrategyResponse>, String> {
+    match source {
+        StrategySource::None => Ok(None),
+        StrategySource::Blob { bytes } => {
+            let table = StrategyTable::from_bytes(bytes).map_err(|e| format!("전략 테이블을 읽을 수 없음: {}", e))?;
+            Ok(Some(table.get_strategy(game_state)))
+        }
+        StrategySource::Preset { spot } => PresetStrategies::load_default()
+            .get_strategy(*spot, game_state.hole_cards)
+            .map(Some)
+            .map_err(|e| e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::presets::PresetPosition;
+    use std::time::Instant;
+
+    /// 100bb 6맥스에서 UTG~컷오프가 폴드하고 버튼(AA)이 액션할 차례
+    fn request(strategy: StrategySource, options: StatelessOptions) -> StatelessRequest {
+        StatelessRequest {
+            game_state: WebGameState {
+                hole_cards: [0, 13],
+                board: vec![],
+                street: 0,
+                pot: 150,
+                stacks: vec![10_000, 10_000, 10_000, 10_000, 9_950, 9_900],
+                alive_players: vec![3, 4, 5],
+                street_investments: vec![0, 0, 0, 0, 50, 100],
+                to_call: 100,
+                player_to_act: 3,
+                hero_position: 3,
+                betting_history: vec![],
+                seat_status: vec![],
+                posted: vec![],
+                straddle: None,
+            },
+            strategy,
+            options,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_is_deterministic() {
+        let spot = PresetSpot::Open {
+            hero: PresetPosition::Button,
+        };
+        let request = request(StrategySource::Preset { spot }, StatelessOptions::default());
+        let first = evaluate(request.clone());
+        let second = evaluate(request.clone());
+
+        assert!(first.error.is_none(), "{:?}", first.error);
+        assert!(first.strategy.is_some());
+        assert!(!first.action_evs.is_empty());
+        assert!(!first.metadata.budget_exhausted);
+        assert_eq!(first.metadata.strategy_source, "preset");
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
+        );
+
+        // JSON 왕복 요청도 같은 응답
+        let json = serde_json::to_string(&request).unwrap();
+        let parsed: StatelessRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            serde_json::to_string(&evaluate(parsed)).unwrap(),
+            serde_json::to_string(&first).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_time_budget_bounds_latency() {
+        let options = StatelessOptions {
+            max_samples: MAX_STATELESS_SAMPLES,
+            max_depth: MAX_STATELESS_DEPTH,
+            time_budget_ms: Some(50),
+            ..StatelessOptions::default()
+        };
+        let start = Instant::now();
+        let response = evaluate(request(StrategySource::None, options));
+        let elapsed = start.elapsed();
+
+        assert!(response.error.is_none(), "{:?}", response.error);
+        assert!(response.metadata.budget_exhausted);
+        assert!(response.metadata.samples_run >= response.action_evs.len());
+        assert!(response.action_evs.iter().all(|ev| ev.ev.is_finite()));
+        assert!(elapsed < Duration::from_millis(150), "{:?}", elapsed);
+    }
+
+    #[test]
+    fn test_bad_inputs_become_errors() {
+        let mut bad_state = request(StrategySource::None, StatelessOptions::default());
+        bad_state.game_state.stacks.clear();
+        let response = evaluate(bad_state);
+        assert!(response.error.is_some());
+        assert!(response.action_evs.is_empty());
+
+        // 전략을 읽지 못해도 EV는 계산하고 경고로 남김
+        let response = evaluate(request(
+            StrategySource::Blob { bytes: vec![1, 2, 3] },
+            StatelessOptions {
+                max_samples: 50,
+                ..StatelessOptions::default()
+            },
+        ));
+        assert!(response.error.is_none());
+        assert!(response.strategy.is_none());
+        assert!(!response.action_evs.is_empty());
+        assert_eq!(response.metadata.warnings.len(), 1);
+    }
+}
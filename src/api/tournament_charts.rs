@@ -0,0 +1,425 @@
+// 토너먼트 푸시/폴드 차트
+// 테이블 설정(스택, 상금 구조, 블라인드, 히어로 좌석)에서 히어로가 첫 액션일 때 169개 핸드 클래스별
+// 올인 빈도와 뒤에 남은 플레이어들의 손익분기 콜 레인지를 계산합니다.
+//
+// 9인 테이블에서도 1초 안에 끝나도록 다음 근사를 씁니다.
+// - 에퀴티는 크레이트에 포함된 169 x 169 클래스 대 클래스 표(`preflop_class_equity`)를 쓰고,
+//   플레이어 사이의 카드 제거 효과는 무시합니다.
+// - 단일 콜러 근사: 뒤의 플레이어는 자기 뒤가 모두 폴드한다고 가정하고 결정하며, 멀티웨이 올인은 없습니다.
+// - 히어로가 폴드하면 팟은 빅 블라인드가 가져갑니다 (뒤의 플레이어끼리의 대결은 무시).
+// - 결과(모두 폴드, 콜러별 승/패, 히어로 폴드)마다 최종 스택의 ICM 에퀴티를 한 번만 계산하므로
+//   반복 중에는 ICM을 다시 풀지 않습니다. ICM은 정확한 Malmuth-Harville 모델입니다.
+// - 균형은 정해진 횟수의 가상 플레이(fictitious play)로 근사하고, 빈도는 최선 응답의 평균입니다.
+
+use crate::game::card_abstraction::hand_class::HandClass;
+use crate::game::card_abstraction::preflop_class_equity;
+use crate::game::tournament::deals::icm_equities;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 기본 가상 플레이 반복 횟수
+pub const DEFAULT_PUSH_FOLD_ITERATIONS: usize = 200;
+
+/// 차트가 지원하는 최대 플레이어 수 (정확한 ICM 계산 비용 상한)
+pub const MAX_PUSH_FOLD_PLAYERS: usize = 10;
+
+/// 결과를 평가하는 보상 단위
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PushFoldPayoff {
+    /// 최종 스택의 ICM 상금 에퀴티
+    #[default]
+    Icm,
+    /// 최종 칩 수 (칩 EV)
+    Chips,
+}
+
+/// 푸시/폴드 차트 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PushFoldConfig {
+    /// 좌석 순서의 스택 (블라인드와 앤티를 내기 전, 칩)
+    pub stacks: Vec<u32>,
+    /// 남은 상금 (1등부터)
+    pub payouts: Vec<u64>,
+    pub small_blind: u32,
+    pub big_blind: u32,
+    /// 플레이어마다 내는 앤티
+    #[serde(default)]
+    pub ante: u32,
+    /// 버튼 좌석 (헤즈업에서는 버튼이 스몰 블라인드)
+    pub button: usize,
+    /// 앞의 플레이어가 모두 폴드하고 첫 액션을 하는 좌석
+    pub hero: usize,
+    #[serde(default)]
+    pub payoff: PushFoldPayoff,
+    /// 가상 플레이 반복 횟수
+    #[serde(default = "default_iterations")]
+    pub iterations: usize,
+}
+
+fn default_iterations() -> usize {
+    DEFAULT_PUSH_FOLD_ITERATIONS
+}
+
+/// 히어로 올인에 대한 뒤 플레이어 한 명의 콜 레인지
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallingRange {
+    pub seat: usize,
+    /// 콜이 폴드보다 나아지는 최소 에퀴티 (보상 단위 기준)
+    pub break_even_equity: f64,
+    /// 클래스별 콜 빈도 (`HandClass::to_index` 순서, 169개)
+    pub call: Vec<f64>,
+}
+
+/// 히어로 좌석의 푸시/폴드 차트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PushFoldChart {
+    pub hero: usize,
+    pub payoff: PushFoldPayoff,
+    /// 클래스별 올인 빈도 (`HandClass::to_index` 순서, 169개, 나머지는 폴드)
+    pub shove: Vec<f64>,
+    /// 액션 순서대로 뒤에 남은 플레이어들의 콜 레인지 (빅 블라인드가 마지막)
+    pub callers: Vec<CallingRange>,
+}
+
+impl PushFoldChart {
+    /// 클래스의 올인 빈도
+    pub fn shove_frequency(&self, class: HandClass) -> f64 {
+        self.shove[class.to_index() as usize]
+    }
+
+    /// 조합 수로 가중한 올인 비율 (0.0-1.0)
+    pub fn shove_fraction(&self) -> f64 {
+        combo_fraction(&self.shove)
+    }
+
+    /// 13x13 차트 배치의 올인 빈도 (행/열 0이 A, 대각선 위 수티드, 아래 오프수트)
+    pub fn to_grid(&self) -> [[f64; 13]; 13] {
+        to_grid(&self.shove)
+    }
+}
+
+impl CallingRange {
+    /// 조합 수로 가중한 콜 비율 (0.0-1.0)
+    pub fn call_fraction(&self) -> f64 {
+        combo_fraction(&self.call)
+    }
+
+    /// 13x13 차트 배치의 콜 빈도
+    pub fn to_grid(&self) -> [[f64; 13]; 13] {
+        to_grid(&self.call)
+    }
+}
+
+/// 푸시/폴드 차트 설정 에러
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PushFoldError {
+    /// 지원하지 않는 플레이어 수
+    PlayerCount(usize),
+    /// 테이블에 없는 좌석
+    InvalidSeat { seat: usize, players: usize },
+    /// 빅 블라인드는 첫 액션으로 올인할 수 없음
+    HeroIsBigBlind,
+    /// 칩이 없는 좌석
+    EmptyStack(usize),
+    /// 상금이 없음
+    NoPayouts,
+    /// 블라인드가 0이거나 스몰 블라인드가 빅 블라인드보다 큼
+    InvalidBlinds { small_blind: u32, big_blind: u32 },
+}
+
+impl fmt::Display for PushFoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlayerCount(count) => {
+                write!(f, "지원하지 않는 플레이어 수: {} (2-{})", count, MAX_PUSH_FOLD_PLAYERS)
+            }
+            Self::InvalidSeat { seat, players } => write!(f, "{}인 테이블에 없는 좌석: {}", players, seat),
+            Self::HeroIsBigBlind => write!(f, "빅 블라인드는 첫 액션으로 올인할 수 없습니다"),
+            Self::EmptyStack(seat) => write!(f, "좌석 {}의 스택이 0입니다", seat),
+            Self::NoPayouts => write!(f, "상금 구조가 비어 있습니다"),
+            Self::InvalidBlinds { small_blind, big_blind } => {
+                write!(f, "유효하지 않은 블라인드: {}/{}", small_blind, big_blind)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PushFoldError {}
+
+/// 뒤 플레이어 한 명이 콜했을 때의 결과별 보상
+struct Showdown {
+    seat: usize,
+    /// 히어로가 이겼을 때 [히어로, 콜러]
+    hero_wins: [f64; 2],
+    /// 콜러가 이겼을 때 [히어로, 콜러]
+    caller_wins: [f64; 2],
+    /// 모두 폴드했을 때 콜러의 보상
+    caller_folds: f64,
+}
+
+/// 히어로가 첫 액션일 때의 ICM(또는 칩 EV) 푸시/폴드 차트
+///
+/// 모듈 설명의 근사를 씁니다. 스택, 상금, 블라인드, 좌석이 올바르지 않으면 에러를 반환합니다.
+pub fn push_fold_chart(config: PushFoldConfig) -> Result<PushFoldChart, PushFoldError> {
+    let players = config.stacks.len();
+    if !(2..=MAX_PUSH_FOLD_PLAYERS).contains(&players) {
+        return Err(PushFoldError::PlayerCount(players));
+    }
+    for seat in [config.button, config.hero] {
+        if seat >= players {
+            return Err(PushFoldError::InvalidSeat { seat, players });
+        }
+    }
+    if let Some(seat) = config.stacks.iter().position(|&stack| stack == 0) {
+        return Err(PushFoldError::EmptyStack(seat));
+    }
+    if config.payouts.is_empty() {
+        return Err(PushFoldError::NoPayouts);
+    }
+    if config.big_blind == 0 || config.small_blind > config.big_blind {
+        return Err(PushFoldError::InvalidBlinds { small_blind: config.small_blind, big_blind: config.big_blind });
+    }
+
+    let (small_blind_seat, big_blind_seat) = if players == 2 {
+        (config.button, (config.button + 1) % players)
+    } else {
+        ((config.button + 1) % players, (config.button + 2) % players)
+    };
+    if config.hero == big_blind_seat {
+        return Err(PushFoldError::HeroIsBigBlind);
+    }
+
+    // 블라인드와 앤티 (스택이 모자라면 있는 만큼)
+    let stacks = &config.stacks;
+    let posted: Vec<u32> = (0..players)
+        .map(|seat| {
+            let blind = match seat {
+                s if s == small_blind_seat => config.small_blind,
+                s if s == big_blind_seat => config.big_blind,
+                _ => 0,
+            };
+            let ante = config.ante.min(stacks[seat]);
+            ante + blind.min(stacks[seat] - ante)
+        })
+        .collect();
+    let pot: u32 = posted.iter().sum();
+    let after_posting: Vec<u32> = (0..players).map(|seat| stacks[seat] - posted[seat]).collect();
+
+    let payoff = |finals: &[u32]| -> Vec<f64> {
+        match config.payoff {
+            PushFoldPayoff::Icm => icm_equities(finals, &config.payouts),
+            PushFoldPayoff::Chips => finals.iter().map(|&chips| chips as f64).collect(),
+        }
+    };
+    let hero = config.hero;
+
+    // 히어로 폴드: 빅 블라인드가 팟을 가져감
+    let mut finals = after_posting.clone();
+    finals[big_blind_seat] += pot;
+    let hero_folds = payoff(&finals)[hero];
+
+    // 모두 폴드: 히어로가 팟을 가져감
+    let mut finals = after_posting.clone();
+    finals[hero] += pot;
+    let all_fold = payoff(&finals);
+
+    let behind: Vec<usize> = (1..players)
+        .map(|offset| (hero + offset) % players)
+        .take_while(|&seat| seat != (big_blind_seat + 1) % players)
+        .collect();
+    let showdowns: Vec<Showdown> = behind
+        .iter()
+        .map(|&seat| {
+            let effective = stacks[hero].min(stacks[seat]);
+            let dead = pot - posted[hero] - posted[seat];
+            let mut finals = after_posting.clone();
+            finals[hero] = stacks[hero] + effective + dead;
+            finals[seat] = stacks[seat] - effective;
+            let hero_wins = payoff(&finals);
+            let mut finals = after_posting.clone();
+            finals[hero] = stacks[hero] - effective;
+            finals[seat] = stacks[seat] + effective + dead;
+            let caller_wins = payoff(&finals);
+            Showdown {
+                seat,
+                hero_wins: [hero_wins[hero], hero_wins[seat]],
+                caller_wins: [caller_wins[hero], caller_wins[seat]],
+                caller_folds: all_fold[seat],
+            }
+        })
+        .collect();
+
+    let equity: Vec<f64> = (0..169u8)
+        .flat_map(|a| (0..169u8).map(move |b| (a, b)))
+        .map(|(a, b)| preflop_class_equity(HandClass::from_index(a).unwrap(), HandClass::from_index(b).unwrap()))
+        .collect();
+    let combos: Vec<f64> = HandClass::all().iter().map(|class| class.combo_count() as f64).collect();
+
+    // 가상 플레이: 상대의 평균 전략에 대한 최선 응답을 평균
+    let mut shove = vec![1.0; 169];
+    let mut calls = vec![vec![0.0; 169]; showdowns.len()];
+    for iteration in 1..=config.iterations.max(1) {
+        let step = 1.0 / (iteration as f64 + 1.0);
+
+        // 히어로 레인지 상대 에퀴티는 모든 콜러에게 같음 (카드 제거 무시)
+        let versus_shove: Vec<f64> = (0..169).map(|class| range_equity(&equity, class, &shove, &combos)).collect();
+        let call_responses: Vec<Vec<f64>> = showdowns
+            .iter()
+            .map(|showdown| {
+                versus_shove
+                    .iter()
+                    .map(|&win| {
+                        let call = win * showdown.caller_wins[1] + (1.0 - win) * showdown.hero_wins[1];
+                        (call > showdown.caller_folds) as u8 as f64
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let call_rates: Vec<f64> = calls.iter().map(|call| combo_fraction(call)).collect();
+        let shove_response: Vec<f64> = (0..169)
+            .map(|class| {
+                let mut reach = 1.0;
+                let mut value = 0.0;
+                for ((showdown, call), &call_rate) in showdowns.iter().zip(&calls).zip(&call_rates) {
+                    if call_rate > 0.0 {
+                        let win = range_equity(&equity, class, call, &combos);
+                        let called = win * showdown.hero_wins[0] + (1.0 - win) * showdown.caller_wins[0];
+                        value += reach * call_rate * called;
+                    }
+                    reach *= 1.0 - call_rate;
+                }
+                value += reach * all_fold[hero];
+                (value > hero_folds) as u8 as f64
+            })
+            .collect();
+
+        for (average, response) in calls.iter_mut().zip(&call_responses) {
+            for (frequency, best) in average.iter_mut().zip(response) {
+                *frequency += (best - *frequency) * step;
+            }
+        }
+        for (frequency, best) in shove.iter_mut().zip(&shove_response) {
+            *frequency += (best - *frequency) * step;
+        }
+    }
+
+    let callers = showdowns
+        .iter()
+        .zip(calls)
+        .map(|(showdown, call)| {
+            let gain = showdown.caller_wins[1] - showdown.hero_wins[1];
+            let break_even_equity = if gain > 0.0 {
+                ((showdown.caller_folds - showdown.hero_wins[1]) / gain).clamp(0.0, 1.0)
+            } else {
+                1.0
+            };
+            CallingRange { seat: showdown.seat, break_even_equity, call }
+        })
+        .collect();
+
+    Ok(PushFoldChart { hero, payoff: config.payoff, shove, callers })
+}
+
+/// `class`가 `frequencies` 레인지(조합 수 가중)를 상대로 가진 에퀴티 (레인지가 비면 0.5)
+fn range_equity(equity: &[f64], class: usize, frequencies: &[f64], combos: &[f64]) -> f64 {
+    let row = &equity[class * 169..(class + 1) * 169];
+    let (mut total, mut weight) = (0.0, 0.0);
+    for ((&eq, &frequency), &count) in row.iter().zip(frequencies).zip(combos) {
+        total += eq * frequency * count;
+        weight += frequency * count;
+    }
+    if weight > 0.0 {
+        total / weight
+    } else {
+        0.5
+    }
+}
+
+/// 클래스별 빈도를 조합 수로 가중한 비율
+fn combo_fraction(frequencies: &[f64]) -> f64 {
+    HandClass::all()
+        .iter()
+        .zip(frequencies)
+        .map(|(class, frequency)| class.combo_count() as f64 * frequency)
+        .sum::<f64>()
+        / 1326.0
+}
+
+/// `HandClass::to_index` 순서의 169개 값을 13x13 배치로
+fn to_grid(frequencies: &[f64]) -> [[f64; 13]; 13] {
+    let mut grid = [[0.0; 13]; 13];
+    for (index, &frequency) in frequencies.iter().enumerate().take(169) {
+        grid[index / 13][index % 13] = frequency;
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bubble_config(payoff: PushFoldPayoff) -> PushFoldConfig {
+        // 4명 남은 버블 (3등까지 상금), 빅 블라인드(좌석 2)가 압도적인 칩 리더
+        PushFoldConfig {
+            stacks: vec![2_000, 2_000, 20_000, 2_000],
+            payouts: vec![500, 300, 200],
+            small_blind: 100,
+            big_blind: 200,
+            ante: 25,
+            button: 0,
+            hero: 1,
+            payoff,
+            iterations: DEFAULT_PUSH_FOLD_ITERATIONS,
+        }
+    }
+
+    #[test]
+    fn test_bubble_icm_tightens_small_blind_shoves() {
+        let icm = push_fold_chart(bubble_config(PushFoldPayoff::Icm)).unwrap();
+        let chips = push_fold_chart(bubble_config(PushFoldPayoff::Chips)).unwrap();
+
+        for chart in [&icm, &chips] {
+            assert_eq!(chart.shove.len(), 169);
+            assert_eq!(chart.callers.len(), 1);
+            assert_eq!(chart.callers[0].seat, 2);
+            let frequencies = chart.shove.iter().chain(chart.callers.iter().flat_map(|caller| &caller.call));
+            assert!(frequencies.into_iter().all(|f| (0.0..=1.0).contains(f)));
+            assert_eq!(chart.shove_frequency(HandClass::Pair(12)), 1.0);
+        }
+        assert!(
+            icm.shove_fraction() < chips.shove_fraction(),
+            "ICM {} vs 칩 EV {}",
+            icm.shove_fraction(),
+            chips.shove_fraction()
+        );
+
+        let grid = icm.to_grid();
+        assert_eq!(grid[0][0], icm.shove_frequency(HandClass::Pair(12)));
+        assert_eq!(grid[1][0], icm.shove_frequency(HandClass::Offsuit(12, 11)));
+    }
+
+    #[test]
+    fn test_nine_handed_chart_and_invalid_config() {
+        let config = PushFoldConfig {
+            stacks: vec![3_000; 9],
+            payouts: vec![400, 250, 150, 100],
+            small_blind: 150,
+            big_blind: 300,
+            ante: 30,
+            button: 8,
+            hero: 2,
+            payoff: PushFoldPayoff::Icm,
+            iterations: DEFAULT_PUSH_FOLD_ITERATIONS,
+        };
+        let chart = push_fold_chart(config.clone()).unwrap();
+        // UTG(좌석 2) 뒤로 빅 블라인드(좌석 1)까지 8명
+        assert_eq!(chart.callers.iter().map(|caller| caller.seat).collect::<Vec<_>>(), vec![3, 4, 5, 6, 7, 8, 0, 1]);
+        assert!(chart.shove_fraction() > 0.0 && chart.shove_fraction() < 0.5);
+
+        let big_blind = PushFoldConfig { hero: 1, ..config.clone() };
+        assert_eq!(push_fold_chart(big_blind), Err(PushFoldError::HeroIsBigBlind));
+        let empty = PushFoldConfig { stacks: vec![0, 3_000], button: 0, hero: 0, ..config };
+        assert_eq!(push_fold_chart(empty), Err(PushFoldError::EmptyStack(0)));
+    }
+}
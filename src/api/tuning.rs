@@ -0,0 +1,664 @@
+//! QuickPokerAPI 휴리스틱 파라미터 튜닝
+//!
+//! 휴리스틱 엔진의 임계값과 빈도를 파라미터 벡터(`HeuristicParams`)로 다루고,
+//! 생성된 스팟 모음의 EV 기준값에 대한 평균 후회(regret)를 줄이는 방향으로
+//! 시드 고정 힐 클라이밍을 수행합니다.
+//!
+//! EV 기준값은 각 스팟마다 고정 시드 몬테카를로 쇼다운으로 미리 기록합니다.
+//! 상대는 랜덤 레인지에서 시작해 베팅을 받으면 최소 방어 빈도(MDF)만큼
+//! 현재 핸드 강도 상위 핸드로 방어한다고 가정합니다.
+//!
+//! 찾은 파라미터는 학습된 CFR 정책과의 헤즈업 대결(`arena_bb_per_100`)로 따로 검증합니다.
+
+use crate::api::web_api_simple::{QuickPokerAPI, WebGameState};
+use crate::game::card_abstraction::hand_strength;
+use crate::game::hand_eval::v7;
+use crate::game::holdem::{Act, State};
+use crate::game::observation::{play_hand, ActionPolicy, ObservedState, TrainerPolicy};
+use crate::game::tournament::AnteType;
+use crate::solver::cfr_core::Trainer;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// 스팟당 몬테카를로 쇼다운 샘플 수
+pub const SHOWDOWN_SAMPLES: usize = 300;
+
+/// 이웃한 핸드 강도 구간 하한 사이의 최소 간격
+///
+/// 간격이 없으면 두 하한이 겹쳐 가운데 구간의 전략이 절대 쓰이지 않습니다.
+pub const MIN_TIER_GAP: f64 = 0.05;
+
+/// QuickPokerAPI 휴리스틱 파라미터
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeuristicParams {
+    /// 프리미엄 핸드 하한 (체크/베트)
+    pub premium_cutoff: f64,
+    /// 강한 핸드 하한 (체크/베트)
+    pub strong_cutoff: f64,
+    /// 중간 핸드 하한 (체크/베트)
+    pub medium_cutoff: f64,
+    /// 블러프 후보 하한 (체크/베트)
+    pub bluff_cutoff: f64,
+    /// 딥 스택(SPR > 8) 블러프 빈도
+    pub bluff_freq_deep: f64,
+    /// 숏 스택 블러프 빈도
+    pub bluff_freq_shallow: f64,
+    /// 넛 핸드 하한 (콜/폴드/레이즈)
+    pub nut_threshold: f64,
+    /// 레이즈 핸드 하한 (콜/폴드/레이즈)
+    pub raise_threshold: f64,
+    /// 콜에 필요한 팟 오즈 대비 여유
+    pub call_margin: f64,
+    /// 큰 베트를 상대로 한 레이즈 빈도
+    pub raise_freq_large_bet: f64,
+    /// 작은 베트를 상대로 한 레이즈 빈도
+    pub raise_freq_small_bet: f64,
+    /// 약한 핸드의 블러프 레이즈 빈도
+    pub bluff_raise_freq: f64,
+}
+
+impl HeuristicParams {
+    /// 파라미터 벡터 길이
+    pub const LEN: usize = 12;
+
+    /// 기존 수작업 튜닝 값
+    pub fn classic() -> Self {
+        Self {
+            premium_cutoff: 0.85,
+            strong_cutoff: 0.7,
+            medium_cutoff: 0.55,
+            bluff_cutoff: 0.3,
+            bluff_freq_deep: 0.15,
+            bluff_freq_shallow: 0.25,
+            nut_threshold: 0.9,
+            raise_threshold: 0.7,
+            call_margin: 0.05,
+            raise_freq_large_bet: 0.4,
+            raise_freq_small_bet: 0.6,
+            bluff_raise_freq: 0.1,
+        }
+    }
+
+    /// `examples/tune_heuristics.rs`로 찾은 값 (소수 둘째 자리 반올림)
+    ///
+    /// 포스트플랍 강도를 `hand_percentile`로 바꾼 뒤 다시 맞춘 값입니다.
+    /// 결과를 바꾸지 못한 파라미터는 `classic()` 값으로 되돌렸습니다.
+    /// 검증 스팟 모음에서 평균 후회가 `classic()` 대비 절반 가까이 줄고,
+    /// 학습된 CFR 정책과의 헤즈업 대결에서도 `classic()`보다 많이 이깁니다.
+    pub fn tuned() -> Self {
+        Self {
+            premium_cutoff: 0.85,
+            strong_cutoff: 0.6,
+            medium_cutoff: 0.55,
+            bluff_cutoff: 0.3,
+            bluff_freq_deep: 0.15,
+            bluff_freq_shallow: 0.25,
+            nut_threshold: 0.9,
+            raise_threshold: 0.44,
+            call_margin: 0.05,
+            raise_freq_large_bet: 0.4,
+            raise_freq_small_bet: 0.84,
+            bluff_raise_freq: 0.66,
+        }
+    }
+
+    /// 파라미터 벡터로 변환
+    pub fn to_vec(&self) -> Vec<f64> {
+        vec![
+            self.premium_cutoff,
+            self.strong_cutoff,
+            self.medium_cutoff,
+            self.bluff_cutoff,
+            self.bluff_freq_deep,
+            self.bluff_freq_shallow,
+            self.nut_threshold,
+            self.raise_threshold,
+            self.call_margin,
+            self.raise_freq_large_bet,
+            self.raise_freq_small_bet,
+            self.bluff_raise_freq,
+        ]
+    }
+
+    /// 파라미터 벡터에서 생성 (범위를 벗어난 값은 보정)
+    ///
+    /// 길이가 `LEN`보다 짧으면 부족한 항목은 `classic()` 값을 사용합니다.
+    pub fn from_vec(values: &[f64]) -> Self {
+        let mut merged = Self::classic().to_vec();
+        for (slot, &value) in merged.iter_mut().zip(values) {
+            if value.is_finite() {
+                *slot = value;
+            }
+        }
+
+        let mut params = Self {
+            premium_cutoff: merged[0],
+            strong_cutoff: merged[1],
+            medium_cutoff: merged[2],
+            bluff_cutoff: merged[3],
+            bluff_freq_deep: merged[4],
+            bluff_freq_shallow: merged[5],
+            nut_threshold: merged[6],
+            raise_threshold: merged[7],
+            call_margin: merged[8],
+            raise_freq_large_bet: merged[9],
+            raise_freq_small_bet: merged[10],
+            bluff_raise_freq: merged[11],
+        };
+        params.clamp();
+        params
+    }
+
+    /// 전략 확률이 유효하도록 범위와 순서 보정
+    ///
+    /// 핸드 강도 구간 하한은 위에서부터 `MIN_TIER_GAP` 이상씩 떨어지도록 맞춥니다.
+    fn clamp(&mut self) {
+        self.premium_cutoff = self.premium_cutoff.clamp(3.0 * MIN_TIER_GAP, 1.0);
+        self.strong_cutoff = self.strong_cutoff.clamp(2.0 * MIN_TIER_GAP, self.premium_cutoff - MIN_TIER_GAP);
+        self.medium_cutoff = self.medium_cutoff.clamp(MIN_TIER_GAP, self.strong_cutoff - MIN_TIER_GAP);
+        self.bluff_cutoff = self.bluff_cutoff.clamp(0.0, self.medium_cutoff - MIN_TIER_GAP);
+        self.bluff_freq_deep = self.bluff_freq_deep.clamp(0.0, 1.0);
+        self.bluff_freq_shallow = self.bluff_freq_shallow.clamp(0.0, 1.0);
+        self.raise_threshold = self.raise_threshold.clamp(0.0, 1.0 - MIN_TIER_GAP);
+        self.nut_threshold = self.nut_threshold.clamp(self.raise_threshold + MIN_TIER_GAP, 1.0);
+        self.call_margin = self.call_margin.clamp(-0.2, 0.3);
+        self.raise_freq_large_bet = self.raise_freq_large_bet.clamp(0.0, 0.95);
+        self.raise_freq_small_bet = self.raise_freq_small_bet.clamp(0.0, 0.95);
+        self.bluff_raise_freq = self.bluff_raise_freq.clamp(0.0, 0.9);
+    }
+}
+
+impl Default for HeuristicParams {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+/// EV 기준값이 기록된 튜닝 스팟
+#[derive(Debug, Clone)]
+pub struct TuningSpot {
+    /// 히어로 관점 게임 상태
+    pub state: WebGameState,
+    /// (상대 현재 핸드 강도, 히어로 쇼다운 지분) - 상대 강도 내림차순
+    showdowns: Vec<(f64, f64)>,
+}
+
+impl TuningSpot {
+    /// 고정 시드 쇼다운 샘플로 스팟 생성
+    pub fn new(state: WebGameState, rng: &mut StdRng) -> Self {
+        let mut deck: Vec<u8> = (0..52)
+            .filter(|card| !state.hole_cards.contains(card) && !state.board.contains(card))
+            .collect();
+
+        let mut showdowns = Vec::with_capacity(SHOWDOWN_SAMPLES);
+        for _ in 0..SHOWDOWN_SAMPLES {
+            deck.shuffle(rng);
+            let villain = [deck[0], deck[1]];
+            let mut board = state.board.clone();
+            board.extend_from_slice(&deck[2..2 + (5 - state.board.len())]);
+
+            let mut hero_cards = [0u8; 7];
+            let mut villain_cards = [0u8; 7];
+            hero_cards[..2].copy_from_slice(&state.hole_cards);
+            villain_cards[..2].copy_from_slice(&villain);
+            hero_cards[2..].copy_from_slice(&board);
+            villain_cards[2..].copy_from_slice(&board);
+
+            // 랭크는 낮을수록 강함
+            let share = match v7(hero_cards).cmp(&v7(villain_cards)) {
+                std::cmp::Ordering::Less => 1.0,
+                std::cmp::Ordering::Equal => 0.5,
+                std::cmp::Ordering::Greater => 0.0,
+            };
+            showdowns.push((hand_strength(villain, &state.board), share));
+        }
+        // 안정 정렬이므로 동일 강도는 무작위 샘플 순서로 남음
+        showdowns.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        Self { state, showdowns }
+    }
+
+    /// 상대 레인지 상위 `fraction`에 대한 히어로 에퀴티
+    fn equity_vs_top(&self, fraction: f64) -> f64 {
+        let count = ((self.showdowns.len() as f64 * fraction).ceil() as usize)
+            .clamp(1, self.showdowns.len());
+        self.showdowns[..count].iter().map(|&(_, share)| share).sum::<f64>() / count as f64
+    }
+
+    /// 상대가 MDF만큼 방어할 때 `risk`를 걸어 `call_amount`를 받는 액션의 EV
+    fn aggressive_ev(&self, risk: f64, call_amount: f64) -> f64 {
+        let pot = self.state.pot as f64;
+        // 히어로 블러프가 손익분기가 되는 최소 방어 빈도
+        let defend = pot / (pot + risk);
+        let final_pot = pot + risk + call_amount;
+        (1.0 - defend) * pot + defend * (self.equity_vs_top(defend) * final_pot - risk)
+    }
+
+    /// 전략 키에 해당하는 액션의 EV (칩 단위, 폴드 = 0)
+    pub fn action_ev(&self, action: &str) -> f64 {
+        let state = &self.state;
+        let pot = state.pot as f64;
+        let to_call = state.to_call.min(state.my_stack) as f64;
+        let effective = state.my_stack.min(state.opponent_stack) as f64;
+
+        match action {
+            "fold" => 0.0,
+            "check" => self.equity_vs_top(1.0) * pot,
+            "call" => self.equity_vs_top(1.0) * (pot + to_call) - to_call,
+            "raise" => {
+                // 팟 사이즈 레이즈 (상대 추가 콜 금액은 유효 스택으로 제한)
+                let risk = (pot + 2.0 * to_call).min(state.my_stack as f64).max(to_call);
+                let call_amount = (risk - to_call).min(state.opponent_stack as f64);
+                self.aggressive_ev(risk, call_amount)
+            }
+            _ => {
+                let spr = if pot > 0.0 { effective / pot } else { 0.0 };
+                match QuickPokerAPI::bet_fraction_from_key(action, spr) {
+                    Some(fraction) => {
+                        let bet = (fraction * pot).min(effective);
+                        self.aggressive_ev(bet, bet)
+                    }
+                    None => 0.0,
+                }
+            }
+        }
+    }
+
+    /// 전략의 팟 대비 후회: (최선 액션 EV - 전략 EV) / 팟
+    pub fn regret(&self, strategy: &crate::api::web_api::StrategyMap) -> f64 {
+        let mut candidates: Vec<&str> = strategy.keys().map(String::as_str).collect();
+        candidates.extend(if self.state.to_call == 0 {
+            ["check", "bet_allin"].as_slice()
+        } else {
+            ["fold", "call", "raise"].as_slice()
+        });
+
+        let best = candidates
+            .iter()
+            .map(|action| self.action_ev(action))
+            .fold(f64::NEG_INFINITY, f64::max);
+        let achieved: f64 = strategy
+            .iter()
+            .map(|(action, &prob)| prob * self.action_ev(action))
+            .sum();
+
+        (best - achieved).max(0.0) / (self.state.pot.max(1) as f64)
+    }
+}
+
+/// 고정 시드로 헤즈업 스팟 모음 생성
+pub fn generate_spots(count: usize, seed: u64) -> Vec<TuningSpot> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let bet_fractions = [0.33, 0.5, 0.75, 1.0];
+
+    (0..count)
+        .map(|_| {
+            let mut deck: Vec<u8> = (0..52).collect();
+            deck.shuffle(&mut rng);
+
+            let street = rng.gen_range(0..4u8);
+            let board_len = match street {
+                0 => 0,
+                1 => 3,
+                2 => 4,
+                _ => 5,
+            };
+            let pot = rng.gen_range(40..=400u32);
+            let to_call = if rng.gen_bool(0.5) {
+                0
+            } else {
+                let fraction = bet_fractions[rng.gen_range(0..bet_fractions.len())];
+                ((pot as f64 * fraction / (1.0 + fraction)) as u32).max(1)
+            };
+
+            let state = WebGameState {
+                hole_cards: [deck[0], deck[1]],
+                board: deck[2..2 + board_len].to_vec(),
+                street,
+                pot,
+                to_call,
+                my_stack: rng.gen_range(200..=2000),
+                opponent_stack: rng.gen_range(200..=2000),
+                num_opponents: 1,
+                hero_position: 0,
+                players_behind: 0,
+                opponent_stacks: vec![],
+                dead_money: 0,
+            };
+            TuningSpot::new(state, &mut rng)
+        })
+        .collect()
+}
+
+/// 스팟 모음에 대한 평균 후회 (낮을수록 좋음)
+pub fn evaluate(params: &HeuristicParams, spots: &[TuningSpot]) -> f64 {
+    if spots.is_empty() {
+        return 0.0;
+    }
+    let api = QuickPokerAPI::with_params(params.clone());
+    spots
+        .iter()
+        .map(|spot| spot.regret(&api.get_optimal_strategy(spot.state.clone()).strategy))
+        .sum::<f64>()
+        / spots.len() as f64
+}
+
+/// 힐 클라이밍 설정
+#[derive(Debug, Clone)]
+pub struct TuningConfig {
+    /// 반복 횟수
+    pub iterations: usize,
+    /// 초기 변이 크기
+    pub initial_step: f64,
+    /// 반복마다 곱해지는 변이 감쇠율
+    pub step_decay: f64,
+    /// 난수 시드
+    pub seed: u64,
+    /// 시작값으로 되돌려도 목적 함수가 이 비율 이하로만 나빠지는 파라미터는 되돌림
+    ///
+    /// 목적 함수가 거의 반응하지 않는 파라미터는 다른 변이에 섞여 범위 끝까지 떠밀려 가므로,
+    /// 근거 없이 바뀐 값을 기본값으로 내보내지 않도록 정리합니다.
+    pub prune_tolerance: f64,
+}
+
+impl Default for TuningConfig {
+    fn default() -> Self {
+        Self {
+            iterations: 300,
+            initial_step: 0.1,
+            step_decay: 0.99,
+            seed: 42,
+            prune_tolerance: 0.005,
+        }
+    }
+}
+
+/// 튜닝 결과
+#[derive(Debug, Clone)]
+pub struct TuningResult {
+    /// 찾은 최선의 파라미터
+    pub params: HeuristicParams,
+    /// 최선 파라미터의 목적 함수 값
+    pub objective: f64,
+    /// 반복별 최선 목적 함수 값 (시작 값 포함)
+    pub history: Vec<f64>,
+}
+
+/// 시드 고정 힐 클라이밍으로 평균 후회를 최소화
+///
+/// 매 반복마다 임의의 파라미터 부분집합을 변이시키고, 개선될 때만 채택합니다.
+/// 끝나면 목적 함수에 거의 기여하지 않는 파라미터를 시작값으로 되돌립니다
+/// (`TuningConfig::prune_tolerance`, `history`에는 포함하지 않음).
+pub fn optimize(start: &HeuristicParams, spots: &[TuningSpot], config: &TuningConfig) -> TuningResult {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut best = start.clone();
+    let mut best_objective = evaluate(&best, spots);
+    let mut history = vec![best_objective];
+    let mut step = config.initial_step;
+
+    for _ in 0..config.iterations {
+        let mut candidate = best.to_vec();
+        let mutate_count = rng.gen_range(1..=3);
+        for _ in 0..mutate_count {
+            let index = rng.gen_range(0..HeuristicParams::LEN);
+            candidate[index] += rng.gen_range(-step..=step);
+        }
+
+        let candidate = HeuristicParams::from_vec(&candidate);
+        let objective = evaluate(&candidate, spots);
+        if objective < best_objective {
+            best = candidate;
+            best_objective = objective;
+        }
+        history.push(best_objective);
+        step *= config.step_decay;
+    }
+
+    let tolerance = best_objective * (1.0 + config.prune_tolerance.max(0.0));
+    for (index, &start_value) in start.to_vec().iter().enumerate() {
+        let mut pruned = best.to_vec();
+        if pruned[index] == start_value {
+            continue;
+        }
+        pruned[index] = start_value;
+        let pruned = HeuristicParams::from_vec(&pruned);
+        let objective = evaluate(&pruned, spots);
+        if objective <= tolerance {
+            best = pruned;
+            best_objective = objective;
+        }
+    }
+
+    TuningResult {
+        params: best,
+        objective: best_objective,
+        history,
+    }
+}
+
+/// QuickPokerAPI 휴리스틱을 따르는 대결용 정책
+///
+/// 엔진 액션은 폴드/콜(체크)/팟 레이즈뿐이므로 휴리스틱의 베트와 레이즈 크기는 모두 `Raise(0)`으로 모읍니다.
+pub struct HeuristicPolicy<R: Rng> {
+    api: QuickPokerAPI,
+    rng: R,
+}
+
+impl<R: Rng> HeuristicPolicy<R> {
+    pub fn new(params: HeuristicParams, rng: R) -> Self {
+        Self {
+            api: QuickPokerAPI::with_params(params),
+            rng,
+        }
+    }
+
+    /// 관찰자 관점 상태를 휴리스틱 API 요청으로 변환
+    fn web_state(observed: &ObservedState) -> WebGameState {
+        let hero = observed.player;
+        let state = observed.to_state();
+        let opponent_stacks: Vec<u32> = (0..6)
+            .filter(|&seat| seat != hero && observed.alive[seat])
+            .map(|seat| observed.stack[seat])
+            .collect();
+        WebGameState {
+            hole_cards: observed.hole,
+            board: observed.board.clone(),
+            street: observed.street,
+            pot: observed.pot,
+            to_call: observed.to_call.saturating_sub(observed.invested[hero]),
+            my_stack: observed.stack[hero],
+            opponent_stack: opponent_stacks.iter().copied().max().unwrap_or(0),
+            num_opponents: opponent_stacks.len().max(1) as u8,
+            hero_position: match state.seat_label(hero) {
+                Some("BTN") => 0,
+                Some("SB") => 1,
+                Some("BB") => 2,
+                _ => 3,
+            },
+            players_behind: state.players_behind(hero) as u8,
+            opponent_stacks,
+            dead_money: 0,
+        }
+    }
+}
+
+impl<R: Rng> ActionPolicy for HeuristicPolicy<R> {
+    fn choose_action(&mut self, observed: &ObservedState, legal_actions: &[Act]) -> Act {
+        let strategy = self.api.get_optimal_strategy(Self::web_state(observed)).strategy;
+        let mut weights = [0.0; 3];
+        for (action, &prob) in &strategy {
+            let slot = match action.as_str() {
+                "fold" => 0,
+                "call" | "check" => 1,
+                _ => 2,
+            };
+            weights[slot] += prob;
+        }
+
+        let legal = |action: Act| legal_actions.contains(&action);
+        if !legal(Act::Raise(0)) {
+            weights[1] += weights[2];
+            weights[2] = 0.0;
+        }
+        if !legal(Act::Call) {
+            weights[0] += weights[1];
+            weights[1] = 0.0;
+        }
+        let total: f64 = weights.iter().sum();
+        let mut roll = self.rng.gen::<f64>() * total;
+        for (action, weight) in [Act::Fold, Act::Call, Act::Raise(0)].into_iter().zip(weights) {
+            if weight > 0.0 && roll < weight {
+                return action;
+            }
+            roll -= weight;
+        }
+        if legal(Act::Call) {
+            Act::Call
+        } else {
+            Act::Fold
+        }
+    }
+}
+
+/// 학습된 정책과의 헤즈업 대결 설정
+#[derive(Debug, Clone)]
+pub struct ArenaConfig {
+    /// 대결 핸드 수 (핸드마다 자리를 바꿈)
+    pub hands: usize,
+    /// 빅블라인드 (스몰블라인드는 절반)
+    pub big_blind: u32,
+    /// 시작 스택 (빅블라인드 단위)
+    pub stack_bb: u32,
+    /// 딜링과 두 정책의 난수 시드
+    pub seed: u64,
+}
+
+impl Default for ArenaConfig {
+    fn default() -> Self {
+        Self {
+            hands: 10_000,
+            big_blind: 100,
+            stack_bb: 20,
+            seed: 7,
+        }
+    }
+}
+
+impl ArenaConfig {
+    /// 대결 핸드 하나의 헤즈업 루트 (홀카드는 `rng`로 딜링)
+    fn root(&self, rng: &mut StdRng) -> State {
+        let mut stacks = [0; 6];
+        stacks[..2].fill(self.big_blind * self.stack_bb);
+        State::new_hand_with_rng([self.big_blind / 2, self.big_blind], 0, AnteType::PerPlayer, stacks, 2, rng)
+    }
+
+    /// 대결 설정과 같은 헤즈업 루트로 상대 CFR 정책 학습
+    pub fn train_opponent(&self, deals: usize, iterations: usize) -> Trainer<State> {
+        let mut rng = StdRng::seed_from_u64(self.seed ^ 0x7124_1e5d);
+        let roots: Vec<State> = (0..deals).map(|_| self.root(&mut rng)).collect();
+        let mut trainer = Trainer::new();
+        trainer.set_seed(self.seed);
+        trainer.run(roots, iterations);
+        trainer
+    }
+}
+
+/// 휴리스틱이 학습된 CFR 정책을 상대로 얻은 100핸드당 빅블라인드 (높을수록 좋음)
+///
+/// 두 정책이 핸드마다 버튼을 번갈아 맡고 같은 시드의 딜링을 씁니다.
+pub fn arena_bb_per_100(params: &HeuristicParams, opponent: &Trainer<State>, config: &ArenaConfig) -> f64 {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut heuristic = HeuristicPolicy::new(params.clone(), StdRng::seed_from_u64(config.seed.wrapping_add(1)));
+    let mut trained = TrainerPolicy::new(opponent, StdRng::seed_from_u64(config.seed.wrapping_add(2)));
+
+    let mut won = 0.0;
+    for hand in 0..config.hands {
+        let root = config.root(&mut rng);
+        let hero = hand % 2;
+        let result = if hero == 0 {
+            play_hand(&root, &mut [&mut heuristic, &mut trained], &mut rng)
+        } else {
+            play_hand(&root, &mut [&mut trained, &mut heuristic], &mut rng)
+        };
+        won += result[hero];
+    }
+    won / config.big_blind as f64 / config.hands.max(1) as f64 * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params_vector_round_trip() {
+        let classic = HeuristicParams::classic();
+        assert_eq!(classic.to_vec().len(), HeuristicParams::LEN);
+        assert_eq!(HeuristicParams::from_vec(&classic.to_vec()), classic);
+
+        // 순서가 뒤집힌 임계값과 범위 밖 빈도는 보정됨
+        let mut values = classic.to_vec();
+        values[1] = 0.95;
+        values[11] = 3.0;
+        let fixed = HeuristicParams::from_vec(&values);
+        assert!(fixed.strong_cutoff <= fixed.premium_cutoff);
+        assert!(fixed.bluff_raise_freq <= 0.9);
+
+        // 티어 임계값은 최소 간격을 유지해 중간 티어가 사라지지 않음
+        let collapsed = HeuristicParams::from_vec(&[0.5; HeuristicParams::LEN]);
+        for params in [collapsed, HeuristicParams::tuned()] {
+            assert!(params.premium_cutoff - params.strong_cutoff >= MIN_TIER_GAP - 1e-9);
+            assert!(params.strong_cutoff - params.medium_cutoff >= MIN_TIER_GAP - 1e-9);
+            assert!(params.medium_cutoff - params.bluff_cutoff >= MIN_TIER_GAP - 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_spot_evs_are_consistent() {
+        let spots = generate_spots(20, 7);
+        for spot in &spots {
+            assert_eq!(spot.action_ev("fold"), 0.0);
+            let strategy = QuickPokerAPI::new()
+                .get_optimal_strategy(spot.state.clone())
+                .strategy;
+            let regret = spot.regret(&strategy);
+            assert!(regret.is_finite() && regret >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_optimizer_improves_objective() {
+        let spots = generate_spots(60, 1);
+        let config = TuningConfig {
+            iterations: 40,
+            ..TuningConfig::default()
+        };
+        let result = optimize(&HeuristicParams::classic(), &spots, &config);
+
+        assert_eq!(result.history.len(), config.iterations + 1);
+        assert!(result.history.windows(2).all(|w| w[1] <= w[0]));
+        assert!(result.objective < result.history[0]);
+        assert_eq!(result.objective, evaluate(&result.params, &spots));
+    }
+
+    #[test]
+    fn test_tuned_beats_classic_on_held_out_spots() {
+        // 튜닝에 사용하지 않은 시드의 스팟 모음
+        let spots = generate_spots(400, 0xbeef);
+        let classic = evaluate(&HeuristicParams::classic(), &spots);
+        let tuned = evaluate(&HeuristicParams::tuned(), &spots);
+        assert!(tuned < classic * 0.75, "tuned={} classic={}", tuned, classic);
+    }
+
+    #[test]
+    fn test_tuned_beats_classic_against_trained_policy() {
+        let config = ArenaConfig {
+            hands: 2_000,
+            ..ArenaConfig::default()
+        };
+        let opponent = config.train_opponent(20, 5);
+        let classic = arena_bb_per_100(&HeuristicParams::classic(), &opponent, &config);
+        let tuned = arena_bb_per_100(&HeuristicParams::tuned(), &opponent, &config);
+        assert!(tuned > classic, "tuned={} classic={}", tuned, classic);
+    }
+}
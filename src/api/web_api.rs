@@ -1,13 +1,23 @@
 // 포커 전략 평가를 위한 웹 API - 무상태 방식
 // 각 요청마다 현재 게임 상태를 제공하면 최적 전략을 반환합니다
 
+use crate::game::flop_subset::{FlopSampler, FlopSubsetConfig, FLOP_SUBSET_METADATA_KEY};
 use crate::game::holdem;
+use crate::game::observation::ObservedState;
+use crate::game::value_unit::ValueUnit;
+use crate::solver::compat::{CompatibilityFingerprint, IncompatibleArtifact, MigrationRegistry};
 use crate::solver::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 /// 웹 API용 게임 상태 - 직렬화 가능
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct WebGameState {
     /// 홀카드 (요청하는 플레이어만)
     pub hole_cards: [u8; 2],
@@ -31,71 +41,597 @@ pub struct WebGameState {
     pub hero_position: usize,
     /// 베팅 히스토리 (각 스트리트별)
     pub betting_history: Vec<Vec<Action>>,
+    /// 좌석별 상태 (`stacks`와 같은 좌석 순서, 비어 있으면 모든 좌석이 살아 있다고 봄)
+    #[serde(default)]
+    pub seat_status: Vec<SeatStatus>,
+    /// 좌석별로 프리플랍에 이미 낸 칩 (블라인드와 히어로 앞의 베팅, 비어 있으면 기본 블라인드)
+    ///
+    /// 폴드한 좌석의 칩은 데드 머니로 팟에 남습니다.
+    #[serde(default)]
+    pub posted: Vec<u32>,
+    /// 라이브 스트래들 금액 (없으면 스트래들 없는 핸드, 스트래들러가 낸 칩은 `posted`에 포함)
+    ///
+    /// 스트래들이 있으면 전략 키와 최소 레이즈가 달라집니다.
+    #[serde(default)]
+    pub straddle: Option<u32>,
 }
 
+/// 좌석 상태
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SeatStatus {
+    /// 아직 액션할 수 있음
+    #[default]
+    Live,
+    /// 이번 핸드에서 폴드함
+    Folded,
+    /// 올인해서 더 액션할 수 없음
+    AllIn,
+}
+
+/// 베팅 히스토리 액션
+///
+/// JSON 표현 (인접 태그): `{"type": "Fold"}`, `{"type": "Raise", "amount": 300}`
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", content = "amount")]
 pub enum Action {
     Fold,
     Call,
     Raise(u32), // 실제 레이즈 금액
 }
 
+/// 액션 이름 -> 확률
+///
+/// 순회 순서와 직렬화 결과가 실행마다 같도록 `BTreeMap`을 씁니다.
+/// JSON에서는 기존과 같은 객체(`{"call": 0.5, "fold": 0.5}`)이며, 키는 사전순으로 나옵니다.
+/// 사전순은 표시용 순서일 뿐이고, 동률 처리는 [`canonical_action_order`]를 따릅니다.
+pub type StrategyMap = BTreeMap<String, f64>;
+
+/// 액션 이름의 표준 순서 키 (작을수록 앞)
+///
+/// 순서: fold < check < call < bet_{N}pct (크기 오름차순) < raise / raise_small < raise_medium
+/// < raise_large < 올인 (`bet_allin`, `all_in`) < 알 수 없는 이름 (이름 사전순)
+pub fn canonical_action_order(action: &str) -> (u8, u32) {
+    match action {
+        "fold" => (0, 0),
+        "check" => (1, 0),
+        "call" => (2, 0),
+        "raise" | "raise_small" => (4, 0),
+        "raise_medium" => (4, 1),
+        "raise_large" => (4, 2),
+        "bet_allin" | "all_in" | "allin" => (5, 0),
+        other => match other
+            .strip_prefix("bet_")
+            .and_then(|size| size.strip_suffix("pct"))
+            .and_then(|pct| pct.parse::<u32>().ok())
+        {
+            Some(pct) => (3, pct),
+            None => (6, 0),
+        },
+    }
+}
+
+/// 확률이 가장 높은 액션 (정확히 동률이면 표준 순서상 앞선 액션, NaN은 무시)
+pub fn best_action(strategy: &StrategyMap) -> Option<&str> {
+    strategy
+        .iter()
+        .filter(|(_, prob)| !prob.is_nan())
+        .min_by(|(a, pa), (b, pb)| {
+            pb.partial_cmp(pa)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| canonical_action_order(a).cmp(&canonical_action_order(b)))
+                .then_with(|| a.cmp(b))
+        })
+        .map(|(action, _)| action.as_str())
+}
+
 /// 웹 API 응답
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StrategyResponse {
-    /// 각 액션에 대한 확률
-    pub strategy: HashMap<String, f64>,
+    /// 각 액션에 대한 확률 (키 사전순으로 직렬화, [`StrategyMap`] 참고)
+    pub strategy: StrategyMap,
     /// 예상 EV
     pub expected_value: f64,
+    /// `expected_value`의 단위
+    #[serde(default)]
+    pub value_unit: ValueUnit,
     /// 권장 액션
     pub recommended_action: String,
     /// 신뢰도 (0-1, 학습된 데이터의 충분함 정도)
     pub confidence: f64,
+    /// 학습된 CFR 전략에 준 가중치 (0 = 대체 전략만, 1 = CFR 전략만)
+    #[serde(default)]
+    pub trust_weight: f64,
+    /// 조회 경고 (예: 학습한 플랍 부분집합 밖의 보드)
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    /// 액션할 플레이어의 레이즈 가능 범위 (베팅 슬라이더용, 레이즈할 수 없으면 None)
+    #[serde(default)]
+    pub raise_bounds: Option<holdem::RaiseBounds>,
+    /// 학습된 전략에 적용한 정제 방식
+    #[serde(default)]
+    pub purification: Purification,
+}
+
+/// 전략 테이블 파일 포맷 버전 (2: 정보 집합별 방문 횟수 추가, 3: 학습 플랍 부분집합 추가, 4: 압축 전략 페이로드,
+/// 5: 호환성 지문 추가)
+pub const STRATEGY_TABLE_FORMAT_VERSION: u32 = 5;
+
+/// 지문 없이 읽을 수 있는 이전 전략 테이블 포맷 버전
+const LEGACY_STRATEGY_TABLE_FORMAT_VERSION: u32 = 4;
+
+/// 기본 스택 깊이 구간 (빅블라인드 단위)
+pub const DEFAULT_STACK_BUCKETS_BB: [u32; 5] = [10, 20, 40, 75, 150];
+
+/// 스택 깊이 구간 사이의 전략 선택 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BucketSelection {
+    /// 가장 가까운 구간의 전략 사용
+    Nearest,
+    /// 양옆 두 구간의 액션 확률을 선형 보간 후 재정규화
+    Interpolate,
+}
+
+/// 구간의 정보 집합별 평균 전략 저장 형식
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum StrategyPayload {
+    /// 키별 f64 전략 그대로 (InfoKey -> Strategy)
+    Raw(HashMap<u64, Vec<f64>>),
+    /// 같은 전략 벡터를 한 번만 저장한 f32 압축 테이블
+    Compact(CompactStrategyTable<u64>),
+}
+
+impl StrategyPayload {
+    /// 정보 집합의 전략
+    pub fn get(&self, key: u64) -> Option<Vec<f64>> {
+        match self {
+            Self::Raw(strategies) => strategies.get(&key).cloned(),
+            Self::Compact(table) => Some(table.lookup(&key)?.iter().map(|&p| p as f64).collect()),
+        }
+    }
+
+    /// 정보 집합 수
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Raw(strategies) => strategies.len(),
+            Self::Compact(table) => table.len(),
+        }
+    }
+
+    /// 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 압축 형식으로 변환 (이미 압축돼 있으면 그대로)
+    pub fn compacted(self) -> Self {
+        match self {
+            Self::Raw(strategies) => Self::Compact(CompactStrategyTable::from_strategies(
+                strategies.iter().map(|(key, strategy)| (*key, strategy.as_slice())),
+                DEFAULT_COMPACT_TOLERANCE,
+            )),
+            compact => compact,
+        }
+    }
+}
+
+impl StrategyPayload {
+    /// 모든 정보 집합 키를 바꾼 페이로드 (저장된 키 마이그레이션용)
+    fn map_keys(self, f: impl Fn(u64) -> u64) -> Self {
+        match self {
+            Self::Raw(strategies) => Self::Raw(strategies.into_iter().map(|(key, strategy)| (f(key), strategy)).collect()),
+            Self::Compact(table) => Self::Compact(table.map_keys(f)),
+        }
+    }
+}
+
+/// 한 스택 깊이에서 학습된 전략 집합
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StackBucket {
+    /// 학습 시 유효 스택 (빅블라인드 단위, None이면 깊이 정보 없음 - 요청 상태 그대로 조회)
+    pub stack_bb: Option<u32>,
+    /// 정보 집합별 평균 전략
+    pub strategies: StrategyPayload,
+    /// 정보 집합별 학습 방문 횟수 (없는 키는 0회)
+    pub visits: HashMap<u64, u64>,
+    /// 학습에 쓴 플랍 부분집합 (None이면 모든 플랍)
+    pub flop_subset: Option<FlopSubsetConfig>,
+}
+
+impl StackBucket {
+    /// 학습 결과를 스택 깊이 태그와 함께 구간으로 변환
+    pub fn from_trainer(stack_bb: Option<u32>, trainer: &Trainer<holdem::State>) -> Self {
+        Self {
+            stack_bb,
+            strategies: StrategyPayload::Raw(
+                trainer
+                    .nodes
+                    .iter()
+                    .map(|(key, node)| (*key, node.average()))
+                    .collect(),
+            ),
+            visits: trainer
+                .nodes
+                .iter()
+                .map(|(key, node)| (*key, node.visits()))
+                .collect(),
+            flop_subset: trainer
+                .metadata
+                .get(FLOP_SUBSET_METADATA_KEY)
+                .and_then(|json| serde_json::from_str(json).ok()),
+        }
+    }
+}
+
+/// 전략 테이블 파일 내용 (bincode 직렬화)
+#[derive(Serialize, Deserialize)]
+struct StrategyTableFile {
+    version: u32,
+    fingerprint: CompatibilityFingerprint,
+    big_blind: u32,
+    action_names: Vec<String>,
+    buckets: Vec<StackBucket>,
+}
+
+/// 지문이 없는 버전 4 전략 테이블 파일
+#[derive(Deserialize)]
+struct LegacyStrategyTableFile {
+    big_blind: u32,
+    action_names: Vec<String>,
+    buckets: Vec<StackBucket>,
+}
+
+/// 전략 테이블 로드 에러
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StrategyTableError {
+    /// 지원하지 않는 파일 형식 버전
+    UnsupportedVersion { found: u32, supported: u32 },
+    /// 현재 빌드와 호환되지 않는 지문 (마이그레이션 경로 없음)
+    Incompatible(IncompatibleArtifact),
+    /// 파일을 해석할 수 없음
+    Corrupt(String),
+}
+
+impl std::fmt::Display for StrategyTableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedVersion { found, supported } => {
+                write!(f, "지원하지 않는 전략 테이블 버전: {} (지원: {})", found, supported)
+            }
+            Self::Incompatible(error) => write!(f, "전략 테이블 로드 실패: {}", error),
+            Self::Corrupt(reason) => write!(f, "전략 테이블 역직렬화 실패: {}", reason),
+        }
+    }
 }
 
+impl std::error::Error for StrategyTableError {}
+
 /// 사전 계산된 전략 조회 테이블
+///
+/// 여러 스택 깊이 구간의 전략을 함께 저장하고, 조회 시 요청의 유효 스택에 맞는
+/// 구간을 고르거나 두 구간 사이를 보간합니다.
 pub struct StrategyTable {
-    /// 미리 계산된 전략들 (InfoKey -> Strategy)
-    strategies: HashMap<u64, Vec<f64>>,
+    /// 스택 깊이 구간들 (깊이 오름차순)
+    buckets: Vec<StackBucket>,
+    /// 구간 깊이 계산에 쓰는 빅블라인드 크기 (칩)
+    big_blind: u32,
+    /// 구간 사이 선택 방식
+    selection: BucketSelection,
+    /// 방문 횟수 기반 블렌딩 정책 (None이면 학습된 전략을 그대로 사용)
+    blend_policy: Option<BlendPolicy>,
+    /// 조회한 학습 전략에 적용할 정제 방식
+    purification: Purification,
+    /// 구간들이 학습한 플랍 부분집합 (None이면 어떤 구간이든 모든 플랍을 학습함)
+    flop_coverage: Option<Vec<FlopSampler>>,
     /// 액션 매핑
     action_names: Vec<String>,
+    /// 전략을 학습한 빌드의 호환성 지문
+    fingerprint: CompatibilityFingerprint,
 }
 
 impl StrategyTable {
-    /// 미리 학습된 CFR 결과로부터 lookup table 생성
-    pub fn from_trained_cfr(trainer: &Trainer<holdem::State>) -> Self {
-        let mut strategies = HashMap::new();
-        let action_names = vec![
+    fn default_action_names() -> Vec<String> {
+        vec![
             "fold".to_string(),
             "call".to_string(),
             "raise_small".to_string(),
             "raise_medium".to_string(),
             "raise_large".to_string(),
             "all_in".to_string(),
-        ];
+        ]
+    }
 
-        // CFR 노드들을 lookup table로 변환
-        for (key, node) in &trainer.nodes {
-            strategies.insert(*key, node.average());
-        }
+    /// 미리 학습된 CFR 결과로부터 lookup table 생성 (스택 깊이 구간 없음)
+    pub fn from_trained_cfr(trainer: &Trainer<holdem::State>) -> Self {
+        Self::from_buckets(vec![StackBucket::from_trainer(None, trainer)], 100).with_fingerprint(trainer.fingerprint())
+    }
 
+    /// 스택 깊이 구간들로 테이블 생성
+    ///
+    /// # 매개변수
+    /// - buckets: 구간 목록 (순서 무관, 깊이 오름차순으로 정렬됨)
+    /// - big_blind: 요청 스택을 빅블라인드 단위로 바꿀 때 쓰는 빅블라인드 크기
+    pub fn from_buckets(mut buckets: Vec<StackBucket>, big_blind: u32) -> Self {
+        buckets.sort_by_key(|bucket| bucket.stack_bb);
+        let flop_coverage = buckets
+            .iter()
+            .map(|bucket| bucket.flop_subset.clone().map(FlopSampler::new))
+            .collect::<Option<Vec<_>>>()
+            .filter(|samplers| !samplers.is_empty());
         Self {
-            strategies,
-            action_names,
+            buckets,
+            big_blind: big_blind.max(1),
+            selection: BucketSelection::Interpolate,
+            blend_policy: None,
+            purification: Purification::None,
+            flop_coverage,
+            action_names: Self::default_action_names(),
+            fingerprint: holdem::State::fingerprint(None),
         }
     }
 
+    /// 전략을 학습한 빌드의 호환성 지문 지정 (기본값은 노리밋 일반 홀덤의 현재 지문)
+    pub fn with_fingerprint(mut self, fingerprint: CompatibilityFingerprint) -> Self {
+        self.fingerprint = fingerprint;
+        self
+    }
+
+    /// 전략을 학습한 빌드의 호환성 지문
+    pub fn fingerprint(&self) -> &CompatibilityFingerprint {
+        &self.fingerprint
+    }
+
+    /// 보드의 플랍이 학습한 플랍 부분집합 밖이면 경고 메시지
+    pub fn flop_warning(&self, board: &[u8]) -> Option<String> {
+        let coverage = self.flop_coverage.as_ref()?;
+        if coverage.iter().any(|sampler| sampler.contains(board)) {
+            return None;
+        }
+        Some(format!(
+            "플랍 {:?}은(는) 학습한 플랍 부분집합에 없어 전략이 부정확할 수 있습니다",
+            &board[..3]
+        ))
+    }
+
+    /// 구간 사이 선택 방식 지정
+    pub fn with_selection(mut self, selection: BucketSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// 방문 횟수 기반 블렌딩 정책 지정 (파일에는 저장되지 않는 서빙 설정)
+    pub fn with_blend_policy(mut self, policy: BlendPolicy) -> Self {
+        self.blend_policy = Some(policy);
+        self
+    }
+
+    /// 조회 시 학습 전략에 적용할 정제 방식 지정 (파일에는 저장되지 않는 서빙 설정)
+    ///
+    /// 블렌딩 정책이 있으면 정제한 학습 전략을 대체 전략과 섞습니다.
+    pub fn with_purification(mut self, purification: Purification) -> Self {
+        self.purification = purification;
+        self
+    }
+
+    /// 모든 구간의 전략을 압축 페이로드로 변환 (서빙용, 확률은 f32 양자화)
+    pub fn compacted(mut self) -> Self {
+        for bucket in &mut self.buckets {
+            let strategies = std::mem::replace(&mut bucket.strategies, StrategyPayload::Raw(HashMap::new()));
+            bucket.strategies = strategies.compacted();
+        }
+        self
+    }
+
+    /// 저장된 스택 깊이 구간들 (빅블라인드 단위)
+    pub fn stack_buckets(&self) -> Vec<u32> {
+        self.buckets.iter().filter_map(|bucket| bucket.stack_bb).collect()
+    }
+
+    /// 테이블을 바이트로 직렬화 (구간 메타데이터 포함)
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        let file = StrategyTableFile {
+            version: STRATEGY_TABLE_FORMAT_VERSION,
+            fingerprint: self.fingerprint.clone(),
+            big_blind: self.big_blind,
+            action_names: self.action_names.clone(),
+            buckets: self.buckets.clone(),
+        };
+        bincode::serialize(&file).map_err(|e| format!("전략 테이블 직렬화 실패: {}", e))
+    }
+
+    /// 바이트에서 테이블 복원 (이 빌드에 포함된 마이그레이션만 사용)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        Self::from_bytes_with_migrations(bytes, &MigrationRegistry::builtin()).map_err(|e| e.to_string())
+    }
+
+    /// 바이트에서 테이블 복원 (호환성 지문 검사)
+    ///
+    /// 저장된 지문을 같은 변형/베팅 규칙 방식의 현재 지문과 비교해, 같으면 그대로 복원하고
+    /// 버전 구성 요소만 다르면 `registry`의 마이그레이션으로 모든 구간의 키를 바꿉니다.
+    /// 지문이 없는 버전 4 파일은 기준 버전으로 저장된 것으로 봅니다.
+    pub fn from_bytes_with_migrations(bytes: &[u8], registry: &MigrationRegistry) -> Result<Self, StrategyTableError> {
+        let corrupt = |e: bincode::Error| StrategyTableError::Corrupt(e.to_string());
+        let version: u32 = bincode::deserialize(bytes).map_err(corrupt)?;
+        let (stored, big_blind, action_names, buckets) = match version {
+            STRATEGY_TABLE_FORMAT_VERSION => {
+                let file: StrategyTableFile = bincode::deserialize(bytes).map_err(corrupt)?;
+                (file.fingerprint, file.big_blind, file.action_names, file.buckets)
+            }
+            LEGACY_STRATEGY_TABLE_FORMAT_VERSION => {
+                let (_, file): (u32, LegacyStrategyTableFile) = bincode::deserialize(bytes).map_err(corrupt)?;
+                let stored = holdem::State::fingerprint(None).with_baseline_versions();
+                (stored, file.big_blind, file.action_names, file.buckets)
+            }
+            found => {
+                return Err(StrategyTableError::UnsupportedVersion {
+                    found,
+                    supported: STRATEGY_TABLE_FORMAT_VERSION,
+                })
+            }
+        };
+
+        let expected = Self::current_fingerprint_for(&stored);
+        let compatibility = registry.check(&stored, &expected).map_err(StrategyTableError::Incompatible)?;
+        let buckets = if compatibility.is_migrated() {
+            let migrate = |key| compatibility.migrate_key(key);
+            buckets
+                .into_iter()
+                .map(|bucket| StackBucket {
+                    strategies: bucket.strategies.map_keys(migrate),
+                    visits: bucket.visits.into_iter().map(|(key, visits)| (migrate(key), visits)).collect(),
+                    ..bucket
+                })
+                .collect()
+        } else {
+            buckets
+        };
+
+        let mut table = Self::from_buckets(buckets, big_blind).with_fingerprint(expected);
+        table.action_names = action_names;
+        Ok(table)
+    }
+
+    /// 저장된 지문과 같은 변형/베팅 규칙 방식의 현재 빌드 지문 (맞는 규칙이 없으면 기본 규칙)
+    fn current_fingerprint_for(stored: &CompatibilityFingerprint) -> CompatibilityFingerprint {
+        let fixed_limit = holdem::BettingRules::FixedLimit {
+            small_bet: 0,
+            big_bet: 0,
+            max_raises: 0,
+        };
+        [holdem::HoldemVariant::Standard, holdem::HoldemVariant::ShortDeck]
+            .into_iter()
+            .flat_map(|variant| {
+                [holdem::BettingRules::NoLimit, holdem::BettingRules::PotLimit, fixed_limit]
+                    .map(|betting| holdem::State::rules_fingerprint(variant, betting))
+            })
+            .find(|current| current.abstraction == stored.abstraction && current.betting_rules == stored.betting_rules)
+            .unwrap_or_else(|| holdem::State::fingerprint(None))
+    }
+
+    /// 테이블 파일 저장
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let bytes = self
+            .to_bytes()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, bytes)
+    }
+
+    /// 테이블 파일 로드
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes(&bytes).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// 요청 상태의 액션 확률 조회 (스택 깊이 구간 선택/보간 적용)
+    pub fn lookup_strategy(&self, state: &WebGameState) -> Option<Vec<f64>> {
+        self.lookup_with_visits(state).map(|(strategy, _)| strategy)
+    }
+
+    /// 요청 상태의 액션 확률과 학습 방문 횟수 조회
+    ///
+    /// 두 구간을 보간한 전략은 두 구간 중 적게 방문한 쪽의 방문 횟수를 사용합니다.
+    pub fn lookup_with_visits(&self, state: &WebGameState) -> Option<(Vec<f64>, u64)> {
+        self.raw_lookup_with_visits(state)
+            .map(|(strategy, visits)| (self.purification.apply(&strategy), visits))
+    }
+
+    /// 정제 전 액션 확률과 학습 방문 횟수 조회
+    fn raw_lookup_with_visits(&self, state: &WebGameState) -> Option<(Vec<f64>, u64)> {
+        let internal_state = self.web_to_internal_state(state);
+        let hero = state.hero_position;
+        let find = |bucket: &StackBucket, key: u64| {
+            let strategy = bucket.strategies.get(key)?;
+            Some((strategy, bucket.visits.get(&key).copied().unwrap_or(0)))
+        };
+
+        // 깊이 정보가 없는 구간은 요청 상태 그대로 조회
+        if let Some(untagged) = self.buckets.iter().find(|bucket| bucket.stack_bb.is_none()) {
+            return find(untagged, holdem::State::info_key(&internal_state, hero));
+        }
+
+        let effective_bb = Self::effective_stack(&internal_state, hero) as f64 / self.big_blind as f64;
+        let lookup = |bucket: &StackBucket| {
+            let depth = bucket.stack_bb?;
+            let scaled = self.rescale_to_depth(&internal_state, hero, depth);
+            find(bucket, holdem::State::info_key(&scaled, hero))
+        };
+
+        let upper = self
+            .buckets
+            .iter()
+            .position(|bucket| bucket.stack_bb.is_some_and(|d| d as f64 >= effective_bb));
+        let (low, high) = match upper {
+            None => (self.buckets.last()?, None),
+            Some(0) => (&self.buckets[0], None),
+            Some(i) => (&self.buckets[i - 1], Some(&self.buckets[i])),
+        };
+        let Some(high) = high else {
+            return lookup(low);
+        };
+
+        let (low_bb, high_bb) = (low.stack_bb? as f64, high.stack_bb? as f64);
+        let t = (effective_bb - low_bb) / (high_bb - low_bb);
+        if t >= 1.0 {
+            return lookup(high);
+        }
+        match self.selection {
+            BucketSelection::Nearest => lookup(if t <= 0.5 { low } else { high }),
+            BucketSelection::Interpolate => match (lookup(low), lookup(high)) {
+                (Some((a, a_visits)), Some((b, b_visits))) => {
+                    Some((interpolate_strategies(&a, &b, t), a_visits.min(b_visits)))
+                }
+                (a, b) => a.or(b),
+            },
+        }
+    }
+
+    /// 유효 스택 (이번 스트리트 투자액 포함, 히어로와 가장 큰 상대 스택 중 작은 값)
+    fn effective_stack(state: &holdem::State, hero: usize) -> u32 {
+        let total = |i: usize| state.stack[i] + state.invested[i];
+        let villain = (0..6)
+            .filter(|&i| i != hero && state.alive[i])
+            .map(total)
+            .max();
+        villain.map_or(total(hero), |v| v.min(total(hero)))
+    }
+
+    /// 유효 스택이 지정한 깊이가 되도록 생존 플레이어들의 스택을 같은 비율로 조정
+    fn rescale_to_depth(&self, state: &holdem::State, hero: usize, depth_bb: u32) -> holdem::State {
+        let effective = Self::effective_stack(state, hero) as u64;
+        let target = depth_bb as u64 * self.big_blind as u64;
+        let mut scaled = state.clone();
+        if effective == 0 || effective == target {
+            return scaled;
+        }
+        for i in (0..6).filter(|&i| state.alive[i]) {
+            let total = (state.stack[i] + state.invested[i]) as u64 * target / effective;
+            scaled.stack[i] = (total as u32).saturating_sub(state.invested[i]);
+        }
+        scaled
+    }
+
     /// 웹 상태로부터 전략 계산
     pub fn get_strategy(&self, state: &WebGameState) -> StrategyResponse {
-        // 1. 현재 상태를 internal state로 변환
-        let internal_state = self.web_to_internal_state(state);
+        let mut response = match &self.blend_policy {
+            Some(policy) => self.blended_strategy(state, policy),
+            None => self.trained_strategy(state),
+        };
+        response.warnings.extend(self.flop_warning(&state.board));
+        response.raise_bounds = self.web_to_internal_state(state).raise_bounds();
+        response
+    }
 
-        // 2. Info key 계산
-        let info_key = holdem::State::info_key(&internal_state, state.hero_position);
+    /// 학습된 전략을 그대로 사용한 응답 (없으면 기본 전략)
+    fn trained_strategy(&self, state: &WebGameState) -> StrategyResponse {
+        let internal_state = self.web_to_internal_state(state);
 
-        // 3. 미리 계산된 전략 조회
-        if let Some(strategy_vec) = self.strategies.get(&info_key) {
-            let mut strategy_map = HashMap::new();
+        // 미리 계산된 전략 조회
+        if let Some(strategy_vec) = self.lookup_strategy(state) {
+            let mut strategy_map = StrategyMap::new();
             let mut max_prob = 0.0;
             let mut recommended = "fold".to_string();
 
@@ -120,8 +656,13 @@ impl StrategyTable {
             StrategyResponse {
                 strategy: strategy_map,
                 expected_value: ev,
+                value_unit: ValueUnit::Chips,
                 recommended_action: recommended,
                 confidence: 0.8, // 고정값, 실제로는 샘플 수 기반으로 계산
+                trust_weight: 1.0,
+                warnings: Vec::new(),
+                raise_bounds: None,
+                purification: self.purification,
             }
         } else {
             // 학습되지 않은 상황 - 기본 전략 사용
@@ -129,44 +670,120 @@ impl StrategyTable {
         }
     }
 
+    /// 방문 횟수에 따라 학습된 전략과 대체 전략을 섞은 응답
+    ///
+    /// 응답에는 가능한 액션만 들어가며, 신뢰도는 휴리스틱(0.3)과 학습 전략(0.8) 사이를
+    /// 신뢰 가중치로 보간한 값입니다.
+    fn blended_strategy(&self, state: &WebGameState, policy: &BlendPolicy) -> StrategyResponse {
+        let internal_state = self.web_to_internal_state(state);
+        let legal_actions = holdem::State::legal_actions(&internal_state);
+        let legal_count = legal_actions.len().min(self.action_names.len());
+
+        let lookup = self.lookup_with_visits(state);
+        let (cfr, visits) = match &lookup {
+            Some((strategy, visits)) => (Some(strategy.as_slice()), *visits),
+            None => (None, 0),
+        };
+        let blended = policy.blend(cfr, visits, legal_count, || {
+            Self::heuristic_for_actions(state, &legal_actions)
+        });
+
+        let mut strategy_map = StrategyMap::new();
+        let mut max_prob = -1.0;
+        let mut recommended = "fold".to_string();
+        for (name, &prob) in self.action_names.iter().zip(&blended.probabilities) {
+            strategy_map.insert(name.clone(), prob);
+            if prob > max_prob {
+                max_prob = prob;
+                recommended = name.clone();
+            }
+        }
+
+        StrategyResponse {
+            expected_value: self.estimate_ev(state, &strategy_map),
+            strategy: strategy_map,
+            value_unit: ValueUnit::Chips,
+            recommended_action: recommended,
+            confidence: 0.3 + 0.5 * blended.trust_weight,
+            trust_weight: blended.trust_weight,
+            warnings: Vec::new(),
+            raise_bounds: None,
+            purification: self.purification,
+        }
+    }
+
+    /// 룰 기반 휴리스틱을 가능한 액션 순서로 정리 (레이즈 크기별 확률은 합산)
+    fn heuristic_for_actions(state: &WebGameState, legal_actions: &[holdem::Act]) -> Vec<f64> {
+        let heuristic = Self::heuristic_strategy(state);
+        legal_actions
+            .iter()
+            .map(|action| match action {
+                holdem::Act::Fold => heuristic.get("fold").copied().unwrap_or(0.0),
+                holdem::Act::Call => heuristic.get("call").copied().unwrap_or(0.0),
+                holdem::Act::Raise(_) => heuristic
+                    .iter()
+                    .filter(|(name, _)| name.starts_with("raise") || name.as_str() == "all_in")
+                    .map(|(_, prob)| prob)
+                    .sum(),
+            })
+            .collect()
+    }
+
     /// 웹 상태를 내부 상태로 변환
+    ///
+    /// 웹 요청에는 히어로 카드만 있으므로 히어로 관점 상태(`ObservedState`)를 거쳐
+    /// 상대 홀카드가 마스킹된 상태를 만듭니다.
     fn web_to_internal_state(&self, web_state: &WebGameState) -> holdem::State {
-        let mut state = holdem::State {
-            hole: [[0; 2]; 6],
+        Self::web_to_observed_state(web_state).to_state()
+    }
+
+    /// 웹 상태를 히어로 관점 상태로 변환
+    pub(crate) fn web_to_observed_state(web_state: &WebGameState) -> ObservedState {
+        let mut observed = ObservedState {
+            player: web_state.hero_position,
+            hole: web_state.hole_cards,
             board: web_state.board.clone(),
-            to_act: web_state.player_to_act,
             street: web_state.street,
             pot: web_state.pot,
             stack: [0; 6],
             alive: [false; 6],
             invested: [0; 6],
+            antes: [0; 6],
             to_call: web_state.to_call,
+            to_act: web_state.player_to_act,
             actions_taken: 0,
+            // 학습 루트(`State::new_hand`)와 같은 버튼이어야 포지션이 같은 키로 조회됨
+            button: holdem::State::default_button(web_state.stacks.len()),
+            rake: None,
+            variant: holdem::HoldemVariant::Standard,
+            betting: holdem::BettingRules::NoLimit,
+            raises_this_street: 0,
+            // 웹 요청에는 블라인드 정보가 없으므로 투자액에서 마지막 레이즈 증가분만 추정
+            big_blind: 0,
+            last_raise: inferred_last_raise(web_state.street, &web_state.street_investments),
+            straddle: web_state.straddle.unwrap_or(0),
         };
 
-        // 히어로의 홀카드 설정
-        state.hole[web_state.hero_position] = web_state.hole_cards;
-
         // 스택과 생존 상태 설정
         for (i, &player_idx) in web_state.alive_players.iter().enumerate() {
             if player_idx < 6 && i < web_state.stacks.len() {
-                state.stack[player_idx] = web_state.stacks[i];
-                state.alive[player_idx] = true;
+                observed.stack[player_idx] = web_state.stacks[i];
+                observed.alive[player_idx] = true;
             }
         }
 
         // 현재 스트리트 투자 금액 설정
         for (i, &investment) in web_state.street_investments.iter().enumerate() {
             if i < 6 {
-                state.invested[i] = investment;
+                observed.invested[i] = investment;
             }
         }
 
-        state
+        observed
     }
 
     /// EV 추정 (간단한 휴리스틱)
-    fn estimate_ev(&self, state: &WebGameState, strategy: &HashMap<String, f64>) -> f64 {
+    fn estimate_ev(&self, state: &WebGameState, strategy: &StrategyMap) -> f64 {
         // 간단한 예시 - 실제로는 더 정교한 계산 필요
         let fold_prob = strategy.get("fold").unwrap_or(&0.0);
         let call_prob = strategy.get("call").unwrap_or(&0.0);
@@ -187,7 +804,23 @@ impl StrategyTable {
 
     /// 기본 전략 (학습되지 않은 상황용)
     fn default_strategy(&self, state: &WebGameState) -> StrategyResponse {
-        let mut strategy = HashMap::new();
+        StrategyResponse {
+            strategy: Self::heuristic_strategy(state),
+            expected_value: 0.0,
+            value_unit: ValueUnit::Chips,
+            recommended_action: "call".to_string(),
+            confidence: 0.3, // 낮은 신뢰도
+            trust_weight: 0.0,
+            warnings: Vec::new(),
+            raise_bounds: None,
+            // 학습된 전략을 쓰지 않으므로 정제도 적용되지 않음
+            purification: Purification::None,
+        }
+    }
+
+    /// 매우 기본적인 룰 기반 전략 (액션 이름 -> 확률)
+    fn heuristic_strategy(state: &WebGameState) -> StrategyMap {
+        let mut strategy = StrategyMap::new();
 
         // 매우 기본적인 룰 기반 전략
         if state.to_call == 0 {
@@ -207,11 +840,328 @@ impl StrategyTable {
             strategy.insert("raise_medium".to_string(), 0.2);
         }
 
-        StrategyResponse {
-            strategy,
-            expected_value: 0.0,
-            recommended_action: "call".to_string(),
-            confidence: 0.3, // 낮은 신뢰도
+        strategy
+    }
+}
+
+/// 두 액션 확률 벡터를 선형 보간 후 합이 1이 되도록 재정규화
+///
+/// 길이가 다르면 (구간마다 가능한 액션 수가 다를 수 있음) 없는 액션은 0으로 취급합니다.
+fn interpolate_strategies(low: &[f64], high: &[f64], t: f64) -> Vec<f64> {
+    let t = t.clamp(0.0, 1.0);
+    let len = low.len().max(high.len());
+    let mut mixed: Vec<f64> = (0..len)
+        .map(|i| {
+            let a = low.get(i).copied().unwrap_or(0.0);
+            let b = high.get(i).copied().unwrap_or(0.0);
+            (1.0 - t) * a + t * b
+        })
+        .collect();
+    let sum: f64 = mixed.iter().sum();
+    if sum > 0.0 {
+        mixed.iter_mut().for_each(|p| *p /= sum);
+    }
+    mixed
+}
+
+/// 스트리트 투자액으로 추정한 마지막 베팅/레이즈 증가분
+///
+/// 가장 큰 투자액과 그다음 투자액(없으면 0)의 차이입니다. 블라인드만 걸린 프리플랍
+/// (투자액이 스몰 블라인드와 그 두 배인 빅블라인드 두 종류뿐)은 빅블라인드 전체를 증가분으로 봅니다.
+fn inferred_last_raise(street: u8, investments: &[u32]) -> u32 {
+    let mut levels: Vec<u32> = investments.iter().copied().filter(|&v| v > 0).collect();
+    levels.sort_unstable();
+    levels.dedup();
+    match levels.as_slice() {
+        [] => 0,
+        [small, big] if street == 0 && small * 2 == *big => *big,
+        [.., below, top] => top - below,
+        [top] => *top,
+    }
+}
+
+/// 전략 캐시 키 - 응답 계산에 쓰이는 모든 필드를 정규화한 상황
+///
+/// 전략적으로 의미 없는 차이(홀카드/보드 순서)만 정규화하고,
+/// 응답에 영향을 주는 필드(팟, 콜 금액, 스택 등)는 그대로 보존합니다.
+/// 해시가 아닌 값 자체를 비교하므로 서로 다른 상황이 엔트리를 공유하지 않습니다.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SituationKey {
+    hole: [u8; 2],
+    board: Vec<u8>,
+    street: u8,
+    pot: u32,
+    to_call: u32,
+    stacks: Vec<u32>,
+    alive_players: Vec<usize>,
+    investments: Vec<u32>,
+    to_act: usize,
+    hero: usize,
+    straddle: u32,
+}
+
+impl SituationKey {
+    /// PokerWebAPI 요청용 키
+    ///
+    /// info_key가 보드 카드 원값을 사용하므로 수트는 정규화하지 않습니다.
+    pub fn from_web_state(state: &WebGameState) -> Self {
+        let mut hole = state.hole_cards;
+        hole.sort_unstable();
+        let mut board = state.board.clone();
+        board.sort_unstable();
+
+        Self {
+            hole,
+            board,
+            street: state.street,
+            pot: state.pot,
+            to_call: state.to_call,
+            stacks: state.stacks.clone(),
+            alive_players: state.alive_players.clone(),
+            investments: state.street_investments.clone(),
+            to_act: state.player_to_act,
+            hero: state.hero_position,
+            straddle: state.straddle.unwrap_or(0),
+        }
+    }
+
+    /// QuickPokerAPI 요청용 키
+    ///
+    /// 휴리스틱 엔진은 수트의 같고 다름만 보므로 수트를 등장 순서대로
+    /// 다시 매겨 수트 동형(isomorphic) 상황이 같은 엔트리를 쓰도록 합니다.
+    pub fn from_simple_state(state: &crate::api::web_api_simple::WebGameState) -> Self {
+        let mut hole = state.hole_cards;
+        hole.sort_unstable_by_key(|&c| (c % 13, c / 13));
+        let mut board = state.board.clone();
+        board.sort_unstable_by_key(|&c| (c % 13, c / 13));
+
+        // 수트 재매핑 (범위 밖 카드는 그대로 유지)
+        let mut suit_map = [u8::MAX; 4];
+        let mut next_suit = 0;
+        let mut canonical = |card: u8| -> u8 {
+            let suit = (card / 13) as usize;
+            if suit >= 4 {
+                return card;
+            }
+            if suit_map[suit] == u8::MAX {
+                suit_map[suit] = next_suit;
+                next_suit += 1;
+            }
+            suit_map[suit] * 13 + card % 13
+        };
+        let hole = [canonical(hole[0]), canonical(hole[1])];
+        let board = board.into_iter().map(&mut canonical).collect();
+
+        Self {
+            hole,
+            board,
+            street: state.street,
+            // 휴리스틱 엔진은 죽은 칩을 포함한 전체 팟만 보므로 합친 값으로 구분
+            pot: state.total_pot(),
+            to_call: state.to_call,
+            stacks: [state.my_stack, state.opponent_stack]
+                .into_iter()
+                .chain(state.opponent_stacks.iter().copied())
+                .collect(),
+            // 상대 수를 참여 플레이어 목록 대신 보존
+            alive_players: vec![state.num_opponents as usize],
+            investments: Vec::new(),
+            to_act: state.players_behind as usize,
+            hero: state.hero_position as usize,
+            straddle: 0,
+        }
+    }
+}
+
+/// 전략 캐시 설정
+#[derive(Debug, Clone)]
+pub struct StrategyCacheConfig {
+    /// 전체 최대 엔트리 수 (샤드에 균등 분배)
+    pub capacity: usize,
+    /// 샤드 수 (락 경합 감소)
+    pub shards: usize,
+    /// 엔트리 유효 시간
+    pub ttl: Duration,
+}
+
+impl Default for StrategyCacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 10_000,
+            shards: 16,
+            ttl: Duration::from_secs(300),
+        }
+    }
+}
+
+/// 전략 캐시 통계
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyCacheStats {
+    pub entries: usize,
+    pub capacity: usize,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub expirations: u64,
+    /// 적중률 (0.0-1.0)
+    pub hit_rate: f64,
+}
+
+struct CacheSlot<V> {
+    value: V,
+    inserted_at: Instant,
+    last_used: u64,
+}
+
+struct CacheShard<K, V> {
+    entries: HashMap<K, CacheSlot<V>>,
+    clock: u64,
+}
+
+/// 샤딩된 LRU 전략 캐시 (TTL 지원, 스레드 안전)
+///
+/// 각 샤드는 독립된 `Mutex`로 보호되어 여러 요청 스레드에서 동시에 사용할 수 있습니다.
+pub struct StrategyCache<K, V> {
+    shards: Vec<Mutex<CacheShard<K, V>>>,
+    shard_capacity: usize,
+    ttl: Duration,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+    expirations: AtomicU64,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> StrategyCache<K, V> {
+    /// 설정으로 캐시 생성
+    pub fn new(config: StrategyCacheConfig) -> Self {
+        let shard_count = config.shards.max(1);
+        let shard_capacity = config.capacity.div_ceil(shard_count).max(1);
+        let shards = (0..shard_count)
+            .map(|_| {
+                Mutex::new(CacheShard {
+                    entries: HashMap::new(),
+                    clock: 0,
+                })
+            })
+            .collect();
+
+        Self {
+            shards,
+            shard_capacity,
+            ttl: config.ttl,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+            expirations: AtomicU64::new(0),
+        }
+    }
+
+    fn shard_for(&self, key: &K) -> &Mutex<CacheShard<K, V>> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        &self.shards[(hasher.finish() as usize) % self.shards.len()]
+    }
+
+    /// 캐시 조회 (만료된 엔트리는 제거하고 미스로 처리)
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut shard = match self.shard_for(key).lock() {
+            Ok(shard) => shard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        shard.clock += 1;
+        let now = shard.clock;
+
+        let expired = match shard.entries.get_mut(key) {
+            Some(slot) if slot.inserted_at.elapsed() <= self.ttl => {
+                slot.last_used = now;
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return Some(slot.value.clone());
+            }
+            Some(_) => true,
+            None => false,
+        };
+
+        if expired {
+            shard.entries.remove(key);
+            self.expirations.fetch_add(1, Ordering::Relaxed);
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        None
+    }
+
+    /// 캐시 저장 (샤드가 가득 차면 가장 오래 사용되지 않은 엔트리 축출)
+    pub fn insert(&self, key: K, value: V) {
+        let mut shard = match self.shard_for(&key).lock() {
+            Ok(shard) => shard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        shard.clock += 1;
+        let now = shard.clock;
+
+        if !shard.entries.contains_key(&key) && shard.entries.len() >= self.shard_capacity {
+            let coldest = shard
+                .entries
+                .iter()
+                .min_by_key(|(_, slot)| slot.last_used)
+                .map(|(k, _)| k.clone());
+            if let Some(coldest) = coldest {
+                shard.entries.remove(&coldest);
+                self.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        shard.entries.insert(
+            key,
+            CacheSlot {
+                value,
+                inserted_at: Instant::now(),
+                last_used: now,
+            },
+        );
+    }
+
+    /// 조회 후 없으면 계산하여 저장
+    pub fn get_or_insert_with(&self, key: K, compute: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = compute();
+        self.insert(key, value.clone());
+        value
+    }
+
+    /// 모든 엔트리 제거 (통계는 유지)
+    pub fn clear(&self) {
+        for shard in &self.shards {
+            if let Ok(mut shard) = shard.lock() {
+                shard.entries.clear();
+            }
+        }
+    }
+
+    /// 캐시 통계
+    pub fn cache_stats(&self) -> StrategyCacheStats {
+        let entries = self
+            .shards
+            .iter()
+            .map(|shard| shard.lock().map(|s| s.entries.len()).unwrap_or(0))
+            .sum();
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let lookups = hits + misses;
+
+        StrategyCacheStats {
+            entries,
+            capacity: self.shard_capacity * self.shards.len(),
+            hits,
+            misses,
+            evictions: self.evictions.load(Ordering::Relaxed),
+            expirations: self.expirations.load(Ordering::Relaxed),
+            hit_rate: if lookups > 0 {
+                hits as f64 / lookups as f64
+            } else {
+                0.0
+            },
         }
     }
 }
@@ -219,6 +1169,7 @@ impl StrategyTable {
 /// 웹 API 메인 핸들러
 pub struct PokerWebAPI {
     strategy_table: StrategyTable,
+    cache: Option<StrategyCache<SituationKey, StrategyResponse>>,
 }
 
 impl PokerWebAPI {
@@ -226,12 +1177,57 @@ impl PokerWebAPI {
     pub fn new(trainer: &Trainer<holdem::State>) -> Self {
         Self {
             strategy_table: StrategyTable::from_trained_cfr(trainer),
+            cache: None,
+        }
+    }
+
+    /// 전략 캐시를 사용하는 API 생성
+    pub fn with_cache(trainer: &Trainer<holdem::State>, config: StrategyCacheConfig) -> Self {
+        Self {
+            strategy_table: StrategyTable::from_trained_cfr(trainer),
+            cache: Some(StrategyCache::new(config)),
+        }
+    }
+
+    /// 저장된 전략 테이블로 API 생성 (스택 깊이 구간 테이블 포함)
+    pub fn from_table(strategy_table: StrategyTable, cache: Option<StrategyCacheConfig>) -> Self {
+        Self {
+            strategy_table,
+            cache: cache.map(StrategyCache::new),
         }
     }
 
     /// 단일 요청 처리 - stateless
     pub fn get_optimal_strategy(&self, game_state: WebGameState) -> StrategyResponse {
-        self.strategy_table.get_strategy(&game_state)
+        match &self.cache {
+            Some(cache) => cache.get_or_insert_with(SituationKey::from_web_state(&game_state), || {
+                self.strategy_table.get_strategy(&game_state)
+            }),
+            None => self.strategy_table.get_strategy(&game_state),
+        }
+    }
+
+    /// 방문 횟수 기반 블렌딩 정책 지정 (이미 캐시된 응답은 비움)
+    pub fn with_blend_policy(mut self, policy: BlendPolicy) -> Self {
+        self.strategy_table.blend_policy = Some(policy);
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+        self
+    }
+
+    /// 학습 전략 정제 방식 지정 (이미 캐시된 응답은 비움)
+    pub fn with_purification(mut self, purification: Purification) -> Self {
+        self.strategy_table.purification = purification;
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+        self
+    }
+
+    /// 캐시 통계 (캐시를 사용하지 않으면 None)
+    pub fn cache_stats(&self) -> Option<StrategyCacheStats> {
+        self.cache.as_ref().map(|cache| cache.cache_stats())
     }
 
     /// 배치 요청 처리 - 여러 상황을 한 번에
@@ -249,9 +1245,32 @@ impl PokerWebAPI {
     }
 }
 
-/// 오프라인 학습용 헬퍼
+/// 스택 깊이 구간별 학습 설정
+#[derive(Debug, Clone)]
+pub struct StackBucketConfig {
+    /// 학습할 유효 스택 깊이 (빅블라인드 단위)
+    pub depths_bb: Vec<u32>,
+    /// 빅블라인드 크기 (칩, 스몰 블라인드는 절반)
+    pub big_blind: u32,
+    /// 구간당 CFR 반복 수
+    pub iterations: usize,
+}
+
+impl Default for StackBucketConfig {
+    fn default() -> Self {
+        Self {
+            depths_bb: DEFAULT_STACK_BUCKETS_BB.to_vec(),
+            big_blind: 100,
+            iterations: 100,
+        }
+    }
+}
+
+/// 오프라인 학습용 헬퍼 (`training` 기능)
+#[cfg(feature = "training")]
 pub struct OfflineTrainer;
 
+#[cfg(feature = "training")]
 impl OfflineTrainer {
     /// 단일 시나리오로 빠른 훈련 (테스트용)
     pub fn train_simple_strategy(iterations: usize) -> Trainer<holdem::State> {
@@ -266,6 +1285,61 @@ impl OfflineTrainer {
         trainer
     }
 
+    /// 스택 깊이 구간마다 따로 학습하여 하나의 전략 테이블로 묶음
+    ///
+    /// 각 구간은 해당 깊이의 헤즈업 핸드로 학습되며, 노드 집합에 깊이 태그가 붙습니다.
+    pub fn train_stack_buckets(config: &StackBucketConfig) -> StrategyTable {
+        let big_blind = config.big_blind.max(2);
+        let buckets = config
+            .depths_bb
+            .iter()
+            .map(|&depth| {
+                let mut trainer = Trainer::new();
+                let root = holdem::State::new_hand([big_blind / 2, big_blind], [depth * big_blind; 6], 2);
+                trainer.run(vec![root], config.iterations);
+                StackBucket::from_trainer(Some(depth), &trainer)
+            })
+            .collect();
+
+        StrategyTable::from_buckets(buckets, big_blind)
+    }
+
+    /// 플랍 딜링을 부분집합으로 제한하여 학습
+    pub fn train_flop_subset(
+        roots: Vec<holdem::State>,
+        subset: &FlopSubsetConfig,
+        iterations: usize,
+    ) -> Trainer<holdem::State> {
+        let mut trainer = Trainer::new();
+        Self::run_flop_subset(&mut trainer, roots, subset, iterations);
+        trainer
+    }
+
+    /// 기존 학습기(시드, 터미널 평가기 등 설정 유지)로 플랍 부분집합 학습
+    ///
+    /// 모든 루트의 플랍 찬스 노드가 부분집합에서만 딜링하며, 사용한 설정은
+    /// 학습기 메타데이터에 기록되어 전략 테이블이 부분집합 밖 보드 조회를 경고할 수 있습니다.
+    pub fn run_flop_subset(
+        trainer: &mut Trainer<holdem::State>,
+        roots: Vec<holdem::State>,
+        subset: &FlopSubsetConfig,
+        iterations: usize,
+    ) {
+        let sampler = std::sync::Arc::new(FlopSampler::new(subset.clone()));
+        let roots = roots
+            .into_iter()
+            .map(|root| holdem::State {
+                flop_sampler: Some(sampler.clone()),
+                ..root
+            })
+            .collect();
+        trainer.metadata.insert(
+            FLOP_SUBSET_METADATA_KEY.to_string(),
+            serde_json::to_string(subset).expect("플랍 부분집합 설정은 항상 JSON으로 직렬화 가능"),
+        );
+        trainer.run(roots, iterations);
+    }
+
     /// 포괄적인 게임 시나리오로 훈련 (느리지만 더 철저함)
     pub fn train_comprehensive_strategy(iterations: usize) -> Trainer<holdem::State> {
         let mut trainer = Trainer::new();
@@ -308,8 +1382,19 @@ impl OfflineTrainer {
                 stack: [2000; 6],
                 alive: [true; 6],
                 invested: [0, 0, 0, 0, 25, 50],
+                antes: [0; 6],
                 to_call: 50,
                 actions_taken: 0,
+                button: 0,
+                rake: None,
+                variant: holdem::HoldemVariant::Standard,
+                flop_sampler: None,
+                betting: holdem::BettingRules::NoLimit,
+                raises_this_street: 0,
+                big_blind: 50,
+                last_raise: 0,
+                straddle: 0,
+                opponent_deal: None,
             },
             // 3벳 시나리오, 콜 시나리오 등 추가...
         ]
@@ -328,8 +1413,19 @@ impl OfflineTrainer {
                 stack: [1000, 1000, 0, 0, 0, 0],
                 alive: [true, true, false, false, false, false],
                 invested: [0, 0, 0, 0, 0, 0],
+                antes: [0; 6],
                 to_call: 0,
                 actions_taken: 0,
+                button: 0,
+                rake: None,
+                variant: holdem::HoldemVariant::Standard,
+                flop_sampler: None,
+                betting: holdem::BettingRules::NoLimit,
+                raises_this_street: 0,
+                big_blind: 50,
+                last_raise: 0,
+                straddle: 0,
+                opponent_deal: None,
             },
             // 웻 보드 시나리오 등 추가...
         ]
@@ -348,8 +1444,19 @@ impl OfflineTrainer {
                 stack: [800, 3000, 0, 0, 0, 0], // Short vs big stack
                 alive: [true, true, false, false, false, false],
                 invested: [25, 50, 0, 0, 0, 0],
+                antes: [0; 6],
                 to_call: 50,
                 actions_taken: 0,
+                button: 0,
+                rake: None,
+                variant: holdem::HoldemVariant::Standard,
+                flop_sampler: None,
+                betting: holdem::BettingRules::NoLimit,
+                raises_this_street: 0,
+                big_blind: 50,
+                last_raise: 0,
+                straddle: 0,
+                opponent_deal: None,
             },
         ]
     }
@@ -359,6 +1466,7 @@ impl OfflineTrainer {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "training")]
     #[test]
     fn test_web_api_basic() {
         // 기본 오프라인 학습 (1회로 축소, 간단한 전략 사용)
@@ -380,6 +1488,9 @@ mod tests {
             player_to_act: 0,
             hero_position: 0,
             betting_history: vec![],
+            seat_status: vec![],
+            posted: vec![],
+            straddle: None,
         };
 
         let response = api.get_optimal_strategy(game_state);
@@ -390,6 +1501,7 @@ mod tests {
         println!("전략 응답: {:?}", response);
     }
 
+    #[cfg(feature = "training")]
     #[test]
     fn test_stateless_multiple_requests() {
         let trainer = OfflineTrainer::train_simple_strategy(1);
@@ -409,6 +1521,9 @@ mod tests {
                 player_to_act: 0,
                 hero_position: 0,
                 betting_history: vec![],
+                seat_status: vec![],
+                posted: vec![],
+                straddle: None,
             },
             WebGameState {
                 hole_cards: [26, 39],    // KQ suited
@@ -422,6 +1537,9 @@ mod tests {
                 player_to_act: 0,
                 hero_position: 0,
                 betting_history: vec![],
+                seat_status: vec![],
+                posted: vec![],
+                straddle: None,
             },
         ];
 
@@ -435,4 +1553,413 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_strategy_cache_lru_and_ttl() {
+        let cache = StrategyCache::new(StrategyCacheConfig {
+            capacity: 2,
+            shards: 1,
+            ttl: Duration::from_secs(60),
+        });
+        cache.insert(1u32, "a");
+        cache.insert(2u32, "b");
+        assert_eq!(cache.get(&1), Some("a")); // 1을 최근 사용으로 갱신
+        cache.insert(3u32, "c"); // 가장 오래된 2가 축출됨
+
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&3), Some("c"));
+        let stats = cache.cache_stats();
+        assert_eq!((stats.entries, stats.evictions), (2, 1));
+        assert_eq!((stats.hits, stats.misses), (2, 1));
+
+        let expiring = StrategyCache::new(StrategyCacheConfig {
+            ttl: Duration::ZERO,
+            ..Default::default()
+        });
+        expiring.insert(1u32, 1u32);
+        std::thread::sleep(Duration::from_millis(2));
+        assert_eq!(expiring.get(&1), None);
+        assert_eq!(expiring.cache_stats().expirations, 1);
+    }
+
+    #[cfg(feature = "training")]
+    #[test]
+    fn test_cached_web_api_matches_uncached() {
+        let trainer = OfflineTrainer::train_simple_strategy(1);
+        let plain = PokerWebAPI::new(&trainer);
+        let cached = PokerWebAPI::with_cache(&trainer, StrategyCacheConfig::default());
+
+        let base = WebGameState {
+            hole_cards: [0, 13],
+            board: vec![],
+            street: 0,
+            pot: 150,
+            stacks: vec![1000, 1000],
+            alive_players: vec![0, 1],
+            street_investments: vec![50, 100],
+            to_call: 0,
+            player_to_act: 0,
+            hero_position: 0,
+            betting_history: vec![],
+            seat_status: vec![],
+            posted: vec![],
+            straddle: None,
+        };
+        // 콜 금액만 다른 상황은 절대 엔트리를 공유하면 안 됨
+        let facing_bet = WebGameState {
+            to_call: 100,
+            ..base.clone()
+        };
+
+        for state in [base.clone(), facing_bet.clone(), facing_bet, base] {
+            let expected = plain.get_optimal_strategy(state.clone());
+            let actual = cached.get_optimal_strategy(state);
+            assert_eq!(actual.strategy, expected.strategy);
+            assert_eq!(actual.recommended_action, expected.recommended_action);
+            assert_eq!(actual.expected_value, expected.expected_value);
+        }
+
+        let stats = cached.cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses), (2, 2));
+        assert!(plain.cache_stats().is_none());
+    }
+
+    fn bucket_test_state(stack: u32) -> WebGameState {
+        WebGameState {
+            hole_cards: [0, 13],
+            board: vec![47, 21, 34],
+            street: 1,
+            pot: 400,
+            stacks: vec![stack, stack],
+            alive_players: vec![0, 1],
+            street_investments: vec![0, 0],
+            to_call: 0,
+            player_to_act: 0,
+            hero_position: 0,
+            betting_history: vec![],
+            seat_status: vec![],
+            posted: vec![],
+            straddle: None,
+        }
+    }
+
+    // 요청 상태를 각 구간 깊이로 옮긴 키에 지정한 전략을 넣은 구간 테이블
+    fn two_bucket_table(state: &WebGameState) -> StrategyTable {
+        let keyer = StrategyTable::from_buckets(Vec::new(), 100);
+        let internal = keyer.web_to_internal_state(state);
+        let bucket = |depth: u32, strategy: Vec<f64>| {
+            let scaled = keyer.rescale_to_depth(&internal, 0, depth);
+            let mut strategies = HashMap::new();
+            strategies.insert(holdem::State::info_key(&scaled, 0), strategy);
+            StackBucket {
+                stack_bb: Some(depth),
+                strategies: StrategyPayload::Raw(strategies),
+                visits: HashMap::new(),
+                flop_subset: None,
+            }
+        };
+        StrategyTable::from_buckets(
+            vec![bucket(40, vec![0.0, 0.2, 0.8]), bucket(20, vec![0.0, 0.6, 0.4])],
+            100,
+        )
+    }
+
+    #[test]
+    fn test_stack_bucket_exact_depth_unchanged() {
+        let at_20bb = bucket_test_state(2000);
+        let table = two_bucket_table(&at_20bb);
+        assert_eq!(table.stack_buckets(), vec![20, 40]);
+        assert_eq!(table.lookup_strategy(&at_20bb), Some(vec![0.0, 0.6, 0.4]));
+
+        let at_40bb = bucket_test_state(4000);
+        assert_eq!(two_bucket_table(&at_40bb).lookup_strategy(&at_40bb), Some(vec![0.0, 0.2, 0.8]));
+
+        // 가장 얕은/깊은 구간 밖은 가까운 구간 사용
+        let shallow = bucket_test_state(1000);
+        assert_eq!(two_bucket_table(&shallow).lookup_strategy(&shallow), Some(vec![0.0, 0.6, 0.4]));
+    }
+
+    #[test]
+    fn test_stack_bucket_interpolation_between_depths() {
+        let at_30bb = bucket_test_state(3000);
+        let table = two_bucket_table(&at_30bb);
+
+        let mixed = table.lookup_strategy(&at_30bb).expect("두 구간 모두 학습된 상황");
+        assert!((mixed.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(mixed[1] > 0.2 && mixed[1] < 0.6, "{:?}", mixed);
+        assert!(mixed[2] > 0.4 && mixed[2] < 0.8, "{:?}", mixed);
+
+        let nearest = table.with_selection(BucketSelection::Nearest);
+        assert_eq!(nearest.lookup_strategy(&at_30bb), Some(vec![0.0, 0.6, 0.4]));
+    }
+
+    #[cfg(feature = "training")]
+    #[test]
+    fn test_stack_bucket_table_round_trip() {
+        let table = OfflineTrainer::train_stack_buckets(&StackBucketConfig {
+            depths_bb: vec![40, 10],
+            big_blind: 100,
+            iterations: 1,
+        });
+        let restored = StrategyTable::from_bytes(&table.to_bytes().unwrap()).unwrap();
+        assert_eq!(restored.stack_buckets(), vec![10, 40]);
+        assert_eq!(restored.big_blind, 100);
+
+        let state = bucket_test_state(2500);
+        assert_eq!(restored.lookup_strategy(&state), table.lookup_strategy(&state));
+        let api = PokerWebAPI::from_table(restored, None);
+        assert!(!api.get_optimal_strategy(state).strategy.is_empty());
+
+        assert!(StrategyTable::from_bytes(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_compacted_table_round_trip() {
+        let state = bucket_test_state(2500);
+        let raw = two_bucket_table(&state);
+        let expected = raw.lookup_strategy(&state).unwrap();
+
+        let compact = two_bucket_table(&state).compacted();
+        assert!(compact
+            .buckets
+            .iter()
+            .all(|bucket| matches!(bucket.strategies, StrategyPayload::Compact(_))));
+        let restored = StrategyTable::from_bytes(&compact.to_bytes().unwrap()).unwrap();
+        let actual = restored.lookup_strategy(&state).unwrap();
+        for (a, e) in actual.iter().zip(&expected) {
+            assert!((a - e).abs() <= 1e-3, "{:?} vs {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_table_fingerprint_is_verified_on_load() {
+        use crate::solver::compat::{FingerprintComponent, INFO_KEY_SCHEMA_VERSION};
+
+        let state = bucket_test_state(2500);
+        let expected = two_bucket_table(&state).lookup_strategy(&state);
+        let current = holdem::State::fingerprint(None);
+
+        // 같은 지문은 그대로 로드
+        let restored = StrategyTable::from_bytes(&two_bucket_table(&state).to_bytes().unwrap()).unwrap();
+        assert_eq!(restored.fingerprint(), &current);
+        assert_eq!(restored.lookup_strategy(&state), expected);
+
+        // 다른 액션 추상화로 저장한 테이블은 구성 요소 이름과 함께 거부
+        let mut other_ladder = current.clone();
+        other_ladder.action_abstraction.push("Raise(2)".to_string());
+        let bytes = two_bucket_table(&state).with_fingerprint(other_ladder).to_bytes().unwrap();
+        let error = StrategyTable::from_bytes(&bytes).err().unwrap();
+        assert!(error.contains("action_abstraction"), "{}", error);
+        match StrategyTable::from_bytes_with_migrations(&bytes, &MigrationRegistry::builtin()) {
+            Err(StrategyTableError::Incompatible(error)) => {
+                assert_eq!(error.mismatched, vec![FingerprintComponent::ActionAbstraction])
+            }
+            _ => panic!("액션 추상화가 다른 테이블이 로드됨"),
+        }
+
+        // 키 스키마 버전만 다르면 등록된 마이그레이션(여기서는 키 그대로)으로 로드
+        let older_schema = CompatibilityFingerprint {
+            info_key_schema: INFO_KEY_SCHEMA_VERSION - 1,
+            ..current.clone()
+        };
+        let bytes = two_bucket_table(&state).compacted().with_fingerprint(older_schema).to_bytes().unwrap();
+        let error = StrategyTable::from_bytes(&bytes).err().unwrap();
+        assert!(error.contains("info_key_schema"), "{}", error);
+
+        let mut registry = MigrationRegistry::new();
+        registry.register(
+            FingerprintComponent::InfoKeySchema,
+            INFO_KEY_SCHEMA_VERSION - 1,
+            INFO_KEY_SCHEMA_VERSION,
+            |key| key,
+        );
+        let migrated = StrategyTable::from_bytes_with_migrations(&bytes, &registry).unwrap();
+        assert_eq!(migrated.fingerprint(), &current);
+        let actual = migrated.lookup_strategy(&state).unwrap();
+        for (a, e) in actual.iter().zip(expected.as_ref().unwrap()) {
+            assert!((a - e).abs() <= 1e-3, "{:?} vs {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_blend_policy_trusts_well_visited_nodes() {
+        let rare = bucket_test_state(2000);
+        let common = WebGameState {
+            pot: 600,
+            ..bucket_test_state(2000)
+        };
+        let keyer = StrategyTable::from_buckets(Vec::new(), 100);
+        let key = |state: &WebGameState| holdem::State::info_key(&keyer.web_to_internal_state(state), 0);
+        let bucket = StackBucket {
+            stack_bb: None,
+            strategies: StrategyPayload::Raw(
+                [(key(&rare), vec![0.0, 0.0, 1.0]), (key(&common), vec![0.0, 0.0, 1.0])]
+                    .into_iter()
+                    .collect(),
+            ),
+            visits: [(key(&rare), 2), (key(&common), 10_000)].into_iter().collect(),
+            flop_subset: None,
+        };
+        let policy = BlendPolicy {
+            min_visits_full_trust: 1_000,
+            fallback: FallbackSource::Heuristic,
+        };
+        let api = PokerWebAPI::from_table(StrategyTable::from_buckets(vec![bucket.clone()], 100), None)
+            .with_blend_policy(policy);
+
+        // 체크 가능한 상황의 휴리스틱: 콜 0.7, 레이즈 0.2, 폴드 0.1
+        let rare_response = api.get_optimal_strategy(rare.clone());
+        assert_eq!(rare_response.trust_weight, 0.002);
+        assert_eq!(rare_response.recommended_action, "call");
+        assert!((rare_response.strategy["call"] - 0.7).abs() < 0.01, "{:?}", rare_response.strategy);
+
+        let common_response = api.get_optimal_strategy(common);
+        assert_eq!(common_response.trust_weight, 1.0);
+        assert_eq!(common_response.strategy["raise_small"], 1.0);
+
+        // 가능한 액션만 포함한 확률 분포
+        for response in [&rare_response, &common_response] {
+            let mut names: Vec<&str> = response.strategy.keys().map(String::as_str).collect();
+            names.sort_unstable();
+            assert_eq!(names, ["call", "fold", "raise_small"]);
+            assert!((response.strategy.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+
+        // 정책이 없으면 방문 횟수와 무관하게 학습된 전략 그대로
+        let plain = PokerWebAPI::from_table(StrategyTable::from_buckets(vec![bucket], 100), None);
+        let response = plain.get_optimal_strategy(rare);
+        assert_eq!(response.strategy["raise_small"], 1.0);
+        assert_eq!(response.trust_weight, 1.0);
+    }
+
+    /// 도달한 터미널 보드를 기록하는 평가기
+    #[cfg(feature = "training")]
+    struct BoardRecorder(std::sync::Arc<Mutex<Vec<Vec<u8>>>>);
+
+    #[cfg(feature = "training")]
+    impl TerminalEvaluator<holdem::State> for BoardRecorder {
+        fn evaluate(&self, state: &holdem::State) -> Vec<f64> {
+            self.0.lock().unwrap().push(state.board.clone());
+            (0..6).map(|player| holdem::State::util(state, player)).collect()
+        }
+    }
+
+    #[cfg(feature = "training")]
+    #[test]
+    fn test_flop_subset_training_and_coverage_warning() {
+        use crate::game::card_abstraction::hand_class::make_card;
+        use crate::game::flop_subset::{canonical_flop, FlopSet, FlopWeighting};
+
+        let flops = vec![
+            [make_card(12, 0), make_card(11, 1), make_card(5, 2)], // AsKh7d
+            [make_card(9, 1), make_card(8, 1), make_card(2, 1)],   // JhTh4h
+            [make_card(6, 0), make_card(6, 2), make_card(1, 3)],   // 8s8d3c
+        ];
+        let subset = FlopSubsetConfig {
+            subset: FlopSet::Custom(flops.clone()),
+            weighting: FlopWeighting::Natural,
+        };
+        let boards = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let mut trainer = Trainer::with_evaluator(BoardRecorder(boards.clone()));
+        trainer.set_seed(3);
+        let root = holdem::State::new_hand([50, 100], [300; 6], 2);
+        OfflineTrainer::run_flop_subset(&mut trainer, vec![root], &subset, 5);
+
+        let canonical: Vec<[u8; 3]> = flops.iter().map(|&flop| canonical_flop(flop)).collect();
+        let boards = boards.lock().unwrap();
+        let postflop: Vec<&Vec<u8>> = boards.iter().filter(|board| board.len() >= 3).collect();
+        assert!(!postflop.is_empty());
+        for board in postflop {
+            assert!(canonical.contains(&canonical_flop([board[0], board[1], board[2]])), "{:?}", board);
+        }
+
+        // 메타데이터로 남은 부분집합 밖의 플랍은 조회 시 경고
+        let table = StrategyTable::from_trained_cfr(&trainer);
+        let outside = bucket_test_state(2000); // 9c 9h 9d
+        let response = table.get_strategy(&outside);
+        assert_eq!(response.warnings.len(), 1, "{:?}", response.warnings);
+
+        let inside = WebGameState {
+            board: vec![make_card(12, 3), make_card(11, 0), make_card(5, 1)], // AcKs7h
+            ..bucket_test_state(2000)
+        };
+        assert!(table.get_strategy(&inside).warnings.is_empty());
+        let preflop = WebGameState {
+            board: vec![],
+            street: 0,
+            ..bucket_test_state(2000)
+        };
+        assert!(table.get_strategy(&preflop).warnings.is_empty());
+
+        // 전체 보드로 학습한 테이블은 경고하지 않음
+        let mut full = Trainer::new();
+        full.run(vec![holdem::State::new_hand([50, 100], [300; 6], 2)], 1);
+        let full = StrategyTable::from_trained_cfr(&full);
+        assert!(full.get_strategy(&outside).warnings.is_empty());
+    }
+
+    #[test]
+    fn test_six_max_trained_lookup_uses_training_button() {
+        // 6맥스 학습 루트는 버튼이 3번 좌석이라 UTG(0번 좌석)는 버튼 기준 세 번째 포지션
+        let root = holdem::State::new_hand([50, 100], [150; 6], 6);
+        assert_eq!(root.button, 3);
+        assert_eq!(root.to_act, 0);
+        let mut trainer = Trainer::new();
+        trainer.set_seed(7);
+        trainer.run(vec![root.clone()], 1);
+        let table = StrategyTable::from_trained_cfr(&trainer);
+
+        let request = WebGameState {
+            hole_cards: root.hole[0],
+            board: vec![],
+            street: 0,
+            pot: root.pot,
+            stacks: root.stack.to_vec(),
+            alive_players: (0..6).collect(),
+            street_investments: root.invested.to_vec(),
+            to_call: root.to_call,
+            player_to_act: 0,
+            hero_position: 0,
+            betting_history: vec![],
+            seat_status: vec![],
+            posted: vec![],
+            straddle: None,
+        };
+        let key = holdem::State::info_key(&table.web_to_internal_state(&request), 0);
+        assert_eq!(key, holdem::State::info_key(&root, 0));
+
+        let trained = &trainer.nodes[&key];
+        let (strategy, visits) = table.lookup_with_visits(&request).unwrap();
+        assert_eq!(strategy, trained.average());
+        assert_eq!(visits, trained.visits());
+    }
+
+    #[test]
+    fn test_inferred_last_raise() {
+        // 블라인드만 걸린 프리플랍은 빅블라인드 전체가 증가분
+        assert_eq!(inferred_last_raise(0, &[0, 0, 0, 0, 50, 100]), 100);
+        // 300 오픈 후 900 3벳
+        assert_eq!(inferred_last_raise(0, &[300, 900, 0, 0, 50, 100]), 600);
+        // 포스트플랍 첫 베팅
+        assert_eq!(inferred_last_raise(1, &[0, 250]), 250);
+    }
+
+    #[test]
+    fn test_best_action_breaks_exact_ties_by_canonical_order() {
+        let strategy: StrategyMap =
+            [("raise", 0.4), ("call", 0.4), ("fold", 0.2)].map(|(a, p)| (a.to_string(), p)).into();
+        for _ in 0..10 {
+            assert_eq!(best_action(&strategy), Some("call"));
+        }
+
+        // 베트 크기는 사전순("bet_100pct" < "bet_66pct")이 아니라 크기순으로 비교
+        let bets: StrategyMap =
+            [("bet_100pct", 0.5), ("bet_66pct", 0.5)].map(|(a, p)| (a.to_string(), p)).into();
+        assert_eq!(best_action(&bets), Some("bet_66pct"));
+
+        let with_nan: StrategyMap =
+            [("check", 0.5), ("bet_allin", 0.5), ("fold", f64::NAN)].map(|(a, p)| (a.to_string(), p)).into();
+        assert_eq!(best_action(&with_nan), Some("check"));
+        assert_eq!(best_action(&StrategyMap::new()), None);
+    }
 }
@@ -2,11 +2,21 @@
 // 정교한 휴리스틱으로 실시간 의사결정
 // 학습 불필요 - 즉석 운영 준비 응답
 
+use crate::api::coaching::{self, ActionAdvice, CoachingInputs, Explanation, Locale};
+use crate::api::tuning::HeuristicParams;
+use crate::api::web_api::{
+    best_action, SituationKey, StrategyCache, StrategyCacheConfig, StrategyCacheStats, StrategyMap,
+};
+use crate::game::hand_eval::classify;
+use crate::game::card_abstraction::hand_percentile;
+use crate::game::poker_math::{geometric_bet_fraction, streets_remaining};
+use crate::game::value_unit::ValueUnit;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// 웹 API 게임 상태 표현
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct WebGameState {
     /// 히어로의 홀카드 [카드1, 카드2] (0-51 범위)
     pub hole_cards: [u8; 2],
@@ -20,27 +30,86 @@ pub struct WebGameState {
     pub to_call: u32,
     /// 칩 단위 히어로의 스택 크기
     pub my_stack: u32,
-    /// 칩 단위 상대방의 스택 크기
+    /// 칩 단위 상대방의 스택 크기 (`opponent_stacks`가 비어 있을 때 사용)
     pub opponent_stack: u32,
+    /// 핸드에 남은 라이브 상대 수 - 폴드한 플레이어는 세지 않음 (기본값 1 - 헤즈업)
+    #[serde(default = "default_num_opponents")]
+    pub num_opponents: u8,
+    /// 버튼 기준 히어로 포지션 (0=버튼, 1=SB, 2=BB, 3=UTG, ..., 기본값 0)
+    #[serde(default)]
+    pub hero_position: u8,
+    /// 히어로 뒤에 아직 액션할 플레이어 수 (기본값 0 - 마지막 액션)
+    #[serde(default)]
+    pub players_behind: u8,
+    /// 칩 단위 상대별 스택 크기 (기본값 빈 목록 - `opponent_stack` 사용)
+    #[serde(default)]
+    pub opponent_stacks: Vec<u32>,
+    /// 칩 단위 죽은 칩 - `pot`에 포함되지 않은, 이미 폴드한 플레이어의 베팅이나 앤티 (기본값 0)
+    ///
+    /// 이긴 사람이 가져가므로 팟 오즈와 블러프 EV는 커지지만 `num_opponents`(라이브 상대 수)는 그대로입니다.
+    #[serde(default)]
+    pub dead_money: u32,
 }
 
+fn default_num_opponents() -> u8 {
+    1
+}
+
+impl WebGameState {
+    /// 상대 수 (최소 1)
+    pub fn opponents(&self) -> u8 {
+        self.num_opponents.max(1)
+    }
+
+    /// 이긴 사람이 가져가는 전체 팟 (`pot` + `dead_money`)
+    pub fn total_pot(&self) -> u32 {
+        self.pot.saturating_add(self.dead_money)
+    }
+
+    /// 가장 큰 상대 스택 (`opponent_stacks`가 비어 있으면 `opponent_stack`)
+    pub fn max_opponent_stack(&self) -> u32 {
+        self.opponent_stacks.iter().copied().max().unwrap_or(self.opponent_stack)
+    }
+
+    /// 히어로 스택과 가장 큰 상대 스택 중 작은 값
+    pub fn effective_stack(&self) -> u32 {
+        self.my_stack.min(self.max_opponent_stack())
+    }
+}
+
+/// 멀티웨이 핸드 강도 지수 (`multiway_strength` 참고)
+const MULTIWAY_EXPONENT: f64 = 0.7;
+
+/// 뒤에 남은 플레이어 한 명당 공격 빈도 감소율
+const POSITION_AGGRESSION_DECAY: f64 = 0.15;
+
+/// 라이브 상대 한 명이 블러프에 폴드할 확률 (블러프는 모든 상대가 폴드해야 성공)
+const BLUFF_FOLD_PROBABILITY: f64 = 0.4;
+
 /// 상세 분석을 포함한 향상된 전략 응답
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StrategyResponse {
-    /// 액션 확률 (예: "fold": 0.2, "call": 0.5, "raise": 0.3)
-    pub strategy: HashMap<String, f64>,
+    /// 액션 확률 (예: "fold": 0.2, "call": 0.5, "raise": 0.3), 키 사전순으로 직렬화
+    pub strategy: StrategyMap,
     /// 권장 주요 액션
     pub recommended_action: String,
     /// 기댓값 추정
     pub expected_value: f64,
+    /// `expected_value`의 단위 (휴리스틱 엔진은 항상 칩)
+    #[serde(default)]
+    pub value_unit: ValueUnit,
     /// 결정 신뢰도 (0.0-1.0)
     pub confidence: f64,
     /// 핸드 강도 평가 (0.0-1.0)
     pub hand_strength: f64,
     /// 팟 오즈 계산
     pub pot_odds: f64,
-    /// 전략적 추론 (디버깅/설명용)
+    /// 전략적 추론 (`explanation`을 기본 로케일로 렌더링한 문장)
     pub reasoning: String,
+    /// 구조화된 결정 설명 (`coaching::render`로 원하는 로케일 렌더링)
+    #[serde(default)]
+    pub explanation: Explanation,
 }
 
 /// 고급 포커 전략 엔진
@@ -54,30 +123,72 @@ pub struct StrategyResponse {
 pub struct QuickPokerAPI {
     /// 프리플랍 핸드 랭킹 조회 테이블
     preflop_rankings: HashMap<(u8, u8, bool), f64>,
+    /// 휴리스틱 임계값/빈도 파라미터
+    params: HeuristicParams,
+    /// 선택적 전략 캐시 (수트 동형 상황 공유)
+    cache: Option<StrategyCache<SituationKey, StrategyResponse>>,
 }
 
 impl QuickPokerAPI {
     /// 사전 계산된 핸드 랭킹으로 포커 API 초기화
     pub fn new() -> Self {
+        Self::with_params(HeuristicParams::classic())
+    }
+
+    /// 지정한 휴리스틱 파라미터로 API 생성 (튜닝 결과 적용용)
+    pub fn with_params(params: HeuristicParams) -> Self {
         let mut preflop_rankings = HashMap::new();
 
         // 프리미엄 핸드 랭킹 초기화
         Self::init_preflop_rankings(&mut preflop_rankings);
 
-        Self { preflop_rankings }
+        Self {
+            preflop_rankings,
+            params,
+            cache: None,
+        }
+    }
+
+    /// 현재 휴리스틱 파라미터
+    pub fn params(&self) -> &HeuristicParams {
+        &self.params
+    }
+
+    /// 전략 캐시를 사용하는 API 생성
+    pub fn with_cache(config: StrategyCacheConfig) -> Self {
+        Self {
+            cache: Some(StrategyCache::new(config)),
+            ..Self::new()
+        }
+    }
+
+    /// 캐시 통계 (캐시를 사용하지 않으면 None)
+    pub fn cache_stats(&self) -> Option<StrategyCacheStats> {
+        self.cache.as_ref().map(|cache| cache.cache_stats())
     }
 
     /// 주어진 게임 상태에 대한 포괄적 전략 계산
     pub fn get_optimal_strategy(&self, state: WebGameState) -> StrategyResponse {
-        // 1. 핵심 지표 계산
-        let hand_strength = self.evaluate_hand_strength(&state);
+        match &self.cache {
+            Some(cache) => cache.get_or_insert_with(SituationKey::from_simple_state(&state), || {
+                self.compute_strategy(state)
+            }),
+            None => self.compute_strategy(state),
+        }
+    }
+
+    /// 캐시 없이 전략 계산
+    fn compute_strategy(&self, state: WebGameState) -> StrategyResponse {
+        // 1. 핵심 지표 계산 (상대 수 반영)
+        let hand_strength = self.live_equity(&state);
         let pot_odds = self.calculate_pot_odds(&state);
         // 2. 정교한 휴리스틱을 기반으로 전략 생성
         let strategy = self.calculate_advanced_strategy(&state, hand_strength, pot_odds);
 
         // 3. 최선의 액션과 근거 결정
         let recommended = self.get_best_action(&strategy);
-        let reasoning = self.generate_reasoning(&state, hand_strength, pot_odds, &recommended);
+        let explanation = self.explain(&state, hand_strength, pot_odds, &recommended);
+        let reasoning = coaching::render(&explanation, Locale::default());
 
         // 4. 기댓값 추정
         let ev = self.estimate_expected_value(&state, &strategy, hand_strength);
@@ -89,10 +200,12 @@ impl QuickPokerAPI {
             strategy,
             recommended_action: recommended,
             expected_value: ev,
+            value_unit: ValueUnit::Chips,
             confidence,
             hand_strength,
             pot_odds,
             reasoning,
+            explanation,
         }
     }
 
@@ -106,7 +219,7 @@ impl QuickPokerAPI {
 
     /// 전체 분석 없이 빠른 추천
     pub fn get_quick_recommendation(&self, state: WebGameState) -> String {
-        let hand_strength = self.evaluate_hand_strength(&state);
+        let hand_strength = self.live_equity(&state);
         let pot_odds = self.calculate_pot_odds(&state);
 
         if state.to_call == 0 {
@@ -129,108 +242,170 @@ impl QuickPokerAPI {
         state: &WebGameState,
         hand_strength: f64,
         pot_odds: f64,
-    ) -> HashMap<String, f64> {
-        let mut strategy = HashMap::new();
+    ) -> StrategyMap {
+        let mut strategy = StrategyMap::new();
 
-        let effective_stack = state.my_stack.min(state.opponent_stack) as f64;
-        let stack_to_pot_ratio = if state.pot > 0 {
-            effective_stack / state.pot as f64
+        let effective_stack = state.effective_stack() as f64;
+        let stack_to_pot_ratio = if state.total_pot() > 0 {
+            effective_stack / state.total_pot() as f64
         } else {
             effective_stack / 100.0
         };
 
-        let bet_size_factor = if stack_to_pot_ratio > 10.0 {
-            0.5 // 딥 스택에서 작은 베트
-        } else if stack_to_pot_ratio > 5.0 {
-            0.75 // 중간 베트
-        } else {
-            1.2 // 큰 베트 / 숏 스택에서 올인
-        };
-
         if state.to_call == 0 {
             // 체크/베트 상황
             self.calculate_check_bet_strategy(
                 &mut strategy,
                 hand_strength,
-                bet_size_factor,
+                state.street,
                 stack_to_pot_ratio,
+                state.opponents(),
             )
         } else {
             // 콜/폴드/레이즈 상황
-            self.calculate_call_fold_strategy(
-                &mut strategy,
-                hand_strength,
-                pot_odds,
-                bet_size_factor,
-                state,
-            )
+            self.calculate_call_fold_strategy(&mut strategy, hand_strength, pot_odds, state)
         }
 
+        // 포지션: 뒤에 남은 플레이어가 많을수록 덜 공격적으로
+        Self::apply_players_behind(&mut strategy, state);
+
         // 확률 정규화
         self.normalize_strategy(&mut strategy);
         strategy
     }
 
+    /// 라이브 상대 전원에 대한 핸드 강도 (헤즈업 강도에 `multiway_strength` 적용)
+    fn live_equity(&self, state: &WebGameState) -> f64 {
+        Self::multiway_strength(self.evaluate_hand_strength(state), state.opponents())
+    }
+
+    /// 상대 N명에 대한 핸드 강도 근사 (헤즈업 강도 `strength` 기준)
+    ///
+    /// 모든 상대를 각각 이겨야 하므로 강도를 `strength^(N^0.7)`로 낮춥니다.
+    /// 독립 가정(`strength^N`)은 상대 핸드 간 상관을 무시해 지나치게 낮으므로 지수를 줄였습니다.
+    /// 예: AJo(0.65)는 상대 4명에서 약 0.32 (랜덤 4명 상대 실제 에퀴티 약 0.30).
+    pub fn multiway_strength(strength: f64, opponents: u8) -> f64 {
+        if opponents <= 1 {
+            return strength;
+        }
+        strength.clamp(0.0, 1.0).powf((opponents as f64).powf(MULTIWAY_EXPONENT))
+    }
+
+    /// 뒤에 남은 플레이어 수만큼 레이즈/베트 빈도를 줄이고 줄어든 빈도를 체크/콜로 옮김
+    fn apply_players_behind(strategy: &mut StrategyMap, state: &WebGameState) {
+        if state.players_behind == 0 {
+            return;
+        }
+        let keep = 1.0 / (1.0 + POSITION_AGGRESSION_DECAY * state.players_behind as f64);
+        let mut moved = 0.0;
+        for (action, prob) in strategy.iter_mut() {
+            if action == "raise" || action.starts_with("bet_") {
+                moved += *prob * (1.0 - keep);
+                *prob *= keep;
+            }
+        }
+        let passive = if state.to_call == 0 { "check" } else { "call" };
+        *strategy.entry(passive.to_string()).or_insert(0.0) += moved;
+    }
+
     /// 체크/베트 상황에 대한 전략 계산
+    ///
+    /// 베팅 후보 크기는 geometric 사이징으로 정합니다:
+    /// - 큰 베트: 남은 스트리트 동안 리버에 올인이 되는 크기
+    /// - 작은 베트: 한 스트리트를 더 나눠 쓰는 크기
+    ///
+    /// 전략 키에는 팟 대비 크기가 포함됩니다 (예: "bet_66pct", "bet_allin").
+    /// 블러프는 모든 상대가 폴드해야 성공하므로 블러프 빈도를 상대 수로 나눕니다.
     fn calculate_check_bet_strategy(
         &self,
-        strategy: &mut HashMap<String, f64>,
+        strategy: &mut StrategyMap,
         hand_strength: f64,
-        _bet_factor: f64,
+        street: u8,
         spr: f64,
+        opponents: u8,
     ) {
-        if hand_strength > 0.85 {
+        let streets = streets_remaining(street);
+        let small_key = Self::bet_key(geometric_bet_fraction(spr, streets + 1), spr);
+        let large_key = Self::bet_key(geometric_bet_fraction(spr, streets), spr);
+
+        let params = &self.params;
+        let (check, small, large) = if hand_strength > params.premium_cutoff {
             // 프리미엄 핸드: 대부분 밸류 베트
-            strategy.insert("check".to_string(), 0.15);
-            strategy.insert("bet_small".to_string(), 0.3);
-            strategy.insert("bet_large".to_string(), 0.55);
-        } else if hand_strength > 0.7 {
+            (0.15, 0.3, 0.55)
+        } else if hand_strength > params.strong_cutoff {
             // 강한 핸드: 균형 잡힌 접근
-            strategy.insert("check".to_string(), 0.4);
-            strategy.insert("bet_small".to_string(), 0.45);
-            strategy.insert("bet_large".to_string(), 0.15);
-        } else if hand_strength > 0.55 {
+            (0.4, 0.45, 0.15)
+        } else if hand_strength > params.medium_cutoff {
             // 중간 핸드: 대부분 체크, 일부 씬 밸류
-            strategy.insert("check".to_string(), 0.7);
-            strategy.insert("bet_small".to_string(), 0.25);
-            strategy.insert("bet_large".to_string(), 0.05);
-        } else if hand_strength > 0.3 {
+            (0.7, 0.25, 0.05)
+        } else if hand_strength > params.bluff_cutoff {
             // 블러프 잠재력이 있는 약한 핸드
-            let bluff_freq = if spr > 8.0 { 0.15 } else { 0.25 };
-            strategy.insert("check".to_string(), 1.0 - bluff_freq);
-            strategy.insert("bet_small".to_string(), bluff_freq * 0.8);
-            strategy.insert("bet_large".to_string(), bluff_freq * 0.2);
+            let base_freq = if spr > 8.0 {
+                params.bluff_freq_deep
+            } else {
+                params.bluff_freq_shallow
+            };
+            let bluff_freq = base_freq / opponents.max(1) as f64;
+            (1.0 - bluff_freq, bluff_freq * 0.8, bluff_freq * 0.2)
         } else {
             // 매우 약한 핸드: 대부분 체크
-            strategy.insert("check".to_string(), 0.9);
-            strategy.insert("bet_small".to_string(), 0.08);
-            strategy.insert("bet_large".to_string(), 0.02);
+            (0.9, 0.08, 0.02)
+        };
+
+        strategy.insert("check".to_string(), check);
+        // 두 크기가 같아지면 (숏 스택 등) 확률을 합침
+        *strategy.entry(small_key).or_insert(0.0) += small;
+        *strategy.entry(large_key).or_insert(0.0) += large;
+    }
+
+    /// 베팅 크기를 전략 키로 변환 ("bet_75pct", 스택 전체면 "bet_allin")
+    fn bet_key(fraction: f64, spr: f64) -> String {
+        if fraction >= spr {
+            "bet_allin".to_string()
+        } else {
+            format!("bet_{}pct", (fraction * 100.0).round() as u32)
         }
     }
 
+    /// 전략 키에서 팟 대비 베팅 비율 추출 ("bet_66pct" -> 0.66)
+    pub(crate) fn bet_fraction_from_key(action: &str, spr: f64) -> Option<f64> {
+        let size = action.strip_prefix("bet_")?;
+        if size == "allin" {
+            return Some(spr);
+        }
+        size.strip_suffix("pct")?
+            .parse::<u32>()
+            .ok()
+            .map(|pct| pct as f64 / 100.0)
+    }
+
     /// 콜/폴드/레이즈 상황에 대한 전략 계산
     fn calculate_call_fold_strategy(
         &self,
-        strategy: &mut HashMap<String, f64>,
+        strategy: &mut StrategyMap,
         hand_strength: f64,
         pot_odds: f64,
-        _bet_factor: f64,
         state: &WebGameState,
     ) {
-        let call_requirement = pot_odds + 0.05; // 콜하려면 약간의 우위 필요
-        let raise_threshold = 0.7; // 레이즈하려면 강한 핸드 필요
+        let params = &self.params;
+        let call_requirement = pot_odds + params.call_margin; // 콜하려면 약간의 우위 필요
+        let raise_threshold = params.raise_threshold; // 레이즈하려면 강한 핸드 필요
 
-        let facing_large_bet = state.to_call > state.pot / 2;
+        let facing_large_bet = state.to_call > state.total_pot() / 2;
         let stack_commitment = state.to_call as f64 / state.my_stack as f64;
 
-        if hand_strength > 0.9 {
+        if hand_strength > params.nut_threshold {
             // 넛/넛에 가까움: 거의 항상 레이즈/콜
             strategy.insert("fold".to_string(), 0.02);
             strategy.insert("call".to_string(), 0.23);
             strategy.insert("raise".to_string(), 0.75);
         } else if hand_strength > raise_threshold {
             // 강한 핸드: 대부분 콜/레이즈
-            let raise_freq = if facing_large_bet { 0.4 } else { 0.6 };
+            let raise_freq = if facing_large_bet {
+                params.raise_freq_large_bet
+            } else {
+                params.raise_freq_small_bet
+            };
             strategy.insert("fold".to_string(), 0.05);
             strategy.insert("call".to_string(), 0.95 - raise_freq);
             strategy.insert("raise".to_string(), raise_freq);
@@ -248,8 +423,8 @@ impl QuickPokerAPI {
                 strategy.insert("raise".to_string(), 0.05);
             }
         } else if hand_strength > 0.2 && !facing_large_bet {
-            // 약한 핸드 - 간헐적 블러프 레이즈
-            let bluff_freq = 0.1;
+            // 약한 핸드 - 간헐적 블러프 레이즈 (멀티웨이에서는 상대 수만큼 감소)
+            let bluff_freq = params.bluff_raise_freq / state.opponents() as f64;
             strategy.insert("fold".to_string(), 0.9 - bluff_freq);
             strategy.insert("call".to_string(), 0.05);
             strategy.insert("raise".to_string(), bluff_freq);
@@ -319,117 +494,85 @@ impl QuickPokerAPI {
         if state.to_call == 0 {
             1.0 // No call required
         } else {
-            state.pot as f64 / (state.pot + state.to_call) as f64
+            state.total_pot() as f64 / (state.total_pot() as f64 + state.to_call as f64)
         }
     }
 
-    /// 전략적 추론 설명 생성  
-    fn generate_reasoning(
-        &self,
-        state: &WebGameState,
-        hand_strength: f64,
-        pot_odds: f64,
-        action: &str,
-    ) -> String {
-        let mut reasoning = String::new();
-
-        // 핸드 스트렝스 평가
-        if hand_strength > 0.8 {
-            reasoning.push_str("프리미엄 핸드 스트렝스. ");
-        } else if hand_strength > 0.6 {
-            reasoning.push_str("좋은 핸드 스트렝스. ");
-        } else if hand_strength > 0.4 {
-            reasoning.push_str("한계적 핸드 스트렝스. ");
-        } else {
-            reasoning.push_str("약한 핸드 스트렝스. ");
-        }
-
-        // Pot odds analysis
-        if state.to_call > 0 {
-            if hand_strength > pot_odds + 0.1 {
-                reasoning.push_str("Favorable pot odds support calling/raising. ");
-            } else if hand_strength > pot_odds - 0.05 {
-                reasoning.push_str("Marginal pot odds situation. ");
-            } else {
-                reasoning.push_str("Poor pot odds suggest folding. ");
-            }
-        }
-
-        // Stack depth considerations
-        let effective_stack = state.my_stack.min(state.opponent_stack);
-        let spr = effective_stack as f64 / state.pot as f64;
-
-        if spr > 10.0 {
-            reasoning.push_str("Deep stacks allow for post-flop play. ");
-        } else if spr < 3.0 {
-            reasoning.push_str("Short stacks favor aggressive play. ");
-        }
-
-        // Action justification
-        match action {
-            "fold" => reasoning.push_str("Folding to minimize losses."),
-            "check" => reasoning.push_str("Checking to control pot size."),
-            "call" => reasoning.push_str("Calling to see next card."),
-            "bet_small" | "raise" => reasoning.push_str("Betting for value/protection."),
-            "bet_large" => reasoning.push_str("Large bet for maximum value."),
-            _ => reasoning.push_str("Standard play."),
-        }
-
-        reasoning
+    /// 결정 설명 생성 (팟 오즈는 팟 비중, 콜에 필요한 에퀴티로 바꿔 전달)
+    fn explain(&self, state: &WebGameState, hand_strength: f64, pot_odds: f64, action: &str) -> Explanation {
+        let inputs = CoachingInputs {
+            hand_strength,
+            required_equity: (state.to_call > 0).then_some(1.0 - pot_odds),
+            players_behind: state.players_behind as usize,
+            spr: state.effective_stack() as f64 / state.total_pot().max(1) as f64,
+            fold_equity: None,
+            icm_pressure: None,
+            made_hand: (!state.board.is_empty())
+                .then(|| classify(&[&state.hole_cards[..], &state.board].concat())),
+        };
+        coaching::explain(&inputs, ActionAdvice::from_action_key(action).unwrap_or_default())
     }
 
-    /// 전략의 기댓값 추정
+    /// 전략의 기댓값 추정 (액션별 EV의 확률 가중 합, 기준점은 [`Self::action_ev`] 참고)
     fn estimate_expected_value(
         &self,
         state: &WebGameState,
-        strategy: &HashMap<String, f64>,
+        strategy: &StrategyMap,
         hand_strength: f64,
     ) -> f64 {
-        let mut ev = 0.0;
-        let win_rate = hand_strength;
-
-        for (action, prob) in strategy {
-            let action_ev = match action.as_str() {
-                "fold" => {
-                    if state.to_call > 0 {
-                        -(state.to_call as f64 * 0.1) // Small loss for folding
-                    } else {
-                        0.0
-                    }
-                }
-                "check" => {
-                    // 팟 컨트롤 - 핸드 스트렝스에 기반한 작은 양수/음수
-                    (win_rate - 0.5) * state.pot as f64 * 0.3
-                }
-                "call" => {
-                    // EV = (win_rate * pot_size) - (lose_rate * call_amount)
-                    let win_amount = state.pot as f64;
-                    let lose_amount = state.to_call as f64;
-                    (win_rate * win_amount) - ((1.0 - win_rate) * lose_amount)
-                }
-                "bet_small" => {
-                    let bet_size = (state.pot as f64 * 0.5).max(50.0);
-                    if win_rate > 0.6 {
-                        bet_size * 0.4 // Good value bet
-                    } else {
-                        bet_size * -0.2 // Bluff that usually fails
-                    }
+        strategy
+            .iter()
+            .map(|(action, prob)| prob * self.action_ev(state, action, hand_strength))
+            .sum()
+    }
+
+    /// 한 액션의 EV 추정
+    ///
+    /// 기준점은 현재 의사결정 시점입니다. 이미 팟에 넣은 칩은 매몰 비용이므로 폴드 EV는 정확히 0이고,
+    /// 다른 액션은 지금부터 더 넣는 칩과 가져올 팟(`dead_money` 포함)으로 계산합니다.
+    /// `win_rate`는 라이브 상대 전원에 대한 에퀴티(`multiway_strength` 적용값)이고,
+    /// 블러프는 라이브 상대가 모두 폴드해야 성공합니다.
+    fn action_ev(&self, state: &WebGameState, action: &str, win_rate: f64) -> f64 {
+        let pot = state.total_pot() as f64;
+        let spr = state.effective_stack() as f64 / state.total_pot().max(1) as f64;
+
+        match action {
+            "fold" => 0.0,
+            "check" => {
+                // 팟 컨트롤 - 핸드 스트렝스에 기반한 작은 양수/음수
+                (win_rate - 0.5) * pot * 0.3
+            }
+            "call" => {
+                // EV = (win_rate * pot_size) - (lose_rate * call_amount)
+                let lose_amount = state.to_call as f64;
+                (win_rate * pot) - ((1.0 - win_rate) * lose_amount)
+            }
+            bet if bet.starts_with("bet_") => {
+                let fraction = Self::bet_fraction_from_key(bet, spr).unwrap_or(0.5);
+                let bet_size = (pot * fraction).max(50.0);
+                // 베팅이 클수록 밸류에 필요한 핸드 강도도 높아짐
+                if win_rate > 0.55 + 0.15 * fraction.min(1.0) {
+                    bet_size * 0.5 // Value bet
+                } else {
+                    Self::bluff_ev(state, bet_size)
                 }
-                "bet_large" | "raise" => {
-                    let bet_size = (state.pot as f64 * 1.0).max(100.0);
-                    if win_rate > 0.7 {
-                        bet_size * 0.6 // Strong value bet
-                    } else {
-                        bet_size * -0.4 // Expensive bluff
-                    }
+            }
+            "raise" => {
+                let bet_size = pot.max(100.0);
+                if win_rate > 0.7 {
+                    bet_size * 0.6 // Strong value bet
+                } else {
+                    Self::bluff_ev(state, bet_size)
                 }
-                _ => 0.0,
-            };
-
-            ev += prob * action_ev;
+            }
+            _ => 0.0,
         }
+    }
 
-        ev
+    /// 블러프 EV: 라이브 상대가 모두 폴드하면 전체 팟, 한 명이라도 콜하면 베팅 금액을 잃음
+    fn bluff_ev(state: &WebGameState, bet_size: f64) -> f64 {
+        let all_fold = BLUFF_FOLD_PROBABILITY.powi(state.opponents() as i32);
+        all_fold * state.total_pot() as f64 - (1.0 - all_fold) * bet_size
     }
 
     /// 상황 명확성에 기반한 의사결정 신뢰도 계산
@@ -453,8 +596,8 @@ impl QuickPokerAPI {
         }
 
         // Short stack situations are clearer (less postflop play)
-        let effective_stack = state.my_stack.min(state.opponent_stack);
-        if effective_stack < state.pot * 3 {
+        let effective_stack = state.effective_stack();
+        if effective_stack < state.total_pot().saturating_mul(3) {
             confidence += 0.08;
         }
 
@@ -462,7 +605,7 @@ impl QuickPokerAPI {
     }
 
     /// 전략 확률을 1.0으로 정규화
-    fn normalize_strategy(&self, strategy: &mut HashMap<String, f64>) {
+    fn normalize_strategy(&self, strategy: &mut StrategyMap) {
         let total: f64 = strategy.values().sum();
         if total > 0.0 {
             for prob in strategy.values_mut() {
@@ -479,8 +622,8 @@ impl QuickPokerAPI {
             // Preflop evaluation using lookup table
             self.preflop_hand_strength(hole)
         } else {
-            // Postflop evaluation with sophisticated analysis
-            self.postflop_hand_strength(hole, &state.board)
+            // 이 보드에서 가능한 상대 핸드 중 히어로 핸드의 퍼센타일
+            hand_percentile(hole, &state.board)
         }
     }
 
@@ -516,186 +659,9 @@ impl QuickPokerAPI {
         }
     }
 
-    /// 고급 포스트플랍 핸드 스트렝스 평가
-    fn postflop_hand_strength(&self, hole: [u8; 2], board: &[u8]) -> f64 {
-        let hole_ranks: Vec<u8> = hole.iter().map(|&c| c % 13).collect();
-        let hole_suits: Vec<u8> = hole.iter().map(|&c| c / 13).collect();
-        let board_ranks: Vec<u8> = board.iter().map(|&c| c % 13).collect();
-        let board_suits: Vec<u8> = board.iter().map(|&c| c / 13).collect();
-
-        let all_ranks = [hole_ranks.clone(), board_ranks.clone()].concat();
-        let all_suits = [hole_suits.clone(), board_suits.clone()].concat();
-
-        // Count rank frequencies
-        let mut rank_counts = [0u8; 13];
-        for &rank in &all_ranks {
-            rank_counts[rank as usize] += 1;
-        }
-
-        // Count suit frequencies
-        let mut suit_counts = [0u8; 4];
-        for &suit in &all_suits {
-            suit_counts[suit as usize] += 1;
-        }
-
-        // Check for various hand types
-        let pairs = rank_counts.iter().filter(|&&count| count >= 2).count();
-        let trips = rank_counts.iter().filter(|&&count| count >= 3).count();
-        let quads = rank_counts.iter().filter(|&&count| count >= 4).count();
-        let flush_possible = suit_counts.iter().any(|&count| count >= 5);
-
-        // 핸드 스트렝스 평가
-        if quads > 0 {
-            0.95 // Four of a kind
-        } else if trips > 0 && pairs > 1 {
-            0.90 // Full house
-        } else if flush_possible {
-            self.evaluate_flush_strength(&all_ranks, &all_suits)
-        } else if self.has_straight(&all_ranks) {
-            self.evaluate_straight_strength(&all_ranks)
-        } else if trips > 0 {
-            0.75 // Three of a kind
-        } else if pairs >= 2 {
-            0.65 // Two pair
-        } else if pairs == 1 {
-            self.evaluate_pair_strength(&hole_ranks, &board_ranks, &all_ranks)
-        } else {
-            self.evaluate_high_card_strength(&hole_ranks, &all_ranks)
-        }
-    }
-
-    /// 플러시 스트렝스 평가
-    fn evaluate_flush_strength(&self, ranks: &[u8], suits: &[u8]) -> f64 {
-        let mut suit_ranks = [Vec::new(), Vec::new(), Vec::new(), Vec::new()];
-        for (i, &suit) in suits.iter().enumerate() {
-            if i < ranks.len() {
-                suit_ranks[suit as usize].push(ranks[i]);
-            }
-        }
-
-        for suit_cards in &mut suit_ranks {
-            if suit_cards.len() >= 5 {
-                suit_cards.sort_by(|a, b| b.cmp(a)); // Sort descending
-                let top_card = suit_cards[0];
-                return if top_card >= 12 {
-                    0.88
-                } else if top_card >= 10 {
-                    0.85
-                } else {
-                    0.82
-                };
-            }
-        }
-        0.82 // Default flush value
-    }
-
-    /// 스트레이트 확인
-    fn has_straight(&self, ranks: &[u8]) -> bool {
-        let mut unique_ranks: Vec<u8> = ranks.iter().cloned().collect();
-        unique_ranks.sort();
-        unique_ranks.dedup();
-
-        // Check for wheel (A-2-3-4-5)
-        if unique_ranks.contains(&12)
-            && unique_ranks.contains(&0)
-            && unique_ranks.contains(&1)
-            && unique_ranks.contains(&2)
-            && unique_ranks.contains(&3)
-        {
-            return true;
-        }
-
-        // Check for regular straights
-        for window in unique_ranks.windows(5) {
-            if window[4] - window[0] == 4 {
-                return true;
-            }
-        }
-        false
-    }
-
-    /// 스트레이트 스트렝스 평가
-    fn evaluate_straight_strength(&self, ranks: &[u8]) -> f64 {
-        let max_rank = *ranks.iter().max().unwrap_or(&0);
-        if max_rank >= 12 {
-            0.80
-        } else if max_rank >= 10 {
-            0.78
-        } else {
-            0.76
-        }
-    }
-
-    /// 페어 스트렝스 평가
-    fn evaluate_pair_strength(
-        &self,
-        hole_ranks: &[u8],
-        board_ranks: &[u8],
-        all_ranks: &[u8],
-    ) -> f64 {
-        // Find the paired rank
-        let mut rank_counts = [0u8; 13];
-        for &rank in all_ranks {
-            rank_counts[rank as usize] += 1;
-        }
-
-        let paired_rank = rank_counts
-            .iter()
-            .position(|&count| count >= 2)
-            .unwrap_or(0) as u8;
-
-        // Check if we have pocket pair or made pair with hole card
-        let pocket_pair = hole_ranks[0] == hole_ranks[1];
-        let top_pair = hole_ranks.contains(&paired_rank) && board_ranks.contains(&paired_rank);
-
-        let base_strength = match paired_rank {
-            12 => 0.68, // Aces
-            11 => 0.65, // Kings
-            10 => 0.62, // Queens
-            9 => 0.58,  // Jacks
-            8 => 0.55,  // Tens
-            _ => 0.50,  // Lower pairs
-        };
-
-        if pocket_pair {
-            base_strength + 0.05 // Pocket pair bonus
-        } else if top_pair {
-            base_strength
-        } else {
-            base_strength - 0.08 // Lower pair penalty
-        }
-    }
-
-    /// 하이카드 스트렝스 평가
-    fn evaluate_high_card_strength(&self, hole_ranks: &[u8], all_ranks: &[u8]) -> f64 {
-        let max_hole = hole_ranks.iter().max().unwrap_or(&0);
-        let max_all = all_ranks.iter().max().unwrap_or(&0);
-
-        if hole_ranks.contains(max_all) {
-            // We have the top card
-            match max_all {
-                12 => 0.45, // Ace high
-                11 => 0.40, // King high
-                10 => 0.35, // Queen high
-                _ => 0.30,  // Lower high cards
-            }
-        } else {
-            // Our hole cards don't include the board's highest card
-            match max_hole {
-                12 => 0.35, // Ace in hole but not top card
-                11 => 0.30, // King in hole
-                _ => 0.25,  // Lower cards
-            }
-        }
-    }
-
-    /// 최고 액션 추천 받기
-    fn get_best_action(&self, strategy: &HashMap<String, f64>) -> String {
-        strategy
-            .iter()
-            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
-            .map(|(action, _)| action.clone())
-            .unwrap_or_else(|| "check".to_string())
+    /// 최고 액션 추천 받기 (동률이면 표준 액션 순서상 앞선 액션)
+    fn get_best_action(&self, strategy: &StrategyMap) -> String {
+        best_action(strategy).unwrap_or("check").to_string()
     }
 }
 
@@ -715,6 +681,11 @@ mod tests {
             to_call: 100,
             my_stack: 1000,
             opponent_stack: 1000,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
         };
 
         let response = api.get_optimal_strategy(state);
@@ -736,6 +707,11 @@ mod tests {
             to_call: 0,
             my_stack: 900,
             opponent_stack: 900,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
         };
 
         let response = api.get_optimal_strategy(state);
@@ -757,6 +733,11 @@ mod tests {
                 to_call: 50,
                 my_stack: 2000,
                 opponent_stack: 2000,
+                num_opponents: 1,
+                hero_position: 0,
+                players_behind: 0,
+                opponent_stacks: vec![],
+                dead_money: 0,
             },
             WebGameState {
                 hole_cards: [26, 39], // KQ suited
@@ -766,6 +747,11 @@ mod tests {
                 to_call: 0,
                 my_stack: 900,
                 opponent_stack: 900,
+                num_opponents: 1,
+                hero_position: 0,
+                players_behind: 0,
+                opponent_stacks: vec![],
+                dead_money: 0,
             },
         ];
 
@@ -779,4 +765,312 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_geometric_bet_keys() {
+        let api = QuickPokerAPI::new();
+
+        // 턴에서 SPR 1: 베팅은 올인
+        let turn = WebGameState {
+            hole_cards: [0, 13],
+            board: vec![1, 21, 34, 45],
+            street: 2,
+            pot: 1000,
+            to_call: 0,
+            my_stack: 1000,
+            opponent_stack: 1000,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
+        };
+        let response = api.get_optimal_strategy(turn);
+        assert!(response.strategy.contains_key("bet_allin"), "{:?}", response.strategy);
+        assert!(response.strategy.keys().all(|k| k == "check" || k == "bet_allin"));
+
+        // 플랍에서 SPR 13: 큰 베트는 팟 사이즈
+        let flop = WebGameState {
+            hole_cards: [0, 13],
+            board: vec![1, 21, 34],
+            street: 1,
+            pot: 100,
+            to_call: 0,
+            my_stack: 1300,
+            opponent_stack: 1300,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
+        };
+        let response = api.get_optimal_strategy(flop);
+        assert!(response.strategy.contains_key("bet_100pct"), "{:?}", response.strategy);
+        let total: f64 = response.strategy.values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_get_optimal_strategy_adversarial_inputs() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let api = QuickPokerAPI::new();
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let extremes = [0, 1, u32::MAX];
+
+        for i in 0..2000 {
+            let pick = |rng: &mut StdRng| {
+                if i % 3 == 0 {
+                    extremes[rng.gen_range(0..extremes.len())]
+                } else {
+                    rng.gen()
+                }
+            };
+            let state = WebGameState {
+                hole_cards: [rng.gen(), rng.gen()],
+                board: (0..rng.gen_range(0..9)).map(|_| rng.gen()).collect(),
+                street: rng.gen(),
+                pot: pick(&mut rng),
+                to_call: pick(&mut rng),
+                my_stack: pick(&mut rng),
+                opponent_stack: pick(&mut rng),
+                num_opponents: 1,
+                hero_position: 0,
+                players_behind: 0,
+                opponent_stacks: vec![],
+                dead_money: 0,
+            };
+
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                let response = api.get_optimal_strategy(state.clone());
+                api.get_quick_recommendation(state.clone());
+                response
+            }));
+            assert!(result.is_ok(), "패닉 발생: {:?}", state);
+        }
+    }
+
+    #[test]
+    fn test_cache_key_keeps_relevant_fields_apart() {
+        let plain = QuickPokerAPI::new();
+        let cached = QuickPokerAPI::with_cache(StrategyCacheConfig::default());
+
+        let checked_to = WebGameState {
+            hole_cards: [0, 26],
+            board: vec![1, 21, 34],
+            street: 1,
+            pot: 200,
+            to_call: 0,
+            my_stack: 900,
+            opponent_stack: 900,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
+        };
+        // 핸드 클래스/보드 텍스처/팟 구간만 쓰는 조악한 키라면 충돌했을 상황들
+        let variants = [
+            WebGameState { to_call: 150, ..checked_to.clone() },
+            WebGameState { my_stack: 150, ..checked_to.clone() },
+            WebGameState { pot: 210, ..checked_to.clone() },
+        ];
+
+        let first = cached.get_optimal_strategy(checked_to.clone());
+        assert_eq!(first.strategy, plain.get_optimal_strategy(checked_to).strategy);
+        for state in variants {
+            let expected = plain.get_optimal_strategy(state.clone());
+            let actual = cached.get_optimal_strategy(state);
+            assert_eq!(actual.strategy, expected.strategy);
+            assert_eq!(actual.reasoning, expected.reasoning);
+        }
+        assert_eq!(cached.cache_stats().unwrap().hits, 0);
+    }
+
+    #[test]
+    fn test_cache_shares_suit_isomorphic_spots() {
+        let cached = QuickPokerAPI::with_cache(StrategyCacheConfig::default());
+        let spades = WebGameState {
+            hole_cards: [0, 12], // AsKs
+            board: vec![],
+            street: 0,
+            pot: 150,
+            to_call: 100,
+            my_stack: 1000,
+            opponent_stack: 1000,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
+        };
+        let hearts = WebGameState {
+            hole_cards: [25, 13], // KhAh
+            ..spades.clone()
+        };
+
+        let a = cached.get_optimal_strategy(spades);
+        let b = cached.get_optimal_strategy(hearts);
+        assert_eq!(a.strategy, b.strategy);
+        let stats = cached.cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    }
+
+    /// 레이즈/베트 확률 합계
+    fn aggression(response: &StrategyResponse) -> f64 {
+        response
+            .strategy
+            .iter()
+            .filter(|(action, _)| *action == "raise" || action.starts_with("bet_"))
+            .map(|(_, prob)| prob)
+            .sum()
+    }
+
+    #[test]
+    fn test_aggression_drops_with_more_opponents() {
+        let api = QuickPokerAPI::new();
+        // 프리플랍 테이블 기준 AJo (랭크 12, 9 / 다른 수트), 체크로 넘어온 상황
+        let heads_up = WebGameState {
+            hole_cards: [12, 22],
+            board: vec![],
+            street: 0,
+            pot: 150,
+            to_call: 0,
+            my_stack: 1000,
+            opponent_stack: 1000,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
+        };
+
+        let by_opponents: Vec<StrategyResponse> = (1..=4)
+            .map(|n| {
+                api.get_optimal_strategy(WebGameState {
+                    num_opponents: n,
+                    opponent_stacks: vec![1000; n as usize],
+                    ..heads_up.clone()
+                })
+            })
+            .collect();
+        assert!(by_opponents.windows(2).all(|w| w[1].hand_strength < w[0].hand_strength));
+        assert!(by_opponents.windows(2).all(|w| aggression(&w[1]) <= aggression(&w[0])));
+        assert!(
+            aggression(&by_opponents[3]) < aggression(&by_opponents[0]) * 0.5,
+            "1명: {:.3}, 4명: {:.3}",
+            aggression(&by_opponents[0]),
+            aggression(&by_opponents[3])
+        );
+        // 헤즈업 강도는 기존 값 그대로
+        assert_eq!(by_opponents[0].hand_strength, 0.65);
+        assert!((by_opponents[3].hand_strength - 0.32).abs() < 0.01);
+
+        // 뒤에 플레이어가 남아 있으면 같은 핸드라도 덜 공격적
+        let early = api.get_optimal_strategy(WebGameState {
+            hero_position: 3,
+            players_behind: 3,
+            ..heads_up.clone()
+        });
+        let late = api.get_optimal_strategy(heads_up);
+        assert!(aggression(&early) < aggression(&late));
+        let total: f64 = early.strategy.values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_old_json_uses_heads_up_defaults() {
+        let state: WebGameState = serde_json::from_str(
+            r#"{"hole_cards": [12, 22], "board": [], "street": 0, "pot": 150,
+                "to_call": 0, "my_stack": 1000, "opponent_stack": 800}"#,
+        )
+        .unwrap();
+        assert_eq!(state.num_opponents, 1);
+        assert_eq!(state.hero_position, 0);
+        assert_eq!(state.players_behind, 0);
+        assert!(state.opponent_stacks.is_empty());
+        assert_eq!(state.dead_money, 0);
+        assert_eq!(state.effective_stack(), 800);
+
+        let multiway = WebGameState {
+            num_opponents: 2,
+            opponent_stacks: vec![500, 1200],
+            ..state
+        };
+        assert_eq!(multiway.effective_stack(), 1000);
+    }
+
+    #[test]
+    fn test_ev_counts_live_opponents_and_dead_money() {
+        let api = QuickPokerAPI::new();
+        // AJo (랭크 12, 9 / 다른 수트)가 플랍에서 베팅에 직면
+        let heads_up = WebGameState {
+            hole_cards: [0, 22],
+            board: vec![4, 19, 47],
+            street: 1,
+            pot: 400,
+            to_call: 150,
+            my_stack: 2000,
+            opponent_stack: 2000,
+            num_opponents: 1,
+            hero_position: 0,
+            players_behind: 0,
+            opponent_stacks: vec![],
+            dead_money: 0,
+        };
+        let call_ev = |state: &WebGameState| api.action_ev(state, "call", api.live_equity(state));
+
+        // 폴드는 현재 시점 기준으로 더 잃는 칩이 없음
+        assert_eq!(api.action_ev(&heads_up, "fold", api.live_equity(&heads_up)), 0.0);
+        let fold_only: StrategyMap = [("fold".to_string(), 1.0)].into();
+        assert_eq!(api.estimate_expected_value(&heads_up, &fold_only, 0.3), 0.0);
+
+        let multiway = WebGameState {
+            num_opponents: 2,
+            ..heads_up.clone()
+        };
+        assert!(call_ev(&multiway) < call_ev(&heads_up));
+
+        // 죽은 칩은 가져올 팟만 키우고 상대 수는 바꾸지 않음
+        let with_dead_money = WebGameState {
+            dead_money: 200,
+            ..heads_up.clone()
+        };
+        assert_eq!(with_dead_money.opponents(), heads_up.opponents());
+        assert_eq!(api.live_equity(&with_dead_money), api.live_equity(&heads_up));
+        assert!(call_ev(&with_dead_money) > call_ev(&heads_up));
+        assert!(api.calculate_pot_odds(&with_dead_money) > api.calculate_pot_odds(&heads_up));
+    }
+
+    #[test]
+    fn test_identical_runs_serialize_identically() {
+        let state = WebGameState {
+            hole_cards: [12, 25], // AcKd
+            board: vec![11, 24, 37], // Kc Qd Qh
+            street: 1,
+            pot: 300,
+            to_call: 0,
+            my_stack: 900,
+            opponent_stack: 900,
+            num_opponents: 2,
+            hero_position: 1,
+            players_behind: 1,
+            opponent_stacks: vec![900, 700],
+            dead_money: 0,
+        };
+
+        let response = QuickPokerAPI::new().get_optimal_strategy(state.clone());
+        let first = serde_json::to_string(&response).unwrap();
+        let second = serde_json::to_string(&QuickPokerAPI::new().get_optimal_strategy(state)).unwrap();
+        assert_eq!(first, second);
+
+        // 전략은 키 사전순 JSON 객체로 직렬화
+        let mut keys: Vec<&String> = response.strategy.keys().collect();
+        keys.sort();
+        assert!(keys.len() > 1);
+        let positions: Vec<usize> =
+            keys.iter().map(|key| first.find(&format!("\"{}\":", key)).unwrap()).collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]), "{}", first);
+    }
 }
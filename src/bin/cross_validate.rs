@@ -0,0 +1,36 @@
+// EV 교차 검증기 (몬테카를로 EV vs 정확한 리버 열거)
+// 사용법: cargo run --release --bin cross_validate [스팟 수] [팟 대비 허용 편차] [시드] [액션당 샘플 수]
+// 허용치를 넘은 스팟은 재현용 JSON(스팟, 두 EV 벡터)으로 출력하고 종료 코드 1로 끝납니다.
+
+use nice_hand_core::solver::validation::{cross_validate_river_with, generate_river_spots, CROSS_VALIDATION_SAMPLES};
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let count: usize = args.next().map_or(1_000, |n| n.parse().expect("스팟 수는 양의 정수여야 합니다"));
+    let tolerance: f64 = args.next().map_or(0.05, |t| t.parse().expect("허용 편차는 실수여야 합니다"));
+    let seed: u64 = args.next().map_or(0, |s| s.parse().expect("시드는 정수여야 합니다"));
+    let samples: usize = args
+        .next()
+        .map_or(CROSS_VALIDATION_SAMPLES, |n| n.parse().expect("샘플 수는 양의 정수여야 합니다"));
+
+    println!("리버 스팟 {}개 교차 검증 중 (허용 편차 {}, 시드 {}, 액션당 {} 샘플)", count, tolerance, seed, samples);
+    let start = std::time::Instant::now();
+    let spots = generate_river_spots(count, seed);
+    let report = cross_validate_river_with(&spots, tolerance, samples);
+
+    for spot in report.flagged_spots() {
+        println!("{}", serde_json::to_string(spot).expect("검증 결과는 항상 JSON으로 직렬화 가능"));
+    }
+    println!(
+        "{}개 검증, {}개 건너뜀, {}개 허용치 초과 - 평균 편차 {:.4}, 최대 편차 {:.4} ({:?})",
+        report.spots.len(),
+        report.skipped.len(),
+        report.flagged.len(),
+        report.mean_deviation(),
+        report.max_deviation(),
+        start.elapsed()
+    );
+    if !report.flagged.is_empty() {
+        std::process::exit(1);
+    }
+}
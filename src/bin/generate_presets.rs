@@ -0,0 +1,44 @@
+// 프리플랍 프리셋 팩 생성기
+// 사용법: cargo run --release --bin generate_presets [출력 경로] [반복 수] [정제: none | argmax | 기준 빈도]
+// 기본 출력 경로는 크레이트에 포함되는 assets/presets/preflop_100bb_6max.bin 입니다.
+
+use nice_hand_core::api::presets::{generate_pack, PresetGenerationConfig, PresetStrategies};
+use nice_hand_core::solver::Purification;
+
+const DEFAULT_OUTPUT: &str = "assets/presets/preflop_100bb_6max.bin";
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let output = args.next().unwrap_or_else(|| DEFAULT_OUTPUT.to_string());
+    let mut config = PresetGenerationConfig::default();
+    if let Some(iterations) = args.next() {
+        config.iterations = iterations.parse().expect("반복 수는 양의 정수여야 합니다");
+    }
+    if let Some(purification) = args.next() {
+        config.purification = match purification.as_str() {
+            "none" => Purification::None,
+            "argmax" => Purification::Argmax,
+            cutoff => Purification::Threshold(cutoff.parse().expect("정제는 none, argmax 또는 0-1 기준 빈도여야 합니다")),
+        };
+    }
+
+    println!("프리셋 팩 생성 중: {:?}", config);
+    let start = std::time::Instant::now();
+    let bytes = generate_pack(&config).unwrap_or_else(|e| panic!("프리셋 팩 생성 실패: {}", e));
+    let presets = PresetStrategies::from_bytes(&bytes).expect("생성한 팩은 다시 읽을 수 있어야 함");
+    std::fs::write(&output, &bytes).unwrap_or_else(|e| panic!("{} 저장 실패: {}", output, e));
+
+    println!(
+        "✅ {}개 상황({} 정제), {} 바이트를 {:?}에 생성했습니다: {}",
+        presets.spots().count(),
+        presets.purification(),
+        bytes.len(),
+        start.elapsed(),
+        output
+    );
+    for spot in presets.spots() {
+        if let Ok(frequency) = presets.raise_frequency(spot) {
+            println!("  {:?}: 레이즈 {:.1}%", spot, frequency * 100.0);
+        }
+    }
+}
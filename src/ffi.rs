@@ -0,0 +1,180 @@
+// C ABI 바인딩 (feature = "ffi")
+// WASM 없이 Python(ctypes/cffi), C# (P/Invoke) 등에서 분석 함수를 호출하기 위한 최소 표면
+//
+// 헤더: include/nice_hand_core.h (cbindgen.toml 설정으로 `cbindgen --output include/nice_hand_core.h`)
+// 빌드/예제 확인: scripts/check_ffi.sh
+//
+// 규칙:
+// - 패닉은 경계에서 잡아 에러 JSON(또는 NaN)으로 바꾸며, FFI 너머로 언와인딩하지 않습니다.
+// - 널 포인터와 잘못된 UTF-8 입력은 에러로 처리합니다.
+// - 라이브러리가 반환한 문자열은 `nhc_free_string`으로 해제해야 합니다 (`nhc_version` 제외).
+//
+// 스레드 안전성: 모든 함수는 상태가 없거나(요청마다 새로 계산) 내부적으로 동기화된
+// 캐시만 사용하므로 여러 스레드에서 동시에 호출해도 안전합니다.
+
+use crate::api::analysis::{analyze_poker_state, AnalysisRequest};
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, UnwindSafe};
+
+/// 라이브러리 버전 (널 종료 문자열)
+const VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+/// 클로저를 실행하고 패닉이 나면 패닉 메시지를 에러로 반환
+fn guarded<T>(f: impl FnOnce() -> T + UnwindSafe) -> Result<T, String> {
+    catch_unwind(f).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "알 수 없는 패닉".to_string())
+    })
+}
+
+fn error_json(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+/// 분석 요청 JSON을 분석해 응답 JSON 반환 (실패하면 `{"error": ...}`)
+fn analyze_json(request_json: &str) -> String {
+    let request: AnalysisRequest = match serde_json::from_str(request_json) {
+        Ok(request) => request,
+        Err(e) => return error_json(&format!("요청 JSON 파싱 실패: {}", e)),
+    };
+    match guarded(move || analyze_poker_state(request)) {
+        Ok(Ok(response)) => serde_json::to_string(&response).unwrap_or_else(|e| error_json(&e.to_string())),
+        Ok(Err(e)) => error_json(&e.to_string()),
+        Err(panic) => error_json(&format!("분석 중 패닉: {}", panic)),
+    }
+}
+
+/// 널 포인터가 아닌 C 문자열을 UTF-8 `&str`로 변환
+///
+/// # Safety
+/// `ptr`은 널이거나 널 종료된 유효한 C 문자열을 가리켜야 합니다.
+unsafe fn read_str<'a>(ptr: *const c_char) -> Result<&'a str, String> {
+    if ptr.is_null() {
+        return Err("널 포인터".to_string());
+    }
+    CStr::from_ptr(ptr).to_str().map_err(|e| format!("UTF-8이 아닌 문자열: {}", e))
+}
+
+/// 호출자가 `nhc_free_string`으로 해제할 C 문자열로 변환 (내부 NUL은 제거)
+fn into_c_string(s: String) -> *mut c_char {
+    CString::new(s.replace('\0', "")).expect("NUL을 제거한 문자열").into_raw()
+}
+
+/// 포커 상황 분석
+///
+/// `request_json`은 `AnalysisRequest` JSON이고, 반환값은 `PokerAnalysisResponse` JSON
+/// 또는 `{"error": "..."}`입니다. 반환된 문자열은 `nhc_free_string`으로 해제해야 합니다.
+///
+/// # Safety
+/// `request_json`은 널이거나 널 종료된 유효한 C 문자열을 가리켜야 합니다.
+#[no_mangle]
+pub unsafe extern "C" fn nhc_analyze_json(request_json: *const c_char) -> *mut c_char {
+    let response = match read_str(request_json) {
+        Ok(request) => analyze_json(request),
+        Err(e) => error_json(&format!("요청을 읽을 수 없음: {}", e)),
+    };
+    into_c_string(response)
+}
+
+/// `nhc_analyze_json`이 반환한 문자열 해제 (널이면 아무것도 하지 않음)
+///
+/// # Safety
+/// `s`는 널이거나 이 라이브러리가 반환하고 아직 해제하지 않은 문자열이어야 합니다.
+#[no_mangle]
+pub unsafe extern "C" fn nhc_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// 핸드 스트렝스 (0.0-1.0, `calculate_hand_strength`와 같음)
+///
+/// `cards`는 홀카드 `n_hole`장, `board`는 보드 `n_board`장(0-5)입니다.
+/// 홀카드가 2장이 아니거나, 포인터가 널이거나, 계산 중 패닉이 나면 NaN을 반환합니다.
+/// 보드가 없으면 `board`는 널이어도 됩니다.
+///
+/// # Safety
+/// `cards`는 `n_hole`바이트, `board`는 `n_board`바이트를 읽을 수 있어야 합니다.
+#[no_mangle]
+pub unsafe extern "C" fn nhc_hand_strength(cards: *const u8, n_hole: u8, board: *const u8, n_board: u8) -> f64 {
+    if cards.is_null() || n_hole != 2 || n_board > 5 || (board.is_null() && n_board > 0) {
+        return f64::NAN;
+    }
+    let hole = [*cards, *cards.add(1)];
+    let board = if n_board == 0 {
+        Vec::new()
+    } else {
+        std::slice::from_raw_parts(board, n_board as usize).to_vec()
+    };
+    guarded(move || crate::calculate_hand_strength(hole, &board)).unwrap_or(f64::NAN)
+}
+
+/// 라이브러리 버전 (정적 문자열, 해제하지 마세요)
+#[no_mangle]
+pub extern "C" fn nhc_version() -> *const c_char {
+    VERSION.as_ptr() as *const c_char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn take_string(ptr: *mut c_char) -> String {
+        assert!(!ptr.is_null());
+        let s = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { nhc_free_string(ptr) };
+        s
+    }
+
+    #[test]
+    fn test_panics_and_null_inputs_become_errors() {
+        assert_eq!(guarded(|| 1 + 1), Ok(2));
+        assert_eq!(guarded(|| -> u8 { panic!("경계 테스트") }), Err("경계 테스트".to_string()));
+        assert!(guarded(|| -> u8 { panic!("{}", 42) }).unwrap_err().contains("42"));
+
+        let response: serde_json::Value =
+            serde_json::from_str(&take_string(unsafe { nhc_analyze_json(std::ptr::null()) })).unwrap();
+        assert!(response["error"].as_str().unwrap().contains("널 포인터"));
+
+        let bad = CString::new("{not json").unwrap();
+        let response: serde_json::Value = serde_json::from_str(&take_string(unsafe { nhc_analyze_json(bad.as_ptr()) })).unwrap();
+        assert!(response["error"].is_string());
+
+        unsafe { nhc_free_string(std::ptr::null_mut()) };
+        assert!(unsafe { nhc_hand_strength(std::ptr::null(), 2, std::ptr::null(), 0) }.is_nan());
+        assert!(unsafe { nhc_hand_strength([0, 13].as_ptr(), 1, std::ptr::null(), 0) }.is_nan());
+        assert!(unsafe { nhc_hand_strength([0, 13].as_ptr(), 2, std::ptr::null(), 3) }.is_nan());
+    }
+
+    #[test]
+    fn test_analyze_and_strength_match_rust_api() {
+        let hole = [0u8, 13];
+        let board = [2u8, 15, 28];
+        let strength = unsafe { nhc_hand_strength(hole.as_ptr(), 2, board.as_ptr(), 3) };
+        assert_eq!(strength, crate::calculate_hand_strength(hole, &board));
+        assert_eq!(
+            unsafe { nhc_hand_strength(hole.as_ptr(), 2, std::ptr::null(), 0) },
+            crate::calculate_hand_strength(hole, &[])
+        );
+
+        let request = CString::new(
+            r#"{"game_state": {"hole_cards": [0, 13], "board": [], "street": 0, "pot": 30,
+                "stacks": [1000, 1000], "alive_players": [0, 1], "street_investments": [10, 20],
+                "to_call": 10, "player_to_act": 0, "hero_position": 0, "betting_history": []},
+               "options": {"depth": "quick", "max_calculation_time_ms": null, "include_insights": false,
+                "include_range_analysis": false, "include_equity_calculation": false,
+                "opponent_modeling": "Tight"}}"#,
+        )
+        .unwrap();
+        let response: serde_json::Value =
+            serde_json::from_str(&take_string(unsafe { nhc_analyze_json(request.as_ptr()) })).unwrap();
+        assert!(response.get("error").is_none(), "{}", response);
+        assert!(response["ev_analysis"].is_object());
+
+        let version = unsafe { CStr::from_ptr(nhc_version()) }.to_str().unwrap();
+        assert_eq!(version, env!("CARGO_PKG_VERSION"));
+    }
+}
@@ -0,0 +1,698 @@
+// 올인 판정 모듈
+// 남은 보드를 모두 열거하거나(턴/플랍, 헤즈업 프리플랍) 몬테카를로로 샘플링해(멀티웨이 프리플랍) 쇼다운 에퀴티 계산
+
+use crate::game::hand_eval::{classify, enumerate_equity_heads_up, HandCategory, PartialEval};
+use crate::game::holdem::{HoldemVariant, State};
+use crate::game::rng::entropy_rng;
+use crate::solver::budget::{Budgeted, ComputeBudget};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// 게임 유틸리티 판정에 쓰는 고정 시드
+const UTILITY_SEED: u64 = 0x5EED_A111;
+
+/// 예산 안에서 샘플링할 때 예산을 검사하는 보드 배치 크기
+const BUDGET_BATCH_BOARDS: usize = 64;
+
+/// 올인 판정 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllInConfig {
+    /// 남은 보드 수가 이 값 이하면 모두 열거 (정확한 에퀴티)
+    ///
+    /// 턴 올인은 44-46개, 플랍 올인은 약 1,000개 보드라 기본값이면 정확히 계산됩니다.
+    pub max_enumerated_boards: usize,
+    /// 열거하기에 보드가 너무 많을 때(프리플랍) 몬테카를로 샘플 수
+    pub monte_carlo_samples: usize,
+    /// 난수 시드 (None이면 매번 다른 샘플)
+    pub seed: Option<u64>,
+    /// 두 명의 올인이면 보드 수와 관계없이 모두 열거 (일반 덱만 해당)
+    ///
+    /// 프리플랍 헤즈업은 1,712,304개 보드라 한 번에 릴리스 빌드 기준 약 0.5초가 걸립니다.
+    /// 같은 두 핸드의 프리플랍 결과는 `preflop_memo`에 캐시되므로 반복 호출은 비용이 거의 없습니다.
+    pub exact_heads_up: bool,
+    /// 프리플랍 헤즈업 열거 결과를 캐시하는 곳 (기본: 프로세스 전역)
+    pub preflop_memo: PreflopMemo,
+}
+
+impl Default for AllInConfig {
+    fn default() -> Self {
+        Self {
+            max_enumerated_boards: 2_000,
+            monte_carlo_samples: 2_000,
+            seed: None,
+            exact_heads_up: true,
+            preflop_memo: PreflopMemo::Shared,
+        }
+    }
+}
+
+/// 프리플랍 헤즈업 열거 결과 ([핸드1, 핸드2] 카드 4장 -> 핸드1 기준 승/무/패)
+type HeadsUpTable = HashMap<[u8; 4], (f64, f64, f64)>;
+
+/// 프리플랍 헤즈업 열거 결과 캐시
+///
+/// 캐시는 결과 값에 영향이 없고 같은 두 핸드를 다시 열거하는 비용만 줄입니다.
+#[derive(Debug, Clone, Default)]
+pub enum PreflopMemo {
+    /// 프로세스 전역 캐시
+    #[default]
+    Shared,
+    /// 이 값을 복제한 설정끼리만 쓰는 캐시 (마지막 복제본과 함께 사라짐)
+    Local(Arc<Mutex<HeadsUpTable>>),
+}
+
+impl PreflopMemo {
+    /// 비어 있는 호출 범위 캐시
+    pub fn local() -> Self {
+        Self::Local(Arc::default())
+    }
+
+    fn table(&self) -> &Mutex<HeadsUpTable> {
+        match self {
+            Self::Shared => &PREFLOP_HEADS_UP,
+            Self::Local(table) => table,
+        }
+    }
+}
+
+impl PartialEq for PreflopMemo {
+    /// 같은 캐시를 가리키면 같음
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Shared, Self::Shared) => true,
+            (Self::Local(a), Self::Local(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// 한 보드의 쇼다운 결과 (승자들이 팟을 나눠 가짐)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ShowdownOutcome {
+    /// 팟을 나눠 가지는 승자들 (핸드 인덱스 또는 좌석 번호)
+    pub winners: Vec<usize>,
+    /// 이 결과가 나올 확률
+    pub probability: f64,
+}
+
+/// 올인 판정 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AllInEquity {
+    /// 각 플레이어의 팟 지분 기댓값 (무승부는 나눠 가짐)
+    pub equities: Vec<f64>,
+    /// 각 플레이어가 팟을 다른 플레이어와 나눠 가질 확률 (`equities`에는 나눈 몫만큼 들어감)
+    pub ties: Vec<f64>,
+    /// 승자 조합별 확률 분포
+    pub outcomes: Vec<ShowdownOutcome>,
+    /// 평가한 보드 수
+    pub boards_evaluated: usize,
+    /// 남은 보드를 모두 열거한 정확한 값인지 여부
+    pub exact: bool,
+}
+
+impl AllInEquity {
+    /// 보드 한 번을 돌렸을 때 각 플레이어 팟 지분의 표준편차
+    pub fn share_std_devs(&self) -> Vec<f64> {
+        let mut second_moments = vec![0.0; self.equities.len()];
+        for outcome in &self.outcomes {
+            let share = 1.0 / outcome.winners.len() as f64;
+            for &winner in &outcome.winners {
+                second_moments[winner] += outcome.probability * share * share;
+            }
+        }
+        second_moments
+            .iter()
+            .zip(&self.equities)
+            .map(|(m2, eq)| (m2 - eq * eq).max(0.0).sqrt())
+            .collect()
+    }
+}
+
+/// 런 잇 N 타임즈의 한 번 런
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RunOutcome {
+    /// 완성된 5장 보드
+    pub board: Vec<u8>,
+    /// 이 런의 승자들
+    pub winners: Vec<usize>,
+    /// 각 핸드의 완성된 족보 (입력 핸드 순서)
+    pub hands: Vec<HandCategory>,
+}
+
+/// 런 잇 N 타임즈 결과 (UI 표시용)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RunItNTimesReport {
+    /// 실제로 딜한 런들 (런끼리 카드를 공유하지 않음)
+    pub runs: Vec<RunOutcome>,
+    /// 각 플레이어가 이번에 실제로 가져간 팟 비율 (런별 지분의 평균)
+    pub realized_shares: Vec<f64>,
+    /// 정확한(또는 저분산) 에퀴티
+    pub equities: Vec<f64>,
+    /// N번 나눠 돌렸을 때 가져가는 팟 비율의 표준편차
+    pub std_devs: Vec<f64>,
+}
+
+/// 올인 판정기
+///
+/// 보드가 완성되지 않은 쇼다운에서 무작위 보드 한 장이나 균등 분할 대신
+/// 남은 보드 전체(또는 충분한 샘플)에 대한 팟 지분을 계산합니다.
+#[derive(Debug, Clone, Default)]
+pub struct AllInAdjudicator {
+    pub config: AllInConfig,
+}
+
+impl AllInAdjudicator {
+    pub fn new(config: AllInConfig) -> Self {
+        Self { config }
+    }
+
+    /// 게임 유틸리티용 판정기 - 고정 시드라 같은 상태는 항상 같은 값
+    ///
+    /// 프리플랍 몬테카를로 결과가 호출마다 달라지면 시드 고정 학습도 재현되지 않습니다.
+    /// 학습은 매 반복 새로 딜한 터미널 노드를 평가하므로 헤즈업 프리플랍도 전체 열거 대신 샘플링합니다.
+    pub fn deterministic() -> Self {
+        Self::new(AllInConfig {
+            seed: Some(UTILITY_SEED),
+            exact_heads_up: false,
+            ..AllInConfig::default()
+        })
+    }
+
+    /// 핸드들의 올인 에퀴티 계산
+    ///
+    /// 반환되는 `equities`와 `winners`는 `hands` 순서의 인덱스입니다.
+    pub fn equities(&self, hands: &[[u8; 2]], board: &[u8]) -> AllInEquity {
+        self.variant_equities(HoldemVariant::Standard, hands, board)
+    }
+
+    /// 변형 규칙(덱과 족보)을 지정한 올인 에퀴티 계산
+    pub fn variant_equities(&self, variant: HoldemVariant, hands: &[[u8; 2]], board: &[u8]) -> AllInEquity {
+        let deck = remaining_deck(variant, hands, board);
+        let needed = 5usize.saturating_sub(board.len());
+        let board_count = combinations(deck.len(), needed);
+        if self.config.exact_heads_up
+            && hands.len() == 2
+            && variant == HoldemVariant::Standard
+            && board_count > self.config.max_enumerated_boards as u64
+        {
+            return heads_up_equity(&self.config.preflop_memo, hands, board, board_count);
+        }
+
+        let mut tally = OutcomeTally::new(hands.len());
+        let partials = partial_evals(variant, hands, board);
+        let exact = board_count <= self.config.max_enumerated_boards as u64;
+        if exact {
+            let mut runout = Vec::with_capacity(needed);
+            enumerate_runouts(&deck, needed, 0, &mut runout, &mut |runout| {
+                tally.add(showdown_winners(&partials, runout));
+            });
+        } else {
+            let mut rng = self.rng();
+            let mut deck = deck;
+            for _ in 0..self.config.monte_carlo_samples.max(1) {
+                tally.add(showdown_winners(&partials, deal(&mut deck, needed, &mut rng)));
+            }
+        }
+        tally.finish(exact)
+    }
+
+    /// `budget` 안에서 계산하는 `variant_equities` (보드 하나가 샘플 하나)
+    ///
+    /// 남은 샘플이 전체 보드 수 이상일 때만 열거하고, 아니면 배치마다 예산을 받아 몬테카를로로
+    /// 샘플링합니다. 보드를 하나도 평가하지 못하면 균등 분할입니다.
+    pub fn variant_equities_within(
+        &self,
+        variant: HoldemVariant,
+        hands: &[[u8; 2]],
+        board: &[u8],
+        budget: &ComputeBudget,
+    ) -> Budgeted<AllInEquity> {
+        let deck = remaining_deck(variant, hands, board);
+        let needed = 5usize.saturating_sub(board.len());
+        let board_count = combinations(deck.len(), needed);
+        let enumerable = board_count <= self.config.max_enumerated_boards as u64
+            || (self.config.exact_heads_up && hands.len() == 2 && variant == HoldemVariant::Standard);
+        if enumerable
+            && budget.remaining_samples().is_none_or(|left| left >= board_count)
+            && budget.take_samples(board_count).is_ok()
+        {
+            return budget.finish(self.variant_equities(variant, hands, board));
+        }
+
+        let mut tally = OutcomeTally::new(hands.len());
+        let partials = partial_evals(variant, hands, board);
+        let mut rng = self.rng();
+        let mut deck = deck;
+        let mut remaining = self.config.monte_carlo_samples.max(1);
+        while remaining > 0 {
+            let wanted = remaining.min(BUDGET_BATCH_BOARDS);
+            let granted = budget.take_samples(wanted as u64).unwrap_or(0) as usize;
+            for _ in 0..granted {
+                tally.add(showdown_winners(&partials, deal(&mut deck, needed, &mut rng)));
+            }
+            if granted < wanted {
+                break;
+            }
+            remaining -= granted;
+        }
+        if tally.boards == 0 {
+            let share = 1.0 / hands.len().max(1) as f64;
+            return budget.finish(AllInEquity {
+                equities: vec![share; hands.len()],
+                ties: vec![0.0; hands.len()],
+                outcomes: Vec::new(),
+                boards_evaluated: 0,
+                exact: false,
+            });
+        }
+        budget.finish(tally.finish(false))
+    }
+
+    /// 게임 상태에서 살아있는 플레이어들의 올인 에퀴티 계산
+    ///
+    /// `equities`는 좌석 번호(0-5)로 인덱싱되며 폴드한 좌석은 0입니다.
+    pub fn state_equity(&self, state: &State) -> AllInEquity {
+        let seats: Vec<usize> = (0..6).filter(|&i| state.alive[i]).collect();
+        let hands: Vec<[u8; 2]> = seats.iter().map(|&seat| state.hole[seat]).collect();
+        let by_hand = self.variant_equities(state.variant, &hands, &state.board);
+
+        let mut equities = vec![0.0; 6];
+        let mut ties = vec![0.0; 6];
+        for (i, &seat) in seats.iter().enumerate() {
+            equities[seat] = by_hand.equities[i];
+            ties[seat] = by_hand.ties[i];
+        }
+        let outcomes = by_hand
+            .outcomes
+            .into_iter()
+            .map(|outcome| ShowdownOutcome {
+                winners: outcome.winners.iter().map(|&i| seats[i]).collect(),
+                probability: outcome.probability,
+            })
+            .collect();
+
+        AllInEquity {
+            equities,
+            ties,
+            outcomes,
+            boards_evaluated: by_hand.boards_evaluated,
+            exact: by_hand.exact,
+        }
+    }
+
+    /// 살아있는 플레이어들의 홀카드가 모두 실제 카드인지 확인
+    ///
+    /// 웹 API처럼 상대 홀카드를 모르는 상태(`[0, 0]` 등)에서는 판정할 수 없습니다.
+    pub fn hands_known(state: &State) -> bool {
+        let mut seen = 0u64;
+        let cards = (0..6)
+            .filter(|&i| state.alive[i])
+            .flat_map(|i| state.hole[i])
+            .chain(state.board.iter().copied());
+        for card in cards {
+            if card >= 52 || seen & (1 << card) != 0 {
+                return false;
+            }
+            seen |= 1 << card;
+        }
+        true
+    }
+
+    /// 런 잇 N 타임즈: 남은 덱에서 겹치지 않는 보드를 `n`번 딜
+    ///
+    /// 덱이 부족하면 가능한 만큼만 딜합니다. 보드가 이미 완성되었으면 한 번만 런합니다.
+    pub fn run_it_n_times(&self, hands: &[[u8; 2]], board: &[u8], n: usize) -> RunItNTimesReport {
+        let equity = self.equities(hands, board);
+        let mut deck = remaining_deck(HoldemVariant::Standard, hands, board);
+        let needed = 5usize.saturating_sub(board.len());
+        let max_runs = deck.len().checked_div(needed).unwrap_or(1);
+        let run_count = n.clamp(1, max_runs.max(1));
+
+        let mut rng = self.rng();
+        deck.shuffle(&mut rng);
+        let partials = partial_evals(HoldemVariant::Standard, hands, board);
+        let mut realized_shares = vec![0.0; hands.len()];
+        let runs: Vec<RunOutcome> = deck
+            .chunks(needed.max(1))
+            .take(run_count)
+            .map(|cards| {
+                let runout = &cards[..needed.min(cards.len())];
+                let mut full_board = board.to_vec();
+                full_board.extend(runout);
+                let winners = showdown_winners(&partials, runout);
+                for &winner in &winners {
+                    realized_shares[winner] += 1.0 / winners.len() as f64 / run_count as f64;
+                }
+                let hands = hands.iter().map(|hand| classify(&[&hand[..], &full_board].concat())).collect();
+                RunOutcome {
+                    board: full_board,
+                    winners,
+                    hands,
+                }
+            })
+            .collect();
+
+        // 런끼리 카드를 공유하지 않으므로 실제 분산은 이보다 약간 작음 (독립 근사)
+        let std_devs = equity
+            .share_std_devs()
+            .iter()
+            .map(|sd| sd / (runs.len() as f64).sqrt())
+            .collect();
+
+        RunItNTimesReport {
+            runs,
+            realized_shares,
+            equities: equity.equities,
+            std_devs,
+        }
+    }
+
+    fn rng(&self) -> StdRng {
+        match self.config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => entropy_rng(),
+        }
+    }
+}
+
+/// 승자 조합별 보드 수 집계
+struct OutcomeTally {
+    players: usize,
+    counts: Vec<(Vec<usize>, u64)>,
+    boards: u64,
+}
+
+impl OutcomeTally {
+    fn new(players: usize) -> Self {
+        Self {
+            players,
+            counts: Vec::new(),
+            boards: 0,
+        }
+    }
+
+    fn add(&mut self, winners: Vec<usize>) {
+        self.boards += 1;
+        match self.counts.iter_mut().find(|(w, _)| *w == winners) {
+            Some((_, count)) => *count += 1,
+            None => self.counts.push((winners, 1)),
+        }
+    }
+
+    fn finish(self, exact: bool) -> AllInEquity {
+        let total = self.boards.max(1) as f64;
+        let mut equities = vec![0.0; self.players];
+        let outcomes: Vec<ShowdownOutcome> = self
+            .counts
+            .into_iter()
+            .map(|(winners, count)| {
+                let probability = count as f64 / total;
+                for &winner in &winners {
+                    equities[winner] += probability / winners.len() as f64;
+                }
+                ShowdownOutcome {
+                    winners,
+                    probability,
+                }
+            })
+            .collect();
+
+        AllInEquity {
+            equities,
+            ties: tie_probabilities(&outcomes, self.players),
+            outcomes,
+            boards_evaluated: self.boards as usize,
+            exact,
+        }
+    }
+}
+
+/// 승자가 둘 이상인 결과의 확률을 플레이어별로 합산
+fn tie_probabilities(outcomes: &[ShowdownOutcome], players: usize) -> Vec<f64> {
+    let mut ties = vec![0.0; players];
+    for outcome in outcomes.iter().filter(|outcome| outcome.winners.len() > 1) {
+        for &winner in &outcome.winners {
+            ties[winner] += outcome.probability;
+        }
+    }
+    ties
+}
+
+/// 최대 캐시 항목 수 (가득 차면 비움)
+const PREFLOP_CACHE_LIMIT: usize = 4_096;
+
+lazy_static::lazy_static! {
+    /// 프로세스 전역 프리플랍 헤즈업 열거 결과 (`PreflopMemo::Shared`)
+    static ref PREFLOP_HEADS_UP: Mutex<HeadsUpTable> = Mutex::new(HashMap::new());
+}
+
+/// 두 핸드의 남은 보드를 모두 열거한 정확한 에퀴티 (프리플랍 결과는 `memo`에 캐시)
+fn heads_up_equity(memo: &PreflopMemo, hands: &[[u8; 2]], board: &[u8], board_count: u64) -> AllInEquity {
+    let (hand1, hand2) = (hands[0], hands[1]);
+    let (win, tie, lose) = if board.is_empty() {
+        let key = [hand1[0], hand1[1], hand2[0], hand2[1]];
+        let cached = memo.table().lock().ok().and_then(|cache| cache.get(&key).copied());
+        cached.unwrap_or_else(|| {
+            let result = enumerate_equity_heads_up(hand1, hand2, board);
+            if let Ok(mut cache) = memo.table().lock() {
+                if cache.len() >= PREFLOP_CACHE_LIMIT {
+                    cache.clear();
+                }
+                cache.insert(key, result);
+            }
+            result
+        })
+    } else {
+        enumerate_equity_heads_up(hand1, hand2, board)
+    };
+
+    let outcomes = [(vec![0], win), (vec![1], lose), (vec![0, 1], tie)]
+        .into_iter()
+        .filter(|&(_, probability)| probability > 0.0)
+        .map(|(winners, probability)| ShowdownOutcome { winners, probability })
+        .collect();
+    AllInEquity {
+        equities: vec![win + tie / 2.0, lose + tie / 2.0],
+        ties: vec![tie, tie],
+        outcomes,
+        boards_evaluated: board_count as usize,
+        exact: true,
+    }
+}
+
+/// 핸드별 홀카드 + 현재 보드를 미리 쌓아 둔 증분 평가기 (보드는 앞의 5장만)
+fn partial_evals(variant: HoldemVariant, hands: &[[u8; 2]], board: &[u8]) -> Vec<PartialEval> {
+    let board = &board[..board.len().min(5)];
+    hands.iter().map(|hole| variant.partial_eval(hole).with_cards(board)).collect()
+}
+
+/// 남은 보드 카드를 더해 완성된 보드에서 가장 강한 핸드들 (낮은 랭크 = 강함)
+fn showdown_winners(partials: &[PartialEval], runout: &[u8]) -> Vec<usize> {
+    let ranks: Vec<u32> = partials.iter().map(|partial| partial.extend(runout)).collect();
+    let best = ranks.iter().copied().min().unwrap_or(0);
+    (0..partials.len()).filter(|&i| ranks[i] == best).collect()
+}
+
+/// 홀카드와 보드를 제외한 남은 카드
+fn remaining_deck(variant: HoldemVariant, hands: &[[u8; 2]], board: &[u8]) -> Vec<u8> {
+    let mut dead = [false; 52];
+    for &card in hands.iter().flatten().chain(board) {
+        if let Some(slot) = dead.get_mut(card as usize) {
+            *slot = true;
+        }
+    }
+    variant.deck().into_iter().filter(|&card| !dead[card as usize]).collect()
+}
+
+/// 남은 덱에서 `needed`장 조합을 모두 방문
+fn enumerate_runouts(
+    deck: &[u8],
+    needed: usize,
+    start: usize,
+    board: &mut Vec<u8>,
+    visit: &mut impl FnMut(&[u8]),
+) {
+    if needed == 0 {
+        visit(board);
+        return;
+    }
+    for i in start..=deck.len().saturating_sub(needed) {
+        board.push(deck[i]);
+        enumerate_runouts(deck, needed - 1, i + 1, board, visit);
+        board.pop();
+    }
+}
+
+/// 덱 앞쪽에 무작위 카드 `count`장을 모아 반환 (부분 셔플)
+fn deal<'a>(deck: &'a mut [u8], count: usize, rng: &mut StdRng) -> &'a [u8] {
+    let count = count.min(deck.len());
+    for i in 0..count {
+        let j = rng.gen_range(i..deck.len());
+        deck.swap(i, j);
+    }
+    &deck[..count]
+}
+
+/// nCk (오버플로 없이 보드 수 계산)
+fn combinations(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    (0..k as u64).fold(1, |acc, i| acc * (n as u64 - i) / (i + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::hand_eval::v7;
+
+    #[test]
+    fn test_turn_all_in_matches_brute_force() {
+        // As Ah vs Ks Kh, 보드 2c 7d 9h Qc
+        let hands = [[0, 13], [12, 25]];
+        let board = [1 + 39, 6 + 26, 8 + 13, 11 + 39];
+        let result = AllInAdjudicator::default().equities(&hands, &board);
+        assert!(result.exact);
+        assert_eq!(result.boards_evaluated, 44);
+
+        // 브루트 포스: 남은 리버 카드를 하나씩 직접 비교
+        let mut wins = [0.0; 2];
+        let mut rivers = 0;
+        for river in 0..52u8 {
+            if hands.iter().flatten().chain(&board).any(|&c| c == river) {
+                continue;
+            }
+            rivers += 1;
+            let mut full = board.to_vec();
+            full.push(river);
+            let aces = v7([hands[0][0], hands[0][1], full[0], full[1], full[2], full[3], full[4]]);
+            let kings = v7([hands[1][0], hands[1][1], full[0], full[1], full[2], full[3], full[4]]);
+            match aces.cmp(&kings) {
+                std::cmp::Ordering::Less => wins[0] += 1.0,
+                std::cmp::Ordering::Greater => wins[1] += 1.0,
+                std::cmp::Ordering::Equal => {
+                    wins[0] += 0.5;
+                    wins[1] += 0.5;
+                }
+            }
+        }
+        assert_eq!(result.equities[0], wins[0] / rivers as f64);
+        assert_eq!(result.equities[1], wins[1] / rivers as f64);
+        assert!((result.equities[0] - 42.0 / 44.0).abs() < 1e-12); // 킹 2장만 역전
+    }
+
+    #[test]
+    fn test_preflop_aces_vs_kings() {
+        // As Ah vs Ks Kd
+        let hands = [[0, 13], [12, 12 + 26]];
+
+        // 헤즈업은 전체 열거 (실행마다 같은 값)
+        let exact = AllInAdjudicator::default().equities(&hands, &[]);
+        assert!(exact.exact);
+        assert_eq!(exact.boards_evaluated, 1_712_304);
+        let (win, tie, _) = enumerate_equity_heads_up(hands[0], hands[1], &[]);
+        assert_eq!(exact.equities[0], win + tie / 2.0);
+        assert_eq!(AllInAdjudicator::default().equities(&hands, &[]), exact);
+
+        // 호출 범위 캐시는 그 설정을 복제한 판정기끼리만 채우고 같은 값을 돌려줌
+        let memo = PreflopMemo::local();
+        let local = AllInAdjudicator::new(AllInConfig {
+            preflop_memo: memo.clone(),
+            ..AllInConfig::default()
+        });
+        assert_eq!(local.equities(&hands, &[]), exact);
+        let PreflopMemo::Local(table) = &memo else { unreachable!() };
+        assert_eq!(table.lock().unwrap().len(), 1);
+        assert_eq!(local.clone().equities(&hands, &[]), exact);
+        assert_eq!(table.lock().unwrap().len(), 1);
+        assert_ne!(PreflopMemo::local(), memo);
+
+        let sampled = AllInAdjudicator::new(AllInConfig {
+            monte_carlo_samples: 50_000,
+            seed: Some(7),
+            exact_heads_up: false,
+            ..AllInConfig::default()
+        })
+        .equities(&hands, &[]);
+        assert!(!sampled.exact);
+        assert!((sampled.equities[0] - exact.equities[0]).abs() < 0.005, "{}", sampled.equities[0]);
+        assert!((sampled.equities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_budget_limits_boards() {
+        // As Ah vs Ks Kd 프리플랍: 전체 열거할 샘플이 없으면 예산만큼만 샘플링
+        let hands = [[0, 13], [12, 12 + 26]];
+        let adjudicator = AllInAdjudicator::new(AllInConfig {
+            seed: Some(3),
+            ..AllInConfig::default()
+        });
+        let budget = ComputeBudget::unlimited().with_max_samples(500);
+        let result = adjudicator.variant_equities_within(HoldemVariant::Standard, &hands, &[], &budget);
+        assert!(result.truncated());
+        assert!(!result.value.exact);
+        assert_eq!(result.value.boards_evaluated, 500);
+        assert_eq!(budget.samples_used(), 500);
+        assert!(result.value.equities[0] > 0.7);
+
+        // 예산이 없으면 균등 분할, 턴 올인은 열거할 만큼 남아 있으면 정확한 값
+        let empty = adjudicator.variant_equities_within(HoldemVariant::Standard, &hands, &[], &budget);
+        assert_eq!((empty.value.equities.clone(), empty.value.boards_evaluated), (vec![0.5, 0.5], 0));
+        let board = [1 + 39, 6 + 26, 8 + 13, 11 + 39];
+        let turn = ComputeBudget::unlimited().with_max_samples(100);
+        let exact = adjudicator.variant_equities_within(HoldemVariant::Standard, &hands, &board, &turn);
+        assert!(exact.value.exact && !exact.truncated());
+        assert_eq!(turn.samples_used(), 44);
+    }
+
+    #[test]
+    fn test_ties_are_reported_separately() {
+        // As Kh vs Ad Kc: 플러시가 나올 때만 승부가 갈림
+        let equity = AllInAdjudicator::default().equities(&[[0, 25], [26, 51]], &[]);
+        assert!(equity.exact);
+        assert_eq!(equity.ties[0], equity.ties[1]);
+        assert!(equity.ties[0] > 0.95, "{:?}", equity.ties);
+        assert!((equity.equities[0] - 0.5).abs() < 0.01, "{:?}", equity.equities);
+
+        // 샘플링/열거 경로도 같은 정의 (승자가 둘 이상인 보드 비율)
+        let board = [9, 23, 37, 51, 0]; // Ts Jh Qd Kc As: 보드 플레이
+        let multiway = AllInAdjudicator::default().equities(&[[1, 15], [29, 43], [5, 19]], &board);
+        assert_eq!(multiway.ties, vec![1.0; 3]);
+        assert!(multiway.equities.iter().all(|&equity| (equity - 1.0 / 3.0).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_run_it_n_times_uses_disjoint_boards() {
+        let adjudicator = AllInAdjudicator::new(AllInConfig {
+            seed: Some(1),
+            ..AllInConfig::default()
+        });
+        let hands = [[0, 13], [12, 25]];
+        let flop = [1 + 39, 6 + 26, 8 + 13];
+        let report = adjudicator.run_it_n_times(&hands, &flop, 3);
+        assert_eq!(report.runs.len(), 3);
+
+        let mut dealt: Vec<u8> = report.runs.iter().flat_map(|run| run.board[3..].to_vec()).collect();
+        dealt.sort_unstable();
+        dealt.dedup();
+        assert_eq!(dealt.len(), 6); // 런끼리 카드를 공유하지 않음
+        // 승자는 그 런에서 가장 강한 족보를 가진 핸드
+        for run in &report.runs {
+            let best = run.hands.iter().max().unwrap();
+            assert!(run.winners.iter().all(|&winner| &run.hands[winner] == best), "{:?}", run);
+        }
+        assert!((report.realized_shares.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        // 한 번만 돌릴 때보다 세 번 나눠 돌릴 때 분산이 작음
+        let once = adjudicator.run_it_n_times(&hands, &flop, 1);
+        assert!(report.std_devs[0] < once.std_devs[0]);
+    }
+}
@@ -1,6 +1,12 @@
 // 카드 추상화 및 버킷팅 모듈  
 // 유사한 핸드들을 그룹화하여 CFR 학습 효율성 향상
 
+pub mod hand_class; // 169 핸드 클래스 표기 변환
+mod multiway; // 상대 수별 프리플랍 버킷 표
+mod preflop_equity; // 클래스 대 클래스 프리플랍 에퀴티 표
+
+pub use preflop_equity::preflop_class_equity;
+
 /// 카드 추상화를 위한 버킷 크기 상수
 pub const PREFLOP_BUCKETS: usize = 50;    // 프리플랍 핸드 그룹 수
 pub const FLOP_BUCKETS: usize = 200;      // 플랍 핸드 그룹 수  
@@ -68,6 +74,96 @@ pub fn preflop_bucket(hole: [u8; 2]) -> u8 {
     }
 }
 
+/// 멀티웨이 프리플랍 버킷이 구분하는 최대 상대 수 (더 많으면 이 값으로 계산)
+pub const MAX_MULTIWAY_OPPONENTS: usize = 5;
+
+/// 상대 수를 반영한 프리플랍 버킷
+///
+/// 상대 `live_opponents`명과 올인했을 때의 에퀴티 순위로 169개 클래스를 정렬하고, 더 강한
+/// 클래스들의 조합 수 누적 비율을 `preflop_bucket`과 같은 50개 버킷으로 나눕니다.
+/// 팟에 남는 상대는 무작위 핸드가 아니라 참여 레인지(무작위 핸드 대비 헤즈업 에퀴티 상위 40% 조합)를
+/// 들고 있다고 봅니다. 그래서 상대가 많을수록 수티드 커넥터처럼 큰 핸드를 만드는 핸드가 올라가고
+/// 도미네이트되기 쉬운 오프수트 브로드웨이가 내려갑니다. 순위는 미리 계산한 표에서 읽습니다.
+///
+/// # 매개변수
+/// - hole: 2장 홀카드
+/// - live_opponents: 살아 있는 상대 수 (0은 1로, `MAX_MULTIWAY_OPPONENTS` 초과는 그 값으로 취급)
+///
+/// # 반환값
+/// - 버킷 번호 (0-49, 낮을수록 강한 핸드)
+pub fn preflop_bucket_multiway(hole: [u8; 2], live_opponents: usize) -> u8 {
+    let opponents = live_opponents.clamp(1, MAX_MULTIWAY_OPPONENTS);
+    let class = hand_class::HandClass::from_cards(hole);
+    multiway::MULTIWAY_PREFLOP_BUCKETS[opponents - 1][class.to_index() as usize]
+}
+
+/// 무작위 핸드를 가진 상대 `opponents`명과 올인했을 때의 프리플랍 에퀴티 (비기면 나눔)
+///
+/// 보드와 상대 핸드를 `samples`번 샘플링하며, 같은 시드면 같은 값을 반환합니다.
+pub fn multiway_equity(hole: [u8; 2], opponents: usize, samples: usize, seed: u64) -> f64 {
+    multiway_equity_in_range(hole, opponents, |_| true, samples, seed)
+}
+
+/// 상대들이 `in_range`를 만족하는 핸드만 들고 있을 때의 프리플랍 올인 에퀴티
+///
+/// 상대 핸드는 남은 카드에서 뽑아 레인지 밖이면 다시 뽑습니다. 카드 제거 때문에 천 번 안에
+/// 레인지 핸드를 뽑지 못하면 그 상대는 마지막으로 뽑은 핸드를 씁니다.
+pub fn multiway_equity_in_range(
+    hole: [u8; 2],
+    opponents: usize,
+    in_range: impl Fn([u8; 2]) -> bool,
+    samples: usize,
+    seed: u64,
+) -> f64 {
+    use crate::game::hand_eval::PartialEval;
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let opponents = opponents.clamp(1, 23);
+    let samples = samples.max(1);
+    let mut total = 0.0;
+    for _ in 0..samples {
+        let mut deck: Vec<u8> = (0..52).filter(|card| !hole.contains(card)).collect();
+        let mut villains = Vec::with_capacity(opponents);
+        for _ in 0..opponents {
+            let mut picked = (0, 1);
+            for _ in 0..1000 {
+                let first = rng.gen_range(0..deck.len());
+                let second = rng.gen_range(0..deck.len() - 1);
+                picked = (first, if second >= first { second + 1 } else { second });
+                if in_range([deck[picked.0], deck[picked.1]]) {
+                    break;
+                }
+            }
+            villains.push([deck[picked.0], deck[picked.1]]);
+            // 큰 인덱스부터 빼야 작은 인덱스가 유지됨
+            deck.swap_remove(picked.0.max(picked.1));
+            deck.swap_remove(picked.0.min(picked.1));
+        }
+        let (board, _) = deck.partial_shuffle(&mut rng, 5);
+        let board_eval = PartialEval::from_cards(board);
+        let value = |cards: [u8; 2]| board_eval.extend(&cards);
+
+        let hero = value(hole);
+        let mut tied = 1;
+        let mut beaten = false;
+        for villain in villains {
+            let villain = value(villain);
+            if villain < hero {
+                beaten = true;
+                break;
+            }
+            if villain == hero {
+                tied += 1;
+            }
+        }
+        if !beaten {
+            total += 1.0 / tied as f64;
+        }
+    }
+    total / samples as f64
+}
+
 /// 포스트플랍 핸드 강도 계산
 /// 
 /// 홀카드 + 보드카드 조합의 상대적 강도를 평가합니다.
@@ -155,11 +251,14 @@ pub fn draw_potential(hole: [u8; 2], board: &[u8]) -> f64 {
     all_cards.extend_from_slice(board);
     
     // 수트 분포 계산 (플러시 드로우)
+    // 범위를 벗어난 카드(52 이상)는 수트 계산에서 제외
     let mut suit_counts = [0u8; 4];
     for &card in &all_cards {
-        suit_counts[(card / 13) as usize] += 1;
+        if let Some(count) = suit_counts.get_mut((card / 13) as usize) {
+            *count += 1;
+        }
     }
-    let max_suit = *suit_counts.iter().max().unwrap();
+    let max_suit = suit_counts.iter().copied().max().unwrap_or(0);
     
     // 연속 카드 계산 (스트레이트 드로우)  
     let mut rank_bits = 0u16;
@@ -198,6 +297,202 @@ fn count_straight_draws(rank_bits: u16) -> u8 {
     draws
 }
 
+/// 스트레이트 드로우 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StraightDraw {
+    /// 스트레이트 드로우 없음 (이미 완성된 스트레이트 포함)
+    None,
+    /// 한 랭크로만 완성 (4아웃)
+    Gutshot,
+    /// 두 랭크 이상으로 완성 (8아웃, 더블 거터샷 포함)
+    OpenEnded,
+}
+
+/// 보드 텍스처 요소
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardTexture {
+    /// 가장 많은 수트의 장수 (3이면 플랍 모노톤)
+    pub max_suit_count: u8,
+    /// 같은 랭크가 2장 이상 있는지
+    pub paired: bool,
+    /// 연속 5랭크 구간 안에 들어가는 보드 랭크의 최대 개수 (스트레이트 가능성)
+    pub connectedness: u8,
+    /// 가장 높은 보드 카드의 강도 순 랭크 (0=2, ..., 12=A)
+    pub high_rank: u8,
+}
+
+/// 강도 순 랭크(0=2, ..., 12=A) 비트마스크 (52 이상의 카드는 무시)
+fn strength_rank_bits(cards: &[u8]) -> u16 {
+    cards
+        .iter()
+        .filter(|&&card| card < 52)
+        .fold(0, |bits, &card| bits | 1 << hand_class::rank_of(card))
+}
+
+/// 연속 5랭크 구간 마스크들 (휠 A-2-3-4-5 포함)
+fn straight_windows() -> impl Iterator<Item = u16> {
+    (0..=8).map(|low| 0x1F << low).chain(std::iter::once(0x100F))
+}
+
+/// 플러시 드로우 여부
+///
+/// 홀카드를 하나 이상 포함해 같은 수트가 정확히 4장이면 플러시 드로우입니다.
+/// 리버(보드 5장)에서는 드로우가 없습니다.
+pub fn has_flush_draw(hole: [u8; 2], board: &[u8]) -> bool {
+    if !(3..5).contains(&board.len()) {
+        return false;
+    }
+    hole.iter().filter(|&&card| card < 52).any(|&card| {
+        let suit = hand_class::suit_of(card);
+        let count = hole
+            .iter()
+            .chain(board)
+            .filter(|&&c| c < 52 && hand_class::suit_of(c) == suit)
+            .count();
+        count == 4
+    })
+}
+
+/// 스트레이트 드로우 판정
+///
+/// 스트레이트를 완성하는 랭크의 수로 판정합니다. 보드만으로 완성되는 스트레이트는
+/// 히어로의 드로우가 아니므로 제외하고, 이미 스트레이트가 완성되었거나 리버에서는
+/// `StraightDraw::None`입니다.
+pub fn straight_draw(hole: [u8; 2], board: &[u8]) -> StraightDraw {
+    if !(3..5).contains(&board.len()) {
+        return StraightDraw::None;
+    }
+    let board_bits = strength_rank_bits(board);
+    let all_bits = board_bits | strength_rank_bits(&hole);
+    if straight_windows().any(|window| all_bits & window == window) {
+        return StraightDraw::None;
+    }
+
+    let outs = (0..13)
+        .filter(|&rank| all_bits & (1 << rank) == 0)
+        .filter(|&rank| {
+            let with_out = all_bits | 1 << rank;
+            let board_with_out = board_bits | 1 << rank;
+            straight_windows().any(|window| with_out & window == window && board_with_out & window != window)
+        })
+        .count();
+
+    match outs {
+        0 => StraightDraw::None,
+        1 => StraightDraw::Gutshot,
+        _ => StraightDraw::OpenEnded,
+    }
+}
+
+/// 보드 텍스처 계산
+pub fn board_texture(board: &[u8]) -> BoardTexture {
+    let cards: Vec<u8> = board.iter().copied().filter(|&card| card < 52).collect();
+    let mut suit_counts = [0u8; 4];
+    let mut rank_counts = [0u8; 13];
+    for &card in &cards {
+        suit_counts[hand_class::suit_of(card) as usize] += 1;
+        rank_counts[hand_class::rank_of(card) as usize] += 1;
+    }
+    let bits = strength_rank_bits(&cards);
+
+    BoardTexture {
+        max_suit_count: suit_counts.iter().copied().max().unwrap_or(0),
+        paired: rank_counts.iter().any(|&count| count >= 2),
+        connectedness: straight_windows()
+            .map(|window| (bits & window).count_ones() as u8)
+            .max()
+            .unwrap_or(0),
+        high_rank: cards.iter().map(|&card| hand_class::rank_of(card)).max().unwrap_or(0),
+    }
+}
+
+/// 핸드 퍼센타일 계산 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PercentileMode {
+    /// 가능한 상대 홀카드 조합 전체 열거 (플랍 1,081개, 턴 1,035개, 리버 990개)
+    #[default]
+    Exact,
+    /// 상대 홀카드 조합을 `samples`개 무작위 샘플링 (같은 시드면 같은 결과)
+    Sampled { samples: usize, seed: u64 },
+}
+
+/// 보드 위 핸드 퍼센타일
+///
+/// 카드 제거를 반영해 상대가 가질 수 있는 모든 두 장 조합과 실제 핸드 평가기로
+/// 비교한 뒤, 이기는 조합 비율 + 비기는 조합 비율의 절반을 반환합니다.
+/// `hand_strength`의 절대 점수와 달리 같은 원페어라도 보드에 따라 값이 달라집니다
+/// (A72 레인보우의 에이스 페어는 넛에 가깝지만 네 장 플러시 보드에서는 평범함).
+///
+/// 보드가 3장 미만이면 `hand_strength`의 프리플랍 값을, 카드가 52 이상이거나
+/// 겹치면 0.5(정보 없음)를 반환합니다. 보드는 앞의 5장만 사용합니다.
+pub fn hand_percentile(hole: [u8; 2], board: &[u8]) -> f64 {
+    hand_percentile_with(hole, board, PercentileMode::Exact)
+}
+
+/// 계산 방식을 지정한 `hand_percentile`
+pub fn hand_percentile_with(hole: [u8; 2], board: &[u8], mode: PercentileMode) -> f64 {
+    if board.len() < 3 {
+        return hand_strength(hole, board);
+    }
+    showdown_split(hole, board, mode).map_or(0.5, |(win, tie, _)| win + tie / 2.0)
+}
+
+/// 현재 보드 위에서 상대가 가질 수 있는 두 장 조합 대비 (승, 무, 패) 비율
+///
+/// `hand_percentile`과 같은 열거(카드 제거 반영)로 비기는 비율을 따로 돌려줍니다.
+/// 무승부 비율은 보드가 플레이되는 정도(찹 가능성)이며 남은 카드는 고려하지 않습니다.
+/// 보드가 3장 미만이거나 카드가 52 이상이거나 겹치면 None. 보드는 앞의 5장만 사용합니다.
+pub fn showdown_split(hole: [u8; 2], board: &[u8], mode: PercentileMode) -> Option<(f64, f64, f64)> {
+    let board = &board[..board.len().min(5)];
+    if board.len() < 3 {
+        return None;
+    }
+
+    let mut dead = 0u64;
+    for &card in hole.iter().chain(board) {
+        if card >= 52 || dead & (1 << card) != 0 {
+            return None;
+        }
+        dead |= 1 << card;
+    }
+
+    let mut cards = board.to_vec();
+    cards.extend_from_slice(&hole);
+    let hero = crate::hand_eval::best_hand_rank(&cards).expect("보드 3-5장 + 홀카드 2장");
+    // 홀카드 자리만 상대 카드로 바꿔 평가 (낮은 랭크 = 강한 핸드), [승, 무, 패] 인덱스
+    let mut outcome = |villain: [u8; 2]| {
+        cards[board.len()..].copy_from_slice(&villain);
+        let rank = crate::hand_eval::best_hand_rank(&cards).expect("보드 3-5장 + 홀카드 2장");
+        match hero.cmp(&rank) {
+            std::cmp::Ordering::Less => 0,
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Greater => 2,
+        }
+    };
+
+    let live: Vec<u8> = (0..52).filter(|&card| dead & (1 << card) == 0).collect();
+    let mut counts = [0usize; 3];
+    match mode {
+        PercentileMode::Exact => {
+            for (i, &first) in live.iter().enumerate() {
+                for &second in &live[i + 1..] {
+                    counts[outcome([first, second])] += 1;
+                }
+            }
+        }
+        PercentileMode::Sampled { samples, seed } => {
+            use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+            let mut rng = StdRng::seed_from_u64(seed);
+            for _ in 0..samples.max(1) {
+                let villain: Vec<u8> = live.choose_multiple(&mut rng, 2).copied().collect();
+                counts[outcome([villain[0], villain[1]])] += 1;
+            }
+        }
+    }
+    let total = counts.iter().sum::<usize>() as f64;
+    Some((counts[0] as f64 / total, counts[1] as f64 / total, counts[2] as f64 / total))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,4 +554,138 @@ mod tests {
         
         println!("포스트플랍 버킷 테스트 통과");
     }
+
+    #[test]
+    fn test_draw_detection() {
+        use hand_class::make_card;
+        // 강도 순 랭크: 0=2, ..., 8=T, 9=J, 10=Q, 11=K, 12=A / 수트: 0=s, 1=h, 2=d, 3=c
+        let card = |rank: u8, suit: u8| make_card(rank, suit);
+
+        // AhKh / Qh7h2c: 넛 플러시 드로우, 스트레이트 드로우 없음 (J, T 두 장 필요)
+        let hole = [card(12, 1), card(11, 1)];
+        let board = [card(10, 1), card(5, 1), card(0, 3)];
+        assert!(has_flush_draw(hole, &board));
+        assert_eq!(straight_draw(hole, &board), StraightDraw::None);
+
+        // JsTd / Qh9c2s: 8 또는 K로 완성되는 양방향 스트레이트 드로우
+        let hole = [card(9, 0), card(8, 2)];
+        let board = [card(10, 1), card(7, 3), card(0, 0)];
+        assert_eq!(straight_draw(hole, &board), StraightDraw::OpenEnded);
+        assert!(!has_flush_draw(hole, &board));
+
+        // JsTd / Kh9c2s: Q로만 완성되는 거터샷
+        let board = [card(11, 1), card(7, 3), card(0, 0)];
+        assert_eq!(straight_draw(hole, &board), StraightDraw::Gutshot);
+
+        // As2d / 3h4c9s: 5로만 완성되는 휠 거터샷
+        let hole = [card(12, 0), card(0, 2)];
+        let board = [card(1, 1), card(2, 3), card(7, 0)];
+        assert_eq!(straight_draw(hole, &board), StraightDraw::Gutshot);
+
+        // 보드만으로 완성되는 스트레이트는 히어로의 드로우가 아님
+        let hole = [card(0, 0), card(0, 1)];
+        let board = [card(6, 1), card(7, 3), card(8, 0), card(9, 2)];
+        assert_eq!(straight_draw(hole, &board), StraightDraw::None);
+
+        // 완성된 플러시는 드로우가 아님, 리버에는 드로우가 없음
+        let hole = [card(12, 1), card(11, 1)];
+        let board = [card(10, 1), card(5, 1), card(0, 1)];
+        assert!(!has_flush_draw(hole, &board));
+        let board = [card(10, 1), card(5, 1), card(0, 3), card(3, 3), card(4, 2)];
+        assert!(!has_flush_draw(hole, &board));
+    }
+
+    #[test]
+    fn test_board_texture() {
+        use hand_class::make_card;
+        let texture = board_texture(&[make_card(10, 1), make_card(5, 1), make_card(10, 3)]);
+        assert_eq!(texture.max_suit_count, 2);
+        assert!(texture.paired);
+        assert_eq!(texture.high_rank, 10);
+
+        let texture = board_texture(&[make_card(8, 0), make_card(7, 0), make_card(6, 0)]);
+        assert_eq!(texture.max_suit_count, 3);
+        assert!(!texture.paired);
+        assert_eq!(texture.connectedness, 3);
+    }
+
+    #[test]
+    fn test_hand_strength_adversarial_inputs() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        for _ in 0..2000 {
+            let hole = [rng.gen::<u8>(), rng.gen::<u8>()];
+            let board: Vec<u8> = (0..rng.gen_range(0..9)).map(|_| rng.gen()).collect();
+
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                let strength = hand_strength(hole, &board);
+                assert!((0.0..=1.0).contains(&strength));
+                postflop_bucket(hole, &board, rng.gen());
+                draw_potential(hole, &board);
+                has_flush_draw(hole, &board);
+                straight_draw(hole, &board);
+                board_texture(&board);
+                let percentile = hand_percentile_with(hole, &board, PercentileMode::Sampled { samples: 16, seed: 0 });
+                assert!((0.0..=1.0).contains(&percentile));
+            }));
+            assert!(result.is_ok(), "패닉 발생: hole={:?}, board={:?}", hole, board);
+        }
+    }
+
+    #[test]
+    fn test_hand_percentile_depends_on_board() {
+        use hand_class::make_card;
+        // 강도 순 랭크: 0=2, ..., 8=T, 9=J, 10=Q, 11=K, 12=A / 수트: 0=s, 1=h, 2=d, 3=c
+        let card = |rank: u8, suit: u8| make_card(rank, suit);
+
+        // KsKc / Kh7d2c: 드라이 보드의 탑 셋
+        let top_set = hand_percentile([card(11, 0), card(11, 3)], &[card(11, 1), card(5, 2), card(0, 3)]);
+        assert!(top_set > 0.97, "탑 셋: {}", top_set);
+
+        // 9s8c 세컨드 페어: 레인보우 Ad9h4c2s vs 하트 네 장 Ah9h4h2h (히어로는 하트 없음)
+        let hole = [card(7, 0), card(6, 3)];
+        let dry = hand_percentile(hole, &[card(12, 2), card(7, 1), card(2, 3), card(0, 0)]);
+        let monotone = hand_percentile(hole, &[card(12, 1), card(7, 1), card(2, 1), card(0, 1)]);
+        assert!(monotone < dry - 0.2, "드라이 {} vs 모노톤 {}", dry, monotone);
+
+        // AsTs / KsQsJs2h3d: 로열 플러시는 모든 조합을 이김
+        let nuts = hand_percentile([card(12, 0), card(8, 0)], &[card(11, 0), card(10, 0), card(9, 0), card(0, 1), card(1, 2)]);
+        assert_eq!(nuts, 1.0);
+
+        // 샘플링은 열거 값에 가깝고 시드가 같으면 재현됨
+        let sampled = PercentileMode::Sampled { samples: 4000, seed: 7 };
+        let board = [card(12, 2), card(7, 1), card(2, 3), card(0, 0)];
+        assert!((hand_percentile_with(hole, &board, sampled) - dry).abs() < 0.03);
+        assert_eq!(hand_percentile_with(hole, &board, sampled), hand_percentile_with(hole, &board, sampled));
+    }
+
+    #[test]
+    fn test_multiway_buckets_follow_opponent_count() {
+        let bucket = |class: &str, opponents: usize| {
+            let hole = class.parse::<hand_class::HandClass>().unwrap().combos(&[])[0];
+            preflop_bucket_multiway(hole, opponents)
+        };
+
+        // 수트드 커넥터는 멀티웨이에서 올라가고, 도미네이트되기 쉬운 오프수트 브로드웨이는 내려감
+        assert!(bucket("76s", 4) < bucket("76s", 1));
+        assert!(bucket("KJo", 4) > bucket("KJo", 1));
+        for opponents in 1..=MAX_MULTIWAY_OPPONENTS + 2 {
+            assert_eq!(bucket("AA", opponents), 0);
+        }
+        assert_eq!(preflop_bucket([0, 13]), 0);
+    }
+
+    #[test]
+    fn test_holdem_uses_multiway_bucket_only_with_several_opponents() {
+        use crate::game::holdem::HoldemVariant;
+        let hole = "KJo".parse::<hand_class::HandClass>().unwrap().combos(&[])[0];
+        assert_eq!(HoldemVariant::Standard.preflop_bucket_vs(hole, 1), preflop_bucket(hole));
+        assert_eq!(HoldemVariant::Standard.preflop_bucket_vs(hole, 4), preflop_bucket_multiway(hole, 4));
+        assert_eq!(
+            HoldemVariant::ShortDeck.preflop_bucket_vs(hole, 4),
+            HoldemVariant::ShortDeck.preflop_bucket(hole)
+        );
+    }
 }
@@ -0,0 +1,275 @@
+// 핸드 클래스 표기 유틸리티
+// 구체적인 카드 [u8; 2], 169개 정규 클래스, "AKs"/"AKo"/"77" 표기 간 변환
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// 랭크 문자 (0=2, ..., 12=A 순서)
+const RANK_CHARS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+/// 169개 프리플랍 핸드 클래스
+///
+/// 랭크는 강도 순서로 0=2, 1=3, ..., 12=A 입니다.
+/// (카드 번호의 `card % 13`은 0=A 이므로 `rank_of`로 변환하세요)
+/// 수티드/오프수트 클래스는 항상 `hi > lo`를 만족합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HandClass {
+    /// 포켓 페어 (예: "77")
+    Pair(u8),
+    /// 수티드 핸드 (예: "AKs")
+    Suited(u8, u8),
+    /// 오프수트 핸드 (예: "AKo")
+    Offsuit(u8, u8),
+}
+
+/// 핸드 클래스 파싱 에러
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandClassParseError {
+    /// 길이가 맞지 않음 (2 또는 3글자)
+    InvalidLength(usize),
+    /// 알 수 없는 랭크 문자
+    InvalidRank(char),
+    /// 's' 또는 'o'가 아닌 접미사
+    InvalidSuffix(char),
+    /// 페어가 아닌데 접미사가 없거나, 페어인데 접미사가 있음
+    AmbiguousSuitedness,
+}
+
+impl fmt::Display for HandClassParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(f, "유효하지 않은 핸드 표기 길이: {}", len),
+            Self::InvalidRank(c) => write!(f, "유효하지 않은 랭크 문자: {}", c),
+            Self::InvalidSuffix(c) => write!(f, "유효하지 않은 접미사: {} (s 또는 o)", c),
+            Self::AmbiguousSuitedness => write!(f, "수티드/오프수트 여부가 올바르지 않습니다"),
+        }
+    }
+}
+
+impl std::error::Error for HandClassParseError {}
+
+/// 카드 번호(0-51)의 강도 순 랭크 (0=2, ..., 12=A)
+pub fn rank_of(card: u8) -> u8 {
+    (card % 13 + 12) % 13
+}
+
+/// 카드 번호(0-51)의 수트 (0=s, 1=h, 2=d, 3=c)
+pub fn suit_of(card: u8) -> u8 {
+    card / 13
+}
+
+/// 강도 순 랭크와 수트로 카드 번호 생성
+pub fn make_card(rank: u8, suit: u8) -> u8 {
+    suit * 13 + (rank + 1) % 13
+}
+
+pub(crate) fn parse_rank(c: char) -> Result<u8, HandClassParseError> {
+    let upper = c.to_ascii_uppercase();
+    RANK_CHARS
+        .iter()
+        .position(|&r| r == upper)
+        .map(|r| r as u8)
+        .ok_or(HandClassParseError::InvalidRank(c))
+}
+
+impl HandClass {
+    /// 홀카드 두 장으로부터 클래스 생성
+    pub fn from_cards(hole: [u8; 2]) -> Self {
+        let (r1, r2) = (rank_of(hole[0]), rank_of(hole[1]));
+        let (hi, lo) = if r1 >= r2 { (r1, r2) } else { (r2, r1) };
+
+        if hi == lo {
+            HandClass::Pair(hi)
+        } else if suit_of(hole[0]) == suit_of(hole[1]) {
+            HandClass::Suited(hi, lo)
+        } else {
+            HandClass::Offsuit(hi, lo)
+        }
+    }
+
+    /// 169 인덱스 (0-168)
+    ///
+    /// 일반적인 13x13 차트 배치를 행 우선으로 펼친 순서입니다.
+    /// 행/열 0이 A, 12가 2이며 대각선은 페어, 대각선 위는 수티드
+    /// (행=높은 랭크), 아래는 오프수트(행=낮은 랭크)입니다.
+    /// 따라서 AA=0, AKs=1, ..., 32s=155, AKo=13, ..., 22=168 입니다.
+    pub fn to_index(&self) -> u8 {
+        let (row, col) = match *self {
+            HandClass::Pair(r) => (12 - r, 12 - r),
+            HandClass::Suited(hi, lo) => (12 - hi, 12 - lo),
+            HandClass::Offsuit(hi, lo) => (12 - lo, 12 - hi),
+        };
+        row * 13 + col
+    }
+
+    /// 169 인덱스로부터 클래스 생성 (범위를 벗어나면 None)
+    pub fn from_index(index: u8) -> Option<Self> {
+        if index >= 169 {
+            return None;
+        }
+
+        let (row, col) = (index / 13, index % 13);
+        let class = match row.cmp(&col) {
+            std::cmp::Ordering::Equal => HandClass::Pair(12 - row),
+            std::cmp::Ordering::Less => HandClass::Suited(12 - row, 12 - col),
+            std::cmp::Ordering::Greater => HandClass::Offsuit(12 - col, 12 - row),
+        };
+        Some(class)
+    }
+
+    /// 모든 169개 클래스를 인덱스 순서로 반환
+    pub fn all() -> Vec<Self> {
+        (0..169).filter_map(Self::from_index).collect()
+    }
+
+    /// 카드 제거 효과 없이 가능한 조합 수 (페어 6, 수티드 4, 오프수트 12)
+    pub fn combo_count(&self) -> usize {
+        match self {
+            HandClass::Pair(_) => 6,
+            HandClass::Suited(..) => 4,
+            HandClass::Offsuit(..) => 12,
+        }
+    }
+
+    /// 데드 카드를 제외한 실제 가능한 조합 나열
+    ///
+    /// # 매개변수
+    /// - dead_cards: 이미 보이는 카드들 (보드, 다른 플레이어의 카드 등)
+    pub fn combos(&self, dead_cards: &[u8]) -> Vec<[u8; 2]> {
+        let mut combos = Vec::with_capacity(self.combo_count());
+        let is_live = |card: &u8| !dead_cards.contains(card);
+
+        match *self {
+            HandClass::Pair(r) => {
+                for s1 in 0..4 {
+                    for s2 in (s1 + 1)..4 {
+                        combos.push([make_card(r, s1), make_card(r, s2)]);
+                    }
+                }
+            }
+            HandClass::Suited(hi, lo) => {
+                for s in 0..4 {
+                    combos.push([make_card(hi, s), make_card(lo, s)]);
+                }
+            }
+            HandClass::Offsuit(hi, lo) => {
+                for s1 in 0..4 {
+                    for s2 in 0..4 {
+                        if s1 != s2 {
+                            combos.push([make_card(hi, s1), make_card(lo, s2)]);
+                        }
+                    }
+                }
+            }
+        }
+
+        combos.retain(|c| is_live(&c[0]) && is_live(&c[1]));
+        combos
+    }
+}
+
+impl fmt::Display for HandClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            HandClass::Pair(r) => {
+                let c = RANK_CHARS[r as usize];
+                write!(f, "{}{}", c, c)
+            }
+            HandClass::Suited(hi, lo) => {
+                write!(f, "{}{}s", RANK_CHARS[hi as usize], RANK_CHARS[lo as usize])
+            }
+            HandClass::Offsuit(hi, lo) => {
+                write!(f, "{}{}o", RANK_CHARS[hi as usize], RANK_CHARS[lo as usize])
+            }
+        }
+    }
+}
+
+impl FromStr for HandClass {
+    type Err = HandClassParseError;
+
+    /// "AKs", "AKo", "77" 형식 파싱 (랭크 순서는 상관없음, 대소문자 무시)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.trim().chars().collect();
+        if chars.len() != 2 && chars.len() != 3 {
+            return Err(HandClassParseError::InvalidLength(chars.len()));
+        }
+
+        let r1 = parse_rank(chars[0])?;
+        let r2 = parse_rank(chars[1])?;
+        let (hi, lo) = if r1 >= r2 { (r1, r2) } else { (r2, r1) };
+
+        match (hi == lo, chars.get(2).map(|c| c.to_ascii_lowercase())) {
+            (true, None) => Ok(HandClass::Pair(hi)),
+            (false, Some('s')) => Ok(HandClass::Suited(hi, lo)),
+            (false, Some('o')) => Ok(HandClass::Offsuit(hi, lo)),
+            (false, Some(c)) => Err(HandClassParseError::InvalidSuffix(c)),
+            _ => Err(HandClassParseError::AmbiguousSuitedness),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_combos_round_trip() {
+        let mut total = 0;
+        for c1 in 0..52u8 {
+            for c2 in (c1 + 1)..52u8 {
+                let class = HandClass::from_cards([c1, c2]);
+                let combos = class.combos(&[]);
+                assert!(
+                    combos.iter().any(|c| (c[0] == c1 && c[1] == c2) || (c[0] == c2 && c[1] == c1)),
+                    "{} 클래스가 조합 {:?}를 포함하지 않음",
+                    class,
+                    [c1, c2]
+                );
+                for combo in combos {
+                    assert_eq!(HandClass::from_cards(combo), class);
+                }
+                total += 1;
+            }
+        }
+        assert_eq!(total, 1326);
+
+        let class_total: usize = HandClass::all().iter().map(|c| c.combo_count()).sum();
+        assert_eq!(class_total, 1326);
+    }
+
+    #[test]
+    fn test_parse_and_card_removal() {
+        let aks: HandClass = "AKs".parse().unwrap();
+        assert_eq!(aks, HandClass::Suited(12, 11));
+        assert_eq!(aks.combos(&[]).len(), 4);
+        assert_eq!(aks.combos(&[0]).len(), 3); // As 데드
+
+        assert_eq!("KAo".parse::<HandClass>().unwrap(), HandClass::Offsuit(12, 11));
+        assert_eq!("77".parse::<HandClass>().unwrap(), HandClass::Pair(5));
+        assert_eq!(HandClass::from_cards([0, 13]).to_string(), "AA");
+
+        assert!("AK".parse::<HandClass>().is_err());
+        assert!("77s".parse::<HandClass>().is_err());
+        assert!("AXs".parse::<HandClass>().is_err());
+        assert!("AKx".parse::<HandClass>().is_err());
+    }
+
+    #[test]
+    fn test_index_round_trip() {
+        for index in 0..169u8 {
+            let class = HandClass::from_index(index).unwrap();
+            assert_eq!(class.to_index(), index);
+            assert_eq!(class.to_string().parse::<HandClass>().unwrap(), class);
+        }
+        assert!(HandClass::from_index(169).is_none());
+
+        assert_eq!("AA".parse::<HandClass>().unwrap().to_index(), 0);
+        assert_eq!("AKs".parse::<HandClass>().unwrap().to_index(), 1);
+        assert_eq!("AKo".parse::<HandClass>().unwrap().to_index(), 13);
+        assert_eq!("22".parse::<HandClass>().unwrap().to_index(), 168);
+    }
+}
@@ -0,0 +1,154 @@
+// 상대 수별 프리플랍 버킷 표
+// 참여 레인지를 가진 상대 N명과 올인했을 때의 에퀴티로 169개 클래스를 정렬해 50개 버킷으로 나눈 값입니다.
+// 표를 정확히 만들려면 클래스마다 수십만 번의 런아웃이 필요해 미리 계산해 두었습니다.
+// 버킷 기준을 바꿨다면 아래 명령의 출력으로 표를 교체하세요:
+//
+//     cargo test --release --lib regenerate_multiway_table -- --ignored --nocapture
+
+use super::MAX_MULTIWAY_OPPONENTS;
+#[cfg(test)]
+use super::{hand_class::HandClass, multiway_equity, multiway_equity_in_range, PREFLOP_BUCKETS};
+
+/// 표를 만들 때 클래스마다 샘플링한 런아웃 수
+#[cfg(test)]
+const TABLE_SAMPLES: usize = 200_000;
+
+/// 상대 레인지: 무작위 핸드 대비 헤즈업 에퀴티 상위 이 비율의 조합
+#[cfg(test)]
+const OPPONENT_RANGE_FRACTION: f64 = 0.4;
+
+/// 상대 수(1..=MAX_MULTIWAY_OPPONENTS)별 169 클래스 인덱스(`HandClass::to_index`) -> 버킷
+pub(super) const MULTIWAY_PREFLOP_BUCKETS: [[u8; 169]; MAX_MULTIWAY_OPPONENTS] = [
+    // 상대 1명
+    [
+        0, 0, 1, 2, 3, 3, 4, 5, 6, 7, 8, 8, 8,
+        1, 0, 4, 5, 6, 9, 12, 13, 13, 15, 15, 15, 16,
+        1, 5, 0, 11, 11, 13, 16, 18, 19, 19, 22, 23, 23,
+        2, 7, 13, 0, 13, 16, 18, 20, 24, 25, 27, 28, 29,
+        3, 8, 14, 15, 1, 16, 18, 20, 23, 29, 29, 29, 32,
+        4, 12, 17, 20, 22, 2, 17, 19, 23, 25, 32, 33, 34,
+        6, 14, 21, 26, 24, 24, 3, 18, 20, 24, 29, 35, 36,
+        7, 15, 26, 32, 31, 27, 25, 4, 19, 22, 26, 31, 37,
+        9, 16, 27, 36, 35, 33, 30, 28, 5, 20, 24, 29, 35,
+        9, 18, 30, 38, 41, 39, 37, 34, 30, 6, 22, 25, 32,
+        10, 19, 32, 40, 42, 45, 43, 40, 37, 34, 8, 29, 35,
+        11, 21, 35, 41, 44, 46, 48, 45, 42, 39, 40, 10, 36,
+        11, 23, 38, 43, 45, 47, 49, 49, 47, 44, 46, 48, 12,
+    ],
+    // 상대 2명
+    [
+        0, 0, 1, 2, 2, 4, 4, 5, 6, 6, 8, 8, 9,
+        1, 0, 3, 4, 5, 8, 11, 13, 15, 17, 19, 21, 23,
+        2, 5, 0, 6, 7, 10, 14, 18, 18, 20, 22, 23, 24,
+        3, 6, 10, 0, 7, 10, 13, 16, 19, 22, 23, 26, 28,
+        3, 8, 11, 13, 1, 9, 12, 15, 18, 24, 25, 27, 29,
+        6, 15, 18, 19, 16, 1, 11, 14, 16, 22, 29, 30, 32,
+        7, 20, 26, 24, 21, 20, 2, 11, 15, 19, 26, 31, 33,
+        9, 25, 32, 30, 27, 25, 21, 3, 12, 16, 20, 27, 34,
+        12, 29, 33, 36, 34, 31, 27, 22, 4, 14, 18, 23, 29,
+        11, 31, 36, 37, 41, 39, 34, 30, 24, 5, 16, 19, 26,
+        14, 35, 39, 40, 41, 46, 42, 35, 32, 28, 7, 23, 29,
+        15, 38, 40, 42, 44, 47, 48, 44, 38, 35, 37, 10, 31,
+        17, 40, 43, 45, 45, 48, 49, 49, 46, 43, 45, 47, 13,
+    ],
+    // 상대 3명
+    [
+        0, 1, 1, 2, 2, 4, 5, 6, 7, 6, 8, 9, 12,
+        1, 0, 3, 3, 5, 7, 12, 13, 15, 18, 22, 25, 28,
+        2, 5, 0, 4, 5, 6, 12, 15, 16, 19, 22, 22, 24,
+        3, 6, 8, 0, 5, 7, 11, 14, 17, 18, 22, 23, 25,
+        4, 10, 11, 11, 0, 6, 9, 12, 14, 22, 23, 23, 27,
+        9, 17, 18, 16, 14, 1, 8, 11, 13, 19, 28, 28, 30,
+        13, 30, 29, 25, 21, 19, 2, 8, 10, 15, 21, 29, 30,
+        15, 32, 34, 31, 26, 24, 19, 3, 8, 12, 15, 23, 30,
+        21, 35, 35, 36, 33, 30, 24, 18, 4, 9, 12, 16, 24,
+        16, 37, 37, 38, 39, 36, 32, 27, 20, 5, 10, 14, 20,
+        23, 43, 39, 41, 42, 46, 40, 33, 28, 26, 7, 16, 22,
+        27, 46, 40, 43, 44, 47, 47, 41, 35, 31, 34, 10, 26,
+        29, 48, 45, 44, 45, 49, 48, 49, 42, 38, 40, 45, 13,
+    ],
+    // 상대 4명
+    [
+        0, 1, 1, 1, 2, 4, 5, 8, 9, 7, 9, 12, 13,
+        2, 0, 2, 3, 4, 8, 13, 14, 16, 18, 23, 27, 28,
+        3, 5, 0, 3, 3, 6, 11, 14, 14, 17, 20, 20, 23,
+        4, 10, 8, 0, 4, 6, 9, 13, 16, 17, 21, 22, 24,
+        7, 13, 11, 11, 0, 5, 7, 10, 13, 19, 20, 21, 24,
+        15, 25, 19, 18, 14, 1, 7, 9, 12, 16, 24, 24, 28,
+        21, 33, 29, 27, 21, 19, 1, 6, 9, 12, 17, 25, 26,
+        25, 35, 34, 32, 27, 24, 17, 2, 5, 8, 12, 17, 28,
+        30, 39, 35, 37, 33, 28, 22, 16, 3, 5, 9, 12, 18,
+        26, 42, 40, 39, 40, 36, 31, 22, 15, 5, 7, 10, 15,
+        31, 47, 42, 41, 41, 45, 38, 30, 23, 18, 6, 12, 16,
+        32, 49, 43, 43, 44, 44, 46, 36, 30, 26, 29, 8, 20,
+        35, 49, 46, 45, 45, 48, 48, 47, 38, 34, 37, 40, 10,
+    ],
+    // 상대 5명
+    [
+        0, 1, 1, 1, 2, 6, 7, 10, 10, 8, 10, 11, 15,
+        3, 0, 2, 2, 4, 9, 15, 16, 16, 21, 25, 26, 29,
+        4, 8, 0, 2, 3, 6, 10, 15, 16, 18, 20, 20, 24,
+        7, 12, 9, 0, 3, 6, 9, 11, 16, 18, 20, 20, 23,
+        13, 18, 13, 12, 0, 5, 7, 9, 11, 18, 21, 22, 24,
+        26, 29, 23, 21, 17, 1, 6, 7, 10, 15, 23, 24, 26,
+        30, 38, 31, 27, 22, 19, 1, 5, 6, 10, 16, 22, 24,
+        31, 39, 37, 30, 27, 23, 16, 2, 3, 6, 10, 15, 23,
+        36, 41, 36, 37, 33, 28, 19, 14, 2, 3, 5, 8, 15,
+        29, 48, 39, 40, 38, 35, 28, 19, 11, 3, 4, 6, 11,
+        32, 48, 43, 42, 41, 43, 35, 26, 17, 14, 5, 8, 13,
+        35, 49, 45, 42, 40, 45, 44, 34, 25, 21, 24, 5, 15,
+        40, 49, 47, 46, 45, 47, 46, 44, 33, 30, 32, 34, 7,
+    ],
+];
+
+/// 클래스별 에퀴티를 높은 순으로 정렬해 더 강한 클래스들의 조합 수 누적 비율로 버킷 결정
+#[cfg(test)]
+fn bucket_table(equity: impl Fn(usize, [u8; 2]) -> f64) -> [u8; 169] {
+    let classes = HandClass::all();
+    let mut ranked: Vec<(usize, f64)> = classes
+        .iter()
+        .enumerate()
+        .map(|(index, class)| (index, equity(index, class.combos(&[])[0])))
+        .collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then(a.0.cmp(&b.0)));
+
+    let mut table = [0u8; 169];
+    let mut stronger_combos = 0usize;
+    for (index, _) in ranked {
+        table[index] = (stronger_combos * PREFLOP_BUCKETS / 1326).min(PREFLOP_BUCKETS - 1) as u8;
+        stronger_combos += classes[index].combo_count();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "클래스마다 20만 번 샘플링하므로 릴리스 빌드에서도 몇 분 걸림"]
+    fn regenerate_multiway_table() {
+        // 1단계: 무작위 핸드 대비 헤즈업 순위로 상대 레인지 결정
+        let heads_up = bucket_table(|index, hole| multiway_equity(hole, 1, TABLE_SAMPLES, index as u64));
+        let cutoff = (OPPONENT_RANGE_FRACTION * PREFLOP_BUCKETS as f64) as u8;
+        let in_range = |cards: [u8; 2]| heads_up[HandClass::from_cards(cards).to_index() as usize] < cutoff;
+
+        // 2단계: 레인지를 가진 상대 수별 순위
+        println!();
+        println!("pub(super) const MULTIWAY_PREFLOP_BUCKETS: [[u8; 169]; MAX_MULTIWAY_OPPONENTS] = [");
+        for opponents in 1..=MAX_MULTIWAY_OPPONENTS {
+            let table = bucket_table(|index, hole| {
+                let seed = (opponents as u64) << 16 | index as u64;
+                multiway_equity_in_range(hole, opponents, in_range, TABLE_SAMPLES, seed)
+            });
+            println!("    // 상대 {}명", opponents);
+            println!("    [");
+            for row in table.chunks(13) {
+                let row: Vec<String> = row.iter().map(|bucket| bucket.to_string()).collect();
+                println!("        {},", row.join(", "));
+            }
+            println!("    ],");
+        }
+        println!("];");
+    }
+}
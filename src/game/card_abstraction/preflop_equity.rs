@@ -0,0 +1,101 @@
+// 169 x 169 프리플랍 클래스 대 클래스 올인 에퀴티 표
+// 클래스마다 겹치지 않는 조합 쌍과 보드를 샘플링해 미리 계산한 값을 assets/equity/에 담아 두었습니다.
+// 형식: 행(히어로 클래스) 우선, `HandClass::to_index` 순서의 u16 리틀 엔디언 (에퀴티 x 65535).
+// 표를 다시 만들려면:
+//
+//     cargo test --release --lib regenerate_preflop_equity_table -- --ignored
+
+use super::hand_class::HandClass;
+
+/// 크레이트에 포함된 에퀴티 표
+static PREFLOP_EQUITY_TABLE: &[u8] = include_bytes!("../../../assets/equity/preflop_169x169.bin");
+
+/// 표를 만들 때 클래스 쌍마다 샘플링한 런아웃 수
+#[cfg(test)]
+const TABLE_SAMPLES: usize = 20_000;
+
+/// `hero` 클래스가 `villain` 클래스와 프리플랍 올인했을 때의 에퀴티 (비기면 나눔)
+///
+/// 두 클래스의 겹치지 않는 조합 쌍을 모두 같은 가중치로 평균한 값이며, 샘플링 오차는 약 ±0.5%입니다.
+/// 같은 클래스끼리는 0.5입니다.
+pub fn preflop_class_equity(hero: HandClass, villain: HandClass) -> f64 {
+    if hero == villain {
+        return 0.5;
+    }
+    let offset = 2 * (hero.to_index() as usize * 169 + villain.to_index() as usize);
+    let raw = u16::from_le_bytes([PREFLOP_EQUITY_TABLE[offset], PREFLOP_EQUITY_TABLE[offset + 1]]);
+    raw as f64 / u16::MAX as f64
+}
+
+/// 두 클래스의 겹치지 않는 조합 쌍과 보드를 `samples`번 샘플링한 `hero`의 에퀴티
+#[cfg(test)]
+fn sample_class_equity(hero: HandClass, villain: HandClass, samples: usize, seed: u64) -> f64 {
+    use crate::game::hand_eval::PartialEval;
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+    let pairs: Vec<([u8; 2], [u8; 2])> = hero
+        .combos(&[])
+        .into_iter()
+        .flat_map(|h| villain.combos(&h).into_iter().map(move |v| (h, v)))
+        .collect();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut total = 0.0;
+    for _ in 0..samples {
+        let (h, v) = pairs[rng.gen_range(0..pairs.len())];
+        let mut deck: Vec<u8> = (0..52).filter(|card| !h.contains(card) && !v.contains(card)).collect();
+        let (board, _) = deck.partial_shuffle(&mut rng, 5);
+        let board_eval = PartialEval::from_cards(board);
+        let (hero_rank, villain_rank) = (board_eval.extend(&h), board_eval.extend(&v));
+        total += match hero_rank.cmp(&villain_rank) {
+            std::cmp::Ordering::Less => 1.0,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Greater => 0.0,
+        };
+    }
+    total / samples as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_antisymmetric_and_ordered() {
+        let classes = HandClass::all();
+        for &a in &classes {
+            assert_eq!(preflop_class_equity(a, a), 0.5);
+            for &b in &classes {
+                let sum = preflop_class_equity(a, b) + preflop_class_equity(b, a);
+                assert!((sum - 1.0).abs() < 1e-4, "{} vs {}: {}", a, b, sum);
+            }
+        }
+
+        let class = |text: &str| text.parse::<HandClass>().unwrap();
+        // 잘 알려진 값: AA vs KK 약 82%, AKo vs 22 약 47%, AKs vs 72o 약 67%
+        assert!((preflop_class_equity(class("AA"), class("KK")) - 0.82).abs() < 0.01);
+        assert!((preflop_class_equity(class("AKo"), class("22")) - 0.47).abs() < 0.015);
+        assert!(preflop_class_equity(class("AKs"), class("72o")) > 0.6);
+        assert!((preflop_class_equity(class("KQs"), class("KQs")) - 0.5).abs() < 1e-9);
+        assert!((sample_class_equity(class("AA"), class("KK"), 2_000, 1) - 0.82).abs() < 0.03);
+    }
+
+    #[test]
+    #[ignore = "클래스 쌍마다 2만 번 샘플링하므로 릴리스 빌드에서도 몇 분 걸림"]
+    fn regenerate_preflop_equity_table() {
+        let classes = HandClass::all();
+        let mut table = vec![0.5f64; 169 * 169];
+        for (i, &a) in classes.iter().enumerate() {
+            for (j, &b) in classes.iter().enumerate().skip(i + 1) {
+                let equity = sample_class_equity(a, b, TABLE_SAMPLES, (i * 169 + j) as u64);
+                table[i * 169 + j] = equity;
+                table[j * 169 + i] = 1.0 - equity;
+            }
+        }
+        let bytes: Vec<u8> = table
+            .iter()
+            .flat_map(|&equity| ((equity * u16::MAX as f64).round() as u16).to_le_bytes())
+            .collect();
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/equity/preflop_169x169.bin");
+        std::fs::write(path, bytes).unwrap();
+    }
+}
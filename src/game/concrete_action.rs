@@ -0,0 +1,149 @@
+// 구체적인 칩 액션 변환
+// 추상 액션(`Act`)과 외부 시스템이 기록하는 칩 단위 액션 사이를 변환합니다.
+
+use crate::game::holdem::{Act, State};
+use crate::solver::cfr_core::Game;
+use serde::{Deserialize, Serialize};
+
+/// 관찰된 베팅을 매핑할 레이즈 버킷 (팟 레이즈, 올인)
+pub const RAISE_BUCKETS: [Act; 2] = [Act::Raise(0), Act::Raise(1)];
+
+/// 구체적인 액션 종류
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ActionKind {
+    Fold,
+    /// 콜할 금액이 없을 때의 콜
+    Check,
+    Call,
+    /// 현재 스트리트에 베팅이 없을 때의 레이즈
+    Bet,
+    Raise,
+    /// 스택 전부를 거는 베팅/레이즈
+    AllIn,
+}
+
+/// 칩 금액이 명시된 액션
+///
+/// `amount`는 이 액션으로 스택에서 팟으로 옮겨지는 칩 수입니다 (폴드/체크는 0).
+/// JSON 표현: `{"kind": "Raise", "amount": 250}`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConcreteAction {
+    pub kind: ActionKind,
+    pub amount: u32,
+}
+
+impl ConcreteAction {
+    pub fn new(kind: ActionKind, amount: u32) -> Self {
+        Self { kind, amount }
+    }
+}
+
+/// 현재 액션할 플레이어가 `act`를 했을 때의 구체적인 액션
+///
+/// 금액 계산은 `State::next_state`와 같습니다.
+pub fn resolve(act: Act, state: &State) -> ConcreteAction {
+    let player = state.to_act.min(5);
+    let stack = state.stack[player];
+    let call_amount = state.to_call.saturating_sub(state.invested[player]).min(stack);
+
+    match act {
+        Act::Fold => ConcreteAction::new(ActionKind::Fold, 0),
+        Act::Call if call_amount == 0 => ConcreteAction::new(ActionKind::Check, 0),
+        Act::Call => ConcreteAction::new(ActionKind::Call, call_amount),
+        Act::Raise(size) => {
+            let amount = state.raise_amount(size);
+            let kind = if amount == stack {
+                ActionKind::AllIn
+            } else if state.to_call == 0 {
+                ActionKind::Bet
+            } else {
+                ActionKind::Raise
+            };
+            ConcreteAction::new(kind, amount)
+        }
+    }
+}
+
+/// 관찰된 구체적인 액션을 가장 가까운 추상 액션으로 매핑
+///
+/// 베팅/레이즈/올인은 `RAISE_BUCKETS` 중 금액이 가장 가까운 버킷으로 매핑합니다
+/// (같으면 앞쪽 버킷). 현재 상태에서 불가능한 액션이면 `None`을 반환합니다.
+pub fn abstract_from(concrete: ConcreteAction, state: &State) -> Option<Act> {
+    let legal_actions = State::legal_actions(state);
+    let player = state.to_act.min(5);
+    let call_amount = state.to_call.saturating_sub(state.invested[player]);
+
+    match concrete.kind {
+        ActionKind::Fold => legal_actions.contains(&Act::Fold).then_some(Act::Fold),
+        ActionKind::Check if call_amount > 0 => None,
+        ActionKind::Check | ActionKind::Call => legal_actions.contains(&Act::Call).then_some(Act::Call),
+        ActionKind::Bet | ActionKind::Raise | ActionKind::AllIn => {
+            if !legal_actions.iter().any(|act| matches!(act, Act::Raise(_))) {
+                return None;
+            }
+            RAISE_BUCKETS
+                .iter()
+                .copied()
+                .min_by_key(|&bucket| resolve(bucket, state).amount.abs_diff(concrete.amount))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_round_trips_legal_actions() {
+        // 프리플랍 UTG, 플랍 첫 액션, 숏스택 세 가지 상태
+        let preflop = State::new_hand([50, 100], [10_000; 6], 6);
+        let mut flop = preflop.clone();
+        flop.street = 1;
+        flop.invested = [0; 6];
+        flop.to_call = 0;
+        flop.pot = 600;
+        let mut short = preflop.clone();
+        short.stack[0] = 180;
+
+        for state in [&preflop, &flop, &short] {
+            for act in State::legal_actions(state) {
+                let concrete = resolve(act, state);
+                assert_eq!(abstract_from(concrete, state), Some(act), "{:?} -> {:?}", act, concrete);
+
+                // 금액은 next_state의 스택 변화와 같음
+                let next = State::next_state(state, act);
+                assert_eq!(concrete.amount, state.stack[0] - next.stack[0]);
+            }
+        }
+
+        assert_eq!(resolve(Act::Call, &flop), ConcreteAction::new(ActionKind::Check, 0));
+        assert_eq!(resolve(Act::Raise(0), &flop), ConcreteAction::new(ActionKind::Bet, 600));
+        assert_eq!(resolve(Act::Raise(0), &short), ConcreteAction::new(ActionKind::AllIn, 180));
+        assert_eq!(resolve(Act::Raise(1), &preflop), ConcreteAction::new(ActionKind::AllIn, 10_000));
+        assert_eq!(abstract_from(resolve(Act::Raise(1), &preflop), &preflop), Some(Act::Raise(1)));
+
+        // 체크할 수 없는 상황의 체크는 매핑되지 않음
+        assert_eq!(abstract_from(ConcreteAction::new(ActionKind::Check, 0), &preflop), None);
+    }
+
+    #[test]
+    fn test_observed_open_maps_to_nearest_bucket() {
+        let state = State::new_hand([50, 100], [10_000; 6], 6);
+        // 2.5배 오픈 (250)은 팟 레이즈(콜 100 + 팟 150 = 250)에 가장 가까움
+        let open = ConcreteAction::new(ActionKind::Raise, 250);
+        assert_eq!(abstract_from(open, &state), Some(Act::Raise(0)));
+        // 3배 오픈도 팟 레이즈, 80BB 레이즈는 올인 버킷
+        assert_eq!(abstract_from(ConcreteAction::new(ActionKind::Raise, 300), &state), Some(Act::Raise(0)));
+        assert_eq!(abstract_from(ConcreteAction::new(ActionKind::Raise, 8_000), &state), Some(Act::Raise(1)));
+    }
+
+    #[test]
+    fn test_concrete_action_json() {
+        let json = serde_json::to_string(&ConcreteAction::new(ActionKind::Raise, 250)).unwrap();
+        assert_eq!(json, r#"{"kind":"Raise","amount":250}"#);
+        let parsed: ConcreteAction = serde_json::from_str(r#"{"kind":"AllIn","amount":1000}"#).unwrap();
+        assert_eq!(parsed, ConcreteAction::new(ActionKind::AllIn, 1000));
+    }
+}
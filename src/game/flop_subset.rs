@@ -0,0 +1,337 @@
+// 플랍 부분집합 학습
+// 모든 보드로 포스트플랍을 학습하는 대신 수트 동형을 합친 1,755개 캐노니컬 플랍
+// (또는 더 작은 대표 부분집합)만 딜링하도록 플랍 찬스 노드를 제한합니다.
+//
+// 캐노니컬 플랍은 수트 순열 24가지 중 카드 번호를 정렬했을 때 사전순으로 가장 작은 표현입니다.
+// 딜링할 때는 캐노니컬 플랍을 고른 뒤 랜덤 수트 순열을 적용해 실제 카드로 되돌리므로,
+// 한 클래스 안의 구체적인 플랍들은 모두 같은 확률로 나옵니다.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// 수트 동형을 합친 캐노니컬 플랍 수
+pub const CANONICAL_FLOP_COUNT: usize = 1_755;
+
+/// 대표 부분집합 크기
+pub const REPRESENTATIVE_FLOP_COUNT: usize = 184;
+
+/// `Trainer::metadata`에 플랍 부분집합 설정(JSON)을 기록하는 키
+pub const FLOP_SUBSET_METADATA_KEY: &str = "flop_subset";
+
+/// 학습할 플랍 집합
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlopSet {
+    /// 1,755개 캐노니컬 플랍 전체
+    #[default]
+    CanonicalAll,
+    /// 텍스처(수트 패턴 × 페어 여부) 비율대로 고른 184개 대표 플랍
+    Representative184,
+    /// 직접 지정한 플랍 (캐노니컬로 변환 후 중복 제거)
+    Custom(Vec<[u8; 3]>),
+}
+
+/// 플랍 딜링 가중치
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlopWeighting {
+    /// 실제 딜링 빈도 (클래스에 속한 구체적인 플랍 수에 비례)
+    #[default]
+    Natural,
+    /// 모든 플랍을 같은 확률로
+    Uniform,
+}
+
+/// 플랍 부분집합 학습 설정
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlopSubsetConfig {
+    pub subset: FlopSet,
+    pub weighting: FlopWeighting,
+}
+
+impl FlopSubsetConfig {
+    /// 부분집합의 캐노니컬 플랍들
+    pub fn flops(&self) -> Vec<[u8; 3]> {
+        match &self.subset {
+            FlopSet::CanonicalAll => CANONICAL_FLOPS.iter().map(|&(flop, _)| flop).collect(),
+            FlopSet::Representative184 => REPRESENTATIVE_FLOPS.clone(),
+            FlopSet::Custom(flops) => {
+                let mut canonical: Vec<[u8; 3]> = flops.iter().map(|&flop| canonical_flop(flop)).collect();
+                canonical.sort_unstable();
+                canonical.dedup();
+                canonical
+            }
+        }
+    }
+}
+
+/// 플랍 찬스 노드용 샘플러
+///
+/// `holdem::State::flop_sampler`에 두면 플랍 딜링이 부분집합에서만 이루어집니다.
+#[derive(Debug)]
+pub struct FlopSampler {
+    config: FlopSubsetConfig,
+    flops: Vec<[u8; 3]>,
+    /// 누적 가중치 (`flops`와 같은 순서)
+    cumulative: Vec<u64>,
+    members: HashSet<[u8; 3]>,
+}
+
+impl FlopSampler {
+    /// 설정으로 샘플러 생성
+    ///
+    /// # Panics
+    /// 부분집합이 비어 있거나 52장 덱에 없는 카드, 중복 카드가 있는 플랍이 있으면 패닉합니다.
+    pub fn new(config: FlopSubsetConfig) -> Self {
+        if let FlopSet::Custom(flops) = &config.subset {
+            for flop in flops {
+                assert!(is_valid_flop(*flop), "잘못된 플랍: {:?}", flop);
+            }
+        }
+        let flops = config.flops();
+        assert!(!flops.is_empty(), "플랍 부분집합이 비어 있습니다");
+
+        let mut total = 0u64;
+        let cumulative = flops
+            .iter()
+            .map(|&flop| {
+                total += match config.weighting {
+                    FlopWeighting::Natural => natural_weight(flop) as u64,
+                    FlopWeighting::Uniform => 1,
+                };
+                total
+            })
+            .collect();
+        let members = flops.iter().copied().collect();
+        Self {
+            config,
+            flops,
+            cumulative,
+            members,
+        }
+    }
+
+    /// 샘플러 설정
+    pub fn config(&self) -> &FlopSubsetConfig {
+        &self.config
+    }
+
+    /// 부분집합의 캐노니컬 플랍들
+    pub fn flops(&self) -> &[[u8; 3]] {
+        &self.flops
+    }
+
+    /// 보드의 플랍(앞 3장)이 부분집합에 속하는지 확인 (플랍 전 보드는 항상 true)
+    pub fn contains(&self, board: &[u8]) -> bool {
+        match board {
+            [a, b, c, ..] => self.members.contains(&canonical_flop([*a, *b, *c])),
+            _ => true,
+        }
+    }
+
+    /// 가중치에 따라 캐노니컬 플랍을 고르고 랜덤 수트 배정으로 구체적인 플랍 딜링
+    ///
+    /// `dead` 카드(홀카드 등)와 겹치지 않는 수트 배정을 고르며, 어떤 배정도 불가능하면
+    /// 다른 플랍을 다시 고릅니다.
+    pub fn sample<R: Rng>(&self, rng: &mut R, dead: &[u8]) -> [u8; 3] {
+        let total = *self.cumulative.last().expect("샘플러는 비어 있지 않음");
+        let mut permutations = SUIT_PERMUTATIONS;
+        let mut fallback = None;
+        for _ in 0..64 {
+            let target = rng.gen_range(0..total);
+            let flop = self.flops[self.cumulative.partition_point(|&c| c <= target)];
+            permutations.shuffle(rng);
+            for &permutation in &permutations {
+                let concrete = permute_suits(flop, permutation);
+                if !concrete.iter().any(|card| dead.contains(card)) {
+                    return concrete;
+                }
+            }
+            fallback.get_or_insert(permute_suits(flop, permutations[0]));
+        }
+        fallback.expect("최소 한 번은 샘플링함")
+    }
+}
+
+/// 수트 동형을 합친 캐노니컬 플랍
+///
+/// 24가지 수트 순열을 적용해 카드 번호를 정렬한 표현 중 가장 작은 것입니다.
+pub fn canonical_flop(flop: [u8; 3]) -> [u8; 3] {
+    SUIT_PERMUTATIONS
+        .iter()
+        .map(|&permutation| permute_suits(flop, permutation))
+        .min()
+        .expect("수트 순열은 24개")
+}
+
+/// 캐노니컬 플랍 클래스에 속한 구체적인 플랍 수 (실제 딜링 빈도에 비례)
+pub fn natural_weight(flop: [u8; 3]) -> u32 {
+    CANONICAL_WEIGHTS.get(&canonical_flop(flop)).copied().unwrap_or(0)
+}
+
+/// 모든 수트 순열 (수트 i -> permutation[i])
+const SUIT_PERMUTATIONS: [[u8; 4]; 24] = [
+    [0, 1, 2, 3], [0, 1, 3, 2], [0, 2, 1, 3], [0, 2, 3, 1], [0, 3, 1, 2], [0, 3, 2, 1],
+    [1, 0, 2, 3], [1, 0, 3, 2], [1, 2, 0, 3], [1, 2, 3, 0], [1, 3, 0, 2], [1, 3, 2, 0],
+    [2, 0, 1, 3], [2, 0, 3, 1], [2, 1, 0, 3], [2, 1, 3, 0], [2, 3, 0, 1], [2, 3, 1, 0],
+    [3, 0, 1, 2], [3, 0, 2, 1], [3, 1, 0, 2], [3, 1, 2, 0], [3, 2, 0, 1], [3, 2, 1, 0],
+];
+
+/// 수트 순열을 적용하고 카드 번호순으로 정렬
+fn permute_suits(flop: [u8; 3], permutation: [u8; 4]) -> [u8; 3] {
+    let mut permuted = flop.map(|card| permutation[(card / 13) as usize] * 13 + card % 13);
+    permuted.sort_unstable();
+    permuted
+}
+
+fn is_valid_flop(flop: [u8; 3]) -> bool {
+    flop.iter().all(|&card| card < 52) && flop[0] != flop[1] && flop[0] != flop[2] && flop[1] != flop[2]
+}
+
+lazy_static::lazy_static! {
+    /// 캐노니컬 플랍과 클래스 크기 (캐노니컬 표현 오름차순)
+    static ref CANONICAL_FLOPS: Vec<WeightedFlop> = {
+        let mut weights: HashMap<[u8; 3], u32> = HashMap::new();
+        for a in 0..52u8 {
+            for b in a + 1..52 {
+                for c in b + 1..52 {
+                    *weights.entry(canonical_flop([a, b, c])).or_insert(0) += 1;
+                }
+            }
+        }
+        let mut flops: Vec<WeightedFlop> = weights.into_iter().collect();
+        flops.sort_unstable();
+        flops
+    };
+
+    static ref CANONICAL_WEIGHTS: HashMap<[u8; 3], u32> = CANONICAL_FLOPS.iter().copied().collect();
+
+    /// 텍스처 그룹별로 실제 빈도에 비례하게 배분해 고른 대표 플랍
+    static ref REPRESENTATIVE_FLOPS: Vec<[u8; 3]> = representative_flops(REPRESENTATIVE_FLOP_COUNT);
+}
+
+/// 텍스처 그룹 (수트 종류 수, 랭크 종류 수)
+type Texture = (usize, usize);
+
+/// 캐노니컬 플랍과 클래스 크기
+type WeightedFlop = ([u8; 3], u32);
+
+/// 플랍의 텍스처 그룹
+fn texture(flop: [u8; 3]) -> Texture {
+    let distinct = |values: [u8; 3]| values.iter().collect::<HashSet<_>>().len();
+    (distinct(flop.map(|card| card / 13)), distinct(flop.map(|card| card % 13)))
+}
+
+/// 에이스 하이 랭크 내림차순 (대표 플랍을 고르게 뽑기 위한 정렬 기준)
+fn rank_profile(flop: [u8; 3]) -> [u8; 3] {
+    let mut ranks = flop.map(|card| (card % 13 + 12) % 13);
+    ranks.sort_unstable_by(|a, b| b.cmp(a));
+    ranks
+}
+
+/// 텍스처 그룹마다 실제 빈도 비율로 자리를 배분(최대 나머지 방식, 그룹당 최소 1개)하고
+/// 그룹 안에서는 랭크 순으로 정렬해 같은 간격으로 고름
+fn representative_flops(count: usize) -> Vec<[u8; 3]> {
+    let mut groups: HashMap<Texture, Vec<WeightedFlop>> = HashMap::new();
+    for &(flop, weight) in CANONICAL_FLOPS.iter() {
+        groups.entry(texture(flop)).or_default().push((flop, weight));
+    }
+    let mut groups: Vec<(Texture, Vec<WeightedFlop>)> = groups.into_iter().collect();
+    groups.sort_unstable_by_key(|(key, _)| *key);
+
+    let total: u64 = CANONICAL_FLOPS.iter().map(|&(_, weight)| weight as u64).sum();
+    let shares: Vec<f64> = groups
+        .iter()
+        .map(|(_, flops)| flops.iter().map(|&(_, w)| w as f64).sum::<f64>() / total as f64 * count as f64)
+        .collect();
+    let mut slots: Vec<usize> = shares
+        .iter()
+        .zip(&groups)
+        .map(|(share, (_, flops))| (share.floor() as usize).clamp(1, flops.len()))
+        .collect();
+    let mut by_remainder: Vec<usize> = (0..groups.len()).collect();
+    by_remainder.sort_by(|&a, &b| {
+        let remainder = |i: usize| shares[i] - shares[i].floor();
+        remainder(b).partial_cmp(&remainder(a)).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    for &group in by_remainder.iter().cycle().take(groups.len() * count) {
+        if slots.iter().sum::<usize>() >= count {
+            break;
+        }
+        if slots[group] < groups[group].1.len() {
+            slots[group] += 1;
+        }
+    }
+
+    let mut selected = Vec::with_capacity(count);
+    for ((_, flops), slot) in groups.iter_mut().zip(slots) {
+        flops.sort_unstable_by_key(|&(flop, _)| (rank_profile(flop), flop));
+        selected.extend((0..slot).map(|i| flops[(2 * i + 1) * flops.len() / (2 * slot)].0));
+    }
+    selected.sort_unstable();
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::card_abstraction::hand_class::make_card;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_canonical_flop_classes() {
+        assert_eq!(CANONICAL_FLOPS.len(), CANONICAL_FLOP_COUNT);
+        assert_eq!(CANONICAL_FLOPS.iter().map(|&(_, w)| w).sum::<u32>(), 22_100);
+
+        // 수트만 바꾼 플랍은 같은 클래스
+        let ak7_rainbow = [make_card(12, 0), make_card(11, 1), make_card(5, 2)];
+        let same_class = [make_card(12, 3), make_card(11, 2), make_card(5, 0)];
+        assert_eq!(canonical_flop(ak7_rainbow), canonical_flop(same_class));
+
+        // 레인보우 24가지, 모노톤 4가지, 트리플 4가지 수트 배정
+        let ak7_monotone = [make_card(12, 1), make_card(11, 1), make_card(5, 1)];
+        let trips = [make_card(3, 0), make_card(3, 1), make_card(3, 2)];
+        assert_eq!(natural_weight(ak7_rainbow), 24);
+        assert_eq!(natural_weight(ak7_monotone), 4);
+        assert_eq!(natural_weight(trips), 4);
+
+        let representative = FlopSubsetConfig {
+            subset: FlopSet::Representative184,
+            weighting: FlopWeighting::Natural,
+        }
+        .flops();
+        assert_eq!(representative.len(), REPRESENTATIVE_FLOP_COUNT);
+        let groups: HashSet<Texture> = representative.iter().map(|&flop| texture(flop)).collect();
+        let all_groups: HashSet<Texture> = CANONICAL_FLOPS.iter().map(|&(flop, _)| texture(flop)).collect();
+        assert_eq!(groups, all_groups);
+    }
+
+    #[test]
+    fn test_natural_weighting_follows_suit_combinatorics() {
+        let rainbow = [make_card(12, 0), make_card(11, 1), make_card(5, 2)];
+        let monotone = [make_card(12, 1), make_card(11, 1), make_card(5, 1)];
+        let sample_counts = |weighting: FlopWeighting| {
+            let sampler = FlopSampler::new(FlopSubsetConfig {
+                subset: FlopSet::Custom(vec![rainbow, monotone]),
+                weighting,
+            });
+            let mut rng = StdRng::seed_from_u64(5);
+            let dead = [make_card(0, 0), make_card(0, 1)];
+            let mut counts = [0u32; 2];
+            for _ in 0..14_000 {
+                let flop = sampler.sample(&mut rng, &dead);
+                assert!(!flop.iter().any(|card| dead.contains(card)));
+                let index = if canonical_flop(flop) == canonical_flop(rainbow) { 0 } else { 1 };
+                counts[index] += 1;
+            }
+            counts[0] as f64 / counts[1] as f64
+        };
+
+        // 레인보우 클래스는 구체적인 플랍이 24개, 모노톤은 4개라 6배 자주 나옴
+        let natural = sample_counts(FlopWeighting::Natural);
+        assert!((5.4..6.6).contains(&natural), "{}", natural);
+        let uniform = sample_counts(FlopWeighting::Uniform);
+        assert!((0.9..1.1).contains(&uniform), "{}", uniform);
+    }
+}
@@ -1,6 +1,9 @@
 // 포커 핸드 평가 모듈
 // 7장 카드로 최고 5장 핸드의 랭킹 계산
 
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
 /// 7장 카드 핸드 평가 함수
 /// 
 /// 텍사스 홀덤에서 2장 홀카드 + 5장 보드카드 = 7장으로
@@ -21,6 +24,11 @@
 /// - 21294-32487: 원페어
 /// - 32488-46672: 하이카드
 pub fn v7(cards: [u8; 7]) -> u32 {
+    // 서로 다른 정상 카드면 조합 없이 바로 계산 (올인 판정처럼 대량 평가용)
+    if let Some(rank) = evaluate_7cards_direct(cards, Ranking::Standard) {
+        return rank;
+    }
+
     // 7장에서 가능한 모든 5장 조합 평가
     let mut best_rank = u32::MAX;
     let mut best_hand = [0u8; 5];
@@ -61,142 +69,359 @@ pub fn v7(cards: [u8; 7]) -> u32 {
 }
 
 /// 5장 카드 핸드 평가 (실제 포커 로직)
+///
+/// 같은 족보 안에서는 에이스를 가장 높은 카드로 비교합니다
+/// (A-2-3-4-5 스트레이트에서만 로우로 취급).
 fn evaluate_5cards(cards: [u8; 5]) -> u32 {
-    let mut ranks = [0u8; 5];
-    let mut suits = [0u8; 5];
+    // 에이스 하이 랭크 (2=0, 3=1, ..., K=11, A=12)
     let mut rank_counts = [0u8; 13];
-    
-    // 카드를 랭크와 수트로 분해
-    for (i, &card) in cards.iter().enumerate() {
-        let rank = card % 13;
-        let suit = card / 13;
-        ranks[i] = rank;
-        suits[i] = suit;
+    let mut rank_mask = 0u16;
+    for &card in &cards {
+        let rank = high_rank(card);
         rank_counts[rank as usize] += 1;
+        rank_mask |= 1 << rank;
     }
-    
 
-    
     // 플러시 체크
-    let is_flush = suits.iter().all(|&s| s == suits[0]);
-    
+    let is_flush = cards.iter().all(|&c| c / 13 == cards[0] / 13);
+
     // 스트레이트 체크
-    let (is_straight, is_low_straight, straight_high) = check_straight(&rank_counts);
-    
-    // 페어/트리플 등 분석 - 개수별로 정렬
-    let mut pair_counts: Vec<(u8, u8)> = rank_counts.iter().enumerate()
-        .filter(|(_, &count)| count > 0)
-        .map(|(rank, &count)| (count, rank as u8))
-        .collect();
-    pair_counts.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1))); // 개수 먼저, 그 다음 랭크
-    
+    let straight_high = straight_high(rank_mask);
 
-    
-    // 핸드 타입 판정 및 순위 계산
+    // 페어/트리플 등 분석 - 개수 내림차순, 같은 개수면 랭크 내림차순
+    let mut groups = [(0u8, 0u8); 5];
+    let mut group_count = 0;
+    for rank in (0..13u8).rev() {
+        let count = rank_counts[rank as usize];
+        if count > 0 && group_count < groups.len() {
+            groups[group_count] = (count, rank);
+            group_count += 1;
+        }
+    }
+    groups[..group_count].sort_by_key(|g| std::cmp::Reverse(g.0)); // 안정 정렬이라 랭크 순서 유지
+
+    let below = |rank: u8| (12 - rank) as u32; // 높은 카드일수록 낮은(강한) 순위
+
+    match (is_flush, straight_high, &groups[..group_count]) {
+        // 스트레이트 플러시 (로얄 = 1)
+        (true, Some(high), _) => 1 + below(high),
 
-    
-    match (is_flush, is_straight || is_low_straight, &pair_counts[..]) {
-        // 스트레이트 플러시
-        (true, true, _) => {
-            if is_low_straight {
-                1599 // A-2-3-4-5 스트레이트 플러시 (가장 낮음)
-            } else {
-                1 + (14 - straight_high) as u32 // 높은 카드일수록 낮은 순위
-            }
-        },
-        
         // 포카드
-        (_, _, [(4, quad_rank), (1, kicker), ..]) => {
-            1600 + (13 - quad_rank) as u32 * 13 + (13 - kicker) as u32
-        },
-        
+        (_, _, [(4, quad_rank), (1, kicker)]) => 1600 + below(*quad_rank) * 13 + below(*kicker),
+
         // 풀하우스
-        (_, _, [(3, trip_rank), (2, pair_rank), ..]) => {
-            2500 + (13 - trip_rank) as u32 * 13 + (13 - pair_rank) as u32
-        },
-        
-        // 플러시
-        (true, false, _) => {
-            let mut flush_ranks = ranks;
-            flush_ranks.sort_by(|a, b| b.cmp(a)); // 내림차순
-            3825 + rank_value_sum(&flush_ranks, &[1, 2, 3, 4, 5])
-        },
-        
-        // 스트레이트
-        (false, true, _) => {
-            if is_low_straight {
-                5863 // A-2-3-4-5 스트레이트 (가장 낮음)
-            } else {
-                5109 + (14 - straight_high) as u32
-            }
-        },
-        
+        (_, _, [(3, trip_rank), (2, pair_rank)]) => {
+            2500 + below(*trip_rank) * 13 + below(*pair_rank)
+        }
+
+        // 플러시 (스트레이트 조합을 제외한 1,277가지를 촘촘하게 배치)
+        (true, None, _) => 3825 + flush_index(rank_mask),
+
+        // 스트레이트 (A-2-3-4-5가 가장 낮음)
+        (false, Some(high), _) => 5109 + below(high),
+
         // 트리플
-        (_, _, [(3, trip_rank), (1, kicker1), (1, kicker2), ..]) => {
-            5864 + (13 - trip_rank) as u32 * 169 + (13 - kicker1) as u32 * 13 + (13 - kicker2) as u32
-        },
-        
+        (_, _, [(3, trip_rank), (1, kicker1), (1, kicker2)]) => {
+            5864 + below(*trip_rank) * 169 + below(*kicker1) * 13 + below(*kicker2)
+        }
+
         // 투페어
-        (_, _, [(2, pair1), (2, pair2), (1, kicker), ..]) => {
-            8920 + (13 - pair1) as u32 * 169 + (13 - pair2) as u32 * 13 + (13 - kicker) as u32
-        },
-        
-        // 원페어 - 정확히 1개의 페어와 3개의 킥커가 있는 경우
+        (_, _, [(2, pair1), (2, pair2), (1, kicker)]) => {
+            8920 + below(*pair1) * 169 + below(*pair2) * 13 + below(*kicker)
+        }
+
+        // 원페어 - 킥커 3장은 13C3 = 286가지 조합 순위로 비교
         (_, _, [(2, pair_rank), (1, k1), (1, k2), (1, k3)]) => {
-            let rank = 21294 + (13 - pair_rank) as u32 * 715 + 
-                    (13 - k1) as u32 * 55 + 
-                    (13 - k2) as u32 * 4 + 
-                    (13 - k3) as u32;
-            rank
-        },
-        
-        // 하이카드
-        _ => {
-            let mut sorted_ranks = ranks;
-            sorted_ranks.sort_by(|a, b| b.cmp(a));
-            32488 + rank_value_sum(&sorted_ranks, &[1, 2, 3, 4, 5])
+            let kickers = (1u16 << k1) | (1 << k2) | (1 << k3);
+            21294 + below(*pair_rank) * 286 + (285 - colex_index(kickers))
         }
+
+        // 하이카드 (중복 카드 등 비정상 입력도 여기서 처리)
+        _ => 32488 + (1286 - colex_index(rank_mask)),
     }
 }
 
-/// 스트레이트 체크 (개선된 버전)
-fn check_straight(rank_counts: &[u8; 13]) -> (bool, bool, u8) {
-    // A-2-3-4-5 로우 스트레이트 체크
-    let is_low_straight = rank_counts[0] > 0 && rank_counts[1] > 0 && 
-                         rank_counts[2] > 0 && rank_counts[3] > 0 && rank_counts[4] > 0;
-    
-    // 일반 스트레이트 체크 (5-6-7-8-9부터 10-J-Q-K-A까지)
-    let mut consecutive = 0;
-    let mut straight_high = 0;
-    
-    for i in 0..13 {
-        if rank_counts[i] > 0 {
-            consecutive += 1;
-            if consecutive >= 5 {
-                // 스트레이트의 하이카드는 연속된 5장 중 가장 높은 카드
-                straight_high = i as u8;
-                return (true, is_low_straight, straight_high);
+/// 족보 규칙
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Ranking {
+    /// 일반 홀덤
+    Standard,
+    /// 숏덱 (플러시 > 풀하우스, A-6-7-8-9가 가장 낮은 스트레이트)
+    ///
+    /// 플러시와 풀하우스의 랭킹 구간이 서로 바뀝니다 (플러시 2500-3824, 풀하우스 3825-5108).
+    ShortDeck,
+}
+
+/// 7장에서 최고 5장 핸드를 조합 없이 바로 계산 (`evaluate_5cards` 21회와 같은 결과)
+///
+/// 중복되거나 범위를 벗어난 카드가 있으면 None.
+pub(crate) fn evaluate_7cards_direct(cards: [u8; 7], ranking: Ranking) -> Option<u32> {
+    let eval = PartialEval::for_ranking(&cards, ranking);
+    (!eval.invalid).then(|| eval.classify())
+}
+
+/// 핸드 랭킹 값 (낮을수록 강한 핸드, 구간은 `v7()` 참고)
+pub type HandRank = u32;
+
+/// 증분 핸드 평가기
+///
+/// 고정된 카드 일부(보드, 홀카드 + 보드 등)의 수트별 랭크 집합과 장수별 랭크 집합을 미리 쌓아 두고,
+/// 남은 1-2장만 더해 `v7()`과 같은 랭킹 값을 계산합니다. 남은 보드 열거나 턴 카드별 리포트처럼
+/// 대부분의 카드를 공유하는 평가를 반복할 때 공유하는 카드를 매번 다시 세지 않습니다.
+///
+/// ```
+/// use nice_hand_core::hand_eval::{v7, PartialEval};
+///
+/// // 홀카드 2장 + 턴 보드 4장을 고정하고 리버만 바꿔 가며 평가
+/// let fixed = [0, 13, 14, 29, 44, 7];
+/// let partial = PartialEval::from_cards(&fixed);
+/// assert_eq!(partial.extend(&[51]), v7([0, 13, 14, 29, 44, 7, 51]));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PartialEval {
+    /// 더한 카드 (앞에서부터 최대 7장, 잘못된 입력의 대체 평가용)
+    cards: [u8; 7],
+    /// 더한 카드 수
+    len: u8,
+    /// 이미 더한 카드 집합 (비트 = 카드 번호)
+    seen: u64,
+    /// 수트별 랭크 집합 (에이스 하이 랭크 비트)
+    suit_masks: [u16; 4],
+    /// `at_least[n]`: n + 1장 이상 있는 랭크 집합
+    at_least: [u16; 4],
+    /// 중복되거나 범위를 벗어난 카드, 또는 7장을 넘는 카드가 있었는지
+    invalid: bool,
+    ranking: Ranking,
+}
+
+impl PartialEval {
+    /// 고정할 카드(0-7장)로 일반 홀덤 평가기 생성
+    pub fn from_cards(cards: &[u8]) -> PartialEval {
+        Self::for_ranking(cards, Ranking::Standard)
+    }
+
+    /// 족보 규칙을 지정해 생성
+    pub(crate) fn for_ranking(cards: &[u8], ranking: Ranking) -> PartialEval {
+        let empty = PartialEval {
+            cards: [0; 7],
+            len: 0,
+            seen: 0,
+            suit_masks: [0; 4],
+            at_least: [0; 4],
+            invalid: false,
+            ranking,
+        };
+        empty.with_cards(cards)
+    }
+
+    /// 카드를 더 고정한 평가기 (원래 평가기는 그대로)
+    ///
+    /// 보드를 한 장씩 열거할 때 단계마다 한 장씩 더해 두면 마지막 카드만 `extend`로 평가하면 됩니다.
+    pub fn with_cards(&self, extra: &[u8]) -> PartialEval {
+        let mut next = *self;
+        for &card in extra {
+            next.add(card);
+        }
+        next
+    }
+
+    /// 남은 카드를 더해 최고 5장 핸드의 랭킹 계산
+    ///
+    /// 합쳐서 7장이면 `evaluate_7cards`, 5-6장이면 `best_hand_rank`와 같은 값입니다.
+    /// 5장 미만이거나 7장을 넘으면 `u32::MAX`이고, 겹치는 카드가 있으면 일반 홀덤은 `v7()`처럼
+    /// 5장 조합으로 평가하며 숏덱은 `u32::MAX`입니다.
+    pub fn extend(&self, extra: &[u8]) -> HandRank {
+        let eval = self.with_cards(extra);
+        if !eval.invalid && eval.len >= 5 {
+            return eval.classify();
+        }
+        match eval.ranking {
+            Ranking::Standard if eval.len <= 7 => best_hand_rank(&eval.cards[..eval.len as usize]).unwrap_or(u32::MAX),
+            _ => u32::MAX,
+        }
+    }
+
+    fn add(&mut self, card: u8) {
+        if let Some(slot) = self.cards.get_mut(self.len as usize) {
+            *slot = card;
+        }
+        self.len = self.len.saturating_add(1);
+        if card >= 52 || self.seen & (1 << card) != 0 || self.len > 7 {
+            self.invalid = true;
+            return;
+        }
+        self.seen |= 1 << card;
+
+        let bit = 1u16 << high_rank(card);
+        self.at_least[3] |= self.at_least[2] & bit;
+        self.at_least[2] |= self.at_least[1] & bit;
+        self.at_least[1] |= self.at_least[0] & bit;
+        self.at_least[0] |= bit;
+        self.suit_masks[(card / 13) as usize] |= bit;
+    }
+
+    /// 겹치지 않는 5-7장의 최고 5장 핸드 랭킹
+    fn classify(&self) -> u32 {
+        let below = |rank: u8| (12 - rank) as u32;
+        let highest = |mask: u16| 15 - mask.leading_zeros() as u8;
+        let without = |mask: u16, rank: u8| mask & !(1 << rank);
+        // 높은 랭크부터 n개 (낮은 비트를 지워 가며)
+        let top_ranks = |mut mask: u16, n: u32| {
+            while mask.count_ones() > n {
+                mask &= mask - 1;
             }
-        } else {
-            consecutive = 0;
+            mask
+        };
+        let straight = |mask: u16| match self.ranking {
+            Ranking::Standard => straight_high(mask),
+            Ranking::ShortDeck => short_deck_straight_high(mask),
+        };
+
+        let [rank_mask, pairs, trips, quads] = self.at_least;
+        let flush_mask = self.suit_masks.iter().copied().find(|mask| mask.count_ones() >= 5);
+
+        // 스트레이트 플러시
+        if let Some(high) = flush_mask.and_then(straight) {
+            return 1 + below(high);
+        }
+
+        // 포카드
+        if quads != 0 {
+            let quad = highest(quads);
+            return 1600 + below(quad) * 13 + below(highest(without(rank_mask, quad)));
+        }
+
+        // 풀하우스 (두 번째 트리플도 페어로 사용 가능)
+        let full_house = (trips != 0).then(|| highest(trips)).and_then(|trip| {
+            let pair = without(pairs, trip);
+            (pair != 0).then(|| below(trip) * 13 + below(highest(pair)))
+        });
+        let flush = flush_mask.map(|mask| flush_index(top_ranks(mask, 5)));
+
+        // 플러시 (숏덱은 풀하우스보다 먼저)
+        let (first, second) = match self.ranking {
+            Ranking::Standard => (full_house, flush),
+            Ranking::ShortDeck => (flush, full_house),
+        };
+        if let Some(index) = first {
+            return 2500 + index;
+        }
+        if let Some(index) = second {
+            return 3825 + index;
+        }
+
+        // 스트레이트
+        if let Some(high) = straight(rank_mask) {
+            return 5109 + below(high);
+        }
+
+        // 트리플
+        if trips != 0 {
+            let trip = highest(trips);
+            let kickers = top_ranks(without(rank_mask, trip), 2);
+            let k1 = highest(kickers);
+            let k2 = highest(without(kickers, k1));
+            return 5864 + below(trip) * 169 + below(k1) * 13 + below(k2);
         }
+
+        // 투페어 (세 번째 페어는 킥커 후보)
+        if pairs.count_ones() >= 2 {
+            let pair1 = highest(pairs);
+            let pair2 = highest(without(pairs, pair1));
+            let kicker = highest(without(without(rank_mask, pair1), pair2));
+            return 8920 + below(pair1) * 169 + below(pair2) * 13 + below(kicker);
+        }
+
+        // 원페어
+        if pairs != 0 {
+            let pair = highest(pairs);
+            let kickers = top_ranks(without(rank_mask, pair), 3);
+            return 21294 + below(pair) * 286 + (285 - colex_index(kickers));
+        }
+
+        // 하이카드
+        32488 + (1286 - colex_index(top_ranks(rank_mask, 5)))
     }
-    
-    // 10-J-Q-K-A 스트레이트 체크 (Ace가 하이카드인 경우)
-    if rank_counts[9] > 0 && rank_counts[10] > 0 && rank_counts[11] > 0 && 
-       rank_counts[12] > 0 && rank_counts[0] > 0 {
-        return (true, false, 0); // Ace 하이 스트레이트에서 Ace는 랭크 0이지만 실제로는 14로 취급
+}
+
+/// 에이스를 가장 높게 둔 랭크 (2=0, ..., K=11, A=12)
+fn high_rank(card: u8) -> u8 {
+    (card % 13 + 12) % 13
+}
+
+/// 스트레이트의 가장 높은 카드 랭크 (A-2-3-4-5는 5 = 3)
+fn straight_high(rank_mask: u16) -> Option<u8> {
+    if let Some(high) = run_high(rank_mask) {
+        return Some(high);
     }
-    
-    (false, is_low_straight, straight_high)
+    // A-2-3-4-5 로우 스트레이트
+    let wheel = (1u16 << 12) | 0xF;
+    (rank_mask & wheel == wheel).then_some(3)
 }
 
+/// 숏덱 스트레이트의 가장 높은 카드 랭크 (A-6-7-8-9는 9 = 7)
+fn short_deck_straight_high(rank_mask: u16) -> Option<u8> {
+    // 2-5가 없으므로 이어진 5장은 항상 10 하이 이상
+    if let Some(high) = run_high(rank_mask) {
+        return Some(high);
+    }
+    // 에이스가 6 아래로 이어짐
+    let low = (1u16 << 12) | (0xF << 4);
+    (rank_mask & low == low).then_some(7)
+}
 
-/// 랭크 값 합계 계산 (타이브레이커용)
-fn rank_value_sum(ranks: &[u8], multipliers: &[u32]) -> u32 {
-    ranks.iter().zip(multipliers.iter())
-        .map(|(&rank, &mult)| (13 - rank) as u32 * mult)
-        .sum()
+/// 랭크 5개가 이어진 가장 높은 구간의 꼭대기 랭크 (에이스 로우 제외)
+fn run_high(rank_mask: u16) -> Option<u8> {
+    // 비트 i: 랭크 i..=i+4가 모두 있음
+    let runs = rank_mask & rank_mask >> 1 & rank_mask >> 2 & rank_mask >> 3 & rank_mask >> 4;
+    (runs != 0).then(|| 15 - runs.leading_zeros() as u8 + 4)
+}
+
+/// 랭크 집합의 colex 순위 (높은 카드 위주로 비교했을 때의 오름차순 순위)
+fn colex_index(rank_mask: u16) -> u32 {
+    let mut mask = rank_mask;
+    let mut index = 0;
+    let mut k = 1;
+    while mask != 0 {
+        index += BINOMIALS[mask.trailing_zeros() as usize][k];
+        mask &= mask - 1;
+        k += 1;
+    }
+    index
+}
+
+/// 플러시 순위 (0 = A-K-Q-J-9), 더 강한 스트레이트 조합 수만큼 당겨서 계산
+fn flush_index(rank_mask: u16) -> u32 {
+    let colex = colex_index(rank_mask);
+    let stronger_straights = (3..=12u8)
+        .map(|high| {
+            if high == 3 {
+                (1u16 << 12) | 0xF
+            } else {
+                0x1F << (high - 4)
+            }
+        })
+        .filter(|&straight| colex_index(straight) > colex)
+        .count() as u32;
+    1286 - colex - stronger_straights
+}
+
+/// nCk 표 (n = 랭크 0-12, k = 0-5, 5장을 넘는 집합은 5장 핸드에 없음)
+const BINOMIALS: [[u32; 6]; 13] = binomial_table();
+
+const fn binomial_table() -> [[u32; 6]; 13] {
+    let mut table = [[0u32; 6]; 13];
+    let mut n = 0;
+    while n < 13 {
+        table[n][0] = 1;
+        let mut k = 1;
+        while k < 6 && k <= n {
+            table[n][k] = table[n][k - 1] * (n - k + 1) as u32 / k as u32;
+            k += 1;
+        }
+        n += 1;
+    }
+    table
 }
 
 /// 7장 카드 핸드 평가 - v7()의 별칭
@@ -213,6 +438,28 @@ pub fn evaluate_7cards(cards: [u8; 7]) -> u32 {
     v7(cards)
 }
 
+/// 5-7장 카드 중 최고 5장 핸드 평가 (플랍/턴처럼 보드가 덜 깔린 상황용)
+///
+/// `v7()`과 같은 랭킹 값을 반환합니다. 카드가 5장 미만이거나 7장보다 많으면 None.
+pub fn best_hand_rank(cards: &[u8]) -> Option<u32> {
+    match *cards {
+        [a, b, c, d, e] => Some(evaluate_5cards([a, b, c, d, e])),
+        // 6장이면 한 장씩 빼 본 5장 중 최고
+        [_, _, _, _, _, _] => (0..6)
+            .map(|skip| {
+                let mut hand = [0u8; 5];
+                let rest = cards.iter().enumerate().filter(|&(i, _)| i != skip);
+                for (slot, (_, &card)) in hand.iter_mut().zip(rest) {
+                    *slot = card;
+                }
+                evaluate_5cards(hand)
+            })
+            .min(),
+        [a, b, c, d, e, f, g] => Some(v7([a, b, c, d, e, f, g])),
+        _ => None,
+    }
+}
+
 /// 핸드 강도를 텍스트로 변환
 /// 
 /// # 매개변수
@@ -265,6 +512,368 @@ pub fn card_to_string(card: u8) -> String {
     format!("{}{}", rank_str, suit_str)
 }
 
+/// 카드 랭크 (에이스가 가장 높음, 선언 순서가 비교 순서)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Rank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Rank {
+    /// 낮은 순서의 모든 랭크
+    pub const ALL: [Rank; 13] = [
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+
+    /// 카드 번호(0-51)의 랭크
+    pub fn of_card(card: u8) -> Rank {
+        Self::ALL[high_rank(card) as usize]
+    }
+
+    /// 한 글자 기호 ('2'-'9', 'T', 'J', 'Q', 'K', 'A')
+    pub fn symbol(self) -> char {
+        b"23456789TJQKA"[self as usize] as char
+    }
+
+    /// 영어 이름 ("Ace")
+    pub fn name(self) -> &'static str {
+        [
+            "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Jack", "Queen", "King", "Ace",
+        ][self as usize]
+    }
+
+    /// 영어 복수형 ("Aces", "Sixes")
+    pub fn plural(self) -> &'static str {
+        [
+            "Twos", "Threes", "Fours", "Fives", "Sixes", "Sevens", "Eights", "Nines", "Tens", "Jacks", "Queens",
+            "Kings", "Aces",
+        ][self as usize]
+    }
+}
+
+/// 최고 5장 핸드의 족보와 비교에 쓰이는 랭크들
+///
+/// 랭크 목록은 모두 높은 순서이고, 파생된 `Ord`는 평가기와 같은 순서입니다:
+/// 겹치지 않는 5-7장 핸드 `a`, `b`에 대해 `classify(a) > classify(b)`는
+/// `best_hand_rank(a) < best_hand_rank(b)`와 같습니다 (랭킹 값은 낮을수록 강함).
+/// 5장 미만의 핸드(프리플랍 홀카드 등)는 킥커가 있는 만큼만 들어갑니다.
+///
+/// `Display`는 영어 설명("Two Pair, Aces and Nines, King kicker"),
+/// [`HandCategory::short_code`]는 기계용 코드("2P-A-9-K")입니다.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum HandCategory {
+    HighCard { kickers: Vec<Rank> },
+    Pair { rank: Rank, kickers: Vec<Rank> },
+    TwoPair { high: Rank, low: Rank, kicker: Option<Rank> },
+    Trips { rank: Rank, kickers: Vec<Rank> },
+    /// 가장 높은 카드 (A-2-3-4-5는 `Five`)
+    Straight { high: Rank },
+    Flush { ranks: Vec<Rank> },
+    FullHouse { trips: Rank, pair: Rank },
+    Quads { rank: Rank, kicker: Option<Rank> },
+    StraightFlush { high: Rank },
+}
+
+impl HandCategory {
+    /// 족보 순번 (0=하이카드, 1=원페어, ..., 8=스트레이트 플러시)
+    pub fn index(&self) -> u8 {
+        match self {
+            Self::HighCard { .. } => 0,
+            Self::Pair { .. } => 1,
+            Self::TwoPair { .. } => 2,
+            Self::Trips { .. } => 3,
+            Self::Straight { .. } => 4,
+            Self::Flush { .. } => 5,
+            Self::FullHouse { .. } => 6,
+            Self::Quads { .. } => 7,
+            Self::StraightFlush { .. } => 8,
+        }
+    }
+
+    /// 족보 이름 (`rank_to_string`과 같은 문자열)
+    pub fn name(&self) -> &'static str {
+        [
+            "하이카드",
+            "원페어",
+            "투페어",
+            "트리플",
+            "스트레이트",
+            "플러시",
+            "풀하우스",
+            "포카드",
+            "스트레이트 플러시",
+        ][self.index() as usize]
+    }
+
+    /// 버전이 바뀌어도 유지되는 짧은 코드 ("2P-A-9-K", "ST-5", "FH-K-7")
+    ///
+    /// 족보 접두어(HC, 1P, 2P, 3K, ST, FL, FH, 4K, SF) 뒤에 비교 순서대로 랭크 기호를 붙입니다.
+    pub fn short_code(&self) -> String {
+        let (prefix, ranks): (&str, Vec<Rank>) = match self {
+            Self::HighCard { kickers } => ("HC", kickers.clone()),
+            Self::Pair { rank, kickers } => ("1P", [*rank].iter().chain(kickers).copied().collect()),
+            Self::TwoPair { high, low, kicker } => ("2P", [*high, *low].into_iter().chain(*kicker).collect()),
+            Self::Trips { rank, kickers } => ("3K", [*rank].iter().chain(kickers).copied().collect()),
+            Self::Straight { high } => ("ST", vec![*high]),
+            Self::Flush { ranks } => ("FL", ranks.clone()),
+            Self::FullHouse { trips, pair } => ("FH", vec![*trips, *pair]),
+            Self::Quads { rank, kicker } => ("4K", [*rank].into_iter().chain(*kicker).collect()),
+            Self::StraightFlush { high } => ("SF", vec![*high]),
+        };
+        ranks.iter().fold(prefix.to_string(), |mut code, rank| {
+            code.push('-');
+            code.push(rank.symbol());
+            code
+        })
+    }
+}
+
+impl fmt::Display for HandCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // "King kicker", "Ace-King kickers"
+        let kickers = |ranks: &[Rank]| match ranks {
+            [] => String::new(),
+            [single] => format!(", {} kicker", single.name()),
+            _ => format!(", {} kickers", joined(ranks)),
+        };
+        match self {
+            Self::HighCard { kickers } => write!(f, "High Card, {}", joined(kickers)),
+            Self::Pair { rank, kickers: rest } => write!(f, "One Pair, {}{}", rank.plural(), kickers(rest)),
+            Self::TwoPair { high, low, kicker } => {
+                let kicker: Vec<Rank> = kicker.iter().copied().collect();
+                write!(f, "Two Pair, {} and {}{}", high.plural(), low.plural(), kickers(&kicker))
+            }
+            Self::Trips { rank, kickers: rest } => write!(f, "Three of a Kind, {}{}", rank.plural(), kickers(rest)),
+            Self::Straight { high } => write!(f, "Straight, {} high", high.name()),
+            Self::Flush { ranks } => write!(f, "Flush, {}", joined(ranks)),
+            Self::FullHouse { trips, pair } => write!(f, "Full House, {} full of {}", trips.plural(), pair.plural()),
+            Self::Quads { rank, kicker } => {
+                let kicker: Vec<Rank> = kicker.iter().copied().collect();
+                write!(f, "Four of a Kind, {}{}", rank.plural(), kickers(&kicker))
+            }
+            Self::StraightFlush { high: Rank::Ace } => write!(f, "Royal Flush"),
+            Self::StraightFlush { high } => write!(f, "Straight Flush, {} high", high.name()),
+        }
+    }
+}
+
+/// 랭크 이름을 '-'로 연결 ("Ace-King-Nine")
+fn joined(ranks: &[Rank]) -> String {
+    ranks.iter().map(|rank| rank.name()).collect::<Vec<_>>().join("-")
+}
+
+/// 카드들의 최고 5장 핸드 족보 분류
+///
+/// 보통 5-7장(홀카드 + 보드)을 받지만 장수 제한은 없고, 5장 미만이면 스트레이트/플러시 없이
+/// 있는 카드만으로 분류합니다. 겹치거나 범위(0-51)를 벗어난 카드는 무시합니다.
+///
+/// ```
+/// use nice_hand_core::hand_eval::{classify, HandCategory, Rank};
+///
+/// // As Ah 9s 9d Kc 4h 2d
+/// let category = classify(&[0, 13, 8, 34, 51, 16, 27]);
+/// assert_eq!(category, HandCategory::TwoPair { high: Rank::Ace, low: Rank::Nine, kicker: Some(Rank::King) });
+/// assert_eq!(category.to_string(), "Two Pair, Aces and Nines, King kicker");
+/// assert_eq!(category.short_code(), "2P-A-9-K");
+/// ```
+pub fn classify(cards: &[u8]) -> HandCategory {
+    let mut seen = 0u64;
+    let mut counts = [0u8; 13];
+    let mut suit_masks = [0u16; 4];
+    for &card in cards {
+        if card >= 52 || seen & (1 << card) != 0 {
+            continue;
+        }
+        seen |= 1 << card;
+        let rank = high_rank(card);
+        counts[rank as usize] += 1;
+        suit_masks[(card / 13) as usize] |= 1 << rank;
+    }
+
+    // n장 이상 있는 랭크 집합 (에이스 하이 랭크 비트)
+    let at_least = |n: u8| {
+        (0..13)
+            .filter(|&rank| counts[rank] >= n)
+            .fold(0u16, |mask, rank| mask | 1 << rank)
+    };
+    // 집합의 랭크를 높은 순서로
+    let ranks = |mask: u16| {
+        (0..13u8)
+            .rev()
+            .filter(move |&rank| mask & (1 << rank) != 0)
+            .map(|rank| Rank::ALL[rank as usize])
+    };
+    let highest = |mask: u16| 15 - mask.leading_zeros() as u8;
+    let rank_of = |rank: u8| Rank::ALL[rank as usize];
+    let rank_mask = at_least(1);
+    let flush_mask = suit_masks.iter().copied().find(|mask| mask.count_ones() >= 5);
+
+    if let Some(high) = flush_mask.and_then(straight_high) {
+        return HandCategory::StraightFlush { high: rank_of(high) };
+    }
+
+    let quads = at_least(4);
+    if quads != 0 {
+        let quad = highest(quads);
+        return HandCategory::Quads {
+            rank: rank_of(quad),
+            kicker: ranks(rank_mask & !(1 << quad)).next(),
+        };
+    }
+
+    // 풀하우스 (두 번째 트리플도 페어로 사용 가능)
+    let trips = at_least(3);
+    let pairs = at_least(2);
+    if trips != 0 {
+        let trip = highest(trips);
+        let pair = pairs & !(1 << trip);
+        if pair != 0 {
+            return HandCategory::FullHouse {
+                trips: rank_of(trip),
+                pair: rank_of(highest(pair)),
+            };
+        }
+    }
+
+    if let Some(mask) = flush_mask {
+        return HandCategory::Flush {
+            ranks: ranks(mask).take(5).collect(),
+        };
+    }
+
+    if let Some(high) = straight_high(rank_mask) {
+        return HandCategory::Straight { high: rank_of(high) };
+    }
+
+    if trips != 0 {
+        let trip = highest(trips);
+        return HandCategory::Trips {
+            rank: rank_of(trip),
+            kickers: ranks(rank_mask & !(1 << trip)).take(2).collect(),
+        };
+    }
+
+    // 투페어 (세 번째 페어는 킥커 후보)
+    if pairs.count_ones() >= 2 {
+        let high = highest(pairs);
+        let low = highest(pairs & !(1 << high));
+        return HandCategory::TwoPair {
+            high: rank_of(high),
+            low: rank_of(low),
+            kicker: ranks(rank_mask & !(1 << high) & !(1 << low)).next(),
+        };
+    }
+
+    if pairs != 0 {
+        let pair = highest(pairs);
+        return HandCategory::Pair {
+            rank: rank_of(pair),
+            kickers: ranks(rank_mask & !(1 << pair)).take(3).collect(),
+        };
+    }
+
+    HandCategory::HighCard {
+        kickers: ranks(rank_mask).take(5).collect(),
+    }
+}
+
+/// 두 핸드의 헤즈업 에퀴티를 남은 보드 전체 열거로 정확히 계산
+///
+/// 몬테카를로 샘플링과 달리 실행마다 같은 값을 반환합니다.
+/// 프리플랍은 48C5 = 1,712,304개, 플랍은 990개, 턴은 44개 보드를 평가하고,
+/// 보드가 완성된 리버는 결과가 0 또는 1입니다.
+///
+/// # 매개변수
+/// - hand1, hand2: 두 플레이어의 홀카드 (서로, 그리고 보드와 겹치지 않아야 함)
+/// - board: 현재 보드 (0-5장, 5장을 넘으면 앞의 5장만 사용)
+///
+/// # 반환값
+/// - hand1 기준 (승, 무, 패) 확률
+pub fn enumerate_equity_heads_up(hand1: [u8; 2], hand2: [u8; 2], board: &[u8]) -> (f64, f64, f64) {
+    let board = &board[..board.len().min(5)];
+    let mut dead = 0u64;
+    for &card in hand1.iter().chain(&hand2).chain(board) {
+        if card < 52 {
+            dead |= 1 << card;
+        }
+    }
+    let deck: Vec<u8> = (0..52u8).filter(|&card| dead & (1 << card) == 0).collect();
+
+    // 보드를 한 장씩 더해 가며 두 핸드의 증분 평가기를 함께 넘김
+    let eval1 = PartialEval::from_cards(&hand1).with_cards(board);
+    let eval2 = PartialEval::from_cards(&hand2).with_cards(board);
+
+    // [승, 무, 패] 보드 수
+    let mut counts = [0u64; 3];
+    enumerate_heads_up_boards(&deck, 0, 5 - board.len(), eval1, eval2, &mut counts);
+
+    let total = counts.iter().sum::<u64>().max(1) as f64;
+    (
+        counts[0] as f64 / total,
+        counts[1] as f64 / total,
+        counts[2] as f64 / total,
+    )
+}
+
+/// 남은 덱에서 `needed`장 조합을 모두 더해 보며 승/무/패 집계
+fn enumerate_heads_up_boards(
+    deck: &[u8],
+    start: usize,
+    needed: usize,
+    eval1: PartialEval,
+    eval2: PartialEval,
+    counts: &mut [u64; 3],
+) {
+    if needed == 0 {
+        // 보드가 이미 완성된 경우 (리버)
+        counts[showdown_outcome(eval1.extend(&[]), eval2.extend(&[]))] += 1;
+        return;
+    }
+    for i in start..=deck.len().saturating_sub(needed) {
+        let card = [deck[i]];
+        if needed == 1 {
+            counts[showdown_outcome(eval1.extend(&card), eval2.extend(&card))] += 1;
+        } else {
+            let (next1, next2) = (eval1.with_cards(&card), eval2.with_cards(&card));
+            enumerate_heads_up_boards(deck, i + 1, needed - 1, next1, next2, counts);
+        }
+    }
+}
+
+/// 승/무/패 인덱스 (낮은 랭크 = 강함)
+fn showdown_outcome(rank1: HandRank, rank2: HandRank) -> usize {
+    match rank1.cmp(&rank2) {
+        std::cmp::Ordering::Less => 0,
+        std::cmp::Ordering::Equal => 1,
+        std::cmp::Ordering::Greater => 2,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -323,6 +932,140 @@ mod tests {
         println!("모든 핸드 평가 테스트 통과!");
     }
     
+    #[test]
+    fn test_ace_ranks_high_within_category() {
+        // 같은 보드에서 AA가 KK보다 강해야 함 (낮은 값 = 강한 핸드)
+        let board = [1 + 13, 3 + 26, 5 + 39, 7 + 13, 9 + 26]; // 2h 4d 6c 8h 10d
+        let aces = v7([0, 13, board[0], board[1], board[2], board[3], board[4]]);
+        let kings = v7([12, 25, board[0], board[1], board[2], board[3], board[4]]);
+        assert!(aces < kings, "AA {} vs KK {}", aces, kings);
+
+        // 브로드웨이 스트레이트 > 휠 스트레이트
+        let broadway = v7([0, 9 + 13, 10 + 26, 11 + 39, 12, 1 + 13, 2 + 26]);
+        let wheel = v7([0, 1 + 13, 2 + 26, 3 + 39, 4, 7 + 13, 9 + 26]);
+        assert!(broadway < wheel && wheel <= 5863);
+
+        // 모든 플러시/하이카드 랭크 집합이 문서화된 범위 안에 있고 서로 다름
+        let mut flushes = std::collections::HashSet::new();
+        for mask in 0u16..(1 << 13) {
+            if mask.count_ones() != 5 {
+                continue;
+            }
+            let ranks: Vec<u8> = (0..13).filter(|r| mask & (1 << r) != 0).collect();
+            let flush = evaluate_5cards([ranks[0], ranks[1], ranks[2], ranks[3], ranks[4]]);
+            if flush > 1599 {
+                assert!((3825..=5108).contains(&flush), "플러시 범위 벗어남: {}", flush);
+                assert!(flushes.insert(flush));
+            }
+        }
+        assert_eq!(flushes.len(), 1277);
+    }
+
+    #[test]
+    fn test_direct_7card_matches_combinations() {
+        use rand::seq::SliceRandom;
+
+        let mut rng = rand::thread_rng();
+        let mut deck: Vec<u8> = (0..52).collect();
+        for _ in 0..20_000 {
+            deck.shuffle(&mut rng);
+            let cards = [deck[0], deck[1], deck[2], deck[3], deck[4], deck[5], deck[6]];
+            let mut best = u32::MAX;
+            for skip1 in 0..7 {
+                for skip2 in (skip1 + 1)..7 {
+                    let mut hand = [0u8; 5];
+                    let mut n = 0;
+                    for (i, &card) in cards.iter().enumerate() {
+                        if i != skip1 && i != skip2 {
+                            hand[n] = card;
+                            n += 1;
+                        }
+                    }
+                    best = best.min(evaluate_5cards(hand));
+                }
+            }
+            assert_eq!(evaluate_7cards_direct(cards, Ranking::Standard), Some(best), "{:?}", cards);
+        }
+        assert_eq!(evaluate_7cards_direct([0, 0, 1, 2, 3, 4, 5], Ranking::Standard), None);
+    }
+
+    #[test]
+    fn test_partial_eval_matches_evaluate_7cards() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut deck: Vec<u8> = (0..52).collect();
+        for i in 0..100_000 {
+            deck.shuffle(&mut rng);
+            let cards = [deck[0], deck[1], deck[2], deck[3], deck[4], deck[5], deck[6]];
+            // 5장 또는 6장을 고정하고 나머지를 더함
+            let fixed = 5 + i % 2;
+            let partial = PartialEval::from_cards(&cards[..fixed]);
+            assert_eq!(partial.extend(&cards[fixed..]), evaluate_7cards(cards), "{:?}", cards);
+            if i % 10 == 0 {
+                assert_eq!(partial.extend(&[]), best_hand_rank(&cards[..fixed]).unwrap());
+            }
+        }
+
+        // 겹치는 카드는 v7()처럼 5장 조합으로, 카드가 모자라면 u32::MAX
+        let duplicated = [0, 0, 1, 2, 3, 4, 5];
+        assert_eq!(PartialEval::from_cards(&duplicated[..6]).extend(&[5]), v7(duplicated));
+        assert_eq!(PartialEval::from_cards(&[0, 13]).extend(&[26]), u32::MAX);
+    }
+
+    #[test]
+    fn test_partial_eval_speeds_up_turn_enumeration() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+        use std::hint::black_box;
+        use std::time::{Duration, Instant};
+
+        // 홀카드 2장 + 턴 보드 4장을 고정하고 남은 리버 46장을 평가
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut deck: Vec<u8> = (0..52).collect();
+        let spots: Vec<Vec<u8>> = (0..2_000)
+            .map(|_| {
+                deck.shuffle(&mut rng);
+                deck.clone()
+            })
+            .collect();
+        let best_of_three = |run: &dyn Fn() -> u64| {
+            (0..3)
+                .map(|_| {
+                    let start = Instant::now();
+                    black_box(run());
+                    start.elapsed()
+                })
+                .min()
+                .unwrap_or(Duration::ZERO)
+        };
+
+        let from_scratch = best_of_three(&|| {
+            let mut checksum = 0u64;
+            for cards in &spots {
+                for &river in &cards[6..] {
+                    let hand = [cards[0], cards[1], cards[2], cards[3], cards[4], cards[5], river];
+                    checksum += evaluate_7cards(black_box(hand)) as u64;
+                }
+            }
+            checksum
+        });
+        let incremental = best_of_three(&|| {
+            let mut checksum = 0u64;
+            for cards in &spots {
+                let partial = PartialEval::from_cards(black_box(&cards[..6]));
+                for &river in &cards[6..] {
+                    checksum += partial.extend(&[black_box(river)]) as u64;
+                }
+            }
+            checksum
+        });
+        assert!(incremental < from_scratch, "{:?} vs {:?}", incremental, from_scratch);
+    }
+
     #[test]
     fn test_card_conversion() {
         assert_eq!(card_to_string(0), "As");   // 스페이드 A
@@ -332,4 +1075,83 @@ mod tests {
         
         println!("카드 변환 테스트 통과");
     }
+
+    #[test]
+    fn test_enumerate_equity_heads_up_preflop() {
+        // As Ah vs Ks Kd (알려진 값 81.75% / 0.46% / 17.79%)
+        let (win, tie, lose) = enumerate_equity_heads_up([0, 13], [12, 38], &[]);
+        assert!((win - 0.8175).abs() < 1e-3, "{}", win);
+        assert!((tie - 0.0046).abs() < 1e-3, "{}", tie);
+        assert!((lose - 0.1779).abs() < 1e-3, "{}", lose);
+        assert!((win + tie + lose - 1.0).abs() < 1e-12);
+
+        // As Ks vs Ah Kh: 수트만 바꾼 대칭이라 승/패가 정확히 같음 (플러시가 나올 때만 승부가 갈림)
+        let (win, tie, lose) = enumerate_equity_heads_up([0, 12], [13, 25], &[]);
+        assert_eq!(win, lose);
+        assert!(tie > 0.85 && win > 0.0, "{} {}", win, tie);
+    }
+
+    #[test]
+    fn test_enumerate_equity_heads_up_river_is_exact() {
+        // 보드 2c 7d 9h Qc Ks: As Ah (원페어) vs Kh Kd (트리플)
+        let board = [40, 32, 21, 50, 12];
+        assert_eq!(enumerate_equity_heads_up([0, 13], [25, 38], &board), (0.0, 0.0, 1.0));
+        assert_eq!(enumerate_equity_heads_up([25, 38], [0, 13], &board), (1.0, 0.0, 0.0));
+        // 보드로 같은 스트레이트를 쓰면 무승부
+        let straight = [4, 18, 32, 46, 8]; // 5s 6h 7d 8c 9s
+        assert_eq!(enumerate_equity_heads_up([0, 13], [1, 14], &straight), (0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_classify_orders_like_evaluator() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(2433);
+        let mut deck: Vec<u8> = (0..52).collect();
+        let mut deal = |rng: &mut StdRng| {
+            deck.shuffle(rng);
+            [deck[0], deck[1], deck[2], deck[3], deck[4], deck[5], deck[6]]
+        };
+        for _ in 0..100_000 {
+            let (a, b) = (deal(&mut rng), deal(&mut rng));
+            let (category_a, category_b) = (classify(&a), classify(&b));
+            // 분류는 높을수록, 랭킹 값은 낮을수록 강함
+            assert_eq!(category_a.cmp(&category_b), v7(b).cmp(&v7(a)), "{:?} {:?}", a, b);
+            assert_eq!(category_a.name(), rank_to_string(v7(a)), "{:?}", a);
+        }
+    }
+
+    #[test]
+    fn test_classify_display_and_codes() {
+        // As 2h 3d 4c 5s Kh 9d: 휠은 5 하이 스트레이트
+        let wheel = classify(&[0, 14, 28, 42, 4, 25, 34]);
+        assert_eq!(wheel, HandCategory::Straight { high: Rank::Five });
+        assert!(wheel < classify(&[1, 15, 29, 43, 5, 25, 34])); // 2-6 스트레이트보다 약함
+
+        let cases: [(&[u8], &str, &str); 10] = [
+            (&[9, 10, 11, 12, 0, 13, 14], "Royal Flush", "SF-A"),
+            (&[4, 5, 6, 7, 8, 20, 33], "Straight Flush, Nine high", "SF-9"),
+            (&[0, 13, 26, 39, 1, 2, 3], "Four of a Kind, Aces, Four kicker", "4K-A-4"),
+            // 트리플 두 개 중 낮은 쪽은 페어로 사용
+            (&[12, 25, 38, 6, 19, 32, 40], "Full House, Kings full of Sevens", "FH-K-7"),
+            (&[0, 2, 4, 6, 8, 13, 14], "Flush, Ace-Nine-Seven-Five-Three", "FL-A-9-7-5-3"),
+            (&[0, 14, 28, 42, 4, 25, 34], "Straight, Five high", "ST-5"),
+            (&[6, 19, 32, 13, 51, 29, 1], "Three of a Kind, Sevens, Ace-King kickers", "3K-7-A-K"),
+            // 세 번째 페어가 킥커
+            (&[0, 13, 8, 34, 44, 18, 27], "Two Pair, Aces and Nines, Six kicker", "2P-A-9-6"),
+            // 프리플랍 포켓 페어는 킥커 없이
+            (&[11, 24], "One Pair, Queens", "1P-Q"),
+            (&[0, 15, 30, 46, 22, 37, 51], "High Card, Ace-King-Queen-Ten-Eight", "HC-A-K-Q-T-8"),
+        ];
+        for (cards, text, code) in cases {
+            let category = classify(cards);
+            assert_eq!(category.to_string(), text);
+            assert_eq!(category.short_code(), code);
+        }
+
+        // 겹치거나 범위를 벗어난 카드는 무시
+        assert_eq!(classify(&[11, 24, 24, 60]), classify(&[11, 24]));
+    }
 }
@@ -1,14 +1,25 @@
 // 텍사스 홀덤 6-Max 게임 로직
 // Preference CFR과 서브게임 리솔빙을 지원하는 완전한 구현
 
+use crate::game::all_in::AllInAdjudicator;
 use crate::game::card_abstraction::*;
-use crate::solver::cfr_core::{Game, GameState, Trainer};
-use rand::{rngs::ThreadRng, Rng};
+use crate::game::flop_subset::FlopSampler;
+use crate::game::hand_eval::{card_to_string, v7, PartialEval};
+use crate::game::opponent_deal::OpponentDealModel;
+use crate::game::rng::entropy_rng;
+use crate::game::short_deck;
+use crate::game::tournament::AnteType;
+use crate::solver::budget::{Budgeted, ComputeBudget};
+use crate::solver::cfr_core::{Game, GameState, MergeMode, TerminalEvaluator, Trainer};
+use crate::solver::compat::CompatibilityFingerprint;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
 
 /// 텍사스 홀덤 게임 상태
 ///
-/// 6명까지 참여 가능한 No-Limit Hold'em 게임의 모든 정보를 포함합니다.
+/// 6명까지 참여 가능한 Hold'em 게임의 모든 정보를 포함합니다 (기본은 노리밋, `betting` 참고).
 /// CFR 알고리즘이 이 상태를 기반으로 최적 전략을 학습합니다.
 #[derive(Clone, Debug)]
 pub struct State {
@@ -36,14 +47,221 @@ pub struct State {
     /// 현재 스트리트에서 각 플레이어가 투자한 금액
     pub invested: [u32; 6],
 
+    /// 이번 핸드에 각 플레이어가 포스트한 앤티 (데드 머니, `invested`에는 포함되지 않음)
+    pub antes: [u32; 6],
+
     /// 콜하기 위해 필요한 금액
     pub to_call: u32,
 
     /// 현재 스트리트에서 수행된 액션 수
     pub actions_taken: usize,
+
+    /// 딜러 버튼 좌석 (포스트플랍은 버튼 왼쪽 첫 생존 플레이어부터 액션)
+    pub button: usize,
+
+    /// 캐시 게임 레이크 (None이면 레이크 없음)
+    pub rake: Option<RakeModel>,
+
+    /// 덱과 족보 규칙 (일반 홀덤 / 숏덱)
+    pub variant: HoldemVariant,
+
+    /// 플랍 딜링을 제한할 플랍 부분집합 (None이면 덱 전체, 일반 홀덤에만 적용)
+    pub flop_sampler: Option<Arc<FlopSampler>>,
+
+    /// 베팅 한도 규칙 (노리밋 / 팟리밋 / 픽스드리밋)
+    pub betting: BettingRules,
+
+    /// 현재 스트리트에서 나온 베팅/레이즈 수 (블라인드 제외)
+    pub raises_this_street: u8,
+
+    /// 빅블라인드 (최소 베팅/레이즈 증가분의 하한, 0이면 알 수 없음)
+    pub big_blind: u32,
+
+    /// 현재 스트리트의 마지막 완전한 베팅/레이즈 증가분 (최소 리레이즈 계산용)
+    pub last_raise: u32,
+
+    /// 이번 핸드의 라이브 스트래들 금액 (가장 큰 스트래들, 0이면 스트래들 없음)
+    pub straddle: u32,
+
+    /// 모르는 상대 홀카드를 시뮬레이션마다 다시 딜링하는 모델 (None이면 `hole`을 그대로 사용)
+    ///
+    /// 부분 정보 상태(분석 요청, 라이브 세션)에서 설정하며 `EVCalculator`가 샘플마다 적용합니다.
+    /// CFR 학습은 이 값을 쓰지 않습니다.
+    pub opponent_deal: Option<Arc<OpponentDealModel>>,
+}
+
+/// 캐시 게임 레이크 모델
+///
+/// 승자에게 지급되는 팟에서 `percentage` 비율을 `cap`까지 공제합니다.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RakeModel {
+    /// 팟 대비 레이크 비율 (0.05 = 5%)
+    pub percentage: f64,
+    /// 핸드당 최대 레이크 (칩)
+    pub cap: u32,
+    /// 플랍이 열리지 않은 팟에서는 레이크를 걷지 않음
+    pub no_flop_no_drop: bool,
+}
+
+impl RakeModel {
+    /// 팟에서 공제되는 레이크 (칩 단위, 소수점 이하 버림)
+    pub fn rake(&self, pot: u32, flop_dealt: bool) -> u32 {
+        if self.no_flop_no_drop && !flop_dealt {
+            return 0;
+        }
+        let raw = (pot as f64 * self.percentage.clamp(0.0, 1.0)).floor() as u32;
+        raw.min(self.cap).min(pot)
+    }
+}
+
+/// 스몰/빅 블라인드 외에 핸드 시작 전에 포스트하는 추가 블라인드
+///
+/// JSON 표현: `{"seat": 3, "amount": 200, "kind": "Straddle"}`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExtraBlind {
+    /// 포스트하는 좌석
+    pub seat: usize,
+    /// 포스트 금액 (스택보다 크면 가진 만큼만 올인으로 포스트)
+    pub amount: u32,
+    /// 라이브 스트래들 / 데드 블라인드
+    pub kind: ExtraBlindKind,
+}
+
+/// 추가 블라인드 종류
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ExtraBlindKind {
+    /// 라이브 스트래들 - 콜 금액과 최소 레이즈 기준이 되는 블라인드 (보통 빅블라인드의 2배)
+    ///
+    /// 프리플랍 액션은 스트래들러 왼쪽부터 시작해 스트래들러가 마지막에 액션하므로, 레이즈 없이
+    /// 돌아오면 스트래들러에게 빅블라인드와 같은 옵션이 있습니다. UTG 스트래들이면 UTG+1부터,
+    /// 버튼(미시시피) 스트래들이면 스몰블라인드부터 액션합니다.
+    Straddle,
+    /// 데드 블라인드 - 팟에만 들어가는 데드 머니 (앤티처럼 콜 금액과 액션 순서에 영향 없음)
+    DeadBlind,
+}
+
+/// 홀덤 변형 규칙
+///
+/// 딜링할 덱, 쇼다운 족보, 프리플랍 버킷을 결정합니다.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum HoldemVariant {
+    /// 52장 일반 홀덤
+    #[default]
+    Standard,
+    /// 36장 숏덱 (6+) 홀덤
+    ShortDeck,
+}
+
+impl HoldemVariant {
+    /// 변형에서 쓰는 카드인지 확인
+    pub fn contains(self, card: u8) -> bool {
+        match self {
+            HoldemVariant::Standard => card < 52,
+            HoldemVariant::ShortDeck => short_deck::is_short_deck_card(card),
+        }
+    }
+
+    /// 변형의 전체 덱
+    pub fn deck(self) -> Vec<u8> {
+        (0..52).filter(|&card| self.contains(card)).collect()
+    }
+
+    /// 7장 카드 핸드 평가 (낮을수록 강한 핸드)
+    pub fn evaluate_7cards(self, cards: [u8; 7]) -> u32 {
+        match self {
+            HoldemVariant::Standard => v7(cards),
+            HoldemVariant::ShortDeck => short_deck::evaluate_7cards_short_deck(cards),
+        }
+    }
+
+    /// 고정할 카드(홀카드, 현재 보드 등)로 이 변형의 족보를 쓰는 증분 평가기 생성
+    pub fn partial_eval(self, cards: &[u8]) -> PartialEval {
+        match self {
+            HoldemVariant::Standard => PartialEval::from_cards(cards),
+            HoldemVariant::ShortDeck => short_deck::partial_eval_short_deck(cards),
+        }
+    }
+
+    /// 프리플랍 핸드 버킷 (0이 가장 강함)
+    pub fn preflop_bucket(self, hole: [u8; 2]) -> u8 {
+        match self {
+            HoldemVariant::Standard => preflop_bucket(hole),
+            HoldemVariant::ShortDeck => short_deck::short_deck_preflop_bucket(hole),
+        }
+    }
+
+    /// 살아 있는 상대 수를 반영한 프리플랍 핸드 버킷 (0이 가장 강함)
+    ///
+    /// 상대가 둘 이상이면 일반 홀덤은 `preflop_bucket_multiway`를 쓰고, 헤즈업은 기존 버킷을
+    /// 그대로 써서 헤즈업으로 학습한 전략의 키가 바뀌지 않습니다. 숏덱은 상대 수와 무관합니다.
+    pub fn preflop_bucket_vs(self, hole: [u8; 2], live_opponents: usize) -> u8 {
+        match self {
+            HoldemVariant::Standard if live_opponents >= 2 => preflop_bucket_multiway(hole, live_opponents),
+            _ => self.preflop_bucket(hole),
+        }
+    }
+}
+
+/// 베팅 한도 규칙
+///
+/// `Act::Raise` 하나가 실제로 넣는 칩과 스트리트당 레이즈 횟수를 정합니다.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum BettingRules {
+    /// 노리밋: 스택 전부까지 레이즈 가능
+    #[default]
+    NoLimit,
+    /// 팟리밋: 최대 레이즈는 콜한 뒤의 팟 크기
+    PotLimit,
+    /// 픽스드리밋: 프리플랍/플랍은 `small_bet`, 턴/리버는 `big_bet` 단위로만 베팅/레이즈하고
+    /// 스트리트당 베팅/레이즈는 `max_raises`번까지 (블라인드는 세지 않음)
+    FixedLimit {
+        small_bet: u32,
+        big_bet: u32,
+        max_raises: u8,
+    },
+}
+
+impl BettingRules {
+    /// 규칙 방식 이름 (픽스드리밋의 베팅 단위는 제외)
+    pub fn mode_name(self) -> &'static str {
+        match self {
+            BettingRules::NoLimit => "NoLimit",
+            BettingRules::PotLimit => "PotLimit",
+            BettingRules::FixedLimit { .. } => "FixedLimit",
+        }
+    }
+
+    /// 정보 집합 키에 섞는 규칙 구분값 (노리밋은 0이라 기존 키가 바뀌지 않음)
+    fn key_bits(self) -> u64 {
+        match self {
+            BettingRules::NoLimit => 0,
+            BettingRules::PotLimit => 1,
+            BettingRules::FixedLimit { .. } => 2,
+        }
+    }
 }
 
 impl State {
+    /// 레이크를 공제하고 승자에게 지급되는 팟
+    pub fn net_pot(&self) -> u32 {
+        let rake = self
+            .rake
+            .map_or(0, |model| model.rake(self.pot, !self.board.is_empty()));
+        self.pot - rake
+    }
+
+    /// `new_hand`가 쓰는 버튼 좌석 (HU는 버튼이 스몰 블라인드)
+    ///
+    /// 정보 집합 키가 버튼 기준 포지션을 쓰므로 학습 루트 밖에서 상태를 만들 때도 같은 버튼을 써야 합니다.
+    pub fn default_button(player_count: usize) -> usize {
+        player_count.saturating_sub(3)
+    }
+
     /// 새 게임 상태 생성 (프리플랍 시작)
     ///
     /// # 매개변수
@@ -54,20 +272,64 @@ impl State {
     /// # 반환값
     /// - 초기화된 게임 상태
     pub fn new_hand(blinds: [u32; 2], stacks: [u32; 6], player_count: usize) -> Self {
+        Self::new_hand_with_antes(blinds, 0, AnteType::PerPlayer, stacks, player_count)
+    }
+
+    /// 앤티가 있는 새 게임 상태 생성 (프리플랍 시작)
+    ///
+    /// 앤티는 데드 머니라 팟에는 들어가지만 콜 금액을 정하는 `invested`에는 들어가지 않습니다.
+    /// `PerPlayer`는 참여한 모든 좌석이 `ante`를, `BigBlindAnte`/`ButtonAnte`는 빅블라인드/버튼
+    /// 좌석 혼자 테이블 전체 앤티 `ante`를 포스트합니다.
+    ///
+    /// 스택이 블라인드와 앤티를 모두 감당하지 못하면 앤티를 먼저 포스트하고 남은 칩을
+    /// 블라인드로 포스트합니다 (앤티 우선). 예를 들어 BB 앤티 100에서 80칩뿐인 빅블라인드는
+    /// 앤티 80만 내고 블라인드 없이 올인이 됩니다.
+    pub fn new_hand_with_antes(
+        blinds: [u32; 2],
+        ante: u32,
+        ante_type: AnteType,
+        stacks: [u32; 6],
+        player_count: usize,
+    ) -> Self {
+        Self::new_hand_with_rng(blinds, ante, ante_type, stacks, player_count, &mut entropy_rng())
+    }
+
+    /// 주어진 난수로 홀카드를 딜링하는 `new_hand_with_antes` (같은 시드면 같은 핸드)
+    pub fn new_hand_with_rng<R: Rng>(
+        blinds: [u32; 2],
+        ante: u32,
+        ante_type: AnteType,
+        stacks: [u32; 6],
+        player_count: usize,
+        rng: &mut R,
+    ) -> Self {
         use rand::seq::SliceRandom;
-        use rand::thread_rng;
+
+        let button = Self::default_button(player_count);
 
         let mut state = Self {
             hole: [[0; 2]; 6],
             board: Vec::new(),
-            to_act: if player_count == 2 { 0 } else { 3 }, // UTG부터 시작 (HU는 버튼부터)
+            // 빅 블라인드 다음 좌석(UTG)부터 시작 (HU는 버튼/SB부터)
+            to_act: if player_count == 2 { 0 } else { (button + 3) % player_count },
             street: 0,
             pot: blinds[0] + blinds[1],
             stack: stacks,
             alive: [false; 6],
             invested: [0; 6],
+            antes: [0; 6],
             to_call: blinds[1],
             actions_taken: 0,
+            button,
+            rake: None,
+            variant: HoldemVariant::Standard,
+            flop_sampler: None,
+            betting: BettingRules::NoLimit,
+            raises_this_street: 0,
+            big_blind: blinds[1],
+            last_raise: 0,
+            straddle: 0,
+            opponent_deal: None,
         };
 
         // 참여 플레이어 설정
@@ -87,14 +349,35 @@ impl State {
             player_count - 1
         };
 
-        state.invested[sb_pos] = blinds[0];
-        state.invested[bb_pos] = blinds[1];
-        state.stack[sb_pos] -= blinds[0];
-        state.stack[bb_pos] -= blinds[1];
+        // 앤티 처리 (블라인드보다 먼저 포스트 - 앤티 우선 규칙)
+        let ante_seats: Vec<usize> = match ante_type {
+            AnteType::PerPlayer => (0..player_count).collect(),
+            AnteType::BigBlindAnte => vec![bb_pos],
+            AnteType::ButtonAnte => vec![button],
+        };
+        for seat in ante_seats {
+            let posted = ante.min(state.stack[seat]);
+            state.antes[seat] = posted;
+            state.stack[seat] -= posted;
+        }
+
+        // 블라인드보다 스택이 작으면 가진 만큼만 올인으로 포스트
+        let sb_posted = blinds[0].min(state.stack[sb_pos]);
+        let bb_posted = blinds[1].min(state.stack[bb_pos]);
+        state.invested[sb_pos] = sb_posted;
+        state.invested[bb_pos] = bb_posted;
+        state.stack[sb_pos] -= sb_posted;
+        state.stack[bb_pos] -= bb_posted;
+        state.pot = sb_posted + bb_posted + state.antes.iter().sum::<u32>();
+
+        // 블라인드나 앤티로 올인한 좌석은 건너뛰고 첫 액션할 수 있는 플레이어부터 시작
+        if !state.can_act(state.to_act) {
+            state.to_act = state.find_next_player(state.to_act).unwrap_or(state.to_act);
+        }
 
         // 홀카드 딜링 (52장 덱에서 랜덤)
         let mut deck: Vec<u8> = (0..52).collect();
-        deck.shuffle(&mut thread_rng());
+        deck.shuffle(rng);
 
         for i in 0..player_count {
             state.hole[i][0] = deck[i * 2];
@@ -104,6 +387,55 @@ impl State {
         state
     }
 
+    /// 스트래들/데드 블라인드가 있는 새 게임 상태 생성 (프리플랍 시작)
+    ///
+    /// 블라인드를 포스트한 뒤 `extra_blinds`를 순서대로 포스트합니다 (`post_extra_blinds` 참고).
+    pub fn new_hand_with_extra_blinds(
+        blinds: [u32; 2],
+        extra_blinds: &[ExtraBlind],
+        stacks: [u32; 6],
+        player_count: usize,
+    ) -> Self {
+        let mut state = Self::new_hand(blinds, stacks, player_count);
+        state.post_extra_blinds(extra_blinds);
+        state
+    }
+
+    /// 아무도 액션하지 않은 프리플랍 상태에 추가 블라인드 포스트
+    ///
+    /// 스트래들은 그 좌석의 이번 스트리트 투자액이 되어 콜 금액을 올리고, 현재 콜 금액보다 크면
+    /// 다음 레이즈의 최소 증가분을 스트래들 금액으로 바꾸며 액션을 스트래들러 왼쪽으로 옮깁니다
+    /// (더블 스트래들은 마지막으로 콜 금액을 올린 좌석 기준). 데드 블라인드는 앤티처럼 `antes`에 쌓입니다.
+    /// 빈 좌석이나 범위를 벗어난 좌석의 블라인드는 무시합니다.
+    pub fn post_extra_blinds(&mut self, extra_blinds: &[ExtraBlind]) {
+        let mut straddler = None;
+        for blind in extra_blinds {
+            let seat = blind.seat;
+            if seat >= 6 || !self.alive[seat] {
+                continue;
+            }
+            let posted = blind.amount.min(self.stack[seat]);
+            self.stack[seat] -= posted;
+            self.pot += posted;
+            match blind.kind {
+                ExtraBlindKind::DeadBlind => self.antes[seat] += posted,
+                ExtraBlindKind::Straddle => {
+                    self.invested[seat] += posted;
+                    if self.invested[seat] > self.to_call {
+                        self.to_call = self.invested[seat];
+                        self.last_raise = self.invested[seat];
+                        self.straddle = self.invested[seat];
+                        straddler = Some(seat);
+                    }
+                }
+            }
+        }
+
+        if let Some(seat) = straddler {
+            self.to_act = self.find_next_player(seat).unwrap_or(seat);
+        }
+    }
+
     /// 기본 게임 상태 생성 (테스트/예제용)
     ///
     /// CFR 학습에 최적화된 헤즈업 게임 설정:
@@ -118,20 +450,21 @@ impl State {
         Self::new_hand(blinds, stacks, player_count)
     }
 
-    /// 다음 액션할 플레이어 찾기
+    /// 다음 액션할 플레이어 찾기 (폴드했거나 올인한 좌석은 건너뜀)
     fn find_next_player(&self, current: usize) -> Option<usize> {
         let alive_count = self.alive.iter().filter(|&&a| a).count();
         if alive_count <= 1 {
             return None; // 게임 종료
         }
 
-        for i in 1..=6 {
-            let next = (current + i) % 6;
-            if self.alive[next] {
-                return Some(next);
-            }
-        }
-        None
+        (1..=6)
+            .map(|i| (current + i) % 6)
+            .find(|&next| self.can_act(next))
+    }
+
+    /// 살아 있고 올인하지 않아 액션할 수 있는 좌석인지
+    fn can_act(&self, seat: usize) -> bool {
+        self.alive[seat] && !self.is_all_in(seat)
     }
 
     /// 베팅 라운드가 끝났는지 확인
@@ -142,11 +475,6 @@ impl State {
             return true;
         }
 
-        // 모든 살아있는 플레이어가 액션했는지 확인
-        if self.actions_taken < alive_players.len() {
-            return false;
-        }
-
         // 모든 살아있는 플레이어가 같은 금액을 투자했는지 확인
         let max_investment = alive_players
             .iter()
@@ -154,6 +482,17 @@ impl State {
             .max()
             .unwrap_or(0);
 
+        // 액션할 수 있는 플레이어가 없거나, 한 명뿐인데 맞출 금액이 없으면 베팅할 상대가 없음
+        let acting: Vec<usize> = alive_players.iter().copied().filter(|&i| self.can_act(i)).collect();
+        if acting.is_empty() || (acting.len() == 1 && self.invested[acting[0]] >= max_investment) {
+            return true;
+        }
+
+        // 액션할 수 있는 플레이어가 모두 액션했는지 확인 (올인한 플레이어는 더 액션할 수 없음)
+        if self.actions_taken < acting.len() {
+            return false;
+        }
+
         for &player in &alive_players {
             // 올인하지 않은 플레이어는 최대 투자액과 같아야 함
             if !self.is_all_in(player) && self.invested[player] < max_investment {
@@ -170,9 +509,102 @@ impl State {
         self.invested = [0; 6]; // 투자 금액 리셋
         self.to_call = 0;
         self.actions_taken = 0;
+        self.raises_this_street = 0;
+        self.last_raise = 0;
+
+        // 버튼 왼쪽(시계 방향)의 첫 번째 살아있는 플레이어부터 시작
+        // HU에서는 빅 블라인드가 포스트플랍에 먼저 액션
+        self.to_act = (1..=6)
+            .map(|offset| (self.button + offset) % 6)
+            .find(|&seat| self.can_act(seat))
+            .unwrap_or(0);
+    }
+
+    /// 보드에 놓을 카드 한 장 딜링
+    ///
+    /// 변형의 덱에서 홀카드와 보드를 뺀 남은 카드 중에서 뽑습니다.
+    fn deal_board_card<R: Rng>(&self, rng: &mut R) -> u8 {
+        let live = self.live_cards();
+        live[rng.gen_range(0..live.len())]
+    }
+
+    /// 변형의 덱에서 홀카드와 보드를 뺀 남은 카드
+    fn live_cards(&self) -> Vec<u8> {
+        let dealt: Vec<u8> = self.hole.iter().flatten().chain(&self.board).copied().collect();
+        self.variant
+            .deck()
+            .into_iter()
+            .filter(|card| !dealt.contains(card))
+            .collect()
+    }
+
+    /// 끝난 핸드의 실제 칩 정산 (좌석별 최종 스택)
+    ///
+    /// `util`처럼 기대값으로 나누지 않습니다. 생존자가 둘 이상이면 남은 보드를 실제로 딜하고,
+    /// 각 좌석이 이번 핸드에 낸 칩(`starting_stacks - stack`, 앤티 포함)으로 팟을 층으로 나눠
+    /// 층마다 그 금액을 낸 생존자 중 가장 강한 핸드에게 줍니다. 아무도 맞추지 않은 초과 베팅은
+    /// 낸 사람에게 돌아가고, 나눠지지 않는 칩은 버튼 왼쪽부터 한 칩씩, 레이크는 메인 팟에서 뺍니다.
+    ///
+    /// # 매개변수
+    /// - starting_stacks: 앤티와 블라인드를 내기 전 좌석별 스택
+    pub fn settle<R: Rng>(&self, starting_stacks: [u32; 6], rng: &mut R) -> [u32; 6] {
+        let mut stacks = self.stack;
+        let contributed: Vec<u32> = (0..6).map(|i| starting_stacks[i].saturating_sub(self.stack[i])).collect();
+        let alive: Vec<usize> = (0..6).filter(|&i| self.alive[i]).collect();
+        let mut rake = self.pot - self.net_pot();
+
+        // 생존자가 하나면 보드 없이 전부 가져감
+        if let [winner] = alive[..] {
+            stacks[winner] += contributed.iter().sum::<u32>().saturating_sub(rake);
+            return stacks;
+        }
+        if alive.is_empty() {
+            return stacks;
+        }
 
-        // 첫 번째 살아있는 플레이어부터 시작
-        self.to_act = (0..6).find(|&i| self.alive[i]).unwrap_or(0);
+        let mut runout = self.clone();
+        while runout.board.len() < 5 {
+            let card = runout.deal_board_card(rng);
+            runout.board.push(card);
+        }
+        let rank = |seat: usize| {
+            let [a, b] = self.hole[seat];
+            let c = &runout.board;
+            self.variant.evaluate_7cards([a, b, c[0], c[1], c[2], c[3], c[4]])
+        };
+        let ranks: Vec<u32> = (0..6).map(|i| if self.alive[i] { rank(i) } else { u32::MAX }).collect();
+
+        let mut levels: Vec<u32> = alive.iter().map(|&i| contributed[i]).collect();
+        levels.sort_unstable();
+        levels.dedup();
+        let mut previous = 0;
+        for (k, &level) in levels.iter().enumerate() {
+            let mut amount: u32 = (0..6)
+                .map(|i| contributed[i].min(level) - contributed[i].min(previous))
+                .sum();
+            if k == levels.len() - 1 {
+                // 가장 큰 생존자 투자금을 넘는 폴드한 좌석의 칩
+                amount += (0..6).map(|i| contributed[i].saturating_sub(level)).sum::<u32>();
+            }
+            previous = level;
+
+            let taken = rake.min(amount);
+            amount -= taken;
+            rake -= taken;
+
+            let eligible: Vec<usize> = alive.iter().copied().filter(|&i| contributed[i] >= level).collect();
+            let best = eligible.iter().map(|&i| ranks[i]).min().unwrap_or(u32::MAX);
+            let winners: Vec<usize> = (1..=6)
+                .map(|offset| (self.button + offset) % 6)
+                .filter(|i| eligible.contains(i) && ranks[*i] == best)
+                .collect();
+            let share = amount / winners.len() as u32;
+            let odd = (amount % winners.len() as u32) as usize;
+            for (j, &seat) in winners.iter().enumerate() {
+                stacks[seat] += share + u32::from(j < odd);
+            }
+        }
+        stacks
     }
 
     /// 올인 여부 확인
@@ -180,10 +612,280 @@ impl State {
         self.stack[player] == 0
     }
 
-    /// 현재 최소 레이즈 크기 계산
+    /// 액션할 플레이어가 `Act::Raise(size)`로 넣는 칩 (콜 금액 포함, 스택이 상한)
+    ///
+    /// - 노리밋: `Raise(0)`은 콜한 뒤 현재 팟(콜 전)만큼 추가, 그 외는 올인
+    /// - 팟리밋: 크기와 상관없이 최대 팟 레이즈. 콜 금액을 먼저 팟에 넣었다고 보고 그 팟만큼
+    ///   추가하므로 레이즈 금액은 `팟 + 콜 금액`입니다. 예를 들어 팟 100에 상대가 50을
+    ///   베팅하면(현재 팟 150) 콜 50 뒤 팟은 200이고, 200을 더 올려 250까지 레이즈합니다.
+    /// - 픽스드리밋: 콜한 뒤 스트리트의 베팅 단위만큼 추가
+    pub fn raise_amount(&self, size: u8) -> u32 {
+        let player = self.to_act.min(5);
+        let stack = self.stack[player];
+        let call_amount = self.to_call.saturating_sub(self.invested[player]).min(stack);
+        let behind = stack - call_amount;
+        let raise = match self.betting {
+            BettingRules::NoLimit => match size {
+                0 => self.pot.min(behind), // 팟 베팅
+                _ => behind,               // 올인
+            },
+            BettingRules::PotLimit => (self.pot + call_amount).min(behind),
+            BettingRules::FixedLimit { small_bet, big_bet, .. } => {
+                let unit = if self.street < 2 { small_bet } else { big_bet };
+                unit.min(behind)
+            }
+        };
+        call_amount + raise
+    }
+
+    /// 베팅 규칙상 이번 스트리트에 레이즈가 더 허용되는지 (픽스드리밋 레이즈 상한)
+    pub fn raise_allowed(&self) -> bool {
+        match self.betting {
+            BettingRules::FixedLimit { max_raises, .. } => self.raises_this_street < max_raises,
+            _ => true,
+        }
+    }
+
+    /// 액션할 플레이어가 이번 스트리트 누적 `raise_to`까지 레이즈한 다음 상태
+    ///
+    /// 추상 액션(`Act::Raise`)을 거치지 않고 임의 금액을 적용합니다 (베팅 크기별 EV 분석용).
+    /// 금액은 스택으로 제한되며, 최소 레이즈 검사는 호출하는 쪽(`raise_bounds`)의 책임입니다.
+    pub(crate) fn apply_concrete_raise(&self, raise_to: u32) -> State {
+        let player = self.to_act.min(5);
+        let amount = raise_to.saturating_sub(self.invested[player]).min(self.stack[player]);
+        let mut next = self.clone();
+        next.commit_raise(player, amount);
+        next.finish_action(player);
+        next
+    }
+
+    /// `player`가 `amount`칩을 레이즈로 넣음 (최소 증가분을 채운 레이즈만 최소 리레이즈 기준을 바꿈)
+    fn commit_raise(&mut self, player: usize, amount: u32) {
+        let previous_to_call = self.to_call;
+        let min_increment = self.min_raise_increment();
+        self.invested[player] += amount;
+        self.stack[player] -= amount;
+        self.pot += amount;
+        // 최소 증가분에 못 미치는 올인은 액션을 다시 열지 않으므로 기준을 바꾸지 않음
+        let increment = self.invested[player].saturating_sub(previous_to_call);
+        if increment >= min_increment {
+            self.last_raise = increment;
+        }
+        self.to_call = self.to_call.max(self.invested[player]);
+        self.raises_this_street = self.raises_this_street.saturating_add(1);
+    }
+
+    /// 액션 수를 세고 베팅 라운드 종료 여부에 따라 다음 차례 설정
+    fn finish_action(&mut self, player: usize) {
+        self.actions_taken += 1;
+
+        // 베팅 라운드 완료 체크 및 다음 플레이어 설정
+        if self.is_betting_complete() {
+            // 베팅 라운드가 끝났으면 찬스 노드가 되거나 터미널 상태가 됨
+            // advance_street는 apply_chance에서 처리하도록 함
+            self.to_act = 6; // 유효하지 않은 플레이어 번호로 설정하여 찬스 노드임을 표시
+        } else if let Some(next_player) = self.find_next_player(player) {
+            // 베팅이 계속되면 다음 플레이어 찾기
+            self.to_act = next_player;
+        }
+    }
+
+    /// 최소 베팅/레이즈 증가분 (마지막 완전한 레이즈 증가분과 빅블라인드 중 큰 값, 최소 1칩)
+    pub fn min_raise_increment(&self) -> u32 {
+        self.last_raise.max(self.big_blind).max(1)
+    }
+
+    /// 최소 레이즈 목표 금액 (현재 콜 금액 + 최소 증가분, 스택 제한 전)
     pub fn min_raise_size(&self) -> u32 {
-        // 마지막 레이즈 크기의 2배 또는 빅블라인드 중 큰 값
-        std::cmp::max(self.to_call * 2, 100) // 100 = 기본 빅블라인드
+        self.to_call + self.min_raise_increment()
+    }
+
+    /// 액션할 플레이어의 레이즈 가능 범위 (UI 슬라이더용, 레이즈할 수 없으면 None)
+    ///
+    /// 모든 금액은 이번 스트리트 누적 투자액 기준의 "레이즈 목표(raise-to)"입니다.
+    /// 최대값은 가장 깊은 상대가 콜할 수 있는 금액(유효 스택)으로 제한되고,
+    /// 팟리밋은 팟 레이즈, 픽스드리밋은 정해진 한 단위가 상한입니다.
+    /// 최소 레이즈를 채울 칩이 없으면 최소값은 올인 금액이 됩니다.
+    pub fn raise_bounds(&self) -> Option<RaiseBounds> {
+        let player = Self::current_player(self)?;
+        if !self.raise_allowed() {
+            return None;
+        }
+        let stack = self.stack[player];
+        let invested = self.invested[player];
+        if stack <= self.to_call.saturating_sub(invested) {
+            return None;
+        }
+        // 레이즈를 콜할 수 있는 상대가 없으면 레이즈는 의미가 없음
+        let covered = (0..6)
+            .filter(|&seat| seat != player && self.alive[seat] && self.stack[seat] > 0)
+            .map(|seat| self.invested[seat] + self.stack[seat])
+            .max()?;
+        if covered <= self.to_call {
+            return None;
+        }
+
+        let all_in = invested + stack;
+        let mut max_raise_to = all_in.min(covered);
+        let mut min_raise_to = self.min_raise_size();
+        match self.betting {
+            BettingRules::NoLimit => {}
+            BettingRules::PotLimit => max_raise_to = max_raise_to.min(invested + self.raise_amount(0)),
+            BettingRules::FixedLimit { .. } => {
+                max_raise_to = max_raise_to.min(invested + self.raise_amount(0));
+                min_raise_to = max_raise_to;
+            }
+        }
+        let min_raise_to = min_raise_to.min(max_raise_to);
+
+        // 최소 레이즈, 추상 레이즈 버킷(팟/올인) 금액, 최대값
+        let mut suggested: Vec<u32> = crate::game::concrete_action::RAISE_BUCKETS
+            .iter()
+            .filter_map(|act| match act {
+                Act::Raise(size) => Some((invested + self.raise_amount(*size)).clamp(min_raise_to, max_raise_to)),
+                _ => None,
+            })
+            .collect();
+        suggested.extend([min_raise_to, max_raise_to]);
+        suggested.sort_unstable();
+        suggested.dedup();
+
+        Some(RaiseBounds {
+            min_raise_to,
+            max_raise_to,
+            all_in,
+            suggested,
+        })
+    }
+}
+
+/// 액션할 플레이어의 레이즈 가능 범위 (칩, 이번 스트리트 누적 투자액 기준)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RaiseBounds {
+    /// 최소 레이즈 목표 금액
+    pub min_raise_to: u32,
+    /// 최대 레이즈 목표 금액 (유효 스택과 베팅 규칙으로 제한)
+    pub max_raise_to: u32,
+    /// 액션할 플레이어의 올인 금액 (상대 스택과 무관)
+    pub all_in: u32,
+    /// 추천 레이즈 목표 금액들 (오름차순, 범위 안으로 보정)
+    pub suggested: Vec<u32>,
+}
+
+impl State {
+    /// `player` 관점의 사람이 읽을 수 있는 상태 (다른 플레이어 홀카드는 `[??]`)
+    pub fn fmt_for_player(&self, player: usize) -> impl fmt::Display + '_ {
+        StateView {
+            state: self,
+            viewer: Some(player),
+        }
+    }
+
+    /// 카드를 받은 좌석 (폴드한 좌석 포함, 빈 좌석 제외)
+    fn dealt_in(&self, seat: usize) -> bool {
+        self.alive[seat] || self.hole[seat] != [0, 0]
+    }
+
+    /// 카드를 받은 좌석 수 (최소 1)
+    fn dealt_count(&self) -> usize {
+        (0..6).filter(|&i| self.dealt_in(i)).count().max(1)
+    }
+
+    /// 버튼/블라인드 좌석 표시 (`new_hand`의 좌석 배치 기준, 그 외 좌석은 None)
+    pub fn seat_label(&self, seat: usize) -> Option<&'static str> {
+        let player_count = self.dealt_count();
+        if seat == self.button {
+            Some("BTN")
+        } else if player_count > 2 && seat == (self.button + 1) % player_count {
+            Some("SB")
+        } else if seat == (self.button + if player_count > 2 { 2 } else { 1 }) % player_count {
+            Some("BB")
+        } else {
+            None
+        }
+    }
+
+    /// 이번 스트리트에서 `seat`보다 뒤에 행동하는, 아직 액션할 수 있는 플레이어 수
+    ///
+    /// 프리플랍은 UTG부터 빅블라인드까지(헤즈업은 버튼부터), 포스트플랍은 버튼 왼쪽부터
+    /// 버튼까지의 순서를 기준으로 하며 폴드했거나 올인한 플레이어는 세지 않습니다.
+    pub fn players_behind(&self, seat: usize) -> usize {
+        let player_count = self.dealt_count();
+        let first = match (self.street, player_count > 2) {
+            (0, true) => self.button + 3,
+            (0, false) => self.button,
+            _ => self.button + 1,
+        } % player_count;
+        let order = |s: usize| (s + player_count - first) % player_count;
+        (0..player_count)
+            .filter(|&s| self.alive[s] && !self.is_all_in(s) && order(s) > order(seat))
+            .count()
+    }
+}
+
+/// 디버깅/로그용 한 줄 요약
+///
+/// 예: `Street: Flop [Ah 7d 2c] | Pot: 350 | To act: P1 (BTN) | P0: [As Kd] stack 870 invested 80 | ...`
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        StateView {
+            state: self,
+            viewer: None,
+        }
+        .fmt(f)
+    }
+}
+
+/// `viewer`가 있으면 그 플레이어의 홀카드만 보여주는 상태 포맷터
+struct StateView<'a> {
+    state: &'a State,
+    viewer: Option<usize>,
+}
+
+impl fmt::Display for StateView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state;
+        let cards = |cards: &[u8]| cards.iter().map(|&card| card_to_string(card)).collect::<Vec<_>>().join(" ");
+
+        let street = match state.street {
+            0 => "Preflop".to_string(),
+            1 => "Flop".to_string(),
+            2 => "Turn".to_string(),
+            3 => "River".to_string(),
+            other => format!("Street {}", other),
+        };
+        write!(f, "Street: {}", street)?;
+        if !state.board.is_empty() {
+            write!(f, " [{}]", cards(&state.board))?;
+        }
+        write!(f, " | Pot: {}", state.pot)?;
+
+        if state.is_terminal() {
+            write!(f, " | Terminal")?;
+        } else {
+            write!(f, " | To act: P{}", state.to_act)?;
+            if let Some(label) = state.seat_label(state.to_act) {
+                write!(f, " ({})", label)?;
+            }
+        }
+
+        for seat in (0..6).filter(|&seat| state.dealt_in(seat)) {
+            let hole = if self.viewer.is_none_or(|viewer| viewer == seat) {
+                cards(&state.hole[seat])
+            } else {
+                "??".to_string()
+            };
+            write!(f, " | P{}: [{}]", seat, hole)?;
+            if !state.alive[seat] {
+                write!(f, " folded")?;
+            }
+            write!(f, " stack {} invested {}", state.stack[seat], state.invested[seat])?;
+            if state.alive[seat] && seat == state.to_act && !state.is_terminal() {
+                write!(f, " (to call {})", state.to_call.saturating_sub(state.invested[seat]))?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -249,7 +951,12 @@ impl GameState for State {
 /// 홀덤 액션 정의
 ///
 /// 플레이어가 할 수 있는 모든 행동을 나타냅니다.
+///
+/// JSON 표현 (인접 태그): `{"type": "Fold"}`, `{"type": "Call"}`,
+/// `{"type": "Raise", "size": 2}`
 #[derive(Copy, Clone, Eq, Hash, PartialEq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", content = "size")]
 pub enum Act {
     /// 포기 (패배 인정)
     Fold,
@@ -257,11 +964,12 @@ pub enum Act {
     /// 콜 (현재 베팅에 맞춤)
     Call,
 
-    /// 레이즈 (베팅 크기 증가)
-    /// 0 = 미니멀 레이즈
-    /// 1 = 스몰 레이즈 (팟의 1/2)
-    /// 2 = 미디엄 레이즈 (팟 크기)  
-    /// 3 = 빅 레이즈 (팟의 2배 또는 올인)
+    /// 레이즈 (사이징 인덱스, 노리밋 기준이며 다른 베팅 규칙은 `State::raise_amount` 참고)
+    /// 0 = 팟 레이즈 (콜한 뒤 현재 팟만큼 추가, 스택이 부족하면 올인)
+    /// 1 이상 = 올인
+    ///
+    /// `legal_actions`는 `Raise(0)`만 제공합니다. 실제 칩 금액은
+    /// `concrete_action::resolve`로 구합니다.
     Raise(u8),
 }
 
@@ -316,8 +1024,8 @@ impl Game for State {
         if s.stack[player] > call_amount {
             let remaining_after_call = s.stack[player] - call_amount;
 
-            // 단 1가지 레이즈 크기만 제공 (복잡도 대폭 감소)
-            if remaining_after_call > 0 {
+            // 단 1가지 레이즈 크기만 제공 (복잡도 대폭 감소, 크기는 베팅 규칙이 제한)
+            if remaining_after_call > 0 && s.raise_allowed() {
                 actions.push(Act::Raise(0)); // 올인만 허용
             }
         }
@@ -345,42 +1053,17 @@ impl Game for State {
             }
 
             Act::Raise(size) => {
-                let call_amount = s.to_call.saturating_sub(s.invested[player]);
-
-                // 레이즈 크기 계산
-                let raise_amount = match size {
-                    0 => std::cmp::min(s.pot, s.stack[player] - call_amount), // 팟 베팅
-                    1 => s.stack[player] - call_amount,                       // 올인
-                    _ => s.stack[player] - call_amount,                       // 기본값은 올인
-                };
-
-                let total_investment = call_amount + raise_amount;
-                next.invested[player] += total_investment;
-                next.stack[player] -= total_investment;
-                next.pot += total_investment;
-                next.to_call = next.invested[player];
-            }
-        }
-
-        next.actions_taken += 1;
-
-        // 베팅 라운드 완료 체크 및 다음 플레이어 설정
-        if next.is_betting_complete() {
-            // 베팅 라운드가 끝났으면 찬스 노드가 되거나 터미널 상태가 됨
-            // advance_street는 apply_chance에서 처리하도록 함
-            next.to_act = 6; // 유효하지 않은 플레이어 번호로 설정하여 찬스 노드임을 표시
-        } else {
-            // 베팅이 계속되면 다음 플레이어 찾기
-            if let Some(next_player) = next.find_next_player(player) {
-                next.to_act = next_player;
+                // 레이즈 크기 계산 (베팅 규칙에 따라 제한)
+                next.commit_raise(player, s.raise_amount(size));
             }
         }
 
+        next.finish_action(player);
         next
     }
 
     /// 찬스 노드에서 카드 딜링
-    fn apply_chance(s: &Self::State, rng: &mut ThreadRng) -> Self::State {
+    fn apply_chance<R: Rng>(s: &Self::State, rng: &mut R) -> Self::State {
         let mut next = s.clone();
 
         if next.is_betting_complete() && next.street < 3 {
@@ -388,19 +1071,24 @@ impl Game for State {
             next.advance_street();
 
             match next.street {
-                1 => {
+                1 => match next.flop_sampler.clone() {
+                    // 플랍 부분집합: 홀카드와 겹치지 않는 수트 배정으로 딜링
+                    Some(sampler) if next.variant == HoldemVariant::Standard => {
+                        let dead: Vec<u8> = next.hole.iter().flatten().copied().collect();
+                        next.board.extend(sampler.sample(rng, &dead));
+                    }
                     // 플랍: 3장 추가
-                    for _ in 0..3 {
-                        next.board.push(rng.gen_range(0..52));
+                    _ => {
+                        for _ in 0..3 {
+                            let card = next.deal_board_card(rng);
+                            next.board.push(card);
+                        }
                     }
-                }
-                2 => {
-                    // 턴: 1장 추가
-                    next.board.push(rng.gen_range(0..52));
-                }
-                3 => {
-                    // 리버: 1장 추가
-                    next.board.push(rng.gen_range(0..52));
+                },
+                2 | 3 => {
+                    // 턴/리버: 1장 추가
+                    let card = next.deal_board_card(rng);
+                    next.board.push(card);
                 }
                 _ => {}
             }
@@ -409,48 +1097,48 @@ impl Game for State {
         next
     }
 
+    /// 턴/리버 딜링의 모든 결과 (남은 카드마다 같은 확률)
+    ///
+    /// 플랍 딜링은 결과가 너무 많아(약 2만 가지) 열거하지 않고 None을 반환하므로,
+    /// 열거 모드 학습은 턴 이후에서 시작하는 서브게임에서만 쓸 수 있습니다.
+    fn chance_outcomes(s: &Self::State) -> Option<Vec<(Self::State, f64)>> {
+        if !s.is_chance_node() || s.street == 0 {
+            return None;
+        }
+        let mut next = s.clone();
+        next.advance_street();
+        let live = next.live_cards();
+        let probability = 1.0 / live.len() as f64;
+        Some(
+            live.into_iter()
+                .map(|card| {
+                    let mut outcome = next.clone();
+                    outcome.board.push(card);
+                    (outcome, probability)
+                })
+                .collect(),
+        )
+    }
+
     /// 터미널 노드에서 유틸리티 계산
     fn util(s: &Self::State, hero: usize) -> f64 {
+        // 현재 스트리트 투자금과 앤티
+        let contributed = (s.invested[hero] + s.antes[hero]) as f64;
         if !s.alive[hero] {
-            // 폴드했으면 현재 투자 금액만큼 손실
-            return -(s.invested[hero] as f64);
+            // 폴드했으면 투자 금액만큼 손실
+            return -contributed;
         }
 
         let alive_players: Vec<usize> = (0..6).filter(|&i| s.alive[i]).collect();
 
         if alive_players.len() == 1 {
-            // 혼자 남았으면 전체 팟 획득
-            return s.pot as f64 - s.invested[hero] as f64;
-        }
-
-        // 쇼다운: 핸드 강도 비교 (간단한 구현)
-        if s.board.len() >= 3 {
-            let hero_strength = hand_strength(s.hole[hero], &s.board);
-            let mut wins = 0;
-            let mut total_opponents = 0;
-
-            for &opponent in &alive_players {
-                if opponent != hero {
-                    let opp_strength = hand_strength(s.hole[opponent], &s.board);
-                    total_opponents += 1;
-                    if hero_strength > opp_strength {
-                        wins += 1;
-                    }
-                }
-            }
-
-            // 승률에 따른 팟 분배 (간단한 근사)
-            let win_rate = if total_opponents > 0 {
-                wins as f64 / total_opponents as f64
-            } else {
-                1.0
-            };
-
-            return win_rate * s.pot as f64 - s.invested[hero] as f64;
+            // 혼자 남았으면 전체 팟 획득 (레이크 공제)
+            return s.net_pot() as f64 - contributed;
         }
 
-        // 보드가 없으면 균등 분할 가정
-        s.pot as f64 / alive_players.len() as f64 - s.invested[hero] as f64
+        // 쇼다운: 남은 보드를 열거/샘플링한 팟 지분 (올인 판정)
+        let showdown = AllInAdjudicator::deterministic().state_equity(s);
+        showdown.equities[hero] * s.net_pot() as f64 - contributed
     }
 
     /// 정보 집합 키 생성
@@ -458,9 +1146,10 @@ impl Game for State {
         // 플레이어가 볼 수 있는 정보만 사용하여 키 생성
         let mut key = 0u64;
 
-        // 홀카드 정보 (플레이어 본인만)
+        // 홀카드 정보 (플레이어 본인만, 프리플랍은 살아 있는 상대 수에 따라 버킷팅)
         let hole_bucket = if s.street == 0 {
-            preflop_bucket(s.hole[player]) as u64
+            let live_opponents = (0..6).filter(|&seat| seat != player && s.alive[seat]).count();
+            s.variant.preflop_bucket_vs(s.hole[player], live_opponents) as u64
         } else {
             postflop_bucket(s.hole[player], &s.board, s.street) as u64
         };
@@ -477,6 +1166,10 @@ impl Game for State {
         key ^= (s.street as u64) << 20;
         key ^= (s.actions_taken as u64) << 8;
 
+        // 버튼 기준 상대 포지션
+        let position = (player + 6 - s.button % 6) % 6;
+        key ^= (position as u64) << 56;
+
         // 스택 크기 구간 (정확한 값 대신 구간 사용)
         let stack_ratio = if s.pot > 0 {
             (s.stack[player] / std::cmp::max(s.pot, 1)) as u64
@@ -489,10 +1182,43 @@ impl Game for State {
         let legal_actions = Self::legal_actions(s);
         key ^= (legal_actions.len() as u64) << 60;
 
+        // 베팅 규칙이 다르면 전략도 다름
+        key ^= s.betting.key_bits() << 62;
+
+        // 스트래들이 있으면 포지션과 팟 배율이 달라 전략도 다름 (없으면 기존 키 유지)
+        key ^= u64::from(s.straddle > 0) << 59;
+
         key
     }
+
+    /// 루트의 변형/베팅 규칙으로 만든 호환성 지문 (`State::rules_fingerprint`)
+    fn fingerprint(root: Option<&Self::State>) -> CompatibilityFingerprint {
+        root.map_or_else(
+            || State::rules_fingerprint(HoldemVariant::default(), BettingRules::default()),
+            |s| State::rules_fingerprint(s.variant, s.betting),
+        )
+    }
+}
+
+impl State {
+    /// 변형과 버킷 수, 베팅 규칙 방식, 폴드/콜과 레이즈 구간으로 이루어진 호환성 지문
+    pub fn rules_fingerprint(variant: HoldemVariant, betting: BettingRules) -> CompatibilityFingerprint {
+        let abstraction = format!(
+            "holdem-{:?}/preflop={}/flop={}/turn={}/river={}",
+            variant, PREFLOP_BUCKETS, FLOP_BUCKETS, TURN_BUCKETS, RIVER_BUCKETS
+        );
+        let actions = [Act::Fold, Act::Call]
+            .iter()
+            .chain(crate::game::concrete_action::RAISE_BUCKETS.iter())
+            .map(|action| format!("{:?}", action))
+            .collect();
+        CompatibilityFingerprint::new(abstraction, betting.mode_name(), actions)
+    }
 }
 
+/// 예산 안에서 리솔빙할 때 예산을 검사하는 반복 배치 크기
+const RESOLVE_BATCH_ITERATIONS: usize = 16;
+
 /// 서브게임 리솔빙 함수
 ///
 /// 특정 상황에서 더 정확한 전략을 얻기 위해 작은 게임 트리에서
@@ -502,17 +1228,62 @@ impl Game for State {
 /// - global: 메인 CFR 트레이너 (결과가 여기에 병합됨)
 /// - root: 서브게임 시작 상태
 /// - extra_iter: 추가 학습 반복 횟수
+///
+/// 서브게임 노드는 모두 리솔빙 루트 이하이므로 `MergeMode::Replace`로 병합되며,
+/// 서브게임에 없는 트렁크 노드는 그대로 유지됩니다.
 pub fn resolve_subgame(global: &mut Trainer<State>, root: State, extra_iter: usize) {
+    resolve_subgame_with_mode(global, root, extra_iter, MergeMode::Replace);
+}
+
+/// 병합 방식을 지정하는 서브게임 리솔빙
+///
+/// `MergeMode::Accumulate`는 이전 `resolve_subgame` 동작과 같습니다.
+pub fn resolve_subgame_with_mode(
+    global: &mut Trainer<State>,
+    root: State,
+    extra_iter: usize,
+    mode: MergeMode,
+) {
+    resolve_subgame_within(global, root, extra_iter, mode, &ComputeBudget::unlimited());
+}
+
+/// 예산 안에서 실행하는 서브게임 리솔빙 (실제로 돌린 반복 수 반환)
+///
+/// 반복 하나가 샘플 하나, 새로 만든 서브게임 노드가 노드 예산이며 `RESOLVE_BATCH_ITERATIONS`번마다
+/// 예산을 검사합니다. 예산이 끝나면 그때까지 학습한 서브게임을 병합합니다.
+pub fn resolve_subgame_within(
+    global: &mut Trainer<State>,
+    root: State,
+    extra_iter: usize,
+    mode: MergeMode,
+    budget: &ComputeBudget,
+) -> Budgeted<usize> {
     println!("🔍 서브게임 리솔빙 시작 - {} 추가 반복", extra_iter);
 
     // 독립적인 서브게임 트레이너 생성
     let mut sub_trainer = Trainer::<State>::new();
 
-    // 서브게임에서 집중 학습
-    sub_trainer.run(vec![root.clone()], extra_iter);
+    // 서브게임에서 집중 학습 (배치 경계에서 예산 검사)
+    let mut iterations = 0;
+    while iterations < extra_iter {
+        let wanted = (extra_iter - iterations).min(RESOLVE_BATCH_ITERATIONS);
+        let granted = budget.take_samples(wanted as u64).unwrap_or(0) as usize;
+        if granted == 0 {
+            break;
+        }
+        let nodes_before = sub_trainer.nodes.len();
+        sub_trainer.run(vec![root.clone()], granted);
+        budget.charge_nodes((sub_trainer.nodes.len() - nodes_before) as u64);
+        iterations += granted;
+        if granted < wanted {
+            break;
+        }
+    }
 
     println!(
-        "  서브게임 학습 완료 - {} 노드 생성",
+        "  서브게임 학습 완료 - {}/{} 반복, {} 노드 생성",
+        iterations,
+        extra_iter,
         sub_trainer.nodes.len()
     );
 
@@ -521,16 +1292,582 @@ pub fn resolve_subgame(global: &mut Trainer<State>, root: State, extra_iter: usi
         global
             .nodes
             .entry(key)
-            .and_modify(|existing_node| existing_node.merge(&node))
+            .and_modify(|existing_node| existing_node.merge_weighted(&node, mode))
             .or_insert(node);
     }
 
     println!("✅ 서브게임 전략 병합 완료");
+    budget.finish(iterations)
+}
+
+/// 칩 EV 터미널 평가기 (사이드 팟 포함)
+///
+/// 생존 플레이어의 현재 스트리트 투자금으로 팟을 층으로 나누고, 층마다 그 금액을 낸
+/// 생존 플레이어끼리 올인 판정기로 지분을 나눕니다. 앤티와 이전 스트리트에 쌓인 칩은
+/// 모든 생존 플레이어가 다투는 메인 팟에 들어가며, 레이크는 모든 층에서 같은 비율로 공제합니다.
+/// 사이드 팟이 없으면 `State::util`과 같은 값입니다.
+#[derive(Debug, Clone)]
+pub struct SidePotEvaluator {
+    pub adjudicator: AllInAdjudicator,
+}
+
+impl SidePotEvaluator {
+    pub fn new(adjudicator: AllInAdjudicator) -> Self {
+        Self { adjudicator }
+    }
+}
+
+impl Default for SidePotEvaluator {
+    /// 고정 시드 판정기 (시드 고정 학습 재현용)
+    fn default() -> Self {
+        Self::new(AllInAdjudicator::deterministic())
+    }
+}
+
+impl TerminalEvaluator<State> for SidePotEvaluator {
+    fn evaluate(&self, s: &State) -> Vec<f64> {
+        let mut utils: Vec<f64> = (0..6).map(|i| -((s.invested[i] + s.antes[i]) as f64)).collect();
+        let alive: Vec<usize> = (0..6).filter(|&i| s.alive[i]).collect();
+        let net_pot = s.net_pot() as f64;
+
+        if alive.len() <= 1 {
+            if let Some(&winner) = alive.first() {
+                utils[winner] += net_pot;
+            }
+            return utils;
+        }
+
+        let mut levels: Vec<u32> = alive.iter().map(|&i| s.invested[i]).collect();
+        levels.sort_unstable();
+        levels.dedup();
+
+        // 메인 팟에 들어가는 앤티와 이전 스트리트 칩
+        let carried = s.pot.saturating_sub(s.invested.iter().sum::<u32>()) as f64;
+        let mut previous = 0;
+        for (k, &level) in levels.iter().enumerate() {
+            let mut amount: f64 = (0..6)
+                .map(|i| (s.invested[i].min(level) - s.invested[i].min(previous)) as f64)
+                .sum();
+            if k == 0 {
+                amount += carried;
+            }
+            if k == levels.len() - 1 {
+                // 가장 큰 생존 투자금을 넘는 폴드한 플레이어의 칩
+                amount += (0..6).map(|i| s.invested[i].saturating_sub(level) as f64).sum::<f64>();
+            }
+            previous = level;
+
+            let share = if s.pot > 0 { amount * net_pot / s.pot as f64 } else { 0.0 };
+            let eligible: Vec<usize> = alive.iter().copied().filter(|&i| s.invested[i] >= level).collect();
+            if let [only] = eligible[..] {
+                utils[only] += share;
+                continue;
+            }
+            let hands: Vec<[u8; 2]> = eligible.iter().map(|&i| s.hole[i]).collect();
+            let equity = self.adjudicator.variant_equities(s.variant, &hands, &s.board);
+            for (j, &seat) in eligible.iter().enumerate() {
+                utils[seat] += share * equity.equities[j];
+            }
+        }
+        utils
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solver::cfr_core::Node;
+
+    fn river_state() -> State {
+        let mut state = State::new_hand([50, 100], [1000; 6], 2);
+        state.hole[0] = [0, 13]; // AA
+        state.hole[1] = [1, 14]; // 22
+        state.board = vec![22, 35, 48, 8, 44];
+        state.street = 3;
+        state.invested = [0; 6];
+        state.to_call = 0;
+        state.actions_taken = 0;
+        state.to_act = 0;
+        state
+    }
+
+    #[test]
+    fn test_display_shows_cards_and_hides_other_holes() {
+        let mut state = river_state();
+        state.board.truncate(3);
+        state.street = 1;
+        let full = state.to_string();
+        assert!(full.starts_with("Street: Flop [10h 10d 10c] | Pot: 150"), "{}", full);
+        assert!(full.contains("To act: P0 (BTN)"), "{}", full);
+        assert!(full.contains("P0: [As Ah] stack 950 invested 0 (to call 0)"), "{}", full);
+        assert!(full.contains("P1: [2s 2h] stack 900"), "{}", full);
+        assert!(!full.contains("P2"), "빈 좌석은 출력하지 않음: {}", full);
+
+        let hero = state.fmt_for_player(1).to_string();
+        assert!(hero.contains("P0: [??]") && hero.contains("P1: [2s 2h]"), "{}", hero);
+        assert!(!hero.contains("As"), "{}", hero);
+    }
+
+    #[test]
+    fn test_display_handles_preflop_and_folded_states() {
+        let root = State::new_hand([50, 100], [1000; 6], 6);
+        let preflop = root.to_string();
+        assert!(preflop.starts_with("Street: Preflop | Pot: 150 | To act: P0"), "{}", preflop);
+        assert!(preflop.contains("P5: [") && !preflop.contains("[??]"), "{}", preflop);
+        assert!(preflop.contains("(to call 100)"), "{}", preflop);
+
+        let mut folded = root.clone();
+        while !folded.is_terminal() {
+            folded = State::next_state(&folded, Act::Fold);
+        }
+        let text = folded.to_string();
+        assert!(text.contains("| Terminal"), "{}", text);
+        assert_eq!(text.matches(" folded ").count(), 5, "{}", text);
+        for player in 0..6 {
+            assert_eq!(folded.fmt_for_player(player).to_string().matches("[??]").count(), 5);
+        }
+
+        // 카드를 받지 않은 상태도 패닉하지 않음
+        let mut empty = root;
+        empty.alive = [false; 6];
+        empty.hole = [[0; 2]; 6];
+        assert_eq!(empty.to_string(), "Street: Preflop | Pot: 150 | Terminal");
+    }
+
+    #[test]
+    fn test_players_behind_follows_street_order() {
+        // 6인: 버튼 3, SB 4, BB 5, UTG 0
+        let mut state = State::new_hand([50, 100], [1000; 6], 6);
+        assert_eq!(state.seat_label(3), Some("BTN"));
+        assert_eq!(state.players_behind(0), 5);
+        assert_eq!(state.players_behind(3), 2);
+        assert_eq!(state.players_behind(5), 0);
+
+        state.street = 1;
+        assert_eq!(state.players_behind(4), 5);
+        assert_eq!(state.players_behind(3), 0);
+        state.alive[1] = false;
+        assert_eq!(state.players_behind(0), 2);
+
+        // 헤즈업은 프리플랍에 버튼이 먼저, 포스트플랍에 나중에 행동
+        let mut heads_up = State::new_hand([50, 100], [1000; 6], 2);
+        assert_eq!(heads_up.players_behind(0), 1);
+        heads_up.street = 1;
+        assert_eq!(heads_up.players_behind(0), 0);
+    }
+
+    #[test]
+    fn test_resolve_subgame_replace_is_idempotent() {
+        let trunk_key = u64::MAX;
+        let mut trunk_node = Node::new(2, vec![1.0; 2]);
+        trunk_node.update_strategy(0, 3.0);
+        trunk_node.update_strategy(1, 1.0);
+
+        let mut global = Trainer::<State>::new();
+        global.nodes.insert(trunk_key, trunk_node);
+
+        resolve_subgame(&mut global, river_state(), 20);
+        let once: Vec<(u64, Vec<f64>)> = {
+            let mut v: Vec<_> = global.nodes.iter().map(|(k, n)| (*k, n.average())).collect();
+            v.sort_by_key(|(k, _)| *k);
+            v
+        };
+
+        resolve_subgame(&mut global, river_state(), 20);
+        let mut twice: Vec<_> = global.nodes.iter().map(|(k, n)| (*k, n.average())).collect();
+        twice.sort_by_key(|(k, _)| *k);
+
+        assert_eq!(once.len(), twice.len());
+        for ((k1, s1), (k2, s2)) in once.iter().zip(twice.iter()) {
+            assert_eq!(k1, k2);
+            for (a, b) in s1.iter().zip(s2.iter()) {
+                assert!((a - b).abs() < 1e-9, "두 번 리솔빙해도 전략이 같아야 함");
+            }
+        }
+
+        // 서브게임 밖의 트렁크 노드는 그대로 유지
+        let trunk = global.nodes[&trunk_key].average();
+        assert!((trunk[0] - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rake_reduces_uncontested_pot() {
+        let rake = RakeModel {
+            percentage: 0.05,
+            cap: 10,
+            no_flop_no_drop: true,
+        };
+        let mut state = river_state();
+        state.alive[1] = false;
+        state.pot = 100;
+        assert_eq!(State::util(&state, 0), 100.0);
+
+        state.rake = Some(rake);
+        assert_eq!(State::util(&state, 0), 95.0);
+
+        // 큰 팟에서는 캡까지만 공제
+        state.pot = 10_000;
+        assert_eq!(State::util(&state, 0), 9_990.0);
+    }
+
+    #[test]
+    fn test_turn_all_in_util_uses_exact_equity() {
+        let mut state = river_state();
+        state.hole[1] = [12, 25]; // KK
+        state.board = vec![1 + 39, 6 + 26, 8 + 13, 11 + 39]; // 2c 7d 9h Qc
+        state.street = 2;
+        state.stack = [0; 6];
+        state.invested = [1000, 1000, 0, 0, 0, 0];
+        state.pot = 2000;
+        assert!(state.is_terminal());
+
+        let showdown = AllInAdjudicator::default().state_equity(&state);
+        assert!(showdown.exact);
+        assert_eq!(showdown.boards_evaluated, 44);
+        assert_eq!(State::util(&state, 0), showdown.equities[0] * 2000.0 - 1000.0);
+        assert!((State::util(&state, 0) + State::util(&state, 1)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_board_plays_splits_pot_exactly() {
+        let mut state = river_state();
+        state.hole[0] = [1, 15]; // 2s 3h
+        state.hole[1] = [29, 43]; // 4d 5c
+        state.board = vec![9, 23, 37, 51, 0]; // Ts Jh Qd Kc As: 브로드웨이 스트레이트
+        state.stack = [0; 6];
+        state.invested = [1000, 1000, 0, 0, 0, 0];
+        state.pot = 2000;
+        assert!(state.is_terminal());
+
+        // 둘 다 보드를 쓰므로 각자 팟의 정확히 절반
+        assert_eq!(State::util(&state, 0), 0.0);
+        assert_eq!(State::util(&state, 1), 0.0);
+        assert_eq!(SidePotEvaluator::default().evaluate(&state)[..2], [0.0, 0.0]);
+        assert_eq!(AllInAdjudicator::default().state_equity(&state).ties[..2], [1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_side_pot_evaluator_matches_util_without_side_pots() {
+        let mut state = river_state();
+        state.stack = [0; 6];
+        state.invested = [1000, 1000, 0, 0, 0, 0];
+        state.pot = 2000;
+        assert!(state.is_terminal());
+
+        let utils = SidePotEvaluator::default().evaluate(&state);
+        for (seat, util) in utils.iter().enumerate() {
+            assert_eq!(*util, State::util(&state, seat), "좌석 {}", seat);
+        }
+    }
+
+    #[test]
+    fn test_side_pot_goes_to_best_eligible_hand() {
+        // 보드 TTT96: AA > KK > QQ 풀하우스, AA는 200만 올인
+        let mut state = State::new_hand([50, 100], [1000; 6], 3);
+        state.hole[0] = [0, 13]; // AA
+        state.hole[1] = [12, 25]; // KK
+        state.hole[2] = [11, 24]; // QQ
+        state.board = vec![22, 35, 48, 8, 44];
+        state.street = 3;
+        state.stack = [0; 6];
+        state.invested = [200, 1000, 1000, 0, 0, 0];
+        state.pot = 2200;
+        assert!(state.is_terminal());
+
+        let utils = SidePotEvaluator::default().evaluate(&state);
+        // 메인 팟 600은 AA, 사이드 팟 1600은 KK
+        assert_eq!(&utils[..3], [400.0, 600.0, -1000.0]);
+        assert_eq!(utils.iter().sum::<f64>(), 0.0);
+        // 사이드 팟을 모르는 util은 AA에게 전체 팟을 줌
+        assert_eq!(State::util(&state, 0), 2000.0);
+    }
+
+    #[test]
+    fn test_settle_pays_side_pots_in_whole_chips() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        // 사이드 팟 평가기와 같은 상황을 실제 칩으로 정산
+        let mut state = State::new_hand([50, 100], [1000; 6], 3);
+        state.hole[0] = [0, 13]; // AA
+        state.hole[1] = [12, 25]; // KK
+        state.hole[2] = [11, 24]; // QQ
+        state.board = vec![22, 35, 48, 8, 44];
+        state.street = 3;
+        state.stack = [0; 6];
+        state.invested = [200, 1000, 1000, 0, 0, 0];
+        state.pot = 2200;
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(state.settle([200, 1000, 1000, 0, 0, 0], &mut rng), [600, 1600, 0, 0, 0, 0]);
+
+        // 프리플랍 올인은 보드를 실제로 딜하고, 어떤 런아웃에서도 칩이 보존됨
+        for seed in 0..200 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let start = [300, 1000, 1000, 700, 0, 0];
+            let mut hand = State::new_hand([50, 100], start, 4);
+            while !hand.is_terminal() {
+                hand = if hand.is_chance_node() {
+                    State::apply_chance(&hand, &mut rng)
+                } else {
+                    let actions = State::legal_actions(&hand);
+                    State::next_state(&hand, *actions.last().unwrap())
+                };
+            }
+            let cards: std::collections::HashSet<u8> =
+                hand.hole[..4].iter().flatten().chain(&hand.board).copied().collect();
+            assert_eq!(cards.len(), 8 + hand.board.len(), "중복 카드: {:?} {:?}", hand.hole, hand.board);
+
+            let end = hand.settle(start, &mut rng);
+            assert_eq!(end.iter().sum::<u32>(), start.iter().sum::<u32>(), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_no_flop_no_drop() {
+        let mut state = State::new_hand([25, 50], [1000; 6], 2);
+        state.rake = Some(RakeModel {
+            percentage: 0.05,
+            cap: 10,
+            no_flop_no_drop: true,
+        });
+        let folded = State::next_state(&state, Act::Fold);
+        assert!(folded.is_terminal());
+        assert_eq!(State::util(&folded, 1), 25.0); // 75 팟 - BB 50
+
+        // no_flop_no_drop이 꺼져 있으면 프리플랍 팟에도 레이크 (75 * 5% = 3)
+        state.rake = state.rake.map(|rake| RakeModel {
+            no_flop_no_drop: false,
+            ..rake
+        });
+        let folded = State::next_state(&state, Act::Fold);
+        assert_eq!(State::util(&folded, 1), 22.0);
+    }
+
+    #[test]
+    fn test_heads_up_action_order() {
+        let state = State::new_hand([25, 50], [1000; 6], 2);
+        assert_eq!(state.button, 0);
+        assert_eq!(state.to_act, 0); // 프리플랍: 버튼/SB 먼저
+        assert_eq!(state.invested[0], 25);
+
+        let state = State::next_state(&state, Act::Call);
+        let state = State::next_state(&state, Act::Call);
+        assert!(state.is_chance_node());
+
+        let flop = State::apply_chance(&state, &mut rand::thread_rng());
+        assert_eq!(flop.street, 1);
+        assert_eq!(flop.to_act, 1); // 플랍: BB 먼저
+    }
+
+    #[test]
+    fn test_six_max_postflop_order_starts_left_of_button() {
+        let mut state = State::new_hand([25, 50], [1000; 6], 6);
+        assert_eq!(state.button, 3);
+        assert_eq!(state.invested[4], 25); // SB
+        assert_eq!(state.invested[5], 50); // BB
+        assert_eq!(state.to_act, 0); // UTG
+
+        let mut flop = state.clone();
+        flop.advance_street();
+        assert_eq!(flop.to_act, 4); // SB 먼저
+
+        // SB가 폴드했으면 다음 생존 좌석 (BB)
+        state.alive[4] = false;
+        state.advance_street();
+        assert_eq!(state.to_act, 5);
+
+        // SB, BB 모두 없으면 시계 방향으로 넘어감
+        state.alive[5] = false;
+        state.advance_street();
+        assert_eq!(state.to_act, 0);
+    }
+
+    #[test]
+    fn test_seeded_deal_is_reproducible() {
+        use rand::SeedableRng;
+        let deal = |seed| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            State::new_hand_with_rng([50, 100], 0, AnteType::PerPlayer, [1000; 6], 6, &mut rng).hole
+        };
+        assert_eq!(deal(7), deal(7));
+        assert_ne!(deal(7), deal(8));
+    }
+
+    #[test]
+    fn test_big_blind_ante_posted_by_big_blind_only() {
+        let state = State::new_hand_with_antes([50, 100], 100, AnteType::BigBlindAnte, [1000; 6], 6);
+        assert_eq!(state.antes, [0, 0, 0, 0, 0, 100]);
+        assert_eq!(state.stack, [1000, 1000, 1000, 1000, 950, 800]);
+        // 앤티는 팟에는 들어가지만 콜 금액에는 포함되지 않음
+        assert_eq!(state.invested[5], 100);
+        assert_eq!(state.to_call, 100);
+        assert_eq!(state.pot, 250);
+
+        // 전원 폴드하면 BB는 자기 블라인드와 앤티를 돌려받고 SB만큼 이득
+        let mut folded = state.clone();
+        for seat in 0..5 {
+            folded.alive[seat] = false;
+        }
+        assert_eq!(State::util(&folded, 5), 50.0);
+        assert_eq!(State::util(&folded, 4), -50.0);
+
+        let per_player = State::new_hand_with_antes([50, 100], 10, AnteType::PerPlayer, [1000; 6], 6);
+        assert_eq!(per_player.pot, 210);
+        let button = State::new_hand_with_antes([50, 100], 100, AnteType::ButtonAnte, [1000; 6], 6);
+        assert_eq!(button.antes[button.button], 100);
+        assert_eq!(button.pot, 250);
+    }
+
+    #[test]
+    fn test_short_big_blind_posts_ante_before_blind() {
+        let mut stacks = [1000; 6];
+        stacks[5] = 80;
+        let state = State::new_hand_with_antes([50, 100], 100, AnteType::BigBlindAnte, stacks, 6);
+        // 앤티 우선: 80칩 전부 앤티로, 블라인드는 없음
+        assert_eq!(state.antes[5], 80);
+        assert_eq!(state.invested[5], 0);
+        assert!(state.is_all_in(5));
+        assert_eq!(state.pot, 50 + 80);
+        assert_eq!(state.to_call, 100);
+
+        // 앤티를 내고 남은 칩은 블라인드로
+        stacks[5] = 150;
+        let state = State::new_hand_with_antes([50, 100], 100, AnteType::BigBlindAnte, stacks, 6);
+        assert_eq!((state.antes[5], state.invested[5]), (100, 50));
+        assert_eq!(state.pot, 50 + 150);
+    }
+
+    #[test]
+    fn test_button_straddle_acts_last_preflop() {
+        // 6인: 버튼 3, SB 4, BB 5. 버튼 스트래들이면 스트래들러 왼쪽인 SB부터 액션
+        let straddle = ExtraBlind { seat: 3, amount: 200, kind: ExtraBlindKind::Straddle };
+        let mut state = State::new_hand_with_extra_blinds([50, 100], &[straddle], [1000; 6], 6);
+        assert_eq!(state.to_act, 4);
+        assert_eq!((state.pot, state.to_call, state.straddle), (350, 200, 200));
+        assert_eq!((state.invested[3], state.stack[3]), (200, 800));
+        // 스트래들이 새 빅블라인드 역할을 해 최소 레이즈는 400까지
+        assert_eq!(state.min_raise_size(), 400);
+
+        // SB, BB, UTG, HJ, CO가 콜하면 레이즈 없이 돌아온 스트래들러에게 옵션
+        for seat in [4, 5, 0, 1, 2] {
+            assert_eq!(State::current_player(&state), Some(seat));
+            state = State::next_state(&state, Act::Call);
+        }
+        assert_eq!(State::current_player(&state), Some(3));
+        assert_eq!(state.pot, 1200);
+        let actions = State::legal_actions(&state);
+        assert!(actions.contains(&Act::Call) && actions.contains(&Act::Raise(0)));
+
+        // 체크하면 프리플랍 종료
+        let checked = State::next_state(&state, Act::Call);
+        assert!(checked.is_chance_node());
+    }
+
+    #[test]
+    fn test_utg_straddle_and_dead_blind() {
+        let blinds = [
+            ExtraBlind { seat: 0, amount: 200, kind: ExtraBlindKind::Straddle },
+            ExtraBlind { seat: 2, amount: 50, kind: ExtraBlindKind::DeadBlind },
+        ];
+        let state = State::new_hand_with_extra_blinds([50, 100], &blinds, [1000; 6], 6);
+        // UTG 스트래들이면 UTG+1부터, 데드 블라인드는 콜 금액과 액션 순서에 영향 없음
+        assert_eq!(state.to_act, 1);
+        assert_eq!(state.to_call, 200);
+        assert_eq!((state.antes[2], state.invested[2], state.stack[2]), (50, 0, 950));
+        assert_eq!(state.pot, 50 + 100 + 200 + 50);
+
+        // 스트래들이 없는 상태와 정보 집합 키가 다름
+        let mut plain = State::new_hand_with_extra_blinds([50, 100], &blinds[1..], [1000; 6], 6);
+        assert_eq!(plain.straddle, 0);
+        plain.hole = state.hole;
+        let mut same_pot = plain.clone();
+        same_pot.straddle = 200;
+        assert_ne!(State::info_key(&plain, 1), State::info_key(&same_pot, 1));
+    }
+
+    #[test]
+    fn test_info_key_depends_on_position() {
+        let state = State::new_hand([25, 50], [1000; 6], 2);
+        let mut moved = state.clone();
+        moved.button = 1;
+        assert_ne!(State::info_key(&state, 0), State::info_key(&moved, 0));
+    }
+
+    #[test]
+    fn test_pot_limit_max_raise_worked_example() {
+        // 팟 100에 상대가 50 베팅: 현재 팟 150, 콜 50 뒤 팟 200, 200을 더해 250까지 레이즈
+        let mut state = river_state();
+        state.stack = [5000; 6];
+        state.betting = BettingRules::PotLimit;
+        state.pot = 150;
+        state.invested[0] = 50;
+        state.to_call = 50;
+        state.actions_taken = 1;
+        state.to_act = 1;
+        assert_eq!(state.raise_amount(0), 250);
+        assert_eq!(state.raise_amount(1), 250); // 팟리밋은 올인도 팟 크기로 제한
+
+        let next = State::next_state(&state, Act::Raise(0));
+        assert_eq!((next.invested[1], next.pot, next.to_call), (250, 400, 250));
+
+        // 스택이 부족하면 올인까지만
+        state.stack[1] = 180;
+        assert_eq!(state.raise_amount(0), 180);
+
+        // 같은 상황의 노리밋 팟 레이즈는 콜 전 팟만큼만 추가
+        state.stack[1] = 5000;
+        state.betting = BettingRules::NoLimit;
+        assert_eq!(state.raise_amount(0), 200);
+        assert_eq!(state.raise_amount(1), 5000);
+    }
+
+    #[test]
+    fn test_fixed_limit_caps_raises_per_street() {
+        let mut state = river_state();
+        state.street = 1;
+        state.board.truncate(3);
+        state.pot = 200;
+        state.stack = [5000; 6];
+        state.betting = BettingRules::FixedLimit {
+            small_bet: 100,
+            big_bet: 200,
+            max_raises: 3,
+        };
+
+        let mut raises = 0;
+        while State::legal_actions(&state).contains(&Act::Raise(0)) {
+            let before = state.invested[state.to_act];
+            let to_call = state.to_call;
+            state = State::next_state(&state, Act::Raise(0));
+            let player = (0..6).find(|&p| state.invested[p] == state.to_call).unwrap();
+            assert_eq!(state.invested[player] - before, to_call - before + 100);
+            raises += 1;
+        }
+        assert_eq!(raises, 3);
+        assert_eq!(State::legal_actions(&state), vec![Act::Fold, Act::Call]);
+
+        // 다음 스트리트는 횟수가 초기화되고 빅 베팅 단위
+        let turn = State::apply_chance(&State::next_state(&state, Act::Call), &mut rand::thread_rng());
+        assert_eq!((turn.street, turn.raises_this_street), (2, 0));
+        assert_eq!(turn.raise_amount(0), 200);
+    }
+
+    #[test]
+    fn test_info_key_depends_on_betting_rules() {
+        let state = State::new_hand([25, 50], [1000; 6], 2);
+        let mut pot_limit = state.clone();
+        pot_limit.betting = BettingRules::PotLimit;
+        let mut fixed_limit = state.clone();
+        fixed_limit.betting = BettingRules::FixedLimit {
+            small_bet: 50,
+            big_bet: 100,
+            max_raises: 4,
+        };
+        let keys = [&state, &pot_limit, &fixed_limit].map(|s| State::info_key(s, 0));
+        assert_ne!(keys[0], keys[1]);
+        assert_ne!(keys[0], keys[2]);
+        assert_ne!(keys[1], keys[2]);
+    }
 
     #[test]
     fn test_game_state_creation() {
@@ -585,4 +1922,98 @@ mod tests {
 
         println!("정보 집합 키 생성 테스트 통과");
     }
+
+    #[test]
+    fn test_raise_bounds_after_three_bet() {
+        let mut state = State::new_hand([50, 100], [10_000; 6], 6);
+        // UTG 3배 오픈(300, 증가분 200) 후 1번 좌석 9배 3벳(900, 증가분 600)
+        state.invested[0] = 300;
+        state.stack[0] -= 300;
+        state.invested[1] = 900;
+        state.stack[1] -= 900;
+        state.pot += 1200;
+        state.to_call = 900;
+        state.last_raise = 600;
+        state.raises_this_street = 2;
+        state.to_act = 2;
+
+        let bounds = state.raise_bounds().expect("레이즈 가능");
+        assert_eq!(bounds.min_raise_to, 1500); // 15배
+        assert_eq!(bounds.max_raise_to, state.stack[2]);
+        assert_eq!(bounds.all_in, state.stack[2]);
+        assert!(bounds.suggested.iter().all(|&to| (1500..=10_000).contains(&to)));
+
+        // 콜 금액만큼만 남은 플레이어는 레이즈할 수 없음
+        state.stack[2] = 900;
+        assert_eq!(state.raise_bounds(), None);
+    }
+
+    #[test]
+    fn test_short_all_in_does_not_reopen_min_raise() {
+        let state = State::new_hand([50, 100], [10_000, 300, 0, 0, 0, 0], 2);
+        // SB 팟 레이즈: 250까지 (증가분 150)
+        let raised = State::next_state(&state, Act::Raise(0));
+        assert_eq!(raised.last_raise, 150);
+        assert_eq!(raised.min_raise_size(), 400);
+
+        // BB 300 올인은 증가분 50으로 최소 레이즈 미만이라 기준 유지
+        let shoved = State::next_state(&raised, Act::Raise(1));
+        assert_eq!((shoved.to_call, shoved.last_raise), (300, 150));
+    }
+
+    #[test]
+    fn test_all_in_seats_are_skipped_when_choosing_actor() {
+        // 3명: SB(좌석 1)가 블라인드로 올인 - UTG 콜 다음 차례는 SB를 건너뛰고 BB
+        let state = State::new_hand([50, 100], [1_000, 30, 1_000, 0, 0, 0], 3);
+        assert!(state.is_all_in(1));
+        let called = State::next_state(&state, Act::Call);
+        assert_eq!(called.to_act, 2);
+        assert!(!State::legal_actions(&called).is_empty());
+        // UTG가 폴드하면 BB는 맞출 금액도 베팅할 상대도 없음
+        assert!(State::next_state(&state, Act::Fold).is_chance_node());
+
+        // 헤즈업 SB가 앤티로 올인: 남은 BB는 맞출 금액이 없으므로 베팅 없이 보드만 딜
+        let ante_all_in = State::new_hand_with_antes([15, 15], 16, AnteType::PerPlayer, [1, 32, 0, 0, 0, 0], 2);
+        assert!(ante_all_in.is_chance_node());
+        assert_eq!(State::current_player(&ante_all_in), None);
+
+        // 올인한 플레이어가 있는 포스트플랍은 남은 두 명이 한 번씩 액션하면 끝남
+        let mut state = State::new_hand([50, 100], [1_000, 300, 1_000, 0, 0, 0], 3);
+        state = State::next_state(&state, Act::Call); // UTG(좌석 0) 콜
+        state = State::next_state(&state, Act::Raise(1)); // SB 올인 300
+        state = State::next_state(&state, Act::Call); // BB 콜
+        state = State::next_state(&state, Act::Call); // UTG 콜
+        let flop = State::apply_chance(&state, &mut rand::thread_rng());
+        assert_eq!(flop.street, 1);
+        let checked = State::next_state(&flop, Act::Call);
+        let checked = State::next_state(&checked, Act::Call);
+        assert!(checked.is_chance_node(), "{:?}", State::current_player(&checked));
+    }
+
+    #[test]
+    fn test_chance_enumeration_only_from_turn() {
+        use crate::solver::cfr_core::{ChanceHandling, TrainingError};
+
+        // 턴에서 체크-체크: 리버 44장 (덱 52 - 홀카드 4 - 보드 4)
+        let mut turn = river_state();
+        turn.board.truncate(4);
+        turn.street = 2;
+        let checked = State::next_state(&State::next_state(&turn, Act::Call), Act::Call);
+        let outcomes = State::chance_outcomes(&checked).expect("리버 딜링은 열거 가능");
+        assert_eq!(outcomes.len(), 44);
+        assert!((outcomes.iter().map(|(_, p)| p).sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(outcomes.iter().all(|(river, _)| river.street == 3 && river.board.len() == 5));
+
+        let mut trainer = Trainer::<State>::new();
+        trainer.set_chance_handling(ChanceHandling::Enumerate);
+        assert_eq!(trainer.try_run(vec![turn], 2), Ok(()));
+        assert!(!trainer.nodes.is_empty());
+
+        // 프리플랍 루트는 플랍 딜링을 열거할 수 없어 명확한 에러로 거부
+        let mut trainer = Trainer::<State>::new();
+        trainer.set_chance_handling(ChanceHandling::Enumerate);
+        let error = trainer.try_run(vec![State::new_hand([50, 100], [1000; 6], 2)], 3).unwrap_err();
+        assert_eq!(error, TrainingError::ChanceNotEnumerable { iterations_completed: 0 });
+        assert!(error.to_string().contains("ChanceHandling::Sample"), "{}", error);
+    }
 }
@@ -0,0 +1,568 @@
+// 르둑 홀덤 (Leduc Hold'em) 게임 구현
+// CFR 코어의 정답 검증용 소형 벤치마크 게임
+//
+// 규칙 (표준 르둑 홀덤):
+// - 덱: J, Q, K 각 2장씩 총 6장
+// - 두 플레이어가 1칩씩 앤티, 각자 1장의 개인 카드를 받음
+// - 1라운드 베팅 (고정 베팅 2) → 보드 카드 1장 공개 → 2라운드 베팅 (고정 베팅 4)
+// - 라운드마다 최대 2번의 베팅/레이즈, 매 라운드 0번 플레이어가 먼저 액션
+// - 쇼다운: 보드와 페어를 이룬 플레이어 승리, 아니면 높은 카드 승리, 같으면 팟 분할
+//
+// 전체 트리가 작아 찬스 노드까지 정확히 열거할 수 있으므로
+// 평균 전략의 게임 가치와 익스플로이터빌리티(exploitability)를 정확히 계산합니다.
+// 알려진 게임 가치: 0번 플레이어 기준 약 -0.0856 칩
+
+use crate::solver::cfr_core::{Game, GameState, InfoKeyFields, Trainer};
+use crate::solver::purification::Purification;
+use rand::Rng;
+use std::collections::BTreeMap;
+
+/// 덱 크기 (J, Q, K 각 2장)
+pub const LEDUC_DECK_SIZE: u8 = 6;
+
+/// 알려진 0번 플레이어의 게임 가치 (앤티 1 기준)
+pub const LEDUC_GAME_VALUE: f64 = -0.0856;
+
+/// 라운드별 고정 베팅 크기
+const BET_SIZES: [u32; 2] = [2, 4];
+
+/// 라운드당 최대 베팅/레이즈 횟수
+const MAX_RAISES: u8 = 2;
+
+/// 카드 인덱스(0..6)를 랭크(0=J, 1=Q, 2=K)로 변환
+pub fn leduc_rank(card: u8) -> u8 {
+    card / 2
+}
+
+/// 르둑 홀덤 액션
+#[derive(Copy, Clone, Eq, Hash, PartialEq, Debug)]
+pub enum LeducAction {
+    /// 폴드
+    Fold,
+    /// 체크 또는 콜
+    Call,
+    /// 베팅 또는 레이즈 (라운드별 고정 크기)
+    Raise,
+}
+
+/// 르둑 홀덤 게임 상태
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeducState {
+    /// 플레이어별 개인 카드 (카드 인덱스 0..6, 딜 전에는 None)
+    pub hole: [Option<u8>; 2],
+    /// 보드 카드 (2라운드 시작 시 공개)
+    pub board: Option<u8>,
+    /// 현재 라운드 (0, 1 = 베팅 라운드, 2 = 쇼다운)
+    pub round: u8,
+    /// 액션할 플레이어
+    pub to_act: usize,
+    /// 플레이어별 팟 기여액 (앤티 포함)
+    pub contributed: [u32; 2],
+    /// 현재 라운드의 베팅/레이즈 횟수
+    pub raises: u8,
+    /// 현재 라운드의 액션 수
+    pub round_actions: u8,
+    /// 폴드한 플레이어
+    pub folded: Option<usize>,
+    /// 전체 액션 히스토리 (3진수 인코딩, 정보 집합 키에 사용)
+    pub history: u32,
+}
+
+impl LeducState {
+    /// 카드 딜 전의 새 핸드 (양쪽 앤티 1)
+    pub fn new() -> Self {
+        Self {
+            hole: [None; 2],
+            board: None,
+            round: 0,
+            to_act: 0,
+            contributed: [1, 1],
+            raises: 0,
+            round_actions: 0,
+            folded: None,
+            history: 0,
+        }
+    }
+
+    /// 지정한 개인 카드로 시작하는 핸드
+    pub fn with_cards(p0: u8, p1: u8) -> Self {
+        Self {
+            hole: [Some(p0), Some(p1)],
+            ..Self::new()
+        }
+    }
+
+    /// 현재 플레이어가 콜하려면 넣어야 하는 금액
+    pub fn to_call(&self) -> u32 {
+        self.contributed[1 - self.to_act].saturating_sub(self.contributed[self.to_act])
+    }
+
+    /// 지정한 카드들을 제외하고 남은 카드 목록
+    fn remaining_cards(&self) -> Vec<u8> {
+        (0..LEDUC_DECK_SIZE)
+            .filter(|&card| !self.hole.contains(&Some(card)) && self.board != Some(card))
+            .collect()
+    }
+
+    /// 쇼다운 핸드 강도 (페어는 랭크보다 항상 높음)
+    fn showdown_strength(&self, player: usize) -> u8 {
+        let rank = leduc_rank(self.hole[player].expect("쇼다운에는 카드가 필요"));
+        match self.board {
+            Some(board) if leduc_rank(board) == rank => 10 + rank,
+            _ => rank,
+        }
+    }
+}
+
+impl Default for LeducState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameState for LeducState {
+    fn is_terminal(&self) -> bool {
+        self.folded.is_some() || self.round >= 2
+    }
+
+    fn is_chance_node(&self) -> bool {
+        if self.is_terminal() {
+            return false;
+        }
+        self.hole.contains(&None) || (self.round == 1 && self.board.is_none())
+    }
+}
+
+impl Game for LeducState {
+    type State = LeducState;
+    type Action = LeducAction;
+    type InfoKey = u64;
+
+    const N_PLAYERS: usize = 2;
+
+    fn current_player(s: &Self::State) -> Option<usize> {
+        if s.is_terminal() || s.is_chance_node() {
+            None
+        } else {
+            Some(s.to_act)
+        }
+    }
+
+    fn legal_actions(s: &Self::State) -> Vec<Self::Action> {
+        if Self::current_player(s).is_none() {
+            return vec![];
+        }
+
+        let mut actions = Vec::with_capacity(3);
+        if s.to_call() > 0 {
+            actions.push(LeducAction::Fold);
+        }
+        actions.push(LeducAction::Call);
+        if s.raises < MAX_RAISES {
+            actions.push(LeducAction::Raise);
+        }
+        actions
+    }
+
+    fn next_state(s: &Self::State, a: Self::Action) -> Self::State {
+        let mut next = s.clone();
+        let player = s.to_act;
+        next.history = s.history * 3 + a as u32 + 1;
+        next.round_actions += 1;
+
+        match a {
+            LeducAction::Fold => {
+                next.folded = Some(player);
+                return next;
+            }
+            LeducAction::Call => {
+                next.contributed[player] += s.to_call();
+                // 라운드 첫 체크가 아니면 베팅 라운드 종료
+                if s.round_actions > 0 {
+                    next.round += 1;
+                    next.to_act = 0;
+                    next.raises = 0;
+                    next.round_actions = 0;
+                    return next;
+                }
+            }
+            LeducAction::Raise => {
+                next.contributed[player] += s.to_call() + BET_SIZES[s.round as usize];
+                next.raises += 1;
+            }
+        }
+
+        next.to_act = 1 - player;
+        next
+    }
+
+    fn apply_chance<R: Rng>(s: &Self::State, r: &mut R) -> Self::State {
+        let mut next = s.clone();
+        for seat in 0..2 {
+            if next.hole[seat].is_none() {
+                let remaining = next.remaining_cards();
+                next.hole[seat] = Some(remaining[r.gen_range(0..remaining.len())]);
+            }
+        }
+        if next.round == 1 && next.board.is_none() {
+            let remaining = next.remaining_cards();
+            next.board = Some(remaining[r.gen_range(0..remaining.len())]);
+        }
+        next
+    }
+
+    /// 비어 있는 개인 카드(좌석 순서)와 보드 카드를 남은 카드로 모두 딜한 결과 (각 카드 같은 확률)
+    fn chance_outcomes(s: &Self::State) -> Option<Vec<(Self::State, f64)>> {
+        let deal = |outcomes: Vec<(LeducState, f64)>, place: &dyn Fn(&mut LeducState, u8)| {
+            outcomes
+                .into_iter()
+                .flat_map(|(state, probability)| {
+                    let remaining = state.remaining_cards();
+                    let weight = probability / remaining.len() as f64;
+                    remaining.into_iter().map(move |card| {
+                        let mut next = state.clone();
+                        place(&mut next, card);
+                        (next, weight)
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut outcomes = vec![(s.clone(), 1.0)];
+        for seat in 0..2 {
+            if s.hole[seat].is_none() {
+                outcomes = deal(outcomes, &|state, card| state.hole[seat] = Some(card));
+            }
+        }
+        if s.round == 1 && s.board.is_none() {
+            outcomes = deal(outcomes, &|state, card| state.board = Some(card));
+        }
+        Some(outcomes)
+    }
+
+    fn util(s: &Self::State, hero: usize) -> f64 {
+        let villain = 1 - hero;
+        let winner = match s.folded {
+            Some(folder) => Some(1 - folder),
+            None => {
+                let (hero_strength, villain_strength) =
+                    (s.showdown_strength(hero), s.showdown_strength(villain));
+                match hero_strength.cmp(&villain_strength) {
+                    std::cmp::Ordering::Greater => Some(hero),
+                    std::cmp::Ordering::Less => Some(villain),
+                    std::cmp::Ordering::Equal => None,
+                }
+            }
+        };
+
+        match winner {
+            Some(w) if w == hero => s.contributed[villain] as f64,
+            Some(_) => -(s.contributed[hero] as f64),
+            None => 0.0,
+        }
+    }
+
+    /// 키 구성: [히스토리 | 보드 랭크+1 (4비트) | 개인 카드 랭크 (4비트)]
+    fn info_key(s: &Self::State, v: usize) -> Self::InfoKey {
+        let rank = s.hole[v].map_or(0xF, leduc_rank) as u64;
+        let board = s.board.map_or(0, |card| leduc_rank(card) + 1) as u64;
+        ((s.history as u64) << 8) | (board << 4) | rank
+    }
+
+    fn describe_info_key(key: &u64) -> Option<InfoKeyFields> {
+        let board = (key >> 4) & 0xF;
+        let mut fields = BTreeMap::from([("hole_rank", key & 0xF), ("history", key >> 8)]);
+        if board > 0 {
+            fields.insert("board_rank", board - 1);
+        }
+        Some(InfoKeyFields {
+            street: Some((board > 0) as u8),
+            fields,
+        })
+    }
+}
+
+/// 정보 집합의 평균 전략 (학습되지 않은 정보 집합은 균일 분포)
+fn average_strategy(trainer: &Trainer<LeducState>, state: &LeducState, n_actions: usize) -> Vec<f64> {
+    let player = state.to_act;
+    trainer
+        .nodes
+        .get(&LeducState::info_key(state, player))
+        .map(|node| node.average())
+        .filter(|average| average.len() == n_actions)
+        .unwrap_or_else(|| vec![1.0 / n_actions as f64; n_actions])
+}
+
+/// 모든 개인 카드 딜 (각 조합 확률 1/30)
+fn all_deals() -> impl Iterator<Item = (u8, u8)> {
+    (0..LEDUC_DECK_SIZE).flat_map(|p0| (0..LEDUC_DECK_SIZE).filter(move |&p1| p1 != p0).map(move |p1| (p0, p1)))
+}
+
+/// 양쪽이 평균 전략을 따를 때 0번 플레이어의 기댓값 (찬스 노드 정확히 열거)
+pub fn average_strategy_value(trainer: &Trainer<LeducState>) -> f64 {
+    purified_strategy_value(trainer, [Purification::None; 2])
+}
+
+/// 좌석별로 정제한 평균 전략끼리 맞붙을 때 0번 플레이어의 기댓값 (찬스 노드 정확히 열거)
+pub fn purified_strategy_value(trainer: &Trainer<LeducState>, purification: [Purification; 2]) -> f64 {
+    let deals = all_deals().count() as f64;
+    all_deals()
+        .map(|(p0, p1)| expected_value(trainer, &LeducState::with_cards(p0, p1), &purification))
+        .sum::<f64>()
+        / deals
+}
+
+/// 정제한 전략이 원래 평균 전략을 상대로 잃는 기댓값 (칩/핸드, 두 좌석 평균)
+///
+/// 원래 전략끼리의 셀프 플레이와 비교하므로 정제 비용을 가정하지 않고 측정합니다.
+/// 상대는 고정 전략이라 정제가 상대를 우연히 더 잘 공략하면 음수가 될 수 있습니다.
+pub fn purification_cost(trainer: &Trainer<LeducState>, purification: Purification) -> f64 {
+    let raw = average_strategy_value(trainer);
+    let as_first = raw - purified_strategy_value(trainer, [purification, Purification::None]);
+    let as_second = purified_strategy_value(trainer, [Purification::None, purification]) - raw;
+    (as_first + as_second) / 2.0
+}
+
+fn expected_value(trainer: &Trainer<LeducState>, state: &LeducState, purification: &[Purification; 2]) -> f64 {
+    if state.is_terminal() {
+        return LeducState::util(state, 0);
+    }
+    if state.is_chance_node() {
+        let boards = state.remaining_cards();
+        let weight = 1.0 / boards.len() as f64;
+        return boards
+            .into_iter()
+            .map(|board| {
+                let next = LeducState {
+                    board: Some(board),
+                    ..state.clone()
+                };
+                weight * expected_value(trainer, &next, purification)
+            })
+            .sum();
+    }
+
+    let actions = LeducState::legal_actions(state);
+    let strategy = purification[state.to_act].apply(&average_strategy(trainer, state, actions.len()));
+    actions
+        .iter()
+        .zip(strategy)
+        .map(|(&action, p)| p * expected_value(trainer, &LeducState::next_state(state, action), purification))
+        .sum()
+}
+
+/// 평균 전략에 대한 최적 대응(best response) 기댓값
+///
+/// `player`가 상대의 평균 전략을 알고 정보 집합마다 최선의 액션을 고를 때의 기댓값입니다.
+pub fn best_response_value(trainer: &Trainer<LeducState>, player: usize) -> f64 {
+    purified_best_response_value(trainer, player, Purification::None)
+}
+
+/// 상대가 정제한 평균 전략을 쓸 때의 최적 대응 기댓값
+pub fn purified_best_response_value(trainer: &Trainer<LeducState>, player: usize, purification: Purification) -> f64 {
+    let opponent = 1 - player;
+    let mut total = 0.0;
+    for card in 0..LEDUC_DECK_SIZE {
+        // 상대 카드별 도달 가중치 (찬스 확률 1/30 포함)
+        let mut weights = [0.0; LEDUC_DECK_SIZE as usize];
+        for (other, weight) in weights.iter_mut().enumerate() {
+            if other as u8 != card {
+                *weight = 1.0 / 30.0;
+            }
+        }
+        let mut state = LeducState::new();
+        state.hole[player] = Some(card);
+        state.hole[opponent] = Some(if card == 0 { 1 } else { 0 });
+        total += best_response(trainer, &state, player, &weights, purification);
+    }
+    total
+}
+
+/// 상대 카드 분포 가중치를 들고 공개 트리를 따라가는 최적 대응 재귀
+fn best_response(
+    trainer: &Trainer<LeducState>,
+    state: &LeducState,
+    player: usize,
+    weights: &[f64; LEDUC_DECK_SIZE as usize],
+    purification: Purification,
+) -> f64 {
+    let opponent = 1 - player;
+    let with_opponent_card = |card: u8| {
+        let mut s = state.clone();
+        s.hole[opponent] = Some(card);
+        s
+    };
+
+    if state.is_terminal() {
+        return weights
+            .iter()
+            .enumerate()
+            .filter(|(_, &w)| w > 0.0)
+            .map(|(card, &w)| w * LeducState::util(&with_opponent_card(card as u8), player))
+            .sum();
+    }
+
+    if state.is_chance_node() {
+        let own = state.hole[player].expect("최적 대응 계산에는 카드가 필요");
+        // 보드는 두 개인 카드를 제외한 4장 중 하나
+        return (0..LEDUC_DECK_SIZE)
+            .filter(|&board| board != own)
+            .map(|board| {
+                let mut next_weights = *weights;
+                next_weights[board as usize] = 0.0;
+                next_weights.iter_mut().for_each(|w| *w /= 4.0);
+                let next = LeducState {
+                    board: Some(board),
+                    ..state.clone()
+                };
+                best_response(trainer, &next, player, &next_weights, purification)
+            })
+            .sum();
+    }
+
+    let actions = LeducState::legal_actions(state);
+    if state.to_act == player {
+        return actions
+            .iter()
+            .map(|&action| best_response(trainer, &LeducState::next_state(state, action), player, weights, purification))
+            .fold(f64::NEG_INFINITY, f64::max);
+    }
+
+    // 상대 노드: 상대 카드별 평균 전략으로 가중치를 나눠 각 액션으로 전파
+    let strategies: Vec<Option<Vec<f64>>> = (0..LEDUC_DECK_SIZE)
+        .map(|card| {
+            (weights[card as usize] > 0.0)
+                .then(|| purification.apply(&average_strategy(trainer, &with_opponent_card(card), actions.len())))
+        })
+        .collect();
+    actions
+        .iter()
+        .enumerate()
+        .map(|(i, &action)| {
+            let mut next_weights = *weights;
+            for (card, weight) in next_weights.iter_mut().enumerate() {
+                *weight *= strategies[card].as_ref().map_or(0.0, |s| s[i]);
+            }
+            best_response(trainer, &LeducState::next_state(state, action), player, &next_weights, purification)
+        })
+        .sum()
+}
+
+/// 평균 전략의 익스플로이터빌리티 (두 플레이어 최적 대응 이득의 평균, 칩 단위)
+///
+/// 내쉬 균형에서 0이며, 학습이 진행될수록 0에 가까워져야 합니다.
+pub fn exploitability(trainer: &Trainer<LeducState>) -> f64 {
+    purified_exploitability(trainer, Purification::None)
+}
+
+/// 정제한 평균 전략의 익스플로이터빌리티 (칩 단위)
+pub fn purified_exploitability(trainer: &Trainer<LeducState>, purification: Purification) -> f64 {
+    (purified_best_response_value(trainer, 0, purification) + purified_best_response_value(trainer, 1, purification)) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_betting_rules() {
+        let root = LeducState::with_cards(0, 4);
+        assert_eq!(LeducState::legal_actions(&root), vec![LeducAction::Call, LeducAction::Raise]);
+
+        // 베팅 - 레이즈 - 리레이즈 불가
+        let bet = LeducState::next_state(&root, LeducAction::Raise);
+        let raise = LeducState::next_state(&bet, LeducAction::Raise);
+        assert_eq!(raise.contributed, [3, 5]);
+        assert_eq!(
+            LeducState::legal_actions(&raise),
+            vec![LeducAction::Fold, LeducAction::Call]
+        );
+
+        // 콜하면 보드 딜 찬스 노드, 2라운드 베팅은 4
+        let call = LeducState::next_state(&raise, LeducAction::Call);
+        assert!(call.is_chance_node());
+        assert_eq!((call.round, call.to_act, call.contributed), (1, 0, [5, 5]));
+        let mut flop = call.clone();
+        flop.board = Some(5);
+        let bet = LeducState::next_state(&flop, LeducAction::Raise);
+        assert_eq!(bet.contributed, [9, 5]);
+
+        // 체크-체크는 쇼다운, K 페어가 J 하이를 이김
+        let showdown = LeducState::next_state(&LeducState::next_state(&flop, LeducAction::Call), LeducAction::Call);
+        assert!(showdown.is_terminal());
+        assert_eq!(LeducState::util(&showdown, 1), 5.0);
+        assert_eq!(LeducState::util(&showdown, 0), -5.0);
+    }
+
+    #[test]
+    fn test_uniform_strategy_is_exploitable() {
+        // 학습 전 트레이너는 모든 정보 집합에서 균일 분포
+        let trainer = Trainer::<LeducState>::new();
+        assert!(average_strategy_value(&trainer).is_finite());
+        // 균일 전략의 익스플로이터빌리티 (NashConv 4.7472의 절반)
+        assert!((exploitability(&trainer) - 2.3736).abs() < 1e-3, "{}", exploitability(&trainer));
+    }
+
+    #[test]
+    fn test_cfr_converges_to_known_game_value() {
+        // 찬스 샘플링이라 결과가 실행마다 조금씩 다름 - 여유 있는 기준 사용
+        // (리그렛/전략 합계를 결합 도달 확률로 가중하던 이전 구현은 -0.115 / 0.19 부근에 머묾)
+        let mut trainer = Trainer::<LeducState>::new();
+        trainer.run(vec![LeducState::new()], 20_000);
+
+        let value = average_strategy_value(&trainer);
+        let exploit = exploitability(&trainer);
+        assert!(
+            (value - LEDUC_GAME_VALUE).abs() < 0.03,
+            "게임 가치 {:.4}가 {}에 수렴해야 함",
+            value,
+            LEDUC_GAME_VALUE
+        );
+        assert!(exploit < 0.12, "익스플로이터빌리티 {:.4}", exploit);
+    }
+
+    #[test]
+    fn test_exploitability_decreases_with_training() {
+        let mut trainer = Trainer::<LeducState>::new();
+        let uniform = exploitability(&trainer);
+        trainer.run(vec![LeducState::new()], 500);
+        let early = exploitability(&trainer);
+        trainer.run(vec![LeducState::new()], 4_500);
+        let later = exploitability(&trainer);
+
+        assert!(early < uniform && later < early, "{} -> {} -> {}", uniform, early, later);
+        // 익스플로이터빌리티는 최적 대응 이득이므로 음수가 될 수 없음
+        assert!(later >= -1e-9);
+    }
+
+    #[test]
+    fn test_purification_cost_is_measured_against_raw_strategy() {
+        let mut trainer = Trainer::<LeducState>::new();
+        trainer.run(vec![LeducState::new()], 5_000);
+        assert_eq!(purification_cost(&trainer, Purification::None), 0.0);
+
+        // 앤티 1칩을 빅블라인드로 보고 bb/100으로 환산
+        let bb_per_100 = |chips: f64| chips * 100.0;
+        let raw = exploitability(&trainer);
+        let threshold = Purification::Threshold(0.05);
+        let threshold_cost = bb_per_100(purification_cost(&trainer, threshold));
+        let threshold_exploit = purified_exploitability(&trainer, threshold);
+        let argmax_cost = bb_per_100(purification_cost(&trainer, Purification::Argmax));
+        let argmax_exploit = purified_exploitability(&trainer, Purification::Argmax);
+        println!(
+            "threshold(0.05): {:.2}bb/100, 익스플로이터빌리티 {:.4}; argmax: {:.2}bb/100, {:.4} (원래 {:.4})",
+            threshold_cost, threshold_exploit, argmax_cost, argmax_exploit, raw
+        );
+
+        // 원래 전략을 상대로 한 셀프 플레이 손익은 작은 범위 안 (고정 상대라 정제가 이득일 수도 있음)
+        assert!(threshold_cost.abs() < 5.0, "threshold 셀프 플레이 {:.2}bb/100", threshold_cost);
+        // 최악의 상대 기준으로는 threshold는 거의 그대로, argmax는 크게 악화
+        assert!(threshold_exploit < raw + 0.05, "{:.4} -> {:.4}", raw, threshold_exploit);
+        assert!(
+            argmax_exploit > threshold_exploit + 0.1,
+            "argmax {:.4} vs threshold {:.4}",
+            argmax_exploit,
+            threshold_exploit
+        );
+    }
+}
@@ -4,17 +4,50 @@
 //! - 핸드 평가 시스템
 //! - 카드 추상화 및 버킷팅 알고리즘
 //! - 텍사스 홀덤 게임 상태 관리
+//! - 베팅 사이징 수학 (geometric sizing)
 //! - 토너먼트 시스템 지원
+//! - CFR 검증용 르둑 홀덤 벤치마크 게임
+//! - 기댓값 단위 (칩/BB/상금/에퀴티) 변환
+//! - 올인 쇼다운 판정 (보드 열거/몬테카를로, 런 잇 N 타임즈)
+//! - 플레이어 관점 상태와 정책 인터페이스 (상대 홀카드 마스킹)
+//! - 핸드 기록 기반 세션 통계 (VPIP, PFR, 3벳, 컨티뉴에이션 벳, WTSD 등)
+//! - 부분 정보 상태의 상대 홀카드 재딜링 (베팅 라인 기반 레인지 가중치)
+//! - 추상 액션과 칩 금액이 명시된 구체적인 액션 변환
+//! - 숏덱 (6+) 홀덤 덱과 족보
+//! - 캐노니컬 플랍 부분집합 학습
+//! - 시드를 받지 않는 곳의 난수 시드 (thread_rng 없이)
 
+pub mod all_in; // 올인 쇼다운 에퀴티 판정
 pub mod card_abstraction; // 카드 추상화 및 핸드 분류
+pub mod concrete_action; // 추상 액션과 칩 단위 액션 변환
+pub mod flop_subset; // 캐노니컬 플랍 부분집합 딜링
 pub mod hand_eval; // 핸드 강도 평가 엔진
 pub mod holdem; // 텍사스 홀덤 게임 로직
+pub mod leduc; // 르둑 홀덤 (CFR 정답 검증용)
+pub mod observation; // 플레이어 관점 상태와 정책
+pub mod opponent_deal; // 모르는 상대 홀카드 재딜링
+pub mod poker_math; // SPR 기반 베팅 사이징 계산
+pub mod rng; // 엔트로피 시드 (wasm32 호환)
+pub mod session_stats; // 핸드 기록 기반 세션 통계
+pub mod short_deck; // 숏덱 (6+) 홀덤
 pub mod tournament; // 토너먼트 지원 모듈
 pub mod tournament_holdem; // CFR 통합 토너먼트 홀덤
+pub mod value_unit; // 기댓값 단위 및 변환
 
 // 자주 사용되는 타입들을 재내보내기
+pub use all_in::*;
 pub use card_abstraction::*;
+pub use concrete_action::*;
+pub use flop_subset::*;
 pub use hand_eval::*;
 pub use holdem::*;
+pub use leduc::*;
+pub use observation::*;
+pub use opponent_deal::*;
+pub use poker_math::*;
+pub use rng::*;
+pub use session_stats::*;
+pub use short_deck::*;
 pub use tournament::*;
 pub use tournament_holdem::*;
+pub use value_unit::*;
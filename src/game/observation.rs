@@ -0,0 +1,359 @@
+// 플레이어 관점 상태 모듈
+// 정책/서빙 인터페이스가 상대 홀카드를 볼 수 없도록 히어로에게 보이는 정보만 담은 뷰
+
+use crate::game::holdem::{Act, BettingRules, HoldemVariant, RakeModel, State};
+use crate::game::session_stats::{HandResult, PlayerStats, SessionTracker};
+use crate::solver::cfr_core::{Game, GameState, Trainer};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// 한 플레이어가 볼 수 있는 게임 상태
+///
+/// `holdem::State`와 달리 상대 홀카드가 없습니다. 정책, EV 계산기의 상대 모델,
+/// 전략 서빙처럼 한 플레이어의 결정을 흉내 내는 코드는 이 타입만 받아야 합니다.
+/// CFR 학습기의 내부 탐색은 계속 전체 상태를 사용합니다.
+///
+/// 현재 스트리트 이전의 액션 순서는 `State`에도 남지 않으므로 포함되지 않습니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ObservedState {
+    /// 관찰하는 플레이어 좌석
+    pub player: usize,
+    /// 관찰자 본인의 홀카드
+    pub hole: [u8; 2],
+    /// 보드카드
+    pub board: Vec<u8>,
+    /// 현재 스트리트 (0=프리플랍, 1=플랍, 2=턴, 3=리버)
+    pub street: u8,
+    /// 현재 팟 크기
+    pub pot: u32,
+    /// 각 플레이어의 스택 크기
+    pub stack: [u32; 6],
+    /// 살아있는 플레이어 여부
+    pub alive: [bool; 6],
+    /// 현재 스트리트에서 각 플레이어가 투자한 금액
+    pub invested: [u32; 6],
+    /// 각 플레이어가 포스트한 앤티
+    #[serde(default)]
+    pub antes: [u32; 6],
+    /// 콜하기 위해 필요한 금액
+    pub to_call: u32,
+    /// 현재 액션할 플레이어
+    pub to_act: usize,
+    /// 현재 스트리트에서 수행된 액션 수
+    pub actions_taken: usize,
+    /// 딜러 버튼 좌석
+    pub button: usize,
+    /// 캐시 게임 레이크
+    pub rake: Option<RakeModel>,
+    /// 덱과 족보 규칙
+    #[serde(default)]
+    pub variant: HoldemVariant,
+    /// 베팅 한도 규칙
+    #[serde(default)]
+    pub betting: BettingRules,
+    /// 현재 스트리트에서 나온 베팅/레이즈 수
+    #[serde(default)]
+    pub raises_this_street: u8,
+    /// 빅블라인드 (0이면 알 수 없음)
+    #[serde(default)]
+    pub big_blind: u32,
+    /// 현재 스트리트의 마지막 완전한 베팅/레이즈 증가분
+    #[serde(default)]
+    pub last_raise: u32,
+    /// 이번 핸드의 라이브 스트래들 금액 (0이면 스트래들 없음)
+    #[serde(default)]
+    pub straddle: u32,
+}
+
+impl State {
+    /// `player` 관점의 상태 (상대 홀카드 제거)
+    pub fn observe(&self, player: usize) -> ObservedState {
+        ObservedState {
+            player,
+            hole: self.hole[player.min(5)],
+            board: self.board.clone(),
+            street: self.street,
+            pot: self.pot,
+            stack: self.stack,
+            alive: self.alive,
+            invested: self.invested,
+            antes: self.antes,
+            to_call: self.to_call,
+            to_act: self.to_act,
+            actions_taken: self.actions_taken,
+            button: self.button,
+            rake: self.rake,
+            variant: self.variant,
+            betting: self.betting,
+            raises_this_street: self.raises_this_street,
+            big_blind: self.big_blind,
+            last_raise: self.last_raise,
+            straddle: self.straddle,
+        }
+    }
+}
+
+impl ObservedState {
+    /// 상대 홀카드를 0으로 채운 마스킹 상태
+    ///
+    /// 액션 규칙과 정보 집합 키는 관찰자 본인의 카드와 공개 정보만 쓰므로 그대로 계산됩니다.
+    /// 쇼다운 유틸리티처럼 상대 카드가 필요한 계산에는 사용하면 안 됩니다.
+    pub fn to_state(&self) -> State {
+        let mut hole = [[0; 2]; 6];
+        hole[self.player.min(5)] = self.hole;
+        State {
+            hole,
+            board: self.board.clone(),
+            to_act: self.to_act,
+            street: self.street,
+            pot: self.pot,
+            stack: self.stack,
+            alive: self.alive,
+            invested: self.invested,
+            antes: self.antes,
+            to_call: self.to_call,
+            actions_taken: self.actions_taken,
+            button: self.button,
+            rake: self.rake,
+            variant: self.variant,
+            flop_sampler: None,
+            betting: self.betting,
+            raises_this_street: self.raises_this_street,
+            big_blind: self.big_blind,
+            last_raise: self.last_raise,
+            straddle: self.straddle,
+            opponent_deal: None,
+        }
+    }
+
+    /// 현재 가능한 액션들
+    pub fn legal_actions(&self) -> Vec<Act> {
+        State::legal_actions(&self.to_state())
+    }
+
+    /// 관찰자의 정보 집합 키 (학습된 전략 조회용)
+    pub fn info_key(&self) -> u64 {
+        State::info_key(&self.to_state(), self.player)
+    }
+}
+
+/// 플레이어 관점 상태만 보고 액션을 고르는 정책
+pub trait ActionPolicy {
+    /// `legal_actions` 중 하나를 선택
+    fn choose_action(&mut self, observed: &ObservedState, legal_actions: &[Act]) -> Act;
+}
+
+/// 학습된 평균 전략을 따르는 정책 (학습되지 않은 상황은 균등 분포)
+pub struct TrainerPolicy<'a, R: Rng> {
+    trainer: &'a Trainer<State>,
+    rng: R,
+}
+
+impl<'a, R: Rng> TrainerPolicy<'a, R> {
+    pub fn new(trainer: &'a Trainer<State>, rng: R) -> Self {
+        Self { trainer, rng }
+    }
+}
+
+impl<R: Rng> ActionPolicy for TrainerPolicy<'_, R> {
+    fn choose_action(&mut self, observed: &ObservedState, legal_actions: &[Act]) -> Act {
+        let strategy = self
+            .trainer
+            .nodes
+            .get(&observed.info_key())
+            .map(|node| node.average())
+            .filter(|strategy| strategy.len() == legal_actions.len());
+
+        let Some(strategy) = strategy else {
+            return legal_actions[self.rng.gen_range(0..legal_actions.len())];
+        };
+        let mut roll = self.rng.gen::<f64>();
+        for (action, prob) in legal_actions.iter().zip(&strategy) {
+            if roll < *prob {
+                return *action;
+            }
+            roll -= prob;
+        }
+        *legal_actions.last().expect("액션이 하나 이상 필요")
+    }
+}
+
+/// 정책들끼리 한 핸드를 끝까지 진행하고 좌석별 칩 결과 반환
+///
+/// 각 정책은 자기 좌석의 `ObservedState`만 받습니다. `policies[i]`는 좌석 `i`의 정책이며,
+/// 정책이 없는 좌석에서 액션이 필요하면 패닉합니다.
+pub fn play_hand<R: Rng>(root: &State, policies: &mut [&mut dyn ActionPolicy], rng: &mut R) -> [f64; 6] {
+    let state = play_to_terminal(root, policies, rng);
+
+    let mut result = [0.0; 6];
+    for (seat, value) in result.iter_mut().enumerate() {
+        if root.alive[seat] {
+            *value = State::util(&state, seat);
+        }
+    }
+    result
+}
+
+/// 정책들끼리 한 핸드를 진행해 마지막 상태 반환
+///
+/// 터미널이거나, CFR 학습기처럼 가능한 액션이 없는 상태에서 멈춥니다.
+/// 실제 칩으로 정산하려면 결과에 `State::settle`을 사용합니다. 정책 규칙은 `play_hand`와 같습니다.
+pub fn play_to_terminal<R: Rng>(root: &State, policies: &mut [&mut dyn ActionPolicy], rng: &mut R) -> State {
+    play_recorded(root, policies, rng, |_, _| {})
+}
+
+/// 정책들끼리 한 핸드를 진행하며 액션마다 (적용 전 상태, 액션)을 `on_action`에 넘김
+fn play_recorded<R: Rng>(
+    root: &State,
+    policies: &mut [&mut dyn ActionPolicy],
+    rng: &mut R,
+    mut on_action: impl FnMut(&State, Act),
+) -> State {
+    let mut state = root.clone();
+    // 무한 루프 방지 (홀덤 상태 머신은 액션 수 제한으로 항상 종료됨)
+    for _ in 0..256 {
+        if state.is_terminal() {
+            break;
+        }
+        if state.is_chance_node() {
+            state = State::apply_chance(&state, rng);
+            continue;
+        }
+        let Some(player) = State::current_player(&state) else {
+            break;
+        };
+        let legal_actions = State::legal_actions(&state);
+        if legal_actions.is_empty() {
+            break;
+        }
+        let policy = policies
+            .get_mut(player)
+            .unwrap_or_else(|| panic!("좌석 {}의 정책이 없습니다", player));
+        let action = policy.choose_action(&state.observe(player), &legal_actions);
+        assert!(legal_actions.contains(&action), "정책이 불가능한 액션 {:?}을 선택", action);
+        on_action(&state, action);
+        state = State::next_state(&state, action);
+    }
+    state
+}
+
+/// 여러 핸드를 진행하고 좌석별 누적 칩 결과 반환
+pub fn play_match<R: Rng>(
+    roots: impl IntoIterator<Item = State>,
+    policies: &mut [&mut dyn ActionPolicy],
+    rng: &mut R,
+) -> [f64; 6] {
+    let mut totals = [0.0; 6];
+    for root in roots {
+        let result = play_hand(&root, policies, rng);
+        for (total, value) in totals.iter_mut().zip(result) {
+            *total += value;
+        }
+    }
+    totals
+}
+
+/// 한 핸드를 진행하고 스트리트별 액션과 손익을 기록한 결과 반환 (정책 규칙은 `play_hand`와 같음)
+pub fn play_hand_recorded<R: Rng>(root: &State, policies: &mut [&mut dyn ActionPolicy], rng: &mut R) -> HandResult {
+    let mut hand = HandResult::new(root);
+    let terminal = play_recorded(root, policies, rng, |state, action| hand.record_action(state, action));
+    hand.finish(&terminal);
+    hand
+}
+
+/// 여러 핸드를 진행한 대결 결과
+#[derive(Debug, Clone, Default)]
+pub struct MatchResult {
+    /// 좌석별 누적 칩 결과 (`play_match`와 같음)
+    pub totals: [f64; 6],
+    /// 핸드별 기록
+    pub session: SessionTracker,
+}
+
+impl MatchResult {
+    /// 좌석의 세션 통계 (VPIP, PFR, 컨티뉴에이션 벳 등)
+    pub fn stats(&self, seat: usize) -> PlayerStats {
+        self.session.stats(seat)
+    }
+}
+
+/// 여러 핸드를 진행하고 칩 결과와 핸드 기록을 함께 반환
+pub fn play_match_tracked<R: Rng>(
+    roots: impl IntoIterator<Item = State>,
+    policies: &mut [&mut dyn ActionPolicy],
+    rng: &mut R,
+) -> MatchResult {
+    let mut result = MatchResult::default();
+    for root in roots {
+        let hand = play_hand_recorded(&root, policies, rng);
+        for (total, value) in result.totals.iter_mut().zip(hand.net) {
+            *total += value;
+        }
+        result.session.record(hand);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn heads_up_state() -> State {
+        let mut state = State::new_hand([50, 100], [1000; 6], 2);
+        state.hole[0] = [0, 25]; // As Kh
+        state.hole[1] = [35, 48]; // 10d 10c
+        state
+    }
+
+    #[test]
+    fn test_observed_state_strips_opponent_hole_cards() {
+        let state = heads_up_state();
+        let observed = state.observe(0);
+        assert_eq!(observed.hole, [0, 25]);
+        assert_eq!(observed.to_state().hole[1], [0, 0]);
+        assert_eq!(observed.info_key(), State::info_key(&state, 0));
+        assert_eq!(observed.legal_actions(), State::legal_actions(&state));
+
+        // 상대 카드만 다른 두 상태의 관찰 결과는 직렬화 바이트까지 같아야 함
+        let mut other = state.clone();
+        other.hole[1] = [12, 38]; // Ks Kd
+        let bytes = serde_json::to_vec(&state.observe(0)).unwrap();
+        assert_eq!(bytes, serde_json::to_vec(&other.observe(0)).unwrap());
+        assert_ne!(bytes, serde_json::to_vec(&state.observe(1)).unwrap());
+    }
+
+    /// 관찰 상태만으로 결정하는 단순 정책 (페어 이상이면 레이즈, 아니면 콜)
+    struct PairRaiser;
+
+    impl ActionPolicy for PairRaiser {
+        fn choose_action(&mut self, observed: &ObservedState, legal_actions: &[Act]) -> Act {
+            let pair = observed.hole[0] % 13 == observed.hole[1] % 13;
+            let preferred = if pair { Act::Raise(0) } else { Act::Call };
+            if legal_actions.contains(&preferred) {
+                preferred
+            } else {
+                legal_actions[0]
+            }
+        }
+    }
+
+    #[test]
+    fn test_observed_policies_play_full_match() {
+        let mut trainer = Trainer::<State>::new();
+        trainer.set_seed(3);
+        trainer.run(vec![heads_up_state()], 2);
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut raiser = PairRaiser;
+        let mut trained = TrainerPolicy::new(&trainer, StdRng::seed_from_u64(5));
+        let roots: Vec<State> = (0..20).map(|_| State::new_hand([50, 100], [1000; 6], 2)).collect();
+        let totals = play_match(roots, &mut [&mut raiser, &mut trained], &mut rng);
+
+        assert!(totals.iter().all(|v| v.is_finite()), "{:?}", totals);
+        assert_ne!(totals[0], 0.0, "20핸드 동안 칩 이동이 있어야 함");
+        assert!(totals[2..].iter().all(|&v| v == 0.0));
+    }
+}
@@ -0,0 +1,234 @@
+// 상대 홀카드 재딜링 모델
+// 상대 홀카드를 모르는 부분 정보 상태(분석 요청, 라이브 세션)에서 시뮬레이션마다 상대 핸드를
+// 새로 딜링합니다. 무작위 딜링은 프리플랍에 폴드했을 약한 핸드를 과대평가하므로, 베팅 라인에서
+// 추정한 레인지의 가중치에 비례해 카드 제거를 반영한 조합을 고를 수 있습니다.
+
+use crate::game::card_abstraction::hand_class::HandClass;
+use crate::game::card_abstraction::preflop_bucket_multiway;
+use crate::game::holdem::{Act, State};
+use crate::interop::pio::HandRangeWeights;
+use crate::solver::cfr_core::{Game, GameState};
+use rand::Rng;
+use std::collections::HashMap;
+
+/// 모르는 상대 홀카드를 딜링하는 방식
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum OpponentDealModel {
+    /// 남은 덱에서 무작위 두 장
+    #[default]
+    Uniform,
+    /// 좌석별 레인지 가중치에 비례 (레인지가 없거나 카드 제거로 비어 버린 좌석은 무작위)
+    RangeWeighted(HashMap<usize, HandRangeWeights>),
+}
+
+impl OpponentDealModel {
+    /// `hero`를 뺀 살아 있는 좌석의 홀카드를 새로 딜링한 홀카드 배열
+    ///
+    /// 히어로 홀카드와 보드, 먼저 딜링한 좌석의 카드를 제외하고 좌석 번호 순으로 딜링합니다.
+    /// 폴드한 좌석의 카드는 그대로 둡니다.
+    pub fn deal<R: Rng>(&self, state: &State, hero: usize, rng: &mut R) -> [[u8; 2]; 6] {
+        let mut hole = state.hole;
+        let mut dead: Vec<u8> = hole[hero.min(5)].iter().chain(&state.board).copied().collect();
+
+        for seat in (0..6).filter(|&seat| seat != hero && state.alive[seat]) {
+            let ranged = match self {
+                OpponentDealModel::RangeWeighted(ranges) => ranges
+                    .get(&seat)
+                    .and_then(|range| deal_from_range(range, state, &dead, rng)),
+                OpponentDealModel::Uniform => None,
+            };
+            let combo = ranged.unwrap_or_else(|| deal_uniform(state, &dead, rng));
+            dead.extend(combo);
+            hole[seat] = combo;
+        }
+        hole
+    }
+}
+
+/// 레인지 가중치에 비례해 데드 카드와 겹치지 않는 조합 하나 (가능한 조합이 없으면 None)
+fn deal_from_range<R: Rng>(range: &HandRangeWeights, state: &State, dead: &[u8], rng: &mut R) -> Option<[u8; 2]> {
+    let combos: Vec<([u8; 2], f64)> = range
+        .remove_conflicts(dead)
+        .into_iter()
+        .filter(|(combo, _)| combo.iter().all(|&card| state.variant.contains(card)))
+        .collect();
+    let total: f64 = combos.iter().map(|&(_, weight)| weight).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut target = rng.gen::<f64>() * total;
+    for &(combo, weight) in &combos {
+        if target < weight {
+            return Some(combo);
+        }
+        target -= weight;
+    }
+    combos.last().map(|&(combo, _)| combo)
+}
+
+/// 변형의 덱에서 데드 카드를 뺀 무작위 두 장
+fn deal_uniform<R: Rng>(state: &State, dead: &[u8], rng: &mut R) -> [u8; 2] {
+    let live: Vec<u8> = state.variant.deck().into_iter().filter(|card| !dead.contains(card)).collect();
+    let first = rng.gen_range(0..live.len());
+    let second = (first + rng.gen_range(1..live.len())) % live.len();
+    [live[first], live[second]]
+}
+
+/// 헤즈업 에퀴티 순위 상위 `fraction` 비율(조합 수 기준)의 레인지
+///
+/// 순위는 상대 1명 기준 `preflop_bucket_multiway` 버킷이며, 같은 버킷 안에서는 클래스 인덱스 순입니다.
+/// 경계 클래스는 통째로 들어가므로 실제 비율은 `fraction`보다 조금 클 수 있습니다.
+pub fn top_hand_range(fraction: f64) -> HandRangeWeights {
+    let mut classes = HandClass::all();
+    classes.sort_by_key(|class| (preflop_bucket_multiway(class.combos(&[])[0], 1), class.to_index()));
+
+    let target = fraction.clamp(0.0, 1.0) * 1326.0;
+    let mut range = HandRangeWeights::new();
+    let mut combos = 0.0;
+    for class in classes {
+        if combos >= target {
+            break;
+        }
+        range.set_weight(class, 1.0);
+        combos += class.combo_count() as f64;
+    }
+    range
+}
+
+/// 좌석 하나의 프리플랍 베팅 라인 (자발적으로 칩을 넣은 액션 수)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PreflopLine {
+    /// 베팅/레이즈 횟수
+    pub raises: u8,
+    /// 베팅을 마주하고 콜한 횟수 (체크 제외)
+    pub calls: u8,
+}
+
+impl PreflopLine {
+    /// 프리플랍에서 `state`의 차례 좌석이 `action`을 했을 때 라인 갱신 (포스트플랍 액션은 무시)
+    pub fn record(&mut self, state: &State, action: Act) {
+        if state.street != 0 {
+            return;
+        }
+        match action {
+            Act::Raise(_) => self.raises = self.raises.saturating_add(1),
+            Act::Call if state.to_call > state.invested[state.to_act.min(5)] => {
+                self.calls = self.calls.saturating_add(1)
+            }
+            _ => {}
+        }
+    }
+
+    /// 라인에서 추정한 레인지 비율 (1.0이면 아직 자발적으로 칩을 넣지 않아 무작위 핸드)
+    ///
+    /// 콜 40%, 오픈 레이즈 20%, 3벳 8%, 4벳 이상 3.5% - 일반적인 6-Max 레귤러의 빈도입니다.
+    pub fn range_fraction(&self) -> f64 {
+        match (self.raises, self.calls) {
+            (0, 0) => 1.0,
+            (0, _) => 0.4,
+            (1, _) => 0.2,
+            (2, _) => 0.08,
+            _ => 0.035,
+        }
+    }
+
+    /// `root`에서 `history`를 프리플랍이 끝날 때까지 재생한 좌석별 라인
+    ///
+    /// 재생할 수 없는 액션을 만나면 거기까지의 라인을 반환합니다.
+    pub fn replay(root: &State, history: &[Act]) -> [PreflopLine; 6] {
+        let mut lines = [PreflopLine::default(); 6];
+        let mut state = root.clone();
+        for &action in history {
+            if state.street != 0 || state.is_chance_node() || !State::legal_actions(&state).contains(&action) {
+                break;
+            }
+            lines[state.to_act.min(5)].record(&state, action);
+            state = State::next_state(&state, action);
+        }
+        lines
+    }
+}
+
+/// 좌석별 프리플랍 라인에서 추정한 딜링 모델 (`hero`와 라인이 없는 좌석은 무작위)
+pub fn estimate_opponent_deal(lines: &[PreflopLine; 6], hero: usize) -> OpponentDealModel {
+    let ranges: HashMap<usize, HandRangeWeights> = lines
+        .iter()
+        .enumerate()
+        .filter(|&(seat, line)| seat != hero && line.range_fraction() < 1.0)
+        .map(|(seat, line)| (seat, top_hand_range(line.range_fraction())))
+        .collect();
+    if ranges.is_empty() {
+        OpponentDealModel::Uniform
+    } else {
+        OpponentDealModel::RangeWeighted(ranges)
+    }
+}
+
+impl State {
+    /// `opponent_deal` 모델로 `hero`를 뺀 살아 있는 좌석의 홀카드를 다시 딜링한 상태 (모델이 없으면 None)
+    pub fn resample_opponents<R: Rng>(&self, hero: usize, rng: &mut R) -> Option<State> {
+        let model = self.opponent_deal.as_ref()?;
+        let mut next = self.clone();
+        next.hole = model.deal(self, hero, rng);
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::card_abstraction::hand_class::rank_of;
+    use crate::interop::pio::parse_range;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_pairs_only_range_always_deals_pairs() {
+        let mut state = State::new_hand([50, 100], [1000; 6], 3);
+        state.hole[0] = [0, 13]; // AA
+        state.board = vec![11, 10, 9];
+        let pairs = parse_range("22,33,44,55,66,77,88,99,TT,JJ,QQ,KK,AA");
+        state.opponent_deal = Some(Arc::new(OpponentDealModel::RangeWeighted(HashMap::from([(1, pairs)]))));
+
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut seat2_pairs = 0;
+        for _ in 0..10_000 {
+            let dealt = state.resample_opponents(0, &mut rng).unwrap();
+            assert_eq!(dealt.hole[0], state.hole[0]);
+            let [a, b] = dealt.hole[1];
+            assert_eq!(rank_of(a), rank_of(b), "{:?}", dealt.hole[1]);
+
+            let mut cards: Vec<u8> = dealt.hole[..3].iter().flatten().chain(&dealt.board).copied().collect();
+            cards.sort_unstable();
+            cards.dedup();
+            assert_eq!(cards.len(), 9);
+            seat2_pairs += (rank_of(dealt.hole[2][0]) == rank_of(dealt.hole[2][1])) as usize;
+        }
+        // 레인지가 없는 좌석은 무작위 (페어 확률 약 6%)
+        assert!(seat2_pairs < 1000, "{}", seat2_pairs);
+    }
+
+    #[test]
+    fn test_preflop_line_narrows_range() {
+        let root = State::new_hand([50, 100], [10_000; 6], 3);
+        // 버튼 오픈, 스몰 블라인드 3벳, 빅 블라인드 폴드, 버튼 콜
+        let lines = PreflopLine::replay(&root, &[Act::Raise(0), Act::Raise(0), Act::Fold, Act::Call]);
+        assert_eq!(lines[0], PreflopLine { raises: 1, calls: 1 });
+        assert_eq!(lines[1], PreflopLine { raises: 1, calls: 0 });
+        assert_eq!(lines[2], PreflopLine::default());
+
+        let open = top_hand_range(lines[0].range_fraction()).combo_fraction();
+        let three_bet = top_hand_range(PreflopLine { raises: 2, calls: 0 }.range_fraction()).combo_fraction();
+        assert!((0.2..0.25).contains(&open), "{}", open);
+        assert!(three_bet < open);
+        assert_eq!(top_hand_range(1.0).combo_fraction(), 1.0);
+        assert!(top_hand_range(0.02).weight(HandClass::Pair(12)) > 0.0);
+
+        match estimate_opponent_deal(&lines, 0) {
+            OpponentDealModel::RangeWeighted(ranges) => assert_eq!(ranges.keys().collect::<Vec<_>>(), vec![&1]),
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(estimate_opponent_deal(&[PreflopLine::default(); 6], 0), OpponentDealModel::Uniform);
+    }
+}
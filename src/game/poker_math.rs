@@ -0,0 +1,128 @@
+// 포커 수학 유틸리티
+// 스택-팟 비율(SPR) 기반 베팅 사이징 계산, 베팅 크기별 방어/블러프 기준 빈도
+
+/// 이 SPR 이하에서는 어떤 베팅이든 팟 커밋이므로 올인으로 취급
+pub const POT_COMMITTED_SPR: f64 = 1.0;
+
+/// 기하급수적(geometric) 베팅 비율 계산
+///
+/// 남은 스트리트마다 같은 팟 비율로 베팅하고 콜을 받았을 때
+/// 리버에서 정확히 올인이 되는 베팅 크기를 팟 대비 비율로 반환합니다.
+///
+/// 베팅 비율을 f, 스트리트 수를 n이라 하면 매 스트리트 팟이 (1 + 2f)배가 되므로
+/// `(1 + 2f)^n = 1 + 2 * SPR` 을 만족하는 f를 구합니다.
+///
+/// # 매개변수
+/// - spr: 유효 스택 / 현재 팟
+/// - streets_remaining: 현재 스트리트를 포함한 남은 베팅 스트리트 수
+///
+/// # 반환값
+/// - 팟 대비 베팅 비율 (올인이면 `spr`과 같음)
+///
+/// # 예시
+/// ```rust
+/// use nice_hand_core::game::poker_math::geometric_bet_fraction;
+///
+/// // 플랍에서 SPR 13: 세 스트리트 동안 팟 사이즈 베팅
+/// let fraction = geometric_bet_fraction(13.0, 3);
+/// assert!((fraction - 1.0).abs() < 1e-9);
+/// ```
+pub fn geometric_bet_fraction(spr: f64, streets_remaining: u32) -> f64 {
+    if !spr.is_finite() || spr <= 0.0 {
+        return 0.0;
+    }
+
+    // 마지막 스트리트이거나 이미 팟 커밋이면 올인
+    if streets_remaining <= 1 || spr <= POT_COMMITTED_SPR {
+        return spr;
+    }
+
+    let growth = (1.0 + 2.0 * spr).powf(1.0 / streets_remaining as f64);
+    ((growth - 1.0) / 2.0).min(spr)
+}
+
+/// 스트리트 번호(0=프리플랍 ... 3=리버)에서 남은 베팅 스트리트 수
+pub fn streets_remaining(street: u8) -> u32 {
+    4u32.saturating_sub(street as u32).max(1)
+}
+
+/// 최소 방어 빈도(MDF)
+///
+/// 베팅 전 팟 `pot`에 `bet`을 베팅받았을 때, 아무 카드로나 하는 블러프가 즉시 이익을 내지
+/// 못하도록 방어자가 계속(콜/레이즈)해야 하는 최소 빈도 `pot / (pot + bet)`입니다.
+/// 하프 팟 베팅이면 약 67%, 팟 베팅이면 50%입니다.
+pub fn minimum_defense_frequency(pot: f64, bet: f64) -> f64 {
+    if bet.is_nan() || bet <= 0.0 || !pot.is_finite() || pot <= 0.0 {
+        return 1.0;
+    }
+    pot / (pot + bet)
+}
+
+/// 리버 베팅 레인지 중 블러프의 최적 비율
+///
+/// 콜러가 콜과 폴드 사이에서 무차별해지는 비율 `bet / (pot + 2 * bet)`입니다.
+/// 팟 베팅이면 1/3, 하프 팟 베팅이면 1/4입니다.
+pub fn optimal_bluff_ratio(pot: f64, bet: f64) -> f64 {
+    if bet.is_nan() || bet <= 0.0 || !pot.is_finite() || pot < 0.0 {
+        return 0.0;
+    }
+    bet / (pot + 2.0 * bet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometric_all_in_when_committed() {
+        // 턴에서 SPR 1: 올인
+        let spr = 1.0;
+        assert_eq!(geometric_bet_fraction(spr, streets_remaining(2)), spr);
+
+        // 리버에서는 항상 올인
+        assert_eq!(geometric_bet_fraction(3.0, streets_remaining(3)), 3.0);
+    }
+
+    #[test]
+    fn test_geometric_reaches_all_in_by_river() {
+        for &(spr, streets) in &[(13.0, 3), (7.8, 3), (4.0, 2), (30.0, 4)] {
+            let fraction = geometric_bet_fraction(spr, streets);
+
+            // 매 스트리트 베팅/콜 후 스택과 팟을 추적
+            let mut pot = 1.0;
+            let mut stack = spr;
+            for _ in 0..streets {
+                let bet = pot * fraction;
+                stack -= bet;
+                pot += 2.0 * bet;
+            }
+            assert!(stack.abs() < 1e-9, "SPR {} / {} 스트리트: 남은 스택 {}", spr, streets, stack);
+        }
+
+        // 플랍 SPR 13은 팟 사이즈, SPR 약 7.8은 팟의 75~80%
+        assert!((geometric_bet_fraction(13.0, 3) - 1.0).abs() < 1e-9);
+        let fraction = geometric_bet_fraction(7.8, 3);
+        assert!(fraction > 0.75 && fraction < 0.8, "fraction = {}", fraction);
+    }
+
+    #[test]
+    fn test_geometric_degenerate_inputs() {
+        assert_eq!(geometric_bet_fraction(0.0, 3), 0.0);
+        assert_eq!(geometric_bet_fraction(-1.0, 3), 0.0);
+        assert_eq!(geometric_bet_fraction(f64::NAN, 3), 0.0);
+        assert_eq!(streets_remaining(0), 4);
+        assert_eq!(streets_remaining(9), 1);
+    }
+
+    #[test]
+    fn test_defense_and_bluff_frequencies() {
+        assert!((minimum_defense_frequency(100.0, 50.0) - 2.0 / 3.0).abs() < 1e-9);
+        assert!((minimum_defense_frequency(100.0, 100.0) - 0.5).abs() < 1e-9);
+        assert!((optimal_bluff_ratio(100.0, 100.0) - 1.0 / 3.0).abs() < 1e-9);
+        assert!((optimal_bluff_ratio(100.0, 50.0) - 0.25).abs() < 1e-9);
+
+        // 베팅이 없으면 폴드할 이유도, 블러프도 없음
+        assert_eq!(minimum_defense_frequency(100.0, 0.0), 1.0);
+        assert_eq!(optimal_bluff_ratio(100.0, 0.0), 0.0);
+    }
+}
@@ -0,0 +1,56 @@
+// 난수 시드 모듈
+// 라이브러리 코드는 `thread_rng`/`rand::random`을 쓰지 않고, 주입받은 RNG나 생성 시
+// 시드한 RNG를 사용합니다. 시드를 받지 않는 편의 생성자와 기본 설정은 여기서 만든
+// 시드를 씁니다. wasm32 빌드에는 getrandom이 들어가지 않도록 OS 엔트로피는 네이티브에서만
+// 사용합니다.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// 시드를 지정하지 않은 곳에서 쓸 새 시드
+///
+/// 네이티브는 OS 엔트로피, wasm32는 `wasm` feature가 켜져 있으면 JS `Math.random`을 쓰며,
+/// 둘 다 없으면 프로세스 안에서 호출마다 다른 값을 주는 카운터입니다.
+pub fn entropy_seed() -> u64 {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use rand::RngCore;
+        rand::rngs::OsRng.next_u64()
+    }
+    #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+    {
+        let high = (js_sys::Math::random() * u32::MAX as f64) as u64;
+        let low = (js_sys::Math::random() * u32::MAX as f64) as u64;
+        ((high << 32) | low) ^ counter_seed()
+    }
+    #[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+    {
+        counter_seed()
+    }
+}
+
+/// `entropy_seed`로 시드한 RNG
+pub fn entropy_rng() -> StdRng {
+    StdRng::seed_from_u64(entropy_seed())
+}
+
+/// 호출마다 바뀌는 시드 (엔트로피 소스가 없는 wasm32 빌드용)
+#[cfg(target_arch = "wasm32")]
+fn counter_seed() -> u64 {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static NEXT: AtomicU64 = AtomicU64::new(0x853C_49E6_748F_EA9B);
+    NEXT.fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_entropy_rngs_differ() {
+        let a: Vec<u64> = (0..4).map(|_| entropy_rng().gen()).collect();
+        assert!(a.windows(2).any(|pair| pair[0] != pair[1]), "{:?}", a);
+    }
+}
@@ -0,0 +1,447 @@
+// 세션 통계
+// 진행한 핸드들의 스트리트별 액션 기록(`HandResult`)을 모아 트래킹 사이트에서 쓰는 표준 지표
+// (VPIP, PFR, 3벳, 스트리트별 컨티뉴에이션 벳, 컨티뉴에이션 벳에 대한 폴드, WTSD, W$SD,
+// 스트리트별 공격 빈도)를 좌석별로 계산합니다. 시뮬레이션 대결(`observation::play_match_tracked`)과
+// 라이브 핸드 모두 `HandResult`에 액션을 기록하면 같은 방식으로 집계됩니다.
+
+use crate::game::holdem::{Act, State};
+use crate::solver::cfr_core::{Game, GameState};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 한 핸드에서 기록된 액션 하나
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedAction {
+    /// 액션한 좌석
+    pub seat: usize,
+    /// 선택한 액션
+    pub action: Act,
+    /// 액션 전에 콜해야 했던 금액 (0이면 체크할 수 있었음)
+    pub facing: u32,
+    /// 액션 전에 이 스트리트에서 나온 베팅/레이즈 수 (블라인드 제외)
+    pub raises_before: u8,
+}
+
+impl RecordedAction {
+    /// 베팅 또는 레이즈
+    pub fn is_aggressive(&self) -> bool {
+        matches!(self.action, Act::Raise(_))
+    }
+
+    /// 앞선 베팅에 칩을 넣은 콜 (체크 제외)
+    pub fn is_call(&self) -> bool {
+        self.action == Act::Call && self.facing > 0
+    }
+
+    /// 폴드
+    pub fn is_fold(&self) -> bool {
+        self.action == Act::Fold
+    }
+}
+
+/// 끝난 핸드 하나의 기록
+///
+/// `new`로 시작 상태를 받고, 액션마다 `record_action`, 터미널에서 `finish`를 호출해 채웁니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HandResult {
+    /// 핸드에 참여한 좌석 (시작 상태의 생존 좌석)
+    pub dealt: [bool; 6],
+    /// 빅블라인드 (칩, bb/100 계산용, 0이면 1로 취급)
+    pub big_blind: u32,
+    /// 스트리트별 액션 순서 (인덱스 0 = 프리플랍)
+    pub streets: [Vec<RecordedAction>; 4],
+    /// 폴드하지 않은 플레이어가 둘 이상 남아 쇼다운(또는 올인 정산)으로 끝났는지 여부
+    pub showdown: bool,
+    /// 핸드가 끝날 때 폴드하지 않은 좌석
+    pub alive_at_end: [bool; 6],
+    /// 마지막 상태의 보드 카드 수
+    pub board_cards: usize,
+    /// 좌석별 손익 (칩, `State::util`)
+    pub net: [f64; 6],
+}
+
+impl HandResult {
+    /// 핸드 시작 상태로 기록 시작
+    pub fn new(root: &State) -> Self {
+        Self {
+            dealt: root.alive,
+            big_blind: root.big_blind,
+            streets: Default::default(),
+            showdown: false,
+            alive_at_end: root.alive,
+            board_cards: root.board.len(),
+            net: [0.0; 6],
+        }
+    }
+
+    /// `state`에서 액션할 좌석이 `action`을 선택했음을 기록 (액션 적용 전 상태)
+    pub fn record_action(&mut self, state: &State, action: Act) {
+        let seat = state.to_act;
+        let street = (state.street as usize).min(3);
+        self.streets[street].push(RecordedAction {
+            seat,
+            action,
+            facing: state.to_call.saturating_sub(state.invested[seat]),
+            raises_before: state.raises_this_street,
+        });
+    }
+
+    /// 마지막 상태로 쇼다운 여부와 좌석별 손익 기록
+    pub fn finish(&mut self, terminal: &State) {
+        self.alive_at_end = terminal.alive;
+        self.board_cards = terminal.board.len();
+        self.showdown = terminal.is_terminal() && terminal.alive.iter().filter(|&&alive| alive).count() >= 2;
+        for seat in 0..6 {
+            self.net[seat] = if self.dealt[seat] { State::util(terminal, seat) } else { 0.0 };
+        }
+    }
+
+    /// 좌석의 한 스트리트 액션들
+    fn actions_of(&self, street: usize, seat: usize) -> impl Iterator<Item = &RecordedAction> {
+        self.streets[street].iter().filter(move |action| action.seat == seat)
+    }
+
+    /// 스트리트에서 마지막으로 베팅/레이즈한 좌석
+    fn last_aggressor(&self, street: usize) -> Option<usize> {
+        self.streets[street].iter().rev().find(|action| action.is_aggressive()).map(|action| action.seat)
+    }
+
+    /// 스트리트의 컨티뉴에이션 벳을 한 좌석 (포스트플랍 스트리트만)
+    ///
+    /// 이전 스트리트의 마지막 공격자가 앞선 베팅 없이 이 스트리트의 첫 베팅을 한 경우입니다.
+    fn cbettor(&self, street: usize) -> Option<usize> {
+        let aggressor = self.last_aggressor(street.checked_sub(1)?)?;
+        let first_bet = self.streets[street].iter().find(|action| action.is_aggressive())?;
+        (first_bet.seat == aggressor && first_bet.raises_before == 0).then_some(aggressor)
+    }
+
+    /// 좌석이 플랍을 봤는지 여부 (프리플랍에 폴드하지 않았고 보드가 열렸거나 올인 정산됨)
+    pub fn saw_flop(&self, seat: usize) -> bool {
+        let folded_preflop = self.actions_of(0, seat).any(|action| action.is_fold());
+        self.dealt[seat] && !folded_preflop && (self.board_cards >= 3 || self.showdown)
+    }
+
+    /// 좌석이 쇼다운까지 갔는지 여부
+    pub fn went_to_showdown(&self, seat: usize) -> bool {
+        self.dealt[seat] && self.showdown && self.alive_at_end[seat]
+    }
+}
+
+/// 기회 대비 실행 횟수
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Frequency {
+    /// 실행한 횟수
+    pub made: u32,
+    /// 기회 수
+    pub opportunities: u32,
+}
+
+impl Frequency {
+    /// 기회 하나를 기록
+    fn count(&mut self, made: bool) {
+        self.opportunities += 1;
+        self.made += u32::from(made);
+    }
+
+    /// 비율 (0.0 ~ 1.0, 기회가 없으면 None)
+    pub fn rate(&self) -> Option<f64> {
+        (self.opportunities > 0).then(|| self.made as f64 / self.opportunities as f64)
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.rate() {
+            Some(rate) => write!(f, "{:.1}%", rate * 100.0),
+            None => f.write_str("-"),
+        }
+    }
+}
+
+/// 좌석 하나의 세션 통계
+///
+/// 정의는 일반적인 트래킹 소프트웨어 관례를 따릅니다.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct PlayerStats {
+    /// 참여한 핸드 수
+    pub hands: u32,
+    /// VPIP: 프리플랍에 자발적으로 칩을 넣은 핸드 비율 (콜 또는 레이즈, 블라인드 포스트와 빅블라인드 체크는 제외)
+    pub vpip: Frequency,
+    /// PFR: 프리플랍에 한 번 이상 레이즈한 핸드 비율
+    pub pfr: Frequency,
+    /// 3벳: 프리플랍에 레이즈 하나를 마주한 첫 액션에서 리레이즈한 비율
+    pub three_bet: Frequency,
+    /// 컨티뉴에이션 벳 (플랍/턴/리버): 이전 스트리트의 마지막 공격자가 앞선 베팅 없이 첫 액션할 때 베팅한 비율
+    pub cbet: [Frequency; 3],
+    /// 컨티뉴에이션 벳에 대한 폴드 (플랍/턴/리버): 다른 좌석의 컨티뉴에이션 벳을 처음 마주했을 때 폴드한 비율
+    pub fold_to_cbet: [Frequency; 3],
+    /// 플랍을 본 핸드 수 (프리플랍에 폴드하지 않았고 보드가 열렸거나 올인 정산됨)
+    pub saw_flop: u32,
+    /// WTSD: 플랍을 본 핸드 중 쇼다운까지 간 비율
+    pub wtsd: Frequency,
+    /// W$SD: 쇼다운까지 간 핸드 중 칩을 딴 비율
+    pub won_at_showdown: Frequency,
+    /// 스트리트별 공격 빈도 (AFq): 베팅/레이즈 ÷ (베팅/레이즈 + 콜 + 폴드), 체크는 제외
+    pub aggression: [Frequency; 4],
+    /// 누적 손익 (빅블라인드 단위)
+    pub net_bb: f64,
+}
+
+impl PlayerStats {
+    /// 플랍을 본 핸드 중 쇼다운 없이 끝난 비율 (WTSD와 합하면 1, 플랍을 본 적이 없으면 None)
+    pub fn non_showdown_rate(&self) -> Option<f64> {
+        self.wtsd.rate().map(|rate| 1.0 - rate)
+    }
+
+    /// 100핸드당 손익 (빅블라인드 단위)
+    pub fn bb_per_100(&self) -> f64 {
+        if self.hands == 0 {
+            0.0
+        } else {
+            self.net_bb / self.hands as f64 * 100.0
+        }
+    }
+
+    /// 핸드 하나를 통계에 반영
+    fn add_hand(&mut self, hand: &HandResult, seat: usize) {
+        if !hand.dealt[seat] {
+            return;
+        }
+        self.hands += 1;
+        self.net_bb += hand.net[seat] / hand.big_blind.max(1) as f64;
+
+        let preflop: Vec<&RecordedAction> = hand.actions_of(0, seat).collect();
+        self.vpip.count(preflop.iter().any(|action| action.is_aggressive() || action.is_call()));
+        self.pfr.count(preflop.iter().any(|action| action.is_aggressive()));
+        if let Some(action) = preflop.iter().find(|action| action.raises_before == 1) {
+            self.three_bet.count(action.is_aggressive());
+        }
+
+        for street in 1..4 {
+            if hand.last_aggressor(street - 1) == Some(seat) {
+                if let Some(first) = hand.actions_of(street, seat).next() {
+                    if first.raises_before == 0 {
+                        self.cbet[street - 1].count(first.is_aggressive());
+                    }
+                }
+            } else if hand.cbettor(street).is_some() {
+                let facing = hand.actions_of(street, seat).find(|action| action.raises_before == 1);
+                if let Some(action) = facing {
+                    self.fold_to_cbet[street - 1].count(action.is_fold());
+                }
+            }
+        }
+
+        if hand.saw_flop(seat) {
+            self.saw_flop += 1;
+            let showdown = hand.went_to_showdown(seat);
+            self.wtsd.count(showdown);
+            if showdown {
+                self.won_at_showdown.count(hand.net[seat] > 0.0);
+            }
+        }
+
+        for (street, aggression) in self.aggression.iter_mut().enumerate() {
+            for action in hand.actions_of(street, seat) {
+                if action.is_aggressive() || action.is_call() || action.is_fold() {
+                    aggression.count(action.is_aggressive());
+                }
+            }
+        }
+    }
+}
+
+/// 세션의 핸드 기록 모음과 좌석별 통계
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionTracker {
+    hands: Vec<HandResult>,
+}
+
+impl SessionTracker {
+    /// 빈 세션
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 끝난 핸드 기록 추가
+    pub fn record(&mut self, hand: HandResult) {
+        self.hands.push(hand);
+    }
+
+    /// 기록된 핸드들
+    pub fn hands(&self) -> &[HandResult] {
+        &self.hands
+    }
+
+    /// 좌석의 세션 통계
+    pub fn stats(&self, seat: usize) -> PlayerStats {
+        let mut stats = PlayerStats::default();
+        for hand in &self.hands {
+            stats.add_hand(hand, seat);
+        }
+        stats
+    }
+
+    /// 한 핸드 이상 참여한 좌석들의 통계 표 (좌석당 한 줄)
+    pub fn summary_table(&self) -> String {
+        let mut table = format!(
+            "{:<5} {:>6} {:>8} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6} {:>7} {:>6} {:>6} {:>6} {:>6} {:>6}\n",
+            "seat", "hands", "bb/100", "VPIP", "PFR", "3bet", "cbetF", "cbetT", "cbetR", "foldCBF", "WTSD", "W$SD", "AFqF",
+            "AFqT", "AFqR"
+        );
+        for seat in 0..6 {
+            let stats = self.stats(seat);
+            if stats.hands == 0 {
+                continue;
+            }
+            table.push_str(&format!(
+                "{:<5} {:>6} {:>8.1} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6} {:>7} {:>6} {:>6} {:>6} {:>6} {:>6}\n",
+                seat,
+                stats.hands,
+                stats.bb_per_100(),
+                stats.vpip.to_string(),
+                stats.pfr.to_string(),
+                stats.three_bet.to_string(),
+                stats.cbet[0].to_string(),
+                stats.cbet[1].to_string(),
+                stats.cbet[2].to_string(),
+                stats.fold_to_cbet[0].to_string(),
+                stats.wtsd.to_string(),
+                stats.won_at_showdown.to_string(),
+                stats.aggression[1].to_string(),
+                stats.aggression[2].to_string(),
+                stats.aggression[3].to_string(),
+            ));
+        }
+        table
+    }
+}
+
+impl fmt::Display for SessionTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.summary_table())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::observation::{play_match_tracked, ActionPolicy, ObservedState};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// 함수로 액션을 고르는 정책
+    struct Scripted(fn(&ObservedState, &[Act]) -> Act);
+
+    impl ActionPolicy for Scripted {
+        fn choose_action(&mut self, observed: &ObservedState, legal_actions: &[Act]) -> Act {
+            (self.0)(observed, legal_actions)
+        }
+    }
+
+    /// 프리플랍 오픈 레이즈, 플랍 첫 베팅, 나머지는 체크/콜
+    fn open_and_cbet(observed: &ObservedState, legal_actions: &[Act]) -> Act {
+        let first_bet = observed.street <= 1 && observed.raises_this_street == 0;
+        if first_bet && legal_actions.contains(&Act::Raise(0)) {
+            Act::Raise(0)
+        } else {
+            Act::Call
+        }
+    }
+
+    fn check_call(_: &ObservedState, _: &[Act]) -> Act {
+        Act::Call
+    }
+
+    fn fold(_: &ObservedState, _: &[Act]) -> Act {
+        Act::Fold
+    }
+
+    fn heads_up_roots(hands: usize) -> Vec<State> {
+        (0..hands).map(|_| State::new_hand([50, 100], [10_000; 6], 2)).collect()
+    }
+
+    #[test]
+    fn test_open_raise_and_cbet_every_hand() {
+        let mut hero = Scripted(open_and_cbet);
+        let mut villain = Scripted(check_call);
+        let mut rng = StdRng::seed_from_u64(1);
+        let result = play_match_tracked(heads_up_roots(30), &mut [&mut hero, &mut villain], &mut rng);
+
+        let stats = result.stats(0);
+        assert_eq!(stats.hands, 30);
+        assert_eq!(stats.pfr, Frequency { made: 30, opportunities: 30 });
+        assert_eq!(stats.vpip.rate(), Some(1.0));
+        assert_eq!(stats.cbet[0], Frequency { made: 30, opportunities: 30 });
+        // 턴에서도 플랍 공격자로 먼저 베팅할 기회가 있었지만 체크함
+        assert_eq!(stats.cbet[1], Frequency { made: 0, opportunities: 30 });
+
+        let villain = result.stats(1);
+        assert_eq!(villain.pfr.rate(), Some(0.0));
+        assert_eq!(villain.three_bet, Frequency { made: 0, opportunities: 30 });
+        assert_eq!(villain.fold_to_cbet[0], Frequency { made: 0, opportunities: 30 });
+        assert_eq!(villain.wtsd.rate(), Some(1.0));
+        assert_eq!(villain.won_at_showdown.opportunities, 30);
+
+        let table = result.session.summary_table();
+        assert_eq!(table.lines().count(), 3, "{}", table);
+        assert!(table.lines().nth(1).unwrap().contains("100.0%"), "{}", table);
+    }
+
+    #[test]
+    fn test_pure_folds_never_enter_the_pot() {
+        let mut folder = Scripted(fold);
+        let mut villain = Scripted(check_call);
+        let mut rng = StdRng::seed_from_u64(2);
+        let result = play_match_tracked(heads_up_roots(20), &mut [&mut folder, &mut villain], &mut rng);
+
+        let stats = result.stats(0);
+        assert_eq!(stats.vpip, Frequency { made: 0, opportunities: 20 });
+        assert_eq!(stats.pfr.rate(), Some(0.0));
+        assert_eq!(stats.saw_flop, 0);
+        assert_eq!(stats.wtsd.rate(), None);
+        // 스몰블라인드만 잃음
+        assert!((stats.bb_per_100() + 50.0).abs() < 1e-9, "{}", stats.bb_per_100());
+    }
+
+    /// 가능한 액션 중 무작위로 고르는 정책
+    struct RandomPolicy(StdRng);
+
+    impl ActionPolicy for RandomPolicy {
+        fn choose_action(&mut self, _: &ObservedState, legal_actions: &[Act]) -> Act {
+            // 폴드는 덜 고르도록 콜/레이즈를 두 번씩 넣음
+            let weighted: Vec<Act> = legal_actions
+                .iter()
+                .flat_map(|&action| if action == Act::Fold { vec![action] } else { vec![action; 2] })
+                .collect();
+            weighted[self.0.gen_range(0..weighted.len())]
+        }
+    }
+
+    #[test]
+    fn test_showdown_and_non_showdown_fractions_sum_to_one() {
+        let mut policies: Vec<RandomPolicy> = (0..6).map(|seat| RandomPolicy(StdRng::seed_from_u64(seat))).collect();
+        let mut policies: Vec<&mut dyn ActionPolicy> =
+            policies.iter_mut().map(|policy| policy as &mut dyn ActionPolicy).collect();
+        let roots = (0..200).map(|_| State::new_hand([50, 100], [10_000; 6], 6));
+        let result = play_match_tracked(roots, &mut policies, &mut StdRng::seed_from_u64(3));
+
+        for seat in 0..6 {
+            let stats = result.stats(seat);
+            assert_eq!(stats.hands, 200);
+            assert!(stats.saw_flop > 0 && stats.wtsd.opportunities == stats.saw_flop);
+
+            let hands = result.session.hands();
+            let saw_flop = hands.iter().filter(|hand| hand.saw_flop(seat)).count() as f64;
+            let no_showdown = hands
+                .iter()
+                .filter(|hand| hand.saw_flop(seat) && !hand.went_to_showdown(seat))
+                .count() as f64;
+            let wtsd = stats.wtsd.rate().unwrap();
+            assert!((wtsd + no_showdown / saw_flop - 1.0).abs() < 1e-12);
+            assert!((wtsd + stats.non_showdown_rate().unwrap() - 1.0).abs() < 1e-12);
+            assert!(stats.won_at_showdown.opportunities == stats.wtsd.made);
+            assert!(stats.vpip.made >= stats.pfr.made);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+// 숏덱 (6+) 홀덤
+// 2-5를 뺀 36장 덱을 쓰는 홀덤 변형
+//
+// 규칙:
+// - 덱: 6, 7, 8, 9, T, J, Q, K, A 각 4장씩 총 36장
+// - 플러시가 풀하우스보다 강함 (카드가 적어 플러시가 더 드묾)
+// - A-6-7-8-9가 가장 낮은 스트레이트 (에이스가 6 아래로 이어짐)
+// - 나머지 족보와 베팅 규칙은 일반 홀덤과 같음
+//
+// 게임 상태는 `holdem::State`의 `variant`를 `HoldemVariant::ShortDeck`으로 두고 사용합니다.
+
+use crate::game::hand_eval::{evaluate_7cards_direct, PartialEval, Ranking};
+use crate::game::holdem::{HoldemVariant, State};
+use crate::game::rng::entropy_rng;
+use crate::game::tournament::AnteType;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// 숏덱 크기
+pub const SHORT_DECK_SIZE: usize = 36;
+
+/// 숏덱에 있는 카드인지 확인 (2-5 제외)
+pub fn is_short_deck_card(card: u8) -> bool {
+    // 카드 랭크: 0=A, 1=2, ..., 12=K
+    card < 52 && !(1..=4).contains(&(card % 13))
+}
+
+/// 숏덱 36장 (카드 번호 오름차순)
+pub fn short_deck() -> Vec<u8> {
+    HoldemVariant::ShortDeck.deck()
+}
+
+/// 숏덱 7장 카드 핸드 평가
+///
+/// 일반 홀덤의 `v7()`과 같은 방식으로 낮을수록 강한 랭킹 값을 반환하지만,
+/// 플러시(2500-3824)와 풀하우스(3825-5108)의 구간이 서로 바뀌고 A-6-7-8-9가
+/// 가장 낮은 스트레이트입니다. 중복되거나 범위를 벗어난 카드가 있으면 `u32::MAX`입니다.
+pub fn evaluate_7cards_short_deck(cards: [u8; 7]) -> u32 {
+    evaluate_7cards_direct(cards, Ranking::ShortDeck).unwrap_or(u32::MAX)
+}
+
+/// 숏덱 족보를 쓰는 증분 평가기 (`PartialEval::from_cards`의 숏덱판)
+pub fn partial_eval_short_deck(cards: &[u8]) -> PartialEval {
+    PartialEval::for_ranking(cards, Ranking::ShortDeck)
+}
+
+/// 숏덱 핸드 강도를 텍스트로 변환
+pub fn short_deck_rank_to_string(rank: u32) -> &'static str {
+    match rank {
+        1..=1599 => "스트레이트 플러시",
+        1600..=2499 => "포카드",
+        2500..=3824 => "플러시",
+        3825..=5108 => "풀하우스",
+        5109..=5863 => "스트레이트",
+        5864..=8919 => "트리플",
+        8920..=21293 => "투페어",
+        21294..=32487 => "원페어",
+        _ => "하이카드",
+    }
+}
+
+/// 숏덱 프리플랍 핸드 버킷 (0이 가장 강함, 일반 홀덤 `preflop_bucket`과 같은 범위)
+///
+/// 36장 덱에서는 스트레이트와 셋이 훨씬 자주 완성되므로 일반 홀덤보다
+/// 포켓 페어와 수티드 커넥터의 버킷이 높고, 오프수트 에이스 하이의 가치는 낮습니다.
+pub fn short_deck_preflop_bucket(hole: [u8; 2]) -> u8 {
+    // 에이스 하이 랭크 (6=4, ..., K=11, A=12)
+    let rank = |card: u8| (card % 13 + 12) % 13;
+    let (high, low) = if rank(hole[0]) >= rank(hole[1]) {
+        (rank(hole[0]), rank(hole[1]))
+    } else {
+        (rank(hole[1]), rank(hole[0]))
+    };
+    let suited = hole[0] / 13 == hole[1] / 13;
+    let gap = high - low;
+    // A-6은 A-6-7-8-9 스트레이트의 양 끝이라 커넥터처럼 취급
+    let connected = gap == 1 || (high == 12 && low == 4);
+
+    match (high, low, suited) {
+        // 포켓 페어 (AA/KK, QQ/JJ, TT/99, 88-66)
+        (h, l, _) if h == l && h >= 11 => 0,
+        (h, l, _) if h == l && h >= 9 => 1,
+        (h, l, _) if h == l && h >= 7 => 4,
+        (h, l, _) if h == l => 8,
+
+        // 프리미엄 수티드 (AKs, AQs, AJs, KQs)
+        (12, 11, true) => 2,
+        (12, l, true) if l >= 9 => 3,
+        (11, 10, true) => 3,
+
+        // 수티드 커넥터 (브로드웨이 / 나머지)
+        (h, _, true) if connected && h >= 9 => 5,
+        (_, _, true) if connected => 6,
+
+        // 프리미엄 오프수트 (AK, AQ, AJ, KQ)
+        (12, 11, false) => 7,
+        (12, l, false) if l >= 9 => 9,
+        (11, 10, false) => 9,
+
+        // 수티드 원 갭퍼, 오프수트 커넥터
+        (_, _, true) if gap == 2 => 10,
+        (_, _, false) if connected => 12,
+
+        // 기타 수티드 에이스, 브로드웨이
+        (12, _, true) => 14,
+        (h, l, _) if h >= 8 && l >= 8 => 16,
+
+        // 나머지 (높은 카드일수록 강함)
+        _ => {
+            let base = if suited { 25 } else { 35 };
+            std::cmp::min(49, base + (12 - high) + gap)
+        }
+    }
+}
+
+/// 숏덱 새 핸드 생성 (프리플랍 시작, 홀카드는 숏덱에서 랜덤 딜링)
+///
+/// 블라인드와 버튼 규칙은 `State::new_hand`와 같습니다.
+pub fn new_short_deck_hand(blinds: [u32; 2], stacks: [u32; 6], player_count: usize) -> State {
+    new_short_deck_hand_with_rng(blinds, stacks, player_count, &mut entropy_rng())
+}
+
+/// 주어진 난수로 홀카드를 딜링하는 `new_short_deck_hand`
+pub fn new_short_deck_hand_with_rng<R: Rng>(
+    blinds: [u32; 2],
+    stacks: [u32; 6],
+    player_count: usize,
+    rng: &mut R,
+) -> State {
+    let mut state = State::new_hand_with_rng(blinds, 0, AnteType::PerPlayer, stacks, player_count, rng);
+    state.variant = HoldemVariant::ShortDeck;
+
+    let mut deck = short_deck();
+    deck.shuffle(rng);
+    for (seat, hole) in state.hole.iter_mut().enumerate().take(player_count.min(6)) {
+        *hole = [deck[seat * 2], deck[seat * 2 + 1]];
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::card_abstraction::hand_class::make_card;
+    use crate::game::hand_eval::v7;
+    use crate::solver::cfr_core::{Game, GameState, Trainer};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    // 강도 순 랭크: 4=6, ..., 7=9, 8=T, 9=J, 10=Q, 11=K, 12=A / 수트: 0=s, 1=h, 2=d, 3=c
+    fn card(rank: u8, suit: u8) -> u8 {
+        make_card(rank, suit)
+    }
+
+    #[test]
+    fn test_ace_six_straight() {
+        // 9s 8h 7d 6c As + Kd Qh
+        let cards = [card(7, 0), card(6, 1), card(5, 2), card(4, 3), card(12, 0), card(11, 2), card(10, 1)];
+        let rank = evaluate_7cards_short_deck(cards);
+        assert_eq!(short_deck_rank_to_string(rank), "스트레이트");
+
+        // 가장 낮은 스트레이트 (6-7-8-9-T보다 약하고 트리플보다 강함)
+        let ten_high = [card(8, 0), card(7, 1), card(6, 2), card(5, 3), card(4, 0), card(11, 2), card(10, 1)];
+        let trips = [card(12, 0), card(12, 1), card(12, 2), card(4, 3), card(6, 0), card(11, 2), card(10, 1)];
+        assert!(evaluate_7cards_short_deck(ten_high) < rank);
+        assert!(rank < evaluate_7cards_short_deck(trips));
+
+        // 일반 홀덤에서는 A 하이
+        assert_eq!(crate::game::hand_eval::rank_to_string(v7(cards)), "하이카드");
+    }
+
+    #[test]
+    fn test_flush_beats_full_house() {
+        // 가장 약한 플러시 (J 9 8 7 6 하트) vs 가장 강한 풀하우스 (AAA KK)
+        let weakest_flush = [card(9, 1), card(7, 1), card(6, 1), card(5, 1), card(4, 1), card(12, 0), card(11, 2)];
+        let best_full_house = [card(12, 0), card(12, 1), card(12, 2), card(11, 0), card(11, 1), card(9, 3), card(7, 2)];
+        let flush = evaluate_7cards_short_deck(weakest_flush);
+        let full_house = evaluate_7cards_short_deck(best_full_house);
+        assert_eq!(short_deck_rank_to_string(flush), "플러시");
+        assert_eq!(short_deck_rank_to_string(full_house), "풀하우스");
+        assert!(flush < full_house);
+
+        // 쇼다운 유틸리티도 숏덱 족보를 사용
+        let mut state = new_short_deck_hand([50, 100], [1000; 6], 2);
+        state.hole[0] = [card(7, 1), card(5, 1)]; // 9h 7h - 하트 플러시
+        state.hole[1] = [card(12, 0), card(11, 0)]; // As Ks - 에이스 풀하우스
+        state.board = vec![card(9, 1), card(4, 1), card(12, 1), card(12, 2), card(11, 2)];
+        state.street = 3;
+        state.pot = 200;
+        state.invested = [100, 100, 0, 0, 0, 0];
+        state.to_call = 100;
+        state.actions_taken = 2;
+        assert!(state.is_terminal());
+        assert_eq!(State::util(&state, 0), 100.0);
+        assert_eq!(State::util(&state, 1), -100.0);
+
+        // 같은 카드라도 일반 홀덤이면 풀하우스가 이김
+        state.variant = HoldemVariant::Standard;
+        assert_eq!(State::util(&state, 0), -100.0);
+    }
+
+    #[test]
+    fn test_dealing_never_produces_low_cards() {
+        assert_eq!(short_deck().len(), SHORT_DECK_SIZE);
+
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..200 {
+            let mut state = new_short_deck_hand([50, 100], [1000; 6], 6);
+            while !state.is_terminal() {
+                state = if state.is_chance_node() {
+                    State::apply_chance(&state, &mut rng)
+                } else {
+                    State::next_state(&state, crate::game::holdem::Act::Call)
+                };
+            }
+
+            let mut cards: Vec<u8> = state.hole.iter().flatten().chain(&state.board).copied().collect();
+            assert!(cards.iter().all(|&c| is_short_deck_card(c)), "{:?}", cards);
+            cards.sort_unstable();
+            cards.dedup();
+            assert_eq!(cards.len(), 12 + state.board.len());
+        }
+    }
+
+    #[test]
+    fn test_short_cfr_run_completes() {
+        // 포켓 페어와 수티드 커넥터의 버킷이 일반 홀덤보다 높음
+        let sixes = [card(4, 0), card(4, 1)];
+        let eight_seven_suited = [card(6, 2), card(5, 2)];
+        assert!(short_deck_preflop_bucket(sixes) < crate::game::card_abstraction::preflop_bucket(sixes));
+        assert!(
+            short_deck_preflop_bucket(eight_seven_suited)
+                < crate::game::card_abstraction::preflop_bucket(eight_seven_suited)
+        );
+
+        let mut trainer = Trainer::<State>::new();
+        trainer.set_seed(11);
+        trainer.run(vec![new_short_deck_hand([50, 100], [500; 6], 2)], 20);
+        assert!(!trainer.nodes.is_empty());
+        assert_eq!(trainer.iterations_run(), 20);
+    }
+}
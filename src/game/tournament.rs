@@ -6,6 +6,13 @@
 //! - Bubble strategy adjustments and pressure calculations
 //! - Multi-table tournament (MTT) management
 //! - Tournament-specific AI strategies
+//! - Final-table deal calculators (ICM chop, chip chop) in [`deals`]
+//! - Overflow-safe chip totals and chip-share normalization in [`chips`]
+//! - Standings with per-player ICM equity and what-ifs in [`standings`]
+//! - Structured tournament events (bustouts, bubble, table breaks) in [`events`]
+//! - Stable seat-to-player mapping for hands dealt at MTT tables in [`seating`]
+//! - Rebuys, add-ons and re-entries with prize pool accounting in [`rebuys`]
+//! - Payout ladders and next-pay-jump equity in [`pay_jumps`]
 //!
 //! # Key Components
 //!
@@ -41,7 +48,7 @@
 //!
 //! ## Tournament Structure Setup
 //! ```
-//! use nice_hand_core::game::tournament::{TournamentStructure, BlindLevel, TournamentState};
+//! use nice_hand_core::game::tournament::{AnteType, TournamentStructure, BlindLevel, TournamentState};
 //!
 //! let structure = TournamentStructure {
 //!     levels: vec![
@@ -52,6 +59,7 @@
 //!     level_duration_minutes: 20,
 //!     starting_stack: 1500,
 //!     ante_schedule: vec![],
+//!     ante_type: AnteType::PerPlayer,
 //! };
 //!
 //! let tournament = TournamentState::new(structure, 180, 100000);
@@ -77,9 +85,27 @@
 //! println!("Adjusted range: {:.1}%", adjusted_range * 100.0);
 //! ```
 
+use crate::game::holdem::{Act as HoldemAct, State as HoldemState};
+use crate::game::observation::ActionPolicy;
+use crate::solver::cfr_core::Game;
+use crate::game::tournament_holdem::{TournamentCFRTrainer, TournamentHoldemState};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub mod chips;
+pub mod clock;
+pub mod deals;
+pub mod events;
+pub mod pay_jumps;
+pub mod rebuys;
+pub mod seating;
+pub mod standings;
+
+pub use events::TournamentEvent;
+pub use rebuys::{Rebuy, RebuyError, RebuyKind};
+pub use seating::SeatMap;
+
 /// Tournament structure and blind schedule management
 ///
 /// Defines the blind levels, antes, and timing structure for a tournament.
@@ -92,11 +118,12 @@ use std::collections::HashMap;
 /// * `level_duration_minutes` - How long each blind level lasts in minutes
 /// * `starting_stack` - Number of chips each player starts with
 /// * `ante_schedule` - Optional separate ante schedule (usually embedded in levels)
+/// * `ante_type` - Who posts the ante each hand (defaults to every player)
 ///
 /// # Examples
 ///
 /// ```
-/// use nice_hand_core::game::tournament::{TournamentStructure, BlindLevel};
+/// use nice_hand_core::game::tournament::{AnteType, TournamentStructure, BlindLevel};
 ///
 /// let structure = TournamentStructure {
 ///     levels: vec![
@@ -106,6 +133,7 @@ use std::collections::HashMap;
 ///     level_duration_minutes: 15,
 ///     starting_stack: 1500,
 ///     ante_schedule: vec![],
+///     ante_type: AnteType::PerPlayer,
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -114,6 +142,8 @@ pub struct TournamentStructure {
     pub level_duration_minutes: u32,
     pub starting_stack: u32,
     pub ante_schedule: Vec<AnteLevel>,
+    #[serde(default)]
+    pub ante_type: AnteType,
 }
 
 /// Individual blind level configuration
@@ -163,6 +193,26 @@ pub struct AnteLevel {
     pub ante: u32,
 }
 
+/// Ante format used by a tournament structure
+///
+/// The level's `ante` is what each player posts under `PerPlayer`, and the
+/// single table-wide ante under `BigBlindAnte` and `ButtonAnte`.
+///
+/// Short-stack rule: when a player cannot cover both their blind and the ante,
+/// the ante is posted first and whatever is left goes to the blind (ante takes
+/// priority). A big blind with 80 chips facing a 100 big blind ante posts an
+/// 80-chip ante and no blind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AnteType {
+    /// Every player dealt in posts the ante
+    #[default]
+    PerPlayer,
+    /// The big blind posts one ante for the whole table
+    BigBlindAnte,
+    /// The button posts one ante for the whole table
+    ButtonAnte,
+}
+
 /// Tournament state tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TournamentState {
@@ -173,29 +223,19 @@ pub struct TournamentState {
     pub total_players: u32,
     pub prize_pool: u64,
     pub payout_structure: Vec<PayoutLevel>,
+    /// Last level at which rebuys and add-ons are accepted (0 for a freezeout)
+    #[serde(default)]
+    pub rebuy_last_level: u32,
+    /// Chips bought with rebuys and add-ons, on top of the starting stacks
+    #[serde(default)]
+    pub added_chips: u64,
+    /// Rebuys and add-ons in the order they were registered
+    #[serde(default)]
+    pub rebuys: Vec<rebuys::Rebuy>,
 }
 
 impl TournamentState {
     pub fn new(structure: TournamentStructure, total_players: u32, prize_pool: u64) -> Self {
-        // Create basic payout structure (top 10% get paid)
-        let payout_spots = (total_players as f64 * 0.1).ceil() as u32;
-        let mut payout_structure = Vec::new();
-
-        for position in 1..=payout_spots {
-            let percentage = match position {
-                1 => 0.4,                             // Winner gets 40%
-                2 => 0.25,                            // Second gets 25%
-                3 => 0.15,                            // Third gets 15%
-                _ => 0.2 / (payout_spots - 3) as f64, // Remaining split the rest
-            };
-
-            payout_structure.push(PayoutLevel {
-                position,
-                percentage,
-                amount: (prize_pool as f64 * percentage) as u64,
-            });
-        }
-
         Self {
             structure,
             current_level: 1,
@@ -203,12 +243,19 @@ impl TournamentState {
             players_remaining: total_players,
             total_players,
             prize_pool,
-            payout_structure,
+            payout_structure: basic_payouts(total_players, prize_pool),
+            rebuy_last_level: 0,
+            added_chips: 0,
+            rebuys: Vec::new(),
         }
     }
 
-    pub fn total_chips(&self) -> u32 {
-        self.total_players * self.structure.starting_stack
+    /// Chips in play (`u64`: large fields with deep stacks overflow `u32`)
+    ///
+    /// Every entry (re-entries included) brings a starting stack, plus the
+    /// chips bought with rebuys and add-ons.
+    pub fn total_chips(&self) -> u64 {
+        self.total_players as u64 * self.structure.starting_stack as u64 + self.added_chips
     }
 
     pub fn current_blinds(&self) -> (u32, u32, u32) {
@@ -218,6 +265,72 @@ impl TournamentState {
             (10, 20, 0) // Default blinds if level not found
         }
     }
+
+    /// Prize for finishing in `position` (1 for the winner, 0 outside the money)
+    pub fn payout_for_position(&self, position: u32) -> u64 {
+        self.payout_structure
+            .iter()
+            .find(|level| level.position == position)
+            .map_or(0, |level| level.amount)
+    }
+
+    /// Advance the tournament by `minutes` of play time
+    ///
+    /// The level is derived from total play minutes and the structure's level
+    /// duration, and never moves past the last configured level. Returns the
+    /// number of levels advanced.
+    pub fn advance_time(&mut self, minutes: u32) -> u32 {
+        self.minutes_elapsed = self.minutes_elapsed.saturating_add(minutes);
+        if self.structure.level_duration_minutes == 0 || self.structure.levels.is_empty() {
+            return 0;
+        }
+
+        let level = (self.minutes_elapsed / self.structure.level_duration_minutes + 1)
+            .min(self.structure.levels.len() as u32);
+        let advanced = level.saturating_sub(self.current_level);
+        self.current_level = self.current_level.max(level);
+        advanced
+    }
+
+    /// Ante format of the structure
+    pub fn current_ante_type(&self) -> AnteType {
+        self.structure.ante_type
+    }
+
+    /// Deal a hold'em hand at the current level, posting blinds and antes
+    /// according to the structure's ante format
+    pub fn new_holdem_hand(&self, stacks: [u32; 6], player_count: usize) -> crate::game::holdem::State {
+        let (small_blind, big_blind, ante) = self.current_blinds();
+        crate::game::holdem::State::new_hand_with_antes(
+            [small_blind, big_blind],
+            ante,
+            self.structure.ante_type,
+            stacks,
+            player_count,
+        )
+    }
+}
+
+/// Basic payout structure: the top 10% of `entries` get paid
+fn basic_payouts(entries: u32, prize_pool: u64) -> Vec<PayoutLevel> {
+    let payout_spots = (entries as f64 * 0.1).ceil() as u32;
+    let mut payout_structure = Vec::new();
+
+    for position in 1..=payout_spots {
+        let percentage = match position {
+            1 => 0.4,                             // Winner gets 40%
+            2 => 0.25,                            // Second gets 25%
+            3 => 0.15,                            // Third gets 15%
+            _ => 0.2 / payout_spots.saturating_sub(3).max(1) as f64, // Remaining split the rest
+        };
+
+        payout_structure.push(PayoutLevel {
+            position,
+            percentage,
+            amount: (prize_pool as f64 * percentage) as u64,
+        });
+    }
+    payout_structure
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -249,7 +362,7 @@ impl ICMCalculator {
 
         // For simple cases, use direct calculation
         if num_players == 1 {
-            return vec![self.payouts.get(0).copied().unwrap_or(0) as f64];
+            return vec![self.payouts[0] as f64];
         }
 
         if num_players == 2 {
@@ -262,12 +375,9 @@ impl ICMCalculator {
 
     /// Calculate heads-up ICM equity (2 players)
     fn calculate_heads_up_equity(&self) -> Vec<f64> {
-        let total_chips = (self.stacks[0] + self.stacks[1]) as f64;
-        let p1_chips = self.stacks[0] as f64;
-
         // Calculate adjusted win probabilities using ICM model
         // In tournament play, the chip leader's advantage is reduced due to ICM pressure
-        let chip_ratio = p1_chips / total_chips;
+        let chip_ratio = chips::chip_shares(&self.stacks)[0];
 
         // Apply ICM pressure adjustment - larger stacks have diminishing returns
         let p1_win_prob = if chip_ratio > 0.5 {
@@ -295,18 +405,16 @@ impl ICMCalculator {
     /// Simplified ICM calculation for multiple players
     fn calculate_simplified_icm(&self) -> Vec<f64> {
         let num_players = self.stacks.len();
-        let total_chips: u32 = self.stacks.iter().sum();
-
-        if total_chips == 0 {
+        if chips::total_chips(&self.stacks) == 0 {
             return vec![0.0; num_players];
         }
 
         let mut equities = vec![0.0; num_players];
-        let total_payout: f64 = self.payouts.iter().map(|&p| p as f64).sum();
+        // Only places the remaining players can still reach are paid out
+        let total_payout = chips::prize_pool(&self.payouts[..self.payouts.len().min(num_players)]);
 
         // Basic proportional distribution adjusted for ICM effects
-        for (i, &stack) in self.stacks.iter().enumerate() {
-            let stack_ratio = stack as f64 / total_chips as f64;
+        for (i, stack_ratio) in chips::chip_shares(&self.stacks).into_iter().enumerate() {
 
             // Apply ICM pressure (diminishing returns for big stacks)
             let icm_adjusted_ratio = if stack_ratio > 0.5 {
@@ -345,9 +453,7 @@ impl ICMCalculator {
 
         // If more players than payouts, calculate elimination probabilities
         let mut equity = 0.0;
-        let total_chips: u32 = remaining_players.iter().map(|&i| self.stacks[i]).sum();
-
-        if total_chips == 0 {
+        if self.remaining_chips(remaining_players) == 0 {
             return 0.0;
         }
 
@@ -405,7 +511,7 @@ impl ICMCalculator {
         player_idx: usize,
         remaining_players: &[usize],
     ) -> f64 {
-        let total_chips: u32 = remaining_players.iter().map(|&i| self.stacks[i]).sum();
+        let total_chips = self.remaining_chips(remaining_players);
         let player_stack = self.stacks[player_idx] as f64;
 
         if total_chips == 0 || player_stack <= 0.0 {
@@ -437,7 +543,7 @@ impl ICMCalculator {
         position: usize,
         remaining_players: &[usize],
     ) -> f64 {
-        let total_chips: u32 = remaining_players.iter().map(|&i| self.stacks[i]).sum();
+        let total_chips = self.remaining_chips(remaining_players);
         let player_stack = self.stacks[player_idx] as f64;
         let num_remaining = remaining_players.len();
 
@@ -583,8 +689,7 @@ impl ICMCalculator {
         remaining_players: &[usize],
     ) -> f64 {
         let player_stack = self.stacks[player_idx] as f64;
-        let total_chips: u32 = remaining_players.iter().map(|&i| self.stacks[i]).sum();
-        let stack_ratio = player_stack / total_chips as f64;
+        let stack_ratio = player_stack / self.remaining_chips(remaining_players) as f64;
 
         // Infer skill from stack accumulation vs elimination rate
         let expected_stack_ratio = 1.0 / remaining_players.len() as f64;
@@ -659,6 +764,11 @@ impl ICMCalculator {
         }
     }
 
+    /// Chips held by `remaining_players`
+    fn remaining_chips(&self, remaining_players: &[usize]) -> u64 {
+        remaining_players.iter().map(|&i| self.stacks[i] as u64).sum()
+    }
+
     /// Calculate ICM pressure - how much equity changes with stack changes
     pub fn calculate_icm_pressure(&self, player_idx: usize, chip_change: i32) -> f64 {
         if player_idx >= self.stacks.len() {
@@ -669,13 +779,12 @@ impl ICMCalculator {
 
         // Create modified stacks for comparison
         let mut modified_stacks = self.stacks.clone();
-        modified_stacks[player_idx] =
-            (modified_stacks[player_idx] as i32 + chip_change).max(0) as u32;
+        modified_stacks[player_idx] = chips::adjust_stack(modified_stacks[player_idx], chip_change as i64);
 
         let modified_icm = ICMCalculator::new(modified_stacks, self.payouts.clone());
         let modified_equity = modified_icm.calculate_equity()[player_idx];
 
-        (modified_equity - original_equity) / chip_change.abs() as f64
+        (modified_equity - original_equity) / chip_change.unsigned_abs() as f64
     }
 }
 
@@ -685,11 +794,13 @@ pub struct TournamentStrategy {
     pub bubble_factor: f64,
     pub icm_pressure: f64,
     pub stack_preservation: f64,
+    /// Share of the next pay jump's place at risk, see [`TournamentStrategy::with_pay_jump_pressure`]
+    pub pay_jump_pressure: f64,
 }
 
 impl TournamentStrategy {
     pub fn new(tournament_state: &TournamentState, player_stack: u32) -> Self {
-        let avg_stack = tournament_state.total_chips() / tournament_state.players_remaining;
+        let avg_stack = tournament_state.total_chips() / tournament_state.players_remaining.max(1) as u64;
         let stack_ratio = player_stack as f64 / avg_stack as f64;
 
         // Calculate bubble factor (how close we are to payouts)
@@ -704,9 +815,17 @@ impl TournamentStrategy {
             bubble_factor,
             icm_pressure: (2.0 - stack_ratio).max(0.0),
             stack_preservation: if stack_ratio < 0.5 { 2.0 } else { 1.0 },
+            pay_jump_pressure: 0.0,
         }
     }
 
+    /// Set the pay jump pressure: the player's pay jump equity (see
+    /// [`pay_jumps::pay_jump_equities`]) over the payout for the jump's place, in `[0, 1]`
+    pub fn with_pay_jump_pressure(mut self, pressure: f64) -> Self {
+        self.pay_jump_pressure = pressure.clamp(0.0, 1.0);
+        self
+    }
+
     /// Adjust CFR strategy based on tournament considerations
     pub fn adjust_strategy(&self, base_strategy: &[f64]) -> Vec<f64> {
         let mut adjusted = base_strategy.to_vec();
@@ -721,6 +840,14 @@ impl TournamentStrategy {
             }
         }
 
+        // Fold more when busting now would forfeit a nearby pay jump
+        if self.pay_jump_pressure > 0.0 && adjusted.len() >= 3 {
+            let fold_boost = self.pay_jump_pressure * 0.3;
+            adjusted[0] += fold_boost;
+            adjusted[1] = (adjusted[1] - fold_boost * 0.5).max(0.0);
+            adjusted[2] = (adjusted[2] - fold_boost * 0.5).max(0.0);
+        }
+
         // Normalize probabilities
         let sum: f64 = adjusted.iter().sum();
         if sum > 0.0 {
@@ -743,6 +870,9 @@ pub struct OpponentModel {
     pub tightness: f64,         // How tight they play
     pub bubble_adjustment: f64, // How they adjust near bubble
     pub stack_based_play: f64,  // How stack size affects their play
+    pub three_bet: f64,         // 3-bet frequency when facing an open
+    pub fold_to_three_bet: f64, // Fold frequency when their open gets 3-bet
+    pub fold_to_cbet: f64,      // Fold frequency when facing a continuation bet
     pub sample_size: u32,       // Number of hands observed
 }
 
@@ -756,6 +886,9 @@ impl OpponentModel {
             tightness: 0.5,         // Moderate tightness
             bubble_adjustment: 0.8, // Tighten up 20% near bubble
             stack_based_play: 1.0,  // Normal stack-based adjustments
+            three_bet: 0.07,        // Default 7% 3-bet
+            fold_to_three_bet: 0.55,
+            fold_to_cbet: 0.45,
             sample_size: 0,
         }
     }
@@ -870,6 +1003,15 @@ pub enum Position {
     Button,
 }
 
+/// Where `TournamentEvaluator::select_opponent_action` looks for a strategy before
+/// falling back to the opponent model
+pub enum OpponentStrategy<'a> {
+    /// A policy that decides every spot from the player's observed state
+    Policy(&'a mut dyn ActionPolicy),
+    /// A tournament CFR strategy, used only for info sets it has trained
+    Trainer(&'a TournamentCFRTrainer),
+}
+
 /// Advanced terminal state evaluation for tournament scenarios
 #[derive(Debug, Clone)]
 pub struct TournamentEvaluator {
@@ -958,67 +1100,149 @@ impl TournamentEvaluator {
         }
     }
 
-    /// Select opponent action using sophisticated modeling
-    pub fn select_opponent_action(
+    /// Select an opponent action for the seat to act in `hand`
+    ///
+    /// A supplied `strategy` is preferred: a policy always decides, a trainer only where
+    /// the info set was trained. Otherwise the action is sampled from the player's
+    /// `OpponentModel` prediction. All randomness comes from `rng`, so a seeded rng
+    /// reproduces the same actions. The abstract choice is mapped to chips with the
+    /// hand's real pot and the seat's stack: `Raise(chips)` is the amount put in by the
+    /// action (call included) and is always less than the stack, otherwise `AllIn`.
+    pub fn select_opponent_action<R: Rng>(
         &self,
         player_id: u32,
+        hand: &HoldemState,
         context: &ActionContext,
-        available_actions: &[TournamentAction],
+        strategy: Option<OpponentStrategy<'_>>,
+        rng: &mut R,
     ) -> TournamentAction {
-        if available_actions.is_empty() {
+        let Some(seat) = HoldemState::current_player(hand) else {
+            return TournamentAction::Fold;
+        };
+        let legal_actions = HoldemState::legal_actions(hand);
+        if legal_actions.is_empty() {
             return TournamentAction::Fold;
         }
 
+        let chosen = match strategy {
+            Some(OpponentStrategy::Policy(policy)) => Some(policy.choose_action(&hand.observe(seat), &legal_actions)),
+            Some(OpponentStrategy::Trainer(trainer)) => {
+                let state = TournamentHoldemState::new_tournament_hand(
+                    hand.clone(),
+                    self.tournament_state.clone(),
+                    self.icm_calculator.stacks.clone(),
+                );
+                if trainer.has_trained_strategy(&state, seat) {
+                    trainer.sample_action(&state, seat, &legal_actions, rng)
+                } else {
+                    None
+                }
+            }
+            None => None,
+        };
+        let action = match chosen.filter(|action| legal_actions.contains(action)) {
+            Some(action) => action,
+            None => self.sample_model_action(player_id, hand, context, &legal_actions, rng),
+        };
+
+        self.to_tournament_action(hand, seat, action, context, rng)
+    }
+
+    /// Sample a legal abstract action from the opponent model's fold/call/raise prediction
+    fn sample_model_action<R: Rng>(
+        &self,
+        player_id: u32,
+        hand: &HoldemState,
+        context: &ActionContext,
+        legal_actions: &[HoldemAct],
+        rng: &mut R,
+    ) -> HoldemAct {
         let model = self
             .opponent_models
             .get(&player_id)
             .cloned()
             .unwrap_or_else(|| OpponentModel::new(player_id));
+        let [fold, call, raise] = match model.predict_action_distribution(context)[..] {
+            [fold, call, raise] => [fold, call, raise],
+            _ => [1.0, 0.0, 0.0],
+        };
 
-        let action_probabilities = model.predict_action_distribution(context);
-
-        // Select action based on probabilities
-        let random_value: f64 = rand::random();
-        let mut cumulative_prob = 0.0;
-
-        for (i, &prob) in action_probabilities.iter().enumerate() {
-            cumulative_prob += prob;
-            if random_value <= cumulative_prob {
-                return match i {
-                    0 => TournamentAction::Fold,
-                    1 => TournamentAction::Call,
-                    2 => {
-                        // Determine raise size based on context
-                        let raise_size = self.calculate_appropriate_raise_size(context);
-                        if context.stack_ratio < 0.15 && rand::random::<f64>() < 0.3 {
-                            TournamentAction::AllIn
-                        } else {
-                            TournamentAction::Raise(raise_size)
-                        }
-                    }
-                    _ => TournamentAction::Fold,
-                };
+        // Nobody folds when checking is free
+        let seat = hand.to_act.min(5);
+        let free_check = hand.to_call <= hand.invested[seat];
+        let weights: Vec<f64> = legal_actions
+            .iter()
+            .map(|action| match action {
+                HoldemAct::Fold if free_check => 0.0,
+                HoldemAct::Fold => fold,
+                HoldemAct::Call if free_check || !legal_actions.contains(&HoldemAct::Fold) => call + fold,
+                HoldemAct::Call => call,
+                HoldemAct::Raise(_) => raise,
+            })
+            .collect();
+
+        let total: f64 = weights.iter().sum();
+        if !(total > 0.0 && total.is_finite()) {
+            return legal_actions[0];
+        }
+        let mut roll = rng.gen::<f64>() * total;
+        for (&action, &weight) in legal_actions.iter().zip(&weights) {
+            if roll < weight {
+                return action;
             }
+            roll -= weight;
         }
-
-        TournamentAction::Fold
+        *legal_actions.last().expect("액션이 하나 이상 필요")
     }
 
-    /// Calculate appropriate raise size based on tournament context
-    fn calculate_appropriate_raise_size(&self, context: &ActionContext) -> u32 {
-        let (_, bb, _) = self.tournament_state.current_blinds();
-
-        if context.is_preflop {
-            // Preflop raise sizing
-            if context.near_bubble {
-                bb * 2 // Smaller raises near bubble
-            } else {
-                (bb as f64 * 2.5) as u32 // Standard 2.5x raise
+    /// Chips for an abstract action given the seat's stack and the hand's pot
+    fn to_tournament_action<R: Rng>(
+        &self,
+        hand: &HoldemState,
+        seat: usize,
+        action: HoldemAct,
+        context: &ActionContext,
+        rng: &mut R,
+    ) -> TournamentAction {
+        let stack = hand.stack[seat];
+        let invested = hand.invested[seat];
+        match action {
+            HoldemAct::Fold => TournamentAction::Fold,
+            HoldemAct::Call if hand.to_call.saturating_sub(invested) >= stack && stack > 0 => TournamentAction::AllIn,
+            HoldemAct::Call => TournamentAction::Call,
+            HoldemAct::Raise(_) => {
+                let Some(bounds) = hand.raise_bounds() else {
+                    // No full raise left: the raise bucket only shoves what the stack has
+                    return TournamentAction::AllIn;
+                };
+                // Short stacks sometimes just move in
+                let raise_to = if context.stack_ratio < 0.15 && rng.gen::<f64>() < 0.3 {
+                    bounds.max_raise_to
+                } else {
+                    self.calculate_appropriate_raise_size(hand, seat, context)
+                        .clamp(bounds.min_raise_to, bounds.max_raise_to)
+                };
+                let chips = raise_to.saturating_sub(invested);
+                if chips >= stack {
+                    TournamentAction::AllIn
+                } else {
+                    TournamentAction::Raise(chips)
+                }
             }
+        }
+    }
+
+    /// Raise-to amount (this street's total investment) from the hand's pot and current bet
+    fn calculate_appropriate_raise_size(&self, hand: &HoldemState, seat: usize, context: &ActionContext) -> u32 {
+        if hand.street == 0 {
+            // Preflop raise sizing: multiple of the current bet
+            let multiplier = if context.near_bubble { 2.0 } else { 2.5 }; // Smaller raises near bubble
+            (hand.to_call.max(hand.big_blind) as f64 * multiplier) as u32
         } else {
-            // Postflop raise sizing (pot-based)
+            // Postflop raise sizing: fraction of the pot after calling
+            let call_amount = hand.to_call.saturating_sub(hand.invested[seat]);
             let pot_fraction = if context.near_bubble { 0.5 } else { 0.75 };
-            ((bb * 10) as f64 * pot_fraction) as u32 // Estimate pot size
+            hand.to_call + ((hand.pot + call_amount) as f64 * pot_fraction) as u32
         }
     }
 
@@ -1049,6 +1273,20 @@ pub struct MTTManager {
     pub tables: Vec<MTTTable>,
     pub tournament_state: TournamentState,
     pub balancing_algorithm: BalancingAlgorithm,
+    /// Finishing places in elimination order (the winner is recorded last)
+    pub finishes: Vec<TournamentFinish>,
+    /// Events not yet taken with [`MTTManager::drain_events`]
+    events: Vec<TournamentEvent>,
+}
+
+/// Finishing place and prize of a player who busted or won
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TournamentFinish {
+    pub player_id: u32,
+    /// 1 for the winner
+    pub position: u32,
+    /// Payout for the place (0 outside the money)
+    pub payout: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -1124,48 +1362,89 @@ impl MTTManager {
             tables,
             tournament_state,
             balancing_algorithm: BalancingAlgorithm::StandardBalancing,
+            finishes: Vec::new(),
+            events: Vec::new(),
         }
     }
 
     /// Balance tables by moving players
+    ///
+    /// Queues a [`TournamentEvent::TableBroken`] for every table closed and a
+    /// [`TournamentEvent::FinalTableFormed`] when the field comes down to one table.
     pub fn balance_tables(&mut self) {
+        let tables_before = self.tables.len();
         match self.balancing_algorithm {
             BalancingAlgorithm::StandardBalancing => self.standard_table_balancing(),
             BalancingAlgorithm::ChipRaceProtocol => self.handle_chip_race(),
             BalancingAlgorithm::FinalTableConsolidation => self.consolidate_to_final_table(),
         }
+        if let [final_table] = &self.tables[..] {
+            if tables_before > 1 {
+                self.events.push(TournamentEvent::FinalTableFormed {
+                    table_id: final_table.table_id,
+                    players: final_table.seated_players(),
+                });
+            }
+        }
     }
 
     /// Standard table balancing algorithm
+    ///
+    /// Empty tables are closed and, while the seated players fit at fewer
+    /// tables, the shortest table is broken up with its players sent to the
+    /// shortest remaining tables. Players are then moved from the fullest
+    /// table to the shortest until no two tables differ by more than one.
     fn standard_table_balancing(&mut self) {
-        let total_active_players = self.count_active_players();
-        let target_players_per_table =
-            (total_active_players + self.tables.len() as u32 - 1) / self.tables.len() as u32;
-
-        // Find tables that need balancing
-        let mut moves = Vec::new();
-
-        for (table_idx, table) in self.tables.iter().enumerate() {
-            let active_players = table.count_active_players();
-
-            if active_players > target_players_per_table + 1 {
-                // Table has too many players, find someone to move
-                if let Some(player_to_move) = self.find_player_to_move(table_idx) {
-                    if let Some(destination_table) =
-                        self.find_destination_table(target_players_per_table)
-                    {
-                        moves.push((table_idx, player_to_move, destination_table));
-                    }
+        let (open, empty) = std::mem::take(&mut self.tables)
+            .into_iter()
+            .partition(|table| table.seated_players() > 0);
+        self.tables = open;
+        for table in empty {
+            self.events.push(TournamentEvent::TableBroken { table_id: table.table_id });
+        }
+        let max_seats = self.tables.iter().map(|table| table.seats.len() as u32).max().unwrap_or(1).max(1);
+
+        loop {
+            let seated: u32 = self.tables.iter().map(MTTTable::seated_players).sum();
+            let tables_needed = seated.div_ceil(max_seats).max(1) as usize;
+            if self.tables.len() <= tables_needed {
+                break;
+            }
+
+            let Some(shortest) = self.shortest_table(false) else {
+                break;
+            };
+            let broken = self.tables.remove(shortest);
+            self.events.push(TournamentEvent::TableBroken { table_id: broken.table_id });
+            for player in broken.seats.into_iter().flatten() {
+                if let Some(destination) = self.shortest_table(true) {
+                    self.tables[destination].seat_player(player);
                 }
             }
         }
 
-        // Execute moves
-        for (source_table, player_pos, dest_table) in moves {
-            self.move_player(source_table, player_pos, dest_table);
+        while let Some(shortest) = self.shortest_table(true) {
+            let Some(fullest) = (0..self.tables.len()).max_by_key(|&idx| self.tables[idx].seated_players()) else {
+                break;
+            };
+            if self.tables[fullest].seated_players() <= self.tables[shortest].seated_players() + 1 {
+                break;
+            }
+            let Some(player_pos) = self.find_player_to_move(fullest) else {
+                break;
+            };
+            self.move_player(fullest, player_pos, shortest);
         }
     }
 
+    /// Index of the table with the fewest seated players
+    /// (only tables with an empty seat when `with_empty_seat` is set)
+    fn shortest_table(&self, with_empty_seat: bool) -> Option<usize> {
+        (0..self.tables.len())
+            .filter(|&idx| !with_empty_seat || self.tables[idx].seats.iter().any(Option::is_none))
+            .min_by_key(|&idx| self.tables[idx].seated_players())
+    }
+
     /// Handle chip race during color-ups
     fn handle_chip_race(&mut self) {
         // Implementation for chip race protocol when removing lower denomination chips
@@ -1184,7 +1463,8 @@ impl MTTManager {
 
     /// Consolidate remaining players to final table
     fn consolidate_to_final_table(&mut self) {
-        if self.count_active_players() <= 9 {
+        let max_seats = self.tables.iter().map(|table| table.max_seats).max().unwrap_or(9);
+        if self.count_active_players() <= max_seats {
             let mut final_table_players = Vec::new();
 
             // Collect all remaining players
@@ -1199,11 +1479,13 @@ impl MTTManager {
             }
 
             // Create single final table
-            self.tables.clear();
+            for table in self.tables.drain(..) {
+                self.events.push(TournamentEvent::TableBroken { table_id: table.table_id });
+            }
             let mut final_table = MTTTable {
                 table_id: 999,
-                seats: vec![None; 9],
-                max_seats: 9,
+                seats: vec![None; max_seats as usize],
+                max_seats,
                 current_hand: 1,
                 button_position: 1,
             };
@@ -1212,7 +1494,7 @@ impl MTTManager {
             final_table_players.sort_by(|a, b| b.stack_size.cmp(&a.stack_size));
 
             for (i, mut player) in final_table_players.into_iter().enumerate() {
-                if i < 9 {
+                if i < max_seats as usize {
                     player.position = i as u32;
                     final_table.seats[i] = Some(player);
                 }
@@ -1246,46 +1528,90 @@ impl MTTManager {
         None
     }
 
-    fn find_destination_table(&self, target_players: u32) -> Option<usize> {
-        self.tables
-            .iter()
-            .enumerate()
-            .find(|(_, table)| table.count_active_players() < target_players)
-            .map(|(idx, _)| idx)
-    }
-
     fn move_player(&mut self, source_table: usize, player_pos: u32, dest_table: usize) {
         if let Some(player) = self.tables[source_table].seats[player_pos as usize].take() {
-            // Find empty seat at destination table
-            if let Some(empty_seat) = self.tables[dest_table]
-                .seats
-                .iter_mut()
-                .find(|seat| seat.is_none())
-            {
-                *empty_seat = Some(player);
-            }
+            self.tables[dest_table].seat_player(player);
         }
     }
 
     /// Eliminate player and update tournament state
+    ///
+    /// The player leaves their seat and the finishing place is recorded in
+    /// [`MTTManager::finishes`] together with its payout. When only one player
+    /// is left they are recorded as the winner. Unknown or already eliminated
+    /// players are ignored. Players busting in the same hand should go through
+    /// [`MTTManager::settle_hand`] instead so their places are tie-broken.
     pub fn eliminate_player(&mut self, table_id: u32, player_id: u32) {
-        for table in &mut self.tables {
-            if table.table_id == table_id {
-                for seat in &mut table.seats {
-                    if let Some(ref mut player) = seat {
-                        if player.player_id == player_id {
-                            player.stack_size = 0;
-                            player.is_sitting_out = true;
-                            self.tournament_state.players_remaining -= 1;
-
-                            // Check if table needs balancing after elimination
-                            if table.count_active_players() <= table.max_seats / 2 {
-                                self.balance_tables();
-                            }
-                            return;
-                        }
-                    }
-                }
+        if let Some(player) = self.take_seat(table_id, player_id) {
+            self.record_eliminations(vec![(player_id, player.stack_size)]);
+        }
+    }
+
+    /// Remove a player from their seat at `table_id`
+    fn take_seat(&mut self, table_id: u32, player_id: u32) -> Option<MTTPlayer> {
+        self.tables
+            .iter_mut()
+            .filter(|table| table.table_id == table_id)
+            .flat_map(|table| table.seats.iter_mut())
+            .find(|seat| seat.as_ref().is_some_and(|player| player.player_id == player_id))
+            .and_then(Option::take)
+    }
+
+    /// Record players who busted in the same hand and were already unseated
+    ///
+    /// `busted` holds each player's id and stack at the start of the hand. The
+    /// player who started the hand with more chips finishes higher. Players who
+    /// started with the same stack tie for the best of their places and split
+    /// the payouts for those places, odd chips going to the lowest player id.
+    fn record_eliminations(&mut self, mut busted: Vec<(u32, u32)>) {
+        if busted.is_empty() {
+            return;
+        }
+        // Worst finisher first, matching the elimination order of `finishes`
+        busted.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+
+        let remaining = self.tournament_state.players_remaining;
+        let mut worst = remaining;
+        for group in busted.chunk_by(|a, b| a.1 == b.1) {
+            let count = group.len() as u32;
+            let position = (worst + 1).saturating_sub(count).max(1);
+            let pool: u64 = (position..=worst)
+                .map(|place| self.tournament_state.payout_for_position(place))
+                .sum();
+            for (idx, &(player_id, _)) in group.iter().enumerate() {
+                let payout = pool / count as u64 + u64::from((idx as u64) < pool % count as u64);
+                self.finishes.push(TournamentFinish {
+                    player_id,
+                    position,
+                    payout,
+                });
+                self.events.push(TournamentEvent::PlayerEliminated {
+                    player: player_id,
+                    finishing_position: position,
+                    payout,
+                });
+            }
+            worst = position.saturating_sub(1);
+        }
+        self.tournament_state.players_remaining = remaining.saturating_sub(busted.len() as u32);
+
+        let paid = self.tournament_state.payout_structure.len() as u32;
+        if remaining > paid && self.tournament_state.players_remaining <= paid {
+            self.events.push(TournamentEvent::BubbleBurst {
+                players_remaining: self.tournament_state.players_remaining,
+            });
+        }
+
+        self.balance_tables();
+
+        if self.tournament_state.players_remaining == 1 {
+            let winner = self.tables.iter().flat_map(|table| table.seats.iter().flatten()).next();
+            if let Some(winner) = winner {
+                self.finishes.push(TournamentFinish {
+                    player_id: winner.player_id,
+                    position: 1,
+                    payout: self.tournament_state.payout_for_position(1),
+                });
             }
         }
     }
@@ -1312,6 +1638,23 @@ impl MTTManager {
 }
 
 impl MTTTable {
+    /// Number of occupied seats, including players sitting out
+    pub fn seated_players(&self) -> u32 {
+        self.seats.iter().filter(|seat| seat.is_some()).count() as u32
+    }
+
+    /// Seat a player in the first empty seat, returning false if the table is full
+    pub fn seat_player(&mut self, mut player: MTTPlayer) -> bool {
+        match self.seats.iter().position(Option::is_none) {
+            Some(seat) => {
+                player.position = seat as u32;
+                self.seats[seat] = Some(player);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn count_active_players(&self) -> u32 {
         self.seats
             .iter()
@@ -1511,6 +1854,7 @@ mod tests {
             level_duration_minutes: 15,
             starting_stack: 1500,
             ante_schedule: vec![AnteLevel { level: 3, ante: 10 }],
+            ante_type: AnteType::PerPlayer,
         };
 
         let tournament = TournamentState::new(structure, 9, 10000);
@@ -1520,6 +1864,24 @@ mod tests {
         assert_eq!(tournament.current_level, 1);
     }
 
+    #[test]
+    fn test_big_blind_ante_structure_deals_holdem_hand() {
+        let json = r#"{
+            "levels": [{"level": 1, "small_blind": 100, "big_blind": 200, "ante": 200}],
+            "level_duration_minutes": 20,
+            "starting_stack": 10000,
+            "ante_schedule": []
+        }"#;
+        let mut structure: TournamentStructure = serde_json::from_str(json).unwrap();
+        assert_eq!(structure.ante_type, AnteType::PerPlayer);
+
+        structure.ante_type = AnteType::BigBlindAnte;
+        let tournament = TournamentState::new(structure, 6, 1000);
+        let hand = tournament.new_holdem_hand([10000; 6], 6);
+        assert_eq!(hand.antes, [0, 0, 0, 0, 0, 200]);
+        assert_eq!(hand.pot, 500);
+    }
+
     #[test]
     fn test_tournament_evaluator() {
         let structure = TournamentStructure {
@@ -1532,6 +1894,7 @@ mod tests {
             level_duration_minutes: 15,
             starting_stack: 1500,
             ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
         };
 
         let tournament_state = TournamentState::new(structure, 6, 5000);
@@ -1559,6 +1922,7 @@ mod tests {
             level_duration_minutes: 15,
             starting_stack: 1500,
             ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
         };
 
         let mtt = MTTManager::new(27, 9, structure, 50000);
@@ -1578,6 +1942,55 @@ mod tests {
         assert!(mtt.tables[2].count_active_players() <= 9);
     }
 
+    #[test]
+    fn test_eliminations_break_tables_and_record_finishes() {
+        let structure = TournamentStructure {
+            levels: vec![BlindLevel {
+                level: 1,
+                small_blind: 25,
+                big_blind: 50,
+                ante: 0,
+            }],
+            level_duration_minutes: 15,
+            starting_stack: 1500,
+            ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
+        };
+        let mut mtt = MTTManager::new(30, 6, structure, 30000);
+
+        // Knock out one player per table: 25 players no longer need a fifth table
+        for table_id in 0..5 {
+            let player_id = table_id * 6 + 1;
+            mtt.eliminate_player(table_id, player_id);
+        }
+        assert_eq!(mtt.tables.len(), 5);
+        mtt.eliminate_player(0, 2);
+        assert_eq!(mtt.tables.len(), 4);
+        let counts: Vec<u32> = mtt.tables.iter().map(MTTTable::seated_players).collect();
+        assert!(counts.iter().all(|&count| count == 6), "{:?}", counts);
+        for table in &mtt.tables {
+            for (seat, player) in table.seats.iter().enumerate() {
+                assert_eq!(player.as_ref().unwrap().position, seat as u32);
+            }
+        }
+
+        // Eliminating the same player twice is ignored
+        mtt.eliminate_player(0, 2);
+        assert_eq!(mtt.tournament_state.players_remaining, 24);
+        assert_eq!(mtt.finishes.len(), 6);
+        assert_eq!(mtt.finishes[5].position, 25);
+
+        while let [_, .., (player_id, _, table_id)] = mtt.get_tournament_standings()[..] {
+            mtt.eliminate_player(table_id, player_id);
+        }
+        assert_eq!(mtt.tables.len(), 1);
+        assert_eq!(mtt.finishes.len(), 30);
+        assert_eq!(mtt.finishes.last().unwrap().position, 1);
+        let paid: u64 = mtt.finishes.iter().map(|finish| finish.payout).sum();
+        let structure_total: u64 = mtt.tournament_state.payout_structure.iter().map(|level| level.amount).sum();
+        assert_eq!(paid, structure_total);
+    }
+
     #[test]
     fn test_tournament_action_evaluation() {
         let _context = ActionContext {
@@ -1610,6 +2023,151 @@ mod tests {
         }
     }
 
+    fn opponent_evaluator() -> TournamentEvaluator {
+        let structure = TournamentStructure {
+            levels: vec![BlindLevel {
+                level: 1,
+                small_blind: 50,
+                big_blind: 100,
+                ante: 0,
+            }],
+            level_duration_minutes: 15,
+            starting_stack: 1500,
+            ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
+        };
+        TournamentEvaluator::new(TournamentState::new(structure, 6, 5000), vec![1500, 1200, 1800, 900, 2100, 1000])
+    }
+
+    fn aggressive_context(stack_ratio: f64, is_preflop: bool) -> ActionContext {
+        ActionContext {
+            stack_ratio,
+            pot_odds: 0.3,
+            is_preflop,
+            near_bubble: false,
+            position: Position::Button,
+            num_opponents: 2,
+        }
+    }
+
+    /// 프리플랍부터 리버까지 스택이 다양한 헤즈업/3인 핸드
+    fn opponent_spots() -> Vec<HoldemState> {
+        use crate::solver::cfr_core::GameState;
+        use rand::SeedableRng;
+
+        let mut spots = Vec::new();
+        for (i, stack) in [90, 240, 1000, 5000].into_iter().enumerate() {
+            let mut state = HoldemState::new_hand([50, 100], [stack, stack + 300, stack / 2 + 60, 0, 0, 0], 2 + i % 2);
+            let mut rng = rand::rngs::StdRng::seed_from_u64(stack as u64);
+            while !state.is_terminal() && spots.len() < 64 {
+                if state.is_chance_node() {
+                    state = HoldemState::apply_chance(&state, &mut rng);
+                    continue;
+                }
+                spots.push(state.clone());
+                state = HoldemState::next_state(&state, HoldemAct::Call);
+            }
+        }
+        spots
+    }
+
+    struct AlwaysFold;
+
+    impl ActionPolicy for AlwaysFold {
+        fn choose_action(&mut self, _observed: &crate::game::observation::ObservedState, legal_actions: &[HoldemAct]) -> HoldemAct {
+            assert!(legal_actions.contains(&HoldemAct::Fold));
+            HoldemAct::Fold
+        }
+    }
+
+    #[test]
+    fn test_opponent_action_selection_is_seedable() {
+        use rand::SeedableRng;
+
+        let evaluator = opponent_evaluator();
+        let spots = opponent_spots();
+        let run = |seed: u64| -> Vec<TournamentAction> {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            spots
+                .iter()
+                .map(|hand| {
+                    let context = aggressive_context(0.5, hand.street == 0);
+                    evaluator.select_opponent_action(1, hand, &context, None, &mut rng)
+                })
+                .collect()
+        };
+        assert_eq!(run(2431), run(2431));
+        assert_ne!(run(2431), run(2432));
+
+        // 공급한 정책이 모델보다 우선
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        for hand in &spots {
+            let action = evaluator.select_opponent_action(
+                1,
+                hand,
+                &aggressive_context(0.5, hand.street == 0),
+                Some(OpponentStrategy::Policy(&mut AlwaysFold)),
+                &mut rng,
+            );
+            assert_eq!(action, TournamentAction::Fold);
+        }
+
+        // 학습되지 않은 정보 집합에서는 트레이너 대신 모델로 돌아감
+        let trainer = TournamentCFRTrainer::new(evaluator.tournament_state.clone(), vec![1000, 1000]);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(2431);
+        let fallback: Vec<TournamentAction> = spots
+            .iter()
+            .map(|hand| {
+                let context = aggressive_context(0.5, hand.street == 0);
+                evaluator.select_opponent_action(1, hand, &context, Some(OpponentStrategy::Trainer(&trainer)), &mut rng)
+            })
+            .collect();
+        assert_eq!(fallback, run(2431));
+    }
+
+    #[test]
+    fn test_selected_opponent_action_fits_the_stack() {
+        use rand::SeedableRng;
+
+        let mut evaluator = opponent_evaluator();
+        // 레이즈를 자주 고르는 공격적인 상대
+        let mut maniac = OpponentModel::new(1);
+        maniac.aggression = 4.0;
+        maniac.tightness = 0.1;
+        evaluator.opponent_models.insert(1, maniac);
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(99);
+        let mut raises = 0;
+        for hand in opponent_spots() {
+            let seat = hand.to_act;
+            let stack = hand.stack[seat];
+            for stack_ratio in [0.05, 0.5, 2.0] {
+                let context = aggressive_context(stack_ratio, hand.street == 0);
+                match evaluator.select_opponent_action(1, &hand, &context, None, &mut rng) {
+                    TournamentAction::Raise(chips) => {
+                        raises += 1;
+                        assert!(chips < stack, "{} >= {} in {:?}", chips, stack, hand);
+                        // 최소 레이즈 이상
+                        assert!(hand.invested[seat] + chips >= hand.min_raise_size().min(hand.invested[seat] + stack));
+                    }
+                    TournamentAction::Fold => assert!(hand.to_call > hand.invested[seat], "체크가 공짜인데 폴드"),
+                    TournamentAction::Call | TournamentAction::AllIn => {}
+                }
+            }
+        }
+        assert!(raises > 0);
+
+        // 포스트플랍 레이즈는 실제 팟 기준 (75% 팟 베팅)
+        let mut flop = HoldemState::new_hand([50, 100], [5000; 6], 2);
+        flop = HoldemState::next_state(&flop, HoldemAct::Call);
+        flop = HoldemState::next_state(&flop, HoldemAct::Call);
+        let flop = HoldemState::apply_chance(&flop, &mut rng);
+        assert_eq!(flop.street, 1);
+        let seat = flop.to_act;
+        let raise_to = evaluator.calculate_appropriate_raise_size(&flop, seat, &aggressive_context(1.0, false));
+        assert_eq!(raise_to, flop.pot * 3 / 4);
+    }
+
     #[test]
     fn test_opponent_model() {
         let mut model = OpponentModel::new(1);
@@ -0,0 +1,169 @@
+//! Overflow-safe chip and prize arithmetic
+//!
+//! Stacks are `u32` and prizes `u64`, but big online tournaments reach
+//! billions of chips, so summing a handful of stacks in `u32` (or a prize pool
+//! in `u64`) can overflow. Every chip total in the tournament math goes through
+//! [`total_chips`] (a `u64` sum) and every probability is computed from
+//! [`chip_shares`], which normalizes by the total first so that only the ratio
+//! between stacks matters, never their absolute size.
+//!
+//! # Examples
+//!
+//! ```
+//! use nice_hand_core::game::tournament::chips::{chip_shares, total_chips};
+//!
+//! let stacks = [u32::MAX; 6];
+//! assert_eq!(total_chips(&stacks), 6 * u32::MAX as u64);
+//! assert!(chip_shares(&stacks).iter().all(|&share| (share - 1.0 / 6.0).abs() < 1e-12));
+//! ```
+
+use std::fmt;
+
+/// A chip or prize amount that does not fit the type it has to be stored in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChipMathError {
+    /// A stack would exceed `u32::MAX` chips
+    StackOverflow { chips: u64 },
+    /// The prize pool exceeds `u64::MAX`
+    PrizePoolOverflow,
+}
+
+impl fmt::Display for ChipMathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StackOverflow { chips } => write!(f, "stack of {} chips does not fit in u32", chips),
+            Self::PrizePoolOverflow => write!(f, "prize pool does not fit in u64"),
+        }
+    }
+}
+
+impl std::error::Error for ChipMathError {}
+
+/// Sum of the stacks; a `u64` cannot overflow for any realistic field
+pub fn total_chips(stacks: &[u32]) -> u64 {
+    stacks.iter().map(|&stack| stack as u64).sum()
+}
+
+/// Each stack as a fraction of the chips in play, summing to 1
+///
+/// With no chips in play every player gets an equal share.
+pub fn chip_shares(stacks: &[u32]) -> Vec<f64> {
+    let total = total_chips(stacks);
+    if total == 0 {
+        return vec![1.0 / stacks.len().max(1) as f64; stacks.len()];
+    }
+    stacks.iter().map(|&stack| stack as f64 / total as f64).collect()
+}
+
+/// The prize pool as `f64`, summed without integer overflow
+pub fn prize_pool(payouts: &[u64]) -> f64 {
+    payouts.iter().map(|&payout| payout as f64).sum()
+}
+
+/// The exact prize pool, or an error if it does not fit in `u64`
+pub fn checked_prize_pool(payouts: &[u64]) -> Result<u64, ChipMathError> {
+    payouts
+        .iter()
+        .try_fold(0u64, |total, &payout| total.checked_add(payout))
+        .ok_or(ChipMathError::PrizePoolOverflow)
+}
+
+/// Convert a chip amount back to a `u32` stack
+pub fn checked_stack(chips: u64) -> Result<u32, ChipMathError> {
+    u32::try_from(chips).map_err(|_| ChipMathError::StackOverflow { chips })
+}
+
+/// `stack` changed by `delta` chips, clamped to `0..=u32::MAX`
+pub fn adjust_stack(stack: u32, delta: i64) -> u32 {
+    (stack as i64).saturating_add(delta).clamp(0, u32::MAX as i64) as u32
+}
+
+/// Stacks computed in `u64`, scaled down together if the largest does not fit in `u32`
+///
+/// ICM depends only on the ratio between stacks, so the scaled stacks give the
+/// same equities up to rounding.
+pub fn fit_stacks(stacks: &[u64]) -> Vec<u32> {
+    let largest = stacks.iter().copied().max().unwrap_or(0);
+    if checked_stack(largest).is_ok() {
+        return stacks.iter().map(|&stack| stack as u32).collect();
+    }
+    let scale = u32::MAX as f64 / largest as f64;
+    stacks.iter().map(|&stack| (stack as f64 * scale) as u32).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tournament::deals::icm_deal;
+    use crate::game::tournament::ICMCalculator;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn assert_close(actual: f64, expected: f64, context: &str) {
+        assert!(
+            (actual - expected).abs() <= expected.abs() * 1e-9 + 1e-6,
+            "{}: {} vs {}",
+            context,
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_extreme_values_give_finite_equities_summing_to_pool() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for case in 0..300 {
+            let players = rng.gen_range(1..=9);
+            let stacks: Vec<u32> = (0..players)
+                .map(|_| if rng.gen_bool(0.7) { rng.gen_range(u32::MAX - 1000..=u32::MAX) } else { rng.gen_range(1..=u32::MAX) })
+                .collect();
+            let places = rng.gen_range(1..=players);
+            let payouts: Vec<u64> = (0..places).map(|_| rng.gen_range(1..=u64::MAX / 1000)).collect();
+            let context = format!("case {}: {:?} {:?}", case, stacks, payouts);
+
+            let pool = prize_pool(&payouts);
+            let equities = ICMCalculator::new(stacks.clone(), payouts.clone()).calculate_equity();
+            assert!(equities.iter().all(|e| e.is_finite() && *e >= 0.0), "{}", context);
+            assert_close(equities.iter().sum(), pool, &context);
+
+            let deal = icm_deal(&stacks, &payouts);
+            assert!(deal.iter().all(|e| e.is_finite()), "{}", context);
+            assert_close(deal.iter().sum(), pool, &context);
+        }
+    }
+
+    #[test]
+    fn test_scaling_stacks_leaves_equities_unchanged() {
+        let mut rng = StdRng::seed_from_u64(12);
+        let payouts = vec![5000, 3000, 2000];
+        for case in 0..200 {
+            let players = rng.gen_range(2..=9);
+            let stacks: Vec<u32> = (0..players).map(|_| rng.gen_range(1..=u32::MAX / 1000)).collect();
+            let scale = rng.gen_range(2..=1000);
+            let scaled: Vec<u32> = stacks.iter().map(|&s| s * scale).collect();
+            let context = format!("case {}: {:?} x{}", case, stacks, scale);
+
+            let base = ICMCalculator::new(stacks.clone(), payouts.clone()).calculate_equity();
+            let bigger = ICMCalculator::new(scaled.clone(), payouts.clone()).calculate_equity();
+            for (a, b) in base.iter().zip(&bigger) {
+                assert_close(*b, *a, &context);
+            }
+            assert_eq!(chip_shares(&stacks).len(), players);
+            for (a, b) in icm_deal(&stacks, &payouts).iter().zip(&icm_deal(&scaled, &payouts)) {
+                assert!((a - b).abs() <= 1.0, "{}: {} vs {}", context, a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_checked_conversions() {
+        assert_eq!(total_chips(&[u32::MAX; 6]), 6 * u32::MAX as u64);
+        assert_eq!(chip_shares(&[0, 0]), vec![0.5, 0.5]);
+        assert_eq!(checked_prize_pool(&[u64::MAX / 2, u64::MAX / 2]), Ok(u64::MAX - 1));
+        assert_eq!(checked_prize_pool(&[u64::MAX, 1]), Err(ChipMathError::PrizePoolOverflow));
+        assert_eq!(checked_stack(u32::MAX as u64 + 1), Err(ChipMathError::StackOverflow { chips: 1 << 32 }));
+        assert_eq!(adjust_stack(u32::MAX - 5, 10), u32::MAX);
+        assert_eq!(adjust_stack(5, -10), 0);
+        assert_eq!(fit_stacks(&[u32::MAX as u64 * 2, u32::MAX as u64]), vec![u32::MAX, u32::MAX / 2]);
+    }
+}
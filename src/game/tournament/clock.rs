@@ -0,0 +1,339 @@
+//! Live tournament clock
+//!
+//! [`TournamentClock`] maps wall-clock time onto a [`TournamentState`]: it
+//! advances blind levels, inserts scheduled breaks and fires registered
+//! callbacks when a level changes, a break starts or the bubble is reached.
+//!
+//! The clock never spawns threads or reads the system time itself. The host
+//! application calls [`TournamentClock::tick`] with the current time, which
+//! keeps it usable from WASM and makes it fully deterministic in tests.
+//!
+//! # Examples
+//!
+//! ```
+//! use nice_hand_core::game::tournament::clock::TournamentClock;
+//! use nice_hand_core::game::tournament::{AnteType, BlindLevel, TournamentState, TournamentStructure};
+//! use std::time::{Duration, Instant};
+//!
+//! let structure = TournamentStructure {
+//!     levels: vec![
+//!         BlindLevel { level: 1, small_blind: 25, big_blind: 50, ante: 0 },
+//!         BlindLevel { level: 2, small_blind: 50, big_blind: 100, ante: 0 },
+//!     ],
+//!     level_duration_minutes: 15,
+//!     starting_stack: 1500,
+//!     ante_schedule: vec![],
+//!     ante_type: AnteType::PerPlayer,
+//! };
+//! let mut clock = TournamentClock::new(TournamentState::new(structure, 50, 5000));
+//! clock.on_level_change(|state| println!("Level {}", state.current_level));
+//!
+//! let start = Instant::now();
+//! clock.start(start);
+//! clock.tick(start + Duration::from_secs(16 * 60));
+//! assert_eq!(clock.state().current_level, 2);
+//! ```
+
+use super::TournamentState;
+use std::time::{Duration, Instant};
+
+const MINUTE: Duration = Duration::from_secs(60);
+
+/// Breaks taken after every `every_levels` levels, lasting `minutes` each
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BreakSchedule {
+    pub every_levels: u32,
+    pub minutes: u32,
+}
+
+type Callback = Box<dyn FnMut(&TournamentState)>;
+
+/// Pollable tournament clock driving a [`TournamentState`]
+///
+/// Paused time does not count toward level or break time. A break scheduled
+/// after level `n` starts when level `n` ends and level `n + 1` begins when the
+/// break is over, so `current_level` stays at `n` during the break.
+pub struct TournamentClock {
+    state: TournamentState,
+    breaks: Option<BreakSchedule>,
+    /// Time of the last tick while running (`None` when stopped or paused)
+    last_tick: Option<Instant>,
+    /// Play time in the current level
+    level_elapsed: Duration,
+    /// Whole minutes of the current level already reported to the state
+    level_minutes_reported: u32,
+    /// Time left in the current break
+    break_remaining: Option<Duration>,
+    bubble_reached: bool,
+    level_change_callbacks: Vec<Callback>,
+    break_start_callbacks: Vec<Callback>,
+    bubble_callbacks: Vec<Callback>,
+}
+
+impl TournamentClock {
+    /// Create a stopped clock for `state`
+    pub fn new(state: TournamentState) -> Self {
+        Self {
+            state,
+            breaks: None,
+            last_tick: None,
+            level_elapsed: Duration::ZERO,
+            level_minutes_reported: 0,
+            break_remaining: None,
+            bubble_reached: false,
+            level_change_callbacks: Vec::new(),
+            break_start_callbacks: Vec::new(),
+            bubble_callbacks: Vec::new(),
+        }
+    }
+
+    /// Take a break of `minutes` after every `every_levels` levels
+    pub fn with_breaks(mut self, every_levels: u32, minutes: u32) -> Self {
+        self.breaks = (every_levels > 0).then_some(BreakSchedule { every_levels, minutes });
+        self
+    }
+
+    /// Register a callback fired after the level changes
+    pub fn on_level_change(&mut self, callback: impl FnMut(&TournamentState) + 'static) {
+        self.level_change_callbacks.push(Box::new(callback));
+    }
+
+    /// Register a callback fired when a scheduled break starts
+    pub fn on_break_start(&mut self, callback: impl FnMut(&TournamentState) + 'static) {
+        self.break_start_callbacks.push(Box::new(callback));
+    }
+
+    /// Register a callback fired once when the field reaches the bubble
+    /// (one player away from the money)
+    pub fn on_bubble_reached(&mut self, callback: impl FnMut(&TournamentState) + 'static) {
+        self.bubble_callbacks.push(Box::new(callback));
+    }
+
+    /// Tournament state driven by the clock
+    pub fn state(&self) -> &TournamentState {
+        &self.state
+    }
+
+    /// Whether the clock is counting time
+    pub fn is_running(&self) -> bool {
+        self.last_tick.is_some()
+    }
+
+    /// Whether a scheduled break is in progress
+    pub fn is_on_break(&self) -> bool {
+        self.break_remaining.is_some()
+    }
+
+    /// Play time left in the current level (or time left in the break)
+    pub fn time_remaining(&self) -> Duration {
+        match self.break_remaining {
+            Some(remaining) => remaining,
+            None => self.level_duration().saturating_sub(self.level_elapsed),
+        }
+    }
+
+    /// Start counting time from `now`
+    pub fn start(&mut self, now: Instant) {
+        if self.last_tick.is_none() {
+            self.last_tick = Some(now);
+        }
+    }
+
+    /// Stop counting time, accounting for everything up to `now`
+    pub fn pause(&mut self, now: Instant) {
+        self.tick(now);
+        self.last_tick = None;
+    }
+
+    /// Continue counting time from `now`
+    pub fn resume(&mut self, now: Instant) {
+        self.start(now);
+    }
+
+    /// Advance the clock to `now` and fire callbacks for every threshold crossed
+    pub fn tick(&mut self, now: Instant) {
+        let Some(last) = self.last_tick else {
+            return;
+        };
+        self.last_tick = Some(now.max(last));
+        let mut delta = now.saturating_duration_since(last);
+
+        while !delta.is_zero() {
+            if let Some(remaining) = self.break_remaining {
+                if delta < remaining {
+                    self.break_remaining = Some(remaining - delta);
+                    break;
+                }
+                delta -= remaining;
+                self.break_remaining = None;
+                self.finish_level();
+                continue;
+            }
+
+            let remaining = self.level_duration().saturating_sub(self.level_elapsed);
+            if self.is_final_level() || delta < remaining {
+                self.level_elapsed += delta;
+                self.report_level_minutes();
+                break;
+            }
+
+            delta -= remaining;
+            self.level_elapsed = self.level_duration();
+            match self.breaks {
+                Some(schedule)
+                    if schedule.minutes > 0 && self.state.current_level.is_multiple_of(schedule.every_levels) =>
+                {
+                    self.break_remaining = Some(MINUTE * schedule.minutes);
+                    fire(&mut self.break_start_callbacks, &self.state);
+                }
+                _ => self.finish_level(),
+            }
+        }
+    }
+
+    /// Feed the current number of players left, firing the bubble callbacks
+    /// the first time the field is one player away from the money
+    pub fn set_players_remaining(&mut self, players_remaining: u32) {
+        self.state.players_remaining = players_remaining;
+        let paid = self.state.payout_structure.len() as u32;
+        if !self.bubble_reached && paid > 0 && players_remaining <= paid + 1 {
+            self.bubble_reached = true;
+            fire(&mut self.bubble_callbacks, &self.state);
+        }
+    }
+
+    fn level_duration(&self) -> Duration {
+        MINUTE * self.state.structure.level_duration_minutes
+    }
+
+    /// The last configured level runs until the tournament ends
+    fn is_final_level(&self) -> bool {
+        self.state.structure.level_duration_minutes == 0
+            || self.state.current_level as usize >= self.state.structure.levels.len()
+    }
+
+    /// Report whole minutes played in the current level, holding back the
+    /// level's final minute until the level actually ends
+    fn report_level_minutes(&mut self) {
+        let played = (self.level_elapsed.as_secs() / 60) as u32;
+        let cap = if self.is_final_level() {
+            played
+        } else {
+            self.state.structure.level_duration_minutes - 1
+        };
+        let minutes = played.min(cap);
+        if minutes > self.level_minutes_reported {
+            self.state.advance_time(minutes - self.level_minutes_reported);
+            self.level_minutes_reported = minutes;
+        }
+    }
+
+    /// Move to the next level through `TournamentState::advance_time`
+    fn finish_level(&mut self) {
+        let rest = self.state.structure.level_duration_minutes - self.level_minutes_reported;
+        self.level_elapsed = Duration::ZERO;
+        self.level_minutes_reported = 0;
+        if self.state.advance_time(rest) > 0 {
+            fire(&mut self.level_change_callbacks, &self.state);
+        }
+    }
+}
+
+fn fire(callbacks: &mut [Callback], state: &TournamentState) {
+    for callback in callbacks {
+        callback(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tournament::{AnteType, BlindLevel, TournamentStructure};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn clock() -> TournamentClock {
+        let levels = (1..=4)
+            .map(|level| BlindLevel {
+                level,
+                small_blind: 25 * level,
+                big_blind: 50 * level,
+                ante: 0,
+            })
+            .collect();
+        let structure = TournamentStructure {
+            levels,
+            level_duration_minutes: 15,
+            starting_stack: 1500,
+            ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
+        };
+        // 100 players, top 10 paid
+        TournamentClock::new(TournamentState::new(structure, 100, 10_000)).with_breaks(2, 10)
+    }
+
+    #[test]
+    fn test_callbacks_fire_in_order_and_pauses_do_not_count() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut clock = clock();
+        let log = events.clone();
+        clock.on_level_change(move |state| log.borrow_mut().push(format!("level {}", state.current_level)));
+        let log = events.clone();
+        clock.on_break_start(move |state| log.borrow_mut().push(format!("break after {}", state.current_level)));
+        let log = events.clone();
+        clock.on_bubble_reached(move |state| log.borrow_mut().push(format!("bubble {}", state.players_remaining)));
+
+        let start = Instant::now();
+        let at = |minute: u64| start + MINUTE * minute as u32;
+        clock.start(start);
+        for minute in 1..=50 {
+            match minute {
+                20 => clock.pause(at(minute)),
+                25 => clock.resume(at(minute)),
+                47 => {
+                    clock.tick(at(minute));
+                    clock.set_players_remaining(12);
+                    clock.set_players_remaining(11);
+                    clock.set_players_remaining(10);
+                }
+                _ => clock.tick(at(minute)),
+            }
+
+            // Level 2 ends after 30 minutes of play: wall minute 35 with the 5-minute pause
+            if minute == 34 {
+                assert_eq!(clock.state().current_level, 2);
+                assert!(!clock.is_on_break());
+                assert_eq!(clock.time_remaining(), MINUTE);
+            }
+            if minute == 40 {
+                assert!(clock.is_on_break());
+                assert_eq!(clock.state().current_level, 2);
+            }
+        }
+
+        assert_eq!(*events.borrow(), ["level 2", "break after 2", "level 3", "bubble 11"]);
+        assert_eq!(clock.state().current_level, 3);
+        assert_eq!(clock.state().minutes_elapsed, 35); // 30 + 5 minutes into level 3
+        assert_eq!(clock.time_remaining(), MINUTE * 10);
+    }
+
+    #[test]
+    fn test_long_tick_crosses_several_thresholds() {
+        let levels = Rc::new(RefCell::new(Vec::new()));
+        let mut clock = clock();
+        let log = levels.clone();
+        clock.on_level_change(move |state| log.borrow_mut().push(state.current_level));
+
+        let start = Instant::now();
+        clock.start(start);
+        // Stopped clocks ignore ticks, and the last level never ends
+        clock.pause(start);
+        clock.tick(start + MINUTE * 100);
+        assert_eq!(clock.state().current_level, 1);
+
+        clock.resume(start + MINUTE * 100);
+        clock.tick(start + MINUTE * 300);
+        assert_eq!(*levels.borrow(), [2, 3, 4]);
+        assert_eq!(clock.state().minutes_elapsed, 190); // 200 minutes minus the 10-minute break
+    }
+}
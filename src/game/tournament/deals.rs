@@ -0,0 +1,286 @@
+//! Final-table deal calculators
+//!
+//! Computes how the remaining prize pool is split when the players left in a
+//! tournament agree to a deal:
+//! - [`icm_deal`] pays each player their ICM equity
+//! - [`chip_chop_deal`] guarantees everyone the next payout and splits the rest by chips
+//! - [`hybrid_deal`] blends the two
+//!
+//! Every deal pays out the whole remaining pool in whole units (the rounding
+//! remainder is handed out deterministically) and never pays anyone less than
+//! the payout they are already guaranteed (the last remaining paid place).
+//!
+//! # Examples
+//!
+//! ```
+//! use nice_hand_core::game::tournament::deals::{chip_chop_deal, icm_deal};
+//!
+//! let stacks = [8000, 1000, 1000];
+//! let payouts = [5000, 3000, 2000];
+//!
+//! let icm = icm_deal(&stacks, &payouts);
+//! let chop = chip_chop_deal(&stacks, &payouts);
+//! assert_eq!(icm.iter().sum::<f64>(), 10000.0);
+//! assert!(chop[0] > icm[0]); // the chip leader prefers a chip chop
+//! ```
+
+use super::{chips, ICMCalculator};
+
+/// Largest field for which [`icm_deal`] uses the exact Malmuth-Harville model.
+///
+/// Larger fields fall back to [`ICMCalculator::calculate_equity`].
+pub const EXACT_ICM_MAX_PLAYERS: usize = 12;
+
+/// ICM deal: each player receives their ICM equity of the remaining pool
+pub fn icm_deal(stacks: &[u32], remaining_payouts: &[u64]) -> Vec<f64> {
+    let raw = icm_equities(stacks, remaining_payouts);
+    finalize_deal(raw, stacks.len(), remaining_payouts)
+}
+
+/// Chip-chop deal: everyone locks up the next payout, the rest is split by chip count
+pub fn chip_chop_deal(stacks: &[u32], remaining_payouts: &[u64]) -> Vec<f64> {
+    let raw = chip_chop_amounts(stacks, remaining_payouts);
+    finalize_deal(raw, stacks.len(), remaining_payouts)
+}
+
+/// Hybrid deal: `icm_weight` of the ICM deal plus the rest of the chip chop
+///
+/// `icm_weight` is clamped to `[0, 1]`; `1.0` is a pure ICM deal, `0.0` a pure chip chop.
+pub fn hybrid_deal(stacks: &[u32], payouts: &[u64], icm_weight: f64) -> Vec<f64> {
+    let weight = if icm_weight.is_nan() {
+        0.5
+    } else {
+        icm_weight.clamp(0.0, 1.0)
+    };
+
+    let icm = icm_equities(stacks, payouts);
+    let chop = chip_chop_amounts(stacks, payouts);
+    let raw = icm
+        .iter()
+        .zip(chop.iter())
+        .map(|(i, c)| weight * i + (1.0 - weight) * c)
+        .collect();
+    finalize_deal(raw, stacks.len(), payouts)
+}
+
+/// Unrounded ICM equities, exact when the field is small enough
+///
+/// These are the amounts [`icm_deal`] pays before rounding to whole units.
+pub fn icm_equities(stacks: &[u32], payouts: &[u64]) -> Vec<f64> {
+    if stacks.len() <= EXACT_ICM_MAX_PLAYERS {
+        exact_icm(stacks, payouts)
+    } else {
+        ICMCalculator::new(stacks.to_vec(), payouts.to_vec()).calculate_equity()
+    }
+}
+
+/// Exact Malmuth-Harville ICM over subsets of players already placed
+///
+/// `reach[mask]` is the probability that exactly the players in `mask` took the
+/// top `|mask|` places; the next place goes to each remaining player in
+/// proportion to their chips.
+fn exact_icm(stacks: &[u32], payouts: &[u64]) -> Vec<f64> {
+    let n = stacks.len();
+    let mut equity = vec![0.0; n];
+    if n == 0 {
+        return equity;
+    }
+
+    let places = payouts.len().min(n);
+    let mut reach = vec![0.0; 1 << n];
+    reach[0] = 1.0;
+
+    for mask in 0usize..(1 << n) {
+        let p = reach[mask];
+        let place = mask.count_ones() as usize;
+        if p == 0.0 || place >= places {
+            continue;
+        }
+
+        let remaining: Vec<usize> = (0..n).filter(|&i| mask & (1 << i) == 0).collect();
+        let remaining_stacks: Vec<u32> = remaining.iter().map(|&i| stacks[i]).collect();
+
+        // With no chips left in play every remaining player is equally likely
+        for (&i, share) in remaining.iter().zip(chips::chip_shares(&remaining_stacks)) {
+            let q = p * share;
+            equity[i] += q * payouts[place] as f64;
+            reach[mask | (1 << i)] += q;
+        }
+    }
+
+    equity
+}
+
+/// Unrounded chip-chop amounts
+fn chip_chop_amounts(stacks: &[u32], payouts: &[u64]) -> Vec<f64> {
+    let n = stacks.len();
+    let floor = guaranteed_payout(n, payouts);
+    let pool = total_pool(payouts);
+    let surplus = (pool - floor * n as f64).max(0.0);
+
+    chips::chip_shares(stacks)
+        .into_iter()
+        .map(|share| floor + surplus * share)
+        .collect()
+}
+
+/// Payout everyone is already guaranteed: the last paid place among `n` players
+fn guaranteed_payout(n: usize, payouts: &[u64]) -> f64 {
+    if n == 0 {
+        return 0.0;
+    }
+    payouts.get(n - 1).copied().unwrap_or(0) as f64
+}
+
+/// Prize money still to be paid to the remaining players
+fn total_pool(payouts: &[u64]) -> f64 {
+    chips::prize_pool(payouts)
+}
+
+/// Apply the floor constraint, then round to whole units summing to the pool
+fn finalize_deal(raw: Vec<f64>, n: usize, payouts: &[u64]) -> Vec<f64> {
+    if n == 0 {
+        return raw;
+    }
+
+    // Only the places that can actually be paid to the remaining players count
+    let paid = &payouts[..payouts.len().min(n)];
+    let pool = total_pool(paid);
+    let floor = guaranteed_payout(n, paid);
+
+    let floored = apply_floor(raw, floor, pool);
+    round_to_pool(&floored, pool.round() as u64)
+}
+
+/// Raise everyone below `floor` up to it, taking the shortfall from the others
+/// in proportion to how far they are above the floor
+fn apply_floor(mut amounts: Vec<f64>, floor: f64, pool: f64) -> Vec<f64> {
+    let n = amounts.len();
+    for amount in amounts.iter_mut() {
+        if !amount.is_finite() {
+            *amount = floor;
+        }
+    }
+
+    // Rescale to the pool first so the redistribution starts from a consistent total
+    let total: f64 = amounts.iter().sum();
+    if total > 0.0 {
+        for amount in amounts.iter_mut() {
+            *amount *= pool / total;
+        }
+    } else {
+        amounts = vec![pool / n as f64; n];
+    }
+
+    if floor * n as f64 >= pool {
+        return vec![pool / n as f64; n];
+    }
+
+    let deficit: f64 = amounts.iter().map(|&a| (floor - a).max(0.0)).sum();
+    if deficit <= 0.0 {
+        return amounts;
+    }
+
+    let excess: f64 = amounts.iter().map(|&a| (a - floor).max(0.0)).sum();
+    amounts
+        .iter()
+        .map(|&a| {
+            if a <= floor {
+                floor
+            } else {
+                a - deficit * (a - floor) / excess
+            }
+        })
+        .collect()
+}
+
+/// Round amounts to whole units that sum exactly to `pool`
+///
+/// Each amount is floored, then the leftover units go to the largest
+/// fractional parts, ties broken by seat order.
+fn round_to_pool(amounts: &[f64], pool: u64) -> Vec<f64> {
+    let mut whole: Vec<u64> = amounts.iter().map(|&a| a.max(0.0).floor() as u64).collect();
+    let assigned: u64 = whole.iter().sum();
+
+    let mut order: Vec<usize> = (0..amounts.len()).collect();
+    order.sort_by(|&a, &b| {
+        let fa = amounts[a] - amounts[a].floor();
+        let fb = amounts[b] - amounts[b].floor();
+        fb.partial_cmp(&fa)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.cmp(&b))
+    });
+
+    if assigned <= pool {
+        let leftover = pool - assigned;
+        for i in 0..leftover as usize {
+            whole[order[i % order.len()]] += 1;
+        }
+    } else {
+        // Floating point drift can overshoot by a unit; take it back from the smallest fractions
+        let overshoot = assigned - pool;
+        for i in 0..overshoot as usize {
+            let idx = order[order.len() - 1 - (i % order.len())];
+            whole[idx] = whole[idx].saturating_sub(1);
+        }
+    }
+
+    whole.into_iter().map(|w| w as f64).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_stacks_split_evenly() {
+        let stacks = [5000, 5000, 5000];
+        let payouts = [5000, 3000, 1000];
+
+        for deal in [
+            icm_deal(&stacks, &payouts),
+            chip_chop_deal(&stacks, &payouts),
+            hybrid_deal(&stacks, &payouts, 0.5),
+        ] {
+            assert_eq!(deal, vec![3000.0, 3000.0, 3000.0]);
+        }
+    }
+
+    #[test]
+    fn test_dominant_stack() {
+        let stacks = [8000, 1000, 1000];
+        let payouts = [5000, 3000, 2000];
+
+        let icm = icm_deal(&stacks, &payouts);
+        let chop = chip_chop_deal(&stacks, &payouts);
+        let hybrid = hybrid_deal(&stacks, &payouts, 0.5);
+
+        assert!(chop[0] > icm[0], "chip chop {:?} vs ICM {:?}", chop, icm);
+        assert!(hybrid[0] > icm[0] && hybrid[0] < chop[0]);
+
+        for deal in [&icm, &chop, &hybrid] {
+            assert_eq!(deal.iter().sum::<f64>(), 10000.0);
+            assert!(deal.iter().all(|&amount| amount >= 2000.0), "floor violated: {:?}", deal);
+            assert!(deal.iter().all(|amount| amount.fract() == 0.0));
+        }
+    }
+
+    #[test]
+    fn test_exact_icm_matches_known_values() {
+        // Classic 3-way example: 50/30/20 chips, 50/30/20 payouts
+        let equity = exact_icm(&[50, 30, 20], &[50, 30, 20]);
+        assert!((equity[0] - 38.3929).abs() < 1e-3);
+        assert!((equity[1] - 32.75).abs() < 1e-3);
+        assert!((equity[2] - 28.8571).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_rounding_is_deterministic() {
+        let stacks = [3333, 3333, 3334];
+        let payouts = [5000, 3000, 2000];
+        let first = icm_deal(&stacks, &payouts);
+        let second = icm_deal(&stacks, &payouts);
+        assert_eq!(first, second);
+        assert_eq!(first.iter().sum::<f64>(), 10000.0);
+    }
+}
@@ -0,0 +1,274 @@
+//! Structured tournament events
+//!
+//! [`MTTManager`] queues a [`TournamentEvent`] whenever something worth
+//! reporting happens: a player busts or doubles up, the bubble bursts, a table
+//! is broken, the final table forms or the blind level goes up. Broadcasts and
+//! hand logs take the queued events with [`MTTManager::drain_events`] instead
+//! of diffing the tournament state themselves.
+//!
+//! # Examples
+//!
+//! ```
+//! use nice_hand_core::game::tournament::{AnteType, BlindLevel, MTTManager, TournamentEvent, TournamentStructure};
+//!
+//! let structure = TournamentStructure {
+//!     levels: vec![BlindLevel { level: 1, small_blind: 25, big_blind: 50, ante: 0 }],
+//!     level_duration_minutes: 15,
+//!     starting_stack: 1500,
+//!     ante_schedule: vec![],
+//!     ante_type: AnteType::PerPlayer,
+//! };
+//! let mut mtt = MTTManager::new(9, 9, structure, 10000);
+//!
+//! // Player 1 doubles through player 2
+//! mtt.settle_hand(0, &[(1, 3000), (2, 0)]);
+//! let events = mtt.drain_events();
+//! assert!(matches!(events[0], TournamentEvent::PlayerDoubledUp { player: 1, .. }));
+//! assert!(matches!(events[1], TournamentEvent::PlayerEliminated { player: 2, finishing_position: 9, .. }));
+//! assert!(mtt.drain_events().is_empty());
+//! ```
+
+use super::clock::TournamentClock;
+use super::MTTManager;
+use serde::{Deserialize, Serialize};
+
+/// Something that happened in a multi-table tournament
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TournamentEvent {
+    /// A player busted out
+    PlayerEliminated {
+        player: u32,
+        /// 1 for the winner (players tied in the same hand share the best place)
+        finishing_position: u32,
+        /// Prize paid for the place (0 outside the money)
+        payout: u64,
+    },
+    /// A player at least doubled their stack in one hand
+    PlayerDoubledUp { player: u32, stack: u32 },
+    /// The last player outside the money busted
+    BubbleBurst { players_remaining: u32 },
+    /// The remaining players fit at a single table
+    FinalTableFormed { table_id: u32, players: u32 },
+    /// The blind level went up
+    LevelChanged {
+        level: u32,
+        small_blind: u32,
+        big_blind: u32,
+        ante: u32,
+    },
+    /// A table was closed and its players moved to other tables
+    TableBroken { table_id: u32 },
+}
+
+impl MTTManager {
+    /// Take every event queued since the last call, oldest first
+    pub fn drain_events(&mut self) -> Vec<TournamentEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Apply the result of a hand played at `table_id`
+    ///
+    /// `final_stacks` holds `(player_id, stack)` after the hand for the players
+    /// whose stack changed. Players left with no chips are eliminated together,
+    /// the one who started the hand with more chips finishing higher, and
+    /// players who at least doubled up are reported. Players not seated at the
+    /// table are ignored.
+    pub fn settle_hand(&mut self, table_id: u32, final_stacks: &[(u32, u32)]) {
+        let mut busted = Vec::new();
+        for &(player_id, stack) in final_stacks {
+            let Some(player) = self
+                .tables
+                .iter_mut()
+                .filter(|table| table.table_id == table_id)
+                .flat_map(|table| table.seats.iter_mut().flatten())
+                .find(|player| player.player_id == player_id)
+            else {
+                continue;
+            };
+
+            if stack == 0 {
+                // The stack is left as it was at the start of the hand for the tie-break
+                busted.push(player_id);
+                continue;
+            }
+            let starting_stack = player.stack_size;
+            player.stack_size = stack;
+            if starting_stack > 0 && stack >= starting_stack.saturating_mul(2) {
+                self.events.push(TournamentEvent::PlayerDoubledUp {
+                    player: player_id,
+                    stack,
+                });
+            }
+        }
+
+        let busted: Vec<(u32, u32)> = busted
+            .into_iter()
+            .filter_map(|player_id| self.take_seat(table_id, player_id))
+            .map(|player| (player.player_id, player.stack_size))
+            .collect();
+        self.record_eliminations(busted);
+    }
+
+    /// Advance the tournament by `minutes` of play time, queueing a
+    /// [`TournamentEvent::LevelChanged`] for every level reached
+    ///
+    /// Returns the number of levels advanced, like
+    /// [`TournamentState::advance_time`](super::TournamentState::advance_time).
+    pub fn advance_time(&mut self, minutes: u32) -> u32 {
+        let level_before = self.tournament_state.current_level;
+        let advanced = self.tournament_state.advance_time(minutes);
+        self.queue_level_changes(level_before);
+        advanced
+    }
+
+    /// Catch up with a [`TournamentClock`] driving this tournament
+    ///
+    /// Copies the clock's level and play time and reports the players left to
+    /// the clock so its bubble callbacks fire, queueing a
+    /// [`TournamentEvent::LevelChanged`] for every level the clock has reached.
+    pub fn sync_clock(&mut self, clock: &mut TournamentClock) {
+        clock.set_players_remaining(self.tournament_state.players_remaining);
+        let level_before = self.tournament_state.current_level;
+        self.tournament_state.current_level = clock.state().current_level.max(level_before);
+        self.tournament_state.minutes_elapsed = clock.state().minutes_elapsed;
+        self.queue_level_changes(level_before);
+    }
+
+    fn queue_level_changes(&mut self, level_before: u32) {
+        for level in level_before + 1..=self.tournament_state.current_level {
+            let Some(blinds) = self.tournament_state.structure.levels.get(level as usize - 1) else {
+                continue;
+            };
+            self.events.push(TournamentEvent::LevelChanged {
+                level,
+                small_blind: blinds.small_blind,
+                big_blind: blinds.big_blind,
+                ante: blinds.ante,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tournament::{AnteType, BlindLevel, TournamentStructure};
+
+    fn structure() -> TournamentStructure {
+        TournamentStructure {
+            levels: vec![
+                BlindLevel {
+                    level: 1,
+                    small_blind: 25,
+                    big_blind: 50,
+                    ante: 0,
+                },
+                BlindLevel {
+                    level: 2,
+                    small_blind: 50,
+                    big_blind: 100,
+                    ante: 10,
+                },
+            ],
+            level_duration_minutes: 15,
+            starting_stack: 1500,
+            ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
+        }
+    }
+
+    fn eliminations(events: &[TournamentEvent]) -> Vec<(u32, u32, u64)> {
+        events
+            .iter()
+            .filter_map(|event| match *event {
+                TournamentEvent::PlayerEliminated {
+                    player,
+                    finishing_position,
+                    payout,
+                } => Some((player, finishing_position, payout)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn table_of(mtt: &MTTManager, player_id: u32) -> u32 {
+        let standings = mtt.get_tournament_standings();
+        standings.iter().find(|standing| standing.0 == player_id).unwrap().2
+    }
+
+    #[test]
+    fn test_double_bustout_ranks_by_starting_stack() {
+        // 30 players, 3 paid: 12000 / 7500 / 4500
+        let mut mtt = MTTManager::new(30, 6, structure(), 30000);
+        for player_id in 6..=30 {
+            let table_id = table_of(&mtt, player_id);
+            mtt.settle_hand(table_id, &[(player_id, 0)]);
+        }
+        mtt.drain_events();
+        assert_eq!(mtt.tournament_state.players_remaining, 5);
+
+        // Players 4 and 5 both bust to player 1; 4 started the hand with more chips
+        let table_id = table_of(&mtt, 1);
+        for (player_id, stack) in [(4, 2000), (5, 800)] {
+            let seats = mtt.tables.iter_mut().flat_map(|table| table.seats.iter_mut().flatten());
+            seats.filter(|player| player.player_id == player_id).for_each(|player| player.stack_size = stack);
+        }
+        mtt.settle_hand(table_id, &[(1, 4300), (5, 0), (4, 0)]);
+
+        let events = mtt.drain_events();
+        assert_eq!(eliminations(&events), vec![(5, 5, 0), (4, 4, 0)]);
+        assert!(events.contains(&TournamentEvent::PlayerDoubledUp { player: 1, stack: 4300 }));
+        assert!(events.contains(&TournamentEvent::BubbleBurst { players_remaining: 3 }));
+
+        // Three left: player 2 and 3 bust together in the money
+        mtt.settle_hand(table_of(&mtt, 1), &[(2, 0), (3, 0), (1, 6000)]);
+        let mut events = mtt.drain_events();
+        events.retain(|event| matches!(event, TournamentEvent::PlayerEliminated { .. }));
+        // Equal starting stacks tie for 2nd and split 2nd + 3rd
+        assert_eq!(eliminations(&events), vec![(2, 2, 6000), (3, 2, 6000)]);
+        assert_eq!(mtt.finishes.last().unwrap().player_id, 1);
+        assert_eq!(mtt.finishes.last().unwrap().position, 1);
+    }
+
+    #[test]
+    fn test_bubble_burst_fires_once() {
+        let mut mtt = MTTManager::new(30, 6, structure(), 30000);
+        let mut events = Vec::new();
+        while let [_, .., (player_id, _, table_id)] = mtt.get_tournament_standings()[..] {
+            mtt.eliminate_player(table_id, player_id);
+            events.extend(mtt.drain_events());
+        }
+
+        let bursts: Vec<&TournamentEvent> = events
+            .iter()
+            .filter(|event| matches!(event, TournamentEvent::BubbleBurst { .. }))
+            .collect();
+        assert_eq!(bursts, vec![&TournamentEvent::BubbleBurst { players_remaining: 3 }]);
+
+        let broken = events.iter().filter(|event| matches!(event, TournamentEvent::TableBroken { .. })).count();
+        assert_eq!(broken, 4);
+        let final_tables = events
+            .iter()
+            .filter(|event| matches!(event, TournamentEvent::FinalTableFormed { .. }))
+            .count();
+        assert_eq!(final_tables, 1);
+        assert_eq!(eliminations(&events).len(), 29);
+    }
+
+    #[test]
+    fn test_level_changes_are_queued() {
+        let mut mtt = MTTManager::new(9, 9, structure(), 1000);
+        assert_eq!(mtt.advance_time(10), 0);
+        assert!(mtt.drain_events().is_empty());
+        assert_eq!(mtt.advance_time(10), 1);
+        assert_eq!(
+            mtt.drain_events(),
+            vec![TournamentEvent::LevelChanged {
+                level: 2,
+                small_blind: 50,
+                big_blind: 100,
+                ante: 10,
+            }]
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Payout ladder utilities
+//!
+//! Lists the pay jumps still ahead of the players left in a tournament and
+//! estimates how much of the next jump each stack at a table stands to lose:
+//! - [`ladder`] lists every place where the payout goes up, nearest first
+//! - [`next_jump_value`] is the size of the nearest jump
+//! - [`pay_jump_equities`] weights that jump by each stack's chance of busting before it
+//!
+//! # Examples
+//!
+//! ```
+//! use nice_hand_core::game::tournament::pay_jumps::{ladder, next_jump_value, PayJump};
+//! use nice_hand_core::game::tournament::PayoutLevel;
+//!
+//! let payouts: Vec<PayoutLevel> = [5000, 3000, 2000, 1000]
+//!     .iter()
+//!     .enumerate()
+//!     .map(|(i, &amount)| PayoutLevel { position: i as u32 + 1, percentage: 0.0, amount })
+//!     .collect();
+//!
+//! // Five left: outlasting one more player locks up the min-cash
+//! assert_eq!(ladder(5, &payouts)[0], PayJump { position: 4, amount_delta: 1000 });
+//! assert_eq!(next_jump_value(5, &payouts), 1000);
+//! assert_eq!(next_jump_value(3, &payouts), 1000); // 3rd -> 2nd
+//! ```
+
+use super::PayoutLevel;
+use serde::{Deserialize, Serialize};
+
+/// One step up the payout ladder
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayJump {
+    /// Finishing place reached by outlasting everyone below it
+    pub position: u32,
+    /// Payout for `position` minus the payout for the place just below it
+    pub amount_delta: u64,
+}
+
+/// Pay jumps still ahead with `players_remaining` left, nearest first
+///
+/// Places where the payout does not change (flat bands, places outside the
+/// money) are skipped, so every entry is a real increase.
+pub fn ladder(players_remaining: u32, payout_structure: &[PayoutLevel]) -> Vec<PayJump> {
+    let payouts = payout_table(players_remaining, payout_structure);
+    (1..players_remaining)
+        .rev()
+        .filter_map(|position| {
+            let amount_delta = payouts[position as usize].saturating_sub(payouts[position as usize + 1]);
+            (amount_delta > 0).then_some(PayJump { position, amount_delta })
+        })
+        .collect()
+}
+
+/// Size of the nearest pay jump (0 when the payouts cannot increase any more)
+///
+/// The jump may be several eliminations away, e.g. the min-cash on the bubble;
+/// its place is `ladder(..)[0].position`.
+pub fn next_jump_value(players_remaining: u32, payouts: &[PayoutLevel]) -> u64 {
+    ladder(players_remaining, payouts).first().map_or(0, |jump| jump.amount_delta)
+}
+
+/// Equity each stack at a table stands to lose on the next pay jump
+///
+/// This is the difference between laddering to the next jump and busting now
+/// (the jump's `amount_delta`), weighted by the chance that the player busts
+/// before enough other players are out to reach it.
+///
+/// Finish probabilities use a min-cash approximation instead of a full ICM
+/// finish distribution over the field:
+/// - the next player out is drawn inversely to stack size among the seats at
+///   this table with chips (Malmuth-Weitzman), and the table's share of the
+///   field's eliminations is `seats / players_remaining`;
+/// - eliminations are independent, so busting within `k` of them has chance
+///   `1 - (1 - p)^k`;
+/// - jumps more eliminations away than there are seats at the table are out
+///   of reach for decisions at this table and give 0 for everyone.
+///
+/// Seats with no chips are not in the tournament and get 0.
+pub fn pay_jump_equities(stacks: &[u32], players_remaining: u32, payout_structure: &[PayoutLevel]) -> Vec<f64> {
+    let seated = stacks.iter().filter(|&&stack| stack > 0).count() as u32;
+    let Some(jump) = ladder(players_remaining, payout_structure).first().copied() else {
+        return vec![0.0; stacks.len()];
+    };
+    let eliminations = players_remaining - jump.position;
+    if seated == 0 || eliminations > seated {
+        return vec![0.0; stacks.len()];
+    }
+
+    let bust_weight = |stack: u32| if stack > 0 { 1.0 / stack as f64 } else { 0.0 };
+    let total_weight: f64 = stacks.iter().map(|&stack| bust_weight(stack)).sum();
+    let table_share = (seated as f64 / players_remaining.max(seated) as f64).min(1.0);
+    stacks
+        .iter()
+        .map(|&stack| {
+            let next_out = bust_weight(stack) / total_weight * table_share;
+            let bust_first = 1.0 - (1.0 - next_out).powi(eliminations as i32);
+            jump.amount_delta as f64 * bust_first
+        })
+        .collect()
+}
+
+/// Payout by finishing place, indexed `0..=players_remaining + 1` (index 0 unused)
+fn payout_table(players_remaining: u32, payout_structure: &[PayoutLevel]) -> Vec<u64> {
+    let mut payouts = vec![0u64; players_remaining as usize + 2];
+    for level in payout_structure {
+        if let Some(amount) = payouts.get_mut(level.position as usize) {
+            *amount = level.amount;
+        }
+    }
+    payouts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Geometric payouts for 18 paid places, every place paying 80% of the one above
+    fn standard_structure() -> Vec<PayoutLevel> {
+        (1..=18)
+            .map(|position| PayoutLevel {
+                position,
+                percentage: 0.0,
+                amount: (100_000.0 * 0.8f64.powi(position as i32 - 1)).round() as u64,
+            })
+            .collect()
+    }
+
+    fn structure(amounts: &[u64]) -> Vec<PayoutLevel> {
+        amounts
+            .iter()
+            .enumerate()
+            .map(|(i, &amount)| PayoutLevel {
+                position: i as u32 + 1,
+                percentage: 0.0,
+                amount,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ladder_deltas_shrink_toward_the_middle() {
+        let payouts = standard_structure();
+        let jumps = ladder(18, &payouts);
+        assert_eq!(jumps.len(), 17);
+        assert_eq!(jumps[0].position, 17);
+        assert_eq!(jumps.last().unwrap().position, 1);
+
+        // Nearest first, so the deltas grow toward the top of the ladder
+        assert!(jumps.windows(2).all(|pair| pair[0].amount_delta < pair[1].amount_delta));
+        let median = jumps[jumps.len() / 2].amount_delta;
+        assert!(jumps[jumps.len() - 3..].iter().all(|jump| jump.amount_delta > 3 * median));
+        assert_eq!(next_jump_value(18, &payouts), jumps[0].amount_delta);
+
+        // Outside the money the ladder starts at the min-cash
+        assert_eq!(ladder(25, &payouts).len(), 18);
+        assert_eq!(ladder(25, &payouts)[0], PayJump { position: 18, amount_delta: payouts[17].amount });
+        assert!(ladder(1, &payouts).is_empty());
+        assert_eq!(next_jump_value(1, &payouts), 0);
+    }
+
+    #[test]
+    fn test_short_stack_has_most_pay_jump_equity() {
+        // Five left, four paid: the 500-chip stack is the most likely to bubble
+        let payouts = structure(&[5000, 3000, 2000, 1000]);
+        let equities = pay_jump_equities(&[4000, 3000, 2000, 1500, 500, 0], 5, &payouts);
+        assert!(equities[4] > equities[3] && equities[3] > equities[0]);
+        assert_eq!(equities[5], 0.0);
+        let bubble_chance: f64 = equities.iter().sum::<f64>() / 1000.0;
+        assert!((bubble_chance - 1.0).abs() < 1e-9, "{}", bubble_chance);
+
+        // A jump further away than the table can eliminate does not count
+        assert!(pay_jump_equities(&[1000; 6], 40, &payouts).iter().all(|&equity| equity == 0.0));
+    }
+}
@@ -0,0 +1,314 @@
+//! Rebuys, add-ons and re-entries
+//!
+//! A freezeout fixes the prize pool and the chips in play when it starts. With
+//! a rebuy period, players can buy more chips (rebuys and add-ons) or come
+//! back with a fresh stack after busting (re-entries) until the last rebuy
+//! level ends, so the prize pool, [`TournamentState::total_chips`] and the
+//! field size change mid-tournament.
+//!
+//! Every purchase is added to the prize pool and the chips in play right
+//! away, but the payout structure is left alone until
+//! [`TournamentState::recompute_payouts`] is called, usually once the rebuy
+//! period has closed. Payouts therefore don't move with every purchase.
+//!
+//! # Examples
+//!
+//! ```
+//! use nice_hand_core::game::tournament::{AnteType, BlindLevel, TournamentState, TournamentStructure};
+//!
+//! let structure = TournamentStructure {
+//!     levels: vec![
+//!         BlindLevel { level: 1, small_blind: 25, big_blind: 50, ante: 0 },
+//!         BlindLevel { level: 2, small_blind: 50, big_blind: 100, ante: 0 },
+//!     ],
+//!     level_duration_minutes: 20,
+//!     starting_stack: 1500,
+//!     ante_schedule: vec![],
+//!     ante_type: AnteType::PerPlayer,
+//! };
+//! let mut tournament = TournamentState::new(structure, 10, 1000).with_rebuy_period(1);
+//!
+//! tournament.register_rebuy(3, 1500, 100).unwrap();
+//! tournament.register_addon(3, 2000, 100).unwrap();
+//! assert_eq!(tournament.prize_pool, 1200);
+//! assert_eq!(tournament.total_chips(), 10 * 1500 + 3500);
+//!
+//! // Level 2: the rebuy period is over, so the payouts can be settled
+//! tournament.advance_time(20);
+//! assert!(tournament.register_rebuy(3, 1500, 100).is_err());
+//! tournament.recompute_payouts();
+//! assert_eq!(tournament.payout_for_position(1), 480);
+//! ```
+
+use super::{basic_payouts, MTTManager, MTTPlayer, TournamentState};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What a player bought during the rebuy period
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RebuyKind {
+    /// More chips for a player still in the tournament
+    Rebuy,
+    /// The one-off extra chips each player may buy
+    AddOn,
+    /// A fresh starting stack for a player who busted
+    ReEntry,
+}
+
+/// One rebuy, add-on or re-entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rebuy {
+    pub player_id: u32,
+    pub kind: RebuyKind,
+    pub chips: u32,
+    /// Amount added to the prize pool
+    pub cost: u64,
+    /// Level at which it was bought
+    pub level: u32,
+}
+
+/// A rebuy, add-on or re-entry that cannot be registered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RebuyError {
+    /// The rebuy period ended before this level
+    PeriodClosed { level: u32 },
+    /// The player already took their add-on
+    AddOnAlreadyTaken { player_id: u32 },
+    /// Only a player who busted can re-enter
+    NotBusted { player_id: u32 },
+    /// Every table is full
+    NoOpenSeat,
+}
+
+impl fmt::Display for RebuyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PeriodClosed { level } => write!(f, "rebuy period is closed at level {}", level),
+            Self::AddOnAlreadyTaken { player_id } => write!(f, "player {} already took an add-on", player_id),
+            Self::NotBusted { player_id } => write!(f, "player {} has not busted", player_id),
+            Self::NoOpenSeat => write!(f, "no table has an open seat"),
+        }
+    }
+}
+
+impl std::error::Error for RebuyError {}
+
+impl TournamentState {
+    /// Accept rebuys and add-ons up to and including `last_level`
+    pub fn with_rebuy_period(mut self, last_level: u32) -> Self {
+        self.rebuy_last_level = last_level;
+        self
+    }
+
+    /// Whether rebuys, add-ons and re-entries are accepted at the current level
+    pub fn rebuy_period_open(&self) -> bool {
+        self.current_level <= self.rebuy_last_level
+    }
+
+    /// Register a rebuy of `chips` for `cost`, added to the prize pool
+    ///
+    /// Crediting the chips to the player's stack is up to the caller.
+    pub fn register_rebuy(&mut self, player_id: u32, chips: u32, cost: u64) -> Result<(), RebuyError> {
+        self.register(player_id, RebuyKind::Rebuy, chips, cost)
+    }
+
+    /// Register the player's add-on of `chips` for `cost`
+    ///
+    /// Each player may take a single add-on.
+    pub fn register_addon(&mut self, player_id: u32, chips: u32, cost: u64) -> Result<(), RebuyError> {
+        let taken = self
+            .rebuys
+            .iter()
+            .any(|rebuy| rebuy.player_id == player_id && rebuy.kind == RebuyKind::AddOn);
+        if taken {
+            return Err(RebuyError::AddOnAlreadyTaken { player_id });
+        }
+        self.register(player_id, RebuyKind::AddOn, chips, cost)
+    }
+
+    /// Rebuild the payout structure from the current entries and prize pool
+    pub fn recompute_payouts(&mut self) {
+        self.payout_structure = basic_payouts(self.total_players, self.prize_pool);
+    }
+
+    fn register(&mut self, player_id: u32, kind: RebuyKind, chips: u32, cost: u64) -> Result<(), RebuyError> {
+        if !self.rebuy_period_open() {
+            return Err(RebuyError::PeriodClosed {
+                level: self.current_level,
+            });
+        }
+        self.prize_pool = self.prize_pool.saturating_add(cost);
+        match kind {
+            // A re-entry is a new entry with its own starting stack
+            RebuyKind::ReEntry => {
+                self.total_players += 1;
+                self.players_remaining += 1;
+            }
+            RebuyKind::Rebuy | RebuyKind::AddOn => self.added_chips += chips as u64,
+        }
+        self.rebuys.push(Rebuy {
+            player_id,
+            kind,
+            chips,
+            cost,
+            level: self.current_level,
+        });
+        Ok(())
+    }
+}
+
+impl MTTManager {
+    /// Bring a busted player back with a fresh starting stack for `cost`
+    ///
+    /// The player takes an empty seat at the shortest table with one, their
+    /// finish is withdrawn from [`MTTManager::finishes`], and the re-entry
+    /// counts as a new entry in the tournament state. Returns the table the
+    /// player was seated at.
+    pub fn reenter_player(&mut self, player_id: u32, cost: u64) -> Result<u32, RebuyError> {
+        let state = &self.tournament_state;
+        if !state.rebuy_period_open() {
+            return Err(RebuyError::PeriodClosed {
+                level: state.current_level,
+            });
+        }
+        let seated = self
+            .tables
+            .iter()
+            .flat_map(|table| table.seats.iter().flatten())
+            .any(|player| player.player_id == player_id);
+        let finish = self.finishes.iter().position(|finish| finish.player_id == player_id);
+        let Some(finish) = finish.filter(|_| !seated) else {
+            return Err(RebuyError::NotBusted { player_id });
+        };
+        let table = self.shortest_table(true).ok_or(RebuyError::NoOpenSeat)?;
+
+        let chips = state.structure.starting_stack;
+        self.tournament_state.register(player_id, RebuyKind::ReEntry, chips, cost)?;
+        self.finishes.remove(finish);
+        self.tables[table].seat_player(MTTPlayer {
+            player_id,
+            stack_size: chips,
+            position: 0,
+            is_sitting_out: false,
+            has_been_dealt_in: false,
+        });
+        Ok(self.tables[table].table_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tournament::{AnteType, BlindLevel, ICMCalculator, TournamentStructure};
+
+    fn structure() -> TournamentStructure {
+        TournamentStructure {
+            levels: vec![
+                BlindLevel {
+                    level: 1,
+                    small_blind: 25,
+                    big_blind: 50,
+                    ante: 0,
+                },
+                BlindLevel {
+                    level: 2,
+                    small_blind: 50,
+                    big_blind: 100,
+                    ante: 0,
+                },
+                BlindLevel {
+                    level: 3,
+                    small_blind: 100,
+                    big_blind: 200,
+                    ante: 25,
+                },
+            ],
+            level_duration_minutes: 20,
+            starting_stack: 1500,
+            ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
+        }
+    }
+
+    #[test]
+    fn test_rebuys_update_prize_pool_and_chips_in_play() {
+        let mut state = TournamentState::new(structure(), 20, 20000).with_rebuy_period(2);
+
+        for player_id in [4, 4, 9] {
+            state.register_rebuy(player_id, 1500, 1000).unwrap();
+        }
+        state.advance_time(20);
+        assert!(state.rebuy_period_open());
+        for player_id in [4, 11] {
+            state.register_addon(player_id, 3000, 1000).unwrap();
+        }
+        assert_eq!(
+            state.register_addon(4, 3000, 1000),
+            Err(RebuyError::AddOnAlreadyTaken { player_id: 4 })
+        );
+
+        assert_eq!(state.prize_pool, 25000);
+        assert_eq!(state.total_chips(), 20 * 1500 + 3 * 1500 + 2 * 3000);
+        assert_eq!(state.rebuys.len(), 5);
+        assert_eq!(state.rebuys[3].level, 2);
+
+        state.advance_time(20);
+        assert!(!state.rebuy_period_open());
+        assert_eq!(state.register_rebuy(4, 1500, 1000), Err(RebuyError::PeriodClosed { level: 3 }));
+        assert_eq!(state.prize_pool, 25000);
+    }
+
+    #[test]
+    fn test_icm_after_rebuy_period_uses_recomputed_payouts() {
+        let mut state = TournamentState::new(structure(), 20, 20000).with_rebuy_period(1);
+        for player_id in 1..=5 {
+            state.register_rebuy(player_id, 1500, 1000).unwrap();
+        }
+        let payouts = |state: &TournamentState| -> Vec<u64> {
+            state.payout_structure.iter().map(|level| level.amount).collect()
+        };
+        // Payouts are deferred until the period closes
+        assert_eq!(payouts(&state), vec![8000, 5000]);
+
+        state.advance_time(20);
+        state.recompute_payouts();
+        assert_eq!(payouts(&state), vec![10000, 6250]);
+
+        // Heads-up with every chip in play split between the last two players
+        let total = state.total_chips() as u32;
+        assert_eq!(total, 37500);
+        let equities = ICMCalculator::new(vec![total - 12500, 12500], payouts(&state)).calculate_equity();
+        assert!((equities.iter().sum::<f64>() - 16250.0).abs() < 1e-6);
+        assert!(equities[0] > equities[1]);
+    }
+
+    #[test]
+    fn test_reentry_takes_an_open_seat_or_fails_when_full() {
+        let mut mtt = MTTManager::new(12, 6, structure(), 12000);
+        mtt.tournament_state.rebuy_last_level = 1;
+        assert_eq!(mtt.reenter_player(1, 1000), Err(RebuyError::NotBusted { player_id: 1 }));
+
+        mtt.eliminate_player(0, 1);
+        assert_eq!(mtt.tournament_state.players_remaining, 11);
+        assert_eq!(mtt.reenter_player(1, 1000), Ok(0));
+        assert!(mtt.finishes.is_empty());
+        assert!(mtt.get_tournament_standings().contains(&(1, 1500, 0)));
+        assert_eq!(mtt.tournament_state.players_remaining, 12);
+        assert_eq!(mtt.tournament_state.total_players, 13);
+        assert_eq!(mtt.tournament_state.prize_pool, 13000);
+        assert_eq!(mtt.tournament_state.total_chips(), 13 * 1500);
+
+        // Player 2's seat is taken by a late registration before they can re-enter
+        mtt.eliminate_player(0, 2);
+        assert!(mtt.tables[0].seat_player(MTTPlayer {
+            player_id: 13,
+            stack_size: 1500,
+            position: 0,
+            is_sitting_out: false,
+            has_been_dealt_in: false,
+        }));
+        assert_eq!(mtt.reenter_player(2, 1000), Err(RebuyError::NoOpenSeat));
+        assert_eq!(mtt.finishes.len(), 1);
+        assert_eq!(mtt.tournament_state.prize_pool, 13000);
+    }
+}
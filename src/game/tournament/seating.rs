@@ -0,0 +1,378 @@
+//! Seat-to-player mapping for hands dealt at MTT tables
+//!
+//! A [`holdem::State`](crate::game::holdem::State) only knows seats 0-5 while
+//! [`MTTManager`] knows player ids, and table balancing moves players between
+//! tables and seats. [`MTTManager::deal_table_hand`] returns the dealt hand
+//! together with a [`SeatMap`] recording which player sits in which hold'em
+//! seat, and [`MTTManager::settle_table_hand`] writes the final stacks back
+//! through that map, so results and per-player statistics always follow the
+//! player rather than the seat index.
+//!
+//! # Examples
+//!
+//! ```
+//! use nice_hand_core::game::tournament::{AnteType, BlindLevel, MTTManager, TournamentStructure};
+//! use rand::rngs::StdRng;
+//! use rand::SeedableRng;
+//!
+//! let structure = TournamentStructure {
+//!     levels: vec![BlindLevel { level: 1, small_blind: 25, big_blind: 50, ante: 0 }],
+//!     level_duration_minutes: 15,
+//!     starting_stack: 1500,
+//!     ante_schedule: vec![],
+//!     ante_type: AnteType::PerPlayer,
+//! };
+//! let mut mtt = MTTManager::new(6, 6, structure, 10000);
+//!
+//! let hand = mtt.deal_table_hand(0, &mut StdRng::seed_from_u64(1)).unwrap();
+//! let button_player = hand.seat_map.player_at(hand.seat_map.button_seat as usize).unwrap();
+//!
+//! // The button player wins 100 chips from the big blind (hold'em seat 5 at six players)
+//! let mut final_stacks = hand.starting_stacks;
+//! final_stacks[hand.seat_map.button_seat as usize] += 100;
+//! final_stacks[5] -= 100;
+//! mtt.settle_table_hand(&hand.result(final_stacks)).unwrap();
+//!
+//! let standings = mtt.get_tournament_standings();
+//! assert_eq!(standings[0], (button_player, 1600, 0));
+//! ```
+
+use super::MTTManager;
+use crate::game::holdem::State;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Seats in a hold'em hand
+pub const HOLDEM_SEATS: usize = 6;
+
+/// Which tournament player sits in each hold'em seat of one hand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeatMap {
+    /// Player id in each hold'em seat (`None` for seats not dealt in)
+    pub seat_to_player: [Option<u32>; HOLDEM_SEATS],
+    /// Hold'em seat of the button
+    pub button_seat: u8,
+}
+
+/// A [`SeatMap`] that cannot be used to settle a hand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatMapError {
+    /// The same player is mapped to two seats
+    DuplicatePlayer { player_id: u32, seats: (usize, usize) },
+    /// A seat still in the hand has no player
+    UnmappedSeat { seat: usize },
+    /// The button is outside the hold'em seats
+    ButtonOutOfRange { button_seat: u8 },
+    /// A mapped player is not seated at the table the hand was played at
+    PlayerNotSeated { player_id: u32, table_id: u32 },
+    /// No table with this id
+    UnknownTable { table_id: u32 },
+    /// A hand needs between 2 and [`HOLDEM_SEATS`] players
+    UnsupportedPlayerCount { players: usize },
+}
+
+impl fmt::Display for SeatMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicatePlayer { player_id, seats } => {
+                write!(f, "player {} is mapped to seats {} and {}", player_id, seats.0, seats.1)
+            }
+            Self::UnmappedSeat { seat } => write!(f, "seat {} is in the hand but has no player", seat),
+            Self::ButtonOutOfRange { button_seat } => write!(f, "button seat {} is out of range", button_seat),
+            Self::PlayerNotSeated { player_id, table_id } => {
+                write!(f, "player {} is not seated at table {}", player_id, table_id)
+            }
+            Self::UnknownTable { table_id } => write!(f, "no table with id {}", table_id),
+            Self::UnsupportedPlayerCount { players } => {
+                write!(f, "a hand needs 2 to {} players, got {}", HOLDEM_SEATS, players)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SeatMapError {}
+
+impl SeatMap {
+    /// Player in a hold'em seat
+    pub fn player_at(&self, seat: usize) -> Option<u32> {
+        self.seat_to_player.get(seat).copied().flatten()
+    }
+
+    /// Hold'em seat of a player
+    pub fn seat_of(&self, player_id: u32) -> Option<usize> {
+        self.seat_to_player.iter().position(|&player| player == Some(player_id))
+    }
+
+    /// `(seat, player_id)` for every mapped seat
+    pub fn players(&self) -> impl Iterator<Item = (usize, u32)> + '_ {
+        self.seat_to_player
+            .iter()
+            .enumerate()
+            .filter_map(|(seat, player)| player.map(|player_id| (seat, player_id)))
+    }
+
+    /// Check that every seat in `alive` maps to a player, no player is mapped
+    /// twice and the button is a hold'em seat
+    pub fn validate(&self, alive: &[bool; HOLDEM_SEATS]) -> Result<(), SeatMapError> {
+        if self.button_seat as usize >= HOLDEM_SEATS {
+            return Err(SeatMapError::ButtonOutOfRange {
+                button_seat: self.button_seat,
+            });
+        }
+        if let Some(seat) = (0..HOLDEM_SEATS).find(|&seat| alive[seat] && self.seat_to_player[seat].is_none()) {
+            return Err(SeatMapError::UnmappedSeat { seat });
+        }
+        for (seat, player_id) in self.players() {
+            if let Some(first) = self.seat_of(player_id).filter(|&first| first != seat) {
+                return Err(SeatMapError::DuplicatePlayer {
+                    player_id,
+                    seats: (first, seat),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A hand dealt at an MTT table by [`MTTManager::deal_table_hand`]
+#[derive(Debug, Clone)]
+pub struct TableHand {
+    pub table_id: u32,
+    /// Hand with blinds and antes posted and hole cards dealt
+    pub root: State,
+    /// Players in the hand's hold'em seats
+    pub seat_map: SeatMap,
+    /// Stacks before blinds and antes, by hold'em seat (for [`State::settle`])
+    pub starting_stacks: [u32; HOLDEM_SEATS],
+}
+
+impl TableHand {
+    /// Result of the hand from the stacks after it, by hold'em seat
+    pub fn result(&self, final_stacks: [u32; HOLDEM_SEATS]) -> SeatedHandResult {
+        SeatedHandResult {
+            table_id: self.table_id,
+            seat_map: self.seat_map,
+            final_stacks,
+        }
+    }
+}
+
+/// Stacks after a hand, by hold'em seat, with the map of who sat where
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeatedHandResult {
+    pub table_id: u32,
+    pub seat_map: SeatMap,
+    pub final_stacks: [u32; HOLDEM_SEATS],
+}
+
+impl MTTManager {
+    /// Deal the next hand at `table_id`
+    ///
+    /// The button moves to the next occupied seat, and every seated player who
+    /// is not sitting out and has chips is dealt in. Players keep their
+    /// clockwise order from the button, mapped onto the hold'em seats so that
+    /// the table's button player holds the hold'em button.
+    pub fn deal_table_hand<R: Rng>(&mut self, table_id: u32, rng: &mut R) -> Result<TableHand, SeatMapError> {
+        let (small_blind, big_blind, ante) = self.tournament_state.current_blinds();
+        let ante_type = self.tournament_state.current_ante_type();
+        let table = self
+            .tables
+            .iter_mut()
+            .find(|table| table.table_id == table_id)
+            .ok_or(SeatMapError::UnknownTable { table_id })?;
+
+        let seat_count = table.seats.len();
+        let in_hand = |seat: usize| {
+            table.seats[seat]
+                .as_ref()
+                .is_some_and(|player| !player.is_sitting_out && player.stack_size > 0)
+        };
+        let button = (1..=seat_count)
+            .map(|offset| (table.button_position as usize + offset) % seat_count)
+            .find(|&seat| in_hand(seat))
+            .ok_or(SeatMapError::UnsupportedPlayerCount { players: 0 })?;
+        // Clockwise from the seat after the button, ending with the button
+        let order: Vec<usize> = (1..=seat_count)
+            .map(|offset| (button + offset) % seat_count)
+            .filter(|&seat| in_hand(seat))
+            .collect();
+        let players = order.len();
+        if !(2..=HOLDEM_SEATS).contains(&players) {
+            return Err(SeatMapError::UnsupportedPlayerCount { players });
+        }
+
+        table.button_position = button as u32;
+        table.current_hand += 1;
+
+        // Hold'em seats: the button is seat 0 heads-up and `players - 3` otherwise
+        let button_seat = if players == 2 { 0 } else { players - 3 };
+        let mut seat_map = SeatMap {
+            seat_to_player: [None; HOLDEM_SEATS],
+            button_seat: button_seat as u8,
+        };
+        let mut starting_stacks = [0; HOLDEM_SEATS];
+        for (idx, &table_seat) in order.iter().enumerate() {
+            let holdem_seat = (button_seat + 1 + idx) % players;
+            let player = table.seats[table_seat].as_mut().expect("seat is occupied");
+            player.has_been_dealt_in = true;
+            seat_map.seat_to_player[holdem_seat] = Some(player.player_id);
+            starting_stacks[holdem_seat] = player.stack_size;
+        }
+
+        let root = State::new_hand_with_rng(
+            [small_blind, big_blind],
+            ante,
+            ante_type,
+            starting_stacks,
+            players,
+            rng,
+        );
+        Ok(TableHand {
+            table_id,
+            root,
+            seat_map,
+            starting_stacks,
+        })
+    }
+
+    /// Apply a hand result through its seat map
+    ///
+    /// Every mapped player must still be seated at the table and the map must
+    /// be valid; otherwise nothing is changed. Stacks are then written back by
+    /// player id as in [`MTTManager::settle_hand`].
+    pub fn settle_table_hand(&mut self, result: &SeatedHandResult) -> Result<(), SeatMapError> {
+        let seat_map = &result.seat_map;
+        seat_map.validate(&seat_map.seat_to_player.map(|player| player.is_some()))?;
+
+        let table = self
+            .tables
+            .iter()
+            .find(|table| table.table_id == result.table_id)
+            .ok_or(SeatMapError::UnknownTable {
+                table_id: result.table_id,
+            })?;
+        for (_, player_id) in seat_map.players() {
+            if !table.seats.iter().flatten().any(|player| player.player_id == player_id) {
+                return Err(SeatMapError::PlayerNotSeated {
+                    player_id,
+                    table_id: result.table_id,
+                });
+            }
+        }
+
+        let final_stacks: Vec<(u32, u32)> = seat_map
+            .players()
+            .map(|(seat, player_id)| (player_id, result.final_stacks[seat]))
+            .collect();
+        self.settle_hand(result.table_id, &final_stacks);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tournament::{AnteType, BlindLevel, TournamentStructure};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn structure() -> TournamentStructure {
+        TournamentStructure {
+            levels: vec![BlindLevel {
+                level: 1,
+                small_blind: 25,
+                big_blind: 50,
+                ante: 0,
+            }],
+            level_duration_minutes: 15,
+            starting_stack: 1500,
+            ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
+        }
+    }
+
+    fn table_of(mtt: &MTTManager, player_id: u32) -> u32 {
+        mtt.get_tournament_standings().iter().find(|standing| standing.0 == player_id).unwrap().2
+    }
+
+    fn stack_of(mtt: &MTTManager, player_id: u32) -> u32 {
+        mtt.get_tournament_standings().iter().find(|standing| standing.0 == player_id).unwrap().1
+    }
+
+    /// Bust two players at table 0 so balancing moves a player over from table 1
+    fn balance_one_player_over(mtt: &mut MTTManager) -> u32 {
+        let before: Vec<u32> = mtt.tables[1].seats.iter().flatten().map(|player| player.player_id).collect();
+        mtt.settle_hand(0, &[(1, 0), (2, 0)]);
+        let after: Vec<u32> = mtt.tables[1].seats.iter().flatten().map(|player| player.player_id).collect();
+        let moved: Vec<u32> = before.into_iter().filter(|id| !after.contains(id)).collect();
+        assert_eq!(moved.len(), 1, "balancing should move exactly one player");
+        moved[0]
+    }
+
+    #[test]
+    fn test_stacks_follow_player_across_tables() {
+        let mut mtt = MTTManager::new(12, 6, structure(), 10000);
+        let mut rng = StdRng::seed_from_u64(3);
+        assert_eq!(table_of(&mtt, 7), 1);
+        let moved = balance_one_player_over(&mut mtt);
+        let new_table = table_of(&mtt, moved);
+        assert_eq!(new_table, 0);
+
+        let hand = mtt.deal_table_hand(new_table, &mut rng).unwrap();
+        hand.seat_map.validate(&hand.root.alive).unwrap();
+        assert_eq!(hand.seat_map.players().count(), 5);
+        let seat = hand.seat_map.seat_of(moved).unwrap();
+        assert_eq!(hand.starting_stacks[seat], 1500);
+
+        // The moved player wins 300 from whoever is in the next hold'em seat
+        let victim_seat = (seat + 1) % 5;
+        let victim = hand.seat_map.player_at(victim_seat).unwrap();
+        let mut final_stacks = hand.starting_stacks;
+        final_stacks[seat] += 300;
+        final_stacks[victim_seat] -= 300;
+        mtt.settle_table_hand(&hand.result(final_stacks)).unwrap();
+
+        assert_eq!(stack_of(&mtt, moved), 1800);
+        assert_eq!(stack_of(&mtt, victim), 1200);
+        let untouched = mtt.tables[0].seats.iter().flatten().filter(|player| player.stack_size == 1500).count();
+        assert_eq!(untouched, 3);
+
+        // The next hand keeps the player mapped wherever the button moved
+        let next = mtt.deal_table_hand(new_table, &mut rng).unwrap();
+        assert_eq!(next.starting_stacks[next.seat_map.seat_of(moved).unwrap()], 1800);
+        assert_ne!(
+            next.seat_map.player_at(next.seat_map.button_seat as usize),
+            hand.seat_map.player_at(hand.seat_map.button_seat as usize)
+        );
+    }
+
+    #[test]
+    fn test_corrupted_seat_map_is_rejected() {
+        let mut mtt = MTTManager::new(6, 6, structure(), 10000);
+        let hand = mtt.deal_table_hand(0, &mut StdRng::seed_from_u64(4)).unwrap();
+
+        let mut result = hand.result([1000; HOLDEM_SEATS]);
+        result.seat_map.seat_to_player[3] = result.seat_map.seat_to_player[0];
+        assert_eq!(
+            mtt.settle_table_hand(&result),
+            Err(SeatMapError::DuplicatePlayer {
+                player_id: hand.seat_map.player_at(0).unwrap(),
+                seats: (0, 3),
+            })
+        );
+        // Nothing was written back
+        assert!(mtt.get_tournament_standings().iter().all(|standing| standing.1 == 1500));
+
+        let mut unmapped = hand.seat_map;
+        unmapped.seat_to_player[2] = None;
+        assert_eq!(unmapped.validate(&hand.root.alive), Err(SeatMapError::UnmappedSeat { seat: 2 }));
+
+        let mut stranger = hand.result([1500; HOLDEM_SEATS]);
+        stranger.seat_map.seat_to_player[1] = Some(99);
+        assert_eq!(
+            mtt.settle_table_hand(&stranger),
+            Err(SeatMapError::PlayerNotSeated { player_id: 99, table_id: 0 })
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Tournament standings with ICM equity
+//!
+//! [`MTTManager::standings_report`] returns the current chip standings of a
+//! multi-table tournament together with each player's ICM equity of the prize
+//! money still to be paid out and what a double up or a bust would change it
+//! by. The report is serde-serializable so it can be sent to a UI as-is.
+//!
+//! # Examples
+//!
+//! ```
+//! use nice_hand_core::game::tournament::{AnteType, BlindLevel, MTTManager, TournamentStructure};
+//!
+//! let structure = TournamentStructure {
+//!     levels: vec![BlindLevel { level: 1, small_blind: 25, big_blind: 50, ante: 0 }],
+//!     level_duration_minutes: 15,
+//!     starting_stack: 1500,
+//!     ante_schedule: vec![],
+//!     ante_type: AnteType::PerPlayer,
+//! };
+//! let mtt = MTTManager::new(18, 9, structure, 10000);
+//!
+//! let report = mtt.standings_report();
+//! assert_eq!(report.players_remaining, 18);
+//! assert_eq!(report.players[0].big_blinds, 30.0);
+//! let json = serde_json::to_string(&report).unwrap();
+//! assert!(json.contains("icm_equity"));
+//! ```
+
+use super::{chips, ICMCalculator, MTTManager};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// One player's line in a [`StandingsReport`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerStanding {
+    /// 1 for the chip leader (equal stacks are ordered by player id)
+    pub rank: u32,
+    pub player_id: u32,
+    pub stack: u32,
+    pub table_id: u32,
+    /// Seat index at the table
+    pub seat: u32,
+    /// Stack in big blinds at the current level
+    pub big_blinds: f64,
+    /// ICM equity of the remaining prize pool
+    pub icm_equity: f64,
+    /// Equity gained by doubling up, with the chips taken from the rest of the
+    /// field in proportion to their stacks
+    pub double_up_gain: f64,
+    /// Equity lost by busting next, i.e. equity above the payout for the place
+    /// the player would finish in
+    pub bust_loss: f64,
+}
+
+/// Next increase of the payout a player is guaranteed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayoutJump {
+    /// Eliminations still needed before the jump
+    pub eliminations: u32,
+    /// Increase over the payout for the current last place
+    pub amount: u64,
+}
+
+/// Current tournament standings enriched with ICM information
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StandingsReport {
+    /// Players still in the tournament, chip leader first
+    pub players: Vec<PlayerStanding>,
+    pub players_remaining: u32,
+    pub average_stack: f64,
+    pub big_blind: u32,
+    /// Sum of the payouts for the places still to be decided
+    pub remaining_prize_pool: u64,
+    /// `None` when the payouts cannot increase any more
+    pub next_payout_jump: Option<PayoutJump>,
+}
+
+impl MTTManager {
+    /// Standings of the players still in the tournament with their ICM equity
+    ///
+    /// Equities come from [`ICMCalculator`] over the payouts for places
+    /// `1..=players_remaining`, so they add up to `remaining_prize_pool`.
+    /// A single calculator is reused for every double-up scenario, and players
+    /// with equal stacks share one calculation since their scenarios only
+    /// differ by seat.
+    pub fn standings_report(&self) -> StandingsReport {
+        let mut players: Vec<(u32, u32, u32, u32)> = Vec::new(); // (player_id, stack, table_id, seat)
+        for table in &self.tables {
+            for (seat, player) in table.seats.iter().enumerate() {
+                if let Some(player) = player {
+                    if !player.is_sitting_out && player.stack_size > 0 {
+                        players.push((player.player_id, player.stack_size, table.table_id, seat as u32));
+                    }
+                }
+            }
+        }
+        players.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let remaining = players.len() as u32;
+        let payout_for = |position: u32| self.tournament_state.payout_for_position(position);
+        let payouts: Vec<u64> = (1..=remaining).map(payout_for).take_while(|&amount| amount > 0).collect();
+        // The amounts are shares of a u64 prize pool, so the sum only saturates on corrupt structures
+        let remaining_prize_pool = chips::checked_prize_pool(&payouts).unwrap_or(u64::MAX);
+
+        let stacks: Vec<u32> = players.iter().map(|p| p.1).collect();
+        let total_chips = chips::total_chips(&stacks);
+        let mut calculator = ICMCalculator::new(stacks.clone(), payouts);
+        let equities = calculator.calculate_equity();
+
+        // Busting in last place pays the last remaining payout (0 outside the money)
+        let bust_payout = payout_for(remaining) as f64;
+        let (_, big_blind, _) = self.tournament_state.current_blinds();
+
+        let mut doubled_equity: HashMap<u32, f64> = HashMap::new();
+        let standings = players
+            .iter()
+            .enumerate()
+            .map(|(idx, &(player_id, stack, table_id, seat))| {
+                let doubled = *doubled_equity.entry(stack).or_insert_with(|| {
+                    calculator.stacks = double_up(&stacks, idx, total_chips);
+                    calculator.calculate_equity()[idx]
+                });
+                PlayerStanding {
+                    rank: idx as u32 + 1,
+                    player_id,
+                    stack,
+                    table_id,
+                    seat,
+                    big_blinds: if big_blind > 0 { stack as f64 / big_blind as f64 } else { 0.0 },
+                    icm_equity: equities[idx],
+                    double_up_gain: doubled - equities[idx],
+                    bust_loss: equities[idx] - bust_payout,
+                }
+            })
+            .collect();
+
+        let next_payout_jump = (1..remaining).rev().find_map(|position| {
+            let amount = payout_for(position).saturating_sub(payout_for(remaining));
+            (amount > 0).then(|| PayoutJump {
+                eliminations: remaining - position,
+                amount,
+            })
+        });
+
+        StandingsReport {
+            players: standings,
+            players_remaining: remaining,
+            average_stack: if remaining > 0 { total_chips as f64 / remaining as f64 } else { 0.0 },
+            big_blind,
+            remaining_prize_pool,
+            next_payout_jump,
+        }
+    }
+}
+
+/// Stacks after `idx` doubles up against the rest of the field
+///
+/// The chips won are taken from every other player in proportion to their
+/// stack, capped at everything the others have.
+fn double_up(stacks: &[u32], idx: usize, total_chips: u64) -> Vec<u32> {
+    let others = total_chips - stacks[idx] as u64;
+    let won = (stacks[idx] as u64).min(others);
+    let doubled: Vec<u64> = stacks
+        .iter()
+        .enumerate()
+        .map(|(i, &stack)| {
+            if i == idx {
+                stack as u64 + won
+            } else {
+                (stack as u128 * (others - won) as u128 / others as u128) as u64
+            }
+        })
+        .collect();
+    // A doubled stack can exceed u32::MAX; ICM only needs the ratios
+    chips::fit_stacks(&doubled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::tournament::{AnteType, BlindLevel, TournamentStructure};
+
+    fn mtt() -> MTTManager {
+        let structure = TournamentStructure {
+            levels: vec![BlindLevel {
+                level: 1,
+                small_blind: 50,
+                big_blind: 100,
+                ante: 0,
+            }],
+            level_duration_minutes: 15,
+            starting_stack: 1500,
+            ante_schedule: vec![],
+            ante_type: AnteType::PerPlayer,
+        };
+        let mut mtt = MTTManager::new(27, 9, structure, 50000);
+        mtt.tables[1].seats[4].as_mut().unwrap().stack_size = 12000;
+        mtt.tables[2].seats[0].as_mut().unwrap().stack_size = 300;
+        mtt
+    }
+
+    #[test]
+    fn test_equities_sum_to_remaining_prize_pool() {
+        let mut mtt = mtt();
+        let report = mtt.standings_report();
+        assert_eq!(report.players_remaining, 27);
+        assert_eq!(report.remaining_prize_pool, 40000); // 3 paid places: 40% + 25% + 15%
+        let total: f64 = report.players.iter().map(|p| p.icm_equity).sum();
+        assert!((total - 40000.0).abs() < 1e-6, "{}", total);
+        assert_eq!(
+            report.next_payout_jump,
+            Some(PayoutJump {
+                eliminations: 24,
+                amount: 7500,
+            })
+        );
+
+        // Down to the final three the pool shrinks to what is left to pay
+        // (re-read every time since eliminations can rebalance tables)
+        while let [_, _, _, .., (player_id, _, table_id)] = mtt.get_tournament_standings()[..] {
+            mtt.eliminate_player(table_id, player_id);
+        }
+        let report = mtt.standings_report();
+        assert_eq!(report.players_remaining, 3);
+        let total: f64 = report.players.iter().map(|p| p.icm_equity).sum();
+        assert!((total - 40000.0).abs() < 1e-6, "{}", total);
+        assert_eq!(
+            report.next_payout_jump,
+            Some(PayoutJump {
+                eliminations: 1,
+                amount: 5000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_chip_leader_loses_more_by_busting() {
+        let report = mtt().standings_report();
+        let leader = &report.players[0];
+        let short = report.players.last().unwrap();
+        assert_eq!((leader.rank, leader.stack, leader.big_blinds), (1, 12000, 120.0));
+        assert_eq!((short.rank, short.stack, short.big_blinds), (27, 300, 3.0));
+        assert!(leader.bust_loss > short.bust_loss);
+        assert!(short.double_up_gain > 0.0);
+        assert_eq!(report.average_stack, (12000.0 + 300.0 + 25.0 * 1500.0) / 27.0);
+
+        // Equal stacks share one what-if result
+        assert_eq!(report.players[1].double_up_gain, report.players[2].double_up_gain);
+    }
+
+    #[test]
+    fn test_report_json_round_trip() {
+        let report = mtt().standings_report();
+        let json = serde_json::to_string(&report).unwrap();
+        let decoded: StandingsReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, report);
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+    }
+}
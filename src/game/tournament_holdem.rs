@@ -2,9 +2,14 @@
 // Integrates tournament context with CFR learning for realistic tournament play
 
 use crate::game::holdem::{Act as HoldemAction, State as HoldemState};
-use crate::game::tournament::{ICMCalculator, TournamentEvaluator, TournamentState};
-use crate::solver::cfr_core::{Game, GameState, Trainer};
-use rand::rngs::ThreadRng;
+use crate::game::all_in::AllInAdjudicator;
+use crate::game::observation::{ActionPolicy, ObservedState};
+use crate::game::tournament::pay_jumps;
+use crate::game::tournament::{AnteType, ICMCalculator, TournamentEvaluator, TournamentState, TournamentStrategy};
+use crate::game::value_unit::ValueUnit;
+use crate::solver::blend::{BlendPolicy, BlendedStrategy};
+use crate::solver::cfr_core::{Game, GameState, TerminalEvaluator, Trainer};
+use rand::Rng;
 
 /// 정규 홀덤과 토너먼트 상황을 결합한 토너먼트 텍사스 홀덤 상태
 #[derive(Clone, Debug)]
@@ -23,6 +28,9 @@ pub struct TournamentHoldemState {
 
     /// 버블 압박 지시자
     pub bubble_pressure: f64,
+
+    /// 레벨 종료까지 남은 핸드와 블라인드 상승 예측 (None이면 블라인드 압박을 무시)
+    pub blind_pressure: Option<BlindPressureModel>,
 }
 
 #[derive(Clone, Debug)]
@@ -30,7 +38,100 @@ pub struct TournamentPlayerPosition {
     pub player_id: u32,
     pub stack_size: u32,
     pub position_rank: u32,   // 1 = chip leader, higher = shorter stack
-    pub pay_jump_equity: f64, // Equity difference between current and next payout
+    pub pay_jump_equity: f64, // Next pay jump weighted by the chance of busting before it (`pay_jumps`)
+}
+
+/// 블라인드 상승 압박 모델
+///
+/// 레벨 시간과 가정한 시간당 핸드 수로 현재 레벨에 남은 핸드 수를 추정하고,
+/// 이번 핸드 이후 `horizon_hands` 동안 각 좌석이 낼 블라인드와 앤티를 예측합니다.
+/// 레벨이 곧 끝나면 다음 레벨 블라인드로 계산하므로 숏스택의 폴드 비용이 커집니다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlindPressureModel {
+    /// 이번 핸드를 포함해 현재 레벨에서 남은 핸드 수 (최소 1)
+    pub hands_left_in_level: u32,
+    /// 레벨 시간을 핸드 수로 바꿀 때 가정한 시간당 핸드 수
+    pub hands_per_hour: u32,
+    /// 현재 레벨 (small blind, big blind, ante)
+    pub current_blinds: (u32, u32, u32),
+    /// 다음 레벨 (small blind, big blind, ante), 마지막 레벨이면 현재와 같음
+    pub next_blinds: (u32, u32, u32),
+    pub ante_type: AnteType,
+    /// 블라인드 비용을 예측할 이후 핸드 수 (기본 한 바퀴)
+    pub horizon_hands: u32,
+}
+
+impl BlindPressureModel {
+    /// 토너먼트 시계로 현재 레벨에 남은 핸드 수 추정
+    ///
+    /// 레벨 시간이 0이면 레벨이 끝나지 않는 것으로 보고 `u32::MAX` 핸드를 남깁니다.
+    pub fn from_clock(tournament_state: &TournamentState, hands_per_hour: u32, table_size: usize) -> Self {
+        let structure = &tournament_state.structure;
+        let duration = structure.level_duration_minutes;
+        let hands_left_in_level = if duration == 0 {
+            u32::MAX
+        } else {
+            let level_end = tournament_state.current_level.saturating_mul(duration);
+            let minutes_left = level_end.saturating_sub(tournament_state.minutes_elapsed).min(duration);
+            (minutes_left as u64 * hands_per_hour as u64).div_ceil(60).clamp(1, u32::MAX as u64) as u32
+        };
+        let current_blinds = tournament_state.current_blinds();
+        let next_blinds = structure
+            .levels
+            .get(tournament_state.current_level as usize)
+            .map_or(current_blinds, |level| (level.small_blind, level.big_blind, level.ante));
+
+        BlindPressureModel {
+            hands_left_in_level,
+            hands_per_hour,
+            current_blinds,
+            next_blinds,
+            ante_type: structure.ante_type,
+            horizon_hands: table_size.max(1) as u32,
+        }
+    }
+
+    /// 남은 핸드 수를 직접 지정 (최소 1)
+    pub fn with_hands_left(mut self, hands_left: u32) -> Self {
+        self.hands_left_in_level = hands_left.max(1);
+        self
+    }
+
+    /// 이후 `horizon_hands` 동안 한 좌석이 낼 것으로 예상되는 블라인드와 앤티 (칩)
+    ///
+    /// 블라인드는 테이블을 도는 동안 좌석마다 고르게 나눠 낸다고 봅니다.
+    pub fn projected_blind_cost(&self, table_size: usize) -> f64 {
+        let table_size = table_size.max(1) as f64;
+        (0..self.horizon_hands)
+            .map(|hand| {
+                // 이번 핸드 다음부터 레벨이 끝날 때까지는 현재 블라인드
+                let (sb, bb, ante) = if hand + 1 < self.hands_left_in_level {
+                    self.current_blinds
+                } else {
+                    self.next_blinds
+                };
+                let ante = match self.ante_type {
+                    AnteType::PerPlayer => ante as f64,
+                    AnteType::BigBlindAnte | AnteType::ButtonAnte => ante as f64 / table_size,
+                };
+                (sb + bb) as f64 / table_size + ante
+            })
+            .sum()
+    }
+
+    /// 정보 집합 키에 넣는 긴급도 구간 (1 = 이번이 레벨의 마지막 핸드, 4 = 여유 있음)
+    ///
+    /// 다음 레벨 블라인드가 오르지 않으면 남은 핸드 수와 관계없이 4입니다.
+    pub fn urgency_bucket(&self) -> u64 {
+        let horizon = self.horizon_hands.max(1);
+        match self.hands_left_in_level {
+            _ if self.next_blinds == self.current_blinds => 4,
+            1 => 1,
+            n if n <= horizon => 2,
+            n if n <= horizon.saturating_mul(2) => 3,
+            _ => 4,
+        }
+    }
 }
 
 impl TournamentHoldemState {
@@ -41,29 +142,25 @@ impl TournamentHoldemState {
         player_stacks: Vec<u32>,
     ) -> Self {
         let mut tournament_positions = Vec::new();
-        let active_players = tournament_state.players_remaining as usize;
+        let pay_jump_equities = pay_jumps::pay_jump_equities(
+            &player_stacks,
+            tournament_state.players_remaining,
+            &tournament_state.payout_structure,
+        );
 
-        // Create player positions with tournament context
-        for (i, &stack) in player_stacks.iter().take(active_players).enumerate() {
+        // Create player positions with tournament context (one per seat at the table,
+        // even when fewer players remain in the tournament than seats were passed)
+        for (i, (&stack, &pay_jump_equity)) in player_stacks.iter().zip(&pay_jump_equities).enumerate() {
             tournament_positions.push(TournamentPlayerPosition {
                 player_id: i as u32,
                 stack_size: stack,
-                position_rank: 0,     // Will be calculated
-                pay_jump_equity: 0.0, // Will be calculated
+                position_rank: 0, // Will be calculated
+                pay_jump_equity,
             });
         }
 
         // Calculate ICM values
-        let default_payouts: Vec<u64> = vec![100, 60, 40, 25, 15, 10]; // Default payout structure
-        let payouts = if tournament_state.payout_structure.is_empty() {
-            default_payouts
-        } else {
-            tournament_state
-                .payout_structure
-                .iter()
-                .map(|p| p.amount)
-                .collect()
-        };
+        let payouts = Self::payouts_for(&tournament_state);
         let icm_calculator = ICMCalculator::new(player_stacks.clone(), payouts);
         let icm_values = icm_calculator.calculate_equity();
 
@@ -76,6 +173,85 @@ impl TournamentHoldemState {
             tournament_positions,
             icm_values,
             bubble_pressure,
+            blind_pressure: None,
+        }
+    }
+
+    /// 블라인드 압박 모델을 유틸리티와 정보 집합 키에 반영
+    pub fn with_blind_pressure(mut self, model: BlindPressureModel) -> Self {
+        self.blind_pressure = Some(model);
+        self
+    }
+
+    /// 한 레벨 안의 여러 시점에 대한 루트 상태 생성
+    ///
+    /// 토너먼트 시계로 레벨 전체의 핸드 수를 구하고, 레벨 시작부터 마지막 핸드까지
+    /// 고르게 나눈 `count`개 시점의 블라인드 압박 모델을 붙인 루트를 반환합니다.
+    pub fn across_level(&self, hands_per_hour: u32, count: usize) -> Vec<Self> {
+        let mut level_start = self.tournament_state.clone();
+        level_start.minutes_elapsed =
+            level_start.current_level.saturating_sub(1) * level_start.structure.level_duration_minutes;
+        let model = BlindPressureModel::from_clock(&level_start, hands_per_hour, self.tournament_positions.len());
+        let level_hands = model.hands_left_in_level;
+
+        (0..count)
+            .map(|i| {
+                let hands_left = if count <= 1 {
+                    level_hands
+                } else {
+                    let step = (level_hands - 1) as f64 / (count - 1) as f64;
+                    level_hands - (step * i as f64).round() as u32
+                };
+                self.clone().with_blind_pressure(model.clone().with_hands_left(hands_left))
+            })
+            .collect()
+    }
+
+    /// 블라인드 압박을 반영한 유효 스택 (앞으로 낼 블라인드와 앤티를 미리 뺌)
+    fn pressured_stacks(&self, stacks: &[u32]) -> Vec<u32> {
+        match &self.blind_pressure {
+            Some(model) => {
+                let cost = model.projected_blind_cost(stacks.len());
+                stacks.iter().map(|&stack| (stack as f64 - cost).max(0.0).round() as u32).collect()
+            }
+            None => stacks.to_vec(),
+        }
+    }
+
+    /// Share of the next pay jump's place a seat stands to lose by busting first
+    ///
+    /// `pay_jump_equity` over the payout for the jump's place, in `[0, 1]`; 0 when no
+    /// jump is within reach of this table.
+    pub fn pay_jump_pressure(&self, seat: usize) -> f64 {
+        let equity = self.tournament_positions.get(seat).map_or(0.0, |position| position.pay_jump_equity);
+        if equity <= 0.0 {
+            return 0.0;
+        }
+        let tournament = &self.tournament_state;
+        let Some(jump) = pay_jumps::ladder(tournament.players_remaining, &tournament.payout_structure).first().copied()
+        else {
+            return 0.0;
+        };
+        match tournament.payout_for_position(jump.position) {
+            0 => 0.0,
+            target => (equity / target as f64).min(1.0),
+        }
+    }
+
+    /// ICM 계산에 쓰는 상금 구조 (구조가 비어 있으면 기본 상금 사용)
+    pub fn payouts(&self) -> Vec<u64> {
+        Self::payouts_for(&self.tournament_state)
+    }
+
+    fn payouts_for(tournament_state: &TournamentState) -> Vec<u64> {
+        if tournament_state.payout_structure.is_empty() {
+            vec![100, 60, 40, 25, 15, 10] // Default payout structure
+        } else {
+            tournament_state
+                .payout_structure
+                .iter()
+                .map(|p| p.amount)
+                .collect()
         }
     }
 
@@ -103,7 +279,7 @@ impl TournamentHoldemState {
             .holdem_state
             .stack
             .iter()
-            .take(self.tournament_state.players_remaining as usize)
+            .take(self.tournament_positions.len())
             .cloned()
             .collect();
 
@@ -136,6 +312,9 @@ pub struct TournamentHoldem {
 }
 
 impl TournamentHoldem {
+    /// Unit of the values returned by `util` (ICM equity change in prize dollars)
+    pub const UTILITY_UNIT: ValueUnit = ValueUnit::PrizeDollars;
+
     pub fn new(tournament_state: TournamentState, player_stacks: Vec<u32>) -> Self {
         let evaluator = TournamentEvaluator::new(tournament_state, player_stacks);
 
@@ -194,53 +373,32 @@ impl Game for TournamentHoldem {
         new_state
     }
 
-    fn apply_chance(state: &Self::State, rng: &mut ThreadRng) -> Self::State {
+    fn apply_chance<R: Rng>(state: &Self::State, rng: &mut R) -> Self::State {
         let mut new_state = state.clone();
         new_state.holdem_state = crate::game::holdem::State::apply_chance(&state.holdem_state, rng);
         new_state
     }
 
-    fn util(state: &Self::State, hero: usize) -> f64 {
-        if !state.holdem_state.is_terminal() {
-            return 0.0;
-        }
-
-        // Get base Hold'em utility (chip change)
-        let chip_change = crate::game::holdem::State::util(&state.holdem_state, hero) as i32;
-
-        // Convert to ICM-adjusted utility
-        let current_stacks: Vec<u32> = state
-            .holdem_state
-            .stack
-            .iter()
-            .take(state.tournament_state.players_remaining as usize)
-            .cloned()
-            .collect();
-
-        let payouts: Vec<u64> = state
-            .tournament_state
-            .payout_structure
-            .iter()
-            .map(|p| p.amount)
-            .collect();
-
-        let icm_evaluator = ICMCalculator::new(current_stacks, payouts);
-        let icm_adjustment = icm_evaluator.calculate_icm_pressure(hero, chip_change);
-
-        // Apply bubble pressure adjustment
-        let bubble_adjustment = if state.bubble_pressure > 0.5 {
-            // High bubble pressure - be more risk averse
-            if chip_change < 0 {
-                chip_change as f64 * (1.0 + state.bubble_pressure)
-            } else {
-                chip_change as f64 * (1.0 - state.bubble_pressure * 0.3)
-            }
-        } else {
-            chip_change as f64
-        };
+    fn chance_outcomes(state: &Self::State) -> Option<Vec<(Self::State, f64)>> {
+        let outcomes = crate::game::holdem::State::chance_outcomes(&state.holdem_state)?;
+        Some(
+            outcomes
+                .into_iter()
+                .map(|(holdem_state, probability)| {
+                    let mut new_state = state.clone();
+                    new_state.holdem_state = holdem_state;
+                    (new_state, probability)
+                })
+                .collect(),
+        )
+    }
 
-        // Combine ICM and bubble adjustments
-        icm_adjustment + bubble_adjustment * 0.1
+    /// Utilities are in prize dollars (`ValueUnit::PrizeDollars`): the change in the
+    /// hero's ICM equity between the stacks at the start of the hand and the stacks
+    /// after applying every seat's chip result. Unfinished boards are adjudicated by
+    /// `AllInAdjudicator`, taking the expected ICM equity over the showdown outcomes.
+    fn util(state: &Self::State, hero: usize) -> f64 {
+        IcmTerminalEvaluator.evaluate(state).get(hero).copied().unwrap_or(0.0)
     }
 
     fn info_key(state: &Self::State, player: usize) -> Self::InfoKey {
@@ -254,17 +412,92 @@ impl Game for TournamentHoldem {
             (state.icm_values[player] * 1000.0) as u64,
         );
 
+        // Level about to end vs. plenty of hands left (0 keeps keys without a model unchanged)
+        let urgency = state.blind_pressure.as_ref().map_or(0, |model| model.urgency_bucket());
+
+        // Pay jump pressure in tenths (0 keeps keys with no jump in reach unchanged)
+        let pay_jump = (state.pay_jump_pressure(player) * 10.0).round() as u64;
+
         // Combine base key with tournament context
         base_key.wrapping_add(
             tournament_context
                 .0
                 .wrapping_mul(1000003)
                 .wrapping_add(tournament_context.1.wrapping_mul(1000033))
-                .wrapping_add(tournament_context.2.wrapping_mul(1000037)),
+                .wrapping_add(tournament_context.2.wrapping_mul(1000037))
+                .wrapping_add(urgency.wrapping_mul(1000039))
+                .wrapping_add(pay_jump.wrapping_mul(1000081)),
         )
     }
 }
 
+/// ICM 터미널 평가기 (상금 달러 단위)
+///
+/// 모든 좌석의 ICM 에퀴티 변화를 한 번에 계산합니다. 쇼다운 결과마다 ICM을
+/// 한 번만 계산하므로 히어로마다 `util`을 부르는 것보다 저렴하며, 값은 `util`과 같습니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IcmTerminalEvaluator;
+
+impl TerminalEvaluator<TournamentHoldem> for IcmTerminalEvaluator {
+    /// Change in every seat's ICM equity between the stacks at the start of the
+    /// hand and the stacks after applying each seat's chip result. Unfinished
+    /// boards are adjudicated by `AllInAdjudicator`, taking the expected ICM
+    /// equity over the showdown outcomes.
+    ///
+    /// With a `BlindPressureModel`, the blinds and antes projected over the next
+    /// hands are taken off every stack before and after the hand, so short stacks
+    /// lose more equity by waiting when the level is about to end.
+    fn evaluate(&self, state: &TournamentHoldemState) -> Vec<f64> {
+        let mut utils = vec![0.0; TournamentHoldem::N_PLAYERS];
+        if !state.holdem_state.is_terminal() {
+            return utils;
+        }
+
+        let holdem = &state.holdem_state;
+        let start_stacks: Vec<u32> = state
+            .tournament_positions
+            .iter()
+            .map(|p| p.stack_size)
+            .collect();
+        let payouts = state.payouts();
+        let before = ICMCalculator::new(state.pressured_stacks(&start_stacks), payouts.clone()).calculate_equity();
+        let equity_after = |chip_change: &dyn Fn(usize) -> f64| {
+            let end_stacks: Vec<u32> = start_stacks
+                .iter()
+                .enumerate()
+                .map(|(seat, &stack)| {
+                    let change = if seat < 6 { chip_change(seat) } else { 0.0 };
+                    (stack as f64 + change).max(0.0).round() as u32
+                })
+                .collect();
+            ICMCalculator::new(state.pressured_stacks(&end_stacks), payouts.clone()).calculate_equity()
+        };
+
+        let alive_count = holdem.alive.iter().filter(|&&a| a).count();
+        let mut after = vec![0.0; start_stacks.len()];
+        if alive_count >= 2 {
+            let net_pot = holdem.net_pot() as f64;
+            for outcome in AllInAdjudicator::deterministic().state_equity(holdem).outcomes {
+                let share = net_pot / outcome.winners.len() as f64;
+                let chip_change = |seat: usize| {
+                    let won = if outcome.winners.contains(&seat) { share } else { 0.0 };
+                    won - (holdem.invested[seat] + holdem.antes[seat]) as f64
+                };
+                for (seat, equity) in equity_after(&chip_change).into_iter().enumerate() {
+                    after[seat] += outcome.probability * equity;
+                }
+            }
+        } else {
+            after = equity_after(&|seat| crate::game::holdem::State::util(holdem, seat));
+        }
+
+        for (seat, util) in utils.iter_mut().enumerate().take(start_stacks.len()) {
+            *util = after[seat] - before[seat];
+        }
+        utils
+    }
+}
+
 impl TournamentHoldem {
     /// 토너먼트 상황에서 액션이 허용되는지 확인
     fn is_action_allowed_in_tournament(
@@ -291,23 +524,35 @@ impl TournamentHoldem {
 }
 
 /// ICM 계산을 통합한 토너먼트 CFR 훈련기
+///
+/// 유틸리티와 `evaluate_tournament_decision`의 결과는 상금 달러
+/// (`ValueUnit::PrizeDollars`, 핸드 전후 ICM 에퀴티 변화량) 단위입니다.
 pub struct TournamentCFRTrainer {
     pub base_trainer: Trainer<TournamentHoldem>,
     pub tournament_game: TournamentHoldem,
+    /// Visit-based blending for strategy lookups (None serves the average strategy as is)
+    pub blend_policy: Option<BlendPolicy>,
 }
 
 impl TournamentCFRTrainer {
     /// 새로운 토너먼트 CFR 훈련기 생성
     pub fn new(tournament_state: TournamentState, player_stacks: Vec<u32>) -> Self {
         let tournament_game = TournamentHoldem::new(tournament_state, player_stacks);
-        let base_trainer = Trainer::new();
+        let base_trainer = Trainer::with_evaluator(IcmTerminalEvaluator);
 
         TournamentCFRTrainer {
             base_trainer,
             tournament_game,
+            blend_policy: None,
         }
     }
 
+    /// Blend rarely visited info sets with a fallback strategy when serving
+    pub fn with_blend_policy(mut self, policy: BlendPolicy) -> Self {
+        self.blend_policy = Some(policy);
+        self
+    }
+
     /// ICM 고려사항과 함께 토너먼트 전략 훈련
     pub fn train_tournament_strategy(
         &mut self,
@@ -323,6 +568,12 @@ impl TournamentCFRTrainer {
 
         println!("✅ Tournament training completed in {:.2?}", elapsed);
         println!("📈 Nodes created: {}", self.base_trainer.nodes.len());
+        println!("💵 Utility unit: {:?}", self.utility_unit());
+    }
+
+    /// 학습 유틸리티와 의사결정 평가 값의 단위
+    pub fn utility_unit(&self) -> ValueUnit {
+        TournamentHoldem::UTILITY_UNIT
     }
 
     /// 토너먼트 상황에 대한 전략 가져오기
@@ -331,19 +582,94 @@ impl TournamentCFRTrainer {
         state: &TournamentHoldemState,
         player: usize,
     ) -> Vec<f64> {
-        let info_key = TournamentHoldem::info_key(state, player);
+        self.get_blended_tournament_strategy(state, player).probabilities
+    }
 
-        if let Some(node) = self.base_trainer.nodes.get(&info_key) {
-            node.average()
-        } else {
+    /// Strategy lookup annotated with the weight given to the trained strategy
+    ///
+    /// Without a blend policy, trained info sets return their average strategy with full
+    /// trust and untrained ones a uniform strategy with zero trust. With a policy, the
+    /// heuristic fallback is the bubble-adjusted uniform strategy (`TournamentStrategy`).
+    pub fn get_blended_tournament_strategy(
+        &self,
+        state: &TournamentHoldemState,
+        player: usize,
+    ) -> BlendedStrategy {
+        let info_key = TournamentHoldem::info_key(state, player);
+        let node = self.base_trainer.nodes.get(&info_key);
+        let legal_count = TournamentHoldem::legal_actions(state).len();
+
+        match (&self.blend_policy, node) {
+            (Some(policy), node) => {
+                let average = node.map(|node| node.average());
+                let visits = node.map_or(0, |node| node.visits());
+                policy.blend(average.as_deref(), visits, legal_count, || {
+                    Self::heuristic_strategy(state, player, legal_count)
+                })
+            }
+            (None, Some(node)) => BlendedStrategy {
+                probabilities: node.average(),
+                trust_weight: 1.0,
+            },
             // Default uniform strategy if no training data
-            let actions = TournamentHoldem::legal_actions(state);
-            let uniform_prob = 1.0 / actions.len() as f64;
-            vec![uniform_prob; actions.len()]
+            (None, None) => BlendedStrategy {
+                probabilities: vec![1.0 / legal_count as f64; legal_count],
+                trust_weight: 0.0,
+            },
         }
     }
 
-    /// ICM 고려사항과 함께 토너먼트 의사결정 평가
+    /// Uniform strategy shifted toward folding near the bubble and near pay jumps
+    fn heuristic_strategy(state: &TournamentHoldemState, player: usize, legal_count: usize) -> Vec<f64> {
+        let uniform = vec![1.0 / legal_count as f64; legal_count];
+        if state.tournament_state.players_remaining == 0 {
+            return uniform;
+        }
+        let stack = state.holdem_state.stack[player.min(5)];
+        TournamentStrategy::new(&state.tournament_state, stack)
+            .with_pay_jump_pressure(state.pay_jump_pressure(player))
+            .adjust_strategy(&uniform)
+    }
+
+    /// 학습된 정보 집합이 있는지 (없으면 `get_tournament_strategy`는 균등/휴리스틱 전략)
+    pub fn has_trained_strategy(&self, state: &TournamentHoldemState, player: usize) -> bool {
+        self.base_trainer
+            .nodes
+            .contains_key(&TournamentHoldem::info_key(state, player))
+    }
+
+    /// `get_tournament_strategy`로 `legal_actions` 중 하나를 샘플링
+    ///
+    /// 토너먼트 규칙이 거르지 않은 액션이 없거나 확률 합이 0이면 None입니다.
+    pub fn sample_action<R: Rng>(
+        &self,
+        state: &TournamentHoldemState,
+        player: usize,
+        legal_actions: &[HoldemAction],
+        rng: &mut R,
+    ) -> Option<HoldemAction> {
+        let strategy = self.get_tournament_strategy(state, player);
+        let weighted: Vec<(HoldemAction, f64)> = TournamentHoldem::legal_actions(state)
+            .into_iter()
+            .zip(strategy)
+            .filter(|(action, prob)| legal_actions.contains(action) && *prob > 0.0)
+            .collect();
+
+        let total: f64 = weighted.iter().map(|(_, prob)| prob).sum();
+        if !(total > 0.0 && total.is_finite()) {
+            return None;
+        }
+        let mut roll = rng.gen::<f64>() * total;
+        for &(action, prob) in &weighted {
+            if roll < prob {
+                return Some(action);
+            }
+            roll -= prob;
+        }
+        weighted.last().map(|&(action, _)| action)
+    }
+
+    /// ICM 고려사항과 함께 토너먼트 의사결정 평가 (상금 달러 단위)
     pub fn evaluate_tournament_decision(
         &self,
         state: &TournamentHoldemState,
@@ -376,6 +702,53 @@ impl TournamentCFRTrainer {
     }
 }
 
+/// 토너먼트 CFR 전략을 따르는 플레이어 정책
+///
+/// 관찰 상태를 토너먼트 상황(`TournamentState`, 핸드 시작 시 테이블 스택)과 묶어
+/// `get_tournament_strategy`로 액션을 샘플링합니다. 토너먼트 규칙이 거르지 않은
+/// 액션이 없거나 확률 합이 0이면 주어진 합법 액션 중 균등하게 고릅니다.
+pub struct TournamentPolicy<'a, R: Rng> {
+    trainer: &'a TournamentCFRTrainer,
+    tournament_state: TournamentState,
+    player_stacks: Vec<u32>,
+    rng: R,
+}
+
+impl<'a, R: Rng> TournamentPolicy<'a, R> {
+    pub fn new(
+        trainer: &'a TournamentCFRTrainer,
+        tournament_state: TournamentState,
+        player_stacks: Vec<u32>,
+        rng: R,
+    ) -> Self {
+        Self {
+            trainer,
+            tournament_state,
+            player_stacks,
+            rng,
+        }
+    }
+
+    /// 새 핸드의 토너먼트 상황으로 교체
+    pub fn set_context(&mut self, tournament_state: TournamentState, player_stacks: Vec<u32>) {
+        self.tournament_state = tournament_state;
+        self.player_stacks = player_stacks;
+    }
+}
+
+impl<R: Rng> ActionPolicy for TournamentPolicy<'_, R> {
+    fn choose_action(&mut self, observed: &ObservedState, legal_actions: &[HoldemAction]) -> HoldemAction {
+        let state = TournamentHoldemState::new_tournament_hand(
+            observed.to_state(),
+            self.tournament_state.clone(),
+            self.player_stacks.clone(),
+        );
+        self.trainer
+            .sample_action(&state, observed.player, legal_actions, &mut self.rng)
+            .unwrap_or_else(|| legal_actions[self.rng.gen_range(0..legal_actions.len())])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -389,6 +762,7 @@ mod tests {
                 level_duration_minutes: 15,
                 starting_stack: 1500,
                 ante_schedule: vec![],
+                ante_type: crate::game::tournament::AnteType::PerPlayer,
             },
             100,
             10000,
@@ -414,6 +788,7 @@ mod tests {
                 level_duration_minutes: 15,
                 starting_stack: 1500,
                 ante_schedule: vec![],
+                ante_type: crate::game::tournament::AnteType::PerPlayer,
             },
             6, // Use 6 players to match holdem state
             5000,
@@ -451,6 +826,7 @@ mod tests {
                 level_duration_minutes: 15,
                 starting_stack: 1500,
                 ante_schedule: vec![],
+                ante_type: crate::game::tournament::AnteType::PerPlayer,
             },
             6,
             5000,
@@ -475,4 +851,297 @@ mod tests {
             println!("✅ Next state transition successful");
         }
     }
+
+    #[test]
+    fn test_tournament_util_is_icm_dollar_change() {
+        let tournament_state = TournamentState::new(
+            crate::game::tournament::TournamentStructure {
+                levels: vec![],
+                level_duration_minutes: 15,
+                starting_stack: 1500,
+                ante_schedule: vec![],
+                ante_type: crate::game::tournament::AnteType::PerPlayer,
+            },
+            3,
+            3000,
+        );
+        let state = TournamentHoldemState::new_tournament_hand(
+            crate::game::holdem::State::new(),
+            tournament_state,
+            vec![1000, 1000, 1000],
+        );
+        let folder = state.holdem_state.to_act;
+        let terminal = TournamentHoldem::next_state(&state, HoldemAction::Fold);
+        assert!(terminal.is_terminal());
+        assert_eq!(TournamentHoldem::UTILITY_UNIT, ValueUnit::PrizeDollars);
+
+        // 폴드한 쪽은 블라인드만큼 칩을 잃으므로 ICM 에퀴티도 줄어듦
+        let folder_util = TournamentHoldem::util(&terminal, folder);
+        let winner_util = TournamentHoldem::util(&terminal, 1 - folder);
+        assert!(folder_util < 0.0, "{}", folder_util);
+        assert!(winner_util > 0.0, "{}", winner_util);
+        // 상금 달러 변화량은 총 상금을 넘을 수 없음
+        let prize_pool: u64 = terminal.payouts().iter().sum();
+        assert!(winner_util < prize_pool as f64);
+    }
+
+    #[test]
+    fn test_icm_evaluator_matches_util_for_every_seat() {
+        let tournament_state = TournamentState::new(
+            crate::game::tournament::TournamentStructure {
+                levels: vec![],
+                level_duration_minutes: 15,
+                starting_stack: 1500,
+                ante_schedule: vec![],
+                ante_type: crate::game::tournament::AnteType::PerPlayer,
+            },
+            3,
+            3000,
+        );
+        let state = TournamentHoldemState::new_tournament_hand(
+            crate::game::holdem::State::new(),
+            tournament_state,
+            vec![1000, 1000, 1000],
+        );
+        // 폴드로 끝난 핸드와 올인 쇼다운
+        let folded = TournamentHoldem::next_state(&state, HoldemAction::Fold);
+        let shoved = TournamentHoldem::next_state(&state, HoldemAction::Raise(1));
+        let all_in = TournamentHoldem::next_state(&shoved, HoldemAction::Call);
+        assert!(folded.is_terminal() && all_in.is_terminal());
+
+        for terminal in [&folded, &all_in] {
+            let utils = IcmTerminalEvaluator.evaluate(terminal);
+            assert_eq!(utils.len(), TournamentHoldem::N_PLAYERS);
+            for (seat, util) in utils.iter().enumerate() {
+                assert_eq!(*util, TournamentHoldem::util(terminal, seat));
+            }
+            // 총 상금은 그대로이므로 ICM 변화량의 합은 0
+            assert!(utils.iter().sum::<f64>().abs() < 1e-6, "{:?}", utils);
+        }
+        // 터미널이 아니면 0
+        assert!(IcmTerminalEvaluator.evaluate(&state).iter().all(|&u| u == 0.0));
+    }
+
+    #[test]
+    fn test_blended_strategy_trusts_visited_nodes() {
+        use crate::solver::blend::FallbackSource;
+        use crate::solver::cfr_core::Node;
+
+        let tournament_state = TournamentState::new(
+            crate::game::tournament::TournamentStructure {
+                levels: vec![],
+                level_duration_minutes: 15,
+                starting_stack: 1500,
+                ante_schedule: vec![],
+                ante_type: crate::game::tournament::AnteType::PerPlayer,
+            },
+            3,
+            3000,
+        );
+        let state = TournamentHoldemState::new_tournament_hand(
+            crate::game::holdem::State::new(),
+            tournament_state.clone(),
+            vec![1000, 1000, 1000],
+        );
+        let legal_count = TournamentHoldem::legal_actions(&state).len();
+        let key = TournamentHoldem::info_key(&state, 0);
+
+        let unblended = TournamentCFRTrainer::new(tournament_state.clone(), vec![1000, 1000, 1000]);
+        let mut trainer = TournamentCFRTrainer::new(tournament_state, vec![1000, 1000, 1000]).with_blend_policy(
+            BlendPolicy {
+                min_visits_full_trust: 1_000,
+                fallback: FallbackSource::Uniform,
+            },
+        );
+        let uniform = 1.0 / legal_count as f64;
+        assert_eq!(unblended.get_tournament_strategy(&state, 0), vec![uniform; legal_count]);
+
+        // 마지막 액션만 고르는 노드, 2회 방문
+        let mut node = Node::new(legal_count, vec![1.0; legal_count]);
+        node.update_strategy(legal_count - 1, 1.0);
+        node.record_visit();
+        node.record_visit();
+        trainer.base_trainer.nodes.insert(key, node.clone());
+
+        let rare = trainer.get_blended_tournament_strategy(&state, 0);
+        assert_eq!(rare.trust_weight, 0.002);
+        assert_eq!(rare.probabilities.len(), legal_count);
+        assert!((rare.probabilities[0] - uniform).abs() < 0.01, "{:?}", rare.probabilities);
+        assert!((rare.probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        for _ in 0..10_000 {
+            node.record_visit();
+        }
+        trainer.base_trainer.nodes.insert(key, node.clone());
+        let common = trainer.get_blended_tournament_strategy(&state, 0);
+        assert_eq!(common.trust_weight, 1.0);
+        assert_eq!(common.probabilities, node.average());
+    }
+
+    #[test]
+    fn test_policy_plays_seats_beyond_players_remaining() {
+        use crate::game::observation::play_to_terminal;
+        use rand::{rngs::StdRng, SeedableRng};
+
+        // 토너먼트에 3명이 남았어도 6인 테이블 스택을 그대로 넘기면 모든 좌석이 정책을 쓸 수 있음
+        let mut tournament_state = TournamentState::new(
+            crate::game::tournament::TournamentStructure {
+                levels: vec![],
+                level_duration_minutes: 15,
+                starting_stack: 1500,
+                ante_schedule: vec![],
+                ante_type: crate::game::tournament::AnteType::PerPlayer,
+            },
+            30,
+            3000,
+        );
+        tournament_state.players_remaining = 3;
+        let stacks = [1000; 6];
+        let trainer = TournamentCFRTrainer::new(tournament_state.clone(), stacks.to_vec());
+
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..20 {
+            let root = crate::game::holdem::State::new_hand([50, 100], stacks, 6);
+            let mut policies: Vec<TournamentPolicy<StdRng>> = (0..6)
+                .map(|seat| {
+                    TournamentPolicy::new(&trainer, tournament_state.clone(), stacks.to_vec(), StdRng::seed_from_u64(seat))
+                })
+                .collect();
+            let mut policy_refs: Vec<&mut dyn ActionPolicy> =
+                policies.iter_mut().map(|policy| policy as &mut dyn ActionPolicy).collect();
+            let end = play_to_terminal(&root, &mut policy_refs, &mut rng);
+            let settled = end.settle(stacks, &mut rng);
+            assert_eq!(settled.iter().sum::<u32>(), 6000);
+        }
+    }
+
+    #[test]
+    fn test_pay_jump_equity_grows_with_jump_size() {
+        use crate::game::tournament::PayoutLevel;
+
+        // Four left, the short stack is one elimination from moving up from 4th to 3rd
+        let state_with_payouts = |amounts: [u64; 4]| {
+            let mut tournament_state = blind_pressure_tournament();
+            tournament_state.players_remaining = 4;
+            tournament_state.payout_structure = amounts
+                .iter()
+                .enumerate()
+                .map(|(i, &amount)| PayoutLevel {
+                    position: i as u32 + 1,
+                    percentage: 0.0,
+                    amount,
+                })
+                .collect();
+            TournamentHoldemState::new_tournament_hand(
+                crate::game::holdem::State::new(),
+                tournament_state,
+                vec![300, 3000, 3000, 3000],
+            )
+        };
+        let double = state_with_payouts([5000, 3000, 2000, 1000]);
+        let small = state_with_payouts([5000, 3000, 1100, 1000]);
+
+        let short_equity = |state: &TournamentHoldemState| state.tournament_positions[0].pay_jump_equity;
+        assert!(short_equity(&double) > short_equity(&small));
+        assert!(short_equity(&double) > double.tournament_positions[1].pay_jump_equity);
+        assert!(double.pay_jump_pressure(0) > small.pay_jump_pressure(0));
+
+        // The heuristic fallback folds more when the jump is worth more
+        let legal_count = TournamentHoldem::legal_actions(&double).len();
+        let fold = |state: &TournamentHoldemState| TournamentCFRTrainer::heuristic_strategy(state, 0, legal_count)[0];
+        assert!(fold(&double) > fold(&small), "{} <= {}", fold(&double), fold(&small));
+    }
+
+    fn blind_pressure_tournament() -> TournamentState {
+        use crate::game::tournament::{BlindLevel, TournamentStructure};
+
+        let level = |level, small_blind, big_blind| BlindLevel {
+            level,
+            small_blind,
+            big_blind,
+            ante: 0,
+        };
+        TournamentState::new(
+            TournamentStructure {
+                levels: vec![level(1, 50, 100), level(2, 100, 200)],
+                level_duration_minutes: 20,
+                starting_stack: 1500,
+                ante_schedule: vec![],
+                ante_type: AnteType::PerPlayer,
+            },
+            3,
+            3000,
+        )
+    }
+
+    #[test]
+    fn test_blind_pressure_model_and_roots_across_level() {
+        let mut tournament_state = blind_pressure_tournament();
+        let stacks = vec![350, 2000, 2000];
+        let model = BlindPressureModel::from_clock(&tournament_state, 60, 3);
+        assert_eq!(model.hands_left_in_level, 20);
+        assert_eq!(model.next_blinds, (100, 200, 0));
+        // 한 바퀴: 남은 핸드가 많으면 현재 블라인드, 레벨 마지막 핸드면 다음 레벨 블라인드
+        assert_eq!(model.projected_blind_cost(3), 150.0);
+        assert_eq!(model.clone().with_hands_left(1).projected_blind_cost(3), 300.0);
+
+        let state = TournamentHoldemState::new_tournament_hand(
+            crate::game::holdem::State::new_hand([50, 100], [350, 2000, 2000, 0, 0, 0], 3),
+            tournament_state.clone(),
+            stacks.clone(),
+        );
+        let key = |hands_left| {
+            let root = state.clone().with_blind_pressure(model.clone().with_hands_left(hands_left));
+            TournamentHoldem::info_key(&root, 0)
+        };
+        assert_ne!(key(1), key(20));
+
+        let roots = state.across_level(60, 4);
+        let hands_left: Vec<u32> = roots
+            .iter()
+            .map(|root| root.blind_pressure.as_ref().unwrap().hands_left_in_level)
+            .collect();
+        assert_eq!(hands_left, vec![20, 14, 7, 1]);
+
+        tournament_state.advance_time(15);
+        assert_eq!(BlindPressureModel::from_clock(&tournament_state, 60, 3).hands_left_in_level, 5);
+        // 마지막 레벨은 블라인드가 오르지 않으므로 긴급도 구간이 하나
+        tournament_state.advance_time(5);
+        let last_level = BlindPressureModel::from_clock(&tournament_state, 60, 3);
+        assert_eq!(last_level.urgency_bucket(), last_level.with_hands_left(1).urgency_bucket());
+    }
+
+    #[test]
+    fn test_short_stack_shoves_more_when_level_ends() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        // 3인 테이블 버튼의 3.5BB 숏스택 (K4o), 팟 레이즈가 곧 올인
+        let tournament_state = blind_pressure_tournament();
+        let stacks = [350, 2000, 2000, 0, 0, 0];
+        let holdem = crate::game::holdem::State::new_hand_with_rng(
+            [50, 100],
+            0,
+            AnteType::PerPlayer,
+            stacks,
+            3,
+            &mut StdRng::seed_from_u64(1),
+        );
+        let state = TournamentHoldemState::new_tournament_hand(holdem, tournament_state.clone(), stacks[..3].to_vec());
+        let shove = TournamentHoldem::legal_actions(&state)
+            .iter()
+            .position(|&action| action == HoldemAction::Raise(0))
+            .unwrap();
+
+        let shove_frequency = |hands_left| {
+            let model = BlindPressureModel::from_clock(&tournament_state, 60, 3).with_hands_left(hands_left);
+            let root = state.clone().with_blind_pressure(model);
+            let mut trainer = TournamentCFRTrainer::new(tournament_state.clone(), stacks[..3].to_vec());
+            trainer.base_trainer.set_seed(3);
+            trainer.base_trainer.run(vec![root.clone()], 10);
+            trainer.get_tournament_strategy(&root, 0)[shove]
+        };
+        let level_ending = shove_frequency(1);
+        let level_starting = shove_frequency(20);
+        assert!(level_ending > level_starting, "{} <= {}", level_ending, level_starting);
+    }
 }
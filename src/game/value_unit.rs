@@ -0,0 +1,241 @@
+// 기댓값 단위 모듈
+// 칩 EV, 빅블라인드 EV, 상금($) EV, 정규화 에퀴티를 명시적으로 구분하고 변환
+
+use crate::game::tournament::{chips, ICMCalculator};
+use serde::{Deserialize, Serialize};
+
+/// 기댓값 단위
+///
+/// JSON 표현: 변형 이름 문자열 (`"Chips"`, `"BigBlinds"`, `"PrizeDollars"`, `"NormalizedEquity"`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ValueUnit {
+    /// 칩 (캐시 게임 칩 EV, 기본값)
+    #[default]
+    Chips,
+    /// 빅블라인드 (칩 / 빅블라인드 크기)
+    BigBlinds,
+    /// 상금 달러 (ICM 에퀴티 변화량)
+    PrizeDollars,
+    /// 전체 상금 대비 에퀴티 비율 (상금 달러 / 총 상금)
+    NormalizedEquity,
+}
+
+impl std::fmt::Display for ValueUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Chips => "chips",
+            Self::BigBlinds => "bb",
+            Self::PrizeDollars => "$",
+            Self::NormalizedEquity => "equity",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// ICM 변환에 필요한 토너먼트 정보
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IcmContext {
+    /// 남은 플레이어들의 현재 스택 (칩)
+    pub stacks: Vec<u32>,
+    /// 순위별 상금 (1등부터)
+    pub payouts: Vec<u64>,
+    /// 히어로의 `stacks` 인덱스
+    pub hero: usize,
+}
+
+/// 단위 변환 문맥 - 변환에 필요한 정보가 없으면 해당 변환은 에러
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ValueContext {
+    /// 빅블라인드 크기 (칩, BigBlinds 변환에 필요)
+    #[serde(default)]
+    pub big_blind: Option<u32>,
+    /// ICM 정보 (PrizeDollars/NormalizedEquity 변환에 필요)
+    #[serde(default)]
+    pub icm: Option<IcmContext>,
+}
+
+/// 단위 변환 에러
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum ValueConversionError {
+    /// BigBlinds 변환에 빅블라인드 크기가 없거나 0
+    MissingBigBlind,
+    /// 상금 단위 변환에 상금 구조(ICM 정보)가 없거나 상금이 비어 있음
+    MissingPayoutStructure,
+    /// ICM 정보의 히어로 인덱스가 스택 범위를 벗어남
+    InvalidHero { hero: usize, players: usize },
+    /// 상금 단위에서 칩 단위로는 되돌릴 수 없음 (ICM은 비선형)
+    NotInvertible { from: ValueUnit, to: ValueUnit },
+}
+
+impl std::fmt::Display for ValueConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingBigBlind => write!(f, "빅블라인드 단위 변환에는 빅블라인드 크기가 필요합니다"),
+            Self::MissingPayoutStructure => {
+                write!(f, "상금 단위 변환에는 상금 구조와 스택 정보(ICM)가 필요합니다")
+            }
+            Self::InvalidHero { hero, players } => {
+                write!(f, "히어로 인덱스 {}가 플레이어 수 {}를 벗어났습니다", hero, players)
+            }
+            Self::NotInvertible { from, to } => {
+                write!(f, "{:?} 단위는 {:?} 단위로 변환할 수 없습니다", from, to)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValueConversionError {}
+
+impl ValueContext {
+    /// 빅블라인드 크기만 있는 문맥
+    pub fn with_big_blind(big_blind: u32) -> Self {
+        Self {
+            big_blind: Some(big_blind),
+            icm: None,
+        }
+    }
+
+    /// ICM 정보가 있는 문맥
+    pub fn with_icm(stacks: Vec<u32>, payouts: Vec<u64>, hero: usize) -> Self {
+        Self {
+            big_blind: None,
+            icm: Some(IcmContext { stacks, payouts, hero }),
+        }
+    }
+
+    /// 칩 값을 `unit`으로 변환할 수 있는지 확인
+    pub fn validate(&self, unit: ValueUnit) -> Result<(), ValueConversionError> {
+        match unit {
+            ValueUnit::Chips => Ok(()),
+            ValueUnit::BigBlinds => self.big_blind_size().map(|_| ()),
+            ValueUnit::PrizeDollars | ValueUnit::NormalizedEquity => self.icm_context().map(|_| ()),
+        }
+    }
+
+    /// 칩 변화량을 지정한 단위로 변환
+    ///
+    /// 상금 단위는 히어로 스택이 `chips`만큼 변했을 때의 ICM 에퀴티 변화량입니다.
+    pub fn chips_to(&self, chips: f64, to: ValueUnit) -> Result<f64, ValueConversionError> {
+        match to {
+            ValueUnit::Chips => Ok(chips),
+            ValueUnit::BigBlinds => Ok(chips / self.big_blind_size()? as f64),
+            ValueUnit::PrizeDollars => self.icm_equity_change(chips),
+            ValueUnit::NormalizedEquity => {
+                let dollars = self.icm_equity_change(chips)?;
+                Ok(dollars / self.prize_pool()?)
+            }
+        }
+    }
+
+    /// 임의 단위 사이 변환
+    ///
+    /// 칩/빅블라인드 사이, 상금 달러/정규화 에퀴티 사이는 선형이라 양방향 가능하며,
+    /// 칩 계열에서 상금 계열로는 ICM을 거쳐 변환합니다. 반대 방향은 `NotInvertible`.
+    pub fn convert(&self, value: f64, from: ValueUnit, to: ValueUnit) -> Result<f64, ValueConversionError> {
+        if from == to {
+            return Ok(value);
+        }
+        match from {
+            ValueUnit::Chips => self.chips_to(value, to),
+            ValueUnit::BigBlinds => self.chips_to(value * self.big_blind_size()? as f64, to),
+            ValueUnit::PrizeDollars | ValueUnit::NormalizedEquity => {
+                let pool = self.prize_pool()?;
+                match (from, to) {
+                    (ValueUnit::PrizeDollars, ValueUnit::NormalizedEquity) => Ok(value / pool),
+                    (ValueUnit::NormalizedEquity, ValueUnit::PrizeDollars) => Ok(value * pool),
+                    _ => Err(ValueConversionError::NotInvertible { from, to }),
+                }
+            }
+        }
+    }
+
+    fn big_blind_size(&self) -> Result<u32, ValueConversionError> {
+        self.big_blind
+            .filter(|&bb| bb > 0)
+            .ok_or(ValueConversionError::MissingBigBlind)
+    }
+
+    fn icm_context(&self) -> Result<&IcmContext, ValueConversionError> {
+        let icm = self
+            .icm
+            .as_ref()
+            .filter(|icm| !icm.payouts.is_empty())
+            .ok_or(ValueConversionError::MissingPayoutStructure)?;
+        if icm.hero >= icm.stacks.len() {
+            return Err(ValueConversionError::InvalidHero {
+                hero: icm.hero,
+                players: icm.stacks.len(),
+            });
+        }
+        Ok(icm)
+    }
+
+    fn prize_pool(&self) -> Result<f64, ValueConversionError> {
+        let total = chips::prize_pool(&self.icm_context()?.payouts);
+        if total == 0.0 {
+            return Err(ValueConversionError::MissingPayoutStructure);
+        }
+        Ok(total)
+    }
+
+    /// 히어로 스택이 `chips`만큼 변했을 때의 ICM 에퀴티 변화량 (상금 달러)
+    fn icm_equity_change(&self, chips: f64) -> Result<f64, ValueConversionError> {
+        let icm = self.icm_context()?;
+        let delta = chips.round() as i64;
+        if delta == 0 {
+            return Ok(0.0);
+        }
+
+        let before = ICMCalculator::new(icm.stacks.clone(), icm.payouts.clone()).calculate_equity();
+        let mut stacks = icm.stacks.clone();
+        stacks[icm.hero] = chips::adjust_stack(stacks[icm.hero], delta);
+        let after = ICMCalculator::new(stacks, icm.payouts.clone()).calculate_equity();
+        Ok(after[icm.hero] - before[icm.hero])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chip_and_big_blind_conversion() {
+        let context = ValueContext::with_big_blind(50);
+        assert_eq!(context.chips_to(150.0, ValueUnit::BigBlinds), Ok(3.0));
+        assert_eq!(context.convert(3.0, ValueUnit::BigBlinds, ValueUnit::Chips), Ok(150.0));
+
+        assert_eq!(
+            ValueContext::default().chips_to(150.0, ValueUnit::BigBlinds),
+            Err(ValueConversionError::MissingBigBlind)
+        );
+    }
+
+    #[test]
+    fn test_prize_conversion_requires_payouts() {
+        let no_payouts = ValueContext::with_big_blind(50);
+        assert_eq!(
+            no_payouts.chips_to(150.0, ValueUnit::PrizeDollars),
+            Err(ValueConversionError::MissingPayoutStructure)
+        );
+        assert_eq!(
+            ValueContext::with_icm(vec![1000, 1000], vec![], 0).validate(ValueUnit::NormalizedEquity),
+            Err(ValueConversionError::MissingPayoutStructure)
+        );
+
+        let context = ValueContext::with_icm(vec![3000, 2000, 1000], vec![500, 300, 200], 2);
+        let dollars = context.chips_to(500.0, ValueUnit::PrizeDollars).unwrap();
+        assert!(dollars > 0.0 && dollars < 500.0, "{}", dollars);
+        let equity = context.chips_to(500.0, ValueUnit::NormalizedEquity).unwrap();
+        assert!((equity - dollars / 1000.0).abs() < 1e-12);
+        assert_eq!(
+            context.convert(dollars, ValueUnit::PrizeDollars, ValueUnit::Chips),
+            Err(ValueConversionError::NotInvertible {
+                from: ValueUnit::PrizeDollars,
+                to: ValueUnit::Chips,
+            })
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! 외부 솔버 연동 모듈
+//!
+//! 다른 솔버의 출력물을 읽어 이 크레이트의 전략과 비교합니다:
+//! - GTO+ / PioSolver 스타일 가중치 레인지 파일
+
+pub mod pio;
+
+pub use pio::{compare_range_to_strategy, parse_range, HandRangeWeights, RangeComparison};
+#[cfg(not(target_arch = "wasm32"))]
+pub use pio::load_range_file;
@@ -0,0 +1,434 @@
+// GTO+ / PioSolver 스타일 레인지 파일 로더
+// "AA:1,AKs:0.5,..." 가중치 표기(쉼표 또는 줄바꿈 구분)를 169 핸드 클래스 가중치로 변환
+
+use crate::game::card_abstraction::hand_class::{make_card, parse_rank, HandClass, HandClassParseError};
+use crate::game::holdem::{Act, State};
+use crate::solver::cfr_core::{Game, Trainer};
+use std::collections::HashMap;
+use std::fmt;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+/// 잘못된 레인지 항목에 대한 경고 (해당 항목만 건너뜀)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeWarning {
+    /// 1부터 시작하는 줄 번호
+    pub line: usize,
+    /// 문제가 된 항목 원문
+    pub entry: String,
+    pub message: String,
+}
+
+impl fmt::Display for RangeWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}번째 줄 '{}': {}", self.line, self.entry, self.message)
+    }
+}
+
+/// 169 핸드 클래스별 가중치 (0.0-1.0)
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandRangeWeights {
+    /// `HandClass::to_index` 순서의 가중치
+    weights: Vec<f64>,
+    /// 파싱 중 건너뛴 항목들
+    pub warnings: Vec<RangeWarning>,
+}
+
+impl Default for HandRangeWeights {
+    fn default() -> Self {
+        Self {
+            weights: vec![0.0; 169],
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl HandRangeWeights {
+    /// 빈 레인지
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 클래스 가중치 (없으면 0)
+    pub fn weight(&self, class: HandClass) -> f64 {
+        self.weights[class.to_index() as usize]
+    }
+
+    /// 클래스 가중치 설정 (0.0-1.0으로 제한)
+    pub fn set_weight(&mut self, class: HandClass, weight: f64) {
+        self.weights[class.to_index() as usize] = weight.clamp(0.0, 1.0);
+    }
+
+    /// 가중치가 0보다 큰 클래스와 가중치
+    pub fn iter(&self) -> impl Iterator<Item = (HandClass, f64)> + '_ {
+        HandClass::all()
+            .into_iter()
+            .map(move |class| (class, self.weight(class)))
+            .filter(|&(_, weight)| weight > 0.0)
+    }
+
+    /// 조합 수 기준 레인지 비율 (0.0-1.0)
+    pub fn combo_fraction(&self) -> f64 {
+        let combos: f64 = self.iter().map(|(class, w)| w * class.combo_count() as f64).sum();
+        combos / 1326.0
+    }
+
+    /// 알려진 카드(보드, 홀카드 등)와 겹치는 조합을 뺀 조합별 가중치
+    ///
+    /// 레인지를 실제 카드와 함께 다루는 계산은 모두 이 결과를 사용합니다.
+    pub fn remove_conflicts(&self, cards: &[u8]) -> Vec<([u8; 2], f64)> {
+        self.iter()
+            .flat_map(|(class, weight)| class.combos(cards).into_iter().map(move |combo| (combo, weight)))
+            .collect()
+    }
+
+    /// 쉼표 구분 표기로 변환 ("AA:1,AKs:0.5")
+    pub fn to_pio_string(&self) -> String {
+        self.entries().join(",")
+    }
+
+    /// 줄바꿈 구분 표기로 변환
+    pub fn to_lines(&self) -> String {
+        self.entries().join("\n")
+    }
+
+    fn entries(&self) -> Vec<String> {
+        self.iter().map(|(class, weight)| format!("{}:{}", class, weight)).collect()
+    }
+}
+
+/// 레인지 파일 읽기 (잘못된 항목은 `warnings`에 기록하고 건너뜀)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_range_file(path: impl AsRef<Path>) -> std::io::Result<HandRangeWeights> {
+    Ok(parse_range(&std::fs::read_to_string(path)?))
+}
+
+/// 레인지 텍스트 파싱
+///
+/// 지원 형식:
+/// - 쉼표 또는 줄바꿈으로 구분된 `핸드[:가중치]` 항목 (가중치 생략 시 1)
+/// - 핸드: "AKs", "AKo", "77", 수티드/오프수트 모두인 "AK", 개별 조합 "AsKh"
+/// - 가중치: 비율(0.5), 퍼센트(50%), 또는 1보다 큰 값은 퍼센트로 간주(50)
+/// - `#` 또는 `//`로 시작하는 줄은 주석
+///
+/// 개별 조합 가중치는 클래스 조합 수로 나눠 클래스 가중치에 더해집니다.
+pub fn parse_range(text: &str) -> HandRangeWeights {
+    let mut range = HandRangeWeights::new();
+    let mut seen = [false; 169];
+
+    for (line_index, line) in text.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with("//") {
+            continue;
+        }
+
+        for entry in trimmed.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let mut warn = |message: String| {
+                range.warnings.push(RangeWarning {
+                    line: line_index + 1,
+                    entry: entry.to_string(),
+                    message,
+                })
+            };
+
+            let (hand, weight) = match entry.split_once(':') {
+                Some((hand, weight)) => match parse_weight(weight) {
+                    Ok(weight) => (hand.trim(), weight),
+                    Err(message) => {
+                        warn(message);
+                        continue;
+                    }
+                },
+                None => (entry, 1.0),
+            };
+
+            match parse_hand(hand) {
+                Ok(ParsedHand::Classes(classes)) => {
+                    for class in classes {
+                        let index = class.to_index() as usize;
+                        if seen[index] {
+                            warn(format!("{} 클래스가 중복되어 마지막 값을 사용합니다", class));
+                        }
+                        seen[index] = true;
+                        range.weights[index] = weight;
+                    }
+                }
+                Ok(ParsedHand::Combo(class)) => {
+                    let index = class.to_index() as usize;
+                    let added = range.weights[index] + weight / class.combo_count() as f64;
+                    range.weights[index] = added.min(1.0);
+                }
+                Err(message) => warn(message),
+            }
+        }
+    }
+
+    range
+}
+
+enum ParsedHand {
+    /// 클래스 전체 (수티드/오프수트 미지정이면 두 클래스)
+    Classes(Vec<HandClass>),
+    /// 개별 조합 하나가 속한 클래스
+    Combo(HandClass),
+}
+
+fn parse_hand(hand: &str) -> Result<ParsedHand, String> {
+    let chars: Vec<char> = hand.chars().collect();
+
+    // 개별 조합 ("AsKh")
+    if chars.len() == 4 {
+        let card = |rank: char, suit: char| -> Result<u8, String> {
+            let rank = parse_rank(rank).map_err(|e| e.to_string())?;
+            let suit = match suit.to_ascii_lowercase() {
+                's' => 0,
+                'h' => 1,
+                'd' => 2,
+                'c' => 3,
+                other => return Err(format!("유효하지 않은 수트 문자: {}", other)),
+            };
+            Ok(make_card(rank, suit))
+        };
+        let c1 = card(chars[0], chars[1])?;
+        let c2 = card(chars[2], chars[3])?;
+        if c1 == c2 {
+            return Err("같은 카드가 두 번 사용되었습니다".to_string());
+        }
+        return Ok(ParsedHand::Combo(HandClass::from_cards([c1, c2])));
+    }
+
+    // 수티드 여부 없는 논페어 ("AK")
+    if chars.len() == 2 && !chars[0].eq_ignore_ascii_case(&chars[1]) {
+        let parse = |suffix: char| -> Result<HandClass, String> {
+            format!("{}{}", hand, suffix)
+                .parse()
+                .map_err(|e: HandClassParseError| e.to_string())
+        };
+        let (suited, offsuit) = (parse('s')?, parse('o')?);
+        return Ok(ParsedHand::Classes(vec![suited, offsuit]));
+    }
+
+    hand.parse::<HandClass>()
+        .map(|class| ParsedHand::Classes(vec![class]))
+        .map_err(|e| e.to_string())
+}
+
+fn parse_weight(text: &str) -> Result<f64, String> {
+    let text = text.trim();
+    let (number, percent) = match text.strip_suffix('%') {
+        Some(number) => (number.trim(), true),
+        None => (text, false),
+    };
+    let value: f64 = number
+        .parse()
+        .map_err(|_| format!("가중치를 숫자로 읽을 수 없습니다: {}", text))?;
+
+    let weight = if percent || value > 1.0 { value / 100.0 } else { value };
+    if !weight.is_finite() || !(0.0..=1.0).contains(&weight) {
+        return Err(format!("가중치가 범위를 벗어났습니다: {}", text));
+    }
+    Ok(weight)
+}
+
+/// 핸드 클래스 하나의 비교 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassComparison {
+    pub class: HandClass,
+    /// 레인지 파일의 가중치
+    pub range_weight: f64,
+    /// 비교 대상 전략의 액션 빈도
+    pub strategy_weight: f64,
+    /// 총 변동 거리 (액션을 한다/안 한다 두 결과 분포 사이의 거리 = |차이|)
+    pub distance: f64,
+}
+
+/// 레인지와 전략의 비교 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeComparison {
+    /// 거리 내림차순 클래스별 비교
+    pub classes: Vec<ClassComparison>,
+    /// 조합 수 가중 평균 거리
+    pub weighted_distance: f64,
+    /// 전략에 값이 없어 비교하지 못한 클래스
+    pub missing_classes: Vec<HandClass>,
+}
+
+impl RangeComparison {
+    /// 거리가 가장 큰 상위 `n`개 클래스
+    pub fn biggest_disagreements(&self, n: usize) -> &[ClassComparison] {
+        &self.classes[..n.min(self.classes.len())]
+    }
+}
+
+/// 레인지 파일과 스팟에서의 전략 액션 빈도 비교
+///
+/// `strategy`는 클래스별로 해당 액션을 선택하는 빈도입니다
+/// (예: `trainer_action_frequencies`의 결과). 레인지에 없는 클래스는 가중치 0으로 봅니다.
+pub fn compare_range_to_strategy(
+    range: &HandRangeWeights,
+    strategy: &HashMap<HandClass, f64>,
+) -> RangeComparison {
+    let mut classes = Vec::new();
+    let mut missing_classes = Vec::new();
+    let mut distance_sum = 0.0;
+    let mut combo_sum = 0.0;
+
+    for class in HandClass::all() {
+        let Some(&strategy_weight) = strategy.get(&class) else {
+            missing_classes.push(class);
+            continue;
+        };
+        let range_weight = range.weight(class);
+        let distance = (range_weight - strategy_weight).abs();
+
+        distance_sum += distance * class.combo_count() as f64;
+        combo_sum += class.combo_count() as f64;
+        classes.push(ClassComparison {
+            class,
+            range_weight,
+            strategy_weight,
+            distance,
+        });
+    }
+
+    classes.sort_by(|a, b| b.distance.total_cmp(&a.distance));
+    RangeComparison {
+        classes,
+        weighted_distance: if combo_sum > 0.0 { distance_sum / combo_sum } else { 0.0 },
+        missing_classes,
+    }
+}
+
+/// 트레이너의 평균 전략에서 스팟의 클래스별 액션 빈도 추출
+///
+/// 각 클래스마다 보드와 겹치지 않는 첫 조합을 `player`의 홀카드로 두고
+/// 정보 집합을 조회합니다. 학습되지 않았거나 액션이 불가능한 클래스는 제외됩니다.
+pub fn trainer_action_frequencies(
+    trainer: &Trainer<State>,
+    spot: &State,
+    player: usize,
+    action: Act,
+) -> HashMap<HandClass, f64> {
+    let mut frequencies = HashMap::new();
+
+    for class in HandClass::all() {
+        let Some(hole) = class.combos(&spot.board).first().copied() else {
+            continue;
+        };
+        let mut state = spot.clone();
+        state.hole[player] = hole;
+
+        let Some(action_index) = State::legal_actions(&state).iter().position(|&a| a == action) else {
+            continue;
+        };
+        if let Some(node) = trainer.nodes.get(&State::info_key(&state, player)) {
+            if let Some(&frequency) = node.average().get(action_index) {
+                frequencies.insert(class, frequency);
+            }
+        }
+    }
+
+    frequencies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn generated_range(seed: u64) -> HandRangeWeights {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut range = HandRangeWeights::new();
+        for class in HandClass::all() {
+            match rng.gen_range(0..4) {
+                0 => {}
+                1 => range.set_weight(class, 1.0),
+                _ => range.set_weight(class, rng.gen_range(1..100) as f64 / 100.0),
+            }
+        }
+        range
+    }
+
+    #[test]
+    fn test_round_trip_both_formats() {
+        let range = generated_range(7);
+
+        let parsed = parse_range(&range.to_pio_string());
+        assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+        assert_eq!(parsed, range);
+
+        let parsed = parse_range(&range.to_lines());
+        assert!(parsed.warnings.is_empty());
+        assert_eq!(parsed, range);
+    }
+
+    #[test]
+    fn test_remove_conflicts_drops_combos_with_known_cards() {
+        let range = parse_range("AA,AKs:0.5");
+        assert_eq!(range.remove_conflicts(&[]).len(), 10);
+
+        // As가 보이면 AA 3조합과 AsKs가 빠짐
+        let ace_of_spades = make_card(12, 0);
+        let combos = range.remove_conflicts(&[ace_of_spades]);
+        assert_eq!(combos.len(), 6);
+        assert!(combos.iter().all(|(combo, _)| !combo.contains(&ace_of_spades)));
+        assert_eq!(combos.iter().filter(|&&(_, weight)| weight == 0.5).count(), 3);
+    }
+
+    #[test]
+    fn test_tolerant_parsing_with_warnings() {
+        let text = "# BTN open\nAA,KK:50%,AKs:0.75\nAK:40,QQ:abc,XYs:1\n\nJJ:1.5%,AsKs:1,77:250";
+        let range = parse_range(text);
+
+        assert_eq!(range.weight("AA".parse().unwrap()), 1.0);
+        assert_eq!(range.weight("KK".parse().unwrap()), 0.5);
+        // AK (수티드+오프수트)가 AKs를 덮어씀 + 개별 조합 AsKs 1/4
+        assert!((range.weight("AKs".parse().unwrap()) - 0.65).abs() < 1e-9);
+        assert!((range.weight("AKo".parse().unwrap()) - 0.4).abs() < 1e-9);
+        assert!((range.weight("JJ".parse().unwrap()) - 0.015).abs() < 1e-9);
+        assert_eq!(range.weight("QQ".parse().unwrap()), 0.0);
+
+        let lines: Vec<(usize, &str)> = range
+            .warnings
+            .iter()
+            .map(|w| (w.line, w.entry.as_str()))
+            .collect();
+        assert_eq!(lines, vec![(3, "AK:40"), (3, "QQ:abc"), (3, "XYs:1"), (5, "77:250")]);
+        assert!(range.warnings[0].to_string().contains("3번째 줄"));
+    }
+
+    #[test]
+    fn test_comparison_identical_and_flipped() {
+        let range = generated_range(11);
+        let mut strategy: HashMap<HandClass, f64> = HandClass::all()
+            .into_iter()
+            .map(|class| (class, range.weight(class)))
+            .collect();
+
+        let same = compare_range_to_strategy(&range, &strategy);
+        assert_eq!(same.weighted_distance, 0.0);
+        assert!(same.classes.iter().all(|c| c.distance == 0.0));
+        assert!(same.missing_classes.is_empty());
+
+        // 한 클래스의 빈도를 뒤집으면 가장 큰 불일치로 보고됨
+        let flipped: HandClass = "T9s".parse().unwrap();
+        let weight = strategy[&flipped];
+        strategy.insert(flipped, if weight > 0.5 { 0.0 } else { 1.0 });
+        strategy.remove(&"22".parse().unwrap());
+
+        let diff = compare_range_to_strategy(&range, &strategy);
+        assert_eq!(diff.biggest_disagreements(1)[0].class, flipped);
+        assert!(diff.weighted_distance > 0.0);
+        assert_eq!(diff.missing_classes, vec!["22".parse().unwrap()]);
+    }
+
+    #[test]
+    fn test_trainer_action_frequencies() {
+        let root = State::new_hand([10, 20], [1000; 6], 2);
+        let mut trainer = Trainer::<State>::new();
+        trainer.run(vec![root.clone()], 3);
+
+        let frequencies = trainer_action_frequencies(&trainer, &root, 0, Act::Call);
+        assert!(frequencies.values().all(|f| (0.0..=1.0).contains(f)));
+    }
+}
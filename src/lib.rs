@@ -17,16 +17,56 @@ pub mod game;
 /// API 모듈 - 외부 연동을 위한 웹 인터페이스들
 pub mod api;
 
+/// 외부 솔버 연동 모듈 - 레인지 파일 가져오기 및 비교
+pub mod interop;
+
+/// C ABI 바인딩 모듈 - Python/C# 등에서 WASM 없이 호출 (feature = "ffi")
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+/// 자주 쓰는 타입 모음 - `use nice_hand_core::prelude::*;`
+pub mod prelude;
+
 // 편의를 위한 재내보내기 (re-exports)
-pub use solver::*;
-pub use game::*;
-pub use api::*;
+// 글롭 재내보내기는 이름 충돌(두 `WebGameState` 등)을 만들고 하위 모듈에 pub 항목을 추가하기만 해도
+// 루트 API가 바뀌므로, 루트에는 아래에 나열한 항목만 내보냅니다.
+
+// 하위 모듈 - `nice_hand_core::holdem::State`처럼 짧은 모듈 경로 유지
+pub use game::{
+    all_in, card_abstraction, concrete_action, flop_subset, hand_eval, holdem, leduc, observation, opponent_deal,
+    poker_math, rng, session_stats, short_deck, tournament, tournament_holdem, value_unit,
+};
+pub use solver::{blend, budget, cfr_core, compact, compat, ev_calculator, ndjson, purification, sanity, validation};
+#[cfg(feature = "training")]
+pub use solver::{abstraction_eval, estimator, inspect, mccfr, tree_export};
+pub use api::{
+    analysis, batch, coaching, live_session, presets, stateless, tournament_charts, tuning, web_api, web_api_simple,
+};
+#[cfg(all(feature = "training", not(target_arch = "wasm32")))]
+pub use api::checkpoint;
+#[cfg(feature = "schema")]
+pub use api::schema;
 
 // 외부에서 사용할 주요 타입들을 re-export
-pub use cfr_core::{Game, Trainer, Node};
+pub use cfr_core::{Game, GameState, Trainer, Node};
 pub use holdem::{State as HoldemState, Act as HoldemAction};
 pub use tournament::{TournamentState, TournamentEvaluator, ICMCalculator};
 pub use tournament_holdem::{TournamentHoldem, TournamentHoldemState, TournamentCFRTrainer};
+#[cfg(feature = "training")]
+pub use solver::{MCCFRTrainer, NodeInspection, NodeMetric};
+pub use solver::Purification;
+pub use api::{
+    analyze_poker_state, best_action, canonical_action_order, get_on_demand_ev_analysis, AnalysisRequest,
+    BucketSelection, PokerAnalysisResponse, PokerWebAPI, PresetError, PresetPosition, PresetSpot, PresetStrategies,
+    QuickPokerAPI, SeatStatus, StackBucket, StackBucketConfig, StatelessRequest, StatelessResponse, StrategyCache,
+    StrategyCacheConfig, StrategyMap, StrategyPayload, StrategySource, StrategyTable, StrategyTableError,
+};
+#[cfg(feature = "training")]
+pub use api::OfflineTrainer;
+#[cfg(all(feature = "training", not(target_arch = "wasm32")))]
+pub use api::CheckpointConfig;
+/// 전체 기능 API의 게임 상태 (`web_api_simple::WebGameState`와 다른 타입)
+pub use api::web_api::WebGameState;
 
 // ----------------------- 편의 함수들 -----------------------
 
@@ -49,7 +89,7 @@ pub fn run_simple_training(iterations: usize) -> HashMap<String, Vec<f64>> {
     // 학습된 전략을 문자열 키로 변환하여 반환
     let mut strategies = HashMap::new();
     for (info_key, node) in trainer.nodes.iter() {
-        let strategy = node.avg_strategy();
+        let strategy = node.average();
         strategies.insert(format!("{:?}", info_key), strategy);
     }
     
@@ -195,12 +235,10 @@ pub fn cards_to_string(cards: &[u8]) -> String {
 // ----------------------- 조건부 컴파일 -----------------------
 
 // WASM 기능이 활성화된 경우에만 포함
-#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
-use serde::{Serialize, Deserialize};
-
 #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
 pub mod wasm_bridge {
     use super::*;
+    use crate::game::observation::ObservedState;
     use wasm_bindgen::prelude::*;
 
     /// WASM에서 사용할 간소화된 트레이너
@@ -228,11 +266,50 @@ pub mod wasm_bridge {
 
         /// 특정 상황에서의 전략 조회
         #[wasm_bindgen]
-        pub fn get_strategy(&self, info_key: &str) -> String {
+        pub fn get_strategy(&self, _info_key: &str) -> String {
             // 실제 구현에서는 info_key를 파싱하여 해당 노드의 전략을 반환
             "구현 필요".to_string()
         }
 
+        /// 플레이어 관점 상태(JSON `ObservedState`)로 학습된 전략 조회
+        ///
+        /// 상대 홀카드를 받지 않으므로 호출하는 쪽에서 정보를 엿볼 수 없습니다.
+        /// 학습되지 않은 상황이면 `null`, 잘못된 JSON이면 `{"error": ...}`를 반환합니다.
+        #[wasm_bindgen]
+        pub fn get_observed_strategy(&self, observed_json: &str) -> String {
+            let observed: ObservedState = match serde_json::from_str(observed_json) {
+                Ok(observed) => observed,
+                Err(e) => return serde_json::json!({ "error": e.to_string() }).to_string(),
+            };
+            let strategy = self
+                .trainer
+                .nodes
+                .get(&observed.info_key())
+                .map(|node| node.average());
+            serde_json::to_string(&strategy).unwrap_or_else(|_| "null".to_string())
+        }
+
+        /// 정제(JSON `Purification`)를 적용한 전략 조회
+        ///
+        /// `{"strategy": [...] | null, "purification": ...}`로 적용한 정제 방식을 함께 반환하며,
+        /// 잘못된 JSON이면 `{"error": ...}`를 반환합니다.
+        #[wasm_bindgen]
+        pub fn get_purified_strategy(&self, observed_json: &str, purification_json: &str) -> String {
+            let parsed = serde_json::from_str::<ObservedState>(observed_json).and_then(|observed| {
+                serde_json::from_str::<solver::Purification>(purification_json).map(|p| (observed, p))
+            });
+            let (observed, purification) = match parsed {
+                Ok(parsed) => parsed,
+                Err(e) => return serde_json::json!({ "error": e.to_string() }).to_string(),
+            };
+            let strategy = self
+                .trainer
+                .nodes
+                .get(&observed.info_key())
+                .map(|node| purification.apply(&node.average()));
+            serde_json::json!({ "strategy": strategy, "purification": purification }).to_string()
+        }
+
         /// 핸드 스트렝스 계산 (JavaScript 바인딩)
         #[wasm_bindgen]
         pub fn calculate_strength(&self, hole_cards: Vec<u8>, board: Vec<u8>) -> f64 {
@@ -242,6 +319,51 @@ pub mod wasm_bridge {
             calculate_hand_strength([hole_cards[0], hole_cards[1]], &board)
         }
     }
+
+    /// 미리 학습된 전략 테이블로 브라우저에서 학습 없이 분석하는 객체
+    #[wasm_bindgen]
+    pub struct WasmAnalyzer {
+        api: api::web_api::PokerWebAPI,
+    }
+
+    #[wasm_bindgen]
+    impl WasmAnalyzer {
+        /// `StrategyTable::to_bytes` 형식의 전략 블롭(`Uint8Array`)으로 생성
+        #[wasm_bindgen(constructor)]
+        pub fn new(strategy_bytes: &[u8]) -> Result<WasmAnalyzer, JsValue> {
+            let table = api::web_api::StrategyTable::from_bytes(strategy_bytes).map_err(|e| JsValue::from_str(&e))?;
+            Ok(WasmAnalyzer {
+                api: api::web_api::PokerWebAPI::from_table(table, None),
+            })
+        }
+
+        /// 정제(JSON `Purification`)를 적용해 조회하는 분석기 생성 (응답에 정제 방식이 기록됨)
+        #[wasm_bindgen]
+        pub fn with_purification(strategy_bytes: &[u8], purification_json: &str) -> Result<WasmAnalyzer, JsValue> {
+            let purification: solver::Purification =
+                serde_json::from_str(purification_json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+            let table = api::web_api::StrategyTable::from_bytes(strategy_bytes).map_err(|e| JsValue::from_str(&e))?;
+            Ok(WasmAnalyzer {
+                api: api::web_api::PokerWebAPI::from_table(table.with_purification(purification), None),
+            })
+        }
+
+        /// `WebGameState` JSON 배열을 분석해 평탄화된 결과 반환
+        ///
+        /// 상황마다 `[fold, call, raise, ev, confidence]` 5개 값이 이어집니다
+        /// (`analyze_batch_meta` 참고). 잘못된 JSON이면 예외를 던집니다.
+        #[wasm_bindgen]
+        pub fn analyze_batch(&self, states_json: &str) -> Result<js_sys::Float64Array, JsValue> {
+            let flat = api::batch::analyze_batch_json(&self.api, states_json).map_err(|e| JsValue::from_str(&e))?;
+            Ok(js_sys::Float64Array::from(flat.as_slice()))
+        }
+    }
+
+    /// `analyze_batch` 결과 레이아웃 (stride, 필드 순서, EV 단위) JSON
+    #[wasm_bindgen]
+    pub fn analyze_batch_meta() -> String {
+        serde_json::to_string(&api::batch::batch_layout()).unwrap_or_else(|_| "{}".to_string())
+    }
 }
 
 // ----------------------- 새로운 고급 분석 함수들 -----------------------
@@ -273,6 +395,9 @@ pub mod wasm_bridge {
 ///     player_to_act: 0,
 ///     hero_position: 0,
 ///     betting_history: vec![],
+///     seat_status: vec![],
+///     posted: vec![],
+///     straddle: None,
 /// };
 /// 
 /// // 상세 분석 실행
@@ -298,8 +423,13 @@ pub fn analyze_comprehensive(
             include_range_analysis: false,
             include_equity_calculation: false,
             max_calculation_time_ms: None,
-            opponent_modeling: api::analysis::OpponentModel::Tight,
+            opponent_modeling: api::analysis::OpponentModelKind::Tight,
+            counterfactuals: false,
+            debug: false,
+            rake: None,
+            ..Default::default()
         },
+        action_history: Vec::new(),
     };
     
     api::analysis::analyze_poker_state(request)
@@ -319,7 +449,12 @@ pub fn analyze_comprehensive(
 /// ```
 /// use nice_hand_core::{calculate_quick_ev, api::web_api::WebGameState};
 /// 
-/// let web_state = WebGameState { /* ... */ };
+/// let web_state = WebGameState {
+/// #   hole_cards: [0, 13], board: vec![], street: 0, pot: 150, stacks: vec![1000, 1000],
+/// #   alive_players: vec![0, 1], street_investments: vec![50, 100], to_call: 100,
+/// #   player_to_act: 0, hero_position: 0, betting_history: vec![], seat_status: vec![], posted: vec![], straddle: None,
+///     /* ... */
+/// };
 /// let ev_results = calculate_quick_ev(&web_state, Some(5000));
 /// 
 /// match ev_results {
@@ -355,7 +490,12 @@ pub fn calculate_quick_ev(
 /// ```
 /// use nice_hand_core::{validate_game_state, api::web_api::WebGameState};
 /// 
-/// let web_state = WebGameState { /* ... */ };
+/// let web_state = WebGameState {
+/// #   hole_cards: [0, 13], board: vec![], street: 0, pot: 150, stacks: vec![1000, 1000],
+/// #   alive_players: vec![0, 1], street_investments: vec![50, 100], to_call: 100,
+/// #   player_to_act: 0, hero_position: 0, betting_history: vec![], seat_status: vec![], posted: vec![], straddle: None,
+///     /* ... */
+/// };
 /// 
 /// match validate_game_state(&web_state) {
 ///     Ok(()) => println!("게임 상태가 유효합니다"),
@@ -386,7 +526,12 @@ pub fn validate_game_state(web_state: &api::web_api::WebGameState) -> Result<(),
 /// ```
 /// use nice_hand_core::{get_action_recommendation, api::web_api::WebGameState};
 /// 
-/// let web_state = WebGameState { /* ... */ };
+/// let web_state = WebGameState {
+/// #   hole_cards: [0, 13], board: vec![], street: 0, pot: 150, stacks: vec![1000, 1000],
+/// #   alive_players: vec![0, 1], street_investments: vec![50, 100], to_call: 100,
+/// #   player_to_act: 0, hero_position: 0, betting_history: vec![], seat_status: vec![], posted: vec![], straddle: None,
+///     /* ... */
+/// };
 /// 
 /// match get_action_recommendation(&web_state, "balanced") {
 ///     Ok((recommended, strengths)) => {
@@ -539,9 +684,7 @@ mod tests {
         // 각 전략이 유효한 확률 분포인지 확인
         for (_, strategy) in strategies.iter() {
             let sum: f64 = strategy.iter().sum();
-            if sum > 0.0 {
-                assert!((sum - 1.0).abs() < 0.1); // 허용 오차 내에서 1.0
-            }
+            assert!((sum - 1.0).abs() < 1e-6, "{:?}", strategy);
         }
     }
 
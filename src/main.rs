@@ -1,4 +1,4 @@
-use nice_hand_core::{holdem, Trainer};
+use nice_hand_core::{holdem, NodeMetric, Trainer};
 
 fn main() {
     println!("Nice Hand Core - 텍사스 홀덤용 선호도 CFR 구현체");
@@ -17,8 +17,19 @@ fn main() {
         stack: [1000; 6],
         alive: [true, true, false, false, false, false], // 2명의 플레이어
         invested: [15, 30, 0, 0, 0, 0],                  // 블라인드 투입됨
+        antes: [0; 6],
         to_call: 30,
         actions_taken: 0,
+        button: 0,
+        rake: None,
+        variant: holdem::HoldemVariant::Standard,
+        flop_sampler: None,
+        betting: holdem::BettingRules::NoLimit,
+        raises_this_street: 0,
+        big_blind: 30,
+        last_raise: 0,
+        straddle: 0,
+        opponent_deal: None,
     };
 
     println!("{}번 반복으로 텍사스 홀덤 학습 중...", 100);
@@ -34,10 +45,19 @@ fn main() {
     );
 
     // 일부 전략 결과 표시
-    for (info_key, node) in holdem_trainer.nodes.iter().take(3) {
+    for (info_key, node) in holdem_trainer.nodes_sorted_by_key().into_iter().take(3) {
         let avg_strategy = node.average();
         println!("정보키 {}: 전략 {:?}", info_key, avg_strategy);
     }
 
+    // 가장 많이 방문한 노드의 학습 상태
+    if let Some(inspection) = holdem_trainer
+        .top_nodes_by(NodeMetric::VisitCount, 1)
+        .first()
+        .and_then(|&(info_key, _)| holdem_trainer.inspect(info_key))
+    {
+        println!("최다 방문 노드: {}", inspection.to_json());
+    }
+
     println!("\n=== CFR 구현이 텍사스 홀덤에 성공적으로 적용되었습니다! ===");
 }
@@ -0,0 +1,33 @@
+//! 자주 쓰는 타입 모음
+//!
+//! `use nice_hand_core::prelude::*;` 한 줄로 학습, 게임 상태, 토너먼트, 웹 API의 핵심 타입을 가져옵니다.
+//! 이름이 겹치지 않는 항목만 담았으며, 프렐루드에 항목을 추가하는 것도 공개 API 변경으로 취급합니다.
+//!
+//! ```
+//! use nice_hand_core::prelude::*;
+//!
+//! let mut trainer = Trainer::<HoldemState>::new();
+//! trainer.run(vec![HoldemState::new()], 1);
+//! assert!(!trainer.nodes.is_empty());
+//! ```
+
+// 학습
+pub use crate::solver::cfr_core::{Game, GameState, Node, Trainer};
+#[cfg(feature = "training")]
+pub use crate::solver::mccfr::MCCFRTrainer;
+pub use crate::solver::purification::Purification;
+
+// 게임 상태
+pub use crate::game::holdem::{Act as HoldemAction, State as HoldemState};
+pub use crate::game::observation::ObservedState;
+pub use crate::game::tournament::{ICMCalculator, TournamentEvaluator, TournamentState};
+pub use crate::game::tournament_holdem::{TournamentCFRTrainer, TournamentHoldem, TournamentHoldemState};
+
+// 웹 API
+pub use crate::api::analysis::{analyze_poker_state, AnalysisRequest, PokerAnalysisResponse};
+pub use crate::api::stateless::{StatelessRequest, StatelessResponse, StrategySource};
+pub use crate::api::web_api::{PokerWebAPI, StrategyTable, WebGameState};
+pub use crate::api::web_api_simple::QuickPokerAPI;
+
+// 편의 함수
+pub use crate::{calculate_hand_strength, card_to_string, cards_to_string};
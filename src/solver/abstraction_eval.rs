@@ -0,0 +1,415 @@
+// 추상화 품질 벤치마크
+// 버킷 수를 감으로 고르지 않도록, 작은 게임에서 추상화마다 전략을 학습하고 가장 세밀한 추상화의
+// 전략과 비교해 잃는 EV(bb/100)를 노드 수, 학습 시간과 함께 보고 (품질/비용 곡선)
+//
+// - 게임: 헤즈업 숏스택 푸시/폴드 (SB 올인 또는 폴드, BB 콜 또는 폴드) - 169 클래스 전부를
+//   펼쳐도 반복당 비용이 작아 무손실 학습이 가능
+// - 맞대결: 두 전략이 좌석을 바꿔 가며 붙는 대결(arena)을 샘플링 대신 모든 클래스 쌍의 정확한 기댓값으로 계산
+// - 익스플로잇: 최선 응답 값을 클래스별로 정확히 계산
+
+use crate::game::card_abstraction::hand_class::HandClass;
+use crate::game::card_abstraction::{hand_strength, preflop_class_equity};
+use crate::game::holdem::{Act, State};
+use crate::solver::cfr_core::{Game, Node};
+use crate::solver::estimator::Abstraction;
+use fxhash::FxHashMap as HashMap;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// 푸시/폴드 게임에서 쓰는 블라인드 (칩)
+const BLINDS: [u32; 2] = [50, 100];
+
+/// 169 핸드 클래스 수
+const CLASSES: usize = 169;
+
+/// 헤즈업 숏스택 푸시/폴드 게임 설정
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PushFoldConfig {
+    /// 두 플레이어의 유효 스택 (빅블라인드 단위, 1 이상)
+    pub stack_bb: u32,
+}
+
+impl PushFoldConfig {
+    /// `stack_bb` 빅블라인드 유효 스택의 푸시/폴드 게임
+    pub fn new(stack_bb: u32) -> Self {
+        Self { stack_bb: stack_bb.max(1) }
+    }
+
+    /// 정보 집합 키를 만들 때 쓰는 시작 상태 (좌석 0이 SB, 홀카드는 키를 만들 때 채움)
+    pub fn root(&self) -> State {
+        let mut stacks = [0; 6];
+        stacks[0] = self.stack_bb * BLINDS[1];
+        stacks[1] = self.stack_bb * BLINDS[1];
+        State::new_hand(BLINDS, stacks, 2)
+    }
+}
+
+impl Default for PushFoldConfig {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+/// 169 클래스 인덱스로 버킷을 나누는 무손실 프리플랍 추상화
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HandClassAbstraction;
+
+impl Abstraction for HandClassAbstraction {
+    fn info_key(&self, state: &State, player: usize) -> u64 {
+        HandClass::from_cards(state.hole[player]).to_index() as u64 ^ decision_context(state, player)
+    }
+}
+
+/// 핸드 강도(`hand_strength`)를 `buckets`개 구간으로 자르는 추상화
+#[derive(Debug, Clone, Copy)]
+pub struct StrengthBuckets {
+    /// 구간 수 (1 이상)
+    pub buckets: usize,
+}
+
+impl StrengthBuckets {
+    /// `buckets`개 구간의 강도 추상화
+    pub fn new(buckets: usize) -> Self {
+        Self { buckets: buckets.max(1) }
+    }
+}
+
+impl Abstraction for StrengthBuckets {
+    fn info_key(&self, state: &State, player: usize) -> u64 {
+        let strength = hand_strength(state.hole[player], &state.board);
+        let bucket = ((strength * self.buckets as f64) as usize).min(self.buckets - 1);
+        bucket as u64 ^ decision_context(state, player)
+    }
+
+    fn name(&self) -> String {
+        format!("StrengthBuckets({})", self.buckets)
+    }
+}
+
+/// 홀카드 버킷과 겹치지 않는 상위 비트에 담은 공개 정보 (팟, 콜 금액, 버튼 기준 포지션)
+fn decision_context(state: &State, player: usize) -> u64 {
+    let position = (player + 6 - state.button % 6) % 6;
+    ((state.pot as u64) << 32) ^ ((state.to_call as u64) << 16) ^ ((position as u64) << 56)
+}
+
+/// 클래스별 푸시/폴드 전략 (인덱스는 `HandClass::to_index`)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PushFoldProfile {
+    /// SB가 올인할 확률
+    pub shove: Vec<f64>,
+    /// BB가 올인에 콜할 확률
+    pub call: Vec<f64>,
+}
+
+/// 한 추상화로 학습한 결과
+#[derive(Clone)]
+pub struct TrainedAbstraction {
+    /// 추상화 이름 (`Abstraction::name`)
+    pub name: String,
+    /// 정보 집합 키별 노드
+    pub nodes: HashMap<u64, Node>,
+    /// 평균 전략을 클래스별로 펼친 전략
+    pub profile: PushFoldProfile,
+    /// 학습에 걸린 시간 (에퀴티 표 준비 제외)
+    pub training_time: Duration,
+}
+
+/// 추상화 하나의 품질/비용
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AbstractionResult {
+    /// 추상화 이름
+    pub name: String,
+    /// 학습된 정보 집합 노드 수
+    pub node_count: usize,
+    /// 학습 시간 (밀리초)
+    pub training_ms: f64,
+    /// 기준 전략과 좌석을 바꿔 가며 붙었을 때 잃는 EV (bb/100, 음수면 이김)
+    pub ev_loss_bb_per_100: f64,
+    /// 최선 응답이 두 좌석 평균으로 얻는 EV (bb/100)
+    pub exploitability_bb_per_100: f64,
+}
+
+/// 추상화별 EV 손실 보고서
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AbstractionReport {
+    /// 유효 스택 (빅블라인드)
+    pub stack_bb: u32,
+    /// 추상화마다 실행한 CFR 반복 수
+    pub iterations: usize,
+    /// 기준으로 쓴 가장 세밀한 추상화 (노드 수가 가장 많은 것, 같으면 먼저 온 것)
+    pub reference: String,
+    /// 입력 순서대로의 추상화별 결과
+    pub results: Vec<AbstractionResult>,
+}
+
+impl AbstractionReport {
+    /// 헤더가 있는 CSV (추상화 한 줄씩, 입력 순서)
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("abstraction,node_count,training_ms,ev_loss_bb_per_100,exploitability_bb_per_100\n");
+        for result in &self.results {
+            csv.push_str(&format!(
+                "{},{},{:.3},{:.4},{:.4}\n",
+                result.name,
+                result.node_count,
+                result.training_ms,
+                result.ev_loss_bb_per_100,
+                result.exploitability_bb_per_100
+            ));
+        }
+        csv
+    }
+}
+
+/// 클래스 쌍별 에퀴티와 조합 가중치를 미리 계산한 푸시/폴드 게임
+struct PushFoldGame {
+    /// 유효 스택 (bb)
+    stack: f64,
+    /// `equity[sb * 169 + bb]`: SB 클래스의 에퀴티
+    equity: Vec<f64>,
+    /// `weight[sb * 169 + bb]`: 겹치지 않는 조합 쌍 수
+    weight: Vec<f64>,
+    /// 전체 조합 쌍 수
+    total_weight: f64,
+}
+
+impl PushFoldGame {
+    fn new(config: &PushFoldConfig) -> Self {
+        let classes = HandClass::all();
+        let mut equity = vec![0.0; CLASSES * CLASSES];
+        let mut weight = vec![0.0; CLASSES * CLASSES];
+        for (i, &sb) in classes.iter().enumerate() {
+            let sb_combos = sb.combos(&[]);
+            for (j, &bb) in classes.iter().enumerate() {
+                equity[i * CLASSES + j] = preflop_class_equity(sb, bb);
+                weight[i * CLASSES + j] = sb_combos.iter().map(|combo| bb.combos(combo).len()).sum::<usize>() as f64;
+            }
+        }
+        let total_weight = weight.iter().sum();
+        Self { stack: config.stack_bb as f64, equity, weight, total_weight }
+    }
+
+    /// 올인 콜이 됐을 때 SB의 손익 (bb)
+    fn showdown(&self, sb: usize, bb: usize) -> f64 {
+        self.stack * (2.0 * self.equity[sb * CLASSES + bb] - 1.0)
+    }
+
+    /// SB 클래스별 (폴드, 올인) 가중 손익 합계
+    fn sb_action_values(&self, call: &[f64]) -> Vec<[f64; 2]> {
+        (0..CLASSES)
+            .map(|i| {
+                let mut values = [0.0; 2];
+                for (j, &p) in call.iter().enumerate() {
+                    let w = self.weight[i * CLASSES + j];
+                    values[0] -= w * 0.5;
+                    values[1] += w * ((1.0 - p) + p * self.showdown(i, j));
+                }
+                values
+            })
+            .collect()
+    }
+
+    /// BB 클래스별 (폴드, 콜) 가중 손익 합계 (SB가 폴드한 핸드는 BB 선택과 무관해 제외)
+    fn bb_action_values(&self, shove: &[f64]) -> Vec<[f64; 2]> {
+        (0..CLASSES)
+            .map(|j| {
+                let mut values = [0.0; 2];
+                for (i, &p) in shove.iter().enumerate() {
+                    let reach = self.weight[i * CLASSES + j] * p;
+                    values[0] -= reach;
+                    values[1] -= reach * self.showdown(i, j);
+                }
+                values
+            })
+            .collect()
+    }
+
+    /// SB 전략 `shove`와 BB 전략 `call`이 붙었을 때 SB의 핸드당 EV (bb)
+    fn sb_value(&self, shove: &[f64], call: &[f64]) -> f64 {
+        let total: f64 = self
+            .sb_action_values(call)
+            .iter()
+            .zip(shove)
+            .map(|(values, &p)| (1.0 - p) * values[0] + p * values[1])
+            .sum();
+        total / self.total_weight
+    }
+
+    /// BB 전략 `call`에 대한 SB 최선 응답의 핸드당 EV (bb)
+    fn best_sb_value(&self, call: &[f64]) -> f64 {
+        let total: f64 = self.sb_action_values(call).iter().map(|values| values[0].max(values[1])).sum();
+        total / self.total_weight
+    }
+
+    /// SB 전략 `shove`에 대한 BB 최선 응답의 핸드당 EV (bb, SB가 폴드한 블라인드 포함)
+    fn best_bb_value(&self, shove: &[f64]) -> f64 {
+        let folded: f64 = (0..CLASSES)
+            .map(|i| (1.0 - shove[i]) * (0..CLASSES).map(|j| self.weight[i * CLASSES + j]).sum::<f64>() * 0.5)
+            .sum();
+        let contested: f64 = self.bb_action_values(shove).iter().map(|values| values[0].max(values[1])).sum();
+        (folded + contested) / self.total_weight
+    }
+}
+
+/// 클래스별 (SB, BB) 정보 집합 키 - 각 클래스의 첫 조합을 대표로 결정 상태를 만들어 추상화에 넘김
+fn class_keys(config: &PushFoldConfig, abstraction: &dyn Abstraction) -> (Vec<u64>, Vec<u64>) {
+    let root = config.root();
+    let facing_shove = State::next_state(&root, Act::Raise(1));
+    HandClass::all()
+        .into_iter()
+        .map(|class| {
+            let hole = class.combos(&[])[0];
+            let (mut sb_state, mut bb_state) = (root.clone(), facing_shove.clone());
+            sb_state.hole[0] = hole;
+            bb_state.hole[1] = hole;
+            (abstraction.info_key(&sb_state, 0), abstraction.info_key(&bb_state, 1))
+        })
+        .unzip()
+}
+
+/// 클래스별 가중 액션 값을 키별로 모아 CFR+ 업데이트 (평균 전략은 반복 번호로 선형 가중)
+fn update_nodes(nodes: &mut HashMap<u64, Node>, keys: &[u64], values: &[[f64; 2]], iteration: usize) {
+    let mut grouped: HashMap<u64, [f64; 2]> = HashMap::default();
+    for (key, class_values) in keys.iter().zip(values) {
+        let entry = grouped.entry(*key).or_insert([0.0; 2]);
+        entry[0] += class_values[0];
+        entry[1] += class_values[1];
+    }
+    for (key, values) in grouped {
+        let node = nodes.get_mut(&key).expect("키마다 노드를 미리 만듦");
+        let strategy = node.strategy();
+        let expected = strategy[0] * values[0] + strategy[1] * values[1];
+        for action in 0..2 {
+            node.update_regret(action, values[action] - expected);
+            node.update_strategy(action, iteration as f64 * strategy[action]);
+        }
+        node.record_visit();
+    }
+}
+
+fn train_on(game: &PushFoldGame, config: &PushFoldConfig, abstraction: &dyn Abstraction, iterations: usize) -> TrainedAbstraction {
+    let start = Instant::now();
+    let (sb_keys, bb_keys) = class_keys(config, abstraction);
+    let mut nodes: HashMap<u64, Node> = HashMap::default();
+    for &key in sb_keys.iter().chain(&bb_keys) {
+        nodes.entry(key).or_insert_with(|| Node::new(2, vec![1.0; 2]));
+    }
+
+    let current = |nodes: &HashMap<u64, Node>, keys: &[u64]| -> Vec<f64> {
+        keys.iter().map(|key| nodes[key].strategy()[1]).collect()
+    };
+    // 교대 업데이트: SB가 현재 BB 전략에 대해 갱신한 뒤 BB가 갱신된 SB 전략에 대해 갱신
+    for iteration in 1..=iterations {
+        let call = current(&nodes, &bb_keys);
+        update_nodes(&mut nodes, &sb_keys, &game.sb_action_values(&call), iteration);
+        let shove = current(&nodes, &sb_keys);
+        update_nodes(&mut nodes, &bb_keys, &game.bb_action_values(&shove), iteration);
+    }
+    let training_time = start.elapsed();
+
+    let average = |keys: &[u64]| -> Vec<f64> { keys.iter().map(|key| nodes[key].average()[1]).collect() };
+    let profile = PushFoldProfile { shove: average(&sb_keys), call: average(&bb_keys) };
+    TrainedAbstraction { name: abstraction.name(), nodes, profile, training_time }
+}
+
+/// 푸시/폴드 게임을 `abstraction`으로 `iterations`번 CFR+ 학습
+pub fn train(config: &PushFoldConfig, abstraction: &dyn Abstraction, iterations: usize) -> TrainedAbstraction {
+    train_on(&PushFoldGame::new(config), config, abstraction, iterations)
+}
+
+/// 추상화마다 전략을 학습하고 가장 세밀한 추상화의 전략을 기준으로 EV 손실과 익스플로잇을 측정
+///
+/// 맞대결 EV는 두 좌석을 한 번씩 맡은 평균이라 기준 추상화 자신은 정확히 0이 됩니다.
+/// 추상화가 없으면 결과가 빈 보고서를 반환합니다.
+pub fn measure(config: &PushFoldConfig, abstractions: &[&dyn Abstraction], iterations: usize) -> AbstractionReport {
+    let game = PushFoldGame::new(config);
+    let trained: Vec<TrainedAbstraction> =
+        abstractions.iter().map(|abstraction| train_on(&game, config, *abstraction, iterations)).collect();
+
+    // max_by_key는 같은 값이면 마지막을 고르므로 앞쪽을 우선하도록 인덱스를 뒤집어 비교
+    let reference = trained
+        .iter()
+        .enumerate()
+        .max_by_key(|(index, result)| (result.nodes.len(), std::cmp::Reverse(*index)))
+        .map(|(_, result)| result);
+
+    let results = trained
+        .iter()
+        .map(|candidate| {
+            let ev_loss = reference.map_or(0.0, |reference| {
+                let as_sb = game.sb_value(&candidate.profile.shove, &reference.profile.call);
+                let as_bb = -game.sb_value(&reference.profile.shove, &candidate.profile.call);
+                -(as_sb + as_bb) / 2.0
+            });
+            let exploitability =
+                (game.best_sb_value(&candidate.profile.call) + game.best_bb_value(&candidate.profile.shove)) / 2.0;
+            AbstractionResult {
+                name: candidate.name.clone(),
+                node_count: candidate.nodes.len(),
+                training_ms: candidate.training_time.as_secs_f64() * 1000.0,
+                ev_loss_bb_per_100: ev_loss * 100.0,
+                exploitability_bb_per_100: exploitability * 100.0,
+            }
+        })
+        .collect();
+
+    AbstractionReport {
+        stack_bb: config.stack_bb,
+        iterations,
+        reference: reference.map(|reference| reference.name.clone()).unwrap_or_default(),
+        results,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::estimator::HoldemAbstraction;
+
+    const ITERATIONS: usize = 300;
+
+    #[test]
+    fn test_lossless_abstraction_has_zero_loss_against_itself() {
+        let report = measure(&PushFoldConfig::new(10), &[&HandClassAbstraction], ITERATIONS);
+
+        assert_eq!(report.reference, "HandClassAbstraction");
+        let lossless = &report.results[0];
+        assert_eq!(lossless.node_count, 2 * 169);
+        assert!(lossless.ev_loss_bb_per_100.abs() < 1e-9, "{}", lossless.ev_loss_bb_per_100);
+        assert!(lossless.exploitability_bb_per_100 < 2.0, "{}", lossless.exploitability_bb_per_100);
+    }
+
+    #[test]
+    fn test_two_buckets_lose_against_lossless() {
+        let coarse = StrengthBuckets::new(2);
+        let report = measure(&PushFoldConfig::new(10), &[&coarse, &HandClassAbstraction], ITERATIONS);
+
+        // 노드 수가 많은 무손실 추상화가 입력 순서와 상관없이 기준
+        assert_eq!(report.reference, "HandClassAbstraction");
+        let (coarse, lossless) = (&report.results[0], &report.results[1]);
+        assert_eq!(coarse.name, "StrengthBuckets(2)");
+        assert!(coarse.ev_loss_bb_per_100 > 10.0, "{}", coarse.ev_loss_bb_per_100);
+        assert!(coarse.exploitability_bb_per_100 > lossless.exploitability_bb_per_100 + 20.0);
+
+        let csv = report.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "abstraction,node_count,training_ms,ev_loss_bb_per_100,exploitability_bb_per_100");
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("StrengthBuckets(2),4,"));
+    }
+
+    #[test]
+    fn test_node_counts_match_trainers() {
+        let config = PushFoldConfig::new(8);
+        let coarse = StrengthBuckets::new(5);
+        let abstractions: [&dyn Abstraction; 3] = [&HandClassAbstraction, &HoldemAbstraction, &coarse];
+        let report = measure(&config, &abstractions, 20);
+
+        for (abstraction, result) in abstractions.iter().zip(&report.results) {
+            let trained = train(&config, *abstraction, 20);
+            assert_eq!(result.name, trained.name);
+            assert_eq!(result.node_count, trained.nodes.len());
+        }
+        assert!(report.results[1].node_count < report.results[0].node_count);
+        assert!(report.results[2].node_count <= 10);
+    }
+}
@@ -0,0 +1,163 @@
+// 서빙 시점 전략 블렌딩
+// 방문 횟수가 적은 정보 집합의 CFR 평균 전략은 노이즈에 가까우므로
+// 방문 횟수에 비례하는 신뢰도로 휴리스틱(또는 균등) 전략과 선형 보간합니다.
+
+use serde::{Deserialize, Serialize};
+
+/// 신뢰도가 낮을 때 섞을 대체 전략
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FallbackSource {
+    /// 서빙 경로의 룰 기반 휴리스틱 전략
+    Heuristic,
+    /// 가능한 액션에 균등 분포
+    Uniform,
+}
+
+/// 방문 횟수 기반 블렌딩 정책
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlendPolicy {
+    /// 이 방문 횟수 이상이면 CFR 전략을 그대로 사용 (그 아래는 선형으로 신뢰도 감소)
+    pub min_visits_full_trust: u64,
+    /// 신뢰도가 부족한 만큼 섞을 전략
+    pub fallback: FallbackSource,
+}
+
+impl Default for BlendPolicy {
+    fn default() -> Self {
+        Self {
+            min_visits_full_trust: 1_000,
+            fallback: FallbackSource::Heuristic,
+        }
+    }
+}
+
+/// 블렌딩 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlendedStrategy {
+    /// 가능한 액션 순서의 확률 (합 1)
+    pub probabilities: Vec<f64>,
+    /// CFR 전략에 준 가중치 (0 = 대체 전략만, 1 = CFR 전략만)
+    pub trust_weight: f64,
+}
+
+impl BlendPolicy {
+    /// 방문 횟수에 대한 CFR 전략 신뢰도 (0-1)
+    pub fn trust_weight(&self, visits: u64) -> f64 {
+        if self.min_visits_full_trust == 0 {
+            return 1.0;
+        }
+        (visits as f64 / self.min_visits_full_trust as f64).min(1.0)
+    }
+
+    /// CFR 전략과 대체 전략을 신뢰도로 섞어 `legal_count`개 액션의 분포 생성
+    ///
+    /// 두 전략 모두 가능한 액션 순서로 맞춰지며, 남는 항목은 버리고 모자란 항목과 음수는 0으로
+    /// 취급한 뒤 정규화합니다. CFR 전략이 없거나 확률 합이 0이면 신뢰도는 0입니다.
+    /// 휴리스틱은 `FallbackSource::Heuristic`이고 신뢰도가 1 미만일 때만 계산하며,
+    /// 휴리스틱도 비어 있으면 균등 분포를 사용합니다.
+    pub fn blend(
+        &self,
+        cfr: Option<&[f64]>,
+        visits: u64,
+        legal_count: usize,
+        heuristic: impl FnOnce() -> Vec<f64>,
+    ) -> BlendedStrategy {
+        let cfr = cfr.and_then(|strategy| normalized(strategy, legal_count));
+        let trust_weight = if cfr.is_some() { self.trust_weight(visits) } else { 0.0 };
+
+        let fallback = if trust_weight >= 1.0 {
+            vec![0.0; legal_count]
+        } else {
+            let fallback = match self.fallback {
+                FallbackSource::Heuristic => normalized(&heuristic(), legal_count),
+                FallbackSource::Uniform => None,
+            };
+            fallback.unwrap_or_else(|| uniform(legal_count))
+        };
+
+        let probabilities = match cfr {
+            Some(cfr) => cfr
+                .iter()
+                .zip(&fallback)
+                .map(|(c, f)| trust_weight * c + (1.0 - trust_weight) * f)
+                .collect(),
+            None => fallback,
+        };
+        BlendedStrategy {
+            probabilities,
+            trust_weight,
+        }
+    }
+}
+
+fn uniform(len: usize) -> Vec<f64> {
+    vec![1.0 / len.max(1) as f64; len]
+}
+
+/// `len`개 항목으로 맞춰 정규화 (합이 0이면 None)
+fn normalized(strategy: &[f64], len: usize) -> Option<Vec<f64>> {
+    let fitted: Vec<f64> = (0..len)
+        .map(|i| strategy.get(i).copied().filter(|p| p.is_finite()).unwrap_or(0.0).max(0.0))
+        .collect();
+    let sum: f64 = fitted.iter().sum();
+    (sum > 0.0).then(|| fitted.iter().map(|p| p / sum).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_distribution(probabilities: &[f64], len: usize) {
+        assert_eq!(probabilities.len(), len);
+        assert!(probabilities.iter().all(|&p| (0.0..=1.0).contains(&p)), "{:?}", probabilities);
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9, "{:?}", probabilities);
+    }
+
+    #[test]
+    fn test_trust_grows_with_visits() {
+        let policy = BlendPolicy {
+            min_visits_full_trust: 1_000,
+            fallback: FallbackSource::Heuristic,
+        };
+        let cfr = [0.0, 0.0, 1.0];
+        let heuristic = || vec![0.7, 0.3, 0.0];
+
+        let few = policy.blend(Some(&cfr), 2, 3, heuristic);
+        assert_eq!(few.trust_weight, 0.002);
+        assert!((few.probabilities[0] - 0.7).abs() < 0.01, "{:?}", few.probabilities);
+
+        let many = policy.blend(Some(&cfr), 10_000, 3, heuristic);
+        assert_eq!(many.trust_weight, 1.0);
+        assert_eq!(many.probabilities, cfr);
+
+        let half = policy.blend(Some(&cfr), 500, 3, heuristic);
+        assert_eq!(half.probabilities, [0.35, 0.15, 0.5]);
+    }
+
+    #[test]
+    fn test_blend_is_distribution_over_legal_actions() {
+        let uniform_policy = BlendPolicy {
+            min_visits_full_trust: 100,
+            fallback: FallbackSource::Uniform,
+        };
+        let heuristic_policy = BlendPolicy {
+            fallback: FallbackSource::Heuristic,
+            ..uniform_policy
+        };
+        let strategies: [&[f64]; 4] = [&[0.2, 0.8], &[0.1, 0.2, 0.3, 0.4], &[0.0, 0.0], &[-1.0, f64::NAN, 2.0]];
+        for policy in [uniform_policy, heuristic_policy] {
+            for legal_count in 1..=3 {
+                for visits in [0, 1, 50, 100, 5_000] {
+                    for strategy in strategies {
+                        let blended = policy.blend(Some(strategy), visits, legal_count, || vec![0.0, 3.0, 1.0, 9.0]);
+                        assert_distribution(&blended.probabilities, legal_count);
+                        assert!((0.0..=1.0).contains(&blended.trust_weight));
+                    }
+                    let missing = policy.blend(None, visits, legal_count, Vec::new);
+                    assert_distribution(&missing.probabilities, legal_count);
+                    assert_eq!(missing.trust_weight, 0.0);
+                }
+            }
+        }
+    }
+}
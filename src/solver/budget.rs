@@ -0,0 +1,280 @@
+// 계산 예산
+// EV 시뮬레이션, 올인 에퀴티, 서브게임 리솔빙, 분석 파이프라인이 함께 쓰는 시간/샘플/노드 한도입니다.
+// 중첩 호출(분석 → EV → 에퀴티)은 부모 예산의 남은 몫을 자식 예산(`child`)으로 받아 쓰고, 사용량은
+// 같은 카운터에 쌓이므로 자식들이 쓴 샘플 합은 루트의 `max_samples`를 넘지 않습니다.
+//
+// 한도는 배치 경계에서만 검사합니다 (샘플마다 시계를 읽지 않음). 샘플은 배치를 시작하기 전에
+// `take_samples`로 미리 받아 두므로 초과하지 않고, 시간과 노드는 진행 중인 배치만큼 넘을 수 있습니다.
+// 예산이 끝나면 `BudgetExhausted`가 기록되며, 부분 결과가 의미 있는 계산은 에러 대신 그때까지의
+// 결과와 `truncated` 표시를 돌려줍니다.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// 예산이 끝난 이유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetExhausted {
+    /// 마감 시각이 지남
+    Deadline,
+    /// 샘플 한도를 다 씀
+    Samples,
+    /// 노드 한도를 다 씀
+    Nodes,
+}
+
+impl BudgetExhausted {
+    fn code(self) -> u8 {
+        match self {
+            Self::Deadline => 1,
+            Self::Samples => 2,
+            Self::Nodes => 3,
+        }
+    }
+
+    fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(Self::Deadline),
+            2 => Some(Self::Samples),
+            3 => Some(Self::Nodes),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BudgetExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Deadline => write!(f, "계산 시간 예산이 끝났습니다"),
+            Self::Samples => write!(f, "샘플 예산을 다 썼습니다"),
+            Self::Nodes => write!(f, "노드 예산을 다 썼습니다"),
+        }
+    }
+}
+
+impl std::error::Error for BudgetExhausted {}
+
+/// 예산을 나눠 쓰는 계산들이 공유하는 사용량
+#[derive(Debug, Default)]
+struct BudgetUsage {
+    samples: AtomicU64,
+    nodes: AtomicU64,
+    /// 처음 끝난 이유 (`BudgetExhausted::code`, 0이면 아직 남음)
+    exhausted: AtomicU8,
+}
+
+/// 계산 예산 (None인 한도는 제한 없음)
+///
+/// 복제본과 `child`로 만든 자식 예산은 사용량을 공유합니다. 기본값은 제한 없는 예산입니다.
+#[derive(Debug, Clone, Default)]
+pub struct ComputeBudget {
+    /// 이 시각이 지나면 새 배치를 시작하지 않음
+    pub deadline: Option<Instant>,
+    /// 이 예산으로 돌릴 수 있는 최대 샘플 수 (시뮬레이션, 보드, 리솔빙 반복)
+    pub max_samples: Option<u64>,
+    /// 이 예산으로 방문할 수 있는 최대 게임 트리 노드 수
+    pub max_nodes: Option<u64>,
+    usage: Arc<BudgetUsage>,
+    /// 예산을 만들 때의 공유 사용량 [샘플, 노드] (이 예산의 한도는 여기서부터 셈)
+    base: [u64; 2],
+}
+
+/// 예산 안에서 계산한 (부분일 수 있는) 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct Budgeted<T> {
+    pub value: T,
+    /// 예산이 끝나 계산을 다 하지 못했으면 그 이유
+    pub exhausted: Option<BudgetExhausted>,
+}
+
+impl<T> Budgeted<T> {
+    /// 예산이 끝나 일부만 계산한 결과인지
+    pub fn truncated(&self) -> bool {
+        self.exhausted.is_some()
+    }
+}
+
+impl ComputeBudget {
+    /// 한도를 지정한 새 예산 (사용량 0에서 시작)
+    pub fn new(deadline: Option<Instant>, max_samples: Option<u64>, max_nodes: Option<u64>) -> Self {
+        Self {
+            deadline,
+            max_samples,
+            max_nodes,
+            ..Self::default()
+        }
+    }
+
+    /// 제한 없는 예산
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// 지금부터 `limit` 뒤를 마감으로 (이미 더 이른 마감이 있으면 그대로)
+    pub fn with_time_limit(mut self, limit: Duration) -> Self {
+        let deadline = Instant::now() + limit;
+        self.deadline = Some(self.deadline.map_or(deadline, |existing| existing.min(deadline)));
+        self
+    }
+
+    /// 샘플 한도 지정
+    pub fn with_max_samples(mut self, max_samples: u64) -> Self {
+        self.max_samples = Some(max_samples);
+        self
+    }
+
+    /// 노드 한도 지정
+    pub fn with_max_nodes(mut self, max_nodes: u64) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
+    /// 이 예산을 만든 뒤로 쓴 샘플 수 (자식 예산 포함)
+    pub fn samples_used(&self) -> u64 {
+        self.usage.samples.load(Ordering::Relaxed).saturating_sub(self.base[0])
+    }
+
+    /// 이 예산을 만든 뒤로 방문한 노드 수 (자식 예산 포함)
+    pub fn nodes_used(&self) -> u64 {
+        self.usage.nodes.load(Ordering::Relaxed).saturating_sub(self.base[1])
+    }
+
+    /// 남은 샘플 수 (제한이 없으면 None)
+    pub fn remaining_samples(&self) -> Option<u64> {
+        self.max_samples.map(|max| max.saturating_sub(self.samples_used()))
+    }
+
+    /// 남은 노드 수 (제한이 없으면 None)
+    pub fn remaining_nodes(&self) -> Option<u64> {
+        self.max_nodes.map(|max| max.saturating_sub(self.nodes_used()))
+    }
+
+    /// 마감까지 남은 시간 (마감이 없으면 None)
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// 남은 예산의 `share` 비율(0-1)을 쓰는 자식 예산 (사용량은 이 예산과 공유)
+    pub fn child(&self, share: f64) -> ComputeBudget {
+        let share = if share.is_nan() { 1.0 } else { share.clamp(0.0, 1.0) };
+        let portion = |remaining: Option<u64>| remaining.map(|left| (left as f64 * share).floor() as u64);
+        ComputeBudget {
+            deadline: self.remaining_time().map(|left| Instant::now() + left.mul_f64(share)),
+            max_samples: portion(self.remaining_samples()),
+            max_nodes: portion(self.remaining_nodes()),
+            usage: Arc::clone(&self.usage),
+            base: [self.usage.samples.load(Ordering::Relaxed), self.usage.nodes.load(Ordering::Relaxed)],
+        }
+    }
+
+    /// 배치 경계 검사: 새 배치를 시작해도 되는지 (끝났으면 이유를 기록)
+    pub fn check(&self) -> Result<(), BudgetExhausted> {
+        let reason = if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            Some(BudgetExhausted::Deadline)
+        } else if self.remaining_samples() == Some(0) {
+            Some(BudgetExhausted::Samples)
+        } else if self.remaining_nodes() == Some(0) {
+            Some(BudgetExhausted::Nodes)
+        } else {
+            None
+        };
+        match reason {
+            Some(reason) => {
+                self.mark(reason);
+                Err(reason)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// 다음 배치에 쓸 샘플을 `wanted`개까지 받아 사용량에 기록 (받은 수, 예산이 끝났으면 Err)
+    ///
+    /// 샘플 한도 때문에 `wanted`보다 적게 받으면 예산이 끝난 것으로 기록합니다.
+    pub fn take_samples(&self, wanted: u64) -> Result<u64, BudgetExhausted> {
+        self.check()?;
+        let granted = self.remaining_samples().map_or(wanted, |left| left.min(wanted));
+        if granted < wanted {
+            self.mark(BudgetExhausted::Samples);
+        }
+        self.usage.samples.fetch_add(granted, Ordering::Relaxed);
+        Ok(granted)
+    }
+
+    /// 시간 한도와 관계없이 샘플 `wanted`개를 받음 (샘플 한도는 지킴)
+    ///
+    /// 마감이 지났어도 최소한의 결과(예: 액션마다 한 번)를 내야 하는 곳에서 씁니다.
+    pub fn take_samples_past_deadline(&self, wanted: u64) -> u64 {
+        let granted = self.remaining_samples().map_or(wanted, |left| left.min(wanted));
+        if granted < wanted {
+            self.mark(BudgetExhausted::Samples);
+        }
+        self.usage.samples.fetch_add(granted, Ordering::Relaxed);
+        granted
+    }
+
+    /// 방문한 노드 수 기록 (검사는 다음 `check`에서)
+    pub fn charge_nodes(&self, nodes: u64) {
+        self.usage.nodes.fetch_add(nodes, Ordering::Relaxed);
+    }
+
+    /// 이 예산이나 사용량을 공유하는 예산이 처음 끝난 이유
+    pub fn exhausted(&self) -> Option<BudgetExhausted> {
+        BudgetExhausted::from_code(self.usage.exhausted.load(Ordering::Relaxed))
+    }
+
+    /// 값과 지금까지의 예산 상태를 묶은 결과
+    pub fn finish<T>(&self, value: T) -> Budgeted<T> {
+        Budgeted {
+            value,
+            exhausted: self.exhausted(),
+        }
+    }
+
+    fn mark(&self, reason: BudgetExhausted) {
+        let _ = self
+            .usage
+            .exhausted
+            .compare_exchange(0, reason.code(), Ordering::Relaxed, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_children_share_the_sample_limit() {
+        let root = ComputeBudget::unlimited().with_max_samples(100);
+        let first = root.child(0.5);
+        assert_eq!(first.max_samples, Some(50));
+        assert_eq!(first.take_samples(30), Ok(30));
+        assert_eq!(first.take_samples(30), Ok(20));
+        assert_eq!(first.take_samples(1), Err(BudgetExhausted::Samples));
+
+        // 두 번째 자식은 남은 50개 전부
+        let second = root.child(1.0);
+        assert_eq!(second.max_samples, Some(50));
+        assert_eq!(second.take_samples(80), Ok(50));
+        assert_eq!(root.samples_used(), 100);
+        assert_eq!(root.exhausted(), Some(BudgetExhausted::Samples));
+        assert!(root.finish(()).truncated());
+
+        let unlimited = ComputeBudget::unlimited();
+        assert_eq!(unlimited.take_samples(1_000_000), Ok(1_000_000));
+        assert!(!unlimited.finish(()).truncated());
+    }
+
+    #[test]
+    fn test_deadline_is_checked_at_batch_boundaries() {
+        let budget = ComputeBudget::unlimited().with_time_limit(Duration::ZERO);
+        assert_eq!(budget.check(), Err(BudgetExhausted::Deadline));
+        assert_eq!(budget.take_samples(10), Err(BudgetExhausted::Deadline));
+        assert_eq!(budget.take_samples_past_deadline(1), 1);
+
+        let nodes = ComputeBudget::unlimited().with_max_nodes(10);
+        nodes.charge_nodes(12);
+        assert_eq!(nodes.remaining_nodes(), Some(0));
+        assert_eq!(nodes.check(), Err(BudgetExhausted::Nodes));
+    }
+}
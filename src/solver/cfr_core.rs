@@ -10,7 +10,13 @@
 // - 메모리 사용량 최적화 (음수 값 저장 불필요)
 
 use fxhash::FxHashMap as HashMap;
-use rand::rngs::ThreadRng;
+use crate::solver::compat::{CompatibilityFingerprint, IncompatibleArtifact, MigrationRegistry, FINGERPRINT_METADATA_KEY};
+use rand::rngs::StdRng;
+use crate::game::rng::entropy_seed;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
 
 /// 게임 공통 트레잇 - 모든 포커 게임이 구현해야 하는 기본 인터페이스
 ///
@@ -33,13 +39,58 @@ pub trait Game: Sync {
     fn next_state(s: &Self::State, a: Self::Action) -> Self::State;
 
     /// 찬스 노드에서 랜덤 이벤트 적용 (카드 딜링 등)
-    fn apply_chance(s: &Self::State, r: &mut ThreadRng) -> Self::State;
+    ///
+    /// 난수 생성기를 제네릭으로 받으므로 시드 고정 학습(`Trainer::set_seed`)에서도 재현됩니다.
+    fn apply_chance<R: Rng>(s: &Self::State, r: &mut R) -> Self::State;
+
+    /// 찬스 노드의 모든 결과와 그 확률 (`ChanceHandling::Enumerate` 학습에서 사용, 확률 합은 1)
+    ///
+    /// 결과를 열거할 수 없는 게임이나 상태(예: 홀덤 플랍 딜링)는 기본 구현처럼 None을 반환하며,
+    /// 열거 모드 학습은 그런 찬스 노드에 도달하면 `TrainingError::ChanceNotEnumerable`로 중단됩니다.
+    fn chance_outcomes(_s: &Self::State) -> Option<Vec<(Self::State, f64)>> {
+        None
+    }
 
     /// 터미널 노드에서 히어로의 유틸리티 값 계산
     fn util(s: &Self::State, hero: usize) -> f64;
 
     /// 플레이어의 정보 집합 키 생성 (같은 키 = 같은 정보)
     fn info_key(s: &Self::State, v: usize) -> Self::InfoKey;
+
+    /// 정보 집합 키를 필드로 풀어 냄 (NDJSON 내보내기의 `fields`와 스트리트 필터에서 사용)
+    ///
+    /// 해시처럼 섞인 키는 되돌릴 수 없으므로 기본 구현은 None을 반환합니다.
+    fn describe_info_key(_key: &Self::InfoKey) -> Option<InfoKeyFields> {
+        None
+    }
+
+    /// 저장 산출물 호환성 지문 (`root`가 있으면 그 상태의 규칙을 반영)
+    ///
+    /// 기본 구현은 게임 타입 이름을 추상화 식별자로 쓰고 베팅 규칙/액션 추상화는 비워 둡니다.
+    /// 추상화나 액션 추상화를 바꿀 수 있는 게임은 그 매개변수를 담도록 재정의합니다.
+    fn fingerprint(_root: Option<&Self::State>) -> CompatibilityFingerprint {
+        CompatibilityFingerprint::new(std::any::type_name::<Self>(), "fixed", Vec::new())
+    }
+}
+
+/// 정보 집합 키에서 읽어 낸 필드 (`Game::describe_info_key`)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct InfoKeyFields {
+    /// 스트리트 (0 = 첫 베팅 라운드)
+    pub street: Option<u8>,
+    /// 게임별 나머지 필드 (이름 -> 값, JSON에서는 `street`와 같은 수준으로 펼침)
+    #[serde(flatten)]
+    pub fields: BTreeMap<&'static str, u64>,
+}
+
+/// 터미널 상태 평가기 - `Game::util` 대신 사용할 유틸리티 계산기
+///
+/// 한 번의 호출로 모든 플레이어의 유틸리티를 반환하므로 사이드 팟이나 ICM처럼
+/// 비싼 계산을 히어로마다 반복하지 않고, 여러 게임이 같은 평가 로직(올인 판정기,
+/// 레이크 모델 등)을 공유할 수 있습니다. `Trainer::with_evaluator`로 지정합니다.
+pub trait TerminalEvaluator<G: Game>: Send + Sync {
+    /// 터미널 상태에서 플레이어별 유틸리티 (좌석 순서, 길이 `G::N_PLAYERS`)
+    fn evaluate(&self, state: &G::State) -> Vec<f64>;
 }
 
 /// CFR 노드 - 각 정보 집합에서의 전략과 리그렛 저장
@@ -47,12 +98,27 @@ pub trait Game: Sync {
 /// 노드는 다음을 추적합니다:
 /// - regret_sum: 각 액션에 대한 누적 리그렛
 /// - strat_sum: 각 액션의 누적 전략 확률  
-/// - delta_prefs: δ-uniform 믹싱을 위한 선호도 값
-#[derive(Clone)]
+/// - delta_prefs: 양수 리그렛이 없을 때 사용할 선호도 값
+/// - last_touch: 마지막 방문 시점 (노드 수 제한 시 LRU 축출에 사용)
+/// - visits: 업데이트 횟수 (병합 시 가중치로 사용)
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Node {
     regret_sum: Vec<f64>,  // 누적 리그렛 합계
     strat_sum: Vec<f64>,   // 누적 전략 합계
     delta_prefs: Vec<f64>, // δ 선호도 (균일 분포 방지)
+    last_touch: u64,       // 마지막 방문 스탬프
+    visits: u64,           // 누적 업데이트 횟수
+}
+
+/// 노드 병합 방식 (서브게임 리솔빙 결과를 글로벌 전략에 합칠 때 사용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeMode {
+    /// 기존 노드를 병합 대상으로 교체 (리솔빙 루트 아래에서 이론적으로 올바른 방식)
+    Replace,
+    /// 방문 횟수로 가중 평균한 전략/리그렛
+    WeightedAverage,
+    /// 전략 합계를 단순 누적 (기존 `merge` 동작, 호환성 유지용)
+    Accumulate,
 }
 
 impl Node {
@@ -66,13 +132,36 @@ impl Node {
             regret_sum: vec![0.0; n_acts],
             strat_sum: vec![0.0; n_acts],
             delta_prefs,
+            last_touch: 0,
+            visits: 0,
         }
     }
 
+    /// 누적 업데이트 횟수 (병합 가중치)
+    pub fn visits(&self) -> u64 {
+        self.visits
+    }
+
+    /// 액션별 누적 리그렛
+    pub fn regrets(&self) -> &[f64] {
+        &self.regret_sum
+    }
+
+    /// 액션별 누적 전략 합계
+    pub fn strategy_sums(&self) -> &[f64] {
+        &self.strat_sum
+    }
+
+    /// 업데이트 1회 기록
+    pub fn record_visit(&mut self) {
+        self.visits += 1;
+    }
+
     /// 현재 전략 계산 (regret matching+ 알고리즘)
     ///
-    /// 리그렛이 양수인 액션에 더 높은 확률을 부여합니다.
-    /// δ-uniform 믹싱을 적용하여 전략 붕괴를 방지합니다.
+    /// 리그렛이 양수인 액션에 비례하는 확률을 부여합니다.
+    /// 양수 리그렛이 없을 때만 δ 선호도 기반 분포를 사용합니다.
+    /// (항상 균일 분포를 섞으면 평균 전략이 균형에서 벗어나 수렴하지 않음)
     pub fn strategy(&self) -> Vec<f64> {
         let n = self.regret_sum.len();
         let mut s = vec![0.0; n];
@@ -85,21 +174,15 @@ impl Node {
             }
         }
 
-        // 전략 계산: 양수 리그렛 비례 + δ-uniform 믹싱
+        // 전략 계산: 양수 리그렛 비례
         if sum_pos > 0.0 {
             for i in 0..n {
-                let regret_part = if self.regret_sum[i] > 0.0 {
-                    self.regret_sum[i] / sum_pos
-                } else {
-                    0.0
-                };
-
-                let delta_part = self.delta_prefs[i] / n as f64;
-                let eps = 0.1; // 믹싱 비율
-                s[i] = (1.0 - eps) * regret_part + eps * delta_part;
+                if self.regret_sum[i] > 0.0 {
+                    s[i] = self.regret_sum[i] / sum_pos;
+                }
             }
         } else {
-            // 리그렛이 모두 음수면 δ 선호도 기반 균일 분포
+            // 리그렛이 모두 0이면 δ 선호도 기반 균일 분포
             for i in 0..n {
                 s[i] = self.delta_prefs[i] / n as f64;
             }
@@ -111,29 +194,100 @@ impl Node {
     /// 평균 전략 계산 (수렴된 최종 전략)
     ///
     /// 학습 과정에서 누적된 전략의 평균을 반환합니다.
-    /// 이것이 실제 게임에서 사용할 최종 전략입니다.
+    /// 이것이 실제 게임에서 사용할 최종 전략이며, 평균 전략을 얻는 유일한 기준 메서드입니다.
+    /// 한 번도 갱신되지 않아 전략 합계가 0이거나 유한하지 않으면 액션 수만큼의 균일 분포를 반환합니다.
     pub fn average(&self) -> Vec<f64> {
+        let n = self.strat_sum.len();
         let sum: f64 = self.strat_sum.iter().sum();
-        if sum > 0.0 {
+        if sum > 0.0 && sum.is_finite() {
             self.strat_sum.iter().map(|&x| x / sum).collect()
         } else {
             // 학습이 충분하지 않으면 균일 분포
-            let n = self.strat_sum.len();
             vec![1.0 / n as f64; n]
         }
     }
 
-    /// average()의 별칭 - lib.rs와의 호환성을 위함
+    /// `average()`의 이전 이름 (동작이 완전히 같음)
+    #[deprecated(note = "`Node::average()`를 사용하세요")]
     pub fn avg_strategy(&self) -> Vec<f64> {
         self.average()
     }
 
+    /// 노드 불변 조건 검사
+    ///
+    /// 리그렛/전략 합계/δ 선호도의 길이가 액션 수와 같고, 누적 값에 NaN이나 무한대가 없으며,
+    /// 평균 전략의 합이 1(허용 오차 `1e-6`)인지 확인합니다. 액션이 없는 노드는 항상 통과합니다.
+    pub fn validate(&self) -> Result<(), NodeInvariantError> {
+        let actions = self.strat_sum.len();
+        if self.regret_sum.len() != actions || self.delta_prefs.len() != actions {
+            return Err(NodeInvariantError::LengthMismatch {
+                regrets: self.regret_sum.len(),
+                strategy_sums: actions,
+                preferences: self.delta_prefs.len(),
+            });
+        }
+        if self.regret_sum.iter().chain(&self.strat_sum).any(|v| !v.is_finite()) {
+            return Err(NodeInvariantError::NonFinite);
+        }
+        if actions == 0 {
+            return Ok(());
+        }
+
+        let average = self.average();
+        let sum: f64 = average.iter().sum();
+        if average.iter().any(|p| !p.is_finite() || *p < 0.0) || (sum - 1.0).abs() > 1e-6 {
+            return Err(NodeInvariantError::NotADistribution { sum });
+        }
+        Ok(())
+    }
+
     /// 다른 노드와 병합 (서브게임 리솔빙에서 사용)
     ///
     /// 서브게임에서 학습한 전략을 메인 전략에 통합할 때 사용합니다.
+    /// `merge_weighted(other, MergeMode::Accumulate)`와 같습니다.
     pub fn merge(&mut self, other: &Node) {
-        for i in 0..self.strat_sum.len() {
-            self.strat_sum[i] += other.strat_sum[i];
+        self.merge_weighted(other, MergeMode::Accumulate);
+    }
+
+    /// 지정한 방식으로 다른 노드와 병합
+    ///
+    /// 액션 수가 다른 노드는 같은 정보 집합으로 볼 수 없으므로
+    /// `Accumulate`/`WeightedAverage`에서는 대상 노드로 교체합니다.
+    pub fn merge_weighted(&mut self, other: &Node, mode: MergeMode) {
+        let same_shape = self.strat_sum.len() == other.strat_sum.len();
+
+        match mode {
+            MergeMode::Accumulate if same_shape => {
+                for i in 0..self.strat_sum.len() {
+                    self.strat_sum[i] += other.strat_sum[i];
+                }
+                self.visits += other.visits;
+            }
+            MergeMode::WeightedAverage if same_shape => {
+                // 방문 기록이 없는 노드끼리는 동일 가중치로 취급
+                let (w_self, w_other) = if self.visits + other.visits == 0 {
+                    (1.0, 1.0)
+                } else {
+                    (self.visits as f64, other.visits as f64)
+                };
+                let total = w_self + w_other;
+
+                // 평균 전략을 가중 평균한 뒤 합계 가중치로 다시 스케일
+                let avg_self = self.average();
+                let avg_other = other.average();
+                for i in 0..self.strat_sum.len() {
+                    self.strat_sum[i] = w_self * avg_self[i] + w_other * avg_other[i];
+                    self.regret_sum[i] =
+                        (w_self * self.regret_sum[i] + w_other * other.regret_sum[i]) / total;
+                }
+                self.visits += other.visits;
+            }
+            _ => {
+                self.regret_sum = other.regret_sum.clone();
+                self.strat_sum = other.strat_sum.clone();
+                self.delta_prefs = other.delta_prefs.clone();
+                self.visits = other.visits;
+            }
         }
     }
 
@@ -153,27 +307,211 @@ impl Node {
     }
 }
 
-/// 스레드 로컬 데이터 - 병렬 CFR 실행을 위한 랜덤 생성기
+/// `Node::validate`가 찾은 불변 조건 위반
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeInvariantError {
+    /// 리그렛/전략 합계/δ 선호도의 길이가 서로 다름
+    LengthMismatch {
+        regrets: usize,
+        strategy_sums: usize,
+        preferences: usize,
+    },
+    /// 누적 리그렛이나 전략 합계에 NaN 또는 무한대가 있음
+    NonFinite,
+    /// 평균 전략이 확률 분포가 아님 (음수 또는 합이 1이 아님)
+    NotADistribution { sum: f64 },
+}
+
+impl std::fmt::Display for NodeInvariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LengthMismatch {
+                regrets,
+                strategy_sums,
+                preferences,
+            } => write!(
+                f,
+                "노드 배열 길이가 다릅니다 (리그렛 {}, 전략 합계 {}, 선호도 {})",
+                regrets, strategy_sums, preferences
+            ),
+            Self::NonFinite => write!(f, "노드 누적 값에 NaN 또는 무한대가 있습니다"),
+            Self::NotADistribution { sum } => {
+                write!(f, "평균 전략이 확률 분포가 아닙니다 (합 {})", sum)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NodeInvariantError {}
+
+/// 스레드 로컬 데이터 - 병렬 CFR 실행을 위한 랜덤 생성기 (스레드마다 한 번 시드)
 struct ThreadLocalData {
-    rng: ThreadRng,
+    rng: StdRng,
 }
 
 thread_local! {
     static TL_DATA: std::cell::RefCell<ThreadLocalData> = std::cell::RefCell::new(ThreadLocalData {
-        rng: rand::thread_rng(),
+        rng: StdRng::seed_from_u64(entropy_seed()),
     });
 }
 
+/// 노드 수 제한에 도달했을 때의 처리 정책
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitPolicy {
+    /// 제한에 도달하면 학습을 중단하고 에러를 반환
+    Abort,
+    /// 가장 오랫동안 방문되지 않은 노드를 축출하여 제한 이하로 유지
+    ///
+    /// 축출된 노드의 누적 리그렛/전략은 사라지므로 CFR의 수렴 보장이 약해집니다.
+    EvictColdest,
+}
+
+/// 평균 전략을 누적할 때 현재 전략에 곱하는 가중치 (`Trainer::set_average_weighting`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AverageWeighting {
+    /// 히어로 자신의 도달 확률(π_i)로 가중 (표준 CFR, 평균 전략이 균형으로 수렴)
+    #[default]
+    ReachWeighted,
+    /// 도달 확률 없이 현재 전략을 그대로 누적 (비교용)
+    ///
+    /// 히어로가 거의 가지 않는 정보 집합의 전략도 자주 가는 곳과 같은 비중으로 평균되어
+    /// 평균 전략이 균형에서 벗어난 곳에 머뭅니다.
+    Unweighted,
+}
+
+/// 찬스 노드 처리 방식 (`Trainer::set_chance_handling`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChanceHandling {
+    /// 반복마다 찬스 결과 하나를 샘플링 (모든 게임에서 동작)
+    #[default]
+    Sample,
+    /// 모든 찬스 결과를 확률로 가중해 정확히 계산 (`Game::chance_outcomes`를 구현한 작은 게임용)
+    ///
+    /// 샘플링 노이즈가 없어 반복당 수렴이 빠르고 결과가 시드와 무관하지만,
+    /// 반복 한 번의 비용이 찬스 결과 수만큼 늘어납니다.
+    Enumerate,
+}
+
+/// 학습 중 발생할 수 있는 에러
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrainingError {
+    /// `LimitPolicy::Abort` 정책에서 노드 수 제한에 도달함
+    NodeLimitReached {
+        limit: usize,
+        iterations_completed: usize,
+    },
+    /// `ChanceHandling::Enumerate`에서 결과를 열거할 수 없는 찬스 노드에 도달함
+    ChanceNotEnumerable { iterations_completed: usize },
+}
+
+impl std::fmt::Display for TrainingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NodeLimitReached {
+                limit,
+                iterations_completed,
+            } => write!(
+                f,
+                "노드 수 제한({})에 도달하여 학습을 중단했습니다 ({} 반복 완료)",
+                limit, iterations_completed
+            ),
+            Self::ChanceNotEnumerable { iterations_completed } => write!(
+                f,
+                "찬스 노드 결과를 열거할 수 없는 상태에 도달하여 학습을 중단했습니다 ({} 반복 완료) - \
+                 이 게임/루트에서는 ChanceHandling::Sample을 사용하세요",
+                iterations_completed
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TrainingError {}
+
+/// 기존 전략으로 새 학습기를 초기화하는 방식 (`Trainer::warm_start_from`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WarmStartMode {
+    /// 원본 평균 전략에 `pseudo_count`를 곱해 전략 합계를 채움 (리그렛은 0에서 시작)
+    ///
+    /// 학습 중 전략 합계에는 반복 번호 × 도달 확률만큼 가중치가 쌓이므로,
+    /// `pseudo_count`는 원본 평균을 그만큼의 학습량으로 믿는다는 뜻입니다.
+    SeedAverageStrategy { pseudo_count: f64 },
+    /// 원본 누적 리그렛을 그대로 복사 (현재 전략을 이어받고 전략 합계는 0에서 시작)
+    SeedRegrets,
+}
+
+/// 학습기 상태 통계
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrainerStats {
+    /// 현재 저장된 노드 수
+    pub node_count: usize,
+    /// 설정된 노드 수 제한 (없으면 None)
+    pub node_limit: Option<usize>,
+    /// 설정된 제한 정책
+    pub limit_policy: Option<LimitPolicy>,
+    /// 학습 중 도달한 최대 노드 수
+    pub peak_node_count: usize,
+    /// 축출된 노드의 누적 개수
+    pub evicted_nodes: u64,
+}
+
 /// CFR 학습기 - 전체 학습 과정을 관리하는 메인 클래스
 ///
 /// 주요 기능:
 /// - 여러 루트 상태에서 CFR 알고리즘 실행
 /// - 병렬 처리를 통한 빠른 학습
 /// - 노드별 전략 저장 및 관리
+/// - 선택적인 노드 수 제한 (`set_node_limit`)
+/// - 선택적인 터미널 평가기 (`with_evaluator`)
+/// - 선택적인 찬스 노드 정확 열거 (`set_chance_handling`)
+///
+/// 노드 수는 기본적으로 제한이 없으며 정보 집합 수에 비례해 계속 증가합니다.
+/// 장시간 무인 학습에서는 `set_node_limit`으로 상한을 두는 것을 권장합니다.
 pub struct Trainer<G: Game> {
     /// 정보 집합별 노드 저장소
     /// 키: 정보 집합 식별자, 값: CFR 노드
     pub nodes: HashMap<G::InfoKey, Node>,
+    /// 노드 수 제한과 정책
+    node_limit: Option<(usize, LimitPolicy)>,
+    /// 방문 스탬프를 발급하는 단조 증가 카운터
+    touch_clock: u64,
+    /// (키, 스탬프) 방문 기록 - 오래된 항목은 축출 시 지연 삭제
+    touch_queue: VecDeque<(G::InfoKey, u64)>,
+    /// 축출된 노드 수
+    evicted_nodes: u64,
+    /// 최대 노드 수
+    peak_nodes: usize,
+    /// Abort 정책에서 제한에 도달했는지 여부
+    limit_reached: bool,
+    /// 찬스 노드 처리 방식
+    chance_handling: ChanceHandling,
+    /// 열거 모드에서 열거할 수 없는 찬스 노드에 도달했는지 여부
+    chance_not_enumerable: bool,
+    /// 현재 재귀 경로의 노드들 (축출 대상에서 제외)
+    active_path: Vec<G::InfoKey>,
+    /// 누적 학습 반복 수 (평균 전략 가중치)
+    iterations_run: u64,
+    /// 평균 전략 누적 가중 방식
+    average_weighting: AverageWeighting,
+    /// 시드 고정 난수 생성기 (None이면 엔트로피로 시드한 스레드 로컬 난수 사용)
+    ///
+    /// `StdRng`와 같은 ChaCha12 알고리즘이지만 스냅샷에 상태를 저장할 수 있습니다.
+    seeded_rng: Option<ChaCha12Rng>,
+    /// 터미널 평가기 (None이면 `Game::util` 사용)
+    evaluator: Option<Box<dyn TerminalEvaluator<G>>>,
+    /// 학습 설정 메타데이터 (예: 플랍 부분집합), 스냅샷과 함께 저장됨
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// 학습 재개에 필요한 학습기 상태
+///
+/// 노드, 누적 반복 수(평균 전략 가중치), 시드 난수 생성기의 현재 위치, 학습 메타데이터를 담습니다.
+/// 노드 수 제한 설정과 터미널 평가기는 포함하지 않습니다.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TrainerSnapshot<K> {
+    pub nodes: Vec<(K, Node)>,
+    pub iterations_run: u64,
+    pub rng: Option<ChaCha12Rng>,
+    pub metadata: BTreeMap<String, String>,
 }
 
 impl<G: Game> Trainer<G> {
@@ -181,7 +519,288 @@ impl<G: Game> Trainer<G> {
     pub fn new() -> Self {
         Self {
             nodes: HashMap::default(),
+            node_limit: None,
+            touch_clock: 0,
+            touch_queue: VecDeque::new(),
+            evicted_nodes: 0,
+            peak_nodes: 0,
+            limit_reached: false,
+            chance_handling: ChanceHandling::default(),
+            chance_not_enumerable: false,
+            active_path: Vec::new(),
+            iterations_run: 0,
+            average_weighting: AverageWeighting::default(),
+            seeded_rng: None,
+            evaluator: None,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// 터미널 평가기를 사용하는 학습기 생성
+    ///
+    /// 평가기가 있으면 터미널 노드의 유틸리티로 `Game::util` 대신 평가기 결과를 사용합니다.
+    pub fn with_evaluator(evaluator: impl TerminalEvaluator<G> + 'static) -> Self {
+        let mut trainer = Self::new();
+        trainer.evaluator = Some(Box::new(evaluator));
+        trainer
+    }
+
+    /// 터미널 노드에서 히어로의 유틸리티 (평가기 우선)
+    fn terminal_util(&self, state: &G::State, hero: usize) -> f64 {
+        match &self.evaluator {
+            Some(evaluator) => evaluator.evaluate(state).get(hero).copied().unwrap_or(0.0),
+            None => G::util(state, hero),
+        }
+    }
+
+    /// 찬스 노드 샘플링 시드 고정
+    ///
+    /// 같은 시드, 같은 루트, 같은 반복 수로 학습하면 같은 전략이 나옵니다.
+    /// 회귀 테스트(골든 파일)처럼 재현성이 필요할 때 사용합니다.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seeded_rng = Some(ChaCha12Rng::seed_from_u64(seed));
+    }
+
+    /// 찬스 노드 처리 방식 설정 (기본값 `ChanceHandling::Sample`)
+    pub fn set_chance_handling(&mut self, handling: ChanceHandling) {
+        self.chance_handling = handling;
+    }
+
+    /// 찬스 노드 처리 방식
+    pub fn chance_handling(&self) -> ChanceHandling {
+        self.chance_handling
+    }
+
+    /// 누적 학습 반복 수
+    pub fn iterations_run(&self) -> u64 {
+        self.iterations_run
+    }
+
+    /// 평균 전략 누적 가중 방식 설정 (기본값 `AverageWeighting::ReachWeighted`)
+    ///
+    /// 이미 누적된 전략 합계는 그대로 두고 이후 반복부터 적용됩니다.
+    pub fn set_average_weighting(&mut self, weighting: AverageWeighting) {
+        self.average_weighting = weighting;
+    }
+
+    /// 평균 전략 누적 가중 방식
+    pub fn average_weighting(&self) -> AverageWeighting {
+        self.average_weighting
+    }
+
+    /// 학습 재개용 스냅샷 생성
+    ///
+    /// 시드 고정 학습이라면 스냅샷에서 복원해 이어서 학습한 결과가
+    /// 중단 없이 학습한 결과와 비트 단위로 같습니다.
+    pub fn snapshot(&self) -> TrainerSnapshot<G::InfoKey> {
+        TrainerSnapshot {
+            nodes: self.nodes.iter().map(|(key, node)| (*key, node.clone())).collect(),
+            iterations_run: self.iterations_run,
+            rng: self.seeded_rng.clone(),
+            metadata: self.metadata.clone(),
+        }
+    }
+
+    /// 학습한 전략의 호환성 지문
+    ///
+    /// 학습을 시작할 때 첫 루트로 계산해 `metadata`에 기록한 지문을 쓰고,
+    /// 아직 학습하지 않았다면 `G::fingerprint(None)`을 반환합니다.
+    pub fn fingerprint(&self) -> CompatibilityFingerprint {
+        CompatibilityFingerprint::from_metadata(&self.metadata).unwrap_or_else(|| G::fingerprint(None))
+    }
+
+    /// 스냅샷에서 학습기 복원
+    pub fn from_snapshot(snapshot: TrainerSnapshot<G::InfoKey>) -> Self {
+        let mut trainer = Self::new();
+        trainer.peak_nodes = snapshot.nodes.len();
+        trainer.nodes = snapshot.nodes.into_iter().collect();
+        trainer.iterations_run = snapshot.iterations_run;
+        trainer.seeded_rng = snapshot.rng;
+        trainer.metadata = snapshot.metadata;
+        trainer
+    }
+
+    /// 다른 학습기의 전략으로 노드를 미리 채움 (웜 스타트)
+    ///
+    /// 추상화를 조금 바꾸거나 스트리트를 추가했을 때 이전 학습 결과를 버리지 않기 위해 사용합니다.
+    /// `key_mapper`는 원본 정보 집합 키를 새 키로 바꾸며 (추상화가 같으면 `Some`), `None`을
+    /// 반환한 키는 건너뜁니다. 여러 원본 키가 같은 새 키로 가면 평균 전략 시드는 더해지고,
+    /// 리그렛은 방문 횟수가 가장 많은 원본 노드의 값을 씁니다. 같은 키의 기존 노드는 덮어쓰며,
+    /// 시드된 노드의 방문 횟수는 0입니다. 채운 노드 수를 반환합니다.
+    ///
+    /// 이론적 주의점:
+    /// - CFR의 수렴 보장은 유한한 초기값에서도 유지되지만, 리그렛 상한에 초기값 크기만큼의
+    ///   상수항이 붙습니다. 원본이 다른 추상화라면 그 리그렛은 새 게임의 반사실적 가치와
+    ///   맞지 않으므로, 잘못된 방향으로 시작한 만큼 되돌리는 데 반복이 더 듭니다.
+    /// - 평균 전략 시드는 `pseudo_count`가 클수록 평균이 원본 전략에 오래 묶입니다. 원본이
+    ///   새 게임의 균형이 아니면 그 편향은 반복 수에 반비례해 천천히 사라집니다.
+    /// - 매핑된 노드의 액션 수가 새 게임과 다르면 학습 중 처음 방문할 때 새 노드로 교체되어
+    ///   처음부터 학습됩니다.
+    pub fn warm_start_from(
+        &mut self,
+        source: &Trainer<G>,
+        key_mapper: impl Fn(G::InfoKey) -> Option<G::InfoKey>,
+        mode: WarmStartMode,
+    ) -> usize {
+        // 새 키 -> (시드 노드, 리그렛을 가져온 원본 노드의 방문 횟수)
+        let mut seeded: HashMap<G::InfoKey, (Node, u64)> = HashMap::default();
+        for (key, source_node) in &source.nodes {
+            let Some(new_key) = key_mapper(*key) else {
+                continue;
+            };
+            let n_actions = source_node.strat_sum.len();
+            let (node, regret_visits) = seeded
+                .entry(new_key)
+                .or_insert_with(|| (Node::new(n_actions, source_node.delta_prefs.clone()), 0));
+            if node.strat_sum.len() != n_actions {
+                continue;
+            }
+
+            match mode {
+                WarmStartMode::SeedAverageStrategy { pseudo_count } => {
+                    for (sum, p) in node.strat_sum.iter_mut().zip(source_node.average()) {
+                        *sum += pseudo_count.max(0.0) * p;
+                    }
+                }
+                WarmStartMode::SeedRegrets => {
+                    if source_node.visits >= *regret_visits {
+                        node.regret_sum = source_node.regret_sum.clone();
+                        *regret_visits = source_node.visits;
+                    }
+                }
+            }
+        }
+
+        let count = seeded.len();
+        for (key, (node, _)) in seeded {
+            self.nodes.insert(key, node);
+            self.touch(key);
+        }
+        self.enforce_limit();
+        self.peak_nodes = self.peak_nodes.max(self.nodes.len());
+        count
+    }
+
+    /// 노드 수 제한 설정
+    ///
+    /// # 매개변수
+    /// - max_nodes: 허용할 최대 노드 수 (최소 1)
+    /// - policy: 제한 도달 시 처리 정책
+    ///
+    /// `LimitPolicy::EvictColdest`는 방문 기록 큐를 사용해 상각 O(1)로
+    /// 가장 오래 방문되지 않은 노드를 찾습니다.
+    pub fn set_node_limit(&mut self, max_nodes: usize, policy: LimitPolicy) {
+        self.node_limit = Some((max_nodes.max(1), policy));
+        self.limit_reached = false;
+        self.touch_queue.clear();
+
+        if policy == LimitPolicy::EvictColdest {
+            // 기존 노드들도 축출 대상이 되도록 방문 기록에 등록
+            let keys: Vec<G::InfoKey> = self.nodes.keys().copied().collect();
+            for key in keys {
+                self.touch(key);
+            }
+            self.enforce_limit();
+            eprintln!("⚠️ 노드 축출이 활성화되었습니다 - CFR 수렴 보장이 약해질 수 있습니다");
+        }
+    }
+
+    /// 노드 수 제한 해제
+    pub fn clear_node_limit(&mut self) {
+        self.node_limit = None;
+        self.limit_reached = false;
+        self.touch_queue.clear();
+    }
+
+    /// 학습기 통계 조회
+    pub fn stats(&self) -> TrainerStats {
+        TrainerStats {
+            node_count: self.nodes.len(),
+            node_limit: self.node_limit.map(|(limit, _)| limit),
+            limit_policy: self.node_limit.map(|(_, policy)| policy),
+            peak_node_count: self.peak_nodes.max(self.nodes.len()),
+            evicted_nodes: self.evicted_nodes,
+        }
+    }
+
+    /// 노드 방문 기록 (EvictColdest 정책에서만 추적)
+    fn touch(&mut self, key: G::InfoKey) {
+        if !matches!(self.node_limit, Some((_, LimitPolicy::EvictColdest))) {
+            return;
+        }
+
+        self.touch_clock += 1;
+        let stamp = self.touch_clock;
+        if let Some(node) = self.nodes.get_mut(&key) {
+            node.last_touch = stamp;
+            self.touch_queue.push_back((key, stamp));
+        }
+
+        // 오래된 방문 기록이 쌓이면 압축 (상각 O(1) 유지)
+        if self.touch_queue.len() > 4 * self.nodes.len() + 64 {
+            let nodes = &self.nodes;
+            self.touch_queue
+                .retain(|(k, s)| nodes.get(k).is_some_and(|n| n.last_touch == *s));
+        }
+    }
+
+    /// 제한을 초과한 노드를 가장 오래된 방문 순서로 축출
+    fn enforce_limit(&mut self) {
+        let Some((limit, LimitPolicy::EvictColdest)) = self.node_limit else {
+            return;
+        };
+
+        // 재귀 경로 위의 노드는 깊이 제한만큼만 건너뛰므로 상수 비용
+        let mut pinned_skips = 0;
+        while self.nodes.len() > limit {
+            let Some((key, stamp)) = self.touch_queue.pop_front() else {
+                break;
+            };
+            // 이후에 다시 방문된 노드의 기록은 건너뜀
+            let is_current = self
+                .nodes
+                .get(&key)
+                .is_some_and(|node| node.last_touch == stamp);
+            if !is_current {
+                continue;
+            }
+
+            // 아직 하위 탐색 중인 노드는 방문 중으로 간주
+            if pinned_skips < self.active_path.len() && self.active_path.contains(&key) {
+                pinned_skips += 1;
+                self.touch(key);
+                continue;
+            }
+
+            self.nodes.remove(&key);
+            self.evicted_nodes += 1;
+        }
+    }
+
+    /// 노드가 없으면 생성 (제한 정책 적용)
+    ///
+    /// Abort 정책에서 제한에 도달하면 노드를 만들지 않고 false를 반환합니다.
+    /// 액션 수가 다른 기존 노드(예: 웜 스타트로 잘못 매핑된 노드)는 새 노드로 교체합니다.
+    fn ensure_node(&mut self, info_key: G::InfoKey, n_actions: usize) -> bool {
+        if self.nodes.get(&info_key).is_some_and(|node| node.strat_sum.len() != n_actions) {
+            self.nodes.remove(&info_key);
         }
+        if !self.nodes.contains_key(&info_key) {
+            if let Some((limit, LimitPolicy::Abort)) = self.node_limit {
+                if self.nodes.len() >= limit {
+                    self.limit_reached = true;
+                    return false;
+                }
+            }
+
+            let delta_prefs = vec![1.0; n_actions];
+            self.nodes.insert(info_key, Node::new(n_actions, delta_prefs));
+        }
+
+        self.touch(info_key);
+        self.enforce_limit();
+        self.peak_nodes = self.peak_nodes.max(self.nodes.len());
+        true
     }
 
     /// CFR 학습 실행
@@ -190,6 +809,9 @@ impl<G: Game> Trainer<G> {
     /// - roots: 학습할 초기 상태들 (다양한 시나리오)
     /// - iterations: 반복 횟수 (많을수록 정확한 전략)
     ///
+    /// 노드 수 제한(Abort)에 도달하면 경고를 출력하고 조기 종료합니다.
+    /// 에러를 직접 처리하려면 `try_run`을 사용하세요.
+    ///
     /// # 예시
     /// ```rust
     /// use nice_hand_core::{Trainer, holdem};
@@ -199,6 +821,25 @@ impl<G: Game> Trainer<G> {
     /// trainer.run(vec![initial_state], 10);
     /// ```
     pub fn run(&mut self, roots: Vec<G::State>, iterations: usize) {
+        if let Err(e) = self.try_run(roots, iterations) {
+            eprintln!("⚠️ {}", e);
+        }
+    }
+
+    /// CFR 학습 실행 (에러 반환 버전)
+    ///
+    /// `LimitPolicy::Abort` 제한에 도달하면 현재 반복을 마치지 않고
+    /// `TrainingError::NodeLimitReached`를 반환합니다. `ChanceHandling::Enumerate`에서
+    /// 열거할 수 없는 찬스 노드에 도달하면 `TrainingError::ChanceNotEnumerable`을 반환합니다.
+    /// 두 경우 모두 그때까지 학습된 노드는 유지됩니다.
+    pub fn try_run(&mut self, roots: Vec<G::State>, iterations: usize) -> Result<(), TrainingError> {
+        if let Some(root) = roots.first() {
+            if !self.metadata.contains_key(FINGERPRINT_METADATA_KEY) {
+                let fingerprint = G::fingerprint(Some(root)).to_metadata_value();
+                self.metadata.insert(FINGERPRINT_METADATA_KEY.to_string(), fingerprint);
+            }
+        }
+
         // 성능을 위해 시작/종료만 로그 - 상세 로깅이 큰 속도 저하를 일으킴
         println!(
             "📚 CFR 학습 시작 - {} 시나리오, {} 반복",
@@ -207,23 +848,60 @@ impl<G: Game> Trainer<G> {
         );
 
         for iteration in 0..iterations {
+            self.iterations_run += 1;
             // 콘솔 오버헤드를 줄이기 위해 10번째마다만 로그
             if iteration % 10 == 0 || iteration == iterations - 1 {
                 println!("  반복 {}/{} 진행 중...", iteration + 1, iterations);
             }
 
-            for (_root_idx, root) in roots.iter().enumerate() {
+            for root in roots.iter() {
                 for hero in 0..G::N_PLAYERS {
-                    TL_DATA.with(|tl| {
-                        let mut tl = tl.borrow_mut();
-                        let _result = self.cfr(root, hero, 1.0, &mut tl.rng);
-                        // 성능을 위해 플레이어별 로깅 제거
-                    });
+                    if let Some(mut rng) = self.seeded_rng.take() {
+                        self.cfr(root, hero, 1.0, 1.0, &mut rng);
+                        self.seeded_rng = Some(rng);
+                    } else {
+                        TL_DATA.with(|tl| {
+                            let mut tl = tl.borrow_mut();
+                            let _result = self.cfr(root, hero, 1.0, 1.0, &mut tl.rng);
+                            // 성능을 위해 플레이어별 로깅 제거
+                        });
+                    }
+
+                    if self.chance_not_enumerable {
+                        self.chance_not_enumerable = false;
+                        return Err(TrainingError::ChanceNotEnumerable {
+                            iterations_completed: iteration,
+                        });
+                    }
+                    if self.limit_reached {
+                        let limit = self.node_limit.map(|(limit, _)| limit).unwrap_or(0);
+                        return Err(TrainingError::NodeLimitReached {
+                            limit,
+                            iterations_completed: iteration,
+                        });
+                    }
                 }
             }
+
+            // 디버그 빌드에서는 10번째 반복마다 모든 노드의 불변 조건 검사
+            #[cfg(debug_assertions)]
+            if iteration % 10 == 9 || iteration == iterations - 1 {
+                self.assert_nodes_valid();
+            }
         }
 
         println!("✅ CFR 학습 완료 - {} 개 노드 생성", self.nodes.len());
+        Ok(())
+    }
+
+    /// 모든 노드의 불변 조건 검사 (위반 시 패닉)
+    #[cfg(debug_assertions)]
+    fn assert_nodes_valid(&self) {
+        for node in self.nodes.values() {
+            if let Err(e) = node.validate() {
+                panic!("CFR 노드 불변 조건 위반 ({} 반복 후): {}", self.iterations_run, e);
+            }
+        }
     }
 
     /// CFR 알고리즘 핵심 재귀 함수
@@ -236,26 +914,32 @@ impl<G: Game> Trainer<G> {
     /// # 매개변수  
     /// - state: 현재 게임 상태
     /// - hero: 관찰자 플레이어 (0~N_PLAYERS-1)
-    /// - prob: 현재 상태에 도달할 확률
+    /// - hero_prob: 히어로 자신의 전략만으로 현재 상태에 도달할 확률
+    /// - opp_prob: 다른 플레이어들의 전략으로 현재 상태에 도달할 확률 (반사실적 도달 확률)
     /// - rng: 랜덤 생성기
     ///
+    /// 리그렛은 `opp_prob`, 전략 합계는 `hero_prob`으로 가중합니다
+    /// (`AverageWeighting::Unweighted`이면 전략 합계는 도달 확률 없이 누적).
+    /// 두 확률을 곱한 값으로 가중하면 평균 전략이 균형으로 수렴하지 않습니다.
+    ///
     /// # 반환값
     /// 히어로의 기댓값 (expected value)
-    fn cfr(&mut self, state: &G::State, hero: usize, prob: f64, rng: &mut ThreadRng) -> f64 {
-        self.cfr_with_depth(state, hero, prob, rng, 0)
+    fn cfr<R: Rng>(&mut self, state: &G::State, hero: usize, hero_prob: f64, opp_prob: f64, rng: &mut R) -> f64 {
+        self.cfr_with_depth(state, hero, hero_prob, opp_prob, rng, 0)
     }
 
     /// CFR 알고리즘 (깊이 추적 버전)
-    fn cfr_with_depth(
+    fn cfr_with_depth<R: Rng>(
         &mut self,
         state: &G::State,
         hero: usize,
-        prob: f64,
-        rng: &mut ThreadRng,
+        hero_prob: f64,
+        opp_prob: f64,
+        rng: &mut R,
         depth: usize,
     ) -> f64 {
         // 매우 보수적인 깊이 제한으로 무한 재귀 방지
-        if depth > 15 {
+        if depth > 15 || self.limit_reached || self.chance_not_enumerable {
             return 0.0;
         }
 
@@ -263,15 +947,13 @@ impl<G: Game> Trainer<G> {
             // 플레이어 노드: 전략 계산 및 리그렛 업데이트
             let actions = G::legal_actions(state);
             if actions.is_empty() {
-                G::util(state, hero)
+                self.terminal_util(state, hero)
             } else {
                 let info_key = G::info_key(state, player);
 
                 // 노드가 없으면 생성 (균일 선호도로 초기화)
-                if !self.nodes.contains_key(&info_key) {
-                    let delta_prefs = vec![1.0; actions.len()];
-                    self.nodes
-                        .insert(info_key, Node::new(actions.len(), delta_prefs));
+                if !self.ensure_node(info_key, actions.len()) {
+                    return 0.0;
                 }
 
                 let strategy = {
@@ -283,34 +965,79 @@ impl<G: Game> Trainer<G> {
                 let mut node_util = 0.0;
 
                 // 각 액션에 대해 재귀적으로 CFR 실행
+                self.active_path.push(info_key);
                 for (i, &action) in actions.iter().enumerate() {
                     let next_state = G::next_state(state, action);
-                    utilities[i] =
-                        self.cfr_with_depth(&next_state, hero, prob * strategy[i], rng, depth + 1);
+                    let (next_hero_prob, next_opp_prob) = if player == hero {
+                        (hero_prob * strategy[i], opp_prob)
+                    } else {
+                        (hero_prob, opp_prob * strategy[i])
+                    };
+                    utilities[i] = self.cfr_with_depth(
+                        &next_state,
+                        hero,
+                        next_hero_prob,
+                        next_opp_prob,
+                        rng,
+                        depth + 1,
+                    );
                     node_util += strategy[i] * utilities[i];
                 }
+                self.active_path.pop();
 
                 // 히어로 플레이어면 리그렛과 전략 합계 업데이트 (CFR+ 버전)
-                if player == hero {
-                    let node = self.nodes.get_mut(&info_key).unwrap();
+                if player == hero && !self.limit_reached && !self.chance_not_enumerable {
+                    // 하위 탐색 중 축출되었을 수 있으므로 다시 확보
+                    let n_actions = actions.len();
+                    let node = self
+                        .nodes
+                        .entry(info_key)
+                        .or_insert_with(|| Node::new(n_actions, vec![1.0; n_actions]));
+                    // CFR+: 평균 전략은 반복 횟수로 선형 가중 (후반 전략일수록 균형에 가까움)
+                    let weight = match self.average_weighting {
+                        AverageWeighting::ReachWeighted => self.iterations_run.max(1) as f64 * hero_prob,
+                        AverageWeighting::Unweighted => self.iterations_run.max(1) as f64,
+                    };
                     for i in 0..actions.len() {
                         let regret = utilities[i] - node_util;
                         // CFR+: 누적 후회값이 음수가 되지 않도록 max(0.0) 적용
-                        node.regret_sum[i] = (node.regret_sum[i] + prob * regret).max(0.0);
-                        node.strat_sum[i] += prob * strategy[i];
+                        node.regret_sum[i] = (node.regret_sum[i] + opp_prob * regret).max(0.0);
+                        node.strat_sum[i] += weight * strategy[i];
                     }
+                    node.visits += 1;
                 }
 
+                // 하위 탐색이 끝난 뒤 다시 방문 기록 (상위 노드일수록 늦게 축출됨)
+                self.touch(info_key);
+                self.enforce_limit();
+
                 node_util
             }
         } else {
             // 터미널 또는 찬스 노드
             if state.is_terminal() {
-                G::util(state, hero)
+                self.terminal_util(state, hero)
             } else {
-                // 찬스 노드: 랜덤 이벤트 적용 후 재귀
-                let chance_state = G::apply_chance(state, rng);
-                self.cfr_with_depth(&chance_state, hero, prob, rng, depth + 1)
+                match self.chance_handling {
+                    ChanceHandling::Sample => {
+                        // 찬스 노드: 랜덤 이벤트 적용 후 재귀
+                        let chance_state = G::apply_chance(state, rng);
+                        self.cfr_with_depth(&chance_state, hero, hero_prob, opp_prob, rng, depth + 1)
+                    }
+                    ChanceHandling::Enumerate => {
+                        // 찬스 확률은 반사실적 도달 확률에 포함하고, 결과별 가치를 확률로 가중
+                        let Some(outcomes) = G::chance_outcomes(state) else {
+                            self.chance_not_enumerable = true;
+                            return 0.0;
+                        };
+                        let mut value = 0.0;
+                        for (outcome, probability) in &outcomes {
+                            value += probability
+                                * self.cfr_with_depth(outcome, hero, hero_prob, opp_prob * probability, rng, depth + 1);
+                        }
+                        value
+                    }
+                }
             }
         };
 
@@ -318,6 +1045,47 @@ impl<G: Game> Trainer<G> {
     }
 }
 
+impl<G: Game<InfoKey = u64>> Trainer<G> {
+    /// 호환성 지문을 검사하고 스냅샷에서 학습기 복원
+    ///
+    /// 스냅샷 메타데이터의 지문을 `expected`와 비교해, 같으면 그대로 복원하고 버전 구성 요소만
+    /// 다르면 `registry`의 마이그레이션으로 키를 바꿔 복원합니다 (메타데이터의 지문도 `expected`로 갱신).
+    /// 지문이 없는 이전 스냅샷은 `expected`의 기준 버전으로 저장된 것으로 봅니다.
+    pub fn from_snapshot_verified(
+        mut snapshot: TrainerSnapshot<u64>,
+        expected: &CompatibilityFingerprint,
+        registry: &MigrationRegistry,
+    ) -> Result<Self, IncompatibleArtifact> {
+        let stored = CompatibilityFingerprint::from_metadata(&snapshot.metadata)
+            .unwrap_or_else(|| expected.clone().with_baseline_versions());
+        let compatibility = registry.check(&stored, expected)?;
+        if compatibility.is_migrated() {
+            for (key, _) in &mut snapshot.nodes {
+                *key = compatibility.migrate_key(*key);
+            }
+        }
+        snapshot
+            .metadata
+            .insert(FINGERPRINT_METADATA_KEY.to_string(), expected.to_metadata_value());
+        Ok(Self::from_snapshot(snapshot))
+    }
+}
+
+impl<G: Game> Trainer<G>
+where
+    G::InfoKey: Ord,
+{
+    /// 정보 집합 키 오름차순으로 정렬된 노드 목록
+    ///
+    /// `nodes`는 `HashMap`이라 순회 순서가 실행마다 달라지므로,
+    /// 로그 출력이나 결과 비교처럼 순서가 중요한 곳에서는 이 함수를 사용합니다.
+    pub fn nodes_sorted_by_key(&self) -> Vec<(&G::InfoKey, &Node)> {
+        let mut nodes: Vec<_> = self.nodes.iter().collect();
+        nodes.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        nodes
+    }
+}
+
 /// 게임 상태 확장 트레잇 - 터미널/찬스 노드 판별
 ///
 /// 각 게임은 이 트레잇을 구현하여 상태 유형을 정의해야 합니다.
@@ -328,3 +1096,343 @@ pub trait GameState {
     /// 찬스 노드인지 확인 (카드를 딜해야 하는 상황)
     fn is_chance_node(&self) -> bool;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::holdem;
+
+    fn node_with(strat: [f64; 2], visits: u64) -> Node {
+        let mut node = Node::new(2, vec![1.0; 2]);
+        node.update_strategy(0, strat[0]);
+        node.update_strategy(1, strat[1]);
+        node.visits = visits;
+        node
+    }
+
+    #[test]
+    fn test_merge_weighted_average() {
+        // 무거운 노드: 항상 액션 0, 가벼운 노드: 항상 액션 1
+        let mut heavy = node_with([50.0, 0.0], 9);
+        let light = node_with([0.0, 7.0], 1);
+
+        heavy.merge_weighted(&light, MergeMode::WeightedAverage);
+        let avg = heavy.average();
+        assert!((avg[0] - 0.9).abs() < 1e-9, "9:1 가중 평균이어야 함: {:?}", avg);
+        assert_eq!(heavy.visits(), 10);
+    }
+
+    #[test]
+    fn test_merge_replace_and_accumulate() {
+        let mut node = node_with([1.0, 3.0], 4);
+        node.merge_weighted(&node_with([2.0, 0.0], 2), MergeMode::Replace);
+        assert_eq!(node.average(), vec![1.0, 0.0]);
+        assert_eq!(node.visits(), 2);
+
+        node.merge(&node_with([0.0, 2.0], 2));
+        assert_eq!(node.average(), vec![0.5, 0.5]);
+        assert_eq!(node.visits(), 4);
+    }
+
+    #[test]
+    fn test_untouched_node_average_is_uniform() {
+        let node = Node::new(4, vec![1.0; 4]);
+        assert_eq!(node.average(), vec![0.25; 4]);
+        assert_eq!(node.validate(), Ok(()));
+
+        let mut broken = node_with([1.0, f64::NAN], 0);
+        assert_eq!(broken.average(), vec![0.5, 0.5]);
+        assert_eq!(broken.validate(), Err(NodeInvariantError::NonFinite));
+
+        broken = Node::new(3, vec![1.0; 2]);
+        assert!(matches!(broken.validate(), Err(NodeInvariantError::LengthMismatch { .. })));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_trained_nodes_pass_validation() {
+        use crate::game::leduc::LeducState;
+
+        let mut trainer = Trainer::<LeducState>::new();
+        trainer.set_seed(7);
+        trainer.run(vec![LeducState::new()], 30);
+        assert!(!trainer.nodes.is_empty());
+        for node in trainer.nodes.values() {
+            assert_eq!(node.validate(), Ok(()));
+            // 이전 이름은 기준 메서드와 비트 단위로 같은 값을 반환
+            assert_eq!(node.avg_strategy(), node.average());
+        }
+    }
+
+    #[test]
+    fn test_seeded_training_is_reproducible() {
+        use crate::game::leduc::LeducState;
+
+        let train = || {
+            let mut trainer = Trainer::<LeducState>::new();
+            trainer.set_seed(42);
+            trainer.run(vec![LeducState::new()], 50);
+            let mut strategies: Vec<(u64, Vec<f64>)> =
+                trainer.nodes.iter().map(|(k, n)| (*k, n.average())).collect();
+            strategies.sort_by_key(|(k, _)| *k);
+            strategies
+        };
+        assert_eq!(train(), train());
+    }
+
+    #[test]
+    fn test_snapshot_fingerprint_is_recorded_and_verified() {
+        use crate::game::leduc::LeducState;
+        use crate::solver::compat::{FingerprintComponent, INFO_KEY_SCHEMA_VERSION};
+
+        let mut trainer = Trainer::<LeducState>::new();
+        trainer.set_seed(3);
+        trainer.run(vec![LeducState::new()], 5);
+        let current = LeducState::fingerprint(None);
+        assert_eq!(trainer.fingerprint(), current);
+
+        let registry = MigrationRegistry::builtin();
+        let restored = Trainer::<LeducState>::from_snapshot_verified(trainer.snapshot(), &current, &registry).unwrap();
+        assert_eq!(restored.nodes.len(), trainer.nodes.len());
+
+        let other_ladder = CompatibilityFingerprint {
+            action_abstraction: vec!["Check".to_string()],
+            ..current.clone()
+        };
+        let error = Trainer::<LeducState>::from_snapshot_verified(trainer.snapshot(), &other_ladder, &registry)
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("action_abstraction"), "{}", error);
+
+        // 키 스키마만 올라간 빌드: 등록된 마이그레이션으로 키를 바꿔 복원
+        let newer = CompatibilityFingerprint {
+            info_key_schema: INFO_KEY_SCHEMA_VERSION + 1,
+            ..current.clone()
+        };
+        let mut registry = MigrationRegistry::new();
+        registry.register(
+            FingerprintComponent::InfoKeySchema,
+            INFO_KEY_SCHEMA_VERSION,
+            INFO_KEY_SCHEMA_VERSION + 1,
+            |key| key | 1 << 63,
+        );
+        let migrated = Trainer::<LeducState>::from_snapshot_verified(trainer.snapshot(), &newer, &registry).unwrap();
+        assert_eq!(migrated.fingerprint(), newer);
+        for key in trainer.nodes.keys() {
+            assert!(migrated.nodes.contains_key(&(key | 1 << 63)));
+        }
+    }
+
+    #[test]
+    fn test_reach_weighted_average_convergence_rate() {
+        use crate::game::leduc::{exploitability, LeducState};
+
+        // 누적 250, 1000, 4000, 16000 반복에서의 익스플로이터빌리티
+        let curve = |weighting: AverageWeighting| {
+            let mut trainer = Trainer::<LeducState>::new();
+            trainer.set_seed(11);
+            trainer.set_average_weighting(weighting);
+            [250, 750, 3000, 12000]
+                .into_iter()
+                .map(|iterations| {
+                    trainer.run(vec![LeducState::new()], iterations);
+                    exploitability(&trainer)
+                })
+                .collect::<Vec<f64>>()
+        };
+        let weighted = curve(AverageWeighting::ReachWeighted);
+        let unweighted = curve(AverageWeighting::Unweighted);
+
+        // 반복 수가 4배가 될 때마다 대략 1/sqrt(T) 속도(절반)로 감소 (찬스 샘플링 노이즈 여유)
+        for pair in weighted.windows(2) {
+            assert!(pair[1] < pair[0] * 0.7, "{:?}", weighted);
+        }
+        // CFR+는 현재 전략도 균형에 가까워지므로 Leduc에서는 가중하지 않은 평균도 크게 뒤처지지
+        // 않습니다 (관측된 차이는 10% 이내). 도달 확률 가중이 눈에 띄게 나쁘지 않은지만 확인
+        assert!(unweighted.iter().all(|e| e.is_finite()));
+        assert!(weighted[3] < unweighted[3] * 1.05, "{:?} vs {:?}", weighted, unweighted);
+    }
+
+    /// 익스플로이터빌리티가 `target` 아래로 내려갈 때까지 걸린 반복 수 (10회 단위)
+    fn iterations_to_reach(trainer: &mut Trainer<crate::game::leduc::LeducState>, target: f64) -> u64 {
+        use crate::game::leduc::{exploitability, LeducState};
+
+        let start = trainer.iterations_run;
+        while exploitability(trainer) > target {
+            assert!(trainer.iterations_run - start < 5000, "목표 익스플로이터빌리티에 도달하지 못함");
+            trainer.run(vec![LeducState::new()], 10);
+        }
+        trainer.iterations_run - start
+    }
+
+    #[test]
+    fn test_enumerated_chance_converges_faster() {
+        use crate::game::leduc::{average_strategy_value, LeducState, LEDUC_GAME_VALUE};
+
+        // 찬스를 열거하면 수십 반복, 샘플링하면 천 반복 안팎이 걸림 (관측값)
+        let target = 0.25;
+        let mut exact = Trainer::<LeducState>::new();
+        exact.set_chance_handling(ChanceHandling::Enumerate);
+        let exact_iterations = iterations_to_reach(&mut exact, target);
+
+        let mut sampled = Trainer::<LeducState>::new();
+        sampled.set_seed(9);
+        let sampled_iterations = iterations_to_reach(&mut sampled, target);
+
+        assert!(
+            exact_iterations * 10 <= sampled_iterations,
+            "열거 {} 반복 vs 샘플링 {} 반복",
+            exact_iterations,
+            sampled_iterations
+        );
+        let value = average_strategy_value(&exact);
+        assert!((value - LEDUC_GAME_VALUE).abs() < 0.03, "게임 가치 {:.4}", value);
+    }
+
+    #[test]
+    fn test_warm_start_reaches_target_faster() {
+        use crate::game::leduc::{exploitability, LeducState};
+
+        let mut source = Trainer::<LeducState>::new();
+        source.set_seed(3);
+        source.run(vec![LeducState::new()], 300);
+
+        let target = 0.3;
+        let mut cold = Trainer::<LeducState>::new();
+        cold.set_seed(5);
+        let cold_iterations = iterations_to_reach(&mut cold, target);
+
+        let mut warm = Trainer::<LeducState>::new();
+        warm.set_seed(5);
+        assert_eq!(warm.warm_start_from(&source, Some, WarmStartMode::SeedRegrets), source.nodes.len());
+        assert!(warm.nodes.values().all(|node| node.visits == 0));
+        let warm_iterations = iterations_to_reach(&mut warm, target);
+        assert!(
+            warm_iterations < cold_iterations,
+            "웜 스타트 {}회, 콜드 스타트 {}회",
+            warm_iterations,
+            cold_iterations
+        );
+
+        // 평균 전략 시드는 학습 전부터 원본 평균 전략을 그대로 가짐
+        let mut seeded = Trainer::<LeducState>::new();
+        seeded.warm_start_from(&source, Some, WarmStartMode::SeedAverageStrategy { pseudo_count: 10.0 });
+        assert!((exploitability(&seeded) - exploitability(&source)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_warm_start_skips_unmappable_keys() {
+        use crate::game::leduc::LeducState;
+
+        let mut source = Trainer::<LeducState>::new();
+        source.set_seed(9);
+        source.run(vec![LeducState::new()], 50);
+
+        // 액션 수가 다른 두 정보 집합을 골라 한쪽을 다른 쪽 키로 보내고, 나머지 절반은 버림
+        let action_count = |key: &u64| source.nodes[key].strat_sum.len();
+        let two = *source.nodes.keys().find(|key| action_count(key) == 2).unwrap();
+        let three = *source.nodes.keys().find(|key| action_count(key) == 3).unwrap();
+        let mapper = |key: u64| match key {
+            _ if key == two => Some(three),
+            _ if key == three || key.is_multiple_of(2) => None,
+            _ => Some(key),
+        };
+
+        let mut warm = Trainer::<LeducState>::new();
+        warm.set_seed(9);
+        let seeded = warm.warm_start_from(&source, mapper, WarmStartMode::SeedRegrets);
+        assert!(seeded > 0 && seeded < source.nodes.len());
+        assert_eq!(warm.nodes[&three].strat_sum.len(), 2);
+
+        // 잘못된 액션 수의 노드와 매핑되지 않은 키는 에러 없이 새로 학습됨
+        warm.run(vec![LeducState::new()], 50);
+        assert_eq!(warm.nodes[&three].strat_sum.len(), 3);
+        for node in warm.nodes.values() {
+            assert_eq!(node.validate(), Ok(()));
+        }
+        assert!(warm.nodes.len() > seeded);
+    }
+
+    /// 모든 유틸리티를 `scale`배 하는 평가기
+    struct ScaledUtil(f64);
+
+    impl<G: Game> TerminalEvaluator<G> for ScaledUtil {
+        fn evaluate(&self, state: &G::State) -> Vec<f64> {
+            (0..G::N_PLAYERS).map(|player| self.0 * G::util(state, player)).collect()
+        }
+    }
+
+    #[test]
+    fn test_terminal_evaluator_takes_precedence_over_util() {
+        use crate::game::leduc::LeducState;
+
+        let train = |trainer: &mut Trainer<LeducState>| {
+            trainer.set_seed(42);
+            trainer.run(vec![LeducState::new()], 50);
+            let mut nodes: Vec<(u64, Node)> = trainer.nodes.iter().map(|(k, n)| (*k, n.clone())).collect();
+            nodes.sort_by_key(|(k, _)| *k);
+            nodes
+        };
+        let default = train(&mut Trainer::new());
+        let identity = train(&mut Trainer::with_evaluator(ScaledUtil(1.0)));
+        let doubled = train(&mut Trainer::with_evaluator(ScaledUtil(2.0)));
+
+        // 평가기가 util을 그대로 돌려주면 기본 경로와 완전히 같음
+        assert_eq!(default.len(), identity.len());
+        for ((key, a), (other_key, b)) in default.iter().zip(&identity) {
+            assert_eq!(key, other_key);
+            assert_eq!(a.regret_sum, b.regret_sum);
+            assert_eq!(a.strat_sum, b.strat_sum);
+        }
+
+        // 유틸리티를 두 배로 하면 리그렛도 정확히 두 배, 평균 전략은 그대로
+        assert_eq!(default.len(), doubled.len());
+        assert!(default.iter().any(|(_, n)| n.regret_sum.iter().any(|&r| r > 0.0)));
+        for ((_, a), (_, b)) in default.iter().zip(&doubled) {
+            let scaled: Vec<f64> = a.regret_sum.iter().map(|r| 2.0 * r).collect();
+            assert_eq!(scaled, b.regret_sum);
+            assert_eq!(a.average(), b.average());
+        }
+    }
+
+    #[test]
+    fn test_node_limit_abort() {
+        let mut trainer = Trainer::<holdem::State>::new();
+        trainer.set_node_limit(10, LimitPolicy::Abort);
+
+        let result = trainer.try_run(vec![holdem::State::new()], 5);
+
+        assert_eq!(
+            result,
+            Err(TrainingError::NodeLimitReached {
+                limit: 10,
+                iterations_completed: 0,
+            })
+        );
+        assert!(trainer.nodes.len() <= 10);
+
+        let stats = trainer.stats();
+        assert_eq!(stats.node_limit, Some(10));
+        assert_eq!(stats.limit_policy, Some(LimitPolicy::Abort));
+        assert_eq!(stats.evicted_nodes, 0);
+    }
+
+    #[test]
+    fn test_node_limit_evict_coldest() {
+        let root = holdem::State::new();
+        let root_player = holdem::State::current_player(&root).unwrap();
+        let root_key = holdem::State::info_key(&root, root_player);
+
+        let mut trainer = Trainer::<holdem::State>::new();
+        trainer.set_node_limit(20, LimitPolicy::EvictColdest);
+        trainer.try_run(vec![root], 3).unwrap();
+
+        let stats = trainer.stats();
+        assert!(stats.peak_node_count <= 20, "peak {} > cap", stats.peak_node_count);
+        assert!(stats.node_count <= 20);
+        assert!(stats.evicted_nodes > 0);
+
+        // 매 반복 갱신되는 프리플랍 루트 노드는 살아남아야 함
+        assert!(trainer.nodes.contains_key(&root_key));
+    }
+}
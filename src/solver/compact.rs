@@ -0,0 +1,278 @@
+// 압축 전략 테이블
+// 학습이 끝난 뒤 정보 집합별 평균 전략을 f32로 양자화하고, 같은 전략 벡터는
+// 아레나에 한 번만 저장해 정보 집합 키에서 인덱스로 참조하는 읽기 전용 서빙 구조입니다.
+// 슈트 정규화 이후에도 대칭 상황의 정보 집합들은 같은 전략을 갖는 경우가 많아
+// 원본 노드 맵보다 훨씬 작아집니다.
+
+use crate::solver::cfr_core::{Game, Trainer};
+use fxhash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
+/// 기본 양자화 간격 (확률을 이 간격의 격자로 반올림, 오차는 간격의 절반 이하)
+pub const DEFAULT_COMPACT_TOLERANCE: f32 = 1e-4;
+
+/// 중복 제거된 정보 집합별 전략 테이블 (읽기 전용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactStrategyTable<K: Eq + Hash> {
+    /// 정보 집합 키 -> 전략 벡터 인덱스
+    index: HashMap<K, u32>,
+    /// 전략 벡터별 (아레나 시작 위치, 길이)
+    spans: Vec<(u32, u32)>,
+    /// 모든 고유 전략 벡터를 이어 붙인 저장소
+    arena: Vec<f32>,
+    /// 양자화 간격
+    tolerance: f32,
+}
+
+/// 압축 결과 보고
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompactionReport {
+    /// 정보 집합 수
+    pub keys: usize,
+    /// 고유 전략 벡터 수
+    pub unique_vectors: usize,
+    /// 중복 제거 비율 (정보 집합 수 / 고유 벡터 수, 1.0이면 중복 없음)
+    pub dedup_ratio: f64,
+    /// 키 -> `Vec<f64>` 맵으로 저장할 때의 대략적인 크기 (바이트)
+    pub raw_bytes: usize,
+    /// 압축 테이블의 대략적인 크기 (바이트)
+    pub compact_bytes: usize,
+}
+
+impl<K: Eq + Hash + Copy> CompactStrategyTable<K> {
+    /// 정보 집합별 전략들로 압축 테이블 생성
+    ///
+    /// 각 확률을 `tolerance` 간격 격자로 반올림한 값이 모두 같은 벡터는 하나로 저장합니다.
+    /// `tolerance`가 0 이하이거나 유한하지 않으면 `DEFAULT_COMPACT_TOLERANCE`를 씁니다.
+    pub fn from_strategies<'a>(strategies: impl IntoIterator<Item = (K, &'a [f64])>, tolerance: f32) -> Self {
+        let mut builder = CompactTableBuilder::new(tolerance);
+        for (key, strategy) in strategies {
+            builder.insert(key, strategy);
+        }
+        builder.finish()
+    }
+
+    /// 정보 집합의 전략 (양자화된 f32 확률)
+    pub fn lookup(&self, key: &K) -> Option<&[f32]> {
+        let (start, len) = self.spans[*self.index.get(key)? as usize];
+        Some(&self.arena[start as usize..(start + len) as usize])
+    }
+
+    /// 정보 집합 수
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// 고유 전략 벡터 수
+    pub fn unique_vectors(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// 양자화 간격
+    pub fn tolerance(&self) -> f32 {
+        self.tolerance
+    }
+
+    /// 모든 (키, 전략) 쌍 (순서 없음)
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &[f32])> {
+        self.index.keys().filter_map(move |key| Some((key, self.lookup(key)?)))
+    }
+
+    /// 키만 바꾼 테이블 (저장된 키 마이그레이션용, 여러 키가 겹치면 하나만 남음)
+    pub(crate) fn map_keys(self, mut f: impl FnMut(K) -> K) -> Self {
+        Self {
+            index: self.index.into_iter().map(|(key, vector)| (f(key), vector)).collect(),
+            ..self
+        }
+    }
+
+    /// 중복 제거 비율과 메모리 사용량 보고
+    pub fn report(&self) -> CompactionReport {
+        let key_size = std::mem::size_of::<K>();
+        let vector_lengths = self.index.values().map(|&vector| self.spans[vector as usize].1 as usize);
+        let raw_bytes = vector_lengths
+            .map(|len| key_size + std::mem::size_of::<Vec<f64>>() + len * std::mem::size_of::<f64>())
+            .sum();
+        let compact_bytes = self.index.len() * (key_size + std::mem::size_of::<u32>())
+            + self.spans.len() * std::mem::size_of::<(u32, u32)>()
+            + self.arena.len() * std::mem::size_of::<f32>();
+        CompactionReport {
+            keys: self.len(),
+            unique_vectors: self.unique_vectors(),
+            dedup_ratio: self.len() as f64 / self.unique_vectors().max(1) as f64,
+            raw_bytes,
+            compact_bytes,
+        }
+    }
+}
+
+/// 전략을 하나씩 넣어 압축 테이블을 만드는 빌더 (모든 전략을 미리 모아 둘 필요 없음)
+pub(crate) struct CompactTableBuilder<K: Eq + Hash> {
+    table: CompactStrategyTable<K>,
+    /// 양자화된 전략 벡터 -> 전략 벡터 인덱스
+    unique: HashMap<Vec<i64>, u32>,
+}
+
+impl<K: Eq + Hash + Copy> CompactTableBuilder<K> {
+    /// `tolerance`가 0 이하이거나 유한하지 않으면 `DEFAULT_COMPACT_TOLERANCE`를 씁니다.
+    pub(crate) fn new(tolerance: f32) -> Self {
+        let tolerance = if tolerance.is_finite() && tolerance > 0.0 {
+            tolerance
+        } else {
+            DEFAULT_COMPACT_TOLERANCE
+        };
+        Self {
+            table: CompactStrategyTable {
+                index: HashMap::default(),
+                spans: Vec::new(),
+                arena: Vec::new(),
+                tolerance,
+            },
+            unique: HashMap::default(),
+        }
+    }
+
+    /// 정보 집합 전략 추가 (같은 키를 다시 넣으면 마지막 전략이 남음)
+    pub(crate) fn insert(&mut self, key: K, strategy: &[f64]) {
+        let table = &mut self.table;
+        let tolerance = table.tolerance as f64;
+        let quantized: Vec<i64> = strategy.iter().map(|&p| (p / tolerance).round() as i64).collect();
+        let vector = *self.unique.entry(quantized).or_insert_with_key(|quantized| {
+            table.spans.push((table.arena.len() as u32, quantized.len() as u32));
+            table.arena.extend(quantized.iter().map(|&q| (q as f64 * tolerance) as f32));
+            (table.spans.len() - 1) as u32
+        });
+        table.index.insert(key, vector);
+    }
+
+    pub(crate) fn finish(self) -> CompactStrategyTable<K> {
+        self.table
+    }
+}
+
+impl<G: Game> Trainer<G> {
+    /// 평균 전략을 중복 제거한 서빙용 압축 테이블 생성 (`DEFAULT_COMPACT_TOLERANCE` 간격)
+    pub fn compact(&self) -> CompactStrategyTable<G::InfoKey> {
+        self.compact_with_tolerance(DEFAULT_COMPACT_TOLERANCE)
+    }
+
+    /// 양자화 간격을 지정한 `compact`
+    pub fn compact_with_tolerance(&self, tolerance: f32) -> CompactStrategyTable<G::InfoKey> {
+        let averages: Vec<(G::InfoKey, Vec<f64>)> =
+            self.nodes.iter().map(|(key, node)| (*key, node.average())).collect();
+        CompactStrategyTable::from_strategies(
+            averages.iter().map(|(key, average)| (*key, average.as_slice())),
+            tolerance,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::leduc::LeducState;
+    use crate::solver::cfr_core::GameState;
+    use rand::Rng;
+
+    /// 보상과 무관한 라벨을 받은 0번 플레이어가 액션 셋 중 하나를 고르는 대칭 게임
+    #[derive(Clone, Copy, Debug)]
+    struct Labeled {
+        label: u16,
+        done: Option<u8>,
+    }
+
+    impl GameState for Labeled {
+        fn is_terminal(&self) -> bool {
+            self.done.is_some()
+        }
+
+        fn is_chance_node(&self) -> bool {
+            false
+        }
+    }
+
+    impl Game for Labeled {
+        type State = Labeled;
+        type Action = u8;
+        type InfoKey = u16;
+
+        const N_PLAYERS: usize = 2;
+
+        fn current_player(s: &Self::State) -> Option<usize> {
+            s.done.is_none().then_some(0)
+        }
+
+        fn legal_actions(s: &Self::State) -> Vec<u8> {
+            if s.is_terminal() { vec![] } else { vec![0, 1, 2] }
+        }
+
+        fn next_state(s: &Self::State, a: u8) -> Self::State {
+            Labeled { done: Some(a), ..*s }
+        }
+
+        fn apply_chance<R: Rng>(s: &Self::State, _r: &mut R) -> Self::State {
+            *s
+        }
+
+        fn util(s: &Self::State, hero: usize) -> f64 {
+            let value = match s.done {
+                Some(1) => 1.0,
+                Some(2) => 0.5,
+                _ => 0.0,
+            };
+            if hero == 0 { value } else { -value }
+        }
+
+        fn info_key(s: &Self::State, _v: usize) -> u16 {
+            s.label
+        }
+    }
+
+    #[test]
+    fn test_compaction_preserves_strategies() {
+        let mut trainer = Trainer::<LeducState>::new();
+        trainer.set_seed(5);
+        trainer.run(vec![LeducState::new()], 200);
+
+        let table = trainer.compact();
+        assert_eq!(table.len(), trainer.nodes.len());
+        for (key, node) in &trainer.nodes {
+            let compact = table.lookup(key).unwrap();
+            let average = node.average();
+            assert_eq!(compact.len(), average.len());
+            for (c, a) in compact.iter().zip(&average) {
+                assert!((*c as f64 - a).abs() <= DEFAULT_COMPACT_TOLERANCE as f64, "{:?} vs {:?}", compact, average);
+            }
+        }
+        assert!(table.lookup(&u64::MAX).is_none());
+
+        let report = table.report();
+        assert!(report.dedup_ratio >= 1.0);
+        assert!(report.compact_bytes < report.raw_bytes, "{:?}", report);
+    }
+
+    #[test]
+    fn test_symmetric_keys_share_vectors() {
+        let roots: Vec<Labeled> = (0..256).map(|label| Labeled { label, done: None }).collect();
+        let mut trainer = Trainer::<Labeled>::new();
+        trainer.run(roots, 50);
+
+        let table = trainer.compact();
+        let report = table.report();
+        assert_eq!(report.keys, 256);
+        assert_eq!(report.unique_vectors, 1);
+        assert_eq!(report.dedup_ratio, 256.0);
+        assert!(report.compact_bytes * 5 < report.raw_bytes, "{:?}", report);
+
+        // 직렬화 후에도 같은 조회 결과
+        let bytes = bincode::serialize(&table).unwrap();
+        let decoded: CompactStrategyTable<u16> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded.lookup(&7), table.lookup(&7));
+    }
+}
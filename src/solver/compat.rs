@@ -0,0 +1,321 @@
+// 저장 산출물 호환성 지문
+// 추상화, 정보 집합 키 배치, 베팅 규칙, 액션 추상화, 카드 정규화 중 하나라도 바뀌면 예전에
+// 저장한 전략은 오류 없이 엉뚱한 키를 조회하게 됩니다. 저장할 때 이 구성 요소들을 지문으로
+// 함께 기록하고 로드할 때 현재 빌드의 지문과 비교해 세 가지로 처리합니다.
+//
+// - 완전 일치: 그대로 로드
+// - 마이그레이션 가능: 버전 구성 요소(키 스키마, 정규화)만 다르고 등록된 변환 경로가 있으면 키를 변환해 로드
+// - 비호환: 다른 구성 요소 이름을 담은 `IncompatibleArtifact` 에러
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// 정보 집합 키 배치 버전 (키에 들어가는 필드나 비트 위치가 바뀌면 올림)
+pub const INFO_KEY_SCHEMA_VERSION: u32 = 1;
+
+/// 카드 정규화(슈트 동형 처리) 버전
+pub const CANONICALIZATION_VERSION: u32 = 1;
+
+/// 지문이 도입되기 전에 저장된 산출물의 키 스키마/정규화 버전
+pub const BASELINE_VERSION: u32 = 1;
+
+/// `Trainer::metadata`에 지문(JSON)을 기록하는 키
+pub const FINGERPRINT_METADATA_KEY: &str = "fingerprint";
+
+/// 저장된 전략을 현재 빌드에서 그대로 쓸 수 있는지 판단하는 구성 요소 묶음
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CompatibilityFingerprint {
+    /// 정보 집합 키 배치 버전
+    pub info_key_schema: u32,
+    /// 카드 추상화 식별자와 매개변수 (예: 버킷 수)
+    pub abstraction: String,
+    /// 베팅 규칙 방식 (노리밋/팟리밋/픽스드리밋)
+    pub betting_rules: String,
+    /// 액션 추상화 단계 (액션 순서 그대로)
+    pub action_abstraction: Vec<String>,
+    /// 카드 정규화 버전
+    pub canonicalization: u32,
+}
+
+impl CompatibilityFingerprint {
+    /// 현재 빌드의 키 스키마/정규화 버전으로 지문 생성
+    pub fn new(abstraction: impl Into<String>, betting_rules: impl Into<String>, action_abstraction: Vec<String>) -> Self {
+        Self {
+            info_key_schema: INFO_KEY_SCHEMA_VERSION,
+            abstraction: abstraction.into(),
+            betting_rules: betting_rules.into(),
+            action_abstraction,
+            canonicalization: CANONICALIZATION_VERSION,
+        }
+    }
+
+    /// 지문 없이 저장된 이전 형식 산출물의 지문 (버전 구성 요소만 `BASELINE_VERSION`으로 바꿈)
+    pub fn with_baseline_versions(mut self) -> Self {
+        self.info_key_schema = BASELINE_VERSION;
+        self.canonicalization = BASELINE_VERSION;
+        self
+    }
+
+    /// 학습기 메타데이터에 기록된 지문 (없거나 해석할 수 없으면 None)
+    pub fn from_metadata(metadata: &BTreeMap<String, String>) -> Option<Self> {
+        serde_json::from_str(metadata.get(FINGERPRINT_METADATA_KEY)?).ok()
+    }
+
+    /// 학습기 메타데이터에 기록할 JSON
+    pub fn to_metadata_value(&self) -> String {
+        serde_json::to_string(self).expect("지문은 항상 JSON으로 직렬화됨")
+    }
+
+    /// `other`와 값이 다른 구성 요소들 (선언 순서)
+    pub fn mismatches(&self, other: &Self) -> Vec<FingerprintComponent> {
+        FingerprintComponent::ALL
+            .into_iter()
+            .filter(|component| match component {
+                FingerprintComponent::InfoKeySchema => self.info_key_schema != other.info_key_schema,
+                FingerprintComponent::Abstraction => self.abstraction != other.abstraction,
+                FingerprintComponent::BettingRules => self.betting_rules != other.betting_rules,
+                FingerprintComponent::ActionAbstraction => self.action_abstraction != other.action_abstraction,
+                FingerprintComponent::Canonicalization => self.canonicalization != other.canonicalization,
+            })
+            .collect()
+    }
+
+    /// 버전 구성 요소의 값
+    fn version(&self, component: FingerprintComponent) -> Option<u32> {
+        match component {
+            FingerprintComponent::InfoKeySchema => Some(self.info_key_schema),
+            FingerprintComponent::Canonicalization => Some(self.canonicalization),
+            _ => None,
+        }
+    }
+}
+
+/// 지문 구성 요소
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum FingerprintComponent {
+    InfoKeySchema,
+    Abstraction,
+    BettingRules,
+    ActionAbstraction,
+    Canonicalization,
+}
+
+impl FingerprintComponent {
+    /// 모든 구성 요소 (선언 순서)
+    pub const ALL: [Self; 5] = [
+        Self::InfoKeySchema,
+        Self::Abstraction,
+        Self::BettingRules,
+        Self::ActionAbstraction,
+        Self::Canonicalization,
+    ];
+
+    /// 에러 메시지와 로그에 쓰는 이름
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::InfoKeySchema => "info_key_schema",
+            Self::Abstraction => "abstraction",
+            Self::BettingRules => "betting_rules",
+            Self::ActionAbstraction => "action_abstraction",
+            Self::Canonicalization => "canonicalization",
+        }
+    }
+
+    /// 키 변환으로 맞출 수 있는 버전 구성 요소인지 여부
+    pub fn is_versioned(self) -> bool {
+        matches!(self, Self::InfoKeySchema | Self::Canonicalization)
+    }
+}
+
+impl fmt::Display for FingerprintComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 현재 빌드와 호환되지 않는 산출물
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncompatibleArtifact {
+    /// 현재 빌드의 지문
+    pub expected: Box<CompatibilityFingerprint>,
+    /// 산출물에 기록된 지문
+    pub found: Box<CompatibilityFingerprint>,
+    /// 맞출 수 없는 구성 요소들 (마이그레이션 경로가 없는 버전 구성 요소 포함)
+    pub mismatched: Vec<FingerprintComponent>,
+}
+
+impl fmt::Display for IncompatibleArtifact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self.mismatched.iter().map(|component| component.name()).collect();
+        write!(f, "현재 빌드와 호환되지 않는 산출물입니다 (다른 구성 요소: {})", names.join(", "))
+    }
+}
+
+impl std::error::Error for IncompatibleArtifact {}
+
+/// 정보 집합 키 하나를 이전 버전에서 다음 버전으로 바꾸는 함수
+pub type KeyMigration = fn(u64) -> u64;
+
+/// 등록된 마이그레이션 한 단계
+#[derive(Debug, Clone, Copy)]
+struct MigrationStep {
+    component: FingerprintComponent,
+    from: u32,
+    to: u32,
+    migrate: KeyMigration,
+}
+
+/// 버전 구성 요소별 키 마이그레이션 목록
+#[derive(Debug, Clone, Default)]
+pub struct MigrationRegistry {
+    steps: Vec<MigrationStep>,
+}
+
+impl MigrationRegistry {
+    /// 빈 목록
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 이 빌드에 포함된 마이그레이션 (키 스키마/정규화 버전을 올릴 때 여기에 등록)
+    pub fn builtin() -> Self {
+        Self::new()
+    }
+
+    /// `component`의 `from` 버전 키를 `to` 버전 키로 바꾸는 마이그레이션 등록
+    ///
+    /// 버전 구성 요소(`FingerprintComponent::is_versioned`)가 아니면 무시합니다.
+    pub fn register(&mut self, component: FingerprintComponent, from: u32, to: u32, migrate: KeyMigration) -> &mut Self {
+        if component.is_versioned() && from != to {
+            self.steps.push(MigrationStep {
+                component,
+                from,
+                to,
+                migrate,
+            });
+        }
+        self
+    }
+
+    /// `from`에서 `to`까지 등록된 단계를 이어 붙인 경로 (먼저 등록된 단계 우선)
+    fn path(&self, component: FingerprintComponent, from: u32, to: u32) -> Option<Vec<KeyMigration>> {
+        let mut path = Vec::new();
+        let mut version = from;
+        while version != to {
+            let step = self.steps.iter().find(|step| {
+                step.component == component
+                    && step.from == version
+                    && if from < to {
+                        step.to > version && step.to <= to
+                    } else {
+                        step.to < version && step.to >= to
+                    }
+            })?;
+            path.push(step.migrate);
+            version = step.to;
+        }
+        Some(path)
+    }
+
+    /// 저장된 지문을 현재 지문과 비교
+    ///
+    /// 버전이 아닌 구성 요소는 정확히 같아야 하고, 버전 구성 요소가 다르면 등록된 경로가
+    /// 있어야 합니다. 맞출 수 없는 구성 요소를 모두 모아 에러로 반환합니다.
+    pub fn check(
+        &self,
+        stored: &CompatibilityFingerprint,
+        current: &CompatibilityFingerprint,
+    ) -> Result<Compatibility, IncompatibleArtifact> {
+        let mut steps = Vec::new();
+        let mut mismatched = Vec::new();
+        for component in stored.mismatches(current) {
+            let path = match (stored.version(component), current.version(component)) {
+                (Some(from), Some(to)) => self.path(component, from, to),
+                _ => None,
+            };
+            match path {
+                Some(path) => steps.extend(path),
+                None => mismatched.push(component),
+            }
+        }
+
+        if !mismatched.is_empty() {
+            return Err(IncompatibleArtifact {
+                expected: Box::new(current.clone()),
+                found: Box::new(stored.clone()),
+                mismatched,
+            });
+        }
+        if steps.is_empty() {
+            Ok(Compatibility::Exact)
+        } else {
+            Ok(Compatibility::Migrated(steps))
+        }
+    }
+}
+
+/// 호환성 검사 결과
+#[derive(Debug, Clone)]
+pub enum Compatibility {
+    /// 지문이 같아 그대로 로드
+    Exact,
+    /// 등록된 마이그레이션을 순서대로 적용해 로드
+    Migrated(Vec<KeyMigration>),
+}
+
+impl Compatibility {
+    /// 저장된 키를 현재 빌드의 키로 변환 (완전 일치면 그대로)
+    pub fn migrate_key(&self, key: u64) -> u64 {
+        match self {
+            Self::Exact => key,
+            Self::Migrated(steps) => steps.iter().fold(key, |key, migrate| migrate(key)),
+        }
+    }
+
+    /// 마이그레이션이 필요한지 여부
+    pub fn is_migrated(&self) -> bool {
+        matches!(self, Self::Migrated(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fingerprint() -> CompatibilityFingerprint {
+        CompatibilityFingerprint::new("buckets(50)", "NoLimit", vec!["Fold".into(), "Call".into(), "Raise(0)".into()])
+    }
+
+    #[test]
+    fn test_mismatches_are_named_and_versions_need_a_registered_path() {
+        let current = fingerprint();
+        let registry = MigrationRegistry::builtin();
+        assert!(matches!(registry.check(&current, &current), Ok(Compatibility::Exact)));
+
+        let mut other = fingerprint();
+        other.action_abstraction.pop();
+        other.info_key_schema += 1;
+        let error = registry.check(&other, &current).unwrap_err();
+        assert_eq!(
+            error.mismatched,
+            vec![FingerprintComponent::InfoKeySchema, FingerprintComponent::ActionAbstraction]
+        );
+        assert!(error.to_string().contains("info_key_schema, action_abstraction"), "{}", error);
+
+        // 두 단계 경로를 이어 붙임
+        let mut registry = MigrationRegistry::new();
+        registry
+            .register(FingerprintComponent::Canonicalization, 1, 2, |key| key + 1)
+            .register(FingerprintComponent::Canonicalization, 2, 3, |key| key * 10)
+            .register(FingerprintComponent::ActionAbstraction, 1, 2, |key| key);
+        let mut stored = fingerprint();
+        stored.canonicalization = 1;
+        let mut current = fingerprint();
+        current.canonicalization = 3;
+        let plan = registry.check(&stored, &current).unwrap();
+        assert!(plan.is_migrated());
+        assert_eq!(plan.migrate_key(4), 50);
+        assert!(registry.check(&current, &stored).is_err());
+    }
+}
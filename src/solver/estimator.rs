@@ -0,0 +1,419 @@
+// 게임 트리 크기 추정 모듈
+// 학습 전에 무작위 플레이아웃으로 정보 집합 수, 노드 메모리, 반복 속도를 예측
+//
+// - 트리 크기: 플레이아웃 경로의 분기 수 곱으로 전체 상태 수를 추정 (Knuth 추정량)
+// - 정보 집합 수: 플레이아웃에서 같은 키를 다시 만난 빈도로 보지 못한 키까지 추정 (Chao1 포획-재포획)
+// - 속도: 노드 수를 제한한 짧은 CFR 실행으로 상태당 비용을 재고 반복당 상태 수를 곱함
+
+use crate::game::holdem::{BettingRules, State};
+use crate::game::rng::entropy_seed;
+use crate::solver::cfr_core::{Game, GameState, LimitPolicy, Node, Trainer, TrainingError};
+use fxhash::FxHashMap as HashMap;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use std::fmt;
+use std::time::Instant;
+
+/// 속도 측정용 CFR 실행에서 만들 최대 노드 수
+pub const PROBE_NODE_LIMIT: usize = 2000;
+
+/// 속도 측정용 CFR 실행의 최대 반복 수 (노드 제한에 먼저 걸리면 거기서 멈춤)
+pub const PROBE_ITERATIONS: usize = 3;
+
+/// 학습기의 재귀 깊이 제한 (`Trainer`는 이보다 깊은 상태를 0으로 평가하고 펼치지 않음)
+const TRAINER_MAX_DEPTH: usize = 15;
+
+/// 정보 집합 추상화 - 플레이어가 구분하지 못하는 상태들을 같은 키로 묶음
+pub trait Abstraction {
+    /// `player` 관점의 정보 집합 키
+    fn info_key(&self, state: &State, player: usize) -> u64;
+
+    /// 보고서에 표시할 이름 (기본은 경로를 뺀 타입 이름)
+    fn name(&self) -> String {
+        let full = std::any::type_name::<Self>();
+        full.rsplit("::").next().unwrap_or(full).to_string()
+    }
+}
+
+/// 학습기와 같은 키를 쓰는 기본 추상화 (`Game::info_key`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HoldemAbstraction;
+
+impl Abstraction for HoldemAbstraction {
+    fn info_key(&self, state: &State, player: usize) -> u64 {
+        <State as Game>::info_key(state, player)
+    }
+}
+
+/// 학습 전 게임 트리 크기 추정 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct TreeEstimate {
+    /// 수행한 플레이아웃 수
+    pub samples: usize,
+    /// 플레이아웃당 평균 결정 노드 수
+    pub decision_nodes_per_playout: f64,
+    /// 결정 노드의 평균 합법 액션 수
+    pub mean_branching: f64,
+    /// 찬스 노드의 평균 딜링 결과 수 (찬스 노드를 만나지 않았으면 0)
+    pub mean_chance_branching: f64,
+    /// 플레이아웃 평균 깊이 (찬스 노드도 한 단계)
+    pub mean_depth: f64,
+    /// 최대 깊이
+    pub max_depth: usize,
+    /// 학습 반복 하나(찬스 샘플링)에서 펼쳐지는 결정 상태 수 추정
+    pub states_per_iteration: f64,
+    /// 모든 딜링 결과를 펼친 전체 결정 상태 수 추정
+    pub total_decision_states: f64,
+    /// 플레이아웃에서 실제로 본 고유 정보 집합 수
+    pub observed_info_sets: usize,
+    /// 보지 못한 키까지 포함한 고유 정보 집합 수 추정
+    pub estimated_info_sets: f64,
+    /// 정보 집합 하나를 학습 노드로 저장하는 데 드는 바이트 (해시맵 오버헤드 포함)
+    pub bytes_per_node: f64,
+    /// 전체 노드 메모리 추정 (바이트)
+    pub estimated_memory_bytes: f64,
+    /// 초당 학습 반복 수 추정 (측정용 실행에서 업데이트가 없었으면 None)
+    pub iterations_per_second: Option<f64>,
+}
+
+impl TreeEstimate {
+    /// `iterations`번 학습에 걸릴 예상 시간 (초)
+    pub fn estimated_training_seconds(&self, iterations: usize) -> Option<f64> {
+        self.iterations_per_second
+            .filter(|&rate| rate > 0.0)
+            .map(|rate| iterations as f64 / rate)
+    }
+
+    /// 사람이 읽을 수 있는 여러 줄 요약
+    pub fn summary(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for TreeEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "게임 트리 추정 (플레이아웃 {}회)", self.samples)?;
+        writeln!(
+            f,
+            "  정보 집합: 약 {:.0}개 (관측 {}개)",
+            self.estimated_info_sets, self.observed_info_sets
+        )?;
+        writeln!(
+            f,
+            "  결정 상태: 반복당 약 {:.3e}개, 전체 약 {:.3e}개",
+            self.states_per_iteration, self.total_decision_states
+        )?;
+        writeln!(
+            f,
+            "  깊이: 평균 {:.1} / 최대 {}, 평균 액션 {:.2}개, 평균 찬스 분기 {:.0}개",
+            self.mean_depth, self.max_depth, self.mean_branching, self.mean_chance_branching
+        )?;
+        writeln!(f, "  예상 메모리: {}", format_bytes(self.estimated_memory_bytes))?;
+        match self.iterations_per_second {
+            Some(rate) => write!(f, "  예상 속도: 초당 {:.2} 반복", rate),
+            None => write!(f, "  예상 속도: 측정 불가"),
+        }
+    }
+}
+
+/// 바이트 수를 KB/MB/GB 단위 문자열로
+fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// 학습 전에 루트에서의 게임 트리 크기와 학습 비용 추정
+///
+/// # 매개변수
+/// - root: 학습을 시작할 상태 (`rules`로 베팅 규칙을 바꿔서 사용)
+/// - abstraction: 정보 집합 키 추상화
+/// - rules: 베팅 한도 규칙
+/// - samples: 무작위 플레이아웃 수 (많을수록 정확하지만 느림, 최소 1)
+pub fn estimate(root: &State, abstraction: &dyn Abstraction, rules: &BettingRules, samples: usize) -> TreeEstimate {
+    estimate_with_seed(root, abstraction, rules, samples, entropy_seed())
+}
+
+/// 시드를 지정한 `estimate` (같은 시드면 같은 플레이아웃, 속도 측정값만 다름)
+pub fn estimate_with_seed(
+    root: &State,
+    abstraction: &dyn Abstraction,
+    rules: &BettingRules,
+    samples: usize,
+    seed: u64,
+) -> TreeEstimate {
+    let mut root = root.clone();
+    root.betting = *rules;
+    let samples = samples.max(1);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut sightings: HashMap<u64, u32> = HashMap::default();
+    let mut stats = PlayoutStats::default();
+    for _ in 0..samples {
+        playout(&root, abstraction, &mut rng, &mut sightings, &mut stats);
+    }
+
+    let observed = sightings.len();
+    let estimated_info_sets = chao1(&sightings);
+    let mean_branching = stats.actions as f64 / stats.decisions.max(1) as f64;
+    let bytes_per_node = bytes_per_node(mean_branching);
+    let states_per_iteration = stats.sampled_states / samples as f64;
+    let n = samples as f64;
+
+    TreeEstimate {
+        samples,
+        decision_nodes_per_playout: stats.decisions as f64 / n,
+        mean_branching,
+        mean_chance_branching: stats.chance_outcomes as f64 / stats.chance_nodes.max(1) as f64,
+        mean_depth: stats.depth_sum as f64 / n,
+        max_depth: stats.max_depth,
+        states_per_iteration,
+        total_decision_states: stats.full_states / n,
+        observed_info_sets: observed,
+        estimated_info_sets,
+        bytes_per_node,
+        estimated_memory_bytes: estimated_info_sets * bytes_per_node,
+        iterations_per_second: probe_iterations_per_second(&root, states_per_iteration, seed),
+    }
+}
+
+/// 플레이아웃 누적 통계
+#[derive(Default)]
+struct PlayoutStats {
+    decisions: u64,
+    actions: u64,
+    chance_nodes: u64,
+    chance_outcomes: u64,
+    depth_sum: u64,
+    max_depth: usize,
+    /// Knuth 추정량 합계 (찬스 노드는 샘플 하나만 펼침)
+    sampled_states: f64,
+    /// Knuth 추정량 합계 (찬스 노드의 모든 결과를 펼침)
+    full_states: f64,
+}
+
+/// 루트에서 무작위 액션/딜링으로 한 핸드를 끝까지 진행하며 통계 기록
+///
+/// 경로의 각 결정 노드에서 그 위 분기 수의 곱을 더하면 해당 깊이의 노드 수에 대한
+/// 불편 추정량이 됩니다 (Knuth). 학습기와 같은 깊이에서 멈춥니다.
+fn playout(
+    root: &State,
+    abstraction: &dyn Abstraction,
+    rng: &mut StdRng,
+    sightings: &mut HashMap<u64, u32>,
+    stats: &mut PlayoutStats,
+) {
+    let mut state = root.clone();
+    let mut sampled_weight = 1.0;
+    let mut full_weight = 1.0;
+    let mut depth = 0;
+
+    while depth <= TRAINER_MAX_DEPTH {
+        if let Some(player) = State::current_player(&state) {
+            let actions = State::legal_actions(&state);
+            if actions.is_empty() {
+                break;
+            }
+            *sightings.entry(abstraction.info_key(&state, player)).or_insert(0) += 1;
+            stats.decisions += 1;
+            stats.actions += actions.len() as u64;
+            stats.sampled_states += sampled_weight;
+            stats.full_states += full_weight;
+            sampled_weight *= actions.len() as f64;
+            full_weight *= actions.len() as f64;
+            state = State::next_state(&state, actions[rng.gen_range(0..actions.len())]);
+        } else if state.is_chance_node() {
+            let outcomes = chance_outcomes(&state);
+            stats.chance_nodes += 1;
+            stats.chance_outcomes += outcomes;
+            full_weight *= outcomes.max(1) as f64;
+            state = State::apply_chance(&state, rng);
+        } else {
+            // 터미널 (또는 액션할 수 있는 플레이어가 없는 상태)
+            break;
+        }
+        depth += 1;
+    }
+
+    stats.depth_sum += depth as u64;
+    stats.max_depth = stats.max_depth.max(depth);
+}
+
+/// 찬스 노드에서 가능한 딜링 결과 수 (플랍은 3장 조합, 턴/리버는 남은 카드 수)
+fn chance_outcomes(state: &State) -> u64 {
+    let dealt = state.hole.iter().flatten().chain(&state.board).copied().collect::<Vec<u8>>();
+    let live = state
+        .variant
+        .deck()
+        .into_iter()
+        .filter(|card| !dealt.contains(card))
+        .count() as u64;
+    match state.street {
+        0 => live * live.saturating_sub(1) * live.saturating_sub(2) / 6,
+        1 | 2 => live,
+        _ => 1,
+    }
+}
+
+/// Chao1 포획-재포획 추정량: 관측 수 + 한 번만 본 키가 많을수록 보지 못한 키가 많다고 봄
+///
+/// 한 번 본 키 f1, 두 번 본 키 f2에 대해 `관측 수 + f1(f1-1) / 2(f2+1)` (편향 보정형).
+fn chao1(sightings: &HashMap<u64, u32>) -> f64 {
+    let singletons = sightings.values().filter(|&&count| count == 1).count() as f64;
+    let doubletons = sightings.values().filter(|&&count| count == 2).count() as f64;
+    sightings.len() as f64 + singletons * (singletons - 1.0).max(0.0) / (2.0 * (doubletons + 1.0))
+}
+
+/// 학습 노드 하나의 메모리 (키 + 노드 + 액션별 벡터 3개, 해시맵 적재율 7/8과 제어 바이트 포함)
+fn bytes_per_node(mean_branching: f64) -> f64 {
+    let entry = (std::mem::size_of::<u64>() + std::mem::size_of::<Node>()) as f64;
+    let vectors = 3.0 * mean_branching * std::mem::size_of::<f64>() as f64;
+    (entry + 1.0) * 8.0 / 7.0 + vectors
+}
+
+/// 노드 수를 제한한 짧은 CFR 실행으로 초당 반복 수 측정
+///
+/// 반복을 하나라도 마쳤으면 마친 반복 수를 걸린 시간으로 나눕니다. 첫 반복 중에 노드 제한에
+/// 걸렸으면 그때까지 방문한 상태는 거의 모두 새 노드를 만들었으므로, 노드 하나당 시간에
+/// 반복 하나의 상태 방문 수(히어로 수 × 반복당 상태 수)를 곱해 반복 시간을 추정합니다.
+fn probe_iterations_per_second(root: &State, states_per_iteration: f64, seed: u64) -> Option<f64> {
+    let mut trainer = Trainer::<State>::new();
+    trainer.set_seed(seed);
+    trainer.set_node_limit(PROBE_NODE_LIMIT, LimitPolicy::Abort);
+
+    let start = Instant::now();
+    let completed = match trainer.try_run(vec![root.clone()], PROBE_ITERATIONS) {
+        Ok(()) => PROBE_ITERATIONS,
+        // 노드 제한 도달은 측정을 끝내는 정상 경로
+        Err(TrainingError::NodeLimitReached { iterations_completed, .. }) => iterations_completed,
+        // 찬스 샘플링 학습이라 발생하지 않음
+        Err(TrainingError::ChanceNotEnumerable { .. }) => return None,
+    };
+    let elapsed = start.elapsed().as_secs_f64();
+    if elapsed <= 0.0 {
+        return None;
+    }
+
+    if completed > 0 {
+        return Some(completed as f64 / elapsed);
+    }
+    let nodes = trainer.nodes.len();
+    if nodes == 0 {
+        return None;
+    }
+    let seconds_per_state = elapsed / nodes as f64;
+    Some(1.0 / (seconds_per_state * State::N_PLAYERS as f64 * states_per_iteration.max(1.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::holdem::Act;
+    use crate::game::tournament::AnteType;
+    use std::collections::HashSet;
+
+    /// 턴까지 체크로 진행한 헤즈업 픽스드리밋 상태 (남은 찬스는 리버 한 장)
+    fn heads_up_turn_root() -> State {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut state = State::new_hand_with_rng([1, 2], 0, AnteType::PerPlayer, [40; 6], 2, &mut rng);
+        state.betting = BettingRules::FixedLimit {
+            small_bet: 2,
+            big_bet: 4,
+            max_raises: 2,
+        };
+        while state.street < 2 {
+            state = if state.is_chance_node() {
+                State::apply_chance(&state, &mut rng)
+            } else {
+                State::next_state(&state, Act::Call)
+            };
+        }
+        assert_eq!(state.board.len(), 4);
+        state
+    }
+
+    /// 모든 액션과 모든 리버 카드를 펼쳐 (결정 상태 수, 고유 정보 집합) 계산
+    fn exhaustive(state: &State, keys: &mut HashSet<u64>, rng: &mut StdRng) -> usize {
+        if let Some(player) = State::current_player(state) {
+            let actions = State::legal_actions(state);
+            if actions.is_empty() {
+                return 0;
+            }
+            keys.insert(HoldemAbstraction.info_key(state, player));
+            1 + actions
+                .iter()
+                .map(|&action| exhaustive(&State::next_state(state, action), keys, rng))
+                .sum::<usize>()
+        } else if state.is_terminal() {
+            0
+        } else {
+            // 리버 딜링: 샘플 하나를 만든 뒤 마지막 카드를 남은 카드 전부로 바꿔 가며 펼침
+            let dealt = State::apply_chance(state, rng);
+            let live: Vec<u8> = (0..52)
+                .filter(|card| !state.board.contains(card) && !state.hole.iter().flatten().any(|c| c == card))
+                .collect();
+            live.iter()
+                .map(|&card| {
+                    let mut river = dealt.clone();
+                    *river.board.last_mut().unwrap() = card;
+                    exhaustive(&river, keys, rng)
+                })
+                .sum()
+        }
+    }
+
+    #[test]
+    fn test_estimate_within_factor_two_of_exhaustive_count() {
+        let root = heads_up_turn_root();
+        let mut keys = HashSet::new();
+        let states = exhaustive(&root, &mut keys, &mut StdRng::seed_from_u64(0));
+
+        let estimate = estimate_with_seed(&root, &HoldemAbstraction, &root.betting, 4000, 17);
+        let info_sets = keys.len() as f64;
+        assert!(
+            estimate.estimated_info_sets > info_sets / 2.0 && estimate.estimated_info_sets < info_sets * 2.0,
+            "정보 집합 {} vs 추정 {}",
+            info_sets,
+            estimate.estimated_info_sets
+        );
+        assert!(estimate.observed_info_sets <= keys.len());
+        assert!(
+            estimate.total_decision_states > states as f64 / 2.0 && estimate.total_decision_states < states as f64 * 2.0,
+            "결정 상태 {} vs 추정 {}",
+            states,
+            estimate.total_decision_states
+        );
+        // 보드 4장, 홀카드 4장, 빈 좌석의 홀카드 자리(카드 0)를 뺀 리버 카드 수
+        assert_eq!(estimate.mean_chance_branching, 43.0);
+        assert!(estimate.estimated_memory_bytes > estimate.estimated_info_sets * 100.0);
+        assert!(estimate.summary().contains("정보 집합"));
+    }
+
+    #[test]
+    fn test_six_max_estimate_runs_in_bounded_time() {
+        let root = State::new_hand_with_rng(
+            [50, 100],
+            0,
+            AnteType::PerPlayer,
+            [10_000; 6],
+            6,
+            &mut StdRng::seed_from_u64(4),
+        );
+        let start = Instant::now();
+        let estimate = estimate_with_seed(&root, &HoldemAbstraction, &BettingRules::NoLimit, 300, 5);
+        assert!(start.elapsed().as_secs() < 60, "추정이 너무 오래 걸림: {:?}", start.elapsed());
+
+        assert!(estimate.estimated_info_sets >= estimate.observed_info_sets as f64);
+        assert!(estimate.total_decision_states >= estimate.states_per_iteration);
+        assert!(estimate.max_depth <= TRAINER_MAX_DEPTH + 1);
+        let rate = estimate.iterations_per_second.expect("측정용 실행에서 업데이트가 있어야 함");
+        assert!(rate.is_finite() && rate > 0.0);
+        assert!(estimate.estimated_training_seconds(100).unwrap() > 0.0);
+    }
+}
@@ -1,46 +1,321 @@
 // EV(Expected Value) 계산 모듈
 // 특정 게임 상황에서 각 액션의 기댓값을 계산하여 최적 의사결정을 지원
 
-use crate::game::card_abstraction::hand_strength;
-use crate::game::holdem::{Act, State};
-use crate::solver::cfr_core::{Game, GameState};
+use crate::game::all_in::{AllInAdjudicator, AllInConfig};
+use crate::game::card_abstraction::{hand_percentile_with, hand_strength, PercentileMode};
+use crate::game::holdem::{Act, SidePotEvaluator, State};
+use crate::game::observation::ObservedState;
+use crate::game::poker_math::{geometric_bet_fraction, streets_remaining};
+use crate::game::value_unit::{ValueContext, ValueConversionError, ValueUnit};
+use crate::solver::budget::{BudgetExhausted, ComputeBudget};
+use crate::solver::cfr_core::{Game, GameState, TerminalEvaluator};
+use crate::game::rng::entropy_rng;
+use fxhash::FxHashMap;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::Serialize;
+use std::time::Duration;
 
 /// 액션별 EV 계산 결과
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ActionEV {
     pub action: Act,
     pub ev: f64,
     pub confidence: f64, // 계산의 신뢰도 (샘플 수 기반)
+    pub std_error: f64,  // ev 몬테카를로 추정의 표준오차 (ev와 같은 단위, 즉시 끝나는 액션은 0)
+    pub unit: ValueUnit, // ev 값의 단위
 }
 
+/// 베팅 크기 곡선의 한 점
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SizingPoint {
+    /// 이번 스트리트 누적 레이즈 목표 금액 (칩)
+    pub raise_to: u32,
+    pub ev: f64,
+    /// ev 몬테카를로 추정의 표준오차 (ev와 같은 단위)
+    pub std_error: f64,
+}
+
+/// 최소 레이즈부터 최대 레이즈까지의 EV-베팅 크기 곡선
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SizingCurve {
+    /// 크기 오름차순 점들
+    pub points: Vec<SizingPoint>,
+    /// EV가 가장 높은 점 (동률이면 작은 크기)
+    pub best: SizingPoint,
+    /// 최고 EV의 95% 신뢰구간 (하한, 상한)
+    pub best_interval: (f64, f64),
+    pub unit: ValueUnit,
+}
+
+/// 95% 신뢰구간의 정규분포 분위수
+const SIZING_CONFIDENCE_Z: f64 = 1.96;
+
+/// 시뮬레이션 중 핸드 퍼센타일 계산 방식
+///
+/// 터미널마다 상대 조합 전체를 열거하면 시뮬레이션이 너무 느려지므로 고정 시드로 샘플링합니다.
+const SIMULATION_PERCENTILE: PercentileMode = PercentileMode::Sampled { samples: 200, seed: 0 };
+
+/// 상대 홀카드를 다시 딜링하는 상태(`State::opponent_deal`)의 쇼다운에서 열거/샘플링할 보드 수
+///
+/// 리버와 턴 쇼다운은 정확히 열거하고, 그보다 이른 쇼다운은 이만큼만 샘플링합니다.
+const RESAMPLED_SHOWDOWN_BOARDS: usize = 48;
+
+/// 예산을 검사하고 샘플을 받는 시뮬레이션 배치 크기
+const EV_BATCH_SAMPLES: usize = 32;
+
 /// EV 계산 설정
 #[derive(Debug, Clone)]
 pub struct EVConfig {
-    pub sample_count: usize,      // 시뮬레이션 샘플 수
-    pub max_depth: u8,            // 최대 탐색 깊이
-    pub use_opponent_model: bool, // 상대방 모델 사용 여부
+    pub sample_count: usize,       // 시뮬레이션 샘플 수
+    pub max_depth: u8,             // 최대 탐색 깊이
+    pub use_opponent_model: bool,  // 상대방 모델 사용 여부
+    pub debug_trace: bool,         // 시뮬레이션 디버그 추적 여부
+    pub debug_sample_limit: usize, // 액션별로 보관할 시뮬레이션 시퀀스 수
+    pub value_unit: ValueUnit,     // 결과 EV 단위 (시뮬레이션은 항상 칩으로 계산 후 변환)
+    pub value_context: ValueContext, // 단위 변환에 필요한 빅블라인드/ICM 정보
+    pub all_in: AllInConfig,       // 홀카드를 아는 쇼다운의 보드 열거/샘플링 설정
+    pub seed: Option<u64>,         // 시뮬레이션 난수 시드 (None이면 계산마다 새 시드)
+    pub subtree_cache: Option<u32>, // 부분 트리 캐시 버킷이 평균을 돌려주기 전 모을 샘플 수 (None이면 캐시 끔)
+    pub time_budget: Option<Duration>, // 계산 한 번의 시간 예산 (액션마다 균등 분배, None이면 sample_count까지)
 }
 
+/// 부분 트리 캐시 버킷이 평균을 돌려주기 전 모으는 기본 샘플 수
+pub const DEFAULT_SUBTREE_CACHE_SAMPLES: u32 = 256;
+
 impl Default for EVConfig {
     fn default() -> Self {
         Self {
             sample_count: 10000,
             max_depth: 10,
             use_opponent_model: true,
+            debug_trace: false,
+            debug_sample_limit: 5,
+            value_unit: ValueUnit::Chips,
+            value_context: ValueContext::default(),
+            all_in: AllInConfig::default(),
+            seed: None,
+            subtree_cache: None,
+            time_budget: None,
+        }
+    }
+}
+
+/// 액션 종류별 횟수
+#[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ActionCounts {
+    pub fold: u64,
+    pub call: u64, // 체크 포함
+    pub raise: u64,
+}
+
+impl ActionCounts {
+    fn record(&mut self, action: &Act) {
+        match action {
+            Act::Fold => self.fold += 1,
+            Act::Call => self.call += 1,
+            Act::Raise(_) => self.raise += 1,
+        }
+    }
+
+    fn merge(&mut self, other: &ActionCounts) {
+        self.fold += other.fold;
+        self.call += other.call;
+        self.raise += other.raise;
+    }
+
+    /// 전체 액션 수
+    pub fn total(&self) -> u64 {
+        self.fold + self.call + self.raise
+    }
+
+    /// 폴드 비율 (기록이 없으면 0)
+    pub fn fold_frequency(&self) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => self.fold as f64 / total as f64,
+        }
+    }
+}
+
+/// 스트리트별 상대방 액션 횟수 (체크를 받은 경우 / 베팅을 받은 경우)
+#[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct StreetActionCounts {
+    pub facing_check: ActionCounts,
+    pub facing_bet: ActionCounts,
+}
+
+/// 시뮬레이션 중 수행된 한 액션
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TracedAction {
+    pub player: usize,
+    pub street: u8,
+    pub facing_bet: bool,
+    pub action: Act,
+}
+
+/// 시뮬레이션 한 번의 전체 액션 시퀀스와 결과
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SimulationSample {
+    /// 평가 대상 액션부터 시작하는 액션 시퀀스
+    pub actions: Vec<TracedAction>,
+    /// 시뮬레이션 종료 시점의 보드
+    pub board: Vec<u8>,
+    /// 원래 플레이어의 최종 payoff (항상 칩 단위)
+    pub payoff: f64,
+}
+
+/// EV 시뮬레이션 디버그 정보 (`EVConfig::debug_trace`가 켜진 경우에만 생성)
+#[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EVDebugInfo {
+    /// 수행된 시뮬레이션 수
+    pub simulations: usize,
+    /// 스트리트별 상대방 액션 횟수 (0=프리플랍 ... 3=리버)
+    pub opponent_actions: [StreetActionCounts; 4],
+    /// 액션별 최대 `debug_sample_limit`개의 시뮬레이션 시퀀스
+    pub samples: Vec<SimulationSample>,
+    /// 터미널 상태와 최대 깊이의 휴리스틱 평가 횟수
+    pub terminal_evaluations: usize,
+    /// 부분 트리 캐시가 평균을 돌려준 횟수 (`EVConfig::subtree_cache`)
+    pub cache_hits: usize,
+    /// 부분 트리 캐시에 샘플이 부족해 시뮬레이션한 횟수
+    pub cache_misses: usize,
+}
+
+impl EVDebugInfo {
+    /// 모든 스트리트의 상대방 액션 횟수 합계
+    pub fn opponent_totals(&self) -> StreetActionCounts {
+        let mut totals = StreetActionCounts::default();
+        for street in &self.opponent_actions {
+            totals.facing_check.merge(&street.facing_check);
+            totals.facing_bet.merge(&street.facing_bet);
+        }
+        totals
+    }
+
+    fn record(&mut self, trace: SimulationTrace, payoff: f64, original_player: usize, keep: bool) {
+        self.simulations += 1;
+        for step in &trace.actions {
+            if step.player != original_player {
+                let street = &mut self.opponent_actions[(step.street as usize).min(3)];
+                if step.facing_bet {
+                    street.facing_bet.record(&step.action);
+                } else {
+                    street.facing_check.record(&step.action);
+                }
+            }
+        }
+        if keep {
+            self.samples.push(SimulationSample {
+                actions: trace.actions,
+                board: trace.board,
+                payoff,
+            });
         }
     }
 }
 
+/// 디버그 정보를 포함한 EV 계산 결과
+#[derive(Debug, Clone)]
+pub struct EVCalculation {
+    pub action_evs: Vec<ActionEV>,
+    pub debug: Option<EVDebugInfo>,
+    /// 모든 액션에 걸쳐 실제로 돌린 시뮬레이션 수
+    pub samples_run: usize,
+    /// 예산(시간, 샘플)이 끝나 `sample_count`보다 적게 시뮬레이션한 액션이 있는지
+    pub budget_exhausted: bool,
+}
+
+/// 한 번의 `calculate_action_evs_traced` 동안 비교하는 모든 액션이 공유하는 시뮬레이션 상태
+///
+/// 부분 트리 캐시는 (부분 트리 루트의 원래 플레이어 정보 집합 키, 남은 깊이)별로 원래 플레이어
+/// payoff의 합계와 샘플 수를 모읍니다. 다시 방문하면 같은 버킷에 샘플을 더하고, 샘플이
+/// `min_samples` 이상 모인 버킷은 부분 트리를 다시 시뮬레이션하는 대신 평균을 돌려줍니다.
+/// 액션 직후 상태(깊이 0)는 모든 샘플이 지나므로 캐시하지 않습니다. 캐시된 평균은 분산이
+/// 작아 보고되는 표준 오차가 실제보다 작을 수 있습니다.
+/// 캐시는 계산 한 번 동안만 살아 있으므로 무효화하지 않습니다.
+///
+/// `budget`은 지금 추정 중인 액션(또는 크기)의 예산이며 배치마다 샘플을 받습니다. 마감이 지난
+/// 뒤에도 액션마다 최소 한 번은 시뮬레이션하고, 샘플 한도를 다 쓰면 시뮬레이션하지 않습니다.
+#[derive(Debug, Default)]
+struct SimulationContext {
+    min_samples: Option<u32>,
+    buckets: FxHashMap<(u64, u8), (f64, u32)>,
+    terminal_evaluations: usize,
+    cache_hits: usize,
+    cache_misses: usize,
+    budget: ComputeBudget,
+    nodes_visited: u64,
+    samples_run: usize,
+    budget_exhausted: bool,
+}
+
+/// 시뮬레이션 한 번의 진행 기록
+#[derive(Debug, Default)]
+struct SimulationTrace {
+    actions: Vec<TracedAction>,
+    board: Vec<u8>,
+}
+
+impl SimulationTrace {
+    fn push(&mut self, state: &State, action: Act) {
+        let player = state.to_act.min(5);
+        self.actions.push(TracedAction {
+            player,
+            street: state.street,
+            facing_bet: state.to_call > state.invested[player],
+            action,
+        });
+    }
+}
+
+/// 시뮬레이션 상대의 액션 분포 모델
+///
+/// 관찰된 상대 성향(통계)으로 EV를 계산할 때 `EVCalculator::with_opponent_model`로 지정합니다.
+/// 지정하면 `use_opponent_model`의 내장 휴리스틱 대신 히어로가 아닌 모든 플레이어에 사용됩니다.
+pub trait OpponentActionModel: Send + Sync {
+    /// 상대 관점 상태에서 `actions` 순서의 액션 확률 (합이 0이면 균등 선택)
+    fn action_probabilities(&self, observed: &ObservedState, actions: &[Act]) -> Vec<f64>;
+}
+
 /// EV 계산기
 pub struct EVCalculator {
     config: EVConfig,
+    /// 상대 액션 모델 (None이면 설정에 따라 내장 휴리스틱 또는 랜덤)
+    opponent: Option<Box<dyn OpponentActionModel>>,
 }
 
 impl EVCalculator {
     /// 새로운 EV 계산기 생성
+    ///
+    /// # Panics
+    /// `value_unit` 변환에 필요한 정보가 `value_context`에 없으면 패닉합니다.
+    /// 사용자 입력으로 만든 설정에는 `try_new`를 사용하세요.
     pub fn new(config: EVConfig) -> Self {
-        Self { config }
+        match Self::try_new(config) {
+            Ok(calculator) => calculator,
+            Err(e) => panic!("잘못된 EV 단위 설정: {}", e),
+        }
+    }
+
+    /// 단위 설정을 검증하여 EV 계산기 생성
+    pub fn try_new(config: EVConfig) -> Result<Self, ValueConversionError> {
+        config.value_context.validate(config.value_unit)?;
+        Ok(Self { config, opponent: None })
+    }
+
+    /// 상대 액션을 주어진 모델로 샘플링하는 계산기로 변경
+    pub fn with_opponent_model(mut self, model: impl OpponentActionModel + 'static) -> Self {
+        self.opponent = Some(Box::new(model));
+        self
     }
 
     /// 기본 설정으로 EV 계산기 생성
@@ -50,97 +325,381 @@ impl EVCalculator {
 
     /// 현재 상태에서 모든 가능한 액션의 EV 계산
     pub fn calculate_action_evs(&self, state: &State) -> Vec<ActionEV> {
+        self.calculate_action_evs_traced(state).action_evs
+    }
+
+    /// 모든 액션의 EV 계산 (`debug_trace`가 켜져 있으면 디버그 정보 포함)
+    pub fn calculate_action_evs_traced(&self, state: &State) -> EVCalculation {
+        self.calculate_action_evs_within(state, &self.config_budget())
+    }
+
+    /// `budget` 안에서 모든 액션의 EV 계산 (`time_budget` 설정 대신 주어진 예산을 씀)
+    ///
+    /// 남은 예산을 아직 계산하지 않은 액션끼리 균등하게 나눠 앞 액션이 예산을 다 쓰지 않게 하고,
+    /// 앞 액션이 남긴 예산은 뒤 액션이 씁니다. 샘플 한도를 다 써서 한 번도 시뮬레이션하지 못한
+    /// 액션은 액션 직후 상태의 휴리스틱 평가값이며 표준오차는 무한대입니다.
+    pub fn calculate_action_evs_within(&self, state: &State, budget: &ComputeBudget) -> EVCalculation {
         let legal_actions = State::legal_actions(state);
         let mut action_evs = Vec::new();
+        let mut debug = self.config.debug_trace.then(EVDebugInfo::default);
+        let (mut rng, mut context) = self.simulation_setup();
+        let action_count = legal_actions.len();
 
-        for action in legal_actions {
-            let ev = self.calculate_single_action_ev(state, &action);
+        for (i, action) in legal_actions.into_iter().enumerate() {
+            context.budget = budget.child(1.0 / (action_count - i) as f64);
+            let (chip_ev, chip_std_error) =
+                self.calculate_single_action_ev(state, &action, debug.as_mut(), &mut context, &mut rng);
             let confidence = self.calculate_confidence(state);
+            let (ev, std_error) = self.to_unit(chip_ev, chip_std_error);
 
             action_evs.push(ActionEV {
                 action,
                 ev,
                 confidence,
+                std_error,
+                unit: self.config.value_unit,
             });
         }
 
-        // EV 높은 순으로 정렬
-        action_evs.sort_by(|a, b| b.ev.partial_cmp(&a.ev).unwrap());
-        action_evs
+        // EV 높은 순으로 정렬 (NaN은 동률로 취급하여 패닉 방지)
+        action_evs.sort_by(|a, b| b.ev.partial_cmp(&a.ev).unwrap_or(std::cmp::Ordering::Equal));
+        if let Some(info) = debug.as_mut() {
+            info.terminal_evaluations = context.terminal_evaluations;
+            info.cache_hits = context.cache_hits;
+            info.cache_misses = context.cache_misses;
+        }
+        EVCalculation {
+            action_evs,
+            debug,
+            samples_run: context.samples_run,
+            budget_exhausted: context.budget_exhausted,
+        }
+    }
+
+    /// 최소 레이즈부터 최대 레이즈까지 `n_points`개 크기의 (레이즈 목표 금액, EV, 표준오차)
+    ///
+    /// 추상 레이즈 크기 대신 각 금액으로 레이즈한 상태를 직접 만들어 시뮬레이션하며,
+    /// `sample_count`를 점들이 나눠 쓰고 부분 트리 캐시도 공유합니다. 최대값은 유효 스택
+    /// (가장 깊은 상대가 콜할 수 있는 올인 금액)이며 베팅 규칙의 상한을 따릅니다.
+    /// `hero`의 차례가 아니거나 레이즈할 수 없으면 빈 벡터입니다.
+    pub fn ev_by_size(&self, state: &State, hero: usize, n_points: usize) -> Vec<(u32, f64, f64)> {
+        self.ev_by_size_within(state, hero, n_points, &self.config_budget())
+    }
+
+    /// `budget` 안에서 계산하는 `ev_by_size` (점마다 남은 예산을 균등하게 나눔)
+    pub fn ev_by_size_within(
+        &self,
+        state: &State,
+        hero: usize,
+        n_points: usize,
+        budget: &ComputeBudget,
+    ) -> Vec<(u32, f64, f64)> {
+        if n_points == 0 || State::current_player(state) != Some(hero) {
+            return Vec::new();
+        }
+        let Some(bounds) = state.raise_bounds() else {
+            return Vec::new();
+        };
+        let (mut rng, mut context) = self.simulation_setup();
+        let samples = (self.config.sample_count / n_points).max(1);
+        let span = (bounds.max_raise_to - bounds.min_raise_to) as u64;
+
+        (0..n_points)
+            .map(|i| {
+                let offset = if n_points > 1 { span * i as u64 / (n_points - 1) as u64 } else { 0 };
+                let raise_to = bounds.min_raise_to + offset as u32;
+                let next_state = state.apply_concrete_raise(raise_to);
+                context.budget = budget.child(1.0 / (n_points - i) as f64);
+                // 디버그 추적을 하지 않으므로 액션 자리는 기록되지 않음
+                let (chip_ev, chip_std_error) =
+                    self.estimate_after(state, (Act::Raise(0), &next_state), samples, None, &mut context, &mut rng);
+                let (ev, std_error) = self.to_unit(chip_ev, chip_std_error);
+                (raise_to, ev, std_error)
+            })
+            .collect()
+    }
+
+    /// `ev_by_size` 곡선과 최고 EV 크기의 95% 신뢰구간 (레이즈할 수 없으면 None)
+    pub fn sizing_curve(&self, state: &State, hero: usize, n_points: usize) -> Option<SizingCurve> {
+        self.sizing_curve_within(state, hero, n_points, &self.config_budget())
+    }
+
+    /// `budget` 안에서 계산하는 `sizing_curve`
+    pub fn sizing_curve_within(
+        &self,
+        state: &State,
+        hero: usize,
+        n_points: usize,
+        budget: &ComputeBudget,
+    ) -> Option<SizingCurve> {
+        let points: Vec<SizingPoint> = self
+            .ev_by_size_within(state, hero, n_points, budget)
+            .into_iter()
+            .map(|(raise_to, ev, std_error)| SizingPoint { raise_to, ev, std_error })
+            .collect();
+        let best = *points.iter().reduce(|best, point| if point.ev > best.ev { point } else { best })?;
+        let margin = SIZING_CONFIDENCE_Z * best.std_error;
+        Some(SizingCurve {
+            points,
+            best,
+            best_interval: (best.ev - margin, best.ev + margin),
+            unit: self.config.value_unit,
+        })
+    }
+
+    /// 계산 한 번 동안 쓰는 난수 생성기와 시뮬레이션 상태
+    fn simulation_setup(&self) -> (StdRng, SimulationContext) {
+        let rng = match self.config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => entropy_rng(),
+        };
+        let context = SimulationContext {
+            min_samples: self.config.subtree_cache,
+            ..SimulationContext::default()
+        };
+        (rng, context)
+    }
+
+    /// 설정의 `time_budget`으로 만든 계산 한 번의 예산 (없으면 제한 없음)
+    fn config_budget(&self) -> ComputeBudget {
+        match self.config.time_budget {
+            Some(limit) => ComputeBudget::unlimited().with_time_limit(limit),
+            None => ComputeBudget::unlimited(),
+        }
+    }
+
+    /// 칩 단위 EV와 표준오차를 설정한 단위로 변환
+    fn to_unit(&self, chip_ev: f64, chip_std_error: f64) -> (f64, f64) {
+        let convert = |chips: f64| {
+            self.config
+                .value_context
+                .chips_to(chips, self.config.value_unit)
+                .expect("단위 문맥은 생성 시 검증됨")
+        };
+        let ev = convert(chip_ev);
+        // 상금 단위 변환은 비선형이므로 EV 주변에서 선형 근사
+        (ev, (convert(chip_ev + chip_std_error) - ev).abs())
     }
 
-    /// 특정 액션의 EV 계산
-    fn calculate_single_action_ev(&self, state: &State, action: &Act) -> f64 {
+    /// 특정 액션의 EV와 그 표준오차 계산 (칩 단위)
+    fn calculate_single_action_ev<R: Rng>(
+        &self,
+        state: &State,
+        action: &Act,
+        debug: Option<&mut EVDebugInfo>,
+        context: &mut SimulationContext,
+        rng: &mut R,
+    ) -> (f64, f64) {
         // 액션 실행 후 상태 생성
-        let next_state = State::next_state(state, action.clone());
+        let next_state = State::next_state(state, *action);
+        self.estimate_after(state, (*action, &next_state), self.config.sample_count, debug, context, rng)
+    }
+
+    /// `state`에서 `step`(액션, 액션 후 상태)을 진행한 뒤의 EV와 표준오차 (칩 단위, `samples`번 시뮬레이션)
+    fn estimate_after<R: Rng>(
+        &self,
+        state: &State,
+        (action, next_state): (Act, &State),
+        samples: usize,
+        mut debug: Option<&mut EVDebugInfo>,
+        context: &mut SimulationContext,
+        rng: &mut R,
+    ) -> (f64, f64) {
+        let kept_before = debug.as_ref().map_or(0, |info| info.samples.len());
 
-        // 터미널 상태인 경우 즉시 평가
-        if next_state.is_terminal() {
-            return self.evaluate_terminal_state(&next_state, state.to_act);
+        // 터미널 상태인 경우 즉시 평가 (상대 홀카드를 다시 딜링하는 쇼다운은 샘플링)
+        let alive_count = next_state.alive.iter().filter(|&&alive| alive).count();
+        if next_state.is_terminal() && (next_state.opponent_deal.is_none() || alive_count <= 1) {
+            context.terminal_evaluations += 1;
+            let payoff = self.evaluate_terminal_state(next_state, state.to_act, rng);
+            if let Some(info) = debug {
+                let mut trace = SimulationTrace::default();
+                trace.push(state, action);
+                trace.board = next_state.board.clone();
+                info.record(trace, payoff, state.to_act, self.config.debug_sample_limit > 0);
+            }
+            return (payoff, 0.0);
         }
 
-        // 몬테카를로 시뮬레이션으로 EV 계산
+        // 몬테카를로 시뮬레이션으로 EV 계산 (예산은 배치 경계에서만 검사)
         let mut total_payoff = 0.0;
-        for _ in 0..self.config.sample_count {
-            let payoff = self.simulate_game(&next_state, state.to_act, 0);
-            total_payoff += payoff;
+        let mut total_squared = 0.0;
+        let mut run = 0;
+        let mut cut = false;
+        while run < samples && !cut {
+            let wanted = (samples - run).min(EV_BATCH_SAMPLES) as u64;
+            let granted = match context.budget.take_samples(wanted) {
+                Ok(granted) => granted as usize,
+                // 마감이 지나도 액션마다 최소 한 번은 시뮬레이션
+                Err(BudgetExhausted::Deadline) if run == 0 => context.budget.take_samples_past_deadline(1) as usize,
+                Err(_) => 0,
+            };
+            cut = granted < wanted as usize;
+            if granted == 0 {
+                break;
+            }
+            let nodes_before = context.nodes_visited;
+            for _ in 0..granted {
+                // 상대 홀카드를 모르는 상태는 샘플마다 딜링 모델로 다시 딜링
+                let resampled = next_state.resample_opponents(state.to_act, rng);
+                let next_state = resampled.as_ref().unwrap_or(next_state);
+                let payoff = match debug.as_deref_mut() {
+                    Some(info) => {
+                        let mut trace = SimulationTrace::default();
+                        trace.push(state, action);
+                        let payoff =
+                            self.simulate_game(next_state, state.to_act, 0, Some(&mut trace), context, rng);
+                        let keep = info.samples.len() - kept_before < self.config.debug_sample_limit;
+                        info.record(trace, payoff, state.to_act, keep);
+                        payoff
+                    }
+                    None => self.simulate_game(next_state, state.to_act, 0, None, context, rng),
+                };
+                total_payoff += payoff;
+                total_squared += payoff * payoff;
+            }
+            run += granted;
+            context.budget.charge_nodes(context.nodes_visited - nodes_before);
+        }
+        context.samples_run += run;
+        context.budget_exhausted |= cut;
+        if run == 0 {
+            return (self.heuristic_evaluation(next_state, state.to_act), f64::INFINITY);
         }
 
-        total_payoff / self.config.sample_count as f64
+        let count = run as f64;
+        let mean = total_payoff / count;
+        let std_error = if run > 1 {
+            let variance = (total_squared - count * mean * mean) / (count - 1.0);
+            (variance.max(0.0) / count).sqrt()
+        } else {
+            0.0
+        };
+        (mean, std_error)
     }
 
     /// 게임 시뮬레이션 (몬테카를로)
-    fn simulate_game(&self, state: &State, original_player: usize, depth: u8) -> f64 {
+    fn simulate_game<R: Rng>(
+        &self,
+        state: &State,
+        original_player: usize,
+        depth: u8,
+        mut trace: Option<&mut SimulationTrace>,
+        context: &mut SimulationContext,
+        rng: &mut R,
+    ) -> f64 {
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.board.clone_from(&state.board);
+        }
+        context.nodes_visited += 1;
+
         // 최대 깊이 도달 시 휴리스틱 평가
         if depth >= self.config.max_depth {
+            context.terminal_evaluations += 1;
             return self.heuristic_evaluation(state, original_player);
         }
 
         // 터미널 상태 처리
         if state.is_terminal() {
-            return self.evaluate_terminal_state(state, original_player);
+            context.terminal_evaluations += 1;
+            return self.evaluate_terminal_state(state, original_player, rng);
         }
 
+        let Some(min_samples) = context.min_samples.filter(|_| depth > 0) else {
+            return self.simulate_step(state, original_player, depth, trace, context, rng);
+        };
+
+        // 부분 트리 캐시: 충분히 모인 버킷은 평균을, 아니면 시뮬레이션 결과를 버킷에 추가
+        let key = (State::info_key(state, original_player), self.config.max_depth - depth);
+        if let Some(&(total, count)) = context.buckets.get(&key) {
+            if count >= min_samples {
+                context.cache_hits += 1;
+                return total / count as f64;
+            }
+        }
+        context.cache_misses += 1;
+        let payoff = self.simulate_step(state, original_player, depth, trace, context, rng);
+        let bucket = context.buckets.entry(key).or_insert((0.0, 0));
+        bucket.0 += payoff;
+        bucket.1 += 1;
+        payoff
+    }
+
+    /// 터미널이 아닌 상태에서 카드를 받거나 액션 하나를 골라 시뮬레이션을 이어감
+    fn simulate_step<R: Rng>(
+        &self,
+        state: &State,
+        original_player: usize,
+        depth: u8,
+        mut trace: Option<&mut SimulationTrace>,
+        context: &mut SimulationContext,
+        rng: &mut R,
+    ) -> f64 {
         // 찬스 노드 처리
         if state.is_chance_node() {
-            let mut rng = rand::thread_rng();
-            let chance_state = State::apply_chance(state, &mut rng);
-            return self.simulate_game(&chance_state, original_player, depth + 1);
+            let chance_state = State::apply_chance(state, rng);
+            return self.simulate_game(&chance_state, original_player, depth + 1, trace, context, rng);
         }
 
         let current_player = State::current_player(state);
         let legal_actions = State::legal_actions(state);
 
         if legal_actions.is_empty() {
+            context.terminal_evaluations += 1;
             return self.heuristic_evaluation(state, original_player);
         }
 
         // 액션 선택 (상대방 모델 또는 랜덤)
-        let action =
-            if self.config.use_opponent_model && current_player.unwrap_or(0) != original_player {
-                self.select_opponent_action(state, &legal_actions)
-            } else {
-                self.select_random_action(&legal_actions)
-            };
+        let action = match current_player {
+            Some(player) if player != original_player && self.opponent.is_some() => {
+                self.sample_opponent_action(&state.observe(player), &legal_actions, rng)
+            }
+            Some(player) if self.config.use_opponent_model && player != original_player => {
+                // 상대 모델은 자기 관점의 상태만 봄 (히어로 홀카드 엿보기 방지)
+                self.select_opponent_action(&state.observe(player), &legal_actions, rng)
+            }
+            _ => self.select_random_action(&legal_actions, rng),
+        };
+
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.push(state, action);
+        }
 
         // 다음 상태로 진행
         let next_state = State::next_state(state, action);
-        self.simulate_game(&next_state, original_player, depth + 1)
+        self.simulate_game(&next_state, original_player, depth + 1, trace, context, rng)
     }
 
     /// 터미널 상태 평가
-    fn evaluate_terminal_state(&self, state: &State, player: usize) -> f64 {
+    fn evaluate_terminal_state<R: Rng>(&self, state: &State, player: usize, rng: &mut R) -> f64 {
         // 정확한 payoff 계산
         let alive_count = state.alive.iter().filter(|&&alive| alive).count();
+        let contributed = (state.invested[player] + state.antes[player]) as f64;
 
         if alive_count <= 1 {
             if state.alive[player] {
-                state.pot as f64 - state.invested[player] as f64 // 팟에서 투자금 제외
+                state.net_pot() as f64 - contributed // 팟에서 레이크와 투자금 제외
             } else {
-                -(state.invested[player] as f64) // 폴드했으면 투자금 손실
+                -contributed // 폴드했으면 투자금 손실
             }
+        } else if AllInAdjudicator::hands_known(state) {
+            // 모든 홀카드를 알면 남은 보드를 열거/샘플링해 정확한 지분 계산
+            // (시드가 없으면 시뮬레이션 RNG에서 받아 계산 전체가 재현 가능하도록)
+            let mut all_in = self.config.all_in.clone();
+            if state.opponent_deal.is_some() {
+                // 상대 홀카드가 샘플마다 바뀌면 캐시가 듣지 않고 바깥 샘플링이 런아웃도 평균내므로 적게 딜링
+                all_in = AllInConfig {
+                    max_enumerated_boards: RESAMPLED_SHOWDOWN_BOARDS,
+                    monte_carlo_samples: RESAMPLED_SHOWDOWN_BOARDS,
+                    exact_heads_up: false,
+                    ..all_in
+                };
+            }
+            all_in.seed = all_in.seed.or_else(|| Some(rng.gen()));
+            // 사이드 팟과 콜되지 않은 베팅은 층마다 그 금액을 낸 좌석끼리만 나눔
+            SidePotEvaluator::new(AllInAdjudicator::new(all_in)).evaluate(state)[player]
         } else {
-            // 쇼다운: 정확한 핸드 평가로 승률 계산
+            // 쇼다운: 상대 홀카드를 모르면 핸드 강도 모델로 승률 추정
             let my_strength = self.estimate_hand_strength(state, player);
             let opponents_average_strength =
                 self.estimate_opponents_average_strength(state, player);
@@ -153,10 +712,9 @@ impl EVCalculator {
             );
 
             // 정확한 EV 계산
-            let total_pot = state.pot as f64;
-            let my_investment = state.invested[player] as f64;
+            let total_pot = state.net_pot() as f64;
 
-            win_probability * total_pot - my_investment
+            win_probability * total_pot - contributed
         }
     }
 
@@ -171,10 +729,10 @@ impl EVCalculator {
         base_ev + position_bonus
     }
 
-    /// 핸드 강도 추정
+    /// 핸드 강도 추정 (포스트플랍은 이 보드에서 가능한 상대 핸드 중 퍼센타일)
     fn estimate_hand_strength(&self, state: &State, player: usize) -> f64 {
         if player < state.hole.len() {
-            hand_strength(state.hole[player], &state.board)
+            hand_percentile_with(state.hole[player], &state.board, SIMULATION_PERCENTILE)
         } else {
             0.5 // 정보 없음
         }
@@ -260,12 +818,22 @@ impl EVCalculator {
     }
 
     /// 상대방 액션 선택 (정교한 모델)
-    fn select_opponent_action(&self, state: &State, actions: &[Act]) -> Act {
-        if let Some(current_player) = State::current_player(state) {
-            let hand_strength = self.estimate_hand_strength(state, current_player);
-            let pot_odds = self.calculate_pot_odds(state);
-            let position_factor = self.get_position_factor(current_player, state);
-            let stack_pressure = self.calculate_stack_pressure(state, current_player);
+    fn select_opponent_action<R: Rng>(&self, observed: &ObservedState, actions: &[Act], rng: &mut R) -> Act {
+        // 공짜 체크가 가능하면 폴드하지 않음
+        let player = observed.player.min(5);
+        let free_check: Vec<Act>;
+        let actions = if observed.to_call <= observed.invested[player] && actions.contains(&Act::Call) {
+            free_check = actions.iter().filter(|a| !matches!(a, Act::Fold)).copied().collect();
+            &free_check[..]
+        } else {
+            actions
+        };
+
+        if observed.alive[player] {
+            let hand_strength = hand_strength(observed.hole, &observed.board);
+            let pot_odds = self.calculate_pot_odds(observed);
+            let position_factor = self.get_position_factor(observed);
+            let stack_pressure = self.calculate_stack_pressure(observed);
 
             // 포지션, 스택 크기, 팟 오즈를 종합적으로 고려
             let aggression_threshold = self.calculate_aggression_threshold(
@@ -278,10 +846,10 @@ impl EVCalculator {
             // 액션 선택 로직
             if hand_strength > 0.75 || (hand_strength > 0.6 && position_factor > 0.7) {
                 // 강한 핸드 또는 좋은 포지션에서 중간 핸드
-                self.select_aggressive_action(actions, hand_strength, aggression_threshold)
+                self.select_aggressive_action(observed, actions, hand_strength, aggression_threshold)
             } else if hand_strength > 0.35 && pot_odds > 0.25 {
                 // 중간 핸드에서 좋은 팟 오즈
-                self.select_balanced_action(actions, hand_strength, pot_odds)
+                self.select_balanced_action(actions, hand_strength, pot_odds, rng)
             } else if hand_strength < 0.3 || stack_pressure > 0.8 {
                 // 약한 핸드 또는 스택 프레셔가 높은 상황
                 self.select_defensive_action(actions)
@@ -290,32 +858,32 @@ impl EVCalculator {
                 self.select_default_action(actions, hand_strength)
             }
         } else {
-            self.select_random_action(actions)
+            self.select_random_action(actions, rng)
         }
     }
 
     /// 팟 오즈 계산
-    fn calculate_pot_odds(&self, state: &State) -> f64 {
-        if state.to_call == 0 {
+    fn calculate_pot_odds(&self, observed: &ObservedState) -> f64 {
+        if observed.to_call == 0 {
             0.0
         } else {
-            state.to_call as f64 / (state.pot + state.to_call) as f64
+            observed.to_call as f64 / (observed.pot + observed.to_call) as f64
         }
     }
 
     /// 포지션 팩터 계산
-    fn get_position_factor(&self, player: usize, state: &State) -> f64 {
-        let active_players = state.alive.iter().filter(|&&alive| alive).count();
-        let relative_position = player as f64 / active_players.max(1) as f64;
+    fn get_position_factor(&self, observed: &ObservedState) -> f64 {
+        let active_players = observed.alive.iter().filter(|&&alive| alive).count();
+        let relative_position = observed.player as f64 / active_players.max(1) as f64;
 
         // 레이트 포지션일수록 높은 값
         relative_position
     }
 
     /// 스택 프레셔 계산
-    fn calculate_stack_pressure(&self, state: &State, player: usize) -> f64 {
+    fn calculate_stack_pressure(&self, observed: &ObservedState) -> f64 {
         let big_blind = 50.0; // 기본 빅블라인드 값
-        let effective_stack = state.stack[player] as f64;
+        let effective_stack = observed.stack[observed.player.min(5)] as f64;
         let bb_ratio = effective_stack / big_blind;
 
         if bb_ratio < 10.0 {
@@ -350,28 +918,72 @@ impl EVCalculator {
     }
 
     /// 공격적인 액션 선택
-    fn select_aggressive_action(&self, actions: &[Act], hand_strength: f64, threshold: f64) -> Act {
-        if hand_strength > threshold + 0.2 {
+    fn select_aggressive_action(
+        &self,
+        observed: &ObservedState,
+        actions: &[Act],
+        hand_strength: f64,
+        threshold: f64,
+    ) -> Act {
+        let raise = self.select_raise_size(observed, actions);
+        let call = actions.iter().find(|a| matches!(a, Act::Call)).copied();
+
+        let preferred = if hand_strength > threshold + 0.2 {
             // 매우 강한 핸드: 레이즈 우선
-            actions
-                .iter()
-                .find(|a| matches!(a, Act::Raise(_)))
-                .or_else(|| actions.iter().find(|a| matches!(a, Act::Call)))
-                .unwrap_or(&actions[0])
-                .clone()
+            raise.or(call)
         } else {
             // 강한 핸드: 콜 우선
-            actions
-                .iter()
-                .find(|a| matches!(a, Act::Call))
-                .or_else(|| actions.iter().find(|a| matches!(a, Act::Raise(_))))
-                .unwrap_or(&actions[0])
-                .clone()
+            call.or(raise)
+        };
+        preferred.unwrap_or(actions[0])
+    }
+
+    /// geometric 사이징에 가장 가까운 레이즈 크기 선택
+    ///
+    /// 남은 스트리트 동안 리버에 올인이 되는 팟 비율을 목표로,
+    /// 가능한 레이즈 중 실제 팟 비율이 가장 가까운 것을 고릅니다.
+    fn select_raise_size(&self, observed: &ObservedState, actions: &[Act]) -> Option<Act> {
+        let pot = observed.pot.max(1) as f64;
+        let behind = self.stack_behind_after_call(observed) as f64;
+        let target = geometric_bet_fraction(behind / pot, streets_remaining(observed.street));
+
+        actions
+            .iter()
+            .filter(|a| matches!(a, Act::Raise(_)))
+            .min_by(|a, b| {
+                let da = (self.raise_fraction(observed, a) - target).abs();
+                let db = (self.raise_fraction(observed, b) - target).abs();
+                da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .copied()
+    }
+
+    /// 레이즈 액션의 팟 대비 크기 (holdem::next_state의 사이징과 동일, 베팅 규칙 반영)
+    fn raise_fraction(&self, observed: &ObservedState, action: &Act) -> f64 {
+        let pot = observed.pot.max(1) as f64;
+        let player = observed.player.min(5);
+        let call_amount = observed.to_call.saturating_sub(observed.invested[player]).min(observed.stack[player]);
+        match action {
+            Act::Raise(size) => observed.to_state().raise_amount(*size).saturating_sub(call_amount) as f64 / pot,
+            _ => 0.0,
         }
     }
 
+    /// 콜 후 남는 스택
+    fn stack_behind_after_call(&self, observed: &ObservedState) -> u32 {
+        let player = observed.player.min(5);
+        let call_amount = observed.to_call.saturating_sub(observed.invested[player]);
+        observed.stack[player].saturating_sub(call_amount)
+    }
+
     /// 균형잡힌 액션 선택
-    fn select_balanced_action(&self, actions: &[Act], hand_strength: f64, pot_odds: f64) -> Act {
+    fn select_balanced_action<R: Rng>(
+        &self,
+        actions: &[Act],
+        hand_strength: f64,
+        pot_odds: f64,
+        rng: &mut R,
+    ) -> Act {
         let call_probability = hand_strength + pot_odds - 0.5;
 
         if call_probability > 0.6 {
@@ -382,7 +994,7 @@ impl EVCalculator {
                 .clone()
         } else if call_probability > 0.3 {
             // 랜덤하게 콜 또는 폴드
-            if rand::random::<f64>() < 0.6 {
+            if rng.gen::<f64>() < 0.6 {
                 actions
                     .iter()
                     .find(|a| matches!(a, Act::Call))
@@ -427,10 +1039,91 @@ impl EVCalculator {
         }
     }
 
+    /// 지정된 상대 모델의 `actions` 순서 액션 가중치 (유한하지 않거나 음수인 값은 0)
+    fn opponent_weights(&self, observed: &ObservedState, actions: &[Act]) -> Vec<f64> {
+        let probabilities = match &self.opponent {
+            Some(model) => model.action_probabilities(observed, actions),
+            None => Vec::new(),
+        };
+        (0..actions.len())
+            .map(|i| probabilities.get(i).copied().filter(|p| p.is_finite()).unwrap_or(0.0).max(0.0))
+            .collect()
+    }
+
+    /// 지정된 상대 모델의 분포로 액션 샘플링
+    fn sample_opponent_action<R: Rng>(&self, observed: &ObservedState, actions: &[Act], rng: &mut R) -> Act {
+        let weights = self.opponent_weights(observed, actions);
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return self.select_random_action(actions, rng);
+        }
+
+        let mut threshold = rng.gen::<f64>() * total;
+        for (action, weight) in actions.iter().zip(&weights) {
+            if threshold < *weight {
+                return *action;
+            }
+            threshold -= weight;
+        }
+        actions[weights.iter().rposition(|&w| w > 0.0).unwrap_or(0)]
+    }
+
+    /// 시뮬레이션과 같은 정책으로 게임 트리를 끝까지 열거한 액션별 정확한 EV (칩 단위, `legal_actions` 순서)
+    ///
+    /// 살아 있는 좌석의 홀카드를 모두 알고 찬스 노드 없이 끝나는 상태(리버)에서만 계산합니다. 상대 액션은
+    /// `with_opponent_model`의 확률이거나 균등 랜덤이어야 하며(내장 휴리스틱 상대는 확률을 주지 않음),
+    /// 쇼다운은 사이드 팟을 나눠 판정하고 `max_depth`는 적용하지 않습니다. 조건에 맞지 않으면 None입니다.
+    pub fn exact_action_evs(&self, state: &State) -> Option<Vec<(Act, f64)>> {
+        if state.opponent_deal.is_some()
+            || !AllInAdjudicator::hands_known(state)
+            || (self.config.use_opponent_model && self.opponent.is_none())
+        {
+            return None;
+        }
+        let hero = state.to_act;
+        let evaluator = SidePotEvaluator::new(AllInAdjudicator::new(self.config.all_in.clone()));
+        State::legal_actions(state)
+            .into_iter()
+            .map(|action| {
+                let ev = self.enumerate_value(&State::next_state(state, action), hero, &evaluator)?;
+                Some((action, ev))
+            })
+            .collect()
+    }
+
+    /// `state`부터 터미널까지 정책 확률로 가중한 `hero`의 정확한 기댓값 (찬스 노드를 만나면 None)
+    fn enumerate_value(&self, state: &State, hero: usize, evaluator: &SidePotEvaluator) -> Option<f64> {
+        if state.is_terminal() {
+            return Some(evaluator.evaluate(state)[hero]);
+        }
+        let actions = State::legal_actions(state);
+        if state.is_chance_node() || actions.is_empty() {
+            return None;
+        }
+
+        let mut weights = match State::current_player(state) {
+            Some(player) if player != hero && self.opponent.is_some() => {
+                self.opponent_weights(&state.observe(player), &actions)
+            }
+            _ => Vec::new(),
+        };
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            weights = vec![1.0; actions.len()];
+        }
+        let total: f64 = weights.iter().sum();
+
+        let mut value = 0.0;
+        for (&action, weight) in actions.iter().zip(&weights) {
+            if *weight > 0.0 {
+                value += weight / total * self.enumerate_value(&State::next_state(state, action), hero, evaluator)?;
+            }
+        }
+        Some(value)
+    }
+
     /// 랜덤 액션 선택
-    fn select_random_action(&self, actions: &[Act]) -> Act {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+    fn select_random_action<R: Rng>(&self, actions: &[Act], rng: &mut R) -> Act {
         let index = rng.gen_range(0..actions.len());
         actions[index].clone()
     }
@@ -457,18 +1150,21 @@ pub fn quick_ev_analysis(state: &State, sample_count: Option<usize>) -> Vec<Acti
         sample_count: sample_count.unwrap_or(1000),
         max_depth: 5,
         use_opponent_model: true,
+        ..EVConfig::default()
     };
 
     let calculator = EVCalculator::new(config);
     calculator.calculate_action_evs(state)
 }
 
-/// 상세한 EV 분석을 위한 헬퍼 함수
+/// 상세한 EV 분석을 위한 헬퍼 함수 (부분 트리 캐시 사용)
 pub fn detailed_ev_analysis(state: &State) -> Vec<ActionEV> {
     let config = EVConfig {
         sample_count: 50000,
         max_depth: 15,
         use_opponent_model: true,
+        subtree_cache: Some(DEFAULT_SUBTREE_CACHE_SAMPLES),
+        ..EVConfig::default()
     };
 
     let calculator = EVCalculator::new(config);
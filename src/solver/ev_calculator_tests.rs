@@ -1,5 +1,7 @@
 use crate::solver::ev_calculator::*;
 use crate::game::holdem::{State, Act};
+use crate::game::value_unit::{ValueContext, ValueConversionError, ValueUnit};
+use crate::solver::cfr_core::{Game, GameState};
 
 #[test]
 fn test_ev_config_creation() {
@@ -16,6 +18,8 @@ fn test_action_ev_creation() {
         action: action.clone(),
         ev: 100.0,
         confidence: 0.8,
+        std_error: 1.5,
+        unit: ValueUnit::Chips,
     };
     assert_eq!(ev.action, action);
     assert_eq!(ev.ev, 100.0);
@@ -32,6 +36,7 @@ fn test_basic_ev_calculation() {
     let results = calculator.calculate_action_evs(&state);
     // Should have at least one action
     assert!(!results.is_empty());
+    assert!(results.iter().all(|r| r.std_error.is_finite() && r.std_error >= 0.0));
 }
 
 #[test]
@@ -40,6 +45,7 @@ fn test_ev_calculation_stability() {
         sample_count: 100, // Smaller sample for faster testing
         max_depth: 5,
         use_opponent_model: true,
+        ..EVConfig::default()
     };
     let calculator = EVCalculator::new(config);
     
@@ -88,6 +94,7 @@ fn test_different_streets() {
         sample_count: 50,
         max_depth: 3,
         use_opponent_model: false,
+        ..EVConfig::default()
     };
     let calculator = EVCalculator::new(config);
 
@@ -108,6 +115,7 @@ fn test_confidence_bounds() {
         sample_count: 50,
         max_depth: 3,
         use_opponent_model: false,
+        ..EVConfig::default()
     };
     let calculator = EVCalculator::new(config);
 
@@ -122,7 +130,203 @@ fn test_confidence_bounds() {
     }
 }
 
+#[test]
+fn test_debug_trace_disabled_by_default() {
+    let config = EVConfig {
+        sample_count: 20,
+        max_depth: 5,
+        ..EVConfig::default()
+    };
+    let calculation = EVCalculator::new(config).calculate_action_evs_traced(&create_test_state());
+    assert!(calculation.debug.is_none());
+}
+
+#[test]
+fn test_debug_trace_opponent_folds_more_facing_raises() {
+    let config = EVConfig {
+        sample_count: 300,
+        max_depth: 10,
+        use_opponent_model: true,
+        debug_trace: true,
+        debug_sample_limit: 8,
+        ..EVConfig::default()
+    };
+    let calculator = EVCalculator::new(config);
+    let root = strong_hero_state();
+    let calculation = calculator.calculate_action_evs_traced(&root);
+    let debug = calculation.debug.expect("debug_trace가 켜져 있으면 디버그 정보가 있어야 함");
+
+    let totals = debug.opponent_totals();
+    assert!(totals.facing_bet.total() > 0 && totals.facing_check.total() > 0);
+    assert!(
+        totals.facing_bet.fold_frequency() > totals.facing_check.fold_frequency(),
+        "facing bet: {:?}, facing check: {:?}",
+        totals.facing_bet,
+        totals.facing_check
+    );
+
+    // 샘플 시퀀스는 next_state로 재생 가능한 합법 시퀀스여야 함
+    assert!(!debug.samples.is_empty());
+    assert!(debug.samples.len() <= 8 * calculation.action_evs.len());
+    let mut rng = rand::thread_rng();
+    for sample in &debug.samples {
+        let mut state = root.clone();
+        for step in &sample.actions {
+            while state.is_chance_node() {
+                let dealt = state.board.len();
+                state = State::apply_chance(&state, &mut rng);
+                for i in dealt..state.board.len() {
+                    state.board[i] = sample.board[i];
+                }
+            }
+            assert_eq!(step.player, state.to_act);
+            assert_eq!(step.street, state.street);
+            assert!(
+                State::legal_actions(&state).contains(&step.action),
+                "불법 액션 {:?} in {:?}",
+                step.action,
+                sample
+            );
+            state = State::next_state(&state, step.action);
+        }
+        assert!(sample.payoff.is_finite());
+    }
+}
+
+#[test]
+fn test_heavy_rake_flips_marginal_call() {
+    use crate::game::holdem::RakeModel;
+
+    let calculator = EVCalculator::new(EVConfig {
+        sample_count: 10,
+        max_depth: 5,
+        ..EVConfig::default()
+    });
+    let call_ev = |state: &State| {
+        calculator
+            .calculate_action_evs(state)
+            .iter()
+            .find(|action_ev| action_ev.action == Act::Call)
+            .map(|action_ev| action_ev.ev)
+            .expect("콜이 가능해야 함")
+    };
+
+    let mut state = marginal_river_call_state();
+    let rake_free = call_ev(&state);
+
+    state.rake = Some(RakeModel {
+        percentage: 0.1,
+        cap: 100,
+        no_flop_no_drop: true,
+    });
+    let raked = call_ev(&state);
+    assert!(rake_free > 0.0, "레이크 없이 +EV 콜이어야 함: {}", rake_free);
+    assert!(raked < 0.0, "높은 레이크에서는 -EV 콜이어야 함: {}", raked);
+}
+
+#[test]
+fn test_subtree_cache_reduces_terminal_evaluations() {
+    let config = EVConfig {
+        sample_count: 2000,
+        max_depth: 15,
+        // 랜덤 상대로 양쪽 모두 분기가 많은 부분 트리
+        use_opponent_model: false,
+        debug_trace: true,
+        debug_sample_limit: 0,
+        seed: Some(7),
+        ..EVConfig::default()
+    };
+    let run = |subtree_cache: Option<u32>| {
+        EVCalculator::new(EVConfig { subtree_cache, ..config.clone() })
+            .calculate_action_evs_traced(&river_lead_state())
+    };
+    let uncached = run(None);
+    let cached = run(Some(DEFAULT_SUBTREE_CACHE_SAMPLES));
+
+    let (plain, memo) = (uncached.debug.unwrap(), cached.debug.unwrap());
+    assert_eq!((plain.cache_hits, plain.cache_misses), (0, 0));
+    assert!(memo.cache_hits > 0);
+    assert!(
+        memo.terminal_evaluations * 10 < plain.terminal_evaluations * 9,
+        "cached {} vs uncached {}",
+        memo.terminal_evaluations,
+        plain.terminal_evaluations
+    );
+
+    for expected in &uncached.action_evs {
+        let actual = cached.action_evs.iter().find(|a| a.action == expected.action).unwrap();
+        let tolerance = 4.0 * (expected.std_error + actual.std_error) + 1e-9;
+        assert!(
+            (actual.ev - expected.ev).abs() <= tolerance,
+            "{:?}: cached {} vs uncached {} (±{})",
+            expected.action,
+            actual.ev,
+            expected.ev,
+            tolerance
+        );
+    }
+}
+
+// 리버에서 팟 오즈가 간신히 맞는 콜 상황 (히어로 0번, 상대 베팅 30 후)
+// 상대 홀카드는 웹 API처럼 모르는 상태라 핸드 강도 모델로 승률을 추정
+fn marginal_river_call_state() -> State {
+    let mut state = State::new_hand([10, 20], [1000; 6], 2);
+    state.hole[0] = [12, 24]; // Ks Qh
+    state.hole[1] = [0, 0]; // 알 수 없음
+    state.board = vec![1, 18, 35, 47, 30]; // 2s 6h 10d 9c 5d
+    state.street = 3;
+    state.pot = 268;
+    state.invested = [0, 30, 0, 0, 0, 0];
+    state.to_call = 30;
+    state.actions_taken = 1;
+    state.to_act = 0;
+    state
+}
+
+// 리버에서 먼저 액션하는 히어로(KQ 탑페어) vs 홀카드를 아는 상대(A10 세컨드 페어), 팟 200
+fn river_lead_state() -> State {
+    let mut state = State::new_hand([10, 20], [1000; 6], 2);
+    let villain = state.to_act;
+    state.street = 3;
+    state.board = vec![11, 18, 35, 47, 30]; // Qs 6h 10d 9c 5d
+    state.pot = 200;
+    state.invested = [0; 6];
+    state.to_call = 0;
+    state.actions_taken = 0;
+    state.to_act = 1 - villain;
+    state.hole[state.to_act] = [12, 24]; // Ks Qh
+    state.hole[villain] = [0, 22]; // As 10h
+    state
+}
+
+// 히어로 AA vs 상대 72o 헤즈업 프리플랍
+fn strong_hero_state() -> State {
+    let mut state = State::new_hand([10, 20], [1000; 6], 2);
+    state.hole[0] = [0, 13]; // As Ah
+    state.hole[1] = [6, 14]; // 7s 2h
+    state
+}
+
 // Helper function to create a test state
+#[test]
+fn test_seeded_ev_is_reproducible() {
+    let config = EVConfig {
+        sample_count: 50,
+        max_depth: 5,
+        seed: Some(42),
+        ..EVConfig::default()
+    };
+    let state = create_test_state_street(1);
+    let evs = |config: &EVConfig| -> Vec<(Act, f64)> {
+        EVCalculator::new(config.clone())
+            .calculate_action_evs(&state)
+            .into_iter()
+            .map(|r| (r.action, r.ev))
+            .collect()
+    };
+    assert_eq!(evs(&config), evs(&config));
+}
+
 fn create_test_state() -> State {
     create_test_state_street(0) // 0 = Preflop
 }
@@ -160,3 +364,177 @@ fn create_test_state_street(street: u8) -> State {
     
     state
 }
+
+#[test]
+fn test_ev_unit_conversion_to_big_blinds() {
+    let state = marginal_river_call_state();
+    let chips = EVCalculator::new(EVConfig {
+        sample_count: 10,
+        max_depth: 5,
+        ..EVConfig::default()
+    });
+    let big_blinds = EVCalculator::new(EVConfig {
+        sample_count: 10,
+        max_depth: 5,
+        value_unit: ValueUnit::BigBlinds,
+        value_context: ValueContext::with_big_blind(20),
+        ..EVConfig::default()
+    });
+
+    // 리버 폴드는 결정적이므로 단위만 다름
+    let fold_ev = |calculator: &EVCalculator| {
+        calculator
+            .calculate_action_evs(&state)
+            .into_iter()
+            .find(|action_ev| action_ev.action == Act::Fold)
+            .expect("폴드가 가능해야 함")
+    };
+    let in_chips = fold_ev(&chips);
+    let in_bb = fold_ev(&big_blinds);
+    assert_eq!(in_chips.unit, ValueUnit::Chips);
+    assert_eq!(in_bb.unit, ValueUnit::BigBlinds);
+    assert!((in_bb.ev - in_chips.ev / 20.0).abs() < 1e-9);
+
+    let json = serde_json::to_value(&in_bb).unwrap();
+    assert_eq!(json["unit"], "BigBlinds");
+}
+
+#[test]
+fn test_ev_prize_unit_without_payouts_is_rejected() {
+    let result = EVCalculator::try_new(EVConfig {
+        value_unit: ValueUnit::PrizeDollars,
+        ..EVConfig::default()
+    });
+    assert_eq!(result.err(), Some(ValueConversionError::MissingPayoutStructure));
+}
+
+#[test]
+fn test_known_hands_all_in_call_is_adjudicated_exactly() {
+    let mut state = State::new_hand([50, 100], [1000; 6], 2);
+    state.hole[0] = [0, 13]; // As Ah
+    state.hole[1] = [12, 25]; // Ks Kh
+    state.board = vec![1 + 39, 6 + 26, 8 + 13, 11 + 39]; // 2c 7d 9h Qc
+    state.street = 2;
+    state.stack = [900, 0, 0, 0, 0, 0];
+    state.invested = [0, 900, 0, 0, 0, 0];
+    state.pot = 1100;
+    state.to_call = 900;
+    state.to_act = 0;
+    state.actions_taken = 1;
+
+    let calculator = EVCalculator::new(EVConfig::default());
+    let call = calculator
+        .calculate_action_evs(&state)
+        .into_iter()
+        .find(|ev| ev.action == Act::Call)
+        .expect("콜 가능해야 함");
+
+    // 킹이 리버에 떨어지는 2/44만 짐
+    let expected = 42.0 / 44.0 * 2000.0 - 900.0;
+    assert!((call.ev - expected).abs() < 1e-9, "{} vs {}", call.ev, expected);
+}
+
+/// 가능하면 항상 폴드하는 상대
+struct AlwaysFolds;
+
+impl OpponentActionModel for AlwaysFolds {
+    fn action_probabilities(&self, _observed: &crate::game::observation::ObservedState, actions: &[Act]) -> Vec<f64> {
+        actions.iter().map(|action| if *action == Act::Fold { 1.0 } else { 0.0 }).collect()
+    }
+}
+
+#[test]
+fn test_opponent_model_drives_simulated_opponents() {
+    let state = State::new_hand([50, 100], [1000; 6], 2);
+    let calculator = EVCalculator::new(EVConfig {
+        sample_count: 50,
+        ..EVConfig::default()
+    })
+    .with_opponent_model(AlwaysFolds);
+
+    let evs = calculator.calculate_action_evs(&state);
+    let ev_of = |action: Act| evs.iter().find(|ev| ev.action == action).unwrap().ev;
+
+    // 레이즈하면 빅블라인드가 항상 폴드해 빅블라인드를 가져감
+    assert_eq!(ev_of(Act::Raise(0)), 100.0);
+    assert_eq!(ev_of(Act::Fold), -50.0);
+}
+
+#[test]
+fn test_ev_by_size_spans_min_raise_to_all_in() {
+    let state = State::new_hand([50, 100], [10_000; 6], 2);
+    let calculator = EVCalculator::new(EVConfig {
+        sample_count: 200,
+        max_depth: 5,
+        seed: Some(3),
+        ..EVConfig::default()
+    });
+
+    let curve = calculator.ev_by_size(&state, 0, 20);
+    assert_eq!(curve.len(), 20);
+    // 빅블라인드 100을 마주한 최소 레이즈는 200, 최대는 스몰 블라인드의 올인 10,000
+    assert_eq!(curve.first().unwrap().0, 200);
+    assert_eq!(curve.last().unwrap().0, 10_000);
+    assert!(curve.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert!(curve.iter().all(|(_, ev, std_error)| ev.is_finite() && *std_error >= 0.0));
+
+    // 히어로 차례가 아니면 곡선 없음
+    assert!(calculator.ev_by_size(&state, 1, 20).is_empty());
+}
+
+#[test]
+fn test_sizing_curve_is_flat_against_opponent_who_always_folds() {
+    let state = State::new_hand([50, 100], [10_000; 6], 2);
+    let calculator = EVCalculator::new(EVConfig {
+        sample_count: 200,
+        ..EVConfig::default()
+    })
+    .with_opponent_model(AlwaysFolds);
+
+    let curve = calculator.sizing_curve(&state, 0, 10).unwrap();
+    assert_eq!(curve.points.len(), 10);
+    // 어떤 크기든 빅블라인드만 가져오므로 큰 크기가 EV를 늘리지 않음 (노이즈 범위 안에서 평평)
+    let noise = curve.points.iter().map(|point| 2.0 * point.std_error).fold(1e-9, f64::max);
+    for pair in curve.points.windows(2) {
+        assert!(pair[1].ev <= pair[0].ev + noise, "{:?}", curve.points);
+    }
+    assert!((curve.best.ev - 100.0).abs() <= noise);
+    assert!(curve.best_interval.0 <= curve.best.ev && curve.best.ev <= curve.best_interval.1);
+}
+
+#[test]
+fn test_pairs_only_villain_range_lowers_bluff_catcher_ev() {
+    use crate::game::opponent_deal::OpponentDealModel;
+    use crate::interop::pio::parse_range;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    let mut state = marginal_river_call_state();
+    state.hole[0] = [0, 25]; // As Kh (에이스 하이)
+    let call_ev = |model: OpponentDealModel| {
+        let state = State {
+            opponent_deal: Some(Arc::new(model)),
+            ..state.clone()
+        };
+        let calculator = EVCalculator::new(EVConfig {
+            sample_count: 2000,
+            max_depth: 5,
+            seed: Some(9),
+            ..EVConfig::default()
+        });
+        calculator
+            .calculate_action_evs(&state)
+            .into_iter()
+            .find(|ev| ev.action == Act::Call)
+            .expect("콜 가능해야 함")
+            .ev
+    };
+
+    let uniform = call_ev(OpponentDealModel::Uniform);
+    let pairs = parse_range("22,33,44,55,66,77,88,99,TT,JJ,QQ,KK,AA");
+    let pairs_only = call_ev(OpponentDealModel::RangeWeighted(HashMap::from([(1, pairs)])));
+
+    // 포켓 페어는 모두 에이스 하이를 이기므로 콜은 콜 금액만큼 잃음
+    assert!((pairs_only + 30.0).abs() < 1e-9, "{}", pairs_only);
+    assert!(uniform > pairs_only + 50.0, "{} vs {}", uniform, pairs_only);
+}
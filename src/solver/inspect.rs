@@ -0,0 +1,146 @@
+// 학습 상태 점검용 노드 조회
+// 리그렛이 폭주하거나 전략이 균일 분포에서 움직이지 않을 때, 디버거 없이
+// 개별 정보 집합의 누적 값과 전략을 확인하고 이상한 노드를 빠르게 찾기 위한 도구
+
+use crate::solver::cfr_core::{Game, Node, Trainer};
+use serde::Serialize;
+
+/// 한 정보 집합 노드의 학습 상태
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodeInspection<K> {
+    /// 정보 집합 키
+    pub info_key: K,
+    /// 액션별 누적 리그렛
+    pub regrets: Vec<f64>,
+    /// 액션별 누적 전략 합계
+    pub strategy_sums: Vec<f64>,
+    /// 누적 업데이트 횟수
+    pub visits: u64,
+    /// 현재 리그렛으로 계산한 전략 (regret matching+)
+    pub current_strategy: Vec<f64>,
+    /// 평균 전략
+    pub average_strategy: Vec<f64>,
+    /// 누적 리그렛 절댓값의 최댓값
+    pub max_abs_regret: f64,
+    /// 평균 전략의 엔트로피 (자연로그, 균일 분포에서 최대)
+    pub strategy_entropy: f64,
+}
+
+impl<K: Serialize> NodeInspection<K> {
+    /// JSON 형식으로 렌더링 (CLI 출력, WASM 브리지용)
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("노드 조회 결과는 항상 JSON으로 직렬화 가능")
+    }
+}
+
+/// 이상 노드를 찾을 때 정렬 기준
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum NodeMetric {
+    /// 누적 리그렛 절댓값의 최댓값 (리그렛 폭주)
+    MaxAbsRegret,
+    /// 누적 업데이트 횟수
+    VisitCount,
+    /// 평균 전략의 엔트로피 (균일 분포에 머문 노드일수록 큼)
+    StrategyEntropy,
+}
+
+impl NodeMetric {
+    /// 노드의 지표 값
+    pub fn value(&self, node: &Node) -> f64 {
+        match self {
+            NodeMetric::MaxAbsRegret => max_abs_regret(node),
+            NodeMetric::VisitCount => node.visits() as f64,
+            NodeMetric::StrategyEntropy => entropy(&node.average()),
+        }
+    }
+}
+
+fn max_abs_regret(node: &Node) -> f64 {
+    node.regrets().iter().fold(0.0, |max, regret| max.max(regret.abs()))
+}
+
+fn entropy(strategy: &[f64]) -> f64 {
+    strategy.iter().filter(|&&p| p > 0.0).map(|&p| p * (1.0 / p).ln()).sum()
+}
+
+impl<G: Game> Trainer<G> {
+    /// 정보 집합 노드의 학습 상태 조회 (학습되지 않은 키면 None)
+    pub fn inspect(&self, info_key: G::InfoKey) -> Option<NodeInspection<G::InfoKey>> {
+        let node = self.nodes.get(&info_key)?;
+        let average_strategy = node.average();
+        Some(NodeInspection {
+            info_key,
+            regrets: node.regrets().to_vec(),
+            strategy_sums: node.strategy_sums().to_vec(),
+            visits: node.visits(),
+            current_strategy: node.strategy(),
+            max_abs_regret: max_abs_regret(node),
+            strategy_entropy: entropy(&average_strategy),
+            average_strategy,
+        })
+    }
+}
+
+impl<G: Game> Trainer<G>
+where
+    G::InfoKey: Ord,
+{
+    /// 지표 값이 큰 순서로 상위 `k`개 노드의 (키, 지표 값)
+    ///
+    /// 값이 같으면 키 오름차순이라 결과가 실행마다 같습니다.
+    pub fn top_nodes_by(&self, metric: NodeMetric, k: usize) -> Vec<(G::InfoKey, f64)> {
+        let mut nodes: Vec<_> = self.nodes.iter().map(|(&key, node)| (key, metric.value(node))).collect();
+        nodes.sort_unstable_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        nodes.truncate(k);
+        nodes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::holdem::State;
+
+    fn trained() -> (Trainer<State>, State) {
+        let root = State::new_hand([10, 20], [1000; 6], 2);
+        let mut trainer = Trainer::<State>::new();
+        trainer.set_seed(7);
+        trainer.run(vec![root.clone()], 5);
+        (trainer, root)
+    }
+
+    #[test]
+    fn test_inspect_preflop_root() {
+        let (trainer, root) = trained();
+        let key = State::info_key(&root, root.to_act);
+        let inspection = trainer.inspect(key).expect("루트 정보 집합은 학습되어 있어야 함");
+
+        let actions = State::legal_actions(&root).len();
+        assert_eq!(inspection.regrets.len(), actions);
+        assert_eq!(inspection.strategy_sums.len(), actions);
+        assert_eq!(inspection.current_strategy.len(), actions);
+        assert_eq!(inspection.average_strategy.len(), actions);
+        assert!(inspection.visits > 0);
+        assert!((inspection.average_strategy.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(inspection.strategy_entropy <= (actions as f64).ln() + 1e-9);
+
+        let json: serde_json::Value = serde_json::from_str(&inspection.to_json()).unwrap();
+        assert_eq!(json["info_key"], key);
+        assert_eq!(json["regrets"].as_array().unwrap().len(), actions);
+        assert!(trainer.inspect(u64::MAX).is_none());
+    }
+
+    #[test]
+    fn test_top_nodes_by_visits_is_sorted_and_has_root() {
+        let (trainer, root) = trained();
+        let top = trainer.top_nodes_by(NodeMetric::VisitCount, 10);
+
+        assert_eq!(top.len(), trainer.nodes.len().min(10));
+        assert!(top.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(top.iter().any(|&(key, _)| key == State::info_key(&root, root.to_act)));
+        assert_eq!(top, trainer.top_nodes_by(NodeMetric::VisitCount, 10));
+
+        let regrets = trainer.top_nodes_by(NodeMetric::MaxAbsRegret, 3);
+        assert_eq!(regrets[0].1, trainer.inspect(regrets[0].0).unwrap().max_abs_regret);
+    }
+}
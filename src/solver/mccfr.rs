@@ -2,8 +2,114 @@
 // 기존 CFR의 게임 트리 폭발 문제를 해결하기 위해 샘플링 기반 CFR 사용
 
 use fxhash::FxHashMap as HashMap;
-use rand::rngs::ThreadRng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
 use crate::cfr_core::{Game, Node, GameState};
+use crate::game::rng::entropy_seed;
+
+/// 상대 노드 탐색 확률(epsilon) 스케줄
+///
+/// 반복 `t`(0부터)에서 상대는 epsilon 확률로 균등 분포에서, 나머지는 현재 전략에서 액션을 고릅니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExplorationSchedule {
+    /// 항상 같은 epsilon
+    Constant(f64),
+    /// 첫 반복 `start`에서 마지막 반복 `end`까지 선형 변화
+    Linear { start: f64, end: f64 },
+    /// `1 / sqrt(t + 1)`
+    InverseSqrt,
+}
+
+impl ExplorationSchedule {
+    /// 전체 `total_iterations` 중 `iteration`번째 반복의 epsilon (0~1로 제한)
+    pub fn epsilon(&self, iteration: usize, total_iterations: usize) -> f64 {
+        let epsilon = match *self {
+            Self::Constant(epsilon) => epsilon,
+            Self::Linear { start, end } => {
+                let progress = if total_iterations > 1 {
+                    iteration as f64 / (total_iterations - 1) as f64
+                } else {
+                    0.0
+                };
+                start + (end - start) * progress.min(1.0)
+            }
+            Self::InverseSqrt => 1.0 / ((iteration + 1) as f64).sqrt(),
+        };
+        if epsilon.is_finite() { epsilon.clamp(0.0, 1.0) } else { 0.0 }
+    }
+}
+
+/// 여러 루트에 반복을 나누는 방식 (`MCCFRTrainer::run_weighted`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootAllocation {
+    /// 주어진 루트 가중치 그대로 샘플링
+    Fixed,
+    /// 체크포인트마다 최근 리그렛이 많이 쌓이는 루트로 가중치를 옮김
+    ///
+    /// 루트별 방문당 양의 리그렛을 최근 `window`개 체크포인트 구간에 걸쳐 평균하고,
+    /// 주어진 가중치 × 리그렛 비율로 다음 구간의 가중치를 정합니다. 모든 루트는 최소
+    /// `min_share`(루트 수 × `min_share`가 1을 넘으면 균등 몫)를 받아 굶지 않습니다.
+    Adaptive {
+        /// 가중치를 다시 계산하는 간격 (반복 수)
+        checkpoint_every: usize,
+        /// 리그렛 비율을 평균하는 최근 체크포인트 구간 수
+        window: usize,
+        /// 루트별 최소 샘플링 비율
+        min_share: f64,
+    },
+}
+
+impl RootAllocation {
+    /// 100 반복마다, 최근 5구간, 루트당 최소 5%
+    pub fn adaptive() -> Self {
+        Self::Adaptive {
+            checkpoint_every: 100,
+            window: 5,
+            min_share: 0.05,
+        }
+    }
+}
+
+/// 배분 체크포인트 하나
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocationCheckpoint {
+    /// 체크포인트까지 끝난 반복 수
+    pub iteration: usize,
+    /// 윈도우 안의 루트별 방문당 평균 양의 리그렛
+    pub regret_rates: Vec<f64>,
+    /// 다음 구간에 쓸 루트별 샘플링 가중치 (합 1)
+    pub weights: Vec<f64>,
+}
+
+/// `run_weighted` 학습 지표 (계산이 어느 루트에 쓰였는지)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AllocationMetrics {
+    /// 루트별 실제 학습 반복 수
+    pub iterations_per_root: Vec<usize>,
+    /// 체크포인트별 가중치 변화 (`RootAllocation::Fixed`면 비어 있음)
+    pub checkpoints: Vec<AllocationCheckpoint>,
+}
+
+/// 음수/비정상 값을 0으로 보고 합이 1이 되도록 정규화 (합이 0이면 균등)
+fn normalized(weights: &[f64]) -> Vec<f64> {
+    let clean: Vec<f64> = weights.iter().map(|&w| if w.is_finite() { w.max(0.0) } else { 0.0 }).collect();
+    let total: f64 = clean.iter().sum();
+    if total > 0.0 {
+        clean.iter().map(|w| w / total).collect()
+    } else {
+        vec![1.0 / weights.len().max(1) as f64; weights.len()]
+    }
+}
+
+/// 기본 가중치 × 리그렛 비율로 새 가중치 계산 (루트별 최소 몫 보장, 합 1)
+fn reallocate(base: &[f64], regret_rates: &[f64], min_share: f64) -> Vec<f64> {
+    let n = base.len().max(1) as f64;
+    let floor = if min_share.is_finite() { min_share.clamp(0.0, 1.0 / n) } else { 0.0 };
+    let target: Vec<f64> = base.iter().zip(regret_rates).map(|(w, rate)| w * rate).collect();
+    // 모든 루트의 리그렛이 0이면 기본 가중치로 복귀
+    let target = if target.iter().sum::<f64>() > 0.0 { normalized(&target) } else { base.to_vec() };
+    target.iter().map(|t| floor + (1.0 - n * floor) * t).collect()
+}
 
 /// Monte Carlo CFR 학습기
 /// 
@@ -12,6 +118,12 @@ use crate::cfr_core::{Game, Node, GameState};
 pub struct MCCFRTrainer<G: Game> {
     pub nodes: HashMap<G::InfoKey, Node>,
     sample_rate: f64,  // 액션 샘플링 비율 (0.0~1.0)
+    /// 설정되면 상대 노드에서 액션 하나만 샘플링 (external sampling + epsilon 탐색)
+    exploration: Option<ExplorationSchedule>,
+    /// 시드 고정 난수 생성기 (None이면 실행마다 엔트로피로 시드)
+    seeded_rng: Option<ChaCha12Rng>,
+    /// 현재 순회에서 히어로 노드에 더한 양의 리그렛 합 (`run_weighted` 배분용)
+    regret_added: f64,
 }
 
 impl<G: Game> MCCFRTrainer<G> {
@@ -23,23 +135,50 @@ impl<G: Game> MCCFRTrainer<G> {
         Self {
             nodes: HashMap::default(),
             sample_rate: sample_rate.clamp(0.1, 1.0),
+            exploration: None,
+            seeded_rng: None,
+            regret_added: 0.0,
         }
     }
+
+    /// 상대 노드를 epsilon-greedy 샘플링으로 탐색
+    ///
+    /// 상대 노드에서는 모든 액션을 펼치는 대신 `q = (1 - epsilon) * 전략 + epsilon * 균등`에서
+    /// 액션 하나를 뽑고, 반환값과 도달 확률에 중요도 가중치 `전략 / q`를 곱해 리그렛 추정이
+    /// 편향되지 않게 합니다. `epsilon`이 0이면 일반 external sampling이며, 값이 클수록
+    /// 상대가 드물게 고르는 라인(예: 4벳)도 자주 방문합니다. `q >= (1 - epsilon) * 전략`이므로
+    /// 가중치는 `epsilon < 1`에서 `1 / (1 - epsilon)`을 넘지 않습니다.
+    pub fn with_exploration(mut self, schedule: ExplorationSchedule) -> Self {
+        self.exploration = Some(schedule);
+        self
+    }
+
+    /// 샘플링 시드 고정 (같은 시드와 설정이면 같은 전략)
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seeded_rng = Some(ChaCha12Rng::seed_from_u64(seed));
+    }
     
     /// MCCFR 학습 실행
     pub fn run(&mut self, roots: Vec<G::State>, iterations: usize) {
         println!("🎲 Monte Carlo CFR 학습 시작 - {} 시나리오, {} 반복, {:.1}% 샘플링", 
                  roots.len(), iterations, self.sample_rate * 100.0);
         
+        // 시드가 없으면 이번 실행 동안 쓸 RNG를 엔트로피로 한 번 시드
+        let seeded = self.seeded_rng.is_some();
+        let mut rng = self
+            .seeded_rng
+            .take()
+            .unwrap_or_else(|| ChaCha12Rng::seed_from_u64(entropy_seed()));
+        
         for iteration in 0..iterations {
             if iteration % 100 == 0 {
                 println!("  반복 {}/{} (노드: {})", iteration + 1, iterations, self.nodes.len());
             }
             
+            let epsilon = self.exploration.map(|schedule| schedule.epsilon(iteration, iterations));
             for root in &roots {
                 for hero in 0..G::N_PLAYERS {
-                    let mut rng = rand::thread_rng();
-                    self.mccfr(root, hero, 1.0, &mut rng, 0);
+                    self.mccfr(root, hero, 1.0, epsilon, &mut rng, 0);
                 }
             }
             
@@ -50,14 +189,113 @@ impl<G: Game> MCCFRTrainer<G> {
                          self.nodes.len());
             }
         }
+        if seeded {
+            self.seeded_rng = Some(rng);
+        }
         
         println!("✅ MCCFR 학습 완료 - {} 개 노드 생성", self.nodes.len());
     }
     
+    /// 가중치를 둔 여러 루트로 학습 (반복마다 루트 하나를 샘플링)
+    ///
+    /// `roots`는 (루트, 가중치) 목록이고 가중치는 정규화해서 씁니다 (합이 0이면 균등).
+    /// `RootAllocation::Adaptive`면 일찍 수렴한 루트에서 리그렛이 남은 루트로 반복을 옮깁니다.
+    /// 루트 샘플링도 학습 RNG를 쓰므로 시드를 고정하면 배분까지 재현됩니다.
+    pub fn run_weighted(
+        &mut self,
+        roots: &[(G::State, f64)],
+        iterations: usize,
+        allocation: RootAllocation,
+    ) -> AllocationMetrics {
+        let mut metrics = AllocationMetrics {
+            iterations_per_root: vec![0; roots.len()],
+            checkpoints: Vec::new(),
+        };
+        if roots.is_empty() {
+            return metrics;
+        }
+
+        let seeded = self.seeded_rng.is_some();
+        let mut rng = self
+            .seeded_rng
+            .take()
+            .unwrap_or_else(|| ChaCha12Rng::seed_from_u64(entropy_seed()));
+
+        let base = normalized(&roots.iter().map(|(_, weight)| *weight).collect::<Vec<_>>());
+        let mut weights = base.clone();
+        // 체크포인트 구간별 (루트별 리그렛 합, 루트별 방문 수)
+        let mut window: std::collections::VecDeque<(Vec<f64>, Vec<usize>)> = std::collections::VecDeque::new();
+        let mut interval = (vec![0.0; roots.len()], vec![0; roots.len()]);
+
+        for iteration in 0..iterations {
+            let epsilon = self.exploration.map(|schedule| schedule.epsilon(iteration, iterations));
+            let mut roll = rng.gen::<f64>();
+            let index = weights
+                .iter()
+                .position(|&w| {
+                    roll -= w;
+                    roll < 0.0
+                })
+                .unwrap_or(roots.len() - 1);
+
+            self.regret_added = 0.0;
+            for hero in 0..G::N_PLAYERS {
+                self.mccfr(&roots[index].0, hero, 1.0, epsilon, &mut rng, 0);
+            }
+            interval.0[index] += self.regret_added;
+            interval.1[index] += 1;
+            metrics.iterations_per_root[index] += 1;
+
+            let RootAllocation::Adaptive { checkpoint_every, window: window_len, min_share } = allocation else {
+                continue;
+            };
+            if (iteration + 1) % checkpoint_every.max(1) != 0 {
+                continue;
+            }
+            window.push_back(std::mem::replace(&mut interval, (vec![0.0; roots.len()], vec![0; roots.len()])));
+            while window.len() > window_len.max(1) {
+                window.pop_front();
+            }
+
+            // 윈도우 안에서 방문하지 않은 루트는 지난 비율을 유지
+            let previous = metrics.checkpoints.last().map(|checkpoint| checkpoint.regret_rates.clone());
+            let regret_rates: Vec<f64> = (0..roots.len())
+                .map(|root| {
+                    let regret: f64 = window.iter().map(|(regret, _)| regret[root]).sum();
+                    let visits: usize = window.iter().map(|(_, visits)| visits[root]).sum();
+                    match (visits, &previous) {
+                        (0, Some(rates)) => rates[root],
+                        (0, None) => 0.0,
+                        _ => regret / visits as f64,
+                    }
+                })
+                .collect();
+            weights = reallocate(&base, &regret_rates, min_share);
+            metrics.checkpoints.push(AllocationCheckpoint {
+                iteration: iteration + 1,
+                regret_rates,
+                weights: weights.clone(),
+            });
+        }
+        if seeded {
+            self.seeded_rng = Some(rng);
+        }
+        metrics
+    }
+    
     /// Monte Carlo CFR 재귀 함수
     /// 
     /// 각 플레이어 노드에서 모든 액션을 탐색하는 대신 일부만 샘플링합니다.
-    fn mccfr(&mut self, state: &G::State, hero: usize, prob: f64, rng: &mut ThreadRng, depth: usize) -> f64 {
+    /// `epsilon`이 있으면 상대 노드는 액션 하나만 샘플링합니다 (`with_exploration` 참고).
+    fn mccfr<R: Rng>(
+        &mut self,
+        state: &G::State,
+        hero: usize,
+        prob: f64,
+        epsilon: Option<f64>,
+        rng: &mut R,
+        depth: usize,
+    ) -> f64 {
         // 깊이 제한 (MCCFR은 일반 CFR보다 더 깊이 탐색 가능)
         if depth > 50 {
             return 0.0;
@@ -82,6 +320,23 @@ impl<G: Game> MCCFRTrainer<G> {
                 let node = self.nodes.get(&info_key).unwrap();
                 node.strategy()
             };
+
+            if let (Some(explore), true) = (epsilon, player != hero) {
+                let uniform = 1.0 / actions.len() as f64;
+                let sampling: Vec<f64> = strategy.iter().map(|p| (1.0 - explore) * p + explore * uniform).collect();
+                let mut roll = rng.gen::<f64>() * sampling.iter().sum::<f64>();
+                let i = sampling
+                    .iter()
+                    .position(|&q| {
+                        roll -= q;
+                        roll < 0.0
+                    })
+                    .unwrap_or(actions.len() - 1);
+                // 샘플링 분포와 전략의 차이를 보정하는 중요도 가중치
+                let weight = strategy[i] / sampling[i];
+                let next_state = G::next_state(state, actions[i]);
+                return weight * self.mccfr(&next_state, hero, prob * weight, epsilon, rng, depth + 1);
+            }
             
             // 액션 샘플링: 모든 액션 대신 일부만 탐색
             let sample_size = ((actions.len() as f64 * self.sample_rate).ceil() as usize).max(1);
@@ -98,7 +353,7 @@ impl<G: Game> MCCFRTrainer<G> {
             for &i in &sampled_indices {
                 let action = actions[i];
                 let next_state = G::next_state(state, action);
-                utilities[i] = self.mccfr(&next_state, hero, prob * strategy[i], rng, depth + 1);
+                utilities[i] = self.mccfr(&next_state, hero, prob * strategy[i], epsilon, rng, depth + 1);
                 node_util += strategy[i] * utilities[i];
             }
             
@@ -108,8 +363,10 @@ impl<G: Game> MCCFRTrainer<G> {
                 for &i in &sampled_indices {
                     let regret = utilities[i] - node_util;
                     node.update_regret(i, prob * regret);
+                    self.regret_added += (prob * regret).max(0.0);
                     node.update_strategy(i, prob * strategy[i]);
                 }
+                node.record_visit();
             }
             
             node_util
@@ -119,8 +376,260 @@ impl<G: Game> MCCFRTrainer<G> {
                 G::util(state, hero)
             } else {
                 let chance_state = G::apply_chance(state, rng);
-                self.mccfr(&chance_state, hero, prob, rng, depth + 1)
+                self.mccfr(&chance_state, hero, prob, epsilon, rng, depth + 1)
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::leduc::{LeducAction, LeducState};
+
+    /// 1번이 먼저 폴드/벳을 고르고, 벳하면 0번이 좋은 선택/나쁜 선택을 고르는 게임
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum RareBranch {
+        Start,
+        Bet,
+        Done(f64),
+    }
+
+    impl GameState for RareBranch {
+        fn is_terminal(&self) -> bool {
+            matches!(self, Self::Done(_))
+        }
+
+        fn is_chance_node(&self) -> bool {
+            false
+        }
+    }
+
+    impl Game for RareBranch {
+        type State = RareBranch;
+        type Action = u8;
+        type InfoKey = u8;
+
+        const N_PLAYERS: usize = 2;
+
+        fn current_player(s: &Self::State) -> Option<usize> {
+            match s {
+                Self::Start => Some(1),
+                Self::Bet => Some(0),
+                Self::Done(_) => None,
+            }
+        }
+
+        fn legal_actions(s: &Self::State) -> Vec<u8> {
+            if s.is_terminal() { vec![] } else { vec![0, 1] }
+        }
+
+        fn next_state(s: &Self::State, a: u8) -> Self::State {
+            match (s, a) {
+                (Self::Start, 0) => Self::Done(1.0),
+                (Self::Start, _) => Self::Bet,
+                (_, 0) => Self::Done(2.0),
+                (_, _) => Self::Done(-2.0),
+            }
+        }
+
+        fn apply_chance<R: Rng>(s: &Self::State, _r: &mut R) -> Self::State {
+            *s
+        }
+
+        /// 0번 플레이어 기준 값 (제로섬)
+        fn util(s: &Self::State, hero: usize) -> f64 {
+            let value = if let Self::Done(value) = s { *value } else { 0.0 };
+            if hero == 0 { value } else { -value }
+        }
+
+        fn info_key(s: &Self::State, _v: usize) -> u8 {
+            match s {
+                Self::Start => 0,
+                _ => 1,
+            }
+        }
+    }
+
+    fn rare_branch_visits(schedule: ExplorationSchedule) -> u64 {
+        let mut trainer = MCCFRTrainer::<RareBranch>::new(1.0).with_exploration(schedule);
+        trainer.set_seed(3);
+        // 1번이 99% 폴드하도록 리그렛을 미리 심어 둠
+        let mut seeded = Node::new(2, vec![1.0; 2]);
+        seeded.update_regret(0, 99_000.0);
+        seeded.update_regret(1, 1_000.0);
+        trainer.nodes.insert(0, seeded);
+
+        trainer.run(vec![RareBranch::Start], 2_000);
+        trainer.nodes.get(&1).map_or(0, |node| node.visits())
+    }
+
+    #[test]
+    fn test_exploration_visits_rare_branch_more_often() {
+        let without = rare_branch_visits(ExplorationSchedule::Constant(0.0));
+        let with = rare_branch_visits(ExplorationSchedule::Constant(0.5));
+        assert!(without < 100, "{}", without);
+        assert!(with > 5 * without.max(1), "{} vs {}", with, without);
+        assert!(rare_branch_visits(ExplorationSchedule::InverseSqrt) > without);
+
+        assert_eq!(ExplorationSchedule::Linear { start: 0.4, end: 0.0 }.epsilon(0, 5), 0.4);
+        assert_eq!(ExplorationSchedule::Linear { start: 0.4, end: 0.0 }.epsilon(4, 5), 0.0);
+        assert_eq!(ExplorationSchedule::InverseSqrt.epsilon(3, 10), 0.5);
+        assert_eq!(ExplorationSchedule::Constant(1.5).epsilon(0, 1), 1.0);
+    }
+
+    #[test]
+    fn test_exploration_keeps_strategies_unbiased() {
+        use crate::cfr_core::Trainer;
+        use crate::game::leduc::{average_strategy_value, exploitability, LEDUC_GAME_VALUE};
+
+        // Leduc 평균 전략을 정확히 평가하기 위해 노드를 일반 학습기로 옮김
+        let train = |schedule| {
+            let mut mccfr = MCCFRTrainer::<LeducState>::new(1.0).with_exploration(schedule);
+            mccfr.set_seed(17);
+            mccfr.run(vec![LeducState::new()], 30_000);
+            let mut trainer = Trainer::<LeducState>::new();
+            trainer.nodes = mccfr.nodes;
+            trainer
+        };
+        let plain = train(ExplorationSchedule::Constant(0.0));
+        let explored = train(ExplorationSchedule::Constant(0.3));
+
+        // 정보 집합별 전략은 균형이 여러 개라 다를 수 있으므로 게임 값과 착취 가능성으로 비교
+        for trainer in [&plain, &explored] {
+            let value = average_strategy_value(trainer);
+            assert!((value - LEDUC_GAME_VALUE).abs() < 0.05, "{}", value);
+        }
+        let (plain_exploit, explored_exploit) = (exploitability(&plain), exploitability(&explored));
+        assert!(plain_exploit < 0.3 && explored_exploit < 0.3, "{} vs {}", plain_exploit, explored_exploit);
+        assert!((plain_exploit - explored_exploit).abs() < 0.1, "{} vs {}", plain_exploit, explored_exploit);
+    }
+
+    /// 한 번의 결정으로 끝나는 루트와 Leduc 루트를 함께 학습하는 게임
+    #[derive(Clone, Debug)]
+    enum TwoRoots {
+        /// 0번이 1점(콜, 정답)과 0점(레이즈) 중 하나를 고름
+        Trivial,
+        TrivialDone(f64),
+        Leduc(LeducState),
+    }
+
+    /// `Trivial` 정보 집합 키 (Leduc 키와 겹치지 않음)
+    const TRIVIAL_KEY: u64 = u64::MAX;
+
+    impl GameState for TwoRoots {
+        fn is_terminal(&self) -> bool {
+            match self {
+                Self::Trivial => false,
+                Self::TrivialDone(_) => true,
+                Self::Leduc(state) => state.is_terminal(),
+            }
+        }
+
+        fn is_chance_node(&self) -> bool {
+            matches!(self, Self::Leduc(state) if state.is_chance_node())
+        }
+    }
+
+    impl Game for TwoRoots {
+        type State = TwoRoots;
+        type Action = LeducAction;
+        type InfoKey = u64;
+
+        const N_PLAYERS: usize = 2;
+
+        fn current_player(s: &Self::State) -> Option<usize> {
+            match s {
+                Self::Trivial => Some(0),
+                Self::TrivialDone(_) => None,
+                Self::Leduc(state) => LeducState::current_player(state),
+            }
+        }
+
+        fn legal_actions(s: &Self::State) -> Vec<Self::Action> {
+            match s {
+                Self::Trivial => vec![LeducAction::Call, LeducAction::Raise],
+                Self::TrivialDone(_) => vec![],
+                Self::Leduc(state) => LeducState::legal_actions(state),
+            }
+        }
+
+        fn next_state(s: &Self::State, a: Self::Action) -> Self::State {
+            match s {
+                Self::Trivial => Self::TrivialDone(if a == LeducAction::Call { 1.0 } else { 0.0 }),
+                Self::TrivialDone(value) => Self::TrivialDone(*value),
+                Self::Leduc(state) => Self::Leduc(LeducState::next_state(state, a)),
+            }
+        }
+
+        fn apply_chance<R: Rng>(s: &Self::State, r: &mut R) -> Self::State {
+            match s {
+                Self::Leduc(state) => Self::Leduc(LeducState::apply_chance(state, r)),
+                _ => s.clone(),
+            }
+        }
+
+        fn util(s: &Self::State, hero: usize) -> f64 {
+            match s {
+                Self::TrivialDone(value) if hero == 0 => *value,
+                Self::TrivialDone(value) => -value,
+                Self::Leduc(state) => LeducState::util(state, hero),
+                Self::Trivial => 0.0,
+            }
+        }
+
+        fn info_key(s: &Self::State, v: usize) -> u64 {
+            match s {
+                Self::Leduc(state) => LeducState::info_key(state, v),
+                _ => TRIVIAL_KEY,
+            }
+        }
+    }
+
+    fn train_two_roots(allocation: RootAllocation) -> (MCCFRTrainer<TwoRoots>, AllocationMetrics) {
+        let mut trainer = MCCFRTrainer::<TwoRoots>::new(1.0).with_exploration(ExplorationSchedule::Constant(0.1));
+        trainer.set_seed(2434);
+        let roots = [(TwoRoots::Trivial, 1.0), (TwoRoots::Leduc(LeducState::new()), 1.0)];
+        let metrics = trainer.run_weighted(&roots, 6_000, allocation);
+        (trainer, metrics)
+    }
+
+    #[test]
+    fn test_adaptive_allocation_moves_iterations_to_deep_root() {
+        let (adaptive, metrics) = train_two_roots(RootAllocation::adaptive());
+        assert_eq!(metrics.iterations_per_root.iter().sum::<usize>(), 6_000);
+        assert_eq!(metrics.checkpoints.len(), 60);
+        for checkpoint in &metrics.checkpoints {
+            assert!((checkpoint.weights.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+            assert!(checkpoint.weights.iter().all(|&w| w >= 0.05 - 1e-12), "{:?}", checkpoint);
+        }
+        // 후반부 가중치는 대부분 Leduc 루트
+        for checkpoint in &metrics.checkpoints[30..] {
+            assert!(checkpoint.weights[1] > 0.9, "{:?}", checkpoint);
+        }
+        assert!(metrics.iterations_per_root[1] > 5 * metrics.iterations_per_root[0], "{:?}", metrics.iterations_per_root);
+
+        // 같은 시드면 배분까지 같음
+        assert_eq!(train_two_roots(RootAllocation::adaptive()).1, metrics);
+
+        // 한 번의 결정 루트는 균등 배분만큼 정답을 고름
+        let (uniform, fixed_metrics) = train_two_roots(RootAllocation::Fixed);
+        assert!(fixed_metrics.checkpoints.is_empty());
+        let correct = |trainer: &MCCFRTrainer<TwoRoots>| {
+            let sums = trainer.nodes[&TRIVIAL_KEY].strategy_sums();
+            sums[0] / sums.iter().sum::<f64>()
+        };
+        assert!(correct(&uniform) > 0.99, "{}", correct(&uniform));
+        assert!(correct(&adaptive) > 0.99, "{}", correct(&adaptive));
+    }
+
+    #[test]
+    fn test_reallocation_keeps_floor_and_normalization() {
+        assert_eq!(reallocate(&[0.5, 0.5], &[0.0, 0.0], 0.1), vec![0.5, 0.5]);
+        let weights = reallocate(&[0.5, 0.5], &[0.0, 3.0], 0.1);
+        assert!((weights[0] - 0.1).abs() < 1e-12 && (weights[1] - 0.9).abs() < 1e-12, "{:?}", weights);
+        // 최소 몫이 너무 크면 균등
+        assert_eq!(reallocate(&[0.25, 0.75], &[1.0, 0.0], 0.9), vec![0.5, 0.5]);
+        assert_eq!(normalized(&[f64::NAN, -1.0]), vec![0.5, 0.5]);
+    }
+}
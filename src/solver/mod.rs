@@ -4,14 +4,53 @@
 //! - Game 트레잇과 함께하는 핵심 CFR 구현
 //! - 대규모 게임 트리를 위한 몬테카를로 CFR
 //! - 학습 및 전략 계산
+//! - 디버깅용 게임 트리 덤프 (DOT/JSON)
+//! - 서빙 시점 CFR/휴리스틱 전략 블렌딩
+//! - 중복 제거된 서빙용 압축 전략 테이블
+//! - 저장 산출물(스냅샷, 체크포인트, 전략 테이블, 프리셋 팩, NDJSON)의 호환성 지문과 키 마이그레이션
+//! - 외부 분석 도구용 노드 맵 NDJSON 스트리밍 내보내기/가져오기
+//! - 학습 전 게임 트리 크기와 학습 비용 추정
+//! - 작은 게임에서 추상화별 EV 손실/노드 수/학습 시간 측정
+//! - 실전용 전략 정제 (낮은 빈도 제거 / 순수 전략화)
+//! - 학습 상태 점검용 노드별 리그렛/전략 조회
+//! - 학습 전략의 이론 기준(MDF, 블러프 비율) 위반 검사
+//! - EV/에퀴티/리솔빙/분석이 함께 쓰는 계산 예산
+//! - 몬테카를로 EV와 정확한 리버 열거의 교차 검증
+//!
+//! MCCFR, 학습 비용 추정, 추상화 품질 측정, 트리 덤프, 노드 조회는 `training` 기능(기본)에서만 빌드됩니다.
 
+#[cfg(feature = "training")]
+pub mod abstraction_eval;
+pub mod blend;
+pub mod budget;
 pub mod cfr_core;
+pub mod compact;
+pub mod compat;
+#[cfg(feature = "training")]
+pub mod estimator;
 pub mod ev_calculator;
+#[cfg(feature = "training")]
+pub mod inspect;
+#[cfg(feature = "training")]
 pub mod mccfr;
+pub mod ndjson;
+pub mod purification;
+pub mod sanity;
+#[cfg(feature = "training")]
+pub mod tree_export;
+pub mod validation;
 
 #[cfg(test)]
 mod ev_calculator_tests;
 
 // 자주 사용되는 타입들을 재수출
+pub use blend::*;
+pub use budget::{BudgetExhausted, Budgeted, ComputeBudget};
 pub use cfr_core::*;
+pub use compact::*;
+#[cfg(feature = "training")]
+pub use inspect::{NodeInspection, NodeMetric};
+#[cfg(feature = "training")]
 pub use mccfr::*;
+pub use ndjson::{ExportOptions, ExportReport};
+pub use purification::Purification;
@@ -0,0 +1,384 @@
+// 노드 맵 NDJSON 내보내기/가져오기
+// 수 GB짜리 노드 맵을 JSON 문서 하나로 직렬화하는 대신, 노드마다 JSON 객체 한 줄씩 청크 단위로
+// 흘려보내 Spark/pandas 같은 외부 분석 도구가 바로 읽을 수 있게 합니다. 출력 전체를 메모리에
+// 만들지 않으므로 노드 수와 관계없이 메모리 사용량은 청크 크기 정도로 일정합니다.
+// 첫 줄은 노드 대신 학습기의 호환성 지문을 담은 헤더입니다.
+
+use crate::solver::cfr_core::{Game, InfoKeyFields, Trainer};
+use crate::solver::compact::{CompactStrategyTable, CompactTableBuilder};
+use crate::solver::compat::{CompatibilityFingerprint, MigrationRegistry};
+use crate::solver::purification::Purification;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufRead, Write};
+
+/// 기본 청크 크기 (바이트, 이만큼 쌓일 때마다 writer에 쓰고 flush)
+pub const DEFAULT_EXPORT_CHUNK_BYTES: usize = 1 << 20;
+
+/// NDJSON 내보내기 설정
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExportOptions {
+    /// 이 스트리트의 노드만 내보냄 (None이면 전체, 키에서 스트리트를 읽을 수 없는 노드는 제외)
+    pub street: Option<u8>,
+    /// 최소 방문 횟수
+    pub min_visits: u64,
+    /// 표본 추출 비율 (1.0 이상이면 전체)
+    ///
+    /// 키 해시로 고르므로 같은 시드면 실행마다, 노드 맵 순서와 관계없이 같은 노드가 뽑힙니다.
+    pub sample_rate: f64,
+    /// 표본 추출 시드
+    pub sample_seed: u64,
+    /// 평균 전략에 적용할 정제
+    pub purification: Purification,
+    /// 청크 크기 (바이트)
+    pub chunk_bytes: usize,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            street: None,
+            min_visits: 0,
+            sample_rate: 1.0,
+            sample_seed: 0,
+            purification: Purification::None,
+            chunk_bytes: DEFAULT_EXPORT_CHUNK_BYTES,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// 표본에 포함되는 키인지 여부
+    fn sampled<K: Hash>(&self, key: &K) -> bool {
+        if self.sample_rate >= 1.0 {
+            return true;
+        }
+        let mut hasher = fxhash::FxHasher64::default();
+        self.sample_seed.hash(&mut hasher);
+        key.hash(&mut hasher);
+        let unit = (mix64(hasher.finish()) >> 11) as f64 / (1u64 << 53) as f64;
+        unit < self.sample_rate
+    }
+}
+
+/// splitmix64 최종 섞기 (FxHash 하위 비트 편향 제거)
+fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// 내보내기 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ExportReport {
+    /// 검사한 노드 수
+    pub scanned: u64,
+    /// 내보낸 노드 줄 수 (헤더 제외)
+    pub exported: u64,
+    /// 쓴 바이트 수 (헤더 포함)
+    pub bytes: u64,
+}
+
+/// 첫 줄 헤더 (`{"fingerprint": ...}`)
+#[derive(Serialize, Deserialize)]
+struct HeaderLine {
+    fingerprint: CompatibilityFingerprint,
+}
+
+/// 내보내는 한 줄 (노드 하나)
+#[derive(Serialize)]
+struct ExportLine<'a, K> {
+    info_key: &'a K,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fields: Option<InfoKeyFields>,
+    visits: u64,
+    strategy: Vec<f64>,
+    purification: &'a str,
+}
+
+/// 가져올 때 읽는 필드 (나머지 필드는 무시)
+#[derive(Deserialize)]
+struct ImportLine<K> {
+    info_key: K,
+    strategy: Vec<f64>,
+}
+
+impl<G: Game> Trainer<G>
+where
+    G::InfoKey: Serialize,
+{
+    /// 노드마다 JSON 객체 한 줄씩 NDJSON으로 스트리밍 내보내기
+    ///
+    /// 첫 줄은 `Trainer::fingerprint`를 담은 헤더 `{"fingerprint": {...}}`이고, 이어지는 각 줄은 `info_key`, `fields`(`Game::describe_info_key`로 풀 수 있을 때만), `visits`,
+    /// 정제를 적용한 평균 전략 `strategy`, 적용한 정제 `purification`을 담습니다.
+    /// 노드 맵 순서 그대로 쓰므로 줄 순서는 정해져 있지 않습니다.
+    ///
+    /// 줄을 `chunk_bytes`만큼 모은 뒤 writer에 쓰고 flush하므로, 노드 수와 관계없이
+    /// 추가 메모리는 청크 하나와 줄 하나 크기입니다.
+    pub fn export_ndjson(&self, mut writer: impl Write, options: ExportOptions) -> io::Result<ExportReport> {
+        let purification = options.purification.to_string();
+        let chunk_bytes = options.chunk_bytes.max(1);
+        let mut chunk = Vec::with_capacity(chunk_bytes.min(DEFAULT_EXPORT_CHUNK_BYTES));
+        let mut report = ExportReport::default();
+
+        serde_json::to_writer(&mut chunk, &HeaderLine { fingerprint: self.fingerprint() })?;
+        chunk.push(b'\n');
+
+        for (key, node) in &self.nodes {
+            report.scanned += 1;
+            if node.visits() < options.min_visits || !options.sampled(key) {
+                continue;
+            }
+            let fields = G::describe_info_key(key);
+            if options.street.is_some() && fields.as_ref().and_then(|fields| fields.street) != options.street {
+                continue;
+            }
+
+            let line = ExportLine {
+                info_key: key,
+                fields,
+                visits: node.visits(),
+                strategy: options.purification.apply(&node.average()),
+                purification: &purification,
+            };
+            serde_json::to_writer(&mut chunk, &line)?;
+            chunk.push(b'\n');
+            report.exported += 1;
+
+            if chunk.len() >= chunk_bytes {
+                write_chunk(&mut writer, &mut chunk, &mut report)?;
+            }
+        }
+        write_chunk(&mut writer, &mut chunk, &mut report)?;
+        Ok(report)
+    }
+}
+
+/// 모은 줄을 writer에 쓰고 flush한 뒤 청크 비우기
+fn write_chunk(writer: &mut impl Write, chunk: &mut Vec<u8>, report: &mut ExportReport) -> io::Result<()> {
+    writer.write_all(chunk)?;
+    writer.flush()?;
+    report.bytes += chunk.len() as u64;
+    chunk.clear();
+    Ok(())
+}
+
+impl<K: Eq + Hash + Copy + DeserializeOwned> CompactStrategyTable<K> {
+    /// `Trainer::export_ndjson` 출력을 한 줄씩 읽어 압축 테이블 생성 (빈 줄과 지문 헤더는 무시)
+    ///
+    /// 내보낼 때 정제를 적용했다면 정제된 전략이 들어갑니다. 같은 키가 여러 번 나오면 마지막 줄이 남습니다.
+    pub fn import_ndjson(reader: impl BufRead, tolerance: f32) -> io::Result<Self> {
+        Self::import_ndjson_with_header(reader, tolerance).map(|(_, table)| table)
+    }
+
+    /// 테이블과 첫 줄 헤더의 지문 (헤더가 없는 이전 출력이면 None)
+    fn import_ndjson_with_header(
+        reader: impl BufRead,
+        tolerance: f32,
+    ) -> io::Result<(Option<CompatibilityFingerprint>, Self)> {
+        let mut builder = CompactTableBuilder::new(tolerance);
+        let mut header = None;
+        let mut first = true;
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if std::mem::take(&mut first) {
+                if let Ok(parsed) = serde_json::from_str::<HeaderLine>(&line) {
+                    header = Some(parsed.fingerprint);
+                    continue;
+                }
+            }
+            let parsed: ImportLine<K> = serde_json::from_str(&line).map_err(|error| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("NDJSON {}번째 줄: {}", index + 1, error))
+            })?;
+            builder.insert(parsed.info_key, &parsed.strategy);
+        }
+        Ok((header, builder.finish()))
+    }
+}
+
+impl CompactStrategyTable<u64> {
+    /// 헤더의 지문을 `expected`와 비교하며 NDJSON 가져오기
+    ///
+    /// 버전 구성 요소만 다르면 `registry`의 마이그레이션으로 키를 바꾸고, 맞출 수 없으면
+    /// `IncompatibleArtifact`를 담은 `InvalidData` 에러를 반환합니다. 헤더가 없는 이전 출력은
+    /// `expected`의 기준 버전으로 내보낸 것으로 봅니다.
+    pub fn import_ndjson_checked(
+        reader: impl BufRead,
+        tolerance: f32,
+        expected: &CompatibilityFingerprint,
+        registry: &MigrationRegistry,
+    ) -> io::Result<Self> {
+        let (header, table) = Self::import_ndjson_with_header(reader, tolerance)?;
+        let stored = header.unwrap_or_else(|| expected.clone().with_baseline_versions());
+        let compatibility = registry
+            .check(&stored, expected)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        Ok(if compatibility.is_migrated() {
+            table.map_keys(|key| compatibility.migrate_key(key))
+        } else {
+            table
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::leduc::LeducState;
+    use crate::solver::cfr_core::Node;
+    use crate::solver::compact::DEFAULT_COMPACT_TOLERANCE;
+
+    fn trained() -> Trainer<LeducState> {
+        let mut trainer = Trainer::<LeducState>::new();
+        trainer.set_seed(11);
+        trainer.run(vec![LeducState::new()], 200);
+        trainer
+    }
+
+    fn export(trainer: &Trainer<LeducState>, options: ExportOptions) -> (Vec<serde_json::Value>, ExportReport) {
+        let mut out = Vec::new();
+        let report = trainer.export_ndjson(&mut out, options).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let mut lines = text.lines().map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap());
+        let header = lines.next().unwrap();
+        assert_eq!(header["fingerprint"], serde_json::to_value(trainer.fingerprint()).unwrap());
+        (lines.collect(), report)
+    }
+
+    #[test]
+    fn test_export_writes_one_line_per_node_and_filters() {
+        let trainer = trained();
+        let (lines, report) = export(&trainer, ExportOptions::default());
+        assert_eq!(lines.len(), trainer.nodes.len());
+        assert_eq!(report.exported, trainer.nodes.len() as u64);
+        assert_eq!(report.scanned, report.exported);
+        for line in &lines {
+            let key = line["info_key"].as_u64().unwrap();
+            let node = &trainer.nodes[&key];
+            assert_eq!(line["visits"], node.visits());
+            assert_eq!(line["strategy"].as_array().unwrap().len(), node.average().len());
+            assert_eq!(line["purification"], "none");
+            assert_eq!(line["fields"]["hole_rank"], key & 0xF);
+        }
+
+        // 스트리트 필터: 보드 공개 전 노드만
+        let preflop = trainer.nodes.keys().filter(|&&key| (key >> 4) & 0xF == 0).count();
+        let (lines, _) = export(&trainer, ExportOptions { street: Some(0), ..ExportOptions::default() });
+        assert_eq!(lines.len(), preflop);
+        assert!(preflop > 0 && preflop < trainer.nodes.len());
+        assert!(lines.iter().all(|line| line["fields"]["street"] == 0));
+
+        // 방문 횟수 필터와 표본 추출
+        let min_visits = 50;
+        let visited = trainer.nodes.values().filter(|node| node.visits() >= min_visits).count();
+        let (lines, _) = export(&trainer, ExportOptions { min_visits, ..ExportOptions::default() });
+        assert_eq!(lines.len(), visited);
+        assert!(visited < trainer.nodes.len());
+
+        let sample = ExportOptions { sample_rate: 0.5, sample_seed: 3, ..ExportOptions::default() };
+        let (sampled, _) = export(&trainer, sample);
+        assert!(sampled.len() > trainer.nodes.len() / 4 && sampled.len() < trainer.nodes.len() * 3 / 4);
+        assert_eq!(export(&trainer, sample).0.len(), sampled.len());
+        assert!(export(&trainer, ExportOptions { sample_rate: 0.0, ..sample }).0.is_empty());
+
+        // 정제 적용
+        let argmax = ExportOptions { purification: Purification::Argmax, ..ExportOptions::default() };
+        let (lines, _) = export(&trainer, argmax);
+        assert!(lines.iter().all(|line| line["purification"] == "argmax"));
+        assert!(lines.iter().all(|line| line["strategy"].as_array().unwrap().iter().any(|p| p == 1.0)));
+    }
+
+    #[test]
+    fn test_import_reproduces_compact_lookups() {
+        let trainer = trained();
+        let mut out = Vec::new();
+        trainer.export_ndjson(&mut out, ExportOptions::default()).unwrap();
+
+        let imported = CompactStrategyTable::<u64>::import_ndjson(out.as_slice(), DEFAULT_COMPACT_TOLERANCE).unwrap();
+        let compact = trainer.compact();
+        assert_eq!(imported.len(), compact.len());
+        for key in trainer.nodes.keys() {
+            assert_eq!(imported.lookup(key), compact.lookup(key));
+        }
+
+        let broken = b"{\"info_key\": 1, \"strategy\": [1.0]}\n\nnot json\n";
+        let error = CompactStrategyTable::<u64>::import_ndjson(&broken[..], DEFAULT_COMPACT_TOLERANCE).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("3번째 줄"), "{}", error);
+    }
+
+    #[test]
+    fn test_checked_import_verifies_header_fingerprint() {
+        let trainer = trained();
+        let mut out = Vec::new();
+        trainer.export_ndjson(&mut out, ExportOptions::default()).unwrap();
+
+        let registry = MigrationRegistry::builtin();
+        let fingerprint = trainer.fingerprint();
+        let imported =
+            CompactStrategyTable::import_ndjson_checked(out.as_slice(), DEFAULT_COMPACT_TOLERANCE, &fingerprint, &registry)
+                .unwrap();
+        assert_eq!(imported.len(), trainer.nodes.len());
+
+        let other_ladder = CompatibilityFingerprint {
+            action_abstraction: vec!["Fold".to_string()],
+            ..fingerprint
+        };
+        let error =
+            CompactStrategyTable::import_ndjson_checked(out.as_slice(), DEFAULT_COMPACT_TOLERANCE, &other_ladder, &registry)
+                .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("action_abstraction"), "{}", error);
+    }
+
+    /// 쓴 바이트 수와 한 번에 받은 가장 큰 쓰기만 기록하는 writer
+    #[derive(Default)]
+    struct CountingWriter {
+        bytes: usize,
+        largest_write: usize,
+        flushes: usize,
+    }
+
+    impl Write for CountingWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.bytes += buf.len();
+            self.largest_write = self.largest_write.max(buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.flushes += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_export_of_millions_of_nodes_stays_chunked() {
+        const NODES: u64 = 2_000_000;
+        const CHUNK_BYTES: usize = 64 * 1024;
+
+        let mut trainer = Trainer::<LeducState>::new();
+        trainer.nodes.reserve(NODES as usize);
+        for key in 0..NODES {
+            let mut node = Node::new(2, vec![1.0, 1.0]);
+            node.update_strategy((key % 2) as usize, 1.0);
+            node.record_visit();
+            trainer.nodes.insert(key, node);
+        }
+
+        let mut writer = CountingWriter::default();
+        let options = ExportOptions { chunk_bytes: CHUNK_BYTES, ..ExportOptions::default() };
+        let report = trainer.export_ndjson(&mut writer, options).unwrap();
+        assert_eq!(report.exported, NODES);
+        assert_eq!(report.bytes, writer.bytes as u64);
+
+        // 출력은 수십 MB지만 writer가 받는 한 번의 쓰기는 청크 하나와 줄 하나를 넘지 않음
+        assert!(writer.bytes > 1000 * CHUNK_BYTES, "{}", writer.bytes);
+        assert!(writer.largest_write < CHUNK_BYTES + 1024, "{}", writer.largest_write);
+        assert!(writer.flushes as u64 >= report.bytes / (CHUNK_BYTES as u64 + 1024));
+    }
+}
@@ -0,0 +1,91 @@
+// 전략 정제(purification)
+// CFR 평균 전략에는 2-3%짜리 혼합 빈도가 많이 남는데, 사람이나 단순한 봇은 실행하기 어렵고
+// 대부분 학습 노이즈입니다. 내보내기/조회 시점에 낮은 빈도를 잘라내거나 순수 전략으로 바꿉니다.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 전략 정제 방식
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Purification {
+    /// 원래 확률 그대로
+    #[default]
+    None,
+    /// 이 빈도 미만인 액션을 0으로 만들고 나머지를 재정규화 (모두 미만이면 `Argmax`와 같음)
+    Threshold(f64),
+    /// 가장 빈도가 높은 액션 하나만 선택 (동률이면 액션 순서상 앞선 액션)
+    Argmax,
+}
+
+impl Purification {
+    /// 액션 확률 벡터에 정제 적용 (빈 벡터나 확률 합이 0이면 그대로 반환)
+    pub fn apply(&self, strategy: &[f64]) -> Vec<f64> {
+        let total: f64 = strategy.iter().filter(|p| **p > 0.0).sum();
+        if total <= 0.0 {
+            return strategy.to_vec();
+        }
+        match *self {
+            Purification::None => strategy.to_vec(),
+            Purification::Threshold(cutoff) => {
+                let kept: Vec<f64> = strategy
+                    .iter()
+                    .map(|&p| if p >= cutoff && p > 0.0 { p } else { 0.0 })
+                    .collect();
+                let kept_total: f64 = kept.iter().sum();
+                if kept_total <= 0.0 {
+                    return Purification::Argmax.apply(strategy);
+                }
+                kept.into_iter().map(|p| p / kept_total).collect()
+            }
+            Purification::Argmax => {
+                let best = strategy
+                    .iter()
+                    .enumerate()
+                    .fold(0, |best, (i, &p)| if p > strategy[best] { i } else { best });
+                (0..strategy.len()).map(|i| if i == best { 1.0 } else { 0.0 }).collect()
+            }
+        }
+    }
+
+    /// 원래 확률을 바꾸지 않는지 여부
+    pub fn is_none(&self) -> bool {
+        matches!(self, Purification::None)
+    }
+}
+
+impl fmt::Display for Purification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Purification::None => write!(f, "none"),
+            Purification::Threshold(cutoff) => write!(f, "threshold({})", cutoff),
+            Purification::Argmax => write!(f, "argmax"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threshold_renormalizes_kept_actions() {
+        let strategy = [0.02, 0.58, 0.37, 0.03];
+        let purified = Purification::Threshold(0.05).apply(&strategy);
+        assert_eq!(purified[0], 0.0);
+        assert_eq!(purified[3], 0.0);
+        assert!((purified[1] - 0.58 / 0.95).abs() < 1e-12);
+        assert!((purified.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+        // 모든 액션이 기준 미만이면 가장 높은 빈도 하나만 남김
+        assert_eq!(Purification::Threshold(0.9).apply(&[0.3, 0.4, 0.3]), vec![0.0, 1.0, 0.0]);
+        assert_eq!(Purification::None.apply(&strategy), strategy.to_vec());
+    }
+
+    #[test]
+    fn test_argmax_breaks_ties_by_action_order() {
+        assert_eq!(Purification::Argmax.apply(&[0.2, 0.4, 0.4]), vec![0.0, 1.0, 0.0]);
+        assert_eq!(Purification::Argmax.apply(&[0.5, 0.5]), vec![1.0, 0.0]);
+        assert!(Purification::Argmax.apply(&[]).is_empty());
+    }
+}
@@ -0,0 +1,361 @@
+// 학습 전략 건전성 검사
+// 학습된 홀덤 전략의 스팟별 집계 빈도를 포커 이론의 기본 기준(MDF, 베팅 크기별 블러프 비율)과
+// 비교해 크게 벗어난 곳을 찾습니다. 이런 큰 편차는 대개 익스플로잇이 아니라 추상화나
+// 상태 머신 버그의 신호이므로, 학습 결과의 회귀 검출기로도 씁니다.
+
+use crate::game::holdem::{Act, State};
+use crate::game::poker_math::{minimum_defense_frequency, optimal_bluff_ratio};
+use crate::solver::cfr_core::{Game, Trainer};
+use serde::{Deserialize, Serialize};
+
+/// 검사할 스팟
+#[derive(Debug, Clone)]
+pub struct SpotSpec {
+    /// 표시 이름 (예: "리버 팟 베팅 대응")
+    pub name: String,
+    /// 결정하는 좌석
+    pub seat: usize,
+    /// 결정하는 상태 (`to_act == seat`, 이 좌석의 홀카드는 모든 조합으로 바꿔 가며 집계)
+    pub state: State,
+}
+
+impl SpotSpec {
+    /// 상태의 액션할 좌석을 결정 좌석으로 하는 스팟
+    pub fn new(name: impl Into<String>, state: State) -> Self {
+        Self {
+            name: name.into(),
+            seat: state.to_act,
+            state,
+        }
+    }
+}
+
+/// 위반한 이론 기준
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SanityRule {
+    /// 베팅을 마주했을 때 계속(콜/레이즈) 빈도가 최소 방어 빈도보다 낮음
+    MinimumDefense,
+    /// 리버 베팅 레인지의 블러프 비율이 베팅 크기의 최적 비율에서 벗어남
+    BluffRatio,
+}
+
+/// 위반 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Severity {
+    /// 이론에서 눈에 띄게 벗어남 (추상화 한계일 수 있음)
+    Warning,
+    /// 이론에서 크게 벗어남 (버그일 가능성이 높음)
+    Critical,
+}
+
+/// 검사 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SanityConfig {
+    /// 측정값과 기준값의 차이가 이 값을 넘으면 경고 (0-1)
+    pub warning_margin: f64,
+    /// 측정값과 기준값의 차이가 이 값을 넘으면 심각 (0-1)
+    pub critical_margin: f64,
+    /// 베팅 빈도가 이 값 미만인 베팅 스팟은 블러프 비율을 검사하지 않음
+    pub min_bet_frequency: f64,
+}
+
+impl Default for SanityConfig {
+    fn default() -> Self {
+        Self {
+            warning_margin: 0.15,
+            critical_margin: 0.3,
+            min_bet_frequency: 0.05,
+        }
+    }
+}
+
+/// 이론 기준을 벗어난 스팟 하나
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SanityFinding {
+    /// 스팟 이름
+    pub spot: String,
+    pub rule: SanityRule,
+    pub severity: Severity,
+    /// 요약 (예: "팟 50% 베팅에 계속 20% (MDF 67%)")
+    pub description: String,
+    /// 전략에서 측정한 빈도
+    pub measured: f64,
+    /// 이론 기준 빈도
+    pub expected: f64,
+    /// 노드가 있는 홀카드 조합의 비율
+    pub coverage: f64,
+}
+
+/// 기본 설정으로 스팟별 건전성 검사
+pub fn check(trainer: &Trainer<State>, spots: &[SpotSpec]) -> Vec<SanityFinding> {
+    check_with_config(trainer, spots, &SanityConfig::default())
+}
+
+/// 스팟별 집계 빈도를 이론 기준과 비교해 기준을 벗어난 스팟을 심각도 높은 순으로 반환
+///
+/// - 베팅을 마주한 스팟: 콜/레이즈 빈도를 MDF(`팟 / (팟 + 베팅)`)와 비교해 부족하면 보고합니다.
+/// - 베팅을 마주하지 않은 리버 스팟: 팟 베팅(`Raise(0)`) 중 하위 절반 핸드의 비율을 블러프로 보고
+///   최적 블러프 비율(`베팅 / (팟 + 2 * 베팅)`)과 비교합니다. 블러프가 없거나 너무 많으면 보고합니다.
+///
+/// 노드가 하나도 없는 스팟은 검사하지 않습니다.
+pub fn check_with_config(trainer: &Trainer<State>, spots: &[SpotSpec], config: &SanityConfig) -> Vec<SanityFinding> {
+    let mut findings = Vec::new();
+    for spot in spots {
+        let legal_actions = State::legal_actions(&spot.state);
+        let combos = combo_frequencies(trainer, &spot.state, spot.seat);
+        if combos.found.is_empty() || legal_actions.is_empty() {
+            continue;
+        }
+        let coverage = combos.coverage();
+        let frequencies = combos.average();
+        let state = &spot.state;
+        let call_amount = state.to_call.saturating_sub(state.invested[spot.seat]);
+
+        let finding = if call_amount > 0 {
+            // 콜할 수 없는 올인 상황은 기준을 적용하지 않음
+            if !legal_actions.contains(&Act::Call) {
+                continue;
+            }
+            let pot_before_bet = state.pot.saturating_sub(call_amount) as f64;
+            let expected = minimum_defense_frequency(pot_before_bet, call_amount as f64);
+            let measured = frequencies[1] + frequencies[2];
+            severity(expected - measured, config).map(|severity| SanityFinding {
+                spot: spot.name.clone(),
+                rule: SanityRule::MinimumDefense,
+                severity,
+                description: format!(
+                    "팟 {:.0}% 베팅에 계속 {:.0}% (MDF {:.0}%)",
+                    call_amount as f64 / pot_before_bet.max(1.0) * 100.0,
+                    measured * 100.0,
+                    expected * 100.0
+                ),
+                measured,
+                expected,
+                coverage,
+            })
+        } else {
+            if state.street != 3 || frequencies[2] < config.min_bet_frequency || !legal_actions.contains(&Act::Raise(0)) {
+                continue;
+            }
+            let bet = state.raise_amount(0) as f64;
+            let expected = optimal_bluff_ratio(state.pot as f64, bet);
+            let measured = combos.bluff_share(state);
+            severity((expected - measured).abs(), config).map(|severity| SanityFinding {
+                spot: spot.name.clone(),
+                rule: SanityRule::BluffRatio,
+                severity,
+                description: format!(
+                    "리버 베팅의 블러프 {} {:.0}% (기준 {:.0}%)",
+                    if measured < expected { "부족" } else { "과다" },
+                    measured * 100.0,
+                    expected * 100.0
+                ),
+                measured,
+                expected,
+                coverage,
+            })
+        };
+        findings.extend(finding);
+    }
+
+    findings.sort_by(|a, b| {
+        b.severity.cmp(&a.severity).then(
+            (b.measured - b.expected)
+                .abs()
+                .partial_cmp(&(a.measured - a.expected).abs())
+                .unwrap_or(std::cmp::Ordering::Equal),
+        )
+    });
+    findings
+}
+
+/// 상태에서 `seat`의 모든 홀카드 조합에 걸친 평균 [폴드, 콜, 레이즈] 빈도와 노드가 있는 조합 비율
+///
+/// 조합마다 학습된 평균 전략을 찾아 같은 가중치로 평균합니다. 노드가 하나도 없으면 None입니다.
+pub fn aggregate_frequencies(trainer: &Trainer<State>, state: &State, seat: usize) -> Option<([f64; 3], f64)> {
+    let combos = combo_frequencies(trainer, state, seat);
+    (!combos.found.is_empty()).then(|| (combos.average(), combos.coverage()))
+}
+
+/// 액션 종류 (0=폴드, 1=콜/체크, 2=베팅/레이즈)
+pub fn action_class(action: &Act) -> usize {
+    match action {
+        Act::Fold => 0,
+        Act::Call => 1,
+        Act::Raise(_) => 2,
+    }
+}
+
+/// 노드가 있는 홀카드 조합별 [폴드, 콜, 레이즈] 빈도
+struct ComboFrequencies {
+    found: Vec<([u8; 2], [f64; 3])>,
+    combos: usize,
+}
+
+impl ComboFrequencies {
+    fn average(&self) -> [f64; 3] {
+        let mut sums = [0.0; 3];
+        for (_, frequencies) in &self.found {
+            for (sum, frequency) in sums.iter_mut().zip(frequencies) {
+                *sum += frequency;
+            }
+        }
+        sums.map(|sum| sum / self.found.len().max(1) as f64)
+    }
+
+    fn coverage(&self) -> f64 {
+        self.found.len() as f64 / self.combos.max(1) as f64
+    }
+
+    /// 팟 베팅 빈도 중 하위 절반 핸드(족보 기준)가 차지하는 비율
+    fn bluff_share(&self, state: &State) -> f64 {
+        let mut ranked: Vec<(u32, f64)> = self
+            .found
+            .iter()
+            .map(|&(hole, frequencies)| {
+                let board = &state.board;
+                let value = state.variant.evaluate_7cards([
+                    hole[0], hole[1], board[0], board[1], board[2], board[3], board[4],
+                ]);
+                (value, frequencies[2])
+            })
+            .collect();
+        // 낮을수록 강한 핸드
+        ranked.sort_by_key(|&(value, _)| value);
+        let total: f64 = ranked.iter().map(|&(_, bet)| bet).sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        let weak: f64 = ranked[ranked.len() / 2..].iter().map(|&(_, bet)| bet).sum();
+        weak / total
+    }
+}
+
+fn combo_frequencies(trainer: &Trainer<State>, state: &State, seat: usize) -> ComboFrequencies {
+    let legal_actions = State::legal_actions(state);
+    let deck: Vec<u8> = state
+        .variant
+        .deck()
+        .into_iter()
+        .filter(|card| !state.board.contains(card))
+        .collect();
+
+    let mut found = Vec::new();
+    let mut combos = 0usize;
+    let mut state = state.clone();
+    for (i, &first) in deck.iter().enumerate() {
+        for &second in &deck[i + 1..] {
+            combos += 1;
+            state.hole[seat] = [first, second];
+            let Some(node) = trainer.nodes.get(&State::info_key(&state, seat)) else {
+                continue;
+            };
+            let mut frequencies = [0.0; 3];
+            for (action, probability) in legal_actions.iter().zip(node.average()) {
+                frequencies[action_class(action)] += probability;
+            }
+            found.push(([first, second], frequencies));
+        }
+    }
+    ComboFrequencies { found, combos }
+}
+
+fn severity(shortfall: f64, config: &SanityConfig) -> Option<Severity> {
+    if shortfall > config.critical_margin {
+        Some(Severity::Critical)
+    } else if shortfall > config.warning_margin {
+        Some(Severity::Warning)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::cfr_core::Node;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    /// 헤즈업 리버 벤치마크: 보드 K♠7♦2♣9♥4♠, 팟 200, 유효 스택 900, 빅블라인드(좌석 1)가 먼저 액션
+    fn river_root(hole: [[u8; 2]; 2]) -> State {
+        let mut state = State::new_hand([50, 100], [1000; 6], 2);
+        state.hole[0] = hole[0];
+        state.hole[1] = hole[1];
+        state.board = vec![12, 19, 27, 47, 3];
+        state.street = 3;
+        state.pot = 200;
+        state.stack[0] = 900;
+        state.stack[1] = 900;
+        state.invested = [0; 6];
+        state.to_call = 0;
+        state.actions_taken = 0;
+        state.raises_this_street = 0;
+        state.to_act = 1;
+        state
+    }
+
+    /// 시드로 섞은 홀카드 조합의 리버 루트들
+    fn benchmark_roots(count: usize) -> Vec<State> {
+        let board = river_root([[0; 2]; 2]).board;
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut deck: Vec<u8> = (0..52).filter(|card| !board.contains(card)).collect();
+        (0..count)
+            .map(|_| {
+                deck.shuffle(&mut rng);
+                river_root([[deck[0], deck[1]], [deck[2], deck[3]]])
+            })
+            .collect()
+    }
+
+    fn benchmark_spots() -> Vec<SpotSpec> {
+        let root = river_root([[0; 2]; 2]);
+        let checked = State::next_state(&root, Act::Call);
+        vec![
+            SpotSpec::new("리버 선 베팅", root.clone()),
+            SpotSpec::new("리버 팟 베팅 대응", State::next_state(&root, Act::Raise(0))),
+            SpotSpec::new("리버 체크 후 베팅 대응", State::next_state(&checked, Act::Raise(0))),
+        ]
+    }
+
+    #[test]
+    fn test_always_folding_trainer_violates_mdf_everywhere() {
+        let mut trainer = Trainer::<State>::new();
+        trainer.run(benchmark_roots(20), 2);
+
+        // 모든 노드의 평균 전략을 폴드로 고정
+        for node in trainer.nodes.values_mut() {
+            let actions = node.average().len();
+            *node = Node::new(actions, vec![1.0; actions]);
+            node.update_strategy(0, 1.0);
+        }
+
+        let spots: Vec<SpotSpec> = benchmark_spots()
+            .into_iter()
+            .filter(|spot| spot.state.to_call > spot.state.invested[spot.seat])
+            .collect();
+        assert_eq!(spots.len(), 2);
+        let findings = check(&trainer, &spots);
+        for spot in &spots {
+            let finding = findings.iter().find(|f| f.spot == spot.name).expect("MDF 위반");
+            assert_eq!(finding.rule, SanityRule::MinimumDefense);
+            assert_eq!(finding.severity, Severity::Critical);
+            assert_eq!(finding.measured, 0.0);
+            assert!((finding.expected - 0.5).abs() < 1e-9, "{:?}", finding);
+        }
+    }
+
+    #[test]
+    fn test_trained_benchmark_has_no_critical_findings() {
+        let mut trainer = Trainer::<State>::new();
+        trainer.run(benchmark_roots(200), 50);
+
+        let findings = check(&trainer, &benchmark_spots());
+        // 학습이 짧아 경고는 남을 수 있지만 MDF를 크게 밑돌거나 블러프가 아예 없지는 않음
+        assert!(findings.iter().all(|f| f.severity != Severity::Critical), "{:?}", findings);
+    }
+}
@@ -0,0 +1,325 @@
+// 게임 트리 덤프 모듈
+// 주어진 상태에서 제한된 깊이까지 트리를 펼쳐 DOT/JSON으로 내보냄
+
+use crate::game::hand_eval::card_to_string;
+use crate::game::holdem::{Act, State};
+use crate::game::rng::entropy_seed;
+use crate::solver::cfr_core::{Game, GameState, Trainer};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// 덤프에 포함할 최대 노드 수 기본값 (트리 폭발 방지)
+pub const DEFAULT_MAX_TREE_NODES: usize = 2000;
+
+/// 트리 덤프 설정
+#[derive(Debug, Clone)]
+pub struct TreeExportConfig {
+    /// 루트로부터 펼칠 최대 깊이 (찬스 노드도 한 단계로 계산)
+    pub max_depth: usize,
+    /// 찬스 노드마다 샘플링할 카드 딜링 결과 수
+    pub chance_samples: usize,
+    /// 최대 노드 수 - 넘으면 더 이상 펼치지 않고 잘림 표시
+    pub max_nodes: usize,
+    /// 찬스 샘플링 시드 (None이면 호출마다 다른 딜링)
+    pub seed: Option<u64>,
+}
+
+impl Default for TreeExportConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 3,
+            chance_samples: 2,
+            max_nodes: DEFAULT_MAX_TREE_NODES,
+            seed: None,
+        }
+    }
+}
+
+/// 노드 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "player")]
+pub enum TreeNodeKind {
+    /// 플레이어 결정 노드
+    Player(usize),
+    /// 카드 딜링 노드
+    Chance,
+    /// 핸드 종료 노드
+    Terminal,
+}
+
+/// 자식 노드로 가는 간선
+#[derive(Debug, Clone, Serialize)]
+pub struct TreeEdge {
+    /// 액션 또는 딜링된 카드 (예: "Call", "deal 7h 2c Ks")
+    pub label: String,
+    /// 액션 간선이면 해당 액션
+    pub action: Option<Act>,
+    /// 자식 노드 id
+    pub child: usize,
+}
+
+/// 덤프된 트리 노드
+#[derive(Debug, Clone, Serialize)]
+pub struct TreeNode {
+    pub id: usize,
+    pub depth: usize,
+    pub kind: TreeNodeKind,
+    pub street: u8,
+    pub pot: u32,
+    /// 사람이 읽을 수 있는 상태 요약 (`State`의 `Display`)
+    pub description: String,
+    /// 가능한 액션 (플레이어 노드만)
+    pub legal_actions: Vec<Act>,
+    /// 트레이너 평균 전략 (legal_actions 순서, 트레이너가 있고 학습된 정보 집합만)
+    pub strategy: Option<Vec<f64>>,
+    /// 플레이어별 유틸리티 (터미널 노드만, 0번부터 참여 인원 수만큼)
+    pub utilities: Option<Vec<f64>>,
+    /// 노드 수 제한으로 자식 일부가 생략되었는지 여부
+    pub truncated: bool,
+    pub children: Vec<TreeEdge>,
+}
+
+/// 게임 트리 덤프 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct GameTreeDump {
+    /// 노드 목록 (id = 인덱스, 0번이 루트, 전위 순회 순서)
+    pub nodes: Vec<TreeNode>,
+    pub max_depth: usize,
+    /// 노드 수 제한에 걸려 트리가 잘렸는지 여부
+    pub truncated: bool,
+}
+
+/// 루트 상태에서 `max_depth`까지 트리 덤프 (기본 설정 사용)
+pub fn export_tree(root: &State, max_depth: usize, trainer: Option<&Trainer<State>>) -> GameTreeDump {
+    let config = TreeExportConfig {
+        max_depth,
+        ..TreeExportConfig::default()
+    };
+    export_tree_with_config(root, &config, trainer)
+}
+
+/// 설정을 지정한 트리 덤프
+pub fn export_tree_with_config(
+    root: &State,
+    config: &TreeExportConfig,
+    trainer: Option<&Trainer<State>>,
+) -> GameTreeDump {
+    let players = (0..6).rev().find(|&i| root.alive[i]).map_or(0, |i| i + 1);
+    let mut builder = TreeBuilder {
+        config,
+        trainer,
+        players,
+        nodes: Vec::new(),
+        truncated: false,
+        rng: StdRng::seed_from_u64(config.seed.unwrap_or_else(entropy_seed)),
+    };
+    builder.visit(root, 0);
+
+    GameTreeDump {
+        nodes: builder.nodes,
+        max_depth: config.max_depth,
+        truncated: builder.truncated,
+    }
+}
+
+struct TreeBuilder<'a> {
+    config: &'a TreeExportConfig,
+    trainer: Option<&'a Trainer<State>>,
+    players: usize,
+    nodes: Vec<TreeNode>,
+    truncated: bool,
+    rng: StdRng,
+}
+
+impl TreeBuilder<'_> {
+    /// 노드를 추가하고 깊이 제한 안에서 자식들을 펼침
+    fn visit(&mut self, state: &State, depth: usize) -> usize {
+        let id = self.nodes.len();
+        let (kind, legal_actions) = if state.is_terminal() {
+            (TreeNodeKind::Terminal, Vec::new())
+        } else if state.is_chance_node() {
+            (TreeNodeKind::Chance, Vec::new())
+        } else {
+            (TreeNodeKind::Player(state.to_act), State::legal_actions(state))
+        };
+
+        let strategy = match (kind, self.trainer) {
+            (TreeNodeKind::Player(player), Some(trainer)) => trainer
+                .nodes
+                .get(&State::info_key(state, player))
+                .map(|node| node.average())
+                .filter(|average| average.len() == legal_actions.len()),
+            _ => None,
+        };
+        let utilities = (kind == TreeNodeKind::Terminal)
+            .then(|| (0..self.players).map(|p| State::util(state, p)).collect());
+
+        self.nodes.push(TreeNode {
+            id,
+            depth,
+            kind,
+            street: state.street,
+            pot: state.pot,
+            description: state.to_string(),
+            legal_actions: legal_actions.clone(),
+            strategy,
+            utilities,
+            truncated: false,
+            children: Vec::new(),
+        });
+
+        if depth >= self.config.max_depth {
+            return id;
+        }
+
+        // (간선 라벨, 액션, 자식 상태) 목록
+        let successors: Vec<(String, Option<Act>, State)> = match kind {
+            TreeNodeKind::Terminal => Vec::new(),
+            TreeNodeKind::Chance => (0..self.config.chance_samples)
+                .map(|_| {
+                    let next = State::apply_chance(state, &mut self.rng);
+                    let dealt: Vec<String> = next.board[state.board.len()..]
+                        .iter()
+                        .map(|&card| card_to_string(card))
+                        .collect();
+                    (format!("deal {}", dealt.join(" ")), None, next)
+                })
+                .collect(),
+            TreeNodeKind::Player(_) => legal_actions
+                .iter()
+                .map(|&action| (format!("{:?}", action), Some(action), State::next_state(state, action)))
+                .collect(),
+        };
+
+        for (label, action, next) in successors {
+            if self.nodes.len() >= self.config.max_nodes {
+                self.nodes[id].truncated = true;
+                self.truncated = true;
+                break;
+            }
+            let child = self.visit(&next, depth + 1);
+            self.nodes[id].children.push(TreeEdge { label, action, child });
+        }
+        id
+    }
+}
+
+impl GameTreeDump {
+    /// Graphviz DOT 형식으로 렌더링
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph GameTree {\n    node [fontname=\"monospace\"];\n");
+        for node in &self.nodes {
+            let (shape, title) = match node.kind {
+                TreeNodeKind::Player(player) => ("box", format!("P{}", player)),
+                TreeNodeKind::Chance => ("ellipse", "chance".to_string()),
+                TreeNodeKind::Terminal => ("doubleoctagon", "terminal".to_string()),
+            };
+            let mut label = format!("{}\\nstreet {} pot {}", title, node.street, node.pot);
+            if let Some(utilities) = &node.utilities {
+                let values: Vec<String> = utilities.iter().map(|u| format!("{:.1}", u)).collect();
+                let _ = write!(label, "\\nutil [{}]", values.join(", "));
+            }
+            if node.truncated {
+                label.push_str("\\n(truncated)");
+            }
+            let _ = writeln!(out, "    n{} [shape={}, label=\"{}\"];", node.id, shape, label);
+
+            for (i, edge) in node.children.iter().enumerate() {
+                let probability = edge
+                    .action
+                    .and(node.strategy.as_ref())
+                    .and_then(|strategy| strategy.get(i));
+                let edge_label = match probability {
+                    Some(p) => format!("{} ({:.2})", edge.label, p),
+                    None => edge.label.clone(),
+                };
+                let _ = writeln!(
+                    out,
+                    "    n{} -> n{} [label=\"{}\"];",
+                    node.id,
+                    edge.child,
+                    edge_label.replace('"', "\\\"")
+                );
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// JSON 형식으로 렌더링
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("트리 덤프는 항상 JSON으로 직렬화 가능")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preflop_tree_branching_and_terminals() {
+        let root = State::new_hand([10, 20], [1000; 6], 2);
+        let dump = export_tree(&root, 3, None);
+
+        let root_node = &dump.nodes[0];
+        assert_eq!(root_node.kind, TreeNodeKind::Player(0));
+        assert_eq!(root_node.legal_actions, vec![Act::Fold, Act::Call, Act::Raise(0)]);
+        let labels: Vec<&str> = root_node.children.iter().map(|e| e.label.as_str()).collect();
+        assert_eq!(labels, vec!["Fold", "Call", "Raise(0)"]);
+        assert!(root_node.description.starts_with("Street: Preflop | Pot: 30 | To act: P0 (BTN)"));
+        assert!(!dump.truncated);
+        assert!(dump.nodes.iter().all(|n| n.depth <= 3));
+
+        // 폴드 직후는 터미널이며 유틸리티가 제로섬
+        let fold = &dump.nodes[root_node.children[0].child];
+        assert_eq!(fold.kind, TreeNodeKind::Terminal);
+        let utilities = fold.utilities.as_ref().unwrap();
+        assert_eq!(utilities, &vec![-10.0, 10.0]);
+        assert!(dump
+            .nodes
+            .iter()
+            .filter(|n| n.kind == TreeNodeKind::Terminal)
+            .all(|n| n.utilities.is_some() && n.children.is_empty()));
+
+        let dot = dump.to_dot();
+        assert!(dot.starts_with("digraph GameTree {"));
+        assert!(dot.contains("n0 -> n"));
+        let json: serde_json::Value = serde_json::from_str(&dump.to_json()).unwrap();
+        assert_eq!(json["nodes"].as_array().unwrap().len(), dump.nodes.len());
+    }
+
+    #[test]
+    fn test_strategy_annotations_with_trainer() {
+        let root = State::new_hand([10, 20], [1000; 6], 2);
+        let mut trainer = Trainer::<State>::new();
+        trainer.run(vec![root.clone()], 5);
+
+        let without = export_tree(&root, 2, None);
+        assert!(without.nodes.iter().all(|n| n.strategy.is_none()));
+
+        let dump = export_tree(&root, 2, Some(&trainer));
+        let strategy = dump.nodes[0].strategy.as_ref().expect("루트 정보 집합은 학습되어 있어야 함");
+        assert_eq!(strategy.len(), 3);
+        assert!((strategy.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(dump.to_dot().contains("Call ("));
+    }
+
+    #[test]
+    fn test_node_cap_truncates() {
+        let root = State::new_hand([10, 20], [1000; 6], 2);
+        let config = TreeExportConfig {
+            max_depth: 10,
+            chance_samples: 3,
+            max_nodes: 25,
+            seed: None,
+        };
+        let dump = export_tree_with_config(&root, &config, None);
+
+        assert!(dump.truncated);
+        assert!(dump.nodes.len() <= 25);
+        assert!(dump.nodes.iter().any(|n| n.truncated));
+        assert!(dump.to_dot().contains("(truncated)"));
+    }
+}
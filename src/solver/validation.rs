@@ -0,0 +1,246 @@
+// EV 계산 교차 검증
+// 홀카드를 모두 아는 리버 스팟에서 EVCalculator의 몬테카를로 EV를 같은 정책으로 게임 트리를
+// 끝까지 열거한 정확한 EV(`EVCalculator::exact_action_evs`)와 비교합니다. 두 값이 샘플링 오차보다
+// 크게 어긋나면 시뮬레이션(터미널 판정, 기준점)이나 열거 중 한쪽의 버그이므로 회귀 검출기로 씁니다.
+// 큰 스팟 묶음은 `cargo run --release --bin cross_validate`로 돌립니다.
+
+use crate::game::holdem::{Act, State};
+use crate::solver::cfr_core::{Game, GameState};
+use crate::solver::ev_calculator::{EVCalculator, EVConfig};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// 스팟마다 액션당 몬테카를로 샘플 수 (기본)
+pub const CROSS_VALIDATION_SAMPLES: usize = 4_000;
+
+/// 몬테카를로 쪽 시뮬레이션 시드 (같은 스팟 묶음은 항상 같은 보고서)
+const CROSS_VALIDATION_SEED: u64 = 0xC4055;
+
+/// 홀카드를 모두 정한 리버 스팟 (직렬화해 그대로 재현 가능)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpotSpec {
+    /// 좌석별 홀카드 (좌석 수 = 플레이어 수, 2-6)
+    pub holes: Vec<[u8; 2]>,
+    /// 리버까지 깔린 보드
+    pub board: [u8; 5],
+    /// 좌석별 남은 스택
+    pub stacks: Vec<u32>,
+    /// 좌석별 이번 스트리트 투자금 (베팅을 마주한 스팟)
+    pub invested: Vec<u32>,
+    /// 이전 스트리트까지 쌓인 팟
+    pub pot: u32,
+    pub big_blind: u32,
+    /// 결정하는 좌석
+    pub to_act: usize,
+}
+
+impl SpotSpec {
+    /// 리버 베팅 중인 게임 상태 (이번 스트리트에 칩을 넣은 좌석은 이미 액션한 것으로 봄)
+    pub fn to_state(&self) -> State {
+        let players = self.holes.len().min(6);
+        let mut state = State::new_hand([self.big_blind / 2, self.big_blind], [0; 6], players);
+        for seat in 0..players {
+            state.hole[seat] = self.holes[seat];
+            state.stack[seat] = self.stacks.get(seat).copied().unwrap_or(0);
+            state.invested[seat] = self.invested.get(seat).copied().unwrap_or(0);
+        }
+        state.board = self.board.to_vec();
+        state.street = 3;
+        state.antes = [0; 6];
+        state.to_call = state.invested.iter().copied().max().unwrap_or(0);
+        state.pot = self.pot + state.invested.iter().sum::<u32>();
+        state.actions_taken = state.invested.iter().filter(|&&chips| chips > 0).count();
+        state.raises_this_street = (state.to_call > 0) as u8;
+        state.last_raise = state.to_call;
+        state.to_act = self.to_act;
+        state
+    }
+
+    /// 결정 시점의 팟 (편차를 팟 대비로 나타낼 때 기준)
+    pub fn total_pot(&self) -> u32 {
+        self.pot + self.invested.iter().sum::<u32>()
+    }
+}
+
+/// `seed`로 만든 리버 스팟 `count`개 (2-3인, 절반은 바로 앞 좌석의 베팅을 마주함)
+///
+/// 짧은 스택 좌석을 섞어 사이드 팟과 콜되지 않은 베팅 반환까지 검증되도록 합니다.
+pub fn generate_river_spots(count: usize, seed: u64) -> Vec<SpotSpec> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count)
+        .map(|_| {
+            let players = rng.gen_range(2..=3);
+            let mut deck: Vec<u8> = (0..52).collect();
+            deck.shuffle(&mut rng);
+            let holes: Vec<[u8; 2]> = (0..players).map(|seat| [deck[2 * seat], deck[2 * seat + 1]]).collect();
+            let mut board = [0u8; 5];
+            board.copy_from_slice(&deck[2 * players..2 * players + 5]);
+
+            let big_blind = 20;
+            let pot = rng.gen_range(5..=30) * big_blind;
+            let mut stacks: Vec<u32> = (0..players)
+                .map(|_| match rng.gen_bool(0.3) {
+                    true => rng.gen_range(1..=pot / big_blind) * big_blind / 2,
+                    false => rng.gen_range(10..=100) * big_blind,
+                })
+                .collect();
+            let to_act = rng.gen_range(0..players);
+            let mut invested = vec![0; players];
+            if rng.gen_bool(0.5) {
+                let bettor = (to_act + players - 1) % players;
+                let bet = (pot * rng.gen_range(1..=4) / 4).min(stacks[bettor]);
+                invested[bettor] = bet;
+                stacks[bettor] -= bet;
+            }
+
+            SpotSpec {
+                holes,
+                board,
+                stacks,
+                invested,
+                pot,
+                big_blind,
+                to_act,
+            }
+        })
+        .collect()
+}
+
+/// 스팟 하나의 두 계산 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct SpotValidation {
+    /// 입력 스팟 묶음에서의 순서
+    pub index: usize,
+    pub spot: SpotSpec,
+    /// 결정 좌석의 합법 액션 (아래 EV 벡터의 순서)
+    pub actions: Vec<Act>,
+    /// 몬테카를로 EV (칩)
+    pub monte_carlo: Vec<f64>,
+    /// 몬테카를로 EV의 표준오차 (칩)
+    pub std_errors: Vec<f64>,
+    /// 정확한 EV (칩)
+    pub exact: Vec<f64>,
+    /// 액션별 |몬테카를로 - 정확| / 결정 시점 팟의 최댓값
+    pub max_deviation: f64,
+}
+
+/// 교차 검증 보고서
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationReport {
+    /// 팟 대비 허용 편차
+    pub tolerance: f64,
+    /// 두 방법으로 모두 계산한 스팟
+    pub spots: Vec<SpotValidation>,
+    /// 편차가 허용치를 넘은 스팟 (`spots` 인덱스)
+    pub flagged: Vec<usize>,
+    /// 정확한 EV를 계산할 수 없었던 스팟 (입력 순서)
+    pub skipped: Vec<usize>,
+}
+
+impl ValidationReport {
+    /// 편차가 허용치를 넘은 스팟들
+    pub fn flagged_spots(&self) -> impl Iterator<Item = &SpotValidation> {
+        self.flagged.iter().map(|&i| &self.spots[i])
+    }
+
+    /// 스팟별 최대 편차의 평균 (팟 대비)
+    pub fn mean_deviation(&self) -> f64 {
+        if self.spots.is_empty() {
+            return 0.0;
+        }
+        self.spots.iter().map(|spot| spot.max_deviation).sum::<f64>() / self.spots.len() as f64
+    }
+
+    /// 가장 크게 어긋난 스팟의 편차 (팟 대비)
+    pub fn max_deviation(&self) -> f64 {
+        self.spots.iter().map(|spot| spot.max_deviation).fold(0.0, f64::max)
+    }
+
+    /// JSON 형식으로 렌더링 (CLI 출력, 재현용)
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("교차 검증 보고서는 항상 JSON으로 직렬화 가능")
+    }
+}
+
+/// 리버 스팟들의 몬테카를로 EV와 정확한 EV를 비교 (`tolerance`는 팟 대비 허용 편차)
+///
+/// 상대와 이후 히어로 액션은 모두 균등 랜덤이며, 액션마다 `CROSS_VALIDATION_SAMPLES`번 시뮬레이션합니다.
+pub fn cross_validate_river(spots: &[SpotSpec], tolerance: f64) -> ValidationReport {
+    cross_validate_river_with(spots, tolerance, CROSS_VALIDATION_SAMPLES)
+}
+
+/// 액션당 몬테카를로 샘플 수를 지정한 `cross_validate_river` (오프라인 대규모 검증용)
+pub fn cross_validate_river_with(spots: &[SpotSpec], tolerance: f64, samples: usize) -> ValidationReport {
+    let calculator = EVCalculator::new(EVConfig {
+        sample_count: samples,
+        // 리버 베팅은 액션 수 제한으로 끝나므로 깊이 제한에 걸리지 않게 둠
+        max_depth: u8::MAX,
+        use_opponent_model: false,
+        seed: Some(CROSS_VALIDATION_SEED),
+        ..EVConfig::default()
+    });
+
+    let mut report = ValidationReport {
+        tolerance,
+        spots: Vec::new(),
+        flagged: Vec::new(),
+        skipped: Vec::new(),
+    };
+    for (index, spot) in spots.iter().enumerate() {
+        let state = spot.to_state();
+        let Some(exact) = calculator.exact_action_evs(&state).filter(|_| !state.is_terminal()) else {
+            report.skipped.push(index);
+            continue;
+        };
+        let simulated = calculator.calculate_action_evs(&state);
+        let actions = State::legal_actions(&state);
+        let lookup = |action: Act| simulated.iter().find(|a| a.action == action);
+        let monte_carlo: Vec<f64> = actions.iter().map(|&a| lookup(a).map_or(f64::NAN, |a| a.ev)).collect();
+        let std_errors: Vec<f64> = actions.iter().map(|&a| lookup(a).map_or(f64::NAN, |a| a.std_error)).collect();
+        let exact: Vec<f64> = exact.into_iter().map(|(_, ev)| ev).collect();
+
+        let pot = spot.total_pot().max(1) as f64;
+        let max_deviation = monte_carlo
+            .iter()
+            .zip(&exact)
+            .map(|(mc, ev)| (mc - ev).abs() / pot)
+            .fold(0.0, |max, deviation| if deviation.is_nan() { f64::INFINITY } else { max.max(deviation) });
+        if max_deviation > tolerance {
+            report.flagged.push(report.spots.len());
+        }
+        report.spots.push(SpotValidation {
+            index,
+            spot: spot.clone(),
+            actions,
+            monte_carlo,
+            std_errors,
+            exact,
+            max_deviation,
+        });
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monte_carlo_matches_exact_river_enumeration() {
+        let spots = generate_river_spots(12, 2429);
+        // 액션당 4,000 샘플의 표준오차는 팟의 1-3% (사이드 팟을 하나로 판정하던 버그는 0.15-1.5)
+        let report = cross_validate_river(&spots, 0.08);
+        assert!(report.skipped.is_empty(), "{:?}", report.skipped);
+        assert_eq!(report.spots.len(), 12);
+        assert!(report.flagged.is_empty(), "{}", report.to_json());
+        assert!(report.mean_deviation() < 0.02, "{}", report.mean_deviation());
+
+        // 스팟은 직렬화한 그대로 같은 상태를 재현
+        let json = serde_json::to_string(&spots[0]).unwrap();
+        let replayed: SpotSpec = serde_json::from_str(&json).unwrap();
+        assert_eq!(replayed, spots[0]);
+        assert_eq!(replayed.to_state().hole, spots[0].to_state().hole);
+    }
+}
@@ -0,0 +1,247 @@
+// 전략 출력 골든 파일 회귀 테스트
+//
+// 시드를 고정한 작은 설정을 학습해 평균 전략 다이제스트를 만들고
+// tests/golden/*.golden 파일과 비교합니다. info_key, 버킷팅, 상태 머신을
+// 의도적으로 바꿨다면 아래 명령으로 골든 파일을 다시 만드세요:
+//
+//     REGENERATE_GOLDEN=1 cargo test --test golden_strategies
+
+use nice_hand_core::game::holdem;
+use nice_hand_core::game::leduc::{leduc_rank, LeducState};
+use nice_hand_core::solver::cfr_core::{Game, GameState, Trainer};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+/// 골든 파일 재생성 환경 변수
+const REGENERATE_ENV: &str = "REGENERATE_GOLDEN";
+
+/// 다이제스트에 쓰는 전략 소수점 자리수
+const DIGEST_DECIMALS: usize = 4;
+
+/// 학습 설정 하나의 결정적 다이제스트
+#[derive(Debug, Clone, PartialEq)]
+struct StrategyDigest {
+    config: String,
+    iterations: usize,
+    nodes: usize,
+    /// 키 순으로 정렬한 노드별 전략 줄의 FNV-1a 해시
+    hash: u64,
+    /// 사람이 비교하기 위한 앞부분 줄
+    preview: Vec<String>,
+}
+
+impl StrategyDigest {
+    fn from_trainer<G: Game>(config: &str, iterations: usize, trainer: &Trainer<G>) -> Self
+    where
+        G::InfoKey: Ord + std::fmt::Debug,
+    {
+        let mut keys: Vec<&G::InfoKey> = trainer.nodes.keys().collect();
+        keys.sort();
+
+        let lines: Vec<String> = keys
+            .iter()
+            .map(|key| {
+                let average = trainer.nodes[*key].average();
+                assert_strategy_is_distribution(config, key, &average);
+                let probs: Vec<String> = average
+                    .iter()
+                    .map(|p| format!("{:.*}", DIGEST_DECIMALS, p))
+                    .collect();
+                format!("{:?} {}", key, probs.join(" "))
+            })
+            .collect();
+
+        Self {
+            config: config.to_string(),
+            iterations,
+            nodes: lines.len(),
+            hash: fnv1a(lines.iter().flat_map(|line| line.bytes().chain(Some(b'\n')))),
+            preview: lines.into_iter().take(5).collect(),
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut text = String::new();
+        writeln!(text, "# 골든 전략 다이제스트 - 재생성: {}=1 cargo test --test golden_strategies", REGENERATE_ENV).unwrap();
+        writeln!(text, "config: {}", self.config).unwrap();
+        writeln!(text, "iterations: {}", self.iterations).unwrap();
+        writeln!(text, "nodes: {}", self.nodes).unwrap();
+        writeln!(text, "digest: {:016x}", self.hash).unwrap();
+        for line in &self.preview {
+            writeln!(text, "preview: {}", line).unwrap();
+        }
+        text
+    }
+}
+
+/// 평균 전략은 항상 확률 분포여야 함
+fn assert_strategy_is_distribution<K: std::fmt::Debug>(config: &str, key: &K, average: &[f64]) {
+    let sum: f64 = average.iter().sum();
+    assert!(
+        average.iter().all(|p| p.is_finite() && *p >= 0.0) && (sum - 1.0).abs() < 1e-6,
+        "[{}] 노드 {:?}의 평균 전략이 확률 분포가 아님: {:?}",
+        config,
+        key,
+        average
+    );
+}
+
+/// 64비트 FNV-1a (플랫폼/러스트 버전과 무관하게 고정)
+fn fnv1a(bytes: impl Iterator<Item = u8>) -> u64 {
+    bytes.fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+fn golden_path(config: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{}.golden", config))
+}
+
+/// 기대 다이제스트와 비교 (다르면 재생성 방법을 포함한 에러 메시지)
+fn compare_digest(config: &str, expected: &str, actual: &str) -> Result<(), String> {
+    if expected == actual {
+        return Ok(());
+    }
+    Err(format!(
+        "[{}] 학습된 전략 다이제스트가 골든 파일과 다릅니다.\n\
+         --- 기대 ({}) ---\n{}--- 실제 ---\n{}\
+         info_key, 버킷팅, 상태 머신, 유틸리티를 의도적으로 바꿨다면 다음으로 재생성하세요:\n    \
+         {}=1 cargo test --test golden_strategies\n\
+         의도하지 않은 변경이라면 전략 출력이 바뀐 원인을 찾아야 합니다.",
+        config,
+        golden_path(config).display(),
+        expected,
+        actual,
+        REGENERATE_ENV
+    ))
+}
+
+/// 골든 파일 확인 (재생성 모드에서는 덮어쓰기)
+fn check_golden(digest: &StrategyDigest) {
+    let path = golden_path(&digest.config);
+    let actual = digest.render();
+
+    if std::env::var_os(REGENERATE_ENV).is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &actual).unwrap();
+        println!("골든 파일 재생성: {}", path.display());
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "[{}] 골든 파일 {}을 읽을 수 없습니다 ({}). {}=1로 생성하세요.",
+            digest.config,
+            path.display(),
+            e,
+            REGENERATE_ENV
+        )
+    });
+    if let Err(message) = compare_digest(&digest.config, &expected, &actual) {
+        panic!("{}", message);
+    }
+}
+
+/// 고정 홀카드로 시작하는 헤즈업 홀덤 (블라인드 50/100)
+fn heads_up_root(stack_bb: u32) -> holdem::State {
+    let mut state = holdem::State::new_hand([50, 100], [stack_bb * 100; 6], 2);
+    state.hole[0] = [0, 25]; // As Kh
+    state.hole[1] = [35, 48]; // 10d 10c
+    state
+}
+
+fn holdem_digest(config: &str, stack_bb: u32, iterations: usize) -> StrategyDigest {
+    let mut trainer = Trainer::<holdem::State>::new();
+    trainer.set_seed(2024);
+    trainer.run(vec![heads_up_root(stack_bb)], iterations);
+    StrategyDigest::from_trainer(config, iterations, &trainer)
+}
+
+fn leduc_digest(iterations: usize) -> StrategyDigest {
+    let mut trainer = Trainer::<LeducState>::new();
+    trainer.set_seed(2024);
+    trainer.run(vec![LeducState::new()], iterations);
+    StrategyDigest::from_trainer("leduc", iterations, &trainer)
+}
+
+#[test]
+fn golden_heads_up_10bb() {
+    check_golden(&holdem_digest("heads_up_10bb", 10, 8));
+}
+
+#[test]
+fn golden_heads_up_25bb() {
+    check_golden(&holdem_digest("heads_up_25bb", 25, 8));
+}
+
+#[test]
+fn golden_leduc() {
+    check_golden(&leduc_digest(300));
+}
+
+#[test]
+fn digest_is_stable_across_runs() {
+    assert_eq!(leduc_digest(100), leduc_digest(100));
+    assert_eq!(holdem_digest("repeat", 10, 3), holdem_digest("repeat", 10, 3));
+}
+
+#[test]
+fn changed_digest_is_reported_with_regeneration_hint() {
+    let digest = leduc_digest(100);
+    let mut changed = digest.clone();
+    changed.hash ^= 1;
+
+    assert!(compare_digest("leduc", &digest.render(), &digest.render()).is_ok());
+    let message = compare_digest("leduc", &digest.render(), &changed.render()).unwrap_err();
+    assert!(message.contains(REGENERATE_ENV), "{}", message);
+    assert!(message.contains("leduc"), "{}", message);
+}
+
+/// 르둑 전체 트리에서 같은 정보 집합 키는 같은 플레이어/액션/공개 정보를 가져야 함
+#[test]
+fn leduc_info_keys_do_not_collide() {
+    type Seen = HashMap<u64, (usize, Vec<<LeducState as Game>::Action>, u8, Option<u8>, u8)>;
+
+    fn walk(state: &LeducState, seen: &mut Seen) {
+        if state.is_terminal() {
+            return;
+        }
+        if state.is_chance_node() {
+            let dealt: Vec<u8> = state.hole.iter().flatten().chain(&state.board).copied().collect();
+            for card in (0..6).filter(|card| !dealt.contains(card)) {
+                let mut next = state.clone();
+                match next.hole.iter_mut().find(|hole| hole.is_none()) {
+                    Some(hole) => *hole = Some(card),
+                    None => next.board = Some(card),
+                }
+                walk(&next, seen);
+            }
+            return;
+        }
+
+        let player = LeducState::current_player(state).expect("플레이어 노드");
+        let actions = LeducState::legal_actions(state);
+        let observed = (
+            player,
+            actions.clone(),
+            state.round,
+            state.board.map(leduc_rank),
+            leduc_rank(state.hole[player].unwrap()),
+        );
+        let key = LeducState::info_key(state, player);
+        if let Some(previous) = seen.insert(key, observed.clone()) {
+            assert_eq!(previous, observed, "정보 집합 키 {:#x} 충돌", key);
+        }
+        for action in actions {
+            walk(&LeducState::next_state(state, action), seen);
+        }
+    }
+
+    let mut seen = Seen::new();
+    walk(&LeducState::new(), &mut seen);
+    assert_eq!(seen.len(), 288, "르둑 홀덤의 정보 집합 수");
+}
@@ -0,0 +1,185 @@
+// 홀덤 게임 상태 불변식 속성 기반 테스트 (proptest)
+//
+// 무작위 루트(2-6명, 블라인드/앤티/스택/베팅 규칙)에서 무작위 합법 액션 시퀀스를 진행하며
+// 매 전이마다 칩 보존, 생존자 수, 합법 액션, 정보 키, 카드 딜링 불변식을 확인합니다.
+// 실패하면 proptest가 루트와 액션 시퀀스를 줄여 가장 짧은 재현 사례를 보고합니다.
+//
+// 기본은 케이스 수를 제한해 일반 `cargo test`에서 돌고, 더 많이 돌리려면:
+//
+//     HOLDEM_PROPTEST_CASES=20000 cargo test --test holdem_invariants
+
+use nice_hand_core::game::holdem::{BettingRules, State};
+use nice_hand_core::game::tournament::AnteType;
+use nice_hand_core::solver::cfr_core::{Game, GameState};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// 일반 `cargo test`에서 돌리는 케이스 수
+const DEFAULT_CASES: u32 = 128;
+
+/// 핸드 하나에서 허용하는 최대 전이 수 (넘으면 핸드가 끝나지 않는 버그)
+const MAX_TRANSITIONS: usize = 200;
+
+fn config() -> ProptestConfig {
+    let cases = std::env::var("HOLDEM_PROPTEST_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(DEFAULT_CASES);
+    ProptestConfig::with_cases(cases)
+}
+
+/// 무작위 핸드 시작 조건
+#[derive(Debug, Clone)]
+struct RootSpec {
+    players: usize,
+    blinds: [u32; 2],
+    ante: u32,
+    ante_type: AnteType,
+    /// 앤티와 블라인드를 내기 전 스택 (블라인드보다 작은 스택도 포함)
+    stacks: [u32; 6],
+    betting: BettingRules,
+    seed: u64,
+}
+
+impl RootSpec {
+    fn root(&self) -> State {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut state =
+            State::new_hand_with_rng(self.blinds, self.ante, self.ante_type, self.stacks, self.players, &mut rng);
+        state.betting = self.betting;
+        state
+    }
+}
+
+fn root_spec() -> impl Strategy<Value = RootSpec> {
+    (
+        2usize..=6,
+        1u32..=100,
+        1u32..=4,
+        prop_oneof![Just(0u32), 1u32..=50],
+        prop_oneof![Just(AnteType::PerPlayer), Just(AnteType::BigBlindAnte), Just(AnteType::ButtonAnte)],
+        prop::array::uniform6(prop_oneof![1u32..=300, 300u32..=10_000]),
+        0u8..3,
+        any::<u64>(),
+    )
+        .prop_map(|(players, small_blind, multiple, ante, ante_type, stacks, rules, seed)| {
+            let big_blind = small_blind * multiple;
+            let betting = match rules {
+                0 => BettingRules::NoLimit,
+                1 => BettingRules::PotLimit,
+                _ => BettingRules::FixedLimit {
+                    small_bet: big_blind,
+                    big_bet: big_blind * 2,
+                    max_raises: 4,
+                },
+            };
+            RootSpec {
+                players,
+                blinds: [small_blind, big_blind],
+                ante,
+                ante_type,
+                stacks,
+                betting,
+                seed,
+            }
+        })
+}
+
+fn alive_count(state: &State) -> usize {
+    state.alive.iter().filter(|&&alive| alive).count()
+}
+
+/// 모든 상태에서 성립해야 하는 불변식
+fn check_state(state: &State, spec: &RootSpec) -> Result<(), TestCaseError> {
+    // 칩 보존: 팟은 각 좌석이 이번 핸드에 낸 칩(앤티, 블라인드, 베팅)의 합
+    let mut contributed = 0u32;
+    for seat in 0..6 {
+        prop_assert!(
+            state.stack[seat] <= spec.stacks[seat],
+            "좌석 {} 스택 {}이 시작 스택 {}보다 큼",
+            seat,
+            state.stack[seat],
+            spec.stacks[seat]
+        );
+        contributed += spec.stacks[seat] - state.stack[seat];
+    }
+    prop_assert_eq!(state.pot, contributed, "팟과 투자 합계 불일치");
+
+    // 딜된 카드는 모두 정상 범위이고 서로 겹치지 않음
+    let mut seen = 0u64;
+    let dealt = (0..spec.players).flat_map(|seat| state.hole[seat]).chain(state.board.iter().copied());
+    for card in dealt {
+        prop_assert!(card < 52, "잘못된 카드 {}", card);
+        prop_assert!(seen & (1 << card) == 0, "중복 카드 {}", card);
+        seen |= 1 << card;
+    }
+    prop_assert!(state.board.len() <= 5);
+
+    // 같은 상태의 정보 키는 항상 같음
+    let copy = state.clone();
+    for seat in 0..spec.players {
+        prop_assert_eq!(State::info_key(state, seat), State::info_key(&copy, seat));
+    }
+
+    if state.is_terminal() {
+        prop_assert!(State::legal_actions(state).is_empty(), "터미널 상태에 합법 액션이 있음");
+        prop_assert_eq!(State::current_player(state), None);
+    } else if !state.is_chance_node() {
+        prop_assert!(!State::legal_actions(state).is_empty(), "진행 중인 상태에 합법 액션이 없음");
+        let player = State::current_player(state);
+        prop_assert!(
+            player.is_some_and(|seat| state.alive[seat] && !state.is_all_in(seat)),
+            "액션할 플레이어가 유효하지 않음: {:?}",
+            player
+        );
+    }
+    Ok(())
+}
+
+/// 루트에서 `choices`로 액션을 골라 핸드를 진행하며 전이마다 불변식 확인
+fn play(spec: &RootSpec, choices: &[u8]) -> Result<(), TestCaseError> {
+    let mut state = spec.root();
+    let mut rng = StdRng::seed_from_u64(spec.seed.wrapping_add(1));
+    let mut choices = choices.iter();
+    check_state(&state, spec)?;
+
+    for _ in 0..MAX_TRANSITIONS {
+        if state.is_terminal() {
+            return Ok(());
+        }
+        let next = if state.is_chance_node() {
+            let next = State::apply_chance(&state, &mut rng);
+            // 찬스 노드는 카드를 추가만 함 (기존 보드와 홀카드는 그대로)
+            prop_assert!(next.board.len() > state.board.len(), "찬스 노드에서 카드가 딜되지 않음");
+            prop_assert_eq!(&next.board[..state.board.len()], &state.board[..]);
+            prop_assert_eq!(next.hole, state.hole);
+            next
+        } else {
+            let Some(&choice) = choices.next() else {
+                return Ok(());
+            };
+            let actions = State::legal_actions(&state);
+            let action = actions[choice as usize % actions.len()];
+            State::next_state(&state, action)
+        };
+        // 생존자는 늘지 않음
+        prop_assert!(alive_count(&next) <= alive_count(&state), "생존자 수가 늘어남");
+        check_state(&next, spec)?;
+        state = next;
+    }
+    Err(TestCaseError::fail(format!("{}번 전이 안에 핸드가 끝나지 않음", MAX_TRANSITIONS)))
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn random_action_sequences_keep_invariants(
+        spec in root_spec(),
+        choices in prop::collection::vec(any::<u8>(), 0..48),
+    ) {
+        play(&spec, &choices)?;
+    }
+}
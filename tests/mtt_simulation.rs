@@ -0,0 +1,268 @@
+// 180명 MTT 종단 간 시뮬레이션
+//
+// 생성한 블라인드 구조로 180명 6-max 토너먼트를 만들고, 작은 토너먼트 CFR 전략을
+// 학습한 뒤 모든 테이블에서 정책 인터페이스로 핸드를 진행합니다. 블라인드 레벨은
+// 토너먼트 시계로 올리고, 탈락과 테이블 밸런싱을 거쳐 우승자가 나올 때까지
+// 칩 보존, 상금 지급, 순위표 일관성, 테이블 정리를 매 라운드 확인합니다.
+//
+// 실행 시간이 길어 기본으로는 건너뜁니다:
+//
+//     cargo test --test mtt_simulation -- --ignored
+
+use nice_hand_core::game::holdem::State;
+use nice_hand_core::game::observation::{play_to_terminal, ActionPolicy};
+use nice_hand_core::game::tournament::clock::TournamentClock;
+use nice_hand_core::game::tournament::{AnteType, BlindLevel, MTTManager, TournamentState, TournamentStructure};
+use nice_hand_core::game::tournament_holdem::{TournamentCFRTrainer, TournamentHoldemState, TournamentPolicy};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+const TOTAL_PLAYERS: u32 = 180;
+/// 홀덤 엔진이 지원하는 최대 인원
+const SEATS_PER_TABLE: u32 = 6;
+const STARTING_STACK: u32 = 3000;
+const PRIZE_POOL: u64 = 180_000;
+/// 모든 테이블이 한 핸드씩 진행하는 데 걸리는 시뮬레이션 시간
+const MINUTES_PER_ROUND: u64 = 2;
+/// 무한 루프 방지
+const MAX_ROUNDS: u32 = 5_000;
+/// 순위표(ICM) 확인 주기
+const STANDINGS_EVERY: u32 = 25;
+
+/// 빅블라인드가 레벨마다 약 1.5배씩 오르고 4레벨부터 앤티가 붙는 구조
+fn generated_structure() -> TournamentStructure {
+    let mut big_blind = 20.0_f64;
+    let levels = (1..=30)
+        .map(|level| {
+            let bb = ((big_blind / 10.0).round() as u32 * 10).max(20);
+            big_blind *= 1.5;
+            BlindLevel {
+                level,
+                small_blind: bb / 2,
+                big_blind: bb,
+                ante: if level >= 4 { bb / 10 } else { 0 },
+            }
+        })
+        .collect();
+    TournamentStructure {
+        levels,
+        level_duration_minutes: 20,
+        starting_stack: STARTING_STACK,
+        ante_schedule: vec![],
+        ante_type: AnteType::PerPlayer,
+    }
+}
+
+/// 시드를 고정한 헤즈업 루트에서 작은 전략 학습
+fn train_tiny_strategy(tournament_state: &TournamentState) -> TournamentCFRTrainer {
+    let stacks = [STARTING_STACK; 6];
+    let mut trainer = TournamentCFRTrainer::new(tournament_state.clone(), stacks.to_vec());
+    trainer.base_trainer.set_seed(11);
+
+    let mut rng = StdRng::seed_from_u64(11);
+    let roots: Vec<TournamentHoldemState> = (0..4)
+        .map(|_| {
+            let mut holdem = State::new_hand([10, 20], stacks, 2);
+            deal_holes(&mut holdem, 2, &mut rng);
+            TournamentHoldemState::new_tournament_hand(holdem, tournament_state.clone(), vec![STARTING_STACK; 2])
+        })
+        .collect();
+    trainer.train_tournament_strategy(30, &roots);
+    trainer
+}
+
+/// 재현 가능하도록 시드 난수로 홀카드를 다시 딜링
+fn deal_holes(state: &mut State, player_count: usize, rng: &mut StdRng) {
+    let mut deck: Vec<u8> = state.variant.deck();
+    deck.shuffle(rng);
+    for seat in 0..player_count {
+        state.hole[seat] = [deck[seat * 2], deck[seat * 2 + 1]];
+    }
+}
+
+/// 테이블 하나에서 한 핸드를 진행하고 좌석 맵으로 결과를 플레이어에게 반영
+///
+/// 좌석 맵이 홀덤 좌석과 플레이어 id를 묶으므로 밸런싱으로 자리가 바뀌어도 스택이
+/// 올바른 플레이어에게 돌아갑니다. 탈락 처리와 밸런싱도 정산 안에서 일어납니다.
+fn play_table_hand(
+    mtt: &mut MTTManager,
+    table_id: u32,
+    policies: &mut [TournamentPolicy<'_, StdRng>],
+    rng: &mut StdRng,
+) {
+    let Ok(hand) = mtt.deal_table_hand(table_id, rng) else {
+        return;
+    };
+    let player_count = hand.seat_map.players().count();
+    hand.seat_map.validate(&hand.root.alive).expect("딜링한 좌석 맵은 유효해야 함");
+
+    for policy in policies.iter_mut() {
+        policy.set_context(mtt.tournament_state.clone(), hand.starting_stacks[..player_count].to_vec());
+    }
+    let mut policy_refs: Vec<&mut dyn ActionPolicy> =
+        policies.iter_mut().map(|policy| policy as &mut dyn ActionPolicy).collect();
+    let end = play_to_terminal(&hand.root, &mut policy_refs, rng);
+    let settled = end.settle(hand.starting_stacks, rng);
+    assert_eq!(
+        settled.iter().sum::<u32>(),
+        hand.starting_stacks.iter().sum::<u32>(),
+        "핸드 정산에서 칩이 사라지거나 생김"
+    );
+
+    mtt.settle_table_hand(&hand.result(settled)).expect("좌석 맵으로 정산");
+}
+
+fn total_seated_chips(mtt: &MTTManager) -> u64 {
+    mtt.tables
+        .iter()
+        .flat_map(|table| table.seats.iter().flatten())
+        .map(|player| player.stack_size as u64)
+        .sum()
+}
+
+fn assert_invariants(mtt: &MTTManager, round: u32) {
+    let remaining = mtt.tournament_state.players_remaining;
+    let total_chips = TOTAL_PLAYERS as u64 * STARTING_STACK as u64;
+    assert_eq!(total_seated_chips(mtt), total_chips, "라운드 {} 칩 보존", round);
+
+    // 탈락자는 자리를 비우고, 남은 인원에 필요한 만큼만 테이블이 남음
+    let seated: Vec<u32> = mtt
+        .tables
+        .iter()
+        .flat_map(|table| table.seats.iter().flatten())
+        .map(|player| player.player_id)
+        .collect();
+    assert_eq!(seated.len() as u32, remaining, "라운드 {} 착석 인원", round);
+    assert_eq!(mtt.count_active_players(), remaining);
+    assert_eq!(mtt.get_tournament_standings().len() as u32, remaining);
+    assert!(
+        mtt.tables.len() as u32 <= remaining.div_ceil(SEATS_PER_TABLE),
+        "라운드 {}: {}명에 테이블 {}개",
+        round,
+        remaining,
+        mtt.tables.len()
+    );
+    let counts: Vec<u32> = mtt.tables.iter().map(|table| table.seated_players()).collect();
+    let (min, max) = (counts.iter().min().unwrap(), counts.iter().max().unwrap());
+    assert!(max - min <= 1, "라운드 {} 테이블 불균형 {:?}", round, counts);
+
+    let finished: HashSet<u32> = mtt.finishes.iter().map(|finish| finish.player_id).collect();
+    assert_eq!(finished.len(), mtt.finishes.len(), "중복 탈락 기록");
+    if remaining > 1 {
+        assert!(seated.iter().all(|id| !finished.contains(id)));
+        assert_eq!(mtt.finishes.len() as u32, TOTAL_PLAYERS - remaining);
+    }
+    for finish in &mtt.finishes {
+        assert_eq!(finish.payout, mtt.tournament_state.payout_for_position(finish.position));
+    }
+}
+
+fn assert_standings(mtt: &MTTManager) {
+    let report = mtt.standings_report();
+    assert_eq!(report.players_remaining, mtt.tournament_state.players_remaining);
+    assert_eq!(report.players.len() as u32, report.players_remaining);
+    for (idx, standing) in report.players.iter().enumerate() {
+        assert_eq!(standing.rank, idx as u32 + 1);
+        assert!(standing.icm_equity.is_finite() && standing.icm_equity >= 0.0);
+    }
+    assert!(report.players.windows(2).all(|pair| pair[0].stack >= pair[1].stack));
+    let equity: f64 = report.players.iter().map(|standing| standing.icm_equity).sum();
+    let tolerance = 1e-6 * report.remaining_prize_pool.max(1) as f64;
+    assert!(
+        (equity - report.remaining_prize_pool as f64).abs() <= tolerance,
+        "ICM 합 {} != 남은 상금 {}",
+        equity,
+        report.remaining_prize_pool
+    );
+}
+
+#[test]
+#[ignore = "180명 토너먼트 전체를 시뮬레이션하므로 느림"]
+fn simulate_180_player_mtt_to_a_winner() {
+    let structure = generated_structure();
+    let mut mtt = MTTManager::new(TOTAL_PLAYERS, SEATS_PER_TABLE, structure, PRIZE_POOL);
+    let trainer = train_tiny_strategy(&mtt.tournament_state);
+    let trained_nodes = trainer.base_trainer.nodes.len();
+    assert!(trained_nodes > 0);
+
+    let mut clock = TournamentClock::new(mtt.tournament_state.clone());
+    let start = Instant::now();
+    clock.start(start);
+
+    let mut rng = StdRng::seed_from_u64(2024);
+    let mut policies: Vec<TournamentPolicy<'_, StdRng>> = (0..SEATS_PER_TABLE as u64)
+        .map(|seat| {
+            TournamentPolicy::new(
+                &trainer,
+                mtt.tournament_state.clone(),
+                Vec::new(),
+                StdRng::seed_from_u64(100 + seat),
+            )
+        })
+        .collect();
+
+    assert_invariants(&mtt, 0);
+    let mut round = 0;
+    let mut max_level = 1;
+    while mtt.tournament_state.players_remaining > 1 {
+        round += 1;
+        assert!(round <= MAX_ROUNDS, "{}라운드 안에 우승자가 나오지 않음", MAX_ROUNDS);
+
+        // 블라인드 레벨은 시계가 정함
+        clock.tick(start + Duration::from_secs(round as u64 * MINUTES_PER_ROUND * 60));
+        mtt.tournament_state.current_level = clock.state().current_level;
+        mtt.tournament_state.minutes_elapsed = clock.state().minutes_elapsed;
+        max_level = max_level.max(mtt.tournament_state.current_level);
+
+        // 앞선 테이블의 탈락으로 밸런싱되어 사라진 테이블은 건너뜀
+        let table_ids: Vec<u32> = mtt.tables.iter().map(|table| table.table_id).collect();
+        for table_id in table_ids {
+            play_table_hand(&mut mtt, table_id, &mut policies, &mut rng);
+            clock.set_players_remaining(mtt.tournament_state.players_remaining);
+        }
+
+        assert_invariants(&mtt, round);
+        if round % STANDINGS_EVERY == 0 {
+            assert_standings(&mtt);
+        }
+    }
+
+    assert!(max_level > 1, "블라인드 레벨이 오르지 않음");
+    assert_eq!(clock.state().players_remaining, 1);
+    assert_eq!(mtt.tables.len(), 1);
+    assert_standings(&mtt);
+
+    // 모든 플레이어의 순위가 기록되고 상금 총액은 구조와 같음
+    // (좌석 맵 정산에서 같은 핸드에 같은 스택으로 탈락하면 가장 좋은 순위를 공유하고 그 순위들의 상금을 나눔)
+    assert_eq!(mtt.finishes.len() as u32, TOTAL_PLAYERS);
+    let mut finishes: Vec<_> = mtt.finishes.iter().collect();
+    finishes.sort_by_key(|finish| finish.position);
+    let mut next_place = 1;
+    for tied in finishes.chunk_by(|a, b| a.position == b.position) {
+        let count = tied.len() as u32;
+        assert_eq!(tied[0].position, next_place, "공유한 순위 다음은 건너뛴 순위");
+        let pool: u64 = (next_place..next_place + count)
+            .map(|place| mtt.tournament_state.payout_for_position(place))
+            .sum();
+        assert_eq!(tied.iter().map(|finish| finish.payout).sum::<u64>(), pool);
+        let (low, high) = tied.iter().fold((u64::MAX, 0), |(low, high), finish| {
+            (low.min(finish.payout), high.max(finish.payout))
+        });
+        assert!(high - low <= 1, "공동 순위 상금은 균등 분배: {:?}", tied);
+        next_place += count;
+    }
+    assert_eq!(next_place, TOTAL_PLAYERS + 1);
+    let winner = mtt.finishes.last().unwrap();
+    assert_eq!(winner.position, 1);
+    assert_eq!(mtt.get_tournament_standings()[0].0, winner.player_id);
+    let paid: u64 = mtt.finishes.iter().map(|finish| finish.payout).sum();
+    let structure_total: u64 = mtt.tournament_state.payout_structure.iter().map(|level| level.amount).sum();
+    assert_eq!(paid, structure_total);
+    assert!(paid <= PRIZE_POOL);
+
+    // 서빙은 학습 노드를 늘리지 않음
+    assert_eq!(trainer.base_trainer.nodes.len(), trained_nodes);
+}
@@ -0,0 +1,167 @@
+// 튜토리얼 3: 분석 API를 JSON 요청 파일로 실행하고 골든 응답과 비교
+//
+// 1. fixtures/analysis_request.json을 `AnalysisRequest`로 읽습니다. 웹 클라이언트가 보내는
+//    요청과 같은 형식이며, 플랍 A-6-T에서 AA(탑 세트)로 300 베팅을 마주한 헤즈업 스팟입니다.
+// 2. `analyze_poker_state`로 분석하고 응답을 JSON으로 직렬화합니다.
+// 3. fixtures/analysis_response.golden.json과 필드 단위로 비교합니다.
+//    - 몬테카를로 EV에서 나온 값(EV, 표준오차, 액션 강도, 폴드 에퀴티)은 아래 허용 범위 안에서
+//    - 계산 시간은 비교하지 않음
+//    - 나머지(액션 순서, 추천 액션, 리스크, 레이즈 범위, 샘플 수 등)는 정확히 같아야 함
+//
+// 응답 형식이나 분석 동작을 의도적으로 바꿨다면 골든 파일을 다시 만드세요:
+//
+//     REGENERATE_GOLDEN=1 cargo test --test tutorials analysis
+
+use crate::fixture_path;
+use nice_hand_core::api::analysis::{analyze_poker_state, AnalysisRequest};
+use serde_json::Value;
+
+/// 골든 파일 재생성 환경 변수 (tests/golden_strategies.rs와 같음)
+const REGENERATE_ENV: &str = "REGENERATE_GOLDEN";
+
+/// EV 허용 오차 (칩). "quick" 분석의 레이즈 EV 표준오차는 약 55칩이므로 두 독립 실행의
+/// 차이가 3σ(약 230칩)를 넘으면 실패합니다.
+const EV_TOLERANCE_CHIPS: f64 = 250.0;
+/// 표준오차의 상대 허용 오차
+const STD_ERROR_TOLERANCE_RATIO: f64 = 0.25;
+/// 액션 강도(0-100, 최고 EV 대비 비율) 허용 오차
+const ACTION_STRENGTH_TOLERANCE: f64 = 15.0;
+
+/// 정확히 비교하는 실수의 허용 오차 (JSON 텍스트 왕복에서 생기는 마지막 자리 차이)
+const FLOAT_EPSILON: f64 = 1e-9;
+
+/// 필드 비교 방식
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tolerance {
+    Exact,
+    Absolute(f64),
+    Relative(f64),
+    Ignore,
+}
+
+/// 응답 경로별 비교 방식 (설명 요인은 인덱스 대신 `kind`로 경로를 만듦)
+fn tolerance_for(path: &[String]) -> Tolerance {
+    let path: Vec<&str> = path.iter().map(String::as_str).collect();
+    match path.as_slice() {
+        ["metadata", "calculation_time_ms"] => Tolerance::Ignore,
+        [.., "ev"] => Tolerance::Absolute(EV_TOLERANCE_CHIPS),
+        [.., "std_error"] => Tolerance::Relative(STD_ERROR_TOLERANCE_RATIO),
+        ["insights", "action_strength", _] => Tolerance::Absolute(ACTION_STRENGTH_TOLERANCE),
+        // 폴드 에퀴티 요인의 값은 레이즈와 콜의 EV 차이
+        ["insights", "explanation", "factors", "FoldEquity", "value"] => Tolerance::Absolute(2.0 * EV_TOLERANCE_CHIPS),
+        _ => Tolerance::Exact,
+    }
+}
+
+/// 기대 값과 실제 값을 재귀적으로 비교해 어긋난 경로를 `mismatches`에 모음
+fn compare(expected: &Value, actual: &Value, path: &mut Vec<String>, mismatches: &mut Vec<String>) {
+    let tolerance = tolerance_for(path);
+    let location = format!("/{}", path.join("/"));
+    match (expected, actual, tolerance) {
+        (_, _, Tolerance::Ignore) => {}
+        (Value::Number(e), Value::Number(a), _) if e.is_f64() || a.is_f64() => {
+            let (e, a) = (e.as_f64().unwrap(), a.as_f64().unwrap());
+            let allowed = match tolerance {
+                Tolerance::Absolute(limit) => limit,
+                Tolerance::Relative(ratio) => ratio * e.abs(),
+                _ => FLOAT_EPSILON * e.abs().max(1.0),
+            };
+            if (e - a).abs() > allowed {
+                mismatches.push(format!("{}: {} (기대 {} ± {})", location, a, e, allowed));
+            }
+        }
+        (Value::Object(e), Value::Object(a), _) => {
+            let mut keys: Vec<&String> = e.keys().chain(a.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                match (e.get(key), a.get(key)) {
+                    (Some(e), Some(a)) => {
+                        path.push(key.clone());
+                        compare(e, a, path, mismatches);
+                        path.pop();
+                    }
+                    (Some(_), None) => mismatches.push(format!("{}/{}: 응답에 없음", location, key)),
+                    (None, Some(_)) => mismatches.push(format!("{}/{}: 골든 파일에 없는 필드", location, key)),
+                    (None, None) => unreachable!(),
+                }
+            }
+        }
+        (Value::Array(e), Value::Array(a), _) if e.len() == a.len() => {
+            for (index, (e, a)) in e.iter().zip(a).enumerate() {
+                let segment = match e.get("kind").and_then(Value::as_str) {
+                    Some(kind) => kind.to_string(),
+                    None => index.to_string(),
+                };
+                path.push(segment);
+                compare(e, a, path, mismatches);
+                path.pop();
+            }
+        }
+        _ if expected == actual => {}
+        _ => mismatches.push(format!("{}: {} (기대 {})", location, actual, expected)),
+    }
+}
+
+#[test]
+fn analysis_response_matches_golden() {
+    // 1. 요청 파일 읽기
+    let request_json = std::fs::read_to_string(fixture_path("analysis_request.json")).unwrap();
+    let request: AnalysisRequest = serde_json::from_str(&request_json).expect("요청 파일은 AnalysisRequest 형식");
+
+    // 2. 분석 실행
+    let response = analyze_poker_state(request).expect("유효한 스팟");
+    let actual = serde_json::to_value(&response).unwrap();
+
+    // 3. 골든 응답과 비교 (재생성 모드에서는 덮어쓰기)
+    let golden_path = fixture_path("analysis_response.golden.json");
+    if std::env::var_os(REGENERATE_ENV).is_some() {
+        std::fs::write(&golden_path, serde_json::to_string_pretty(&actual).unwrap() + "\n").unwrap();
+        println!("골든 파일 재생성: {}", golden_path.display());
+        return;
+    }
+    let golden_json = std::fs::read_to_string(&golden_path)
+        .unwrap_or_else(|e| panic!("골든 파일 {}을 읽을 수 없습니다 ({}). {}=1로 생성하세요.", golden_path.display(), e, REGENERATE_ENV));
+    let expected: Value = serde_json::from_str(&golden_json).unwrap();
+
+    let mut mismatches = Vec::new();
+    compare(&expected, &actual, &mut Vec::new(), &mut mismatches);
+    assert!(
+        mismatches.is_empty(),
+        "분석 응답이 골든 파일과 다릅니다:\n  {}\n의도한 변경이라면 {}=1 cargo test --test tutorials analysis로 재생성하세요.",
+        mismatches.join("\n  "),
+        REGENERATE_ENV
+    );
+
+    // 문서에 적힌 핵심 결론: 탑 세트는 레이즈가 최선이고 폴드는 최악
+    let evs = &response.ev_analysis.action_evs;
+    assert_eq!(evs.first().map(|ev| ev.action), Some(nice_hand_core::game::holdem::Act::Raise(0)));
+    assert_eq!(evs.last().map(|ev| ev.action), Some(nice_hand_core::game::holdem::Act::Fold));
+}
+
+#[test]
+fn golden_comparison_applies_tolerance_bands() {
+    let golden: Value = serde_json::json!({
+        "ev_analysis": { "action_evs": [{ "ev": 1000.0, "std_error": 50.0 }] },
+        "metadata": { "calculation_time_ms": 400, "samples_used": 2000 }
+    });
+    let within = |actual: Value| {
+        let mut mismatches = Vec::new();
+        compare(&golden, &actual, &mut Vec::new(), &mut mismatches);
+        mismatches
+    };
+
+    // EV가 허용 범위 안이고 계산 시간만 다르면 통과
+    let mut close = golden.clone();
+    close["ev_analysis"]["action_evs"][0]["ev"] = (1000.0 + EV_TOLERANCE_CHIPS / 2.0).into();
+    close["metadata"]["calculation_time_ms"] = 9999.into();
+    assert!(within(close).is_empty());
+
+    // EV가 범위를 넘거나 정확히 비교하는 필드가 다르면 실패
+    let mut drifted = golden.clone();
+    drifted["ev_analysis"]["action_evs"][0]["ev"] = (1000.0 + 2.0 * EV_TOLERANCE_CHIPS).into();
+    drifted["metadata"]["samples_used"] = 1000.into();
+    let mismatches = within(drifted);
+    assert_eq!(mismatches.len(), 2, "{:?}", mismatches);
+    assert!(mismatches[0].starts_with("/ev_analysis/action_evs/0/ev"), "{:?}", mismatches);
+}
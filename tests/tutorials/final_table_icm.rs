@@ -0,0 +1,71 @@
+// 튜토리얼 2: 6인 파이널 테이블 ICM 분석과 딜 계산
+//
+// 1. 남은 6명의 스택과 6위까지의 상금으로 `ICMCalculator`를 만듭니다.
+// 2. 각자의 ICM 에퀴티(남은 상금 중 기대 상금)를 계산합니다. 합은 항상 남은 상금입니다.
+// 3. 같은 스택으로 세 가지 딜을 계산합니다.
+//    - ICM 딜: 각자 자기 ICM 에퀴티를 받음
+//    - 칩 찹: 모두 6위 상금을 먼저 받고 나머지를 칩 비율로 나눔
+//    - 하이브리드(ICM 50%): 두 딜의 평균
+//    딜은 항상 정수 단위로 반올림되고, 남는 단위는 정해진 순서로 배분되어 합이 남은 상금과 같습니다.
+//
+// 아래 값은 센트 단위까지 고정된 문서 값입니다. ICM 모델이나 반올림 규칙을 의도적으로
+// 바꿨다면 값을 다시 계산해 고쳐야 합니다.
+
+use nice_hand_core::game::tournament::deals::{chip_chop_deal, hybrid_deal, icm_deal, icm_equities};
+
+/// 칩 리더부터 남은 6명의 스택 (합 154,000)
+const STACKS: [u32; 6] = [52_000, 38_500, 27_000, 19_500, 11_000, 6_000];
+/// 1-6위 상금 (합 106,000)
+const PAYOUTS: [u64; 6] = [40_000, 25_000, 16_000, 11_000, 8_000, 6_000];
+
+/// 에퀴티 비교 허용 오차 (반 센트: 센트 단위로 반올림하면 같은 값)
+const CENT_TOLERANCE: f64 = 0.005;
+
+/// 정확한 ICM 에퀴티 (센트 단위)
+const EXPECTED_EQUITIES: [f64; 6] = [25_395.25, 22_394.63, 19_131.50, 16_485.58, 12_715.82, 9_877.22];
+/// ICM 딜 (정수 단위, 에퀴티를 반올림한 값)
+const EXPECTED_ICM_DEAL: [f64; 6] = [25_395.0, 22_395.0, 19_131.0, 16_486.0, 12_716.0, 9_877.0];
+/// 칩 찹: 6,000씩 먼저 받고 나머지 70,000을 칩 비율로 (예: 11,000칩은 6,000 + 5,000)
+const EXPECTED_CHIP_CHOP: [f64; 6] = [29_636.0, 23_500.0, 18_273.0, 14_864.0, 11_000.0, 8_727.0];
+/// ICM 50% 하이브리드
+const EXPECTED_HYBRID: [f64; 6] = [27_516.0, 22_947.0, 18_702.0, 15_675.0, 11_858.0, 9_302.0];
+
+#[test]
+fn final_table_icm_equities_and_deals() {
+    let pool: u64 = PAYOUTS.iter().sum();
+
+    // 1-2. ICM 에퀴티: 칩 리더는 칩 비율(33.8%)보다 적게, 숏스택은 더 많이 받음
+    let equities = icm_equities(&STACKS, &PAYOUTS);
+    for (seat, (equity, expected)) in equities.iter().zip(EXPECTED_EQUITIES).enumerate() {
+        assert!(
+            (equity - expected).abs() < CENT_TOLERANCE,
+            "{}번째 스택({})의 ICM 에퀴티 {:.4} != {:.2}",
+            seat + 1,
+            STACKS[seat],
+            equity,
+            expected
+        );
+    }
+    assert!((equities.iter().sum::<f64>() - pool as f64).abs() < CENT_TOLERANCE);
+    let chip_total: u32 = STACKS.iter().sum();
+    assert!(equities[0] < pool as f64 * STACKS[0] as f64 / chip_total as f64);
+    assert!(equities[5] > pool as f64 * STACKS[5] as f64 / chip_total as f64);
+
+    // 3. 딜: 정수 단위까지 정확히 같고, 모두 남은 상금 전체를 나눔
+    let deals = [
+        ("ICM 딜", icm_deal(&STACKS, &PAYOUTS), EXPECTED_ICM_DEAL),
+        ("칩 찹", chip_chop_deal(&STACKS, &PAYOUTS), EXPECTED_CHIP_CHOP),
+        ("하이브리드", hybrid_deal(&STACKS, &PAYOUTS, 0.5), EXPECTED_HYBRID),
+    ];
+    for (name, deal, expected) in &deals {
+        assert_eq!(deal.as_slice(), expected.as_slice(), "{}", name);
+        assert_eq!(deal.iter().sum::<f64>(), pool as f64, "{}", name);
+        // 누구도 이미 확보한 6위 상금보다 적게 받지 않음
+        assert!(deal.iter().all(|&amount| amount >= PAYOUTS[5] as f64), "{}", name);
+    }
+
+    // 칩 리더는 칩 찹을, 숏스택은 ICM 딜을 선호
+    let (icm, chop) = (&deals[0].1, &deals[1].1);
+    assert!(chop[0] > icm[0]);
+    assert!(icm[5] > chop[5]);
+}
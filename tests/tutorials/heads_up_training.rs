@@ -0,0 +1,118 @@
+// 튜토리얼 1: 헤즈업 25bb 전략 학습, 저장, 다시 읽기, 조회
+//
+// 1. 블라인드 50/100, 스택 2,500(25bb)의 헤즈업 핸드 두 개를 루트로 정합니다.
+//    - 딜 A: 스몰 블라인드(버튼) AA, 빅 블라인드 72o
+//    - 딜 B: 스몰 블라인드 72o, 빅 블라인드 KK
+// 2. 시드를 고정한 external sampling MCCFR로 5,000번 학습합니다.
+// 3. 학습 결과를 `TrainerSnapshot`으로 디스크에 저장하고 새 학습기로 다시 읽습니다.
+// 4. 다시 읽은 전략에서 이름 붙은 세 스팟의 [폴드, 콜, 레이즈] 확률을 조회합니다.
+//
+// 루트마다 홀카드가 정해져 있으므로 각 플레이어는 한 가지 상대 핸드만 상대로 학습합니다
+// (체크포인트 학습과 같은 방식). 아래 범위는 이 학습 결과를 문서로 고정한 것이며, 시드가
+// 같으면 결과는 항상 같으므로 범위의 여유는 학습 코드의 작은 변경을 흡수하기 위한 것입니다.
+// 디버그 빌드에서 약 3분 걸립니다.
+
+use nice_hand_core::game::holdem::{Act, State};
+use nice_hand_core::solver::cfr_core::{Game, Trainer, TrainerSnapshot};
+use nice_hand_core::solver::mccfr::{ExplorationSchedule, MCCFRTrainer};
+use std::collections::BTreeMap;
+
+/// 학습 반복 수와 시드 (바꾸면 아래 범위를 다시 확인해야 함)
+const ITERATIONS: usize = 5_000;
+const SEED: u64 = 2432;
+/// 상대 노드 탐색 비율 (드문 라인도 방문하도록)
+const EXPLORATION: f64 = 0.1;
+
+/// 블라인드와 25bb 스택
+const BLINDS: [u32; 2] = [50, 100];
+const STACK: u32 = 2_500;
+
+/// 카드 번호 (수트 * 13 + 랭크, 랭크 0 = 에이스, 1 = 2, ..., 12 = 킹)
+const ACES: [u8; 2] = [0, 13]; // As Ah
+const SEVEN_DEUCE: [u8; 2] = [6, 14]; // 7s 2h
+const SEVEN_DEUCE_OTHER: [u8; 2] = [19, 27]; // 7h 2d
+const KINGS: [u8; 2] = [25, 38]; // Kh Kd
+
+/// 문서화된 확률 범위 [최소, 최대] (폴드, 콜, 레이즈 순)
+type Band = [(f64, f64); 3];
+
+/// 스팟 1: 스몰 블라인드 AA의 첫 액션 - 폴드하지 않고 대부분 레이즈 (약 80%)
+const SB_ACES_OPEN: Band = [(0.0, 0.02), (0.05, 0.35), (0.65, 0.95)];
+/// 스팟 2: KK를 상대로 한 스몰 블라인드 72o의 첫 액션 - 50칩만 더 내면 되므로 거의 폴드하지
+/// 않고 림프와 레이즈를 섞지만, AA보다 레이즈가 적음
+const SB_SEVEN_DEUCE_OPEN: Band = [(0.0, 0.1), (0.4, 0.75), (0.25, 0.6)];
+/// 스팟 3: 72o의 팟 레이즈를 받은 빅 블라인드 KK - 폴드하지 않고 거의 항상 리레이즈
+const BB_KINGS_VS_RAISE: Band = [(0.0, 0.02), (0.0, 0.2), (0.8, 1.0)];
+
+fn deal(sb: [u8; 2], bb: [u8; 2]) -> State {
+    let mut root = State::new_hand(BLINDS, [STACK; 6], 2);
+    root.hole[0] = sb; // 헤즈업은 0번 좌석이 버튼이자 스몰 블라인드
+    root.hole[1] = bb;
+    root
+}
+
+/// 이름 붙은 스팟과 조회할 좌석
+fn named_spots() -> Vec<(&'static str, State, usize, Band)> {
+    let deal_b = deal(SEVEN_DEUCE, KINGS);
+    vec![
+        ("SB AA 오픈", deal(ACES, SEVEN_DEUCE_OTHER), 0, SB_ACES_OPEN),
+        ("SB 72o 오픈", deal_b.clone(), 0, SB_SEVEN_DEUCE_OPEN),
+        ("BB KK vs 팟 레이즈", State::next_state(&deal_b, Act::Raise(0)), 1, BB_KINGS_VS_RAISE),
+    ]
+}
+
+#[test]
+fn train_save_reload_and_query_heads_up_25bb() {
+    // 1-2. 학습 (시드 고정: 같은 코드면 항상 같은 전략)
+    let roots = vec![deal(ACES, SEVEN_DEUCE_OTHER), deal(SEVEN_DEUCE, KINGS)];
+    let mut mccfr = MCCFRTrainer::<State>::new(1.0).with_exploration(ExplorationSchedule::Constant(EXPLORATION));
+    mccfr.set_seed(SEED);
+    mccfr.run(roots, ITERATIONS);
+    assert!(!mccfr.nodes.is_empty());
+
+    // 3. 저장과 다시 읽기 (학습기 스냅샷은 bincode로 직렬화 가능)
+    let snapshot = TrainerSnapshot {
+        nodes: mccfr.nodes.iter().map(|(key, node)| (*key, node.clone())).collect(),
+        iterations_run: ITERATIONS as u64,
+        rng: None,
+        metadata: BTreeMap::from([("tutorial".to_string(), "heads_up_25bb".to_string())]),
+    };
+    let path = std::env::temp_dir().join(format!("nice-hand-tutorial-{}.bin", std::process::id()));
+    std::fs::write(&path, bincode::serialize(&snapshot).unwrap()).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let reloaded: Trainer<State> = Trainer::from_snapshot(bincode::deserialize(&bytes).unwrap());
+    assert_eq!(reloaded.nodes.len(), mccfr.nodes.len());
+    assert_eq!(reloaded.iterations_run(), ITERATIONS as u64);
+    assert_eq!(reloaded.metadata["tutorial"], "heads_up_25bb");
+
+    // 4. 이름 붙은 스팟 조회
+    let mut raise_frequencies = Vec::new();
+    for (name, spot, seat, band) in named_spots() {
+        assert_eq!(State::current_player(&spot), Some(seat), "{}", name);
+        assert_eq!(State::legal_actions(&spot), vec![Act::Fold, Act::Call, Act::Raise(0)], "{}", name);
+
+        let key = State::info_key(&spot, seat);
+        let strategy = reloaded.nodes.get(&key).map(|node| node.average()).unwrap_or_else(|| panic!("{}: 학습되지 않은 스팟", name));
+        // 다시 읽은 전략은 학습 직후의 전략과 같음
+        assert_eq!(strategy, mccfr.nodes[&key].average(), "{}", name);
+
+        println!("{}: 폴드 {:.3} 콜 {:.3} 레이즈 {:.3}", name, strategy[0], strategy[1], strategy[2]);
+        raise_frequencies.push(strategy[2]);
+        for ((label, prob), (min, max)) in ["폴드", "콜", "레이즈"].iter().zip(&strategy).zip(band) {
+            assert!(
+                (min..=max).contains(prob),
+                "{}: {} 확률 {:.3}이 문서화된 범위 [{}, {}]를 벗어남 (전략 {:?})",
+                name,
+                label,
+                prob,
+                min,
+                max,
+                strategy
+            );
+        }
+    }
+
+    // AA는 72o보다 자주 레이즈
+    assert!(raise_frequencies[0] > raise_frequencies[1], "{:?}", raise_frequencies);
+}
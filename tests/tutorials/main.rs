@@ -0,0 +1,27 @@
+// 실행 가능한 튜토리얼 (문서 수준의 종단 간 예제)
+//
+// 각 모듈은 문서가 설명하는 순서 그대로 공개 API를 사용하는 하나의 시나리오이며,
+// 결과가 문서에 적힌 범위를 벗어나면 실패합니다. 허용 범위는 모듈 맨 위의 상수로만
+// 정해 두었으므로, 의도적으로 동작을 바꿨다면 그 상수(또는 fixtures/의 골든 파일)를
+// 직접 고쳐야 테스트가 다시 통과합니다.
+//
+//     cargo test --test tutorials
+//
+// - heads_up_training: 헤즈업 25bb 전략 학습 → 저장 → 다시 읽기 → 이름 붙은 스팟 조회
+// - final_table_icm: 6인 파이널 테이블 ICM 에퀴티와 딜 계산 (센트 단위까지 고정)
+// - analysis_golden: 고정 JSON 요청으로 분석 API 실행 → 골든 JSON 응답과 비교
+
+mod analysis_golden;
+mod final_table_icm;
+mod heads_up_training;
+
+use std::path::PathBuf;
+
+/// 튜토리얼 입력/골든 파일 위치 (tests/tutorials/fixtures/)
+pub fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("tutorials")
+        .join("fixtures")
+        .join(name)
+}